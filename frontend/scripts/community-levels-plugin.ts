@@ -0,0 +1,189 @@
+import { existsSync, readdirSync, readFileSync } from 'node:fs'
+import type { IncomingMessage, ServerResponse } from 'node:http'
+import { homedir } from 'node:os'
+import path from 'node:path'
+import process from 'node:process'
+
+import type { Plugin } from 'vite'
+
+import type { Result } from '../src/core/result'
+import { ASCII_LEVEL_SUFFIX, formatAsciiLevelError, parseAsciiLevel } from '../src/data/asciiLevel'
+
+/** URL prefix the page loads community packs from; pack ids become `community/<id>`. */
+const COMMUNITY_URL_PREFIX = '/data/community/'
+const LEVEL_SUFFIX = '.level.json'
+const EMPTY_BEHAVIOR = { schemaVersion: 1, policies: {}, assignments: {} }
+
+/**
+ * User levels directory: `HWU_LEVELS_DIR`, else `configuredDir` (`[levels] directory` in
+ * `config.toml`), else `$XDG_DATA_HOME/he-walks-unseen/levels`, else
+ * `~/.local/share/he-walks-unseen/levels`.
+ */
+export function resolveCommunityLevelsDir(configuredDir: string | null = null): string {
+  if (process.env.HWU_LEVELS_DIR) {
+    return path.resolve(process.env.HWU_LEVELS_DIR)
+  }
+
+  if (configuredDir) {
+    return configuredDir
+  }
+
+  const dataHome = process.env.XDG_DATA_HOME ?? path.join(homedir(), '.local', 'share')
+
+  return path.join(dataHome, 'he-walks-unseen', 'levels')
+}
+
+/** Ids of every `<id>.level.json` or `<id>.level.txt` in `dir`, sorted; none if it is missing. */
+export function scanCommunityLevelIds(dir: string): string[] {
+  if (!existsSync(dir)) {
+    return []
+  }
+
+  const ids = readdirSync(dir).flatMap((file) => {
+    const suffix = [LEVEL_SUFFIX, ASCII_LEVEL_SUFFIX].find((candidate) => file.endsWith(candidate))
+
+    return suffix && file.length > suffix.length ? [file.slice(0, -suffix.length)] : []
+  })
+
+  return [...new Set(ids)].sort()
+}
+
+function sendJson(res: ServerResponse, status: number, body: string) {
+  res.statusCode = status
+  res.setHeader('Content-Type', 'application/json')
+  res.end(body)
+}
+
+/**
+ * Companion files are optional: a level without its own behavior gets no patrols, and missing
+ * rules or theme fall back to the default pack's.
+ */
+function readCommunityFile(dir: string, publicDataDir: string, file: string): string | null {
+  const own = path.join(dir, file)
+
+  if (existsSync(own)) {
+    return readFileSync(own, 'utf8')
+  }
+
+  const match = /^.+\.(behavior|rules|theme)\.json$/.exec(file)
+
+  if (!match) {
+    return null
+  }
+
+  if (match[1] === 'behavior') {
+    return JSON.stringify(EMPTY_BEHAVIOR)
+  }
+
+  return readFileSync(path.join(publicDataDir, `default.${match[1]}.json`), 'utf8')
+}
+
+/**
+ * `<id>.level.json` for a level that only exists as a `<id>.level.txt` sketch, converted on the
+ * fly; null when there is no such sketch (or a JSON file shadows it).
+ */
+function readSketchedLevel(dir: string, file: string): Result<string, string> | null {
+  if (!file.endsWith(LEVEL_SUFFIX) || existsSync(path.join(dir, file))) {
+    return null
+  }
+
+  const id = file.slice(0, -LEVEL_SUFFIX.length)
+  const sketchFile = `${id}${ASCII_LEVEL_SUFFIX}`
+  const sketch = path.join(dir, sketchFile)
+
+  if (!existsSync(sketch)) {
+    return null
+  }
+
+  const parsed = parseAsciiLevel(readFileSync(sketch, 'utf8'), { id })
+
+  return parsed.ok
+    ? { ok: true, value: JSON.stringify(parsed.value) }
+    : { ok: false, error: formatAsciiLevelError(sketchFile, parsed.error) }
+}
+
+function handleCommunityRequest(
+  dir: string,
+  publicDataDir: string,
+  req: IncomingMessage,
+  res: ServerResponse,
+  next: () => void,
+) {
+  const url = req.url?.split('?')[0] ?? ''
+
+  if (!url.startsWith(COMMUNITY_URL_PREFIX)) {
+    next()
+    return
+  }
+
+  let file: string
+
+  try {
+    file = decodeURIComponent(url.slice(COMMUNITY_URL_PREFIX.length))
+  } catch {
+    // A stray `%` is a malformed escape; answer it like any other bad name, not with a crash.
+    sendJson(res, 400, JSON.stringify({ error: 'invalid community file name' }))
+    return
+  }
+
+  // Rescanned per request so levels dropped in while the server runs show up on reload.
+  if (file === 'index.json') {
+    const packs = scanCommunityLevelIds(dir).map((id) => ({ id, class: 'experimental' }))
+    sendJson(res, 200, JSON.stringify({ schemaVersion: 1, packs }))
+    return
+  }
+
+  if (file.includes('/') || file.includes('\\') || file.startsWith('.')) {
+    sendJson(res, 400, JSON.stringify({ error: 'invalid community file name' }))
+    return
+  }
+
+  const sketched = readSketchedLevel(dir, file)
+
+  if (sketched) {
+    sendJson(
+      res,
+      sketched.ok ? 200 : 422,
+      sketched.ok ? sketched.value : JSON.stringify({ error: sketched.error }),
+    )
+    return
+  }
+
+  const body = readCommunityFile(dir, publicDataDir, file)
+
+  if (body === null) {
+    sendJson(res, 404, JSON.stringify({ error: `${file} not found` }))
+    return
+  }
+
+  sendJson(res, 200, body)
+}
+
+/**
+ * Serves the user levels directory under `/data/community/` for the dev and preview servers.
+ * Static builds have no such route, so the page just finds no community levels there.
+ */
+export function communityLevelsPlugin(configuredDir: string | null = null): Plugin {
+  const dir = resolveCommunityLevelsDir(configuredDir)
+
+  return {
+    name: 'hwu-community-levels',
+    configureServer(server) {
+      const publicDataDir = path.join(server.config.publicDir, 'data')
+
+      server.config.logger.info(
+        `[community] ${scanCommunityLevelIds(dir).length} level(s) in ${dir}`,
+      )
+      server.middlewares.use((req, res, next) => {
+        handleCommunityRequest(dir, publicDataDir, req, res, next)
+      })
+    },
+    configurePreviewServer(server) {
+      const publicDataDir = path.join(server.config.publicDir, 'data')
+
+      server.middlewares.use((req, res, next) => {
+        handleCommunityRequest(dir, publicDataDir, req, res, next)
+      })
+    },
+  }
+}
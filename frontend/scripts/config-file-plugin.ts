@@ -0,0 +1,167 @@
+import { existsSync, mkdirSync, readFileSync, writeFileSync } from 'node:fs'
+import type { IncomingMessage, ServerResponse } from 'node:http'
+import { homedir } from 'node:os'
+import path from 'node:path'
+import process from 'node:process'
+
+import type { Plugin } from 'vite'
+
+import { parseToml } from '../src/data/toml'
+
+/** Same URL the page fetches in `src/app/runtimeConfig.ts`. */
+const CONFIG_URL = '/config.toml'
+const CONFIG_CONTENT_TYPE = 'application/toml'
+
+/**
+ * Runtime config file: `HWU_CONFIG`, else `$XDG_CONFIG_HOME/he-walks-unseen/config.toml`, else
+ * `~/.config/he-walks-unseen/config.toml`.
+ */
+export function resolveConfigPath(): string {
+  if (process.env.HWU_CONFIG) {
+    return path.resolve(process.env.HWU_CONFIG)
+  }
+
+  const configHome = process.env.XDG_CONFIG_HOME ?? path.join(homedir(), '.config')
+
+  return path.join(configHome, 'he-walks-unseen', 'config.toml')
+}
+
+/**
+ * `[levels] directory` from the config file, with `~` expanded; null when unset or unreadable.
+ * The page reports a broken file itself, so the server just starts without it.
+ */
+export function readConfiguredLevelsDir(configPath: string): string | null {
+  if (!existsSync(configPath)) {
+    return null
+  }
+
+  const parsed = parseToml(readFileSync(configPath, 'utf8'))
+  const dir = parsed.ok ? parsed.value.get('levels.directory') : undefined
+
+  if (typeof dir !== 'string') {
+    return null
+  }
+
+  return path.resolve(path.dirname(configPath), dir.replace(/^~(?=$|\/)/, homedir()))
+}
+
+function readBody(req: IncomingMessage): Promise<string> {
+  return new Promise((resolve, reject) => {
+    let body = ''
+
+    req.setEncoding('utf8')
+    req.on('data', (chunk: string) => {
+      body += chunk
+    })
+    req.on('end', () => resolve(body))
+    req.on('error', reject)
+  })
+}
+
+/**
+ * Browsers send `Origin` with every PUT; only the page this server itself serves may write, so
+ * another site open in the same browser cannot rewrite the file through it.
+ */
+function isSameOrigin(req: IncomingMessage): boolean {
+  const { origin, host } = req.headers
+  const fetchSite = req.headers['sec-fetch-site']
+
+  if (!origin || !host || (fetchSite !== undefined && fetchSite !== 'same-origin')) {
+    return false
+  }
+
+  try {
+    return new URL(origin).host === host
+  } catch {
+    return false
+  }
+}
+
+function sendToml(res: ServerResponse, status: number, body: string) {
+  res.statusCode = status
+  res.setHeader('Content-Type', CONFIG_CONTENT_TYPE)
+  res.end(body)
+}
+
+/** GET answers with the file (empty when missing); PUT, from the settings screen, replaces it. */
+function handleConfigRequest(
+  configPath: string,
+  writable: boolean,
+  req: IncomingMessage,
+  res: ServerResponse,
+  next: () => void,
+) {
+  if (req.url?.split('?')[0] !== CONFIG_URL) {
+    next()
+    return
+  }
+
+  // The page only offers to save settings back when PUT is allowed.
+  res.setHeader('Allow', writable ? 'GET, HEAD, PUT' : 'GET, HEAD')
+
+  if (req.method === 'GET' || req.method === 'HEAD') {
+    sendToml(res, 200, existsSync(configPath) ? readFileSync(configPath, 'utf8') : '')
+    return
+  }
+
+  if (req.method !== 'PUT' || !writable) {
+    res.statusCode = 405
+    res.end()
+    return
+  }
+
+  if (!isSameOrigin(req)) {
+    res.statusCode = 403
+    res.end()
+    return
+  }
+
+  void readBody(req).then(
+    (body) => {
+      // Never overwrite the user's file with something the page could not read back.
+      if (!parseToml(body).ok) {
+        res.statusCode = 400
+        res.end()
+        return
+      }
+
+      mkdirSync(path.dirname(configPath), { recursive: true })
+      writeFileSync(configPath, body, 'utf8')
+      sendToml(res, 200, body)
+    },
+    () => {
+      res.statusCode = 400
+      res.end()
+    },
+  )
+}
+
+export interface ConfigFilePluginOptions {
+  /** Accept same-origin writes from the settings screen; only ever set for the dev server. */
+  writable: boolean
+}
+
+/**
+ * Serves the runtime config file at `/config.toml`. With `writable` the dev server also accepts
+ * writes from the settings screen; the preview server only reads it, and static builds have no
+ * such route.
+ */
+export function configFilePlugin(
+  configPath = resolveConfigPath(),
+  { writable }: ConfigFilePluginOptions = { writable: false },
+): Plugin {
+  return {
+    name: 'hwu-config-file',
+    configureServer(server) {
+      server.config.logger.info(`[config] ${configPath}${writable ? '' : ' (read-only)'}`)
+      server.middlewares.use((req, res, next) => {
+        handleConfigRequest(configPath, writable, req, res, next)
+      })
+    },
+    configurePreviewServer(server) {
+      server.middlewares.use((req, res, next) => {
+        handleConfigRequest(configPath, false, req, res, next)
+      })
+    },
+  }
+}
@@ -0,0 +1,533 @@
+import { spawnSync } from 'node:child_process'
+import { existsSync } from 'node:fs'
+import path from 'node:path'
+import process from 'node:process'
+import { fileURLToPath } from 'node:url'
+
+import { actionSummary, actionText } from '../src/app/shell/actionSummary'
+import { createRng } from '../src/core/rng'
+import type { Result } from '../src/core/result'
+import { createTimelineSet } from '../src/core/timelineSet'
+import { formatTraceLine, setTraceSink } from '../src/core/trace'
+import { ASCII_LEVEL_SUFFIX } from '../src/data/asciiLevel'
+import {
+  createInitialSolverState,
+  findTightDetectionConstraints,
+  replaySolverActions,
+  searchSolutionFromState,
+  type DetectionConstraint,
+} from '../src/data/generation/solver'
+import { lintContentPack } from '../src/data/lint'
+import { toLoadedBootContent } from '../src/data/loader'
+import { applyMutators, normalizeMutators } from '../src/data/mutators'
+import {
+  applyPlayDifficulty,
+  DEFAULT_PLAY_DIFFICULTY,
+  isPlayDifficulty,
+} from '../src/data/playDifficulty'
+import { applyRuleProfile, ruleProfileById, STANDARD_RULE_PROFILE } from '../src/data/ruleProfiles'
+import { gameFrames } from '../src/game/frames'
+import { runInteractionPipeline } from '../src/game/interactions/pipeline'
+import type { InteractionAction, InteractionState } from '../src/game/interactions/types'
+import { renderFrameAscii, renderSliceAscii } from '../src/render/ascii'
+import { createTranslator } from '../src/render/i18n'
+import { loadPackFiles, readJson, readPackFiles } from './story-shared'
+
+const SCRIPTS_DIR = path.dirname(fileURLToPath(import.meta.url))
+const LEVEL_SUFFIX = '.level.json'
+
+const USAGE = `usage: npm run hwu -- <command> [flags]
+
+commands:
+  play <level>        start the dev server and open the level in the browser
+  edit <level>        open the level file in $VISUAL/$EDITOR, then lint it
+  solve <level>       print the shortest solution and where detection was tight
+  lint <level>        run the level linter
+  replay <file>       step through a replay or crash report file
+  generate [flags]    export a generated pack (same flags as gen:pack, e.g. --seed)
+
+<level> is a pack id under public/data or a path to a *.level.json or *.level.txt file.
+
+global flags:
+  --theme <file>      play with the cssVars of a *.theme.json file
+  --ascii             print the board as text after every solved or replayed turn
+  --frames            after solve or replay, print every turn of the final world line as text
+  --debug             play with the debug console; elsewhere trace the pipeline to stderr
+  --help              show this message`
+
+type HwuCommand =
+  | { kind: 'play'; level: string }
+  | { kind: 'edit'; level: string }
+  | { kind: 'solve'; level: string }
+  | { kind: 'lint'; level: string }
+  | { kind: 'replay'; file: string }
+  | { kind: 'generate'; args: string[] }
+
+interface CliArgs {
+  command: HwuCommand | null
+  theme: string | null
+  ascii: boolean
+  frames: boolean
+  debug: boolean
+  publicDataDir: string
+}
+
+/** A level on disk: its file prefix (`<prefix>.level.json`) and the id the page knows it by. */
+interface LevelRef {
+  prefix: string
+  /** The level file itself: `<prefix>.level.json`, or a `<prefix>.level.txt` sketch. */
+  file: string
+  packId: string
+  /** False for standalone files, which borrow missing companions the way community levels do. */
+  inPublicData: boolean
+}
+
+/** Global flags may appear anywhere; everything else is the command and its arguments. */
+function parseArgs(argv: string[]): Result<CliArgs, string> {
+  const rest: string[] = []
+  let theme: string | null = null
+  let ascii = false
+  let frames = false
+  let debug = false
+  let help = false
+
+  for (let index = 0; index < argv.length; index += 1) {
+    const token = argv[index]
+
+    if (token === '--theme') {
+      const value = argv[index + 1]
+
+      if (!value || value.startsWith('--')) {
+        return { ok: false, error: '--theme needs a *.theme.json path' }
+      }
+
+      theme = path.resolve(process.cwd(), value)
+      index += 1
+    } else if (token === '--ascii') {
+      ascii = true
+    } else if (token === '--frames') {
+      frames = true
+    } else if (token === '--debug') {
+      debug = true
+    } else if (token === '--help' || token === '-h') {
+      help = true
+    } else {
+      rest.push(token)
+    }
+  }
+
+  const publicDataDir = path.resolve(process.cwd(), 'public/data')
+  const [name, ...operands] = rest
+
+  if (help || name === undefined) {
+    return { ok: true, value: { command: null, theme, ascii, frames, debug, publicDataDir } }
+  }
+
+  if (name === 'generate') {
+    return {
+      ok: true,
+      value: {
+        command: { kind: 'generate', args: operands },
+        theme,
+        ascii,
+        frames,
+        debug,
+        publicDataDir,
+      },
+    }
+  }
+
+  if (!['play', 'edit', 'solve', 'lint', 'replay'].includes(name)) {
+    return { ok: false, error: `unknown command ${name}` }
+  }
+
+  const unknownFlag = operands.find((operand) => operand.startsWith('--'))
+
+  if (unknownFlag) {
+    return { ok: false, error: `unknown flag ${unknownFlag} for ${name}` }
+  }
+
+  if (operands.length !== 1) {
+    const operand = name === 'replay' ? 'file' : 'level'
+
+    return { ok: false, error: `${name} takes exactly one ${operand}` }
+  }
+
+  const command: HwuCommand =
+    name === 'replay'
+      ? { kind: 'replay', file: path.resolve(process.cwd(), operands[0]) }
+      : { kind: name as 'play' | 'edit' | 'solve' | 'lint', level: operands[0] }
+
+  return { ok: true, value: { command, theme, ascii, frames, debug, publicDataDir } }
+}
+
+function resolveLevel(level: string, publicDataDir: string): LevelRef {
+  if (level.endsWith(ASCII_LEVEL_SUFFIX)) {
+    // Only the community route converts sketches for the page, so they always load as one.
+    const prefix = path.resolve(process.cwd(), level.slice(0, -ASCII_LEVEL_SUFFIX.length))
+
+    return {
+      prefix,
+      file: `${prefix}${ASCII_LEVEL_SUFFIX}`,
+      packId: `community/${path.basename(prefix)}`,
+      inPublicData: false,
+    }
+  }
+
+  if (!level.endsWith(LEVEL_SUFFIX)) {
+    const prefix = path.join(publicDataDir, level)
+
+    return { prefix, file: `${prefix}${LEVEL_SUFFIX}`, packId: level, inPublicData: true }
+  }
+
+  const prefix = path.resolve(process.cwd(), level.slice(0, -LEVEL_SUFFIX.length))
+  const file = `${prefix}${LEVEL_SUFFIX}`
+  const relative = path.relative(publicDataDir, prefix)
+
+  if (!relative.startsWith('..') && !path.isAbsolute(relative)) {
+    return { prefix, file, packId: relative.split(path.sep).join('/'), inPublicData: true }
+  }
+
+  return { prefix, file, packId: `community/${path.basename(prefix)}`, inPublicData: false }
+}
+
+/** Standalone levels may leave out their companions, like community levels. */
+function companionDefaults(level: LevelRef, publicDataDir: string): string | null {
+  return level.inPublicData ? null : publicDataDir
+}
+
+/** Runs another script in this folder through vite-node, passing the terminal through. */
+function runScript(script: string, args: string[]): number {
+  const binary = process.platform === 'win32' ? 'vite-node.cmd' : 'vite-node'
+  const viteNode = path.join(process.cwd(), 'node_modules', '.bin', binary)
+  const result = spawnSync(viteNode, [path.join(SCRIPTS_DIR, script), ...args], {
+    stdio: 'inherit',
+  })
+
+  return result.status ?? 1
+}
+
+/** How a near miss reads next to its step in `solve`. */
+const TIGHT_LABELS: Record<DetectionConstraint, string> = {
+  maxDistance: 'one cell out of range',
+  delayTurns: 'one turn ahead of its delay',
+}
+
+function printBoard(state: InteractionState) {
+  for (const row of renderSliceAscii(state, state.currentTime)) {
+    console.log(`  ${row}`)
+  }
+}
+
+/** The whole run, turn by turn, as the final cube resolved it. */
+function printFrames(state: InteractionState) {
+  for (const frame of gameFrames(state)) {
+    console.log(`  turn ${frame.turn}, t=${frame.time}`)
+
+    for (const row of renderFrameAscii(frame, state.boardWidth, state.boardHeight)) {
+      console.log(`  ${row}`)
+    }
+  }
+}
+
+async function play(level: LevelRef, cli: CliArgs): Promise<void> {
+  if (!existsSync(level.file)) {
+    throw new Error(`no level at ${level.file}`)
+  }
+
+  if (!level.inPublicData) {
+    // The community plugin serves this directory; it reads the variable when vite loads it.
+    process.env.HWU_LEVELS_DIR = path.dirname(level.prefix)
+  }
+
+  const query = new URLSearchParams({ pack: level.packId })
+
+  if (cli.debug) {
+    query.set('debug', '')
+  }
+
+  if (cli.theme) {
+    const theme = await readJson(cli.theme)
+
+    if (!theme.ok) {
+      throw new Error(`theme load failed: ${theme.error}`)
+    }
+
+    const cssVars = (theme.value as { cssVars?: Record<string, string> }).cssVars ?? {}
+
+    for (const [name, value] of Object.entries(cssVars)) {
+      query.set(`theme.${name}`, value)
+    }
+  }
+
+  const { createServer } = await import('vite')
+  const server = await createServer({ server: { open: `/?${query.toString()}` } })
+
+  await server.listen()
+  server.printUrls()
+}
+
+async function lint(level: LevelRef, cli: CliArgs): Promise<number> {
+  const files = await readPackFiles(
+    level.prefix,
+    level.packId,
+    companionDefaults(level, cli.publicDataDir),
+  )
+
+  if (!files.ok) {
+    console.error(`[hwu] ${files.error}`)
+    return 1
+  }
+
+  const issues = lintContentPack(files.value)
+  const errors = issues.filter((issue) => issue.severity === 'error').length
+
+  for (const issue of issues) {
+    const log = issue.severity === 'error' ? console.error : console.warn
+    log(`[hwu] ${issue.severity} ${issue.code}: ${issue.message}`)
+  }
+
+  console.log(
+    `[hwu] lint ${level.packId}: ${errors} error(s), ${issues.length - errors} warning(s)`,
+  )
+  return errors > 0 ? 1 : 0
+}
+
+async function edit(level: LevelRef, cli: CliArgs): Promise<number> {
+  const [editor, ...editorArgs] = (process.env.VISUAL ?? process.env.EDITOR ?? 'vi').split(' ')
+  const result = spawnSync(editor, [...editorArgs, level.file], { stdio: 'inherit' })
+
+  if (result.error || result.status !== 0) {
+    console.error(`[hwu] ${editor} exited without saving cleanly`)
+    return 1
+  }
+
+  return lint(level, cli)
+}
+
+async function solve(level: LevelRef, cli: CliArgs): Promise<number> {
+  const pack = await loadPackFiles(
+    level.prefix,
+    level.packId,
+    companionDefaults(level, cli.publicDataDir),
+  )
+
+  if (!pack.ok) {
+    console.error(`[hwu] ${pack.error}`)
+    return 1
+  }
+
+  const initial = createInitialSolverState(pack.value)
+
+  if (!initial) {
+    console.error(`[hwu] ${level.packId}: no player start`)
+    return 1
+  }
+
+  const t = createTranslator('en')
+  const { report, actions } = searchSolutionFromState(initial)
+
+  if (!report.solved || !actions) {
+    console.log(`[hwu] ${level.packId}: no solution (${report.visitedNodes} nodes visited)`)
+    return 1
+  }
+
+  const states = replaySolverActions(initial, actions)
+  const tight = findTightDetectionConstraints(initial, actions)
+
+  console.log(
+    `[hwu] ${level.packId}: path length ${report.shortestPathLength} ` +
+      `(${report.visitedNodes} nodes visited)`,
+  )
+
+  if (cli.ascii) {
+    printBoard(initial)
+  }
+
+  actions.forEach((action, index) => {
+    const step = index + 1
+    const notes = tight
+      .filter((entry) => entry.step === step)
+      .map((entry) => `${entry.enemyId} ${TIGHT_LABELS[entry.constraint]}`)
+    const suffix = notes.length > 0 ? `  [${notes.join(', ')}]` : ''
+
+    console.log(`  ${step}. ${actionText(t, action)}${suffix}`)
+
+    if (cli.ascii && states[index]) {
+      printBoard(states[index])
+    }
+  })
+
+  if (cli.frames) {
+    printFrames(states.at(-1) ?? initial)
+  }
+
+  console.log(
+    tight.length > 0
+      ? `[hwu] tight detection: ${tight.length} near miss(es) across ` +
+          `${new Set(tight.map((entry) => entry.step)).size} step(s)`
+      : '[hwu] tight detection: none',
+  )
+  return 0
+}
+
+interface ReplayFile {
+  packId: string
+  seed?: string
+  ruleProfileId?: unknown
+  playDifficulty?: unknown
+  mutators?: unknown[]
+  actions: InteractionAction[]
+}
+
+function isReplayFile(value: unknown): value is ReplayFile {
+  if (typeof value !== 'object' || value === null) {
+    return false
+  }
+
+  const record = value as Record<string, unknown>
+
+  return typeof record.packId === 'string' && Array.isArray(record.actions)
+}
+
+/** Replays a saved replay or crash report from the level start, one line per action. */
+async function replay(file: string, cli: CliArgs): Promise<number> {
+  const loaded = await readJson(file)
+
+  if (!loaded.ok || !isReplayFile(loaded.value)) {
+    const reason = loaded.ok ? 'needs packId and actions' : loaded.error
+
+    console.error(`[hwu] ${file}: not a replay (${reason})`)
+    return 1
+  }
+
+  const record = loaded.value
+  const level = resolveLevel(record.packId, cli.publicDataDir)
+  const pack = await loadPackFiles(
+    level.prefix,
+    level.packId,
+    companionDefaults(level, cli.publicDataDir),
+  )
+
+  if (!pack.ok) {
+    console.error(`[hwu] ${pack.error}`)
+    return 1
+  }
+
+  const state = createInitialSolverState(pack.value)
+
+  if (!state) {
+    console.error(`[hwu] ${record.packId}: no player start`)
+    return 1
+  }
+
+  if (record.seed) {
+    state.rng = createRng(record.seed)
+  }
+
+  // Same layering as the page's loader: difficulty, then rule profile, then mutators.
+  const ruleProfile = ruleProfileById(
+    typeof record.ruleProfileId === 'string' ? record.ruleProfileId : STANDARD_RULE_PROFILE.id,
+  )
+  const playDifficulty =
+    ruleProfile.difficulty ??
+    (isPlayDifficulty(record.playDifficulty) ? record.playDifficulty : DEFAULT_PLAY_DIFFICULTY)
+  const mutators = normalizeMutators(record.mutators ?? [])
+  const content = applyMutators(
+    applyRuleProfile(
+      applyPlayDifficulty(toLoadedBootContent(pack.value), playDifficulty),
+      ruleProfile,
+    ),
+    mutators,
+  )
+
+  state.detectionConfig = content.detectionConfig
+  state.enemyDetectionConfigById = content.enemyDetectionConfigById
+  state.interactionConfig = content.interactionConfig
+  state.timelines = ruleProfile.branching ? createTimelineSet(state.cube) : null
+  console.log(`[hwu] ${record.packId}: ${ruleProfile.name} rules on ${playDifficulty}`)
+
+  if (mutators.length > 0) {
+    console.log(`[hwu] ${record.packId}: mutators ${mutators.join(', ')}`)
+  }
+
+  const t = createTranslator('en')
+
+  if (cli.ascii) {
+    printBoard(state)
+  }
+
+  for (const action of record.actions) {
+    const historyLength = state.history.length
+
+    runInteractionPipeline(state, action)
+
+    const entry = state.history.length > historyLength ? state.history.at(-1) : undefined
+
+    console.log(
+      entry
+        ? `  ${entry.turn}. ${actionSummary(t, entry)}`
+        : `  -. ${actionText(t, action)}: ${state.status}`,
+    )
+
+    if (cli.ascii) {
+      printBoard(state)
+    }
+  }
+
+  if (cli.frames) {
+    printFrames(state)
+  }
+
+  console.log(`[hwu] ${record.packId}: ${state.phase} after turn ${state.turn}`)
+  return 0
+}
+
+async function main(): Promise<void> {
+  const parsed = parseArgs(process.argv.slice(2))
+
+  if (!parsed.ok) {
+    console.error(`[hwu] ${parsed.error}`)
+    console.error(USAGE)
+    process.exitCode = 1
+    return
+  }
+
+  const cli = parsed.value
+  const command = cli.command
+
+  if (!command) {
+    console.log(USAGE)
+    return
+  }
+
+  if (cli.debug && command.kind !== 'play') {
+    setTraceSink({ record: (record) => process.stderr.write(formatTraceLine(record)) })
+  }
+
+  switch (command.kind) {
+    case 'play':
+      await play(resolveLevel(command.level, cli.publicDataDir), cli)
+      return
+    case 'edit':
+      process.exitCode = await edit(resolveLevel(command.level, cli.publicDataDir), cli)
+      return
+    case 'solve':
+      process.exitCode = await solve(resolveLevel(command.level, cli.publicDataDir), cli)
+      return
+    case 'lint':
+      process.exitCode = await lint(resolveLevel(command.level, cli.publicDataDir), cli)
+      return
+    case 'replay':
+      process.exitCode = await replay(command.file, cli)
+      return
+    case 'generate':
+      process.exitCode = runScript('export-generated-pack.ts', command.args)
+      return
+  }
+}
+
+main().catch((error) => {
+  console.error('[hwu] failed', error)
+  process.exitCode = 1
+})
@@ -1,3 +1,4 @@
+import { existsSync } from 'node:fs'
 import { mkdir, readFile, writeFile } from 'node:fs/promises'
 import path from 'node:path'
 
@@ -8,7 +9,9 @@ import {
   type PublicContentPackManifest,
   type PublicContentPackManifestEntry,
 } from '../src/data/loader'
+import { ASCII_LEVEL_SUFFIX, formatAsciiLevelError, parseAsciiLevel } from '../src/data/asciiLevel'
 import type { ContentPack } from '../src/data/contracts'
+import { validateContentPack } from '../src/data/validate'
 
 export function parseArgMap(argv: string[]): Map<string, string> {
   const args = new Map<string, string>()
@@ -50,6 +53,94 @@ export async function readJson(filePath: string): Promise<Result<unknown, string
   }
 }
 
+export interface RawPackFiles {
+  level: unknown
+  behavior: unknown
+  rules: unknown
+  theme: unknown
+}
+
+const EMPTY_BEHAVIOR = { schemaVersion: 1, policies: {}, assignments: {} }
+
+/**
+ * Reads `<prefix>.level.json` (or, failing that, a `<prefix>.level.txt` sketch) and its behavior,
+ * rules and theme files. With `defaultsDir`, missing companions fall back the way community
+ * levels do: no patrols, and the default pack's rules and theme.
+ */
+export async function readPackFiles(
+  prefix: string,
+  label: string,
+  defaultsDir: string | null = null,
+): Promise<Result<RawPackFiles, string>> {
+  const parts = ['level', 'behavior', 'rules', 'theme'] as const
+  const files: Partial<Record<(typeof parts)[number], unknown>> = {}
+
+  for (const part of parts) {
+    const own = `${prefix}.${part}.json`
+
+    if (part === 'level' && !existsSync(own) && existsSync(`${prefix}${ASCII_LEVEL_SUFFIX}`)) {
+      const sketchFile = `${path.basename(prefix)}${ASCII_LEVEL_SUFFIX}`
+      const sketch = await readFile(`${prefix}${ASCII_LEVEL_SUFFIX}`, 'utf8')
+      const parsed = parseAsciiLevel(sketch, { id: path.basename(prefix) })
+
+      if (!parsed.ok) {
+        const message = formatAsciiLevelError(sketchFile, parsed.error)
+
+        return { ok: false, error: `level load failed (${label}): ${message}` }
+      }
+
+      files.level = parsed.value
+      continue
+    }
+
+    if (defaultsDir && part === 'behavior' && !existsSync(own)) {
+      files[part] = EMPTY_BEHAVIOR
+      continue
+    }
+
+    const loaded = await readJson(
+      defaultsDir && part !== 'level' && !existsSync(own)
+        ? path.join(defaultsDir, `default.${part}.json`)
+        : own,
+    )
+
+    if (!loaded.ok) {
+      return { ok: false, error: `${part} load failed (${label}): ${loaded.error}` }
+    }
+
+    files[part] = loaded.value
+  }
+
+  return {
+    ok: true,
+    value: {
+      level: files.level,
+      behavior: files.behavior,
+      rules: files.rules,
+      theme: files.theme,
+    },
+  }
+}
+
+/** `readPackFiles` plus content validation. */
+export async function loadPackFiles(
+  prefix: string,
+  label: string,
+  defaultsDir: string | null = null,
+): Promise<Result<ContentPack, string>> {
+  const files = await readPackFiles(prefix, label, defaultsDir)
+
+  if (!files.ok) {
+    return files
+  }
+
+  const validated = validateContentPack(files.value)
+
+  return validated.ok
+    ? validated
+    : { ok: false, error: `content validation failed (${label}): ${validated.error.kind}` }
+}
+
 export async function writeJson(filePath: string, value: unknown): Promise<void> {
   await mkdir(path.dirname(filePath), { recursive: true })
   await writeFile(filePath, `${JSON.stringify(value, null, 2)}\n`, 'utf8')
@@ -2,8 +2,8 @@ import { readFile } from 'node:fs/promises'
 import path from 'node:path'
 import process from 'node:process'
 
-import { type ContentLoadError } from '../src/data/contracts'
 import {
+  formatContentLoadError,
   parsePublicContentPackManifest,
   type PublicContentPackManifestEntry,
 } from '../src/data/loader'
@@ -18,11 +18,14 @@ import {
   validateIconPackConfig,
   validateLevelSymbolSlots,
 } from '../src/data/validate'
+import { lintContentPack, type LintOptions } from '../src/data/lint'
 import { evaluatePackClassPolicy } from '../src/data/packPolicy'
 
 interface CliArgs {
   all: boolean
   packId?: string
+  lint?: string
+  propagationWarnings?: LintOptions['propagationWarnings']
   manifestPath: string
   publicDataDir: string
 }
@@ -55,49 +58,23 @@ function parseArgs(argv: string[]): CliArgs {
     args.get('manifest') ?? path.join(publicDataDir, 'index.json'),
   )
 
+  const propagationWarnings = args.get('propagation-warnings')
+
   return {
     all: args.get('all') === 'true',
     packId: args.get('pack-id'),
+    lint: args.get('lint'),
+    propagationWarnings:
+      propagationWarnings === 'error' ||
+      propagationWarnings === 'warning' ||
+      propagationWarnings === 'off'
+        ? propagationWarnings
+        : undefined,
     manifestPath,
     publicDataDir,
   }
 }
 
-function formatContentLoadError(error: ContentLoadError): string {
-  switch (error.kind) {
-    case 'InvalidShape':
-      return `${error.kind} (${error.file}): ${error.message}`
-    case 'InvalidSchemaVersion':
-      return `${error.kind} (${error.file}): expected ${error.expected}, got ${String(error.actual)}`
-    case 'MissingIconPackId':
-      return `${error.kind}: theme=${error.themeId}`
-    case 'UnknownArchetypeReference':
-      return `${error.kind}: instance=${error.instanceId}, archetype=${error.archetype}`
-    case 'InvalidRiftTarget':
-      return `${error.kind}: archetype=${error.archetype}`
-    case 'ConflictingRiftSource':
-      return `${error.kind}: archetype=${error.archetype}`
-    case 'InvalidBehaviorPathPoint':
-      return `${error.kind}: key=${error.key}`
-    case 'UnknownBehaviorReference':
-      return `${error.kind}: instance=${error.instanceId}, behavior=${error.behavior}`
-    case 'UnknownBehaviorAssignmentInstance':
-      return `${error.kind}: instance=${error.instanceId}`
-    case 'UnknownDetectionProfileReference':
-      return `${error.kind}: instance=${error.instanceId}, profile=${error.profile}`
-    case 'InvalidDetectionProfile':
-      return `${error.kind}: key=${error.key}, message=${error.message}`
-    case 'InvalidIconSlotReference':
-      return `${error.kind}: archetype=${error.archetype}, symbol=${error.symbol}`
-    case 'InvalidMapBounds':
-      return `${error.kind}: ${error.width}x${error.height}, depth=${error.timeDepth}`
-    case 'InvalidStartPosition':
-      return `${error.kind}: (${error.start.x},${error.start.y},t=${error.start.t})`
-    case 'UnsupportedBehaviorPolicy':
-      return `${error.kind}: key=${error.key}, kind=${error.policyKind}`
-  }
-}
-
 function formatProgressionError(error: ProgressionLoadError): string {
   switch (error.kind) {
     case 'FetchFailed':
@@ -215,8 +192,67 @@ async function validatePackAtPath(
   return { ok: true }
 }
 
+/**
+ * `--lint path/to/<id>.level.json`: authoring checks for one level, reading the behavior, rules
+ * and theme files next to it. Exits non-zero only on errors; warnings are printed.
+ */
+async function lintLevelAtPath(levelPath: string, options: LintOptions): Promise<void> {
+  if (!levelPath.endsWith('.level.json')) {
+    console.error(`[validate:pack] --lint expects a <id>.level.json path (got ${levelPath})`)
+    process.exitCode = 1
+    return
+  }
+
+  const prefix = path.resolve(process.cwd(), levelPath.slice(0, -'.level.json'.length))
+  const parts = ['level', 'behavior', 'rules', 'theme'] as const
+  const files: Partial<Record<(typeof parts)[number], unknown>> = {}
+
+  for (const part of parts) {
+    const loaded = await readJson(`${prefix}.${part}.json`)
+
+    if (!loaded.ok) {
+      console.error(`[validate:pack] ${part} load failed: ${loaded.error}`)
+      process.exitCode = 1
+      return
+    }
+
+    files[part] = loaded.value
+  }
+
+  const issues = lintContentPack(
+    {
+      level: files.level,
+      behavior: files.behavior,
+      rules: files.rules,
+      theme: files.theme,
+    },
+    {},
+    options,
+  )
+  const errors = issues.filter((issue) => issue.severity === 'error').length
+
+  for (const issue of issues) {
+    const log = issue.severity === 'error' ? console.error : console.warn
+    log(`[validate:pack] ${issue.severity} ${issue.code}: ${issue.message}`)
+  }
+
+  console.log(
+    `[validate:pack] lint ${path.basename(levelPath)}: ${errors} error(s), ` +
+      `${issues.length - errors} warning(s)`,
+  )
+
+  if (errors > 0) {
+    process.exitCode = 1
+  }
+}
+
 async function main(): Promise<void> {
   const cli = parseArgs(process.argv.slice(2))
+
+  if (cli.lint) {
+    await lintLevelAtPath(cli.lint, { propagationWarnings: cli.propagationWarnings })
+    return
+  }
   const manifestRaw = await readJson(cli.manifestPath)
 
   if (!manifestRaw.ok) {
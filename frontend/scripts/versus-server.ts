@@ -0,0 +1,237 @@
+import { createWriteStream } from 'node:fs'
+import { createServer, type Socket } from 'node:net'
+import path from 'node:path'
+import process from 'node:process'
+
+import type { Result } from '../src/core/result'
+import { formatTraceLine, setTraceSink } from '../src/core/trace'
+import { createInitialSolverState } from '../src/data/generation/solver'
+import { runInteractionPipeline } from '../src/game/interactions/pipeline'
+import type { InteractionState } from '../src/game/interactions/types'
+import { diffInteractionState } from '../src/game/stateDiff'
+import {
+  encodeMessage,
+  parseClientMessage,
+  splitLines,
+  type ServerMessage,
+  type VersusRole,
+} from '../src/net/protocol'
+import { parseBroadcastTarget, type BroadcastTarget } from '../src/net/broadcast'
+import { applyGuardPlan, guardPlanCost, type GuardPlanError } from '../src/net/versus'
+import { loadPackFiles, parseArgMap } from './story-shared'
+
+const FEATURE_FLAG = 'HWU_EXPERIMENTAL_NET'
+
+interface CliArgs {
+  packId: string
+  publicDataDir: string
+  host: string
+  port: number
+  budget: number
+  broadcast: BroadcastTarget | null
+  /** JSON-lines trace of every pipeline run (`--log-file`); null leaves tracing off. */
+  logFile: string | null
+}
+
+function parseArgs(argv: string[]): Result<CliArgs, string> {
+  const args = parseArgMap(argv)
+  const broadcastArg = args.get('broadcast')
+  const broadcast = broadcastArg ? parseBroadcastTarget(broadcastArg) : null
+
+  if (broadcast && !broadcast.ok) {
+    return broadcast
+  }
+
+  const logFile = args.get('log-file')
+
+  if (logFile === 'true') {
+    return { ok: false, error: '--log-file needs a path' }
+  }
+
+  return {
+    ok: true,
+    value: {
+      packId: args.get('pack-id') ?? 'default',
+      publicDataDir: path.resolve(process.cwd(), args.get('public-data-dir') ?? 'public/data'),
+      host: args.get('host') ?? '127.0.0.1',
+      port: Number(args.get('port') ?? 7878),
+      budget: Number(args.get('budget') ?? 6),
+      broadcast: broadcast ? broadcast.value : null,
+      logFile: logFile ? path.resolve(process.cwd(), logFile) : null,
+    },
+  }
+}
+
+function formatPlanError(error: GuardPlanError): string {
+  switch (error.kind) {
+    case 'UnknownGuard':
+      return `${error.instanceId} is not a guard`
+    case 'OverBudget':
+      return `plan costs ${error.cost}, budget is ${error.budget}`
+    case 'InvalidPack':
+      return `plan produces an invalid level: ${error.error.kind}`
+  }
+}
+
+async function main(): Promise<void> {
+  if (process.env[FEATURE_FLAG] !== '1') {
+    console.error(`[versus:server] experimental; set ${FEATURE_FLAG}=1 to enable`)
+    process.exitCode = 1
+    return
+  }
+
+  const parsedArgs = parseArgs(process.argv.slice(2))
+
+  if (!parsedArgs.ok) {
+    console.error(`[versus:server] ${parsedArgs.error}`)
+    process.exitCode = 1
+    return
+  }
+
+  const cli = parsedArgs.value
+  const pack = await loadPackFiles(path.join(cli.publicDataDir, cli.packId), cli.packId)
+
+  if (!pack.ok) {
+    console.error(`[versus:server] ${pack.error}`)
+    process.exitCode = 1
+    return
+  }
+
+  if (cli.logFile) {
+    const log = createWriteStream(cli.logFile, { flags: 'a' })
+
+    setTraceSink({ record: (record) => log.write(formatTraceLine(record)) })
+    console.log(`[versus:server] tracing to ${cli.logFile}`)
+  }
+
+  const clients = new Map<VersusRole, Socket>()
+  const spectators = new Set<Socket>()
+  let run: InteractionState | null = null
+
+  const send = (socket: Socket, message: ServerMessage) => socket.write(encodeMessage(message))
+  const broadcast = (message: ServerMessage) => {
+    for (const socket of [...clients.values(), ...spectators]) {
+      send(socket, message)
+    }
+  }
+
+  const handleLine = (socket: Socket, role: VersusRole | null, line: string): VersusRole | null => {
+    const parsed = parseClientMessage(line)
+
+    if (!parsed.ok) {
+      send(socket, { type: 'error', message: parsed.error })
+      return role
+    }
+
+    const message = parsed.value
+
+    if (message.type === 'join') {
+      if (role || clients.has(message.role)) {
+        send(socket, { type: 'error', message: `${message.role} seat is taken` })
+        return role
+      }
+
+      clients.set(message.role, socket)
+      send(socket, {
+        type: 'welcome',
+        role: message.role,
+        packId: cli.packId,
+        budget: cli.budget,
+      })
+      return message.role
+    }
+
+    if (message.type === 'plan') {
+      if (role !== 'planner' || run) {
+        send(socket, { type: 'error', message: 'only the planner can plan, before the run' })
+        return role
+      }
+
+      const planned = applyGuardPlan(pack.value, message.plan, cli.budget)
+
+      if (!planned.ok) {
+        send(socket, { type: 'error', message: formatPlanError(planned.error) })
+        return role
+      }
+
+      run = createInitialSolverState(planned.value)
+
+      if (!run) {
+        send(socket, { type: 'error', message: 'planned level failed to bootstrap' })
+        return role
+      }
+
+      run.status = 'Versus run started'
+      send(socket, { type: 'planAccepted', cost: guardPlanCost(pack.value, message.plan) })
+      broadcast({ type: 'start' })
+      return role
+    }
+
+    if (role !== 'sneaker' || !run) {
+      send(socket, { type: 'error', message: 'only the sneaker can act, after the plan' })
+      return role
+    }
+
+    const previous = structuredClone(run)
+
+    runInteractionPipeline(run, message.action)
+    broadcast({ type: 'diff', diff: diffInteractionState(previous, run) })
+
+    if (run.phase !== 'Playing') {
+      broadcast({ type: 'end', phase: run.phase, turn: run.turn })
+    }
+
+    return role
+  }
+
+  const server = createServer((socket) => {
+    let role: VersusRole | null = null
+    let buffer = ''
+
+    socket.setEncoding('utf8')
+    socket.on('data', (chunk: string) => {
+      const split = splitLines(buffer, chunk)
+
+      buffer = split.rest
+      for (const line of split.lines) {
+        // One bad line costs its sender an error reply, never the whole server.
+        try {
+          role = handleLine(socket, role, line)
+        } catch (error) {
+          console.error(`[versus:server] ${String(error)}`)
+          send(socket, { type: 'error', message: 'message could not be handled' })
+        }
+      }
+    })
+    socket.on('close', () => {
+      if (role && clients.get(role) === socket) {
+        clients.delete(role)
+      }
+    })
+    // A reset connection is followed by 'close', which frees the seat.
+    socket.on('error', (error) => {
+      console.error(`[versus:server] ${role ?? 'client'}: ${error.message}`)
+    })
+  })
+
+  server.listen(cli.port, cli.host, () => {
+    console.log(
+      `[versus:server] ${cli.packId} on ${cli.host}:${cli.port} (planner budget ${cli.budget})`,
+    )
+  })
+
+  if (cli.broadcast) {
+    const { host, port } = cli.broadcast
+
+    // Spectators only listen: they get every line the players get and their input is ignored.
+    createServer((socket) => {
+      spectators.add(socket)
+      socket.on('close', () => spectators.delete(socket))
+      socket.on('error', () => spectators.delete(socket))
+    }).listen(port, host, () => {
+      console.log(`[versus:server] broadcasting to spectators on ${host}:${port}`)
+    })
+  }
+}
+
+void main()
@@ -1,15 +1,20 @@
 import { useEffect } from 'react'
 
 import { GameShell } from './app/GameShell'
+import type { LoadedRuntimeConfig } from './app/runtimeConfig'
 import { applyThemeCssVars, minimalMonoTheme } from './render/theme'
 import './App.css'
 
-function App() {
+interface AppProps {
+  runtimeConfig: LoadedRuntimeConfig
+}
+
+function App({ runtimeConfig }: AppProps) {
   useEffect(() => {
     applyThemeCssVars(minimalMonoTheme)
   }, [])
 
-  return <GameShell />
+  return <GameShell runtimeConfig={runtimeConfig} />
 }
 
 export default App
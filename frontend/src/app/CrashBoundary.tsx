@@ -0,0 +1,100 @@
+import { Component, type ErrorInfo, type ReactNode } from 'react'
+
+import type { AppStore } from '../game/store'
+import { restart } from '../game/gameSlice'
+import { createTranslator, DEFAULT_LOCALE } from '../render/i18n'
+import {
+  buildCrashReport,
+  crashReportFileName,
+  storeCrashReport,
+  type CrashReport,
+} from './crashReport'
+
+interface CrashBoundaryProps {
+  store: AppStore
+  children: ReactNode
+}
+
+interface CrashBoundaryState {
+  crashed: boolean
+  /** Filled in once the crash has been recorded, right after the fallback first renders. */
+  report: CrashReport | null
+}
+
+// Rendered outside the shell's translator context, so it sticks to the default locale.
+const t = createTranslator(DEFAULT_LOCALE)
+
+function downloadReport(report: CrashReport): void {
+  const url = URL.createObjectURL(
+    new Blob([JSON.stringify(report, null, 2)], { type: 'application/json' }),
+  )
+  const link = document.createElement('a')
+
+  link.href = url
+  link.download = crashReportFileName(report)
+  link.click()
+  URL.revokeObjectURL(url)
+}
+
+/**
+ * A render crash would otherwise unmount the whole page and leave a blank screen. This keeps a
+ * usable fallback instead, saves a crash report with the run so far and lets the player restart.
+ */
+export class CrashBoundary extends Component<CrashBoundaryProps, CrashBoundaryState> {
+  state: CrashBoundaryState = { crashed: false, report: null }
+
+  static getDerivedStateFromError(): Partial<CrashBoundaryState> {
+    return { crashed: true }
+  }
+
+  componentDidCatch(error: unknown, info: ErrorInfo): void {
+    const report = buildCrashReport(error, this.props.store.getState().game, new Date())
+
+    console.error('[crash]', error, info.componentStack)
+    storeCrashReport(window.localStorage, report)
+    this.setState({ report })
+  }
+
+  render(): ReactNode {
+    const { crashed, report } = this.state
+
+    if (!crashed) {
+      return this.props.children
+    }
+
+    return (
+      <div className="overlay-backdrop" role="alertdialog" aria-modal="true" aria-label="Crash">
+        <section className="overlay-window confirm-window">
+          <header className="overlay-header">
+            <h2>{t('crash.title')}</h2>
+            {report ? (
+              <p>{t('crash.summary', { turn: report.turn, packId: report.packId })}</p>
+            ) : null}
+          </header>
+          <div className="overlay-body">
+            {report ? <p className="confirm-message">{report.message}</p> : null}
+            <div className="crash-actions">
+              {report ? (
+                <button type="button" onClick={() => downloadReport(report)}>
+                  {t('crash.download')}
+                </button>
+              ) : null}
+              <button
+                type="button"
+                onClick={() => {
+                  this.props.store.dispatch(restart())
+                  this.setState({ crashed: false, report: null })
+                }}
+              >
+                {t('crash.restart')}
+              </button>
+              <button type="button" onClick={() => window.location.reload()}>
+                {t('crash.reload')}
+              </button>
+            </div>
+          </div>
+        </section>
+      </div>
+    )
+  }
+}
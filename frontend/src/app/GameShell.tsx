@@ -1,62 +1,229 @@
 import { lazy, Suspense, useCallback, useEffect, useMemo, useRef, useState } from 'react'
 
 import { evaluateDetectionV1 } from '../core/detection'
-import type { Direction2D } from '../core/position'
-import { objectsAtTime } from '../core/timeCube'
-import { currentPosition, positionsAtTime } from '../core/worldLine'
-import { useAppDispatch, useAppSelector } from '../game/hooks'
-import { movePlayer2D, pullPlayer2D, pushPlayer2D, setContentPackId } from '../game/gameSlice'
+import type { CommunityLevelEntry } from '../data/loader'
+import { dailyMutators, sameMutators, type MutatorId } from '../data/mutators'
+import { ruleProfileById, STANDARD_RULE_PROFILE } from '../data/ruleProfiles'
+import { lockdownAt } from '../core/alarm'
+import { extractionTurnsAt, hotseatSeat } from '../core/characters'
+import { currentLayer, type Layer } from '../core/elevation'
+import { fogCellKey, fogViewAt, isSceneryObject } from '../core/fog'
+import { darkCellsAt } from '../core/lighting'
+import type { Position2D } from '../core/position'
+import { regionForDisplay } from '../core/regions'
+import { decorAtTime, objectsAtTime } from '../core/timeCube'
+import { timelineById } from '../core/timelineSet'
+import { createMemoryTraceSink, setTraceSink } from '../core/trace'
+import {
+  currentPosition,
+  headTurn,
+  positionsAtTime,
+  type WorldLineState,
+} from '../core/worldLine'
+import { useAppDispatch, useAppSelector, useAppStore } from '../game/hooks'
+import {
+  commitTurnPlan,
+  peek,
+  performInteraction,
+  resumeSession,
+  setContentPackId,
+  setLocale,
+  setStatus,
+} from '../game/gameSlice'
+import { simulateInteractionPipeline } from '../game/interactions/pipeline'
+import { inspectCell } from '../game/inspection'
+import type { InteractionAction } from '../game/interactions/types'
+import { changedCells, diffCubeObjects } from '../game/stateDiff'
+import { diffTurnPlan, simulateTurnPlan } from '../game/turnPlan'
+import { enemyFacingsAt } from '../render/board/facing'
+import { buildLegend } from '../render/board/legend'
 import { buildActionPreview } from '../render/board/preview'
 import { GameBoardCanvas } from '../render/board/GameBoardCanvas'
+import { NO_CAMERA_SHIFT, torusCameraShift } from '../render/board/camera'
+import { createTranslator } from '../render/i18n'
 import { buildIsoViewModel } from '../render/iso/buildIsoViewModel'
-import { applyCssVars } from '../render/theme'
+import { applyCssVars, minimalMonoTheme } from '../render/theme'
+import {
+  createToneAudioBackend,
+  cueForOutcome,
+  cueForPhase,
+  silentAudioBackend,
+} from './audio'
 import {
   closeTopLayer,
   createInputStateMachine,
-  type DirectionalActionMode,
+  openConfirmPrompt,
+  type DirectionalIntent,
   type InputStateMachine,
 } from './inputStateMachine'
+import { saveRuntimeConfig, type LoadedRuntimeConfig } from './runtimeConfig'
+import { downloadSnapshot, formatSnapshot, snapshotFileName } from './snapshot'
 import { BottomHintsBar } from './shell/BottomHintsBar'
+import { pendingBreadcrumb } from './shell/breadcrumbs'
+import { ConfirmOverlay } from './shell/ConfirmOverlay'
+import {
+  DEBUG_CONSOLE_MAX_LINES,
+  executeDebugCommand,
+  parseDebugCommand,
+  resolveDebugConsoleEnabled,
+} from './shell/debugConsole'
+import { DebugConsoleOverlay } from './shell/DebugConsoleOverlay'
+import { exitCompass } from './shell/exitCompass'
+import { HelpOverlay } from './shell/HelpOverlay'
 import { DEFAULT_PACK_SEQUENCE, directionalOptions } from './shell/constants'
 import { HudPanels } from './shell/HudPanels'
+import {
+  applyKeymapOverrides,
+  defaultKeymap,
+  detectLevelMechanics,
+  keysForCommand,
+} from './shell/keymap'
+import {
+  DEFAULT_LEVEL_FILTER,
+  type LevelFilter,
+  type LevelSelectTab,
+} from './shell/levelFilter'
 import { LogOverlay } from './shell/LogOverlay'
+import { entitiesPerSlice, perfSample, type PerfSample } from './shell/perfHud'
+import { PlanningOverlay } from './shell/PlanningOverlay'
+import { describeReplayComparison } from './shell/replayDiff'
 import { ProgressionOverlay } from './shell/ProgressionOverlay'
 import { SettingsOverlay } from './shell/SettingsOverlay'
+import {
+  IDLE_SPEEDRUN_CLOCK,
+  SPEEDRUN_TICK_MS,
+  speedrunElapsedMs,
+  syncSpeedrunClock,
+} from './shell/speedrun'
 import { StateOverlay } from './shell/StateOverlay'
+import { TutorialPanel } from './shell/TutorialPanel'
 import {
   type PackDisplayMeta,
+  useCommunityLevels,
   useContentPackManifest,
   useEnsureSelectedContentPack,
   useLoadSelectedContentPack,
 } from './shell/useContentPackLoading'
 import { useKeyboardControls } from './shell/useKeyboardControls'
-import { useProgressionState } from './shell/useProgressionState'
+import {
+  IRONMAN_PROGRESSION_STORAGE_KEY,
+  isSelectedTrackComplete,
+  useIronmanStats,
+} from './shell/useIronmanCampaign'
+import {
+  applyCompletionToProgressionSnapshot,
+  useProgressionState,
+} from './shell/useProgressionState'
+import { bestReplayKey, ghostPositionAtTurn, useReplayStore } from './shell/useReplayStore'
+import { useSessionAutosave, type SavedSession } from './shell/useSessionAutosave'
+import { TranslatorContext } from './shell/useTranslator'
 import { useUiSettings } from './shell/useUiSettings'
+import { WorldLineOverlay } from './shell/WorldLineOverlay'
+import { worldLineRows } from './shell/worldLineTable'
 
 const LazyIsoTimeCubePanel = lazy(async () => {
   const module = await import('../render/iso/IsoTimeCubePanel')
   return { default: module.IsoTimeCubePanel }
 })
 
+const NO_CELLS: Position2D[] = []
 const BUILD_CHANNEL = String(import.meta.env.MODE ?? 'development').toUpperCase()
+const DEBUG_CONSOLE_ENABLED =
+  typeof window !== 'undefined' && resolveDebugConsoleEnabled(window.location.search)
+
+/** Null for peeks, which only look and so never take a turn. */
+function actionForIntent(intent: DirectionalIntent, lureRange: number): InteractionAction | null {
+  switch (intent.mode) {
+    case 'Move':
+    case 'Push':
+    case 'Pull':
+    case 'TimePush':
+    case 'Break':
+    case 'Interact':
+      return { kind: intent.mode, direction: intent.direction }
+    case 'Throw':
+      return { kind: 'Throw', direction: intent.direction, range: lureRange }
+    case 'Peek':
+      return null
+  }
+}
+
+/** Slice picked in the world line table, kept while the world line it was picked on is current. */
+interface ViewedSlice {
+  worldLine: WorldLineState
+  time: number
+}
+
+interface PendingAssistAction {
+  action: InteractionAction
+  message: string
+}
+
+interface GameShellProps {
+  /** `config.toml` plus page flags, read once before the first render. */
+  runtimeConfig: LoadedRuntimeConfig
+}
 
-export function GameShell() {
+export function GameShell({ runtimeConfig }: GameShellProps) {
   const dispatch = useAppDispatch()
+  const store = useAppStore()
   const [inputMachine, setInputMachine] = useState(createInputStateMachine)
   const [availablePackIds, setAvailablePackIds] = useState<string[]>(DEFAULT_PACK_SEQUENCE)
   const [packMetaById, setPackMetaById] = useState<Record<string, PackDisplayMeta>>({})
+  const [pendingAction, setPendingAction] = useState<PendingAssistAction | null>(null)
+  const [levelFilter, setLevelFilter] = useState<LevelFilter>(DEFAULT_LEVEL_FILTER)
+  const [levelSelectTab, setLevelSelectTab] = useState<LevelSelectTab>('tracks')
+  const [communityLevels, setCommunityLevels] = useState<CommunityLevelEntry[]>([])
+  const [communityIndex, setCommunityIndex] = useState(0)
+  const [mutators, setMutators] = useState<MutatorId[]>([])
+  const [challengeIndex, setChallengeIndex] = useState(0)
+  const dailyChallenge = useMemo(() => dailyMutators(new Date()), [])
+  const [plannedActions, setPlannedActions] = useState<InteractionAction[]>([])
+  const [speedrunClock, setSpeedrunClock] = useState(IDLE_SPEEDRUN_CLOCK)
+  const [speedrunNow, setSpeedrunNow] = useState(0)
+  const [layerView, setLayerView] = useState<Layer>('ground')
+  const [showTimelineDiff, setShowTimelineDiff] = useState(false)
+  const [viewedTimelineId, setViewedTimelineId] = useState<string | null>(null)
+  const [examineCursor, setExamineCursor] = useState<Position2D | null>(null)
+  const [worldLineIndex, setWorldLineIndex] = useState(0)
+  const [viewedSlice, setViewedSlice] = useState<ViewedSlice | null>(null)
+  const [debugLines, setDebugLines] = useState<string[]>([])
+  const [perfEnabled, setPerfEnabled] = useState(false)
+  const [lastPerfSample, setLastPerfSample] = useState<PerfSample | null>(null)
 
   const {
     uiSettings,
     setUiSettings,
     showDangerPreview,
     setShowDangerPreview,
-  } = useUiSettings()
+  } = useUiSettings(runtimeConfig.config.settings)
+  const t = useMemo(() => createTranslator(uiSettings.language), [uiSettings.language])
+
+  const audio = useMemo(
+    () => (uiSettings.soundCues ? createToneAudioBackend() : silentAudioBackend),
+    [uiSettings.soundCues],
+  )
 
+  const recordedWinRef = useRef(false)
+  const recordedDetectionRef = useRef(false)
+  const cuedHistoryLengthRef = useRef(0)
   const logOverlayRef = useRef<HTMLElement | null>(null)
+  const worldLineOverlayRef = useRef<HTMLElement | null>(null)
+  const perfSinkRef = useRef<ReturnType<typeof createMemoryTraceSink> | null>(null)
   const settingsOverlayRef = useRef<HTMLElement | null>(null)
   const stateOverlayRef = useRef<HTMLElement | null>(null)
   const progressionOverlayRef = useRef<HTMLElement | null>(null)
+  const confirmOverlayRef = useRef<HTMLElement | null>(null)
+  const helpOverlayRef = useRef<HTMLElement | null>(null)
+  const planningOverlayRef = useRef<HTMLElement | null>(null)
+  const debugConsoleInputRef = useRef<HTMLInputElement | null>(null)
+  // Session to replay once its pack has finished loading under its own rules and difficulty.
+  const pendingResumeRef = useRef<SavedSession | null>(null)
+  // Shown once the first level has loaded, so the load status does not hide it.
+  const pendingConfigErrorRef = useRef(runtimeConfig.error)
+  const savedUiSettingsRef = useRef(uiSettings)
+
+  const gameState = useAppSelector((state) => state.game)
 
   const boardWidth = useAppSelector((state) => state.game.boardWidth)
   const boardHeight = useAppSelector((state) => state.game.boardHeight)
@@ -69,11 +236,37 @@ export function GameShell() {
   const contentPackId = useAppSelector((state) => state.game.contentPackId)
   const riftDefaultDelta = useAppSelector((state) => state.game.riftSettings.defaultDelta)
   const interactionConfig = useAppSelector((state) => state.game.interactionConfig)
+  const lures = useAppSelector((state) => state.game.lures)
+  const extractionTurns = useAppSelector((state) => state.game.extractionTurns)
+  const alarmConfig = useAppSelector((state) => state.game.alarmConfig)
+  const alarm = useAppSelector((state) => state.game.alarm)
+  const activeCharacterId = useAppSelector((state) => state.game.activeCharacterId)
+  const parkedCharacters = useAppSelector((state) => state.game.parkedCharacters)
+  const characterStarts = useAppSelector((state) => state.game.characterStarts)
+  const hotseat = useAppSelector((state) => state.game.hotseat)
+  const exploredByTime = useAppSelector((state) => state.game.exploredByTime)
+  const timelineBaseline = useAppSelector((state) => state.game.timelineBaseline)
+  const timelines = useAppSelector((state) => state.game.timelines)
   const detectionConfig = useAppSelector((state) => state.game.detectionConfig)
+  const enemyDetectionConfigById = useAppSelector(
+    (state) => state.game.enemyDetectionConfigById,
+  )
   const themeCssVars = useAppSelector((state) => state.game.themeCssVars)
   const iconPackId = useAppSelector((state) => state.game.iconPackId)
   const history = useAppSelector((state) => state.game.history)
   const status = useAppSelector((state) => state.game.status)
+  const hintBudget = useAppSelector((state) => state.game.hintBudget)
+  const hintsUsed = useAppSelector((state) => state.game.hintsUsed)
+  const peekBudget = useAppSelector((state) => state.game.peekBudget)
+  const knockoutConfig = useAppSelector((state) => state.game.knockoutConfig)
+  const peeksUsed = useAppSelector((state) => state.game.peeksUsed)
+  const lastHint = useAppSelector((state) => state.game.lastHint)
+  const levelMeta = useAppSelector((state) => state.game.levelMeta)
+  const rngSeed = useAppSelector((state) => state.game.rng.seed)
+  const playDifficulty = useAppSelector((state) => state.game.playDifficulty)
+  const ruleProfile = useAppSelector((state) => state.game.ruleProfile)
+  const activeMutators = useAppSelector((state) => state.game.mutators)
+  const restartsRemaining = useAppSelector((state) => state.game.restartsRemaining)
 
   const directionalActionMode = inputMachine.mode
   const isActionMenuOpen = inputMachine.layer === 'ActionMenu'
@@ -81,22 +274,112 @@ export function GameShell() {
   const isLogOpen = inputMachine.layer === 'LogOverlay'
   const isSystemMenuOpen = inputMachine.layer === 'SystemMenu'
   const isProgressionOverlayOpen = inputMachine.layer === 'ProgressionOverlay'
+  const isHelpOverlayOpen = inputMachine.layer === 'HelpOverlay'
+  const isConfirmPromptOpen = inputMachine.layer === 'ConfirmPrompt' && pendingAction !== null
+  const isPlanningOpen = inputMachine.layer === 'PlanningOverlay'
+  const isDebugConsoleOpen = inputMachine.layer === 'DebugConsole'
+  const isWorldLineOpen = inputMachine.layer === 'WorldLineOverlay'
 
   const player = currentPosition(worldLine)
-  const selvesAtCurrentTime = positionsAtTime(worldLine, currentTime)
-  const objectsAtCurrentTime = objectsAtTime(cube, currentTime)
+  // A jump from the world line table holds until the line moves on; every action replaces it.
+  const viewedTime = viewedSlice?.worldLine === worldLine ? viewedSlice.time : null
+  const boardTime = viewedTime ?? currentTime
+  const selvesAtCurrentTime = positionsAtTime(worldLine, boardTime)
+  const worldLineTurn = headTurn(worldLine)
+  const riftWarp = useMemo(() => {
+    const latest = history[history.length - 1]
+    const from = worldLine.path[worldLine.path.length - 2]
+    const to = worldLine.path[worldLine.path.length - 1]
+
+    // In hotseat the world line may already belong to the next character; then skip the streak.
+    if (latest?.outcome.kind !== 'Rifted' || !from || !to || to.t !== latest.outcome.to.t) {
+      return null
+    }
+
+    return { from, to }
+  }, [history, worldLine])
+  const fogView = useMemo(
+    () =>
+      uiSettings.fogOfWar && player
+        ? fogViewAt(cube, exploredByTime, player, boardTime)
+        : null,
+    [uiSettings.fogOfWar, player, cube, exploredByTime, boardTime],
+  )
+  const cameraShift = useMemo(
+    () =>
+      player && (cube.xWraps || cube.yWraps)
+        ? torusCameraShift(player, boardWidth, boardHeight, { x: cube.xWraps, y: cube.yWraps })
+        : NO_CAMERA_SHIFT,
+    [player, cube.xWraps, cube.yWraps, boardWidth, boardHeight],
+  )
+  // Branching mode can show an older branch; play itself always continues in the active one.
+  const viewedTimeline =
+    timelines && viewedTimelineId ? timelineById(timelines, viewedTimelineId) : null
+  const boardCube = viewedTimeline?.cube ?? cube
+  const objectsAtCurrentTime = objectsAtTime(boardCube, boardTime)
+  const decorAtCurrentTime = decorAtTime(boardCube, boardTime)
+  const objectsInView = fogView
+    ? objectsAtCurrentTime.filter((object) => {
+        const key = fogCellKey(object.position)
+
+        return fogView.visible.has(key) || (fogView.explored.has(key) && isSceneryObject(object))
+      })
+    : objectsAtCurrentTime
+  const idsInView = new Set(objectsInView.map((object) => object.id))
+  // Under fog a nearby exit outside the lit cells still gets the compass.
+  const exitHeading =
+    uiSettings.exitCompass && player
+      ? exitCompass(
+          cube,
+          player,
+          (position) => !fogView || fogView.visible.has(fogCellKey(position)),
+        )
+      : null
+  const extractionNeeded = extractionTurnsAt(cube, worldLine)
+  const lockdown = lockdownAt(cube, currentTime)
+  const enemyFacings = enemyFacingsAt(boardCube, boardTime).filter((entry) =>
+    idsInView.has(entry.id),
+  )
+  const darkCells = useMemo(
+    () =>
+      detectionConfig.darkVisionFactor === undefined ? [] : darkCellsAt(cube, boardTime),
+    [cube, boardTime, detectionConfig.darkVisionFactor],
+  )
+  const timelineChanges = useMemo(
+    () => (timelineBaseline ? changedCells(diffCubeObjects(timelineBaseline, cube)) : null),
+    [timelineBaseline, cube],
+  )
+  const timelineChangedCells = useMemo(
+    () =>
+      showTimelineDiff && timelineChanges
+        ? timelineChanges.filter((position) => position.t === boardTime)
+        : NO_CELLS,
+    [showTimelineDiff, timelineChanges, boardTime],
+  )
+  const examinedEntities = useMemo(
+    () => (examineCursor ? inspectCell(boardCube, { ...examineCursor, t: boardTime }) : null),
+    [boardCube, boardTime, examineCursor],
+  )
+  const companionsAtCurrentTime = parkedCharacters.flatMap((character) =>
+    positionsAtTime(character.worldLine, boardTime).map((entry) => entry.position),
+  )
+  const luresAtCurrentTime = lures.thrown
+    .filter((lure) => lure.position.t <= boardTime)
+    .map((lure) => ({ ...lure.position, t: boardTime }))
   const isoViewModel = useMemo(
     () =>
       buildIsoViewModel({
-        currentT: currentTime,
+        currentT: boardTime,
         timeDepth,
         worldLine,
         cube,
         maxWindow: 10,
       }),
-    [currentTime, timeDepth, worldLine, cube],
+    [boardTime, timeDepth, worldLine, cube],
   )
 
+  // Per-guard configs matter here: a precog sentinel's danger is where the world line already
+  // runs ahead of the present slice.
   const detectionPreviewReport = useMemo(
     () =>
       evaluateDetectionV1({
@@ -104,10 +387,16 @@ export function GameShell() {
         worldLine,
         currentTime,
         config: detectionConfig,
+        configByEnemyId: enemyDetectionConfigById,
       }),
-    [cube, worldLine, currentTime, detectionConfig],
+    [cube, worldLine, currentTime, detectionConfig, enemyDetectionConfigById],
   )
 
+  // Rule profiles without preview hide the overlay without touching the saved toggle. Previews
+  // are for the present slice, so a viewed earlier one hides them too.
+  const dangerPreviewVisible = showDangerPreview && ruleProfile.preview
+  const isViewingPresent = viewedTime === null
+
   const actionPreview = useMemo(
     () =>
       buildActionPreview({
@@ -119,6 +408,8 @@ export function GameShell() {
         intent: null,
         maxPushChain: interactionConfig.maxPushChain,
         allowPull: interactionConfig.allowPull,
+        allowTimePush: interactionConfig.allowTimePush,
+        lureRange: interactionConfig.lure.maxRange,
       }),
     [
       cube,
@@ -128,24 +419,125 @@ export function GameShell() {
       timeDepth,
       interactionConfig.maxPushChain,
       interactionConfig.allowPull,
+      interactionConfig.allowTimePush,
+      interactionConfig.lure.maxRange,
     ],
   )
 
+  const issueAction = useCallback(
+    (action: InteractionAction) => {
+      if (
+        uiSettings.assistDetectionWarnings &&
+        gameState.ruleProfile.preview &&
+        gameState.phase === 'Playing'
+      ) {
+        const simulated = simulateInteractionPipeline(gameState, action)
+
+        if (simulated.phase === 'Detected' && simulated.lastDetection) {
+          const primary = simulated.lastDetection.events[0]
+
+          setPendingAction({
+            action,
+            message: primary
+              ? t('confirm.detectionBy', {
+                  time: simulated.lastDetection.atTime,
+                  enemyId: primary.enemyId,
+                })
+              : t('confirm.detection', { time: simulated.lastDetection.atTime }),
+          })
+          setInputMachine((machine) => openConfirmPrompt(machine))
+          return
+        }
+      }
+
+      dispatch(performInteraction(action))
+    },
+    [dispatch, gameState, t, uiSettings.assistDetectionWarnings],
+  )
+
+  const resolvePendingAction = useCallback(
+    (confirmed: boolean) => {
+      setInputMachine((machine) => closeTopLayer(machine))
+
+      if (confirmed && pendingAction) {
+        dispatch(performInteraction(pendingAction.action))
+      }
+
+      setPendingAction(null)
+    },
+    [dispatch, pendingAction],
+  )
+
   const dispatchDirectionalIntent = useCallback(
-    (intent: { mode: DirectionalActionMode; direction: Direction2D }) => {
-      switch (intent.mode) {
-        case 'Move':
-          dispatch(movePlayer2D(intent.direction))
-          break
-        case 'Push':
-          dispatch(pushPlayer2D(intent.direction))
-          break
-        case 'Pull':
-          dispatch(pullPlayer2D(intent.direction))
-          break
+    (intent: DirectionalIntent) => {
+      const action = actionForIntent(intent, interactionConfig.lure.maxRange)
+
+      if (action) {
+        issueAction(action)
+      } else {
+        dispatch(peek(intent.direction))
+      }
+    },
+    [dispatch, interactionConfig.lure.maxRange, issueAction],
+  )
+
+  const planSimulation = useMemo(
+    () => (isPlanningOpen ? simulateTurnPlan(gameState, plannedActions) : null),
+    [gameState, isPlanningOpen, plannedActions],
+  )
+  const planPreview = planSimulation?.steps ?? []
+  const planDiff = useMemo(
+    () => (planSimulation ? diffTurnPlan(gameState, planSimulation.final) : null),
+    [gameState, planSimulation],
+  )
+
+  const queuePlanAction = useCallback((action: InteractionAction) => {
+    setPlannedActions((actions) => [...actions, action])
+  }, [])
+
+  const queuePlanIntent = useCallback(
+    (intent: DirectionalIntent) => {
+      const action = actionForIntent(intent, interactionConfig.lure.maxRange)
+
+      // A plan is a run of turns; a peek is not one, so it is left out.
+      if (action) {
+        queuePlanAction(action)
       }
     },
-    [dispatch],
+    [interactionConfig.lure.maxRange, queuePlanAction],
+  )
+
+  const undoPlanStep = useCallback(() => {
+    setPlannedActions((actions) => actions.slice(0, -1))
+  }, [])
+
+  const discardPlan = useCallback(() => {
+    setPlannedActions([])
+  }, [])
+
+  const commitPlan = useCallback(() => {
+    if (plannedActions.length > 0) {
+      dispatch(commitTurnPlan(plannedActions))
+    }
+
+    setPlannedActions([])
+  }, [dispatch, plannedActions])
+
+  const runDebugLine = useCallback(
+    (line: string) => {
+      const parsed = parseDebugCommand(line)
+
+      if (parsed.ok && parsed.value.kind === 'Perf') {
+        setPerfEnabled(parsed.value.enabled)
+      }
+
+      const output = parsed.ok
+        ? executeDebugCommand(parsed.value, () => store.getState().game, dispatch)
+        : [parsed.error]
+
+      setDebugLines((lines) => [...lines, `> ${line}`, ...output].slice(-DEBUG_CONSOLE_MAX_LINES))
+    },
+    [dispatch, store],
   )
 
   const applyMachineTransition = useCallback(
@@ -155,9 +547,132 @@ export function GameShell() {
     [],
   )
 
+  const worldLineTableRows = useMemo(() => worldLineRows(worldLine), [worldLine])
+  const viewWorldLineRow = useCallback(
+    (index: number) => {
+      const row = worldLineTableRows[index]
+
+      if (!row) {
+        return
+      }
+
+      if (row.t === currentTime) {
+        setViewedSlice(null)
+        dispatch(setStatus(t('status.viewingPresent')))
+        return
+      }
+
+      setViewedSlice({ worldLine, time: row.t })
+      dispatch(setStatus(t('status.viewingSlice', { t: row.t, turn, now: currentTime })))
+    },
+    [currentTime, dispatch, t, turn, worldLine, worldLineTableRows],
+  )
+
+  const { savedSession } = useSessionAutosave(store)
+  const resumeLastSession = useCallback(() => {
+    if (!savedSession) {
+      dispatch(setStatus(t('status.noSavedSession')))
+      return
+    }
+
+    if (
+      savedSession.packId === contentPackId &&
+      savedSession.ruleProfileId === uiSettings.ruleProfileId &&
+      savedSession.playDifficulty === uiSettings.playDifficulty &&
+      sameMutators(savedSession.mutators ?? [], mutators)
+    ) {
+      dispatch(resumeSession(savedSession.actions))
+      return
+    }
+
+    pendingResumeRef.current = savedSession
+    setUiSettings((current) => ({
+      ...current,
+      ruleProfileId: savedSession.ruleProfileId,
+      playDifficulty: savedSession.playDifficulty,
+    }))
+    setMutators(savedSession.mutators ?? [])
+    dispatch(setContentPackId(savedSession.packId))
+    dispatch(
+      setStatus(
+        t('status.resumingSession', { packId: savedSession.packId, turn: savedSession.turn }),
+      ),
+    )
+  }, [
+    contentPackId,
+    dispatch,
+    mutators,
+    savedSession,
+    setUiSettings,
+    t,
+    uiSettings.playDifficulty,
+    uiSettings.ruleProfileId,
+  ])
+  const exportSnapshot = useCallback(
+    (allSlices: boolean) => {
+      // Same cube the board draws, so a viewed older branch is what gets saved.
+      const input = {
+        packId: contentPackId,
+        turn,
+        timeDepth,
+        currentTime: boardTime,
+        boardWidth,
+        boardHeight,
+        cube: boardCube,
+        worldLine,
+      }
+      const fileName = snapshotFileName(input, allSlices, new Date())
+
+      downloadSnapshot(fileName, formatSnapshot(input, allSlices))
+      dispatch(setStatus(t('status.snapshotSaved', { fileName })))
+    },
+    [
+      boardCube,
+      boardHeight,
+      boardTime,
+      boardWidth,
+      contentPackId,
+      dispatch,
+      t,
+      timeDepth,
+      turn,
+      worldLine,
+    ],
+  )
+  const onContentLoaded = useCallback(
+    (packId: string) => {
+      const configError = pendingConfigErrorRef.current
+
+      if (configError) {
+        pendingConfigErrorRef.current = null
+        dispatch(setStatus(t('status.configError', { error: configError })))
+      }
+
+      const pending = pendingResumeRef.current
+
+      if (pending && pending.packId === packId) {
+        pendingResumeRef.current = null
+        dispatch(resumeSession(pending.actions))
+      }
+    },
+    [dispatch, t],
+  )
+
   useContentPackManifest(setAvailablePackIds, setPackMetaById)
+  useCommunityLevels(setCommunityLevels)
   useEnsureSelectedContentPack(dispatch, availablePackIds, contentPackId)
-  useLoadSelectedContentPack(dispatch, contentPackId)
+  useLoadSelectedContentPack(
+    dispatch,
+    contentPackId,
+    t,
+    uiSettings.playDifficulty,
+    ruleProfileById(uiSettings.ruleProfileId),
+    runtimeConfig.config.detection,
+    mutators,
+    onContentLoaded,
+  )
+  const standardProgression = useProgressionState()
+  const ironmanProgression = useProgressionState(IRONMAN_PROGRESSION_STORAGE_KEY)
   const {
     progressionManifest,
     progressionState,
@@ -165,7 +680,74 @@ export function GameShell() {
     setSelectedTrack,
     setCurrentEntryIndex,
     applyWinForPack,
-  } = useProgressionState()
+    resetProgression,
+  } = uiSettings.ironmanCampaign ? ironmanProgression : standardProgression
+  const { ironmanStats, recordIronmanClear, recordIronmanDetection } = useIronmanStats()
+  // Iron-man only counts levels its own campaign has unlocked, so a reset cannot be skipped.
+  const isIronmanCampaignLevel =
+    uiSettings.ironmanCampaign && !!progressionState?.unlockedPackIds.includes(contentPackId)
+  const { replaySnapshot, lastWinComparison, recordWin } = useReplayStore()
+  const bestReplay =
+    replaySnapshot.bestByPackId[bestReplayKey(contentPackId, playDifficulty)] ?? null
+  const ghostPosition = useMemo(() => {
+    if (!uiSettings.showGhostRun || !bestReplay) {
+      return null
+    }
+
+    // The recorded run is a world line path, so it is paced by path index, not by actions taken.
+    const position = ghostPositionAtTurn(bestReplay, worldLineTurn)
+
+    return position && position.t === boardTime ? position : null
+  }, [bestReplay, boardTime, worldLineTurn, uiSettings.showGhostRun])
+  const legend = useMemo(
+    () =>
+      buildLegend({
+        cube: boardCube,
+        theme: minimalMonoTheme.canvas,
+        hasGhost: uiSettings.showGhostRun && bestReplay !== null,
+        showDangerPreview: dangerPreviewVisible,
+        dark: detectionConfig.darkVisionFactor !== undefined,
+      }),
+    [
+      boardCube,
+      bestReplay,
+      uiSettings.showGhostRun,
+      dangerPreviewVisible,
+      detectionConfig.darkVisionFactor,
+    ],
+  )
+  const keymap = useMemo(
+    () => applyKeymapOverrides(defaultKeymap, runtimeConfig.config.keymap),
+    [runtimeConfig.config.keymap],
+  )
+  const levelMechanics = useMemo(
+    () =>
+      detectLevelMechanics(
+        cube,
+        interactionConfig.allowPull,
+        interactionConfig.allowTimePush,
+        interactionConfig.lure.supply,
+        parkedCharacters.length + 1,
+        timelines !== null,
+        peekBudget,
+        knockoutConfig !== null,
+      ),
+    [
+      cube,
+      interactionConfig.allowPull,
+      interactionConfig.allowTimePush,
+      interactionConfig.lure.supply,
+      parkedCharacters.length,
+      timelines,
+      peekBudget,
+      knockoutConfig,
+    ],
+  )
+  const levelDirectionalOptions = useMemo(
+    () =>
+      directionalOptions.filter((option) => !option.mechanic || levelMechanics[option.mechanic]),
+    [levelMechanics],
+  )
   const currentProgressionEntry = useMemo(() => {
     if (!progressionManifest) {
       return null
@@ -184,6 +766,9 @@ export function GameShell() {
 
   useKeyboardControls({
     dispatch,
+    t,
+    keymap,
+    repeatDelayMs: uiSettings.keyRepeatDelayMs,
     inputMachine,
     isActionMenuOpen,
     isProgressionOverlayOpen,
@@ -191,18 +776,142 @@ export function GameShell() {
     contentPackId,
     riftDefaultDelta,
     interactionMaxPushChain: interactionConfig.maxPushChain,
+    hotseat,
+    ruleProfileName: ruleProfile.name,
+    allowPlanning: ruleProfile.planning,
+    allowPreview: ruleProfile.preview,
     progressionManifest,
     progressionState,
     setSelectedTrack,
     setCurrentEntryIndex,
+    packMetaById,
+    levelFilter,
+    setLevelFilter,
+    levelSelectTab,
+    setLevelSelectTab,
+    communityLevels,
+    communityIndex,
+    setCommunityIndex,
+    mutators,
+    setMutators,
+    dailyChallenge,
+    challengeIndex,
+    setChallengeIndex,
     applyMachineTransition,
     dispatchDirectionalIntent,
+    issueAction,
+    queuePlanIntent,
+    queuePlanAction,
+    undoPlanStep,
+    commitPlan,
+    discardPlan,
+    resolvePendingAction,
     setShowDangerPreview,
+    layerView,
+    setLayerView,
+    showTimelineDiff,
+    setShowTimelineDiff,
+    timelineChangeCount: timelineChanges?.length ?? null,
+    timelines,
+    viewedTimelineId,
+    setViewedTimelineId,
+    boardWidth,
+    boardHeight,
+    player,
+    examineCursor,
+    setExamineCursor,
+    worldLineRows: worldLineTableRows,
+    worldLineIndex,
+    setWorldLineIndex,
+    viewedTime,
+    viewWorldLineRow,
+    debugConsoleEnabled: DEBUG_CONSOLE_ENABLED,
+    resumeLastSession,
+    exportSnapshot,
+  })
+
+  useEffect(() => {
+    applyCssVars({ ...themeCssVars, ...runtimeConfig.config.theme })
+  }, [runtimeConfig.config.theme, themeCssVars])
+
+  // Reducers write the status line, so they take the UI language from the store.
+  useEffect(() => {
+    dispatch(setLocale(uiSettings.language))
+  }, [dispatch, uiSettings.language])
+
+  // Synced from the store listener so the clock starts and stops on the dispatch itself.
+  useEffect(
+    () =>
+      store.subscribe(() => {
+        const { turn: nextTurn, phase: nextPhase } = store.getState().game
+
+        setSpeedrunClock((clock) =>
+          syncSpeedrunClock(clock, nextTurn, nextPhase, performance.now()),
+        )
+      }),
+    [store],
+  )
+
+  // Each dispatch that ran the pipeline leaves one sample. Spans recorded outside a dispatch
+  // (previews and plan simulations while rendering) are dropped after every render.
+  useEffect(() => {
+    if (!perfEnabled) {
+      return
+    }
+
+    const sink = createMemoryTraceSink()
+    let cubeBefore = store.getState().game.cube
+
+    perfSinkRef.current = sink
+    setTraceSink(sink)
+    const unsubscribe = store.subscribe(() => {
+      const game = store.getState().game
+
+      if (sink.records.length > 0) {
+        setLastPerfSample(perfSample(sink.records, cubeBefore, game))
+        sink.records.length = 0
+      }
+
+      cubeBefore = game.cube
+    })
+
+    return () => {
+      unsubscribe()
+      setTraceSink(null)
+      perfSinkRef.current = null
+      setLastPerfSample(null)
+    }
+  }, [perfEnabled, store])
+
+  useEffect(() => {
+    perfSinkRef.current?.records.splice(0)
   })
 
+  const speedrunRunning = speedrunClock.startedAt !== null && speedrunClock.stoppedAt === null
+
+  useEffect(() => {
+    if (!uiSettings.speedrunTimer || !speedrunRunning) {
+      return
+    }
+
+    const timer = window.setInterval(() => {
+      setSpeedrunNow(performance.now())
+    }, SPEEDRUN_TICK_MS)
+
+    return () => {
+      window.clearInterval(timer)
+    }
+  }, [speedrunRunning, uiSettings.speedrunTimer])
+
+  // The settings screen writes back to config.toml when the dev server allows it.
   useEffect(() => {
-    applyCssVars(themeCssVars)
-  }, [themeCssVars])
+    if (!runtimeConfig.writable || savedUiSettingsRef.current === uiSettings) {
+      return
+    }
+
+    savedUiSettingsRef.current = uiSettings
+    void saveRuntimeConfig({ ...runtimeConfig.config, settings: uiSettings })
+  }, [runtimeConfig, uiSettings])
 
   useEffect(() => {
     if (isLogOpen) {
@@ -210,6 +919,12 @@ export function GameShell() {
     }
   }, [isLogOpen])
 
+  useEffect(() => {
+    if (isWorldLineOpen) {
+      worldLineOverlayRef.current?.focus()
+    }
+  }, [isWorldLineOpen])
+
   useEffect(() => {
     if (isSystemMenuOpen) {
       settingsOverlayRef.current?.focus()
@@ -228,118 +943,401 @@ export function GameShell() {
     }
   }, [isProgressionOverlayOpen])
 
+  useEffect(() => {
+    if (isHelpOverlayOpen) {
+      helpOverlayRef.current?.focus()
+    }
+  }, [isHelpOverlayOpen])
+
+  useEffect(() => {
+    if (isPlanningOpen) {
+      planningOverlayRef.current?.focus()
+    }
+  }, [isPlanningOpen])
+
+  useEffect(() => {
+    if (isDebugConsoleOpen) {
+      debugConsoleInputRef.current?.focus()
+    }
+  }, [isDebugConsoleOpen])
+
+  useEffect(() => {
+    if (isConfirmPromptOpen) {
+      confirmOverlayRef.current?.focus()
+      audio.play('warning')
+    }
+  }, [audio, isConfirmPromptOpen])
+
+  useEffect(() => {
+    const grew = history.length > cuedHistoryLengthRef.current
+    const latest = history[history.length - 1]
+
+    cuedHistoryLengthRef.current = history.length
+    if (grew && latest) {
+      const cue = cueForOutcome(latest.outcome)
+
+      if (cue) {
+        audio.play(cue)
+      }
+    }
+  }, [audio, history])
+
+  useEffect(() => {
+    const cue = cueForPhase(phase)
+
+    if (cue) {
+      audio.play(cue)
+    }
+  }, [audio, phase])
+
   useEffect(() => {
     if (phase !== 'Won') {
+      recordedWinRef.current = false
       return
     }
 
-    applyWinForPack(contentPackId)
-  }, [applyWinForPack, contentPackId, phase])
+    if (!uiSettings.ironmanCampaign || isIronmanCampaignLevel) {
+      applyWinForPack(contentPackId)
+    }
+
+    if (recordedWinRef.current) {
+      return
+    }
+
+    recordedWinRef.current = true
+
+    if (
+      isIronmanCampaignLevel &&
+      progressionManifest &&
+      progressionState &&
+      !progressionState.completedPackIds.includes(contentPackId)
+    ) {
+      recordIronmanClear(
+        isSelectedTrackComplete(
+          progressionManifest,
+          applyCompletionToProgressionSnapshot(progressionManifest, progressionState, contentPackId),
+        ),
+      )
+    }
+
+    recordWin(
+      {
+        packId: contentPackId,
+        turns: turn,
+        actions: history.map((entry) => entry.action),
+        path: worldLine.path,
+        hintsUsed,
+        seed: rngSeed,
+        playDifficulty,
+        ruleProfileId: ruleProfile.id,
+        ...(activeMutators.length > 0 ? { mutators: activeMutators } : {}),
+        ...(uiSettings.speedrunTimer && speedrunClock.stoppedAt !== null
+          ? { elapsedMs: speedrunElapsedMs(speedrunClock, speedrunClock.stoppedAt) }
+          : {}),
+      },
+      cube.regions,
+    )
+  }, [
+    activeMutators,
+    applyWinForPack,
+    contentPackId,
+    cube.regions,
+    hintsUsed,
+    history,
+    isIronmanCampaignLevel,
+    phase,
+    playDifficulty,
+    progressionManifest,
+    progressionState,
+    recordIronmanClear,
+    recordWin,
+    rngSeed,
+    ruleProfile.id,
+    speedrunClock,
+    turn,
+    uiSettings.ironmanCampaign,
+    uiSettings.speedrunTimer,
+    worldLine,
+  ])
+
+  useEffect(() => {
+    if (phase !== 'Detected') {
+      recordedDetectionRef.current = false
+      return
+    }
+
+    if (!isIronmanCampaignLevel || recordedDetectionRef.current) {
+      return
+    }
+
+    recordedDetectionRef.current = true
+    recordIronmanDetection()
+    resetProgression()
+    dispatch(setStatus(t('status.ironmanReset')))
+  }, [dispatch, isIronmanCampaignLevel, phase, recordIronmanDetection, resetProgression, t])
+
+  const bestComparison =
+    phase === 'Won' &&
+    lastWinComparison?.packId === contentPackId &&
+    lastWinComparison.turns === turn
+      ? describeReplayComparison(t, lastWinComparison.comparison)
+      : null
 
   return (
-    <div className="game-shell">
-      <header className="game-header">
-        <h1>He Walks Unseen</h1>
-        <p>{`Pack: ${contentPackId} | Channel: ${BUILD_CHANNEL}`}</p>
-      </header>
-
-      <main className="game-layout">
-        <section className="board-panel" aria-label="Gameplay Panel">
-          <div className={['board-stage', uiSettings.showIsoPanel ? '' : 'board-stage--single'].filter(Boolean).join(' ')}>
-            <div className="board-stage-item">
-              <GameBoardCanvas
-                boardWidth={boardWidth}
-                boardHeight={boardHeight}
-                iconPackId={iconPackId}
-                objectsAtCurrentTime={objectsAtCurrentTime}
-                selvesAtCurrentTime={selvesAtCurrentTime}
-                currentTurn={turn}
-                showDangerPreview={showDangerPreview}
-                detectionEvents={detectionPreviewReport.events}
-                actionPreview={actionPreview}
-              />
-            </div>
-            {uiSettings.showIsoPanel ? (
-              <div className="board-stage-item iso-stage-item">
-                <Suspense fallback={<div className="iso-fallback">Loading isometric view...</div>}>
-                  <LazyIsoTimeCubePanel
-                    boardWidth={boardWidth}
-                    boardHeight={boardHeight}
-                    currentTurn={turn}
-                    viewModel={isoViewModel}
-                  />
-                </Suspense>
-                <p className="iso-caption">
-                  Iso window t={isoViewModel.startT}..{isoViewModel.endT}, focus={isoViewModel.focusT}
-                </p>
+    <TranslatorContext value={t}>
+      <div className="game-shell">
+        <header className="game-header">
+          <h1>He Walks Unseen</h1>
+          <p>{t('shell.pack', { packId: contentPackId, channel: BUILD_CHANNEL })}</p>
+        </header>
+
+        <main className="game-layout">
+          <section className="board-panel" aria-label="Gameplay Panel">
+            <TutorialPanel
+              steps={gameState.tutorialSteps}
+              stepIndex={gameState.tutorialStepIndex}
+            />
+            <div className={['board-stage', uiSettings.showIsoPanel ? '' : 'board-stage--single'].filter(Boolean).join(' ')}>
+              <div className="board-stage-item">
+                <GameBoardCanvas
+                  boardWidth={boardWidth}
+                  boardHeight={boardHeight}
+                  iconPackId={iconPackId}
+                  objectsAtCurrentTime={objectsInView}
+                  enemyFacings={enemyFacings}
+                  decorAtCurrentTime={decorAtCurrentTime}
+                  luresAtCurrentTime={luresAtCurrentTime}
+                  companionsAtCurrentTime={companionsAtCurrentTime}
+                  darkCells={darkCells}
+                  fogCells={fogView?.unexplored ?? NO_CELLS}
+                  memoryCells={fogView?.remembered ?? NO_CELLS}
+                  selvesAtCurrentTime={selvesAtCurrentTime}
+                  currentTurn={worldLineTurn}
+                  ghostPosition={ghostPosition}
+                  hintTarget={isViewingPresent ? (lastHint?.target ?? null) : null}
+                  examineCursor={examineCursor}
+                  showDangerPreview={dangerPreviewVisible && isViewingPresent}
+                  detectionEvents={detectionPreviewReport.events}
+                  actionPreview={ruleProfile.preview && isViewingPresent ? actionPreview : null}
+                  planDiff={isViewingPresent ? planDiff : null}
+                  accessibleCues={uiSettings.accessibleCues}
+                  phase={phase}
+                  reduceMotion={uiSettings.reduceMotion}
+                  riftWarp={isViewingPresent ? riftWarp : null}
+                  cameraShift={cameraShift}
+                  layerView={layerView}
+                  timelineChangedCells={timelineChangedCells}
+                />
               </div>
-            ) : null}
-          </div>
-        </section>
+              {uiSettings.showIsoPanel ? (
+                <div className="board-stage-item iso-stage-item">
+                  <Suspense fallback={<div className="iso-fallback">{t('shell.isoLoading')}</div>}>
+                    <LazyIsoTimeCubePanel
+                      boardWidth={boardWidth}
+                      boardHeight={boardHeight}
+                      currentTurn={worldLineTurn}
+                      viewModel={isoViewModel}
+                      accessibleCues={uiSettings.accessibleCues}
+                    />
+                  </Suspense>
+                  <p className="iso-caption">
+                    {t('shell.isoCaption', {
+                      start: isoViewModel.startT,
+                      end: isoViewModel.endT,
+                      focus: isoViewModel.focusT,
+                    })}
+                  </p>
+                </div>
+              ) : null}
+            </div>
+          </section>
 
-        <HudPanels
-          directionalActionMode={directionalActionMode}
-          isActionMenuOpen={isActionMenuOpen}
-          directionalOptions={directionalOptions}
+          <HudPanels
+            keymap={keymap}
+            directionalActionMode={directionalActionMode}
+            isActionMenuOpen={isActionMenuOpen}
+            directionalOptions={levelDirectionalOptions}
+            turn={turn}
+            currentTime={currentTime}
+            phase={phase}
+            riftDefaultDelta={riftDefaultDelta}
+            showDangerPreview={dangerPreviewVisible}
+            hintsUsed={hintsUsed}
+            hintBudget={hintBudget}
+            playDifficulty={playDifficulty}
+            ruleProfileName={ruleProfile.id === STANDARD_RULE_PROFILE.id ? null : ruleProfile.name}
+            mutators={activeMutators}
+            restartsRemaining={restartsRemaining}
+            luresRemaining={interactionConfig.lure.supply > 0 ? lures.remaining : null}
+            peeksRemaining={peekBudget > 0 ? peekBudget - peeksUsed : null}
+            activeCharacterId={parkedCharacters.length > 0 ? activeCharacterId : null}
+            hotseatSeat={
+              hotseat && parkedCharacters.length > 0
+                ? hotseatSeat(characterStarts, activeCharacterId)
+                : null
+            }
+            regionName={player ? (regionForDisplay(cube.regions, player)?.name ?? null) : null}
+            playerLayer={levelMechanics.elevation ? currentLayer(worldLine) : null}
+            exitCompass={exitHeading}
+            extraction={
+              extractionNeeded > 0 ? { held: extractionTurns, needed: extractionNeeded } : null
+            }
+            alarm={
+              alarmConfig
+                ? {
+                    suspicion: alarm.suspicion,
+                    threshold: alarmConfig.threshold,
+                    lockdownUntil: lockdown?.to ?? null,
+                  }
+                : null
+            }
+            timelines={timelines}
+            viewedTimelineId={viewedTimeline?.id ?? null}
+            examineCursor={examineCursor}
+            examinedEntities={examinedEntities}
+            speedrunElapsedMs={
+              uiSettings.speedrunTimer ? speedrunElapsedMs(speedrunClock, speedrunNow) : null
+            }
+            levelMeta={phase === 'Won' ? levelMeta : null}
+            bestComparison={bestComparison}
+            ironmanCampaign={uiSettings.ironmanCampaign}
+            legend={legend}
+            perf={
+              perfEnabled
+                ? { sample: lastPerfSample, entitiesPerSlice: entitiesPerSlice(cube) }
+                : null
+            }
+            status={status}
+          />
+        </main>
+
+        <BottomHintsBar
+          uiSettings={uiSettings}
+          keymap={keymap}
+          mechanics={levelMechanics}
+          breadcrumb={pendingBreadcrumb(
+            {
+              layer: inputMachine.layer,
+              mode: inputMachine.mode,
+              examineCursor,
+              plannedSteps: plannedActions.length,
+            },
+            keymap,
+            t,
+          )}
+        />
+
+        <LogOverlay isOpen={isLogOpen} overlayRef={logOverlayRef} history={history} />
+
+        <WorldLineOverlay
+          isOpen={isWorldLineOpen}
+          overlayRef={worldLineOverlayRef}
+          rows={worldLineTableRows}
+          selectedIndex={worldLineIndex}
+          viewedTime={boardTime}
+          closeKeys={keysForCommand(keymap, 'ToggleWorldLine')}
+          onJump={(index) => {
+            viewWorldLineRow(index)
+            applyMachineTransition(closeTopLayer(inputMachine))
+          }}
+        />
+
+        <HelpOverlay
+          isOpen={isHelpOverlayOpen}
+          overlayRef={helpOverlayRef}
+          keymap={keymap}
+          mechanics={levelMechanics}
+        />
+
+        <PlanningOverlay
+          isOpen={isPlanningOpen}
+          overlayRef={planningOverlayRef}
+          steps={planPreview}
+        />
+
+        <DebugConsoleOverlay
+          isOpen={isDebugConsoleOpen}
+          inputRef={debugConsoleInputRef}
+          lines={debugLines}
+          onSubmit={runDebugLine}
+        />
+
+        <ConfirmOverlay
+          isOpen={isConfirmPromptOpen}
+          overlayRef={confirmOverlayRef}
+          message={pendingAction?.message ?? ''}
+        />
+
+        <StateOverlay
+          isOpen={isStateOverlayOpen}
+          overlayRef={stateOverlayRef}
+          boardWidth={boardWidth}
+          boardHeight={boardHeight}
+          timeDepth={timeDepth}
           turn={turn}
           currentTime={currentTime}
           phase={phase}
+          directionalActionMode={directionalActionMode}
           riftDefaultDelta={riftDefaultDelta}
-          showDangerPreview={showDangerPreview}
-          status={status}
+          interactionConfig={interactionConfig}
+          showDangerPreview={dangerPreviewVisible}
+          objectsAtCurrentTimeCount={objectsAtCurrentTime.length}
+          player={player}
+          contentPackId={contentPackId}
+          contentPackClass={packMetaById[contentPackId]?.class}
+          contentPackDifficulty={packMetaById[contentPackId]?.difficulty}
+          contentPackDifficultyMeta={packMetaById[contentPackId]?.difficultyMeta}
+          contentPackDifficultyFlavor={currentProgressionEntry?.difficultyFlavor}
+          contentPackDifficultyTarget={currentProgressionEntry?.difficultyTarget}
+          replayStats={replaySnapshot.statsByPackId[contentPackId]}
+          bestTurns={bestReplay?.turns}
+        />
+
+        <SettingsOverlay
+          isOpen={isSystemMenuOpen}
+          overlayRef={settingsOverlayRef}
+          uiSettings={uiSettings}
+          setUiSettings={setUiSettings}
+          setShowDangerPreview={setShowDangerPreview}
+          savesToConfigFile={runtimeConfig.writable}
+        />
+
+        <ProgressionOverlay
+          isOpen={isProgressionOverlayOpen}
+          overlayRef={progressionOverlayRef}
+          progressionManifest={progressionManifest}
+          progressionState={progressionState}
+          progressionError={progressionError}
+          packMetaById={packMetaById}
+          levelFilter={levelFilter}
+          onChangeLevelFilter={setLevelFilter}
+          levelSelectTab={levelSelectTab}
+          onChangeLevelSelectTab={setLevelSelectTab}
+          communityLevels={communityLevels}
+          communityIndex={communityIndex}
+          replaySnapshot={replaySnapshot}
+          ironmanCampaign={uiSettings.ironmanCampaign}
+          ironmanStats={ironmanStats}
+          onSelectCommunityIndex={setCommunityIndex}
+          mutators={mutators}
+          dailyMutators={dailyChallenge}
+          challengeIndex={challengeIndex}
+          onSelectChallengeIndex={setChallengeIndex}
+          onChangeMutators={setMutators}
+          currentContentPackId={contentPackId}
+          onSelectTrack={setSelectedTrack}
+          onSelectEntryIndex={setCurrentEntryIndex}
+          onLoadPack={(packId) => {
+            dispatch(setContentPackId(packId))
+            applyMachineTransition(closeTopLayer(inputMachine))
+          }}
+          savedSession={savedSession}
+          onResumeSession={() => {
+            resumeLastSession()
+            applyMachineTransition(closeTopLayer(inputMachine))
+          }}
         />
-      </main>
-
-      <BottomHintsBar uiSettings={uiSettings} />
-
-      <LogOverlay isOpen={isLogOpen} overlayRef={logOverlayRef} history={history} />
-
-      <StateOverlay
-        isOpen={isStateOverlayOpen}
-        overlayRef={stateOverlayRef}
-        boardWidth={boardWidth}
-        boardHeight={boardHeight}
-        timeDepth={timeDepth}
-        turn={turn}
-        currentTime={currentTime}
-        phase={phase}
-        directionalActionMode={directionalActionMode}
-        riftDefaultDelta={riftDefaultDelta}
-        interactionConfig={interactionConfig}
-        showDangerPreview={showDangerPreview}
-        objectsAtCurrentTimeCount={objectsAtCurrentTime.length}
-        player={player}
-        contentPackId={contentPackId}
-        contentPackClass={packMetaById[contentPackId]?.class}
-        contentPackDifficulty={packMetaById[contentPackId]?.difficulty}
-        contentPackDifficultyMeta={packMetaById[contentPackId]?.difficultyMeta}
-        contentPackDifficultyFlavor={currentProgressionEntry?.difficultyFlavor}
-        contentPackDifficultyTarget={currentProgressionEntry?.difficultyTarget}
-      />
-
-      <SettingsOverlay
-        isOpen={isSystemMenuOpen}
-        overlayRef={settingsOverlayRef}
-        uiSettings={uiSettings}
-        setUiSettings={setUiSettings}
-        setShowDangerPreview={setShowDangerPreview}
-      />
-
-      <ProgressionOverlay
-        isOpen={isProgressionOverlayOpen}
-        overlayRef={progressionOverlayRef}
-        progressionManifest={progressionManifest}
-        progressionState={progressionState}
-        progressionError={progressionError}
-        packMetaById={packMetaById}
-        currentContentPackId={contentPackId}
-        onSelectTrack={setSelectedTrack}
-        onSelectEntryIndex={setCurrentEntryIndex}
-        onLoadPack={(packId) => {
-          dispatch(setContentPackId(packId))
-          applyMachineTransition(closeTopLayer(inputMachine))
-        }}
-      />
-    </div>
+      </div>
+    </TranslatorContext>
   )
 }
@@ -0,0 +1,24 @@
+import { describe, expect, it } from 'vitest'
+
+import { cueForOutcome, cueForPhase } from './audio'
+
+describe('audio cues', () => {
+  it('cues rifts, pushes and blows but not plain moves', () => {
+    const to = { x: 1, y: 1, t: 1 }
+
+    expect(cueForOutcome({ kind: 'Rifted', to, mode: 'default' })).toBe('rift')
+    expect(cueForOutcome({ kind: 'Pushed', to, movedObjectIds: ['box'] })).toBe('push')
+    expect(cueForOutcome({ kind: 'Pulled', to, movedObjectIds: ['box'] })).toBe('push')
+    expect(
+      cueForOutcome({ kind: 'Broke', to, objectId: 'wall', at: to, destroyed: false }),
+    ).toBe('break')
+    expect(cueForOutcome({ kind: 'Moved', to })).toBeNull()
+  })
+
+  it('cues victory and losses', () => {
+    expect(cueForPhase('Won')).toBe('victory')
+    expect(cueForPhase('Detected')).toBe('warning')
+    expect(cueForPhase('Paradox')).toBe('warning')
+    expect(cueForPhase('Playing')).toBeNull()
+  })
+})
@@ -0,0 +1,83 @@
+import type { GamePhase, SuccessfulOutcome } from '../game/interactions/types'
+
+/** Events that are easy to miss on screen and get a sound when audio cues are on. */
+export type AudioCue = 'warning' | 'rift' | 'push' | 'break' | 'victory'
+
+export interface AudioBackend {
+  play(cue: AudioCue): void
+}
+
+/** Default backend: cues are computed but nothing is played. */
+export const silentAudioBackend: AudioBackend = {
+  play() {},
+}
+
+const CUE_TONES: Record<AudioCue, { frequencies: number[]; durationMs: number }> = {
+  warning: { frequencies: [220, 180], durationMs: 160 },
+  rift: { frequencies: [520, 780], durationMs: 120 },
+  push: { frequencies: [140], durationMs: 80 },
+  break: { frequencies: [110, 90], durationMs: 90 },
+  victory: { frequencies: [523, 659, 784], durationMs: 140 },
+}
+
+/**
+ * Optional backend built on Web Audio: each cue is a short run of square-wave beeps. The context
+ * is created on first use (after a key press, so autoplay policies allow it); without Web Audio
+ * the backend stays silent.
+ */
+export function createToneAudioBackend(): AudioBackend {
+  let context: AudioContext | null = null
+
+  return {
+    play(cue) {
+      if (typeof AudioContext === 'undefined') {
+        return
+      }
+
+      const audio = context ?? new AudioContext()
+      context = audio
+      const tone = CUE_TONES[cue]
+      const step = tone.durationMs / 1000
+
+      tone.frequencies.forEach((frequency, index) => {
+        const startAt = audio.currentTime + index * step
+        const oscillator = audio.createOscillator()
+        const gain = audio.createGain()
+
+        oscillator.type = 'square'
+        oscillator.frequency.value = frequency
+        gain.gain.setValueAtTime(0.05, startAt)
+        gain.gain.exponentialRampToValueAtTime(0.001, startAt + step)
+        oscillator.connect(gain).connect(audio.destination)
+        oscillator.start(startAt)
+        oscillator.stop(startAt + step)
+      })
+    },
+  }
+}
+
+export function cueForOutcome(outcome: SuccessfulOutcome): AudioCue | null {
+  switch (outcome.kind) {
+    case 'Rifted':
+      return 'rift'
+    case 'Pushed':
+    case 'Pulled':
+      return 'push'
+    case 'Broke':
+      return 'break'
+    default:
+      return null
+  }
+}
+
+export function cueForPhase(phase: GamePhase): AudioCue | null {
+  switch (phase) {
+    case 'Won':
+      return 'victory'
+    case 'Detected':
+    case 'Paradox':
+      return 'warning'
+    default:
+      return null
+  }
+}
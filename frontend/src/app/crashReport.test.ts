@@ -0,0 +1,50 @@
+import { describe, expect, it } from 'vitest'
+
+import { gameReducer, waitTurn } from '../game/gameSlice'
+import {
+  buildCrashReport,
+  CRASH_REPORT_STORAGE_KEY,
+  crashReportFileName,
+  storeCrashReport,
+} from './crashReport'
+
+describe('crashReport', () => {
+  it('captures the error with the actions needed to replay the run', () => {
+    const game = gameReducer(gameReducer(undefined, { type: 'init' }), waitTurn())
+    const report = buildCrashReport(new Error('boom'), game, new Date('2026-01-02T03:04:05.000Z'))
+
+    expect(report).toMatchObject({
+      version: 1,
+      message: 'boom',
+      packId: game.contentPackId,
+      seed: game.rng.seed,
+      ruleProfileId: game.ruleProfile.id,
+      playDifficulty: game.playDifficulty,
+      turn: 1,
+      actions: [{ kind: 'Wait' }],
+    })
+    expect(crashReportFileName(report)).toBe(
+      `hwu-crash-${game.contentPackId}-2026-01-02T03-04-05-000Z.json`,
+    )
+  })
+
+  it('stores the latest report and survives a failing storage', () => {
+    const game = gameReducer(undefined, { type: 'init' })
+    const report = buildCrashReport('not an error', game, new Date(0))
+    const saved = new Map<string, string>()
+
+    storeCrashReport({ setItem: (key, value) => saved.set(key, value) }, report)
+    expect(JSON.parse(saved.get(CRASH_REPORT_STORAGE_KEY) ?? '{}').message).toBe('not an error')
+
+    expect(() =>
+      storeCrashReport(
+        {
+          setItem: () => {
+            throw new Error('quota')
+          },
+        },
+        report,
+      ),
+    ).not.toThrow()
+  })
+})
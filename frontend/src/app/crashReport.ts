@@ -0,0 +1,53 @@
+import type { MutatorId } from '../data/mutators'
+import type { PlayDifficulty } from '../data/playDifficulty'
+import type { GameState } from '../game/gameSlice'
+import type { InteractionAction } from '../game/interactions/types'
+
+export const CRASH_REPORT_STORAGE_KEY = 'hwu.web.crash.v1'
+
+/**
+ * What the crash screen saves and offers for download: the error plus enough of the run
+ * (pack, seed, rules, difficulty, actions so far) to replay it up to the crash.
+ */
+export interface CrashReport {
+  version: 1
+  createdAt: string
+  message: string
+  stack: string | null
+  packId: string
+  seed: string
+  ruleProfileId: string
+  playDifficulty: PlayDifficulty
+  mutators: MutatorId[]
+  turn: number
+  actions: InteractionAction[]
+}
+
+export function buildCrashReport(error: unknown, game: GameState, now: Date): CrashReport {
+  return {
+    version: 1,
+    createdAt: now.toISOString(),
+    message: error instanceof Error ? error.message : String(error),
+    stack: error instanceof Error ? (error.stack ?? null) : null,
+    packId: game.contentPackId,
+    seed: game.rng.seed,
+    ruleProfileId: game.ruleProfile.id,
+    playDifficulty: game.playDifficulty,
+    mutators: [...game.mutators],
+    turn: game.turn,
+    actions: game.history.map((entry) => entry.action),
+  }
+}
+
+export function crashReportFileName(report: CrashReport): string {
+  return `hwu-crash-${report.packId}-${report.createdAt.replace(/[:.]/g, '-')}.json`
+}
+
+/** Keeps only the latest report; storage failures are ignored, the crash screen still shows. */
+export function storeCrashReport(storage: Pick<Storage, 'setItem'>, report: CrashReport): void {
+  try {
+    storage.setItem(CRASH_REPORT_STORAGE_KEY, JSON.stringify(report))
+  } catch {
+    // Quota or privacy mode: the report can still be downloaded from the crash screen.
+  }
+}
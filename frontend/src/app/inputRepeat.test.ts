@@ -0,0 +1,41 @@
+import { describe, expect, it } from 'vitest'
+
+import { createKeyRepeatGate, gateKeyPress } from './inputRepeat'
+
+const repeatable = { repeatable: true, repeatDelayMs: 200 }
+
+describe('gateKeyPress', () => {
+  it('always accepts fresh presses', () => {
+    const first = gateKeyPress(createKeyRepeatGate(), { key: 'd', repeat: false, timestamp: 0 }, repeatable)
+    const second = gateKeyPress(first.gate, { key: 'd', repeat: false, timestamp: 10 }, repeatable)
+
+    expect(first.accepted).toBe(true)
+    expect(second.accepted).toBe(true)
+  })
+
+  it('coalesces held repeats until the delay has elapsed', () => {
+    let gate = gateKeyPress(createKeyRepeatGate(), { key: 'd', repeat: false, timestamp: 0 }, repeatable).gate
+    let accepted = 0
+
+    for (let timestamp = 30; timestamp <= 600; timestamp += 30) {
+      const decision = gateKeyPress(gate, { key: 'D', repeat: true, timestamp }, repeatable)
+      gate = decision.gate
+      accepted += decision.accepted ? 1 : 0
+    }
+
+    expect(accepted).toBe(2)
+  })
+
+  it('drops repeats for non-repeatable commands or when repeat is disabled', () => {
+    const gate = gateKeyPress(createKeyRepeatGate(), { key: 'f', repeat: false, timestamp: 0 }, repeatable).gate
+
+    expect(
+      gateKeyPress(gate, { key: 'f', repeat: true, timestamp: 500 }, { repeatable: false, repeatDelayMs: 200 })
+        .accepted,
+    ).toBe(false)
+    expect(
+      gateKeyPress(gate, { key: 'f', repeat: true, timestamp: 500 }, { repeatable: true, repeatDelayMs: 0 })
+        .accepted,
+    ).toBe(false)
+  })
+})
@@ -0,0 +1,57 @@
+/** Held-key repeats are coalesced per key so a fast OS repeat rate cannot burn several turns at once. */
+export interface KeyRepeatGate {
+  lastAcceptedAtByKey: Record<string, number>
+}
+
+export interface KeyPress {
+  key: string
+  repeat: boolean
+  timestamp: number
+}
+
+export interface KeyRepeatDecision {
+  accepted: boolean
+  gate: KeyRepeatGate
+}
+
+export const KEY_REPEAT_DELAY_OPTIONS_MS = [0, 150, 250, 400]
+export const DEFAULT_KEY_REPEAT_DELAY_MS = 250
+
+export function createKeyRepeatGate(): KeyRepeatGate {
+  return { lastAcceptedAtByKey: {} }
+}
+
+/**
+ * Fresh presses always pass. Repeats pass only for repeatable commands, and only once the delay
+ * has elapsed since the last accepted event for that key; a delay of 0 disables repeat entirely.
+ */
+export function gateKeyPress(
+  gate: KeyRepeatGate,
+  press: KeyPress,
+  options: { repeatable: boolean; repeatDelayMs: number },
+): KeyRepeatDecision {
+  const key = press.key.length === 1 ? press.key.toLowerCase() : press.key
+
+  if (press.repeat) {
+    const lastAcceptedAt = gate.lastAcceptedAtByKey[key]
+
+    if (
+      !options.repeatable ||
+      options.repeatDelayMs <= 0 ||
+      lastAcceptedAt === undefined ||
+      press.timestamp - lastAcceptedAt < options.repeatDelayMs
+    ) {
+      return { accepted: false, gate }
+    }
+  }
+
+  return {
+    accepted: true,
+    gate: {
+      lastAcceptedAtByKey: {
+        ...gate.lastAcceptedAtByKey,
+        [key]: press.timestamp,
+      },
+    },
+  }
+}
@@ -1,18 +1,34 @@
 import { describe, expect, it } from 'vitest'
 
 import {
+  cancelDirectionalMode,
   closeTopLayer,
   createInputStateMachine,
+  openConfirmPrompt,
   pushDirectionalInput,
   selectDirectionalMode,
   toggleActionMenu,
+  toggleDebugConsole,
+  toggleExamineMode,
+  toggleHelpOverlay,
   toggleLogOverlay,
+  togglePlanningOverlay,
   toggleProgressionOverlay,
   toggleStateOverlay,
   toggleSystemMenu,
+  toggleWorldLineOverlay,
 } from './inputStateMachine'
 
 describe('inputStateMachine', () => {
+  it('cancels a pending directional mode back to Move from gameplay only', () => {
+    const pushing = selectDirectionalMode(createInputStateMachine(), 'Push')
+    const menu = toggleActionMenu(pushing)
+
+    expect(cancelDirectionalMode(pushing)).toEqual({ layer: 'Gameplay', mode: 'Move' })
+    expect(cancelDirectionalMode(menu)).toBe(menu)
+    expect(cancelDirectionalMode(createInputStateMachine())).toEqual(createInputStateMachine())
+  })
+
   it('dispatches direction immediately in gameplay layer', () => {
     const machine = createInputStateMachine()
     const result = pushDirectionalInput(machine, 'east')
@@ -73,4 +89,78 @@ describe('inputStateMachine', () => {
     expect(blockedFromState.layer).toBe('StateOverlay')
     expect(blockedFromAction.layer).toBe('ActionMenu')
   })
+
+  it('confirm prompt blocks other overlays and directional dispatch until closed', () => {
+    const prompted = openConfirmPrompt(createInputStateMachine())
+
+    expect(prompted.layer).toBe('ConfirmPrompt')
+    expect(toggleActionMenu(prompted).layer).toBe('ConfirmPrompt')
+    expect(toggleLogOverlay(prompted).layer).toBe('ConfirmPrompt')
+    expect(toggleSystemMenu(prompted).layer).toBe('ConfirmPrompt')
+    expect(pushDirectionalInput(prompted, 'east').immediate).toBeNull()
+    expect(closeTopLayer(prompted).layer).toBe('Gameplay')
+  })
+
+  it('does not open confirm prompt over another layer', () => {
+    const menu = toggleSystemMenu(createInputStateMachine())
+
+    expect(openConfirmPrompt(menu)).toBe(menu)
+  })
+
+  it('help overlay toggles from gameplay and blocks other overlays while open', () => {
+    const opened = toggleHelpOverlay(createInputStateMachine())
+
+    expect(opened.layer).toBe('HelpOverlay')
+    expect(toggleSystemMenu(opened).layer).toBe('HelpOverlay')
+    expect(toggleStateOverlay(opened).layer).toBe('HelpOverlay')
+    expect(pushDirectionalInput(opened, 'north').immediate).toBeNull()
+    expect(toggleHelpOverlay(opened).layer).toBe('Gameplay')
+  })
+
+  it('queues directional input while planning instead of playing it', () => {
+    const planning = togglePlanningOverlay(createInputStateMachine())
+    const result = pushDirectionalInput(planning, 'west')
+
+    expect(planning.layer).toBe('PlanningOverlay')
+    expect(result).toEqual({ immediate: null, planned: { mode: 'Move', direction: 'west' } })
+    expect(toggleLogOverlay(planning).layer).toBe('PlanningOverlay')
+    expect(togglePlanningOverlay(toggleHelpOverlay(createInputStateMachine())).layer).toBe(
+      'HelpOverlay',
+    )
+    expect(togglePlanningOverlay(planning).layer).toBe('Gameplay')
+  })
+
+  it('examine mode swallows directions and blocks other overlays until closed', () => {
+    const examining = toggleExamineMode(createInputStateMachine())
+
+    expect(examining.layer).toBe('Examine')
+    expect(pushDirectionalInput(examining, 'south')).toEqual({ immediate: null, planned: null })
+    expect(toggleActionMenu(examining).layer).toBe('Examine')
+    expect(togglePlanningOverlay(examining).layer).toBe('Examine')
+    expect(toggleExamineMode(toggleSystemMenu(createInputStateMachine())).layer).toBe('SystemMenu')
+    expect(toggleExamineMode(examining).layer).toBe('Gameplay')
+  })
+
+  it('opens the world line table from gameplay and keeps other overlays out', () => {
+    const listing = toggleWorldLineOverlay(createInputStateMachine())
+
+    expect(listing.layer).toBe('WorldLineOverlay')
+    expect(toggleHelpOverlay(listing).layer).toBe('WorldLineOverlay')
+    expect(toggleWorldLineOverlay(toggleLogOverlay(createInputStateMachine())).layer).toBe(
+      'LogOverlay',
+    )
+    expect(closeTopLayer(listing).layer).toBe('Gameplay')
+    expect(toggleWorldLineOverlay(listing).layer).toBe('Gameplay')
+  })
+
+  it('opens the debug console from gameplay only', () => {
+    const opened = toggleDebugConsole(createInputStateMachine())
+
+    expect(opened.layer).toBe('DebugConsole')
+    expect(pushDirectionalInput(opened, 'east').immediate).toBeNull()
+    expect(toggleDebugConsole(toggleHelpOverlay(createInputStateMachine())).layer).toBe(
+      'HelpOverlay',
+    )
+    expect(toggleDebugConsole(opened).layer).toBe('Gameplay')
+  })
 })
@@ -1,6 +1,14 @@
 import type { Direction2D } from '../core/position'
 
-export type DirectionalActionMode = 'Move' | 'Push' | 'Pull'
+export type DirectionalActionMode =
+  | 'Move'
+  | 'Push'
+  | 'Pull'
+  | 'TimePush'
+  | 'Throw'
+  | 'Break'
+  | 'Interact'
+  | 'Peek'
 
 export type InputLayer =
   | 'Gameplay'
@@ -9,6 +17,12 @@ export type InputLayer =
   | 'LogOverlay'
   | 'SystemMenu'
   | 'ProgressionOverlay'
+  | 'ConfirmPrompt'
+  | 'HelpOverlay'
+  | 'PlanningOverlay'
+  | 'Examine'
+  | 'WorldLineOverlay'
+  | 'DebugConsole'
 
 export interface DirectionalIntent {
   mode: DirectionalActionMode
@@ -22,6 +36,8 @@ export interface InputStateMachine {
 
 export interface DirectionalInputResult {
   immediate: DirectionalIntent | null
+  /** Set while planning: the intent is queued instead of played. */
+  planned: DirectionalIntent | null
 }
 
 export function createInputStateMachine(): InputStateMachine {
@@ -33,6 +49,11 @@ export function createInputStateMachine(): InputStateMachine {
 
 export function toggleActionMenu(machine: InputStateMachine): InputStateMachine {
   if (
+    machine.layer === 'ConfirmPrompt' ||
+    machine.layer === 'PlanningOverlay' ||
+    machine.layer === 'Examine' ||
+    machine.layer === 'WorldLineOverlay' ||
+    machine.layer === 'HelpOverlay' ||
     machine.layer === 'SystemMenu' ||
     machine.layer === 'LogOverlay' ||
     machine.layer === 'StateOverlay' ||
@@ -49,6 +70,11 @@ export function toggleActionMenu(machine: InputStateMachine): InputStateMachine
 
 export function toggleLogOverlay(machine: InputStateMachine): InputStateMachine {
   if (
+    machine.layer === 'ConfirmPrompt' ||
+    machine.layer === 'PlanningOverlay' ||
+    machine.layer === 'Examine' ||
+    machine.layer === 'WorldLineOverlay' ||
+    machine.layer === 'HelpOverlay' ||
     machine.layer === 'SystemMenu' ||
     machine.layer === 'StateOverlay' ||
     machine.layer === 'ProgressionOverlay'
@@ -63,7 +89,15 @@ export function toggleLogOverlay(machine: InputStateMachine): InputStateMachine
 }
 
 export function toggleSystemMenu(machine: InputStateMachine): InputStateMachine {
-  if (machine.layer === 'StateOverlay' || machine.layer === 'ProgressionOverlay') {
+  if (
+    machine.layer === 'ConfirmPrompt' ||
+    machine.layer === 'PlanningOverlay' ||
+    machine.layer === 'Examine' ||
+    machine.layer === 'WorldLineOverlay' ||
+    machine.layer === 'HelpOverlay' ||
+    machine.layer === 'StateOverlay' ||
+    machine.layer === 'ProgressionOverlay'
+  ) {
     return machine
   }
 
@@ -75,6 +109,11 @@ export function toggleSystemMenu(machine: InputStateMachine): InputStateMachine
 
 export function toggleStateOverlay(machine: InputStateMachine): InputStateMachine {
   if (
+    machine.layer === 'ConfirmPrompt' ||
+    machine.layer === 'PlanningOverlay' ||
+    machine.layer === 'Examine' ||
+    machine.layer === 'WorldLineOverlay' ||
+    machine.layer === 'HelpOverlay' ||
     machine.layer === 'SystemMenu' ||
     machine.layer === 'LogOverlay' ||
     machine.layer === 'ActionMenu' ||
@@ -91,6 +130,11 @@ export function toggleStateOverlay(machine: InputStateMachine): InputStateMachin
 
 export function toggleProgressionOverlay(machine: InputStateMachine): InputStateMachine {
   if (
+    machine.layer === 'ConfirmPrompt' ||
+    machine.layer === 'PlanningOverlay' ||
+    machine.layer === 'Examine' ||
+    machine.layer === 'WorldLineOverlay' ||
+    machine.layer === 'HelpOverlay' ||
     machine.layer === 'SystemMenu' ||
     machine.layer === 'LogOverlay' ||
     machine.layer === 'StateOverlay' ||
@@ -105,6 +149,86 @@ export function toggleProgressionOverlay(machine: InputStateMachine): InputState
   }
 }
 
+export function toggleHelpOverlay(machine: InputStateMachine): InputStateMachine {
+  if (
+    machine.layer === 'ConfirmPrompt' ||
+    machine.layer === 'PlanningOverlay' ||
+    machine.layer === 'Examine' ||
+    machine.layer === 'WorldLineOverlay' ||
+    machine.layer === 'SystemMenu' ||
+    machine.layer === 'LogOverlay' ||
+    machine.layer === 'StateOverlay' ||
+    machine.layer === 'ProgressionOverlay'
+  ) {
+    return machine
+  }
+
+  return {
+    ...machine,
+    layer: machine.layer === 'HelpOverlay' ? 'Gameplay' : 'HelpOverlay',
+  }
+}
+
+/** Planning opens from gameplay only and closes back to it. */
+export function togglePlanningOverlay(machine: InputStateMachine): InputStateMachine {
+  if (machine.layer !== 'Gameplay' && machine.layer !== 'PlanningOverlay') {
+    return machine
+  }
+
+  return {
+    ...machine,
+    layer: machine.layer === 'PlanningOverlay' ? 'Gameplay' : 'PlanningOverlay',
+  }
+}
+
+/** The examine cursor, like planning, opens from gameplay only and closes back to it. */
+export function toggleExamineMode(machine: InputStateMachine): InputStateMachine {
+  if (machine.layer !== 'Gameplay' && machine.layer !== 'Examine') {
+    return machine
+  }
+
+  return {
+    ...machine,
+    layer: machine.layer === 'Examine' ? 'Gameplay' : 'Examine',
+  }
+}
+
+/** The world line table opens from gameplay only and closes back to it. */
+export function toggleWorldLineOverlay(machine: InputStateMachine): InputStateMachine {
+  if (machine.layer !== 'Gameplay' && machine.layer !== 'WorldLineOverlay') {
+    return machine
+  }
+
+  return {
+    ...machine,
+    layer: machine.layer === 'WorldLineOverlay' ? 'Gameplay' : 'WorldLineOverlay',
+  }
+}
+
+/** The debug console takes over the keyboard; it opens from gameplay only. */
+export function toggleDebugConsole(machine: InputStateMachine): InputStateMachine {
+  if (machine.layer !== 'Gameplay' && machine.layer !== 'DebugConsole') {
+    return machine
+  }
+
+  return {
+    ...machine,
+    layer: machine.layer === 'DebugConsole' ? 'Gameplay' : 'DebugConsole',
+  }
+}
+
+/** Opens the modal confirmation prompt from gameplay; other layers keep priority. */
+export function openConfirmPrompt(machine: InputStateMachine): InputStateMachine {
+  if (machine.layer !== 'Gameplay') {
+    return machine
+  }
+
+  return {
+    ...machine,
+    layer: 'ConfirmPrompt',
+  }
+}
+
 export function closeTopLayer(machine: InputStateMachine): InputStateMachine {
   if (machine.layer === 'Gameplay') {
     return machine
@@ -116,6 +240,18 @@ export function closeTopLayer(machine: InputStateMachine): InputStateMachine {
   }
 }
 
+/** Escape in gameplay drops a pending Push/Pull/... mode back to Move; nothing else changes. */
+export function cancelDirectionalMode(machine: InputStateMachine): InputStateMachine {
+  if (machine.layer !== 'Gameplay' || machine.mode === 'Move') {
+    return machine
+  }
+
+  return {
+    ...machine,
+    mode: 'Move',
+  }
+}
+
 export function selectDirectionalMode(
   machine: InputStateMachine,
   mode: DirectionalActionMode,
@@ -139,10 +275,12 @@ export function pushDirectionalInput(
   if (machine.layer === 'Gameplay') {
     return {
       immediate: intent,
+      planned: null,
     }
   }
 
   return {
     immediate: null,
+    planned: machine.layer === 'PlanningOverlay' ? intent : null,
   }
 }
@@ -0,0 +1,99 @@
+import { describe, expect, it } from 'vitest'
+
+import {
+  applyQueryOverrides,
+  EMPTY_RUNTIME_CONFIG,
+  formatRuntimeConfig,
+  parseRuntimeConfig,
+} from './runtimeConfig'
+
+const SAMPLE = `language = "es"
+difficulty = "Hard"
+
+[assist]
+ghost_run = false
+
+[animation]
+reduce_motion = true
+
+[keymap]
+Wait = ["Enter", "."]
+
+[theme]
+--ui-bg = "#101010"
+
+[detection]
+max_distance = 3
+
+[levels]
+directory = "~/hwu-levels"
+`
+
+describe('runtimeConfig', () => {
+  it('maps config.toml keys onto settings, keymap, theme and detection', () => {
+    const parsed = parseRuntimeConfig(SAMPLE)
+
+    expect(parsed).toEqual({
+      ok: true,
+      value: {
+        settings: {
+          language: 'es',
+          playDifficulty: 'Hard',
+          showGhostRun: false,
+          reduceMotion: true,
+        },
+        keymap: { Wait: ['Enter', '.'] },
+        theme: { '--ui-bg': '#101010' },
+        detection: { maxDistance: 3 },
+        levelsDir: '~/hwu-levels',
+      },
+    })
+  })
+
+  it('rejects unknown keys, commands and mistyped values', () => {
+    expect(parseRuntimeConfig('[assist]\nghost = true')).toEqual({
+      ok: false,
+      error: 'unknown key assist.ghost',
+    })
+    expect(parseRuntimeConfig('[keymap]\nFly = ["f"]')).toEqual({
+      ok: false,
+      error: 'unknown command Fly',
+    })
+    expect(parseRuntimeConfig('[animation]\nreduce_motion = "yes"')).toEqual({
+      ok: false,
+      error: 'animation.reduce_motion must be a boolean',
+    })
+    expect(parseRuntimeConfig('[detection]\ndelay_turns = 0')).toEqual({
+      ok: false,
+      error: 'detection.delay_turns must be an integer >= 1',
+    })
+    expect(parseRuntimeConfig('language = ').ok).toBe(false)
+  })
+
+  it('lets page flags override the file', () => {
+    const parsed = parseRuntimeConfig(SAMPLE)
+    const base = parsed.ok ? parsed.value : EMPTY_RUNTIME_CONFIG
+    const overridden = applyQueryOverrides(
+      base,
+      '?debug&animation.reduce_motion=false&detection.max_distance=5&assist.ghost_run=maybe' +
+        '&theme.--ink=%23222222',
+    )
+
+    expect(overridden.settings.reduceMotion).toBe(false)
+    expect(overridden.settings.showGhostRun).toBe(false)
+    expect(overridden.detection).toEqual({ maxDistance: 5 })
+    expect(overridden.theme).toEqual({ '--ui-bg': '#101010', '--ink': '#222222' })
+    expect(base.settings.reduceMotion).toBe(true)
+  })
+
+  it('writes back a file that parses to the same config', () => {
+    const parsed = parseRuntimeConfig(SAMPLE)
+
+    expect(parsed.ok).toBe(true)
+    if (!parsed.ok) {
+      return
+    }
+
+    expect(parseRuntimeConfig(formatRuntimeConfig(parsed.value))).toEqual(parsed)
+  })
+})
@@ -0,0 +1,285 @@
+import type { DetectionConfig } from '../core/detection'
+import type { Result } from '../core/result'
+import { formatToml, parseToml, type TomlTable, type TomlValue } from '../data/toml'
+import { defaultUiSettings, type UiSettings } from './shell/constants'
+import { isKeyCommand, type KeyCommand } from './shell/keymap'
+
+/** Served by the dev and preview servers from the user's config directory. */
+export const RUNTIME_CONFIG_URL = '/config.toml'
+const RUNTIME_CONFIG_CONTENT_TYPE = 'application/toml'
+
+export type DetectionOverrides = Partial<
+  Pick<DetectionConfig, 'enabled' | 'delayTurns' | 'maxDistance'>
+>
+
+/** Everything `config.toml` can set; fields it leaves out keep the page's own defaults. */
+export interface RuntimeConfig {
+  settings: Partial<UiSettings>
+  /** CSS variables layered over each level pack's theme. */
+  theme: Record<string, string>
+  keymap: Partial<Record<KeyCommand, string[]>>
+  /** Replaces each level's default detection values; difficulty still adjusts them. */
+  detection: DetectionOverrides
+  /** Community levels directory; only the dev server reads it. */
+  levelsDir: string | null
+}
+
+export const EMPTY_RUNTIME_CONFIG: RuntimeConfig = {
+  settings: {},
+  theme: {},
+  keymap: {},
+  detection: {},
+  levelsDir: null,
+}
+
+const SETTING_KEYS = {
+  language: 'language',
+  playDifficulty: 'difficulty',
+  ruleProfileId: 'rules',
+  showIsoPanel: 'display.iso_panel',
+  compactHints: 'display.compact_hints',
+  fogOfWar: 'display.fog_of_war',
+  speedrunTimer: 'display.speedrun_timer',
+  defaultDangerPreview: 'assist.danger_preview',
+  showGhostRun: 'assist.ghost_run',
+  exitCompass: 'assist.exit_compass',
+  assistDetectionWarnings: 'assist.detection_warnings',
+  accessibleCues: 'assist.accessible_cues',
+  reduceMotion: 'animation.reduce_motion',
+  soundCues: 'audio.sound_cues',
+  keyRepeatDelayMs: 'input.key_repeat_delay_ms',
+  ironmanCampaign: 'campaign.ironman',
+} satisfies Record<keyof UiSettings, string>
+
+const DETECTION_KEYS = {
+  enabled: 'detection.enabled',
+  delayTurns: 'detection.delay_turns',
+  maxDistance: 'detection.max_distance',
+} satisfies Record<keyof DetectionOverrides, string>
+
+const LEVELS_DIR_KEY = 'levels.directory'
+
+const SETTING_BY_KEY = new Map(
+  Object.entries(SETTING_KEYS).map(([field, key]) => [key, field as keyof UiSettings]),
+)
+const DETECTION_BY_KEY = new Map(
+  Object.entries(DETECTION_KEYS).map(([field, key]) => [key, field as keyof DetectionOverrides]),
+)
+
+function isStringArray(value: TomlValue): value is string[] {
+  return Array.isArray(value) && value.every((item) => typeof item === 'string')
+}
+
+/** Sets one dotted key on `config`; returns why it was rejected, or null. */
+function applyConfigEntry(config: RuntimeConfig, key: string, value: TomlValue): string | null {
+  const setting = SETTING_BY_KEY.get(key)
+
+  if (setting) {
+    const expected = typeof defaultUiSettings[setting]
+
+    if (typeof value !== expected) {
+      return `${key} must be a ${expected}`
+    }
+
+    config.settings = { ...config.settings, [setting]: value }
+    return null
+  }
+
+  const detection = DETECTION_BY_KEY.get(key)
+
+  if (detection === 'enabled') {
+    if (typeof value !== 'boolean') {
+      return `${key} must be a boolean`
+    }
+
+    config.detection = { ...config.detection, enabled: value }
+    return null
+  }
+
+  if (detection) {
+    const minimum = detection === 'delayTurns' ? 1 : 0
+
+    if (typeof value !== 'number' || !Number.isInteger(value) || value < minimum) {
+      return `${key} must be an integer >= ${minimum}`
+    }
+
+    config.detection = { ...config.detection, [detection]: value }
+    return null
+  }
+
+  if (key === LEVELS_DIR_KEY) {
+    if (typeof value !== 'string') {
+      return `${key} must be a string`
+    }
+
+    config.levelsDir = value
+    return null
+  }
+
+  if (key.startsWith('theme.')) {
+    if (typeof value !== 'string') {
+      return `${key} must be a string`
+    }
+
+    config.theme = { ...config.theme, [key.slice('theme.'.length)]: value }
+    return null
+  }
+
+  if (key.startsWith('keymap.')) {
+    const command = key.slice('keymap.'.length)
+
+    if (!isKeyCommand(command)) {
+      return `unknown command ${command}`
+    }
+
+    if (!isStringArray(value)) {
+      return `${key} must be an array of keys`
+    }
+
+    config.keymap = { ...config.keymap, [command]: value }
+    return null
+  }
+
+  return `unknown key ${key}`
+}
+
+export function runtimeConfigFromToml(table: TomlTable): Result<RuntimeConfig, string> {
+  const config: RuntimeConfig = { ...EMPTY_RUNTIME_CONFIG }
+
+  for (const [key, value] of table) {
+    const error = applyConfigEntry(config, key, value)
+
+    if (error) {
+      return { ok: false, error }
+    }
+  }
+
+  return { ok: true, value: config }
+}
+
+export function parseRuntimeConfig(source: string): Result<RuntimeConfig, string> {
+  const table = parseToml(source)
+
+  if (!table.ok) {
+    return { ok: false, error: `line ${table.error.line}: ${table.error.message}` }
+  }
+
+  return runtimeConfigFromToml(table.value)
+}
+
+function parseQueryValue(raw: string): TomlValue {
+  if (raw === '' || raw === 'true' || raw === 'false') {
+    return raw !== 'false'
+  }
+
+  const number = Number(raw)
+
+  return Number.isFinite(number) ? number : raw
+}
+
+/**
+ * Page flags win over the file, the way command-line flags would: `?animation.reduce_motion=true`,
+ * `?detection.max_distance=3` or `?theme.--ink=%23222`. Unknown or invalid flags are left for
+ * other readers (`?debug`).
+ */
+export function applyQueryOverrides(config: RuntimeConfig, search: string): RuntimeConfig {
+  const next: RuntimeConfig = { ...config }
+
+  for (const [key, raw] of new URLSearchParams(search)) {
+    if (key.startsWith('theme.')) {
+      applyConfigEntry(next, key, raw)
+    } else if (SETTING_BY_KEY.has(key) || DETECTION_BY_KEY.has(key)) {
+      applyConfigEntry(next, key, parseQueryValue(raw))
+    }
+  }
+
+  return next
+}
+
+export function formatRuntimeConfig(config: RuntimeConfig): string {
+  const table: TomlTable = new Map()
+
+  for (const [field, key] of Object.entries(SETTING_KEYS)) {
+    const value = config.settings[field as keyof UiSettings]
+
+    if (value !== undefined) {
+      table.set(key, value)
+    }
+  }
+
+  for (const [field, key] of Object.entries(DETECTION_KEYS)) {
+    const value = config.detection[field as keyof DetectionOverrides]
+
+    if (value !== undefined) {
+      table.set(key, value)
+    }
+  }
+
+  for (const [command, keys] of Object.entries(config.keymap)) {
+    table.set(`keymap.${command}`, keys ?? [])
+  }
+
+  for (const [name, value] of Object.entries(config.theme)) {
+    table.set(`theme.${name}`, value)
+  }
+
+  if (config.levelsDir !== null) {
+    table.set(LEVELS_DIR_KEY, config.levelsDir)
+  }
+
+  return formatToml(table)
+}
+
+export interface LoadedRuntimeConfig {
+  config: RuntimeConfig
+  /** Why the file was ignored; null when it loaded or there is none. */
+  error: string | null
+  /** Whether settings changes are written back to the file (only the dev server allows it). */
+  writable: boolean
+  /** Level to open first (`?pack=<id>`, as `hwu play` passes); null for the usual default. */
+  startPackId: string | null
+}
+
+/** Static builds have no config route; they run on defaults and page flags only. */
+export async function loadRuntimeConfig(search: string): Promise<LoadedRuntimeConfig> {
+  let source = ''
+  let writable = false
+
+  try {
+    const response = await fetch(RUNTIME_CONFIG_URL)
+    const served =
+      response.ok &&
+      (response.headers.get('Content-Type') ?? '').startsWith(RUNTIME_CONFIG_CONTENT_TYPE)
+
+    source = served ? await response.text() : ''
+    writable = served && (response.headers.get('Allow') ?? '').includes('PUT')
+  } catch {
+    // No server route: same as no file.
+  }
+
+  const parsed = parseRuntimeConfig(source)
+  const startPackId = new URLSearchParams(search).get('pack') || null
+
+  return parsed.ok
+    ? { config: applyQueryOverrides(parsed.value, search), error: null, writable, startPackId }
+    : {
+        config: applyQueryOverrides(EMPTY_RUNTIME_CONFIG, search),
+        error: `config.toml: ${parsed.error}`,
+        writable: false,
+        startPackId,
+      }
+}
+
+export async function saveRuntimeConfig(config: RuntimeConfig): Promise<boolean> {
+  try {
+    const response = await fetch(RUNTIME_CONFIG_URL, {
+      method: 'PUT',
+      headers: { 'Content-Type': RUNTIME_CONFIG_CONTENT_TYPE },
+      body: formatRuntimeConfig(config),
+    })
+
+    return response.ok
+  } catch {
+    return false
+  }
+}
@@ -1,31 +1,26 @@
 import type { UiSettings } from './constants'
+import { buildBottomBarHints, type KeyBinding, type LevelMechanics } from './keymap'
+import { useTranslator } from './useTranslator'
 
 interface BottomHintsBarProps {
   uiSettings: UiSettings
+  keymap: KeyBinding[]
+  mechanics: LevelMechanics
+  /** Interaction waiting on more keys; shown ahead of the hints. */
+  breadcrumb: string | null
 }
 
-export function BottomHintsBar({ uiSettings }: BottomHintsBarProps) {
-  const bottomHints = uiSettings.compactHints
-    ? ['F Menu', 'G Levels', 'Tab State', 'WASD/Arrows', 'Space Rift', 'Enter Wait', 'M Settings', 'R Restart']
-    : [
-        'F Menu',
-        'G Levels',
-        '1/2/3 Mode',
-        'Tab State',
-        'WASD/Arrows Direction',
-        'Space Rift',
-        'Enter Wait',
-        'L Log',
-        'P Danger',
-        'V Pack',
-        '[ ] Rift +/-',
-        '- = Push Max +/-',
-        'M Settings',
-        'R Restart',
-      ]
+export function BottomHintsBar({ uiSettings, keymap, mechanics, breadcrumb }: BottomHintsBarProps) {
+  const t = useTranslator()
+  const bottomHints = buildBottomBarHints(keymap, mechanics, uiSettings.compactHints, t)
 
   return (
     <footer className={['bottom-bar', uiSettings.compactHints ? 'is-compact' : ''].filter(Boolean).join(' ')}>
+      {breadcrumb ? (
+        <span className="bottom-bar-breadcrumb" role="status" aria-live="polite">
+          {breadcrumb}
+        </span>
+      ) : null}
       {bottomHints.map((hint) => (
         <span key={hint}>{hint}</span>
       ))}
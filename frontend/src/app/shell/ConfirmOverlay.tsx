@@ -0,0 +1,31 @@
+import type { RefObject } from 'react'
+
+import { useTranslator } from './useTranslator'
+
+interface ConfirmOverlayProps {
+  isOpen: boolean
+  overlayRef: RefObject<HTMLElement | null>
+  message: string
+}
+
+export function ConfirmOverlay({ isOpen, overlayRef, message }: ConfirmOverlayProps) {
+  const t = useTranslator()
+
+  if (!isOpen) {
+    return null
+  }
+
+  return (
+    <div className="overlay-backdrop" role="alertdialog" aria-modal="true" aria-label="Confirm Action">
+      <section className="overlay-window confirm-window" ref={overlayRef} tabIndex={-1}>
+        <header className="overlay-header">
+          <h2>{t('confirm.title')}</h2>
+          <p>{t('confirm.controls')}</p>
+        </header>
+        <div className="overlay-body">
+          <p className="confirm-message">{message}</p>
+        </div>
+      </section>
+    </div>
+  )
+}
@@ -0,0 +1,57 @@
+import { useState, type RefObject } from 'react'
+
+import { useTranslator } from './useTranslator'
+
+interface DebugConsoleOverlayProps {
+  isOpen: boolean
+  inputRef: RefObject<HTMLInputElement | null>
+  lines: string[]
+  onSubmit: (line: string) => void
+}
+
+export function DebugConsoleOverlay({ isOpen, inputRef, lines, onSubmit }: DebugConsoleOverlayProps) {
+  const t = useTranslator()
+  const [draft, setDraft] = useState('')
+
+  if (!isOpen) {
+    return null
+  }
+
+  return (
+    <div className="overlay-backdrop" role="dialog" aria-modal="true" aria-label="Debug Console">
+      <section className="overlay-window debug-console-window">
+        <header className="overlay-header">
+          <h2>{t('debug.title')}</h2>
+          <p>{t('debug.controls')}</p>
+        </header>
+        <div className="overlay-body">
+          {lines.map((line, index) => (
+            <div className="log-row" key={index}>
+              <span className="log-text">{line}</span>
+            </div>
+          ))}
+          <form
+            onSubmit={(event) => {
+              event.preventDefault()
+
+              if (draft.trim()) {
+                onSubmit(draft)
+                setDraft('')
+              }
+            }}
+          >
+            <input
+              ref={inputRef}
+              className="debug-console-input"
+              aria-label={t('debug.input')}
+              autoComplete="off"
+              spellCheck={false}
+              value={draft}
+              onChange={(event) => setDraft(event.target.value)}
+            />
+          </form>
+        </div>
+      </section>
+    </div>
+  )
+}
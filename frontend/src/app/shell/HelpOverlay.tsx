@@ -0,0 +1,123 @@
+import type { RefObject } from 'react'
+
+import {
+  bindingDescription,
+  bindingLabel,
+  bindingsForMechanics,
+  formatBindingKeys,
+  KEY_BINDING_GROUPS,
+  keysForCommand,
+  type KeyBinding,
+  type LevelMechanics,
+} from './keymap'
+import { useTranslator } from './useTranslator'
+
+interface HelpOverlayProps {
+  isOpen: boolean
+  overlayRef: RefObject<HTMLElement | null>
+  keymap: KeyBinding[]
+  mechanics: LevelMechanics
+}
+
+export function HelpOverlay({ isOpen, overlayRef, keymap, mechanics }: HelpOverlayProps) {
+  const t = useTranslator()
+
+  if (!isOpen) {
+    return null
+  }
+
+  const bindings = bindingsForMechanics(keymap, mechanics)
+  const menuKeys = keysForCommand(keymap, 'ToggleActionMenu')
+
+  return (
+    <div className="overlay-backdrop" role="dialog" aria-modal="true" aria-label="Help">
+      <section className="overlay-window help-window" ref={overlayRef} tabIndex={-1}>
+        <header className="overlay-header">
+          <h2>{t('help.title')}</h2>
+          <p>{t('common.closeHint', { keys: keysForCommand(keymap, 'ToggleHelp') })}</p>
+        </header>
+        <div className="overlay-body">
+          {KEY_BINDING_GROUPS.map((group) => {
+            const rows = bindings.filter((binding) => binding.group === group)
+
+            if (rows.length === 0) {
+              return null
+            }
+
+            return (
+              <section className="help-group" key={group}>
+                <h3>{t(`group.${group}`)}</h3>
+                {rows.map((binding) => (
+                  <div className="help-row" key={binding.command}>
+                    <span className="help-keys">{formatBindingKeys(binding)}</span>
+                    <span className="help-label">{bindingLabel(t, binding)}</span>
+                    <span className="help-desc">{bindingDescription(t, binding)}</span>
+                  </div>
+                ))}
+              </section>
+            )
+          })}
+          <section className="help-group">
+            <h3>{t('help.levelTitle')}</h3>
+            <ul className="help-mechanics">
+              <li>{t('help.goal')}</li>
+              {mechanics.riftTiles ? (
+                <li>{t('help.riftTiles', { keys: keysForCommand(keymap, 'Rift') })}</li>
+              ) : null}
+              {mechanics.push ? (
+                <li>{t('help.push', { menuKeys, keys: keysForCommand(keymap, 'SelectPush') })}</li>
+              ) : null}
+              {mechanics.pull ? (
+                <li>{t('help.pull', { menuKeys, keys: keysForCommand(keymap, 'SelectPull') })}</li>
+              ) : null}
+              {mechanics.timePush ? (
+                <li>
+                  {t('help.timePush', { menuKeys, keys: keysForCommand(keymap, 'SelectTimePush') })}
+                </li>
+              ) : null}
+              {mechanics.slippery ? <li>{t('help.slippery')}</li> : null}
+              {mechanics.water ? <li>{t('help.water')}</li> : null}
+              {mechanics.shadows ? <li>{t('help.shadows')}</li> : null}
+              {mechanics.lures ? (
+                <li>{t('help.lures', { menuKeys, keys: keysForCommand(keymap, 'SelectThrow') })}</li>
+              ) : null}
+              {mechanics.breakables ? (
+                <li>
+                  {t('help.breakables', { menuKeys, keys: keysForCommand(keymap, 'SelectBreak') })}
+                </li>
+              ) : null}
+              {mechanics.interactables ? (
+                <li>
+                  {t('help.interactables', {
+                    menuKeys,
+                    keys: keysForCommand(keymap, 'SelectInteract'),
+                  })}
+                </li>
+              ) : null}
+              {mechanics.knockouts ? (
+                <li>
+                  {t('help.knockouts', {
+                    menuKeys,
+                    keys: keysForCommand(keymap, 'SelectInteract'),
+                  })}
+                </li>
+              ) : null}
+              {mechanics.peek ? (
+                <li>{t('help.peek', { menuKeys, keys: keysForCommand(keymap, 'SelectPeek') })}</li>
+              ) : null}
+              {mechanics.characters ? (
+                <li>{t('help.characters', { keys: keysForCommand(keymap, 'SwitchCharacter') })}</li>
+              ) : null}
+              {mechanics.elevation ? (
+                <li>{t('help.elevation', { keys: keysForCommand(keymap, 'Climb') })}</li>
+              ) : null}
+              {mechanics.characters ? (
+                <li>{t('help.hotseat', { keys: keysForCommand(keymap, 'ToggleHotseat') })}</li>
+              ) : null}
+            </ul>
+          </section>
+        </div>
+      </section>
+    </div>
+  )
+}
@@ -1,8 +1,25 @@
+import type { Layer } from '../../core/elevation'
+import type { Position2D } from '../../core/position'
+import type { TimelineSet } from '../../core/timelineSet'
 import type { DirectionalActionMode } from '../inputStateMachine'
+import type { LevelDisplayMeta } from '../../data/loader'
+import type { MutatorId } from '../../data/mutators'
+import type { PlayDifficulty } from '../../data/playDifficulty'
 import type { GamePhase } from '../../game/gameSlice'
+import { componentSummary, type EntityInspection } from '../../game/inspection'
+import type { LegendEntry } from '../../render/board/legend'
 import type { DirectionalOption } from './constants'
+import type { ExitCompass } from './exitCompass'
+import { bindingLabel, keysForCommand, type KeyBinding, type KeyCommand } from './keymap'
+import { LegendWindow } from './LegendWindow'
+import { PerfWindow, type PerfHud } from './PerfWindow'
+import { formatElapsed } from './speedrun'
+import { useTranslator } from './useTranslator'
+
+const COMMAND_SUMMARY: KeyCommand[] = ['ToggleActionMenu', 'ToggleLevels', 'Rift', 'Wait', 'Restart']
 
 interface HudPanelsProps {
+  keymap: KeyBinding[]
   directionalActionMode: DirectionalActionMode
   isActionMenuOpen: boolean
   directionalOptions: DirectionalOption[]
@@ -11,10 +28,58 @@ interface HudPanelsProps {
   phase: GamePhase
   riftDefaultDelta: number
   showDangerPreview: boolean
+  hintsUsed: number
+  hintBudget: number
+  playDifficulty: PlayDifficulty
+  /** Name of the active rule profile; null under the standard rules. */
+  ruleProfileName: string | null
+  /** Challenge mutators the level was loaded with. */
+  mutators: MutatorId[]
+  /** Null when the rule profile allows unlimited restarts. */
+  restartsRemaining: number | null
+  /** Null when the level hands out no lures. */
+  luresRemaining: number | null
+  /** Null on levels that grant no peeks. */
+  peeksRemaining: number | null
+  /** Null on single-character levels. */
+  activeCharacterId: string | null
+  /** Seat whose turn it is in hotseat play; null otherwise. */
+  hotseatSeat: number | null
+  /** Name of the level region the player stands in, if any. */
+  regionName: string | null
+  /** Layer the player walks on; null on levels without catwalks. */
+  playerLayer: Layer | null
+  /** Nearest exit when it is far or out of view; null otherwise or with the compass off. */
+  exitCompass: ExitCompass | null
+  /** Turns held on the extraction point under the player; null off one. */
+  extraction: { held: number; needed: number } | null
+  /** Suspicion toward the alarm and the end of the lockdown in force; null without an alarm. */
+  alarm: { suspicion: number; threshold: number; lockdownUntil: number | null } | null
+  /** Branching mode's timelines; null under rules that overwrite the past. */
+  timelines: TimelineSet | null
+  /** Branch the board currently shows. */
+  viewedTimelineId: string | null
+  /** Cell under the examine cursor; null outside examine mode. */
+  examineCursor: Position2D | null
+  /** What stands under the examine cursor in the viewed slice. */
+  examinedEntities: EntityInspection[] | null
+  /** Real time of the current run; null while the speedrun timer is off. */
+  speedrunElapsedMs: number | null
+  /** Set once the level is won; shown in the victory window. */
+  levelMeta: LevelDisplayMeta | null
+  /** Lines comparing this win with the previous best run; null on a first win. */
+  bestComparison: string[] | null
+  /** Shows the permadeath warning above the status line. */
+  ironmanCampaign: boolean
+  /** Marks and object looks the board can show on this level. */
+  legend: LegendEntry[]
+  /** Debug performance readout; null unless turned on from the debug console. */
+  perf: PerfHud | null
   status: string
 }
 
 export function HudPanels({
+  keymap,
   directionalActionMode,
   isActionMenuOpen,
   directionalOptions,
@@ -23,20 +88,53 @@ export function HudPanels({
   phase,
   riftDefaultDelta,
   showDangerPreview,
+  hintsUsed,
+  hintBudget,
+  playDifficulty,
+  ruleProfileName,
+  mutators,
+  restartsRemaining,
+  luresRemaining,
+  peeksRemaining,
+  activeCharacterId,
+  hotseatSeat,
+  regionName,
+  playerLayer,
+  exitCompass,
+  extraction,
+  alarm,
+  timelines,
+  viewedTimelineId,
+  examineCursor,
+  examinedEntities,
+  speedrunElapsedMs,
+  levelMeta,
+  bestComparison,
+  ironmanCampaign,
+  legend,
+  perf,
   status,
 }: HudPanelsProps) {
+  const t = useTranslator()
+
   return (
     <aside className="hud-stack" aria-label="HUD Panel">
       <section className="ui-window command-window" aria-label="Command Window">
-        <h2 className="ui-window-title">Command</h2>
+        <h2 className="ui-window-title">{t('hud.command')}</h2>
         <div className="ui-window-body">
-          <p className="window-note">Mode: {directionalActionMode}</p>
+          <p className="window-note">
+            {t('hud.mode', { mode: t(`mode.${directionalActionMode}`) })}
+          </p>
           <div className="command-meta command-meta-compact">
-            <span>F Menu</span>
-            <span>G Levels</span>
-            <span>Space Rift</span>
-            <span>Enter Wait</span>
-            <span>R Restart</span>
+            {COMMAND_SUMMARY.map((command) => {
+              const binding = keymap.find((candidate) => candidate.command === command)
+
+              return binding ? (
+                <span key={command}>
+                  {keysForCommand(keymap, command)} {bindingLabel(t, binding)}
+                </span>
+              ) : null
+            })}
           </div>
           {isActionMenuOpen ? (
             <div className="command-list">
@@ -52,8 +150,8 @@ export function HudPanels({
                     .join(' ')}
                 >
                   <span className="command-key">{option.keyLabel}</span>
-                  <span className="command-text">{option.mode}</span>
-                  <span className="command-desc">{option.description}</span>
+                  <span className="command-text">{t(`mode.${option.mode}`)}</span>
+                  <span className="command-desc">{t(`mode.${option.mode}.description`)}</span>
                 </div>
               ))}
             </div>
@@ -62,37 +160,271 @@ export function HudPanels({
       </section>
 
       <section className="ui-window state-window" aria-label="State Window">
-        <h2 className="ui-window-title">State</h2>
+        <h2 className="ui-window-title">{t('hud.state')}</h2>
         <div className="ui-window-body">
           <div className="metric-grid">
             <div className="metric-item">
-              <span className="metric-label">Turn</span>
+              <span className="metric-label">{t('hud.turn')}</span>
               <span className="metric-value">{turn}</span>
             </div>
             <div className="metric-item">
-              <span className="metric-label">Time</span>
+              <span className="metric-label">{t('hud.time')}</span>
               <span className="metric-value">{currentTime}</span>
             </div>
+            {speedrunElapsedMs !== null ? (
+              <div className="metric-item">
+                <span className="metric-label">{t('hud.timer')}</span>
+                <span className="metric-value">{formatElapsed(speedrunElapsedMs)}</span>
+              </div>
+            ) : null}
             <div className="metric-item">
-              <span className="metric-label">Phase</span>
-              <span className="metric-value">{phase}</span>
+              <span className="metric-label">{t('hud.phase')}</span>
+              <span className="metric-value">{t(`phase.${phase}`)}</span>
             </div>
             <div className="metric-item">
-              <span className="metric-label">Rift Delta</span>
+              <span className="metric-label">{t('hud.riftDelta')}</span>
               <span className="metric-value">-{riftDefaultDelta}</span>
             </div>
             <div className="metric-item">
-              <span className="metric-label">Danger</span>
-              <span className="metric-value">{showDangerPreview ? 'on' : 'off'}</span>
+              <span className="metric-label">{t('hud.danger')}</span>
+              <span className="metric-value">{t(showDangerPreview ? 'common.on' : 'common.off')}</span>
+            </div>
+            <div className="metric-item">
+              <span className="metric-label">{t('hud.hints')}</span>
+              <span className="metric-value">{hintBudget - hintsUsed}/{hintBudget}</span>
+            </div>
+            <div className="metric-item">
+              <span className="metric-label">{t('hud.difficulty')}</span>
+              <span className="metric-value">{t(`playDifficulty.${playDifficulty}`)}</span>
             </div>
+            {ruleProfileName !== null ? (
+              <div className="metric-item">
+                <span className="metric-label">{t('hud.rules')}</span>
+                <span className="metric-value">{ruleProfileName}</span>
+              </div>
+            ) : null}
+            {mutators.length > 0 ? (
+              <div className="metric-item">
+                <span className="metric-label">{t('hud.mutators')}</span>
+                <span className="metric-value">
+                  {mutators.map((id) => t(`mutator.${id}.label`)).join(', ')}
+                </span>
+              </div>
+            ) : null}
+            {restartsRemaining !== null ? (
+              <div className="metric-item">
+                <span className="metric-label">{t('hud.restarts')}</span>
+                <span className="metric-value">{restartsRemaining}</span>
+              </div>
+            ) : null}
+            {luresRemaining !== null ? (
+              <div className="metric-item">
+                <span className="metric-label">{t('hud.lures')}</span>
+                <span className="metric-value">{luresRemaining}</span>
+              </div>
+            ) : null}
+            {peeksRemaining !== null ? (
+              <div className="metric-item">
+                <span className="metric-label">{t('hud.peeks')}</span>
+                <span className="metric-value">{peeksRemaining}</span>
+              </div>
+            ) : null}
+            {activeCharacterId !== null ? (
+              <div className="metric-item">
+                <span className="metric-label">{t('hud.character')}</span>
+                <span className="metric-value">{activeCharacterId}</span>
+              </div>
+            ) : null}
+            {hotseatSeat !== null ? (
+              <div className="metric-item">
+                <span className="metric-label">{t('hud.seat')}</span>
+                <span className="metric-value">{t('hud.seatValue', { seat: hotseatSeat })}</span>
+              </div>
+            ) : null}
+            {regionName !== null ? (
+              <div className="metric-item">
+                <span className="metric-label">{t('hud.region')}</span>
+                <span className="metric-value">{regionName}</span>
+              </div>
+            ) : null}
+            {playerLayer !== null ? (
+              <div className="metric-item">
+                <span className="metric-label">{t('hud.layer')}</span>
+                <span className="metric-value">{t(`layer.${playerLayer}`)}</span>
+              </div>
+            ) : null}
+            {exitCompass !== null ? (
+              <div className="metric-item">
+                <span className="metric-label">{t('hud.exit')}</span>
+                <span className="metric-value">
+                  {exitCompass.point
+                    ? t('hud.exitHeading', {
+                        point: t(`compass.${exitCompass.point}`),
+                        distance: exitCompass.distance,
+                      })
+                    : t('hud.exitHere')}
+                </span>
+              </div>
+            ) : null}
+            {extraction !== null ? (
+              <div className="metric-item">
+                <span className="metric-label">{t('hud.extraction')}</span>
+                <span
+                  className="metric-value"
+                  aria-label={t('hud.extractionProgress', extraction)}
+                >
+                  {Array.from({ length: extraction.needed }, (_, index) =>
+                    index < extraction.held ? '●' : '○',
+                  ).join('')}
+                </span>
+              </div>
+            ) : null}
+            {alarm !== null ? (
+              <div className="metric-item">
+                <span className="metric-label">{t('hud.alarm')}</span>
+                <span className="metric-value">
+                  {alarm.lockdownUntil !== null
+                    ? t('hud.lockdown', { time: alarm.lockdownUntil })
+                    : t('hud.suspicion', {
+                        suspicion: alarm.suspicion,
+                        threshold: alarm.threshold,
+                      })}
+                </span>
+              </div>
+            ) : null}
           </div>
-          <p className="window-note state-zoom-note">Tab: details</p>
+          <p className="window-note state-zoom-note">
+            {t('hud.stateDetailsHint', { keys: keysForCommand(keymap, 'ToggleState') })}
+          </p>
         </div>
       </section>
 
+      {timelines ? (
+        <section className="ui-window timeline-window" aria-label="Timeline Window">
+          <h2 className="ui-window-title">{t('hud.timelines')}</h2>
+          <div className="ui-window-body">
+            <div className="command-list">
+              {timelines.branches.map((branch) => (
+                <div
+                  key={branch.id}
+                  className={[
+                    'command-row',
+                    branch.id === (viewedTimelineId ?? timelines.activeId) ? 'is-selected' : '',
+                  ]
+                    .filter(Boolean)
+                    .join(' ')}
+                >
+                  <span className="command-key">{branch.id === timelines.activeId ? '>' : ''}</span>
+                  <span className="command-text">{branch.id}</span>
+                  <span className="command-desc">
+                    {t('hud.timelineRow', {
+                      forkTime: branch.forkTime,
+                      state: t(branch.consistent ? 'timeline.consistent' : 'timeline.inconsistent'),
+                    })}
+                  </span>
+                </div>
+              ))}
+            </div>
+            <p className="window-note">
+              {t('hud.timelinesHint', { keys: keysForCommand(keymap, 'CycleTimeline') })}
+            </p>
+          </div>
+        </section>
+      ) : null}
+
+      {examineCursor && examinedEntities ? (
+        <section className="ui-window examine-window" aria-label="Examine Window">
+          <h2 className="ui-window-title">
+            {t('hud.examine', { x: examineCursor.x, y: examineCursor.y, time: currentTime })}
+          </h2>
+          <div className="ui-window-body">
+            {examinedEntities.length === 0 ? (
+              <p className="window-note">{t('hud.examineEmpty')}</p>
+            ) : null}
+            {examinedEntities.map((entity) => (
+              <div key={entity.id} className="examine-entity">
+                <p className="window-note">
+                  <strong>{entity.id}</strong> ({entity.archetypeKind})
+                </p>
+                <div className="command-list">
+                  {entity.components.map((component, index) => (
+                    <div key={`${component.kind}-${index}`} className="command-row">
+                      <span className="command-text">{componentSummary(component)}</span>
+                    </div>
+                  ))}
+                </div>
+                {entity.route ? (
+                  <p className="window-note">
+                    {t('hud.examineRoute', {
+                      route: entity.route.map((point) => `(${point.x},${point.y})`).join(' '),
+                    })}
+                  </p>
+                ) : null}
+                {entity.schedule.length > 0 ? (
+                  <p className="window-note">
+                    {t('hud.examineSchedule', {
+                      schedule: entity.schedule
+                        .map((point) => `t${point.t}:(${point.x},${point.y})`)
+                        .join(' '),
+                    })}
+                  </p>
+                ) : null}
+                {entity.rift ? (
+                  <p className="window-note">
+                    {t(entity.rift.bidirectional ? 'hud.examineRiftBoth' : 'hud.examineRift', {
+                      x: entity.rift.target.x,
+                      y: entity.rift.target.y,
+                      time: entity.rift.target.t,
+                    })}
+                  </p>
+                ) : null}
+              </div>
+            ))}
+            <p className="window-note">
+              {t('hud.examineHint', { keys: keysForCommand(keymap, 'ToggleExamine') })}
+            </p>
+          </div>
+        </section>
+      ) : null}
+
+      {levelMeta || bestComparison ? (
+        <section className="ui-window victory-window" aria-label="Victory Window">
+          <h2 className="ui-window-title">{t('hud.victory')}</h2>
+          <div className="ui-window-body">
+            {levelMeta ? (
+              <p className="window-note">
+                {levelMeta.author
+                  ? t('hud.victoryByAuthor', { name: levelMeta.name, author: levelMeta.author })
+                  : t('hud.victoryLevel', { name: levelMeta.name })}
+              </p>
+            ) : null}
+            {levelMeta?.description ? (
+              <p className="window-note">{levelMeta.description}</p>
+            ) : null}
+            {levelMeta && (levelMeta.difficulty || levelMeta.tags?.length) ? (
+              <p className="window-note">
+                {[levelMeta.difficulty, ...(levelMeta.tags ?? [])].filter(Boolean).join(' · ')}
+              </p>
+            ) : null}
+            {bestComparison?.map((line) => (
+              <p className="window-note" key={line}>
+                {line}
+              </p>
+            ))}
+          </div>
+        </section>
+      ) : null}
+
+      {perf ? <PerfWindow perf={perf} /> : null}
+
+      <LegendWindow entries={legend} />
+
       <section className="ui-window log-window" aria-label="Log Window">
-        <h2 className="ui-window-title">Log</h2>
+        <h2 className="ui-window-title">{t('hud.log')}</h2>
         <div className="ui-window-body log-body-compact">
+          {ironmanCampaign ? (
+            <p className="window-note ironman-warning">{t('ironman.warning')}</p>
+          ) : null}
           <p className="window-note status-line" role="status" aria-live="polite">
             {status}
           </p>
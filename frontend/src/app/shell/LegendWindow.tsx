@@ -0,0 +1,35 @@
+import type { LegendEntry } from '../../render/board/legend'
+import { useTranslator } from './useTranslator'
+
+interface LegendWindowProps {
+  entries: LegendEntry[]
+}
+
+/** Collapsed by default; lists what the board draws on this level and how. */
+export function LegendWindow({ entries }: LegendWindowProps) {
+  const t = useTranslator()
+
+  return (
+    <details className="ui-window legend-window" aria-label="Legend Window">
+      <summary className="ui-window-title">{t('hud.legend')}</summary>
+      <div className="ui-window-body">
+        <ul className="legend-list">
+          {entries.map((entry) => (
+            <li className="legend-row" key={`${entry.kind}:${entry.id}`}>
+              <span
+                className="legend-swatch"
+                style={{ background: entry.fill, borderColor: entry.stroke }}
+                aria-hidden="true"
+              >
+                {entry.glyph}
+              </span>
+              <span className="legend-label">
+                {entry.kind === 'mark' ? t(`legend.${entry.id}`) : entry.name}
+              </span>
+            </li>
+          ))}
+        </ul>
+      </div>
+    </details>
+  )
+}
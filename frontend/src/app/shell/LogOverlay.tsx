@@ -2,6 +2,7 @@ import type { RefObject } from 'react'
 
 import type { InteractionHistoryEntry } from '../../game/gameSlice'
 import { actionSummary } from './actionSummary'
+import { useTranslator } from './useTranslator'
 
 interface LogOverlayProps {
   isOpen: boolean
@@ -10,6 +11,8 @@ interface LogOverlayProps {
 }
 
 export function LogOverlay({ isOpen, overlayRef, history }: LogOverlayProps) {
+  const t = useTranslator()
+
   if (!isOpen) {
     return null
   }
@@ -18,12 +21,12 @@ export function LogOverlay({ isOpen, overlayRef, history }: LogOverlayProps) {
     <div className="overlay-backdrop" role="dialog" aria-modal="true" aria-label="Action Log">
       <section className="overlay-window" ref={overlayRef} tabIndex={-1}>
         <header className="overlay-header">
-          <h2>Action Log</h2>
-          <p>L / Esc: close</p>
+          <h2>{t('log.title')}</h2>
+          <p>{t('common.closeHint', { keys: 'L' })}</p>
         </header>
         <div className="overlay-body">
           {history.length === 0 ? (
-            <p className="empty-log">No actions yet.</p>
+            <p className="empty-log">{t('log.empty')}</p>
           ) : (
             history
               .slice()
@@ -31,7 +34,7 @@ export function LogOverlay({ isOpen, overlayRef, history }: LogOverlayProps) {
               .map((entry) => (
                 <div className="log-row" key={`${entry.turn}-${entry.action.kind}`}>
                   <span className="log-turn">T{entry.turn}</span>
-                  <span className="log-text">{actionSummary(entry)}</span>
+                  <span className="log-text">{actionSummary(t, entry)}</span>
                 </div>
               ))
           )}
@@ -0,0 +1,67 @@
+import type { PerfSample } from './perfHud'
+import { useTranslator } from './useTranslator'
+
+export interface PerfHud {
+  /** Null until an action has run with the HUD on. */
+  sample: PerfSample | null
+  entitiesPerSlice: number[]
+}
+
+interface PerfWindowProps {
+  perf: PerfHud
+}
+
+function formatKilobytes(bytes: number): string {
+  return `${(bytes / 1024).toFixed(1)} KB`
+}
+
+/** Debug-only window (`perf on` in the console) for measuring the pipeline while playing. */
+export function PerfWindow({ perf }: PerfWindowProps) {
+  const t = useTranslator()
+  const { sample } = perf
+
+  return (
+    <section className="ui-window perf-window" aria-label="Performance Window">
+      <h2 className="ui-window-title">{t('hud.perf')}</h2>
+      <div className="ui-window-body">
+        {sample ? (
+          <div className="metric-grid">
+            <div className="metric-item">
+              <span className="metric-label">{t('perf.apply')}</span>
+              <span className="metric-value">{sample.applyUs} µs</span>
+            </div>
+            <div className="metric-item">
+              <span className="metric-label">{t('perf.slicesUpdated')}</span>
+              <span className="metric-value">{sample.slicesUpdated}</span>
+            </div>
+            <div className="metric-item">
+              <span className="metric-label">{t('perf.propagationWarnings')}</span>
+              <span className="metric-value">{sample.propagationWarnings}</span>
+            </div>
+            <div className="metric-item">
+              <span className="metric-label">{t('perf.stateSize')}</span>
+              <span className="metric-value">{formatKilobytes(sample.stateBytes)}</span>
+            </div>
+            <div className="metric-item">
+              <span className="metric-label">{t('perf.cubeSize')}</span>
+              <span className="metric-value">{formatKilobytes(sample.cubeBytes)}</span>
+            </div>
+            {sample.spans.map((entry) => (
+              <div className="metric-item" key={entry.span}>
+                <span className="metric-label">{entry.span}</span>
+                <span className="metric-value">{entry.us} µs</span>
+              </div>
+            ))}
+          </div>
+        ) : (
+          <p className="window-note">{t('perf.waiting')}</p>
+        )}
+        <p className="window-note perf-slices">
+          {t('perf.entities', {
+            counts: perf.entitiesPerSlice.map((count, time) => `t${time}:${count}`).join(' '),
+          })}
+        </p>
+      </div>
+    </section>
+  )
+}
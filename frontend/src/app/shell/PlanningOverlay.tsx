@@ -0,0 +1,74 @@
+import type { RefObject } from 'react'
+
+import type { PlannedStepPreview } from '../../game/turnPlan'
+import type { Translator } from '../../render/i18n'
+import { actionText } from './actionSummary'
+import { useTranslator } from './useTranslator'
+
+interface PlanningOverlayProps {
+  isOpen: boolean
+  overlayRef: RefObject<HTMLElement | null>
+  steps: PlannedStepPreview[]
+}
+
+function stepText(t: Translator, step: PlannedStepPreview): string {
+  const action = actionText(t, step.action)
+
+  if (!step.committed || !step.position) {
+    return t('plan.rejected', { action, status: step.status })
+  }
+
+  const parts = [t('plan.step', { action, ...step.position })]
+
+  if (step.seenBy.length > 0) {
+    parts.push(t('plan.seenBy', { enemies: step.seenBy.join(', ') }))
+  }
+
+  if (step.phase !== 'Playing') {
+    parts.push(t('plan.ends', { phase: t(`phase.${step.phase}`) }))
+  }
+
+  return parts.join('; ')
+}
+
+export function PlanningOverlay({ isOpen, overlayRef, steps }: PlanningOverlayProps) {
+  const t = useTranslator()
+
+  if (!isOpen) {
+    return null
+  }
+
+  // Docked to the side so the planned outcome stays visible on the board.
+  return (
+    <div
+      className="overlay-backdrop overlay-backdrop--docked"
+      role="dialog"
+      aria-modal="true"
+      aria-label="Turn Plan"
+    >
+      <section className="overlay-window" ref={overlayRef} tabIndex={-1}>
+        <header className="overlay-header">
+          <h2>{t('plan.title')}</h2>
+          <p>{t('plan.controls')}</p>
+        </header>
+        <div className="overlay-body">
+          {steps.length === 0 ? (
+            <p className="empty-log">{t('plan.empty')}</p>
+          ) : (
+            steps.map((step, index) => (
+              <div
+                className={
+                  step.committed && step.seenBy.length === 0 ? 'log-row' : 'log-row plan-row--warn'
+                }
+                key={index}
+              >
+                <span className="log-turn">{index + 1}</span>
+                <span className="log-text">{stepText(t, step)}</span>
+              </div>
+            ))
+          )}
+        </div>
+      </section>
+    </div>
+  )
+}
@@ -1,8 +1,29 @@
 import type { RefObject } from 'react'
 
-import type { ProgressionManifest } from '../../data/progression'
+import type { CommunityLevelEntry } from '../../data/loader'
+import { MUTATOR_IDS, toggleMutator, type MutatorId } from '../../data/mutators'
+import type { ProgressionManifest, ProgressionTrack } from '../../data/progression'
+import {
+  cycleFilterValue,
+  entryDifficulty,
+  entryTags,
+  levelFilterOptions,
+  visibleEntryIndices,
+  type LevelFilter,
+  LEVEL_SELECT_TABS,
+  type LevelSelectTab,
+} from './levelFilter'
+import { formatElapsed, trackSplits } from './speedrun'
 import type { PackDisplayMeta } from './useContentPackLoading'
+import {
+  IRONMAN_ACHIEVEMENTS,
+  unlockedIronmanAchievements,
+  type IronmanStats,
+} from './useIronmanCampaign'
 import type { ProgressionSnapshot } from './useProgressionState'
+import type { ReplaySnapshot } from './useReplayStore'
+import type { SavedSession } from './useSessionAutosave'
+import { useTranslator } from './useTranslator'
 
 interface ProgressionOverlayProps {
   isOpen: boolean
@@ -11,10 +32,30 @@ interface ProgressionOverlayProps {
   progressionState: ProgressionSnapshot | null
   progressionError: string | null
   packMetaById: Record<string, PackDisplayMeta>
+  levelFilter: LevelFilter
+  onChangeLevelFilter: (filter: LevelFilter) => void
+  levelSelectTab: LevelSelectTab
+  onChangeLevelSelectTab: (tab: LevelSelectTab) => void
+  communityLevels: CommunityLevelEntry[]
+  communityIndex: number
+  replaySnapshot: ReplaySnapshot
+  /** When set, the tracks tab shows the iron-man campaign's own progress. */
+  ironmanCampaign: boolean
+  ironmanStats: IronmanStats
+  onSelectCommunityIndex: (index: number) => void
+  /** Mutators the next level load (and the current level, restarted) plays under. */
+  mutators: MutatorId[]
+  dailyMutators: MutatorId[]
+  challengeIndex: number
+  onSelectChallengeIndex: (index: number) => void
+  onChangeMutators: (mutators: MutatorId[]) => void
   currentContentPackId: string
   onSelectTrack: (trackId: string) => void
   onSelectEntryIndex: (index: number) => void
   onLoadPack: (packId: string) => void
+  /** Autosaved run offered above the level list; null when there is none. */
+  savedSession: SavedSession | null
+  onResumeSession: () => void
 }
 
 export function ProgressionOverlay({
@@ -24,28 +65,134 @@ export function ProgressionOverlay({
   progressionState,
   progressionError,
   packMetaById,
+  levelFilter,
+  onChangeLevelFilter,
+  levelSelectTab,
+  onChangeLevelSelectTab,
+  communityLevels,
+  communityIndex,
+  replaySnapshot,
+  ironmanCampaign,
+  ironmanStats,
+  onSelectCommunityIndex,
+  mutators,
+  dailyMutators,
+  challengeIndex,
+  onSelectChallengeIndex,
+  onChangeMutators,
   currentContentPackId,
   onSelectTrack,
   onSelectEntryIndex,
   onLoadPack,
+  savedSession,
+  onResumeSession,
 }: ProgressionOverlayProps) {
+  const t = useTranslator()
+
   if (!isOpen) {
     return null
   }
 
+  const tabBar = (
+    <>
+      <div className="progression-track-bar progression-tab-bar" role="tablist">
+        {LEVEL_SELECT_TABS.map((tab) => (
+          <button
+            key={tab}
+            type="button"
+            role="tab"
+            aria-selected={levelSelectTab === tab}
+            className={[
+              'progression-track-button',
+              levelSelectTab === tab ? 'is-selected' : '',
+            ]
+              .filter(Boolean)
+              .join(' ')}
+            onClick={() => {
+              onChangeLevelSelectTab(tab)
+            }}
+          >
+            {tab === 'tracks'
+              ? t('progression.tabTracks')
+              : tab === 'community'
+                ? t('progression.tabCommunity', { count: communityLevels.length })
+                : tab === 'records'
+                  ? t('progression.tabRecords')
+                  : t('progression.tabChallenge', { count: mutators.length })}
+          </button>
+        ))}
+      </div>
+      {savedSession ? (
+        <button type="button" className="progression-track-button" onClick={onResumeSession}>
+          {t('progression.resumeSession', {
+            name: packMetaById[savedSession.packId]?.name ?? savedSession.packId,
+            turn: savedSession.turn,
+          })}
+        </button>
+      ) : null}
+    </>
+  )
+
+  if (levelSelectTab === 'community') {
+    return (
+      <div className="overlay-backdrop" role="dialog" aria-modal="true" aria-label="Progression">
+        <section className="overlay-window progression-window" ref={overlayRef} tabIndex={-1}>
+          <header className="overlay-header">
+            <h2>{t('progression.title')}</h2>
+            <p>{t('progression.communityControls')}</p>
+          </header>
+          <div className="overlay-body progression-body">
+            {tabBar}
+            <CommunityLevelList
+              levels={communityLevels}
+              selectedIndex={communityIndex}
+              currentContentPackId={currentContentPackId}
+              onSelectIndex={onSelectCommunityIndex}
+              onLoadPack={onLoadPack}
+            />
+          </div>
+        </section>
+      </div>
+    )
+  }
+
+  if (levelSelectTab === 'challenge') {
+    return (
+      <div className="overlay-backdrop" role="dialog" aria-modal="true" aria-label="Progression">
+        <section className="overlay-window progression-window" ref={overlayRef} tabIndex={-1}>
+          <header className="overlay-header">
+            <h2>{t('progression.title')}</h2>
+            <p>{t('progression.challengeControls')}</p>
+          </header>
+          <div className="overlay-body progression-body">
+            {tabBar}
+            <ChallengeBoard
+              mutators={mutators}
+              dailyMutators={dailyMutators}
+              selectedIndex={challengeIndex}
+              onSelectIndex={onSelectChallengeIndex}
+              onChangeMutators={onChangeMutators}
+            />
+          </div>
+        </section>
+      </div>
+    )
+  }
+
   if (!progressionManifest || !progressionState) {
     return (
       <div className="overlay-backdrop" role="dialog" aria-modal="true" aria-label="Progression">
         <section className="overlay-window progression-window" ref={overlayRef} tabIndex={-1}>
           <header className="overlay-header">
-            <h2>Progression</h2>
-            <p>G / Esc: close</p>
+            <h2>{t('progression.title')}</h2>
+            <p>{t('common.closeHint', { keys: 'G' })}</p>
           </header>
           <div className="overlay-body progression-body">
+            {tabBar}
             <p className="empty-log">
               {progressionError
-                ? `Progression unavailable (${progressionError}).`
-                : 'Loading progression...'}
+                ? t('progression.unavailable', { error: progressionError })
+                : t('progression.loading')}
             </p>
           </div>
         </section>
@@ -53,6 +200,24 @@ export function ProgressionOverlay({
     )
   }
 
+  if (levelSelectTab === 'records') {
+    return (
+      <div className="overlay-backdrop" role="dialog" aria-modal="true" aria-label="Progression">
+        <section className="overlay-window progression-window" ref={overlayRef} tabIndex={-1}>
+          <header className="overlay-header">
+            <h2>{t('progression.title')}</h2>
+            <p>{t('progression.recordsControls')}</p>
+          </header>
+          <div className="overlay-body progression-body">
+            {tabBar}
+            <RecordsBoard tracks={progressionManifest.tracks} replaySnapshot={replaySnapshot} />
+            <IronmanBoard stats={ironmanStats} />
+          </div>
+        </section>
+      </div>
+    )
+  }
+
   const selectedTrack =
     progressionManifest.tracks.find((track) => track.id === progressionState.selectedTrackId) ??
     progressionManifest.tracks[0] ??
@@ -69,14 +234,25 @@ export function ProgressionOverlay({
       )
     : 0
 
+  const filterOptions = levelFilterOptions(selectedTrack?.entries ?? [], packMetaById)
+  const visibleIndices = selectedTrack
+    ? visibleEntryIndices(selectedTrack.entries, packMetaById, levelFilter)
+    : []
+  const selectedPackId = selectedTrack?.entries[selectedEntryIndex]?.packId
+  const selectedDescription = selectedPackId ? packMetaById[selectedPackId]?.description : undefined
+
   return (
     <div className="overlay-backdrop" role="dialog" aria-modal="true" aria-label="Progression">
       <section className="overlay-window progression-window" ref={overlayRef} tabIndex={-1}>
         <header className="overlay-header">
-          <h2>Progression</h2>
-          <p>G / Esc close | Arrows navigate | Enter load</p>
+          <h2>{t('progression.title')}</h2>
+          <p>{t('progression.controls')}</p>
         </header>
         <div className="overlay-body progression-body">
+          {tabBar}
+          {ironmanCampaign ? (
+            <p className="window-note ironman-warning">{t('ironman.warning')}</p>
+          ) : null}
           {selectedTrack ? (
             <>
               <div className="progression-track-bar">
@@ -95,10 +271,14 @@ export function ProgressionOverlay({
                     onSelectTrack(progressionManifest.tracks[previousIndex].id)
                   }}
                 >
-                  Prev
+                  {t('progression.prev')}
                 </button>
                 <p className="progression-track-label">
-                  Track {selectedTrackIndex + 1}/{progressionManifest.tracks.length}: {selectedTrack.id}
+                  {t('progression.track', {
+                    index: selectedTrackIndex + 1,
+                    count: progressionManifest.tracks.length,
+                    trackId: selectedTrack.id,
+                  })}
                 </p>
                 <button
                   type="button"
@@ -115,20 +295,81 @@ export function ProgressionOverlay({
                     onSelectTrack(progressionManifest.tracks[nextIndex].id)
                   }}
                 >
-                  Next
+                  {t('progression.next')}
+                </button>
+              </div>
+
+              <div className="progression-track-bar progression-filter-bar">
+                <button
+                  type="button"
+                  className="progression-track-button"
+                  onClick={() => {
+                    onChangeLevelFilter({
+                      ...levelFilter,
+                      difficulty: cycleFilterValue(
+                        filterOptions.difficulties,
+                        levelFilter.difficulty,
+                      ),
+                    })
+                  }}
+                >
+                  {t('progression.filterDifficulty', {
+                    value: levelFilter.difficulty ?? t('progression.filterAll'),
+                  })}
+                </button>
+                <button
+                  type="button"
+                  className="progression-track-button"
+                  onClick={() => {
+                    onChangeLevelFilter({
+                      ...levelFilter,
+                      tag: cycleFilterValue(filterOptions.tags, levelFilter.tag),
+                    })
+                  }}
+                >
+                  {t('progression.filterTag', {
+                    value: levelFilter.tag ?? t('progression.filterAll'),
+                  })}
+                </button>
+                <button
+                  type="button"
+                  className="progression-track-button"
+                  onClick={() => {
+                    onChangeLevelFilter({
+                      ...levelFilter,
+                      sort: levelFilter.sort === 'track' ? 'difficulty' : 'track',
+                    })
+                  }}
+                >
+                  {t(
+                    levelFilter.sort === 'track'
+                      ? 'progression.sortTrack'
+                      : 'progression.sortDifficulty',
+                  )}
                 </button>
               </div>
 
               <div className="progression-entry-list">
-                {selectedTrack.entries.map((entry, index) => {
+                {visibleIndices.length === 0 ? (
+                  <p className="empty-log">{t('progression.noMatches')}</p>
+                ) : null}
+                {visibleIndices.map((index) => {
+                  const entry = selectedTrack.entries[index]
                   const unlocked = progressionState.unlockedPackIds.includes(entry.packId)
                   const completed = progressionState.completedPackIds.includes(entry.packId)
                   const isSelected = index === selectedEntryIndex
                   const isCurrent = entry.packId === currentContentPackId
                   const packMeta = packMetaById[entry.packId]
-                  const difficulty = entry.difficulty ?? packMeta?.difficulty ?? 'n/a'
-                  const packClass = packMeta?.class ?? 'n/a'
-                  const stateLabel = completed ? 'complete' : unlocked ? 'unlocked' : 'locked'
+                  const difficulty = entryDifficulty(entry, packMeta) ?? t('common.na')
+                  const tags = entryTags(entry, packMeta)
+                  const packClass = packMeta?.class ?? t('common.na')
+                  const stateLabel = t(
+                    completed
+                      ? 'progression.complete'
+                      : unlocked
+                        ? 'progression.unlocked'
+                        : 'progression.locked',
+                  )
 
                   return (
                     <button
@@ -156,25 +397,294 @@ export function ProgressionOverlay({
                         {String(index + 1).padStart(2, '0')}
                       </span>
                       <span className="progression-entry-col progression-entry-name">
-                        {entry.title ?? entry.packId}
+                        {entry.title ?? packMeta?.name ?? entry.packId}
+                      </span>
+                      <span className="progression-entry-col progression-entry-author">
+                        {packMeta?.author ?? ''}
                       </span>
                       <span className="progression-entry-col progression-entry-id">{entry.packId}</span>
                       <span className="progression-entry-col progression-entry-class">{packClass}</span>
                       <span className="progression-entry-col progression-entry-difficulty">{difficulty}</span>
+                      <span className="progression-entry-col progression-entry-tags">
+                        {tags.join(', ')}
+                      </span>
                       <span className="progression-entry-col progression-entry-state">{stateLabel}</span>
                       <span className="progression-entry-col progression-entry-current">
-                        {isCurrent ? 'current' : ''}
+                        {isCurrent ? t('progression.current') : ''}
                       </span>
                     </button>
                   )
                 })}
               </div>
+              {selectedDescription ? (
+                <p className="window-note progression-description">{selectedDescription}</p>
+              ) : null}
             </>
           ) : (
-            <p className="empty-log">No progression tracks defined.</p>
+            <p className="empty-log">{t('progression.empty')}</p>
           )}
         </div>
       </section>
     </div>
   )
 }
+
+interface CommunityLevelListProps {
+  levels: CommunityLevelEntry[]
+  selectedIndex: number
+  currentContentPackId: string
+  onSelectIndex: (index: number) => void
+  onLoadPack: (packId: string) => void
+}
+
+/** User levels directory listing; broken files stay in the list with their validation error. */
+function CommunityLevelList({
+  levels,
+  selectedIndex,
+  currentContentPackId,
+  onSelectIndex,
+  onLoadPack,
+}: CommunityLevelListProps) {
+  const t = useTranslator()
+
+  if (levels.length === 0) {
+    return <p className="empty-log">{t('progression.communityEmpty')}</p>
+  }
+
+  const selected = levels[Math.min(selectedIndex, levels.length - 1)]
+
+  return (
+    <>
+      <div className="progression-entry-list">
+        {levels.map((level, index) => {
+          const isCurrent = level.packId === currentContentPackId
+
+          return (
+            <button
+              key={level.packId}
+              type="button"
+              className={[
+                'progression-entry',
+                index === selectedIndex ? 'is-selected' : '',
+                level.error ? 'is-locked is-invalid' : 'is-unlocked',
+                isCurrent ? 'is-current' : '',
+              ]
+                .filter(Boolean)
+                .join(' ')}
+              onClick={() => {
+                onSelectIndex(index)
+              }}
+              onDoubleClick={() => {
+                if (!level.error) {
+                  onLoadPack(level.packId)
+                }
+              }}
+            >
+              <span className="progression-entry-col progression-entry-index">
+                {String(index + 1).padStart(2, '0')}
+              </span>
+              <span className="progression-entry-col progression-entry-name">
+                {level.meta?.name ?? level.fileName}
+              </span>
+              <span className="progression-entry-col progression-entry-author">
+                {level.meta?.author ?? ''}
+              </span>
+              <span className="progression-entry-col progression-entry-id">{level.fileName}</span>
+              <span className="progression-entry-col progression-entry-class">
+                {t('progression.community')}
+              </span>
+              <span className="progression-entry-col progression-entry-difficulty">
+                {level.meta?.difficulty ?? t('common.na')}
+              </span>
+              <span className="progression-entry-col progression-entry-tags">
+                {(level.meta?.tags ?? []).join(', ')}
+              </span>
+              <span className="progression-entry-col progression-entry-state">
+                {t(level.error ? 'progression.invalid' : 'progression.unlocked')}
+              </span>
+              <span className="progression-entry-col progression-entry-current">
+                {isCurrent ? t('progression.current') : ''}
+              </span>
+            </button>
+          )
+        })}
+      </div>
+      {selected.error ? (
+        <p className="window-note progression-description progression-error">
+          {t('progression.communityError', { file: selected.fileName, error: selected.error })}
+        </p>
+      ) : selected.meta?.description ? (
+        <p className="window-note progression-description">{selected.meta.description}</p>
+      ) : null}
+    </>
+  )
+}
+
+interface ChallengeBoardProps {
+  mutators: MutatorId[]
+  dailyMutators: MutatorId[]
+  selectedIndex: number
+  onSelectIndex: (index: number) => void
+  onChangeMutators: (mutators: MutatorId[]) => void
+}
+
+/** Mutator checklist plus the daily pick; any change restarts the current level under it. */
+function ChallengeBoard({
+  mutators,
+  dailyMutators,
+  selectedIndex,
+  onSelectIndex,
+  onChangeMutators,
+}: ChallengeBoardProps) {
+  const t = useTranslator()
+
+  return (
+    <>
+      <div className="progression-track-bar progression-filter-bar">
+        <button
+          type="button"
+          className="progression-track-button"
+          onClick={() => {
+            onChangeMutators(dailyMutators)
+          }}
+        >
+          {t('progression.challengeDaily', {
+            names: dailyMutators.map((id) => t(`mutator.${id}.label`)).join(' + '),
+          })}
+        </button>
+        <button
+          type="button"
+          className="progression-track-button"
+          onClick={() => {
+            onChangeMutators([])
+          }}
+        >
+          {t('progression.challengeClear')}
+        </button>
+      </div>
+      <div className="progression-entry-list">
+        {MUTATOR_IDS.map((id, index) => {
+          const active = mutators.includes(id)
+
+          return (
+            <button
+              key={id}
+              type="button"
+              className={[
+                'progression-entry',
+                index === selectedIndex ? 'is-selected' : '',
+                active ? 'is-complete' : 'is-unlocked',
+              ]
+                .filter(Boolean)
+                .join(' ')}
+              onClick={() => {
+                onSelectIndex(index)
+                onChangeMutators(toggleMutator(mutators, id))
+              }}
+            >
+              <span className="progression-entry-col progression-entry-index">
+                {active ? '[x]' : '[ ]'}
+              </span>
+              <span className="progression-entry-col progression-entry-name">
+                {t(`mutator.${id}.label`)}
+              </span>
+              <span className="progression-entry-col progression-entry-tags">
+                {t(`mutator.${id}.description`)}
+              </span>
+            </button>
+          )
+        })}
+      </div>
+      <p className="window-note progression-description">{t('progression.challengeNote')}</p>
+    </>
+  )
+}
+
+interface RecordsBoardProps {
+  tracks: ProgressionTrack[]
+  replaySnapshot: ReplaySnapshot
+}
+
+/** Personal bests per track: fewest turns, fastest time and the running split for each level. */
+function RecordsBoard({ tracks, replaySnapshot }: RecordsBoardProps) {
+  const t = useTranslator()
+
+  if (tracks.length === 0) {
+    return <p className="empty-log">{t('progression.empty')}</p>
+  }
+
+  return tracks.map((track) => {
+    const board = trackSplits(track, replaySnapshot)
+
+    return (
+      <section className="records-track" key={board.trackId}>
+        <h3>{board.title}</h3>
+        {board.splits.map((split, index) => (
+          <div className="log-row records-row" key={`${board.trackId}-${index}-${split.packId}`}>
+            <span className="log-turn">{String(index + 1).padStart(2, '0')}</span>
+            <span className="log-text">{split.title}</span>
+            <span className="records-time">
+              {split.bestTurns === null
+                ? t('common.na')
+                : t('progression.recordTurns', { count: split.bestTurns })}
+            </span>
+            <span className="records-time">
+              {split.bestTimeMs === null ? t('common.na') : formatElapsed(split.bestTimeMs)}
+            </span>
+            <span className="records-time log-turn">
+              {split.cumulativeMs === null ? '' : formatElapsed(split.cumulativeMs)}
+            </span>
+          </div>
+        ))}
+        <p className="window-note">
+          {t('progression.sumOfBest', {
+            time: board.sumOfBestMs === null ? t('common.na') : formatElapsed(board.sumOfBestMs),
+          })}
+        </p>
+      </section>
+    )
+  })
+}
+
+interface IronmanBoardProps {
+  stats: IronmanStats
+}
+
+/** Iron-man campaign stats and its own achievement track. */
+function IronmanBoard({ stats }: IronmanBoardProps) {
+  const t = useTranslator()
+  const unlocked = unlockedIronmanAchievements(stats)
+
+  return (
+    <section className="records-track">
+      <h3>{t('ironman.title')}</h3>
+      <p className="window-note">
+        {t('ironman.stats', {
+          streak: stats.currentStreak,
+          best: stats.bestStreak,
+          resets: stats.resets,
+          tracks: stats.completedTracks,
+        })}
+      </p>
+      {IRONMAN_ACHIEVEMENTS.map((achievement) => {
+        const isUnlocked = unlocked.includes(achievement.id)
+
+        return (
+          <div
+            className={[
+              'log-row',
+              'ironman-achievement',
+              isUnlocked ? 'is-unlocked' : 'is-locked',
+            ].join(' ')}
+            key={achievement.id}
+          >
+            <span className="log-turn">{isUnlocked ? '[x]' : '[ ]'}</span>
+            <span className="log-text">
+              {t(`ironman.achievement.${achievement.id}`)}
+            </span>
+          </div>
+        )
+      })}
+    </section>
+  )
+}
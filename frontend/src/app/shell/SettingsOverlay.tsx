@@ -1,6 +1,11 @@
 import type { Dispatch, RefObject, SetStateAction } from 'react'
 
+import { isPlayDifficulty, PLAY_DIFFICULTIES } from '../../data/playDifficulty'
+import { RULE_PROFILES, RULE_PROFILES_ERROR } from '../../data/ruleProfiles'
+import { isLocale, SUPPORTED_LOCALES } from '../../render/i18n'
+import { KEY_REPEAT_DELAY_OPTIONS_MS } from '../inputRepeat'
 import type { UiSettings } from './constants'
+import { useTranslator } from './useTranslator'
 
 interface SettingsOverlayProps {
   isOpen: boolean
@@ -8,6 +13,8 @@ interface SettingsOverlayProps {
   uiSettings: UiSettings
   setUiSettings: Dispatch<SetStateAction<UiSettings>>
   setShowDangerPreview: Dispatch<SetStateAction<boolean>>
+  /** Changes are also written back to `config.toml` (dev server only). */
+  savesToConfigFile: boolean
 }
 
 export function SettingsOverlay({
@@ -16,7 +23,10 @@ export function SettingsOverlay({
   uiSettings,
   setUiSettings,
   setShowDangerPreview,
+  savesToConfigFile,
 }: SettingsOverlayProps) {
+  const t = useTranslator()
+
   if (!isOpen) {
     return null
   }
@@ -25,12 +35,109 @@ export function SettingsOverlay({
     <div className="overlay-backdrop" role="dialog" aria-modal="true" aria-label="Settings">
       <section className="overlay-window settings-window" ref={overlayRef} tabIndex={-1}>
         <header className="overlay-header">
-          <h2>Settings</h2>
-          <p>M / Esc: close</p>
+          <h2>{t('settings.title')}</h2>
+          <p>{t('common.closeHint', { keys: 'M' })}</p>
         </header>
         <div className="overlay-body settings-body">
+          <label className="settings-row" htmlFor="setting-language">
+            <span>{t('settings.language')}</span>
+            <select
+              id="setting-language"
+              value={uiSettings.language}
+              onChange={(event) => {
+                const nextValue = event.target.value
+
+                if (!isLocale(nextValue)) {
+                  return
+                }
+
+                setUiSettings((settings) => ({
+                  ...settings,
+                  language: nextValue,
+                }))
+              }}
+            >
+              {SUPPORTED_LOCALES.map((locale) => (
+                <option key={locale.id} value={locale.id}>
+                  {locale.label}
+                </option>
+              ))}
+            </select>
+          </label>
+          <label className="settings-row" htmlFor="setting-difficulty">
+            <span>{t('settings.difficulty')}</span>
+            <select
+              id="setting-difficulty"
+              value={uiSettings.playDifficulty}
+              onChange={(event) => {
+                const nextValue = event.target.value
+
+                if (!isPlayDifficulty(nextValue)) {
+                  return
+                }
+
+                setUiSettings((settings) => ({
+                  ...settings,
+                  playDifficulty: nextValue,
+                }))
+              }}
+            >
+              {PLAY_DIFFICULTIES.map((difficulty) => (
+                <option key={difficulty} value={difficulty}>
+                  {t(`playDifficulty.${difficulty}`)}
+                </option>
+              ))}
+            </select>
+          </label>
+          <label className="settings-row" htmlFor="setting-rule-profile">
+            <span>{t('settings.ruleProfile')}</span>
+            <select
+              id="setting-rule-profile"
+              value={uiSettings.ruleProfileId}
+              onChange={(event) => {
+                const nextValue = event.target.value
+
+                setUiSettings((settings) => ({
+                  ...settings,
+                  ruleProfileId: nextValue,
+                }))
+              }}
+            >
+              {RULE_PROFILES.map((profile) => (
+                <option key={profile.id} value={profile.id}>
+                  {profile.name}
+                </option>
+              ))}
+            </select>
+          </label>
+          {RULE_PROFILES_ERROR ? (
+            <p className="window-note">
+              {t('settings.ruleProfilesError', { error: RULE_PROFILES_ERROR })}
+            </p>
+          ) : null}
+          <label className="settings-row" htmlFor="setting-key-repeat">
+            <span>{t('settings.keyRepeat')}</span>
+            <select
+              id="setting-key-repeat"
+              value={uiSettings.keyRepeatDelayMs}
+              onChange={(event) => {
+                const nextValue = Number(event.target.value)
+
+                setUiSettings((settings) => ({
+                  ...settings,
+                  keyRepeatDelayMs: nextValue,
+                }))
+              }}
+            >
+              {KEY_REPEAT_DELAY_OPTIONS_MS.map((delayMs) => (
+                <option key={delayMs} value={delayMs}>
+                  {delayMs === 0 ? t('settings.keyRepeatOff') : t('settings.keyRepeatMs', { ms: delayMs })}
+                </option>
+              ))}
+            </select>
+          </label>
           <label className="settings-row" htmlFor="setting-iso-panel">
-            <span>Show isometric panel</span>
+            <span>{t('settings.isoPanel')}</span>
             <input
               id="setting-iso-panel"
               type="checkbox"
@@ -44,7 +151,7 @@ export function SettingsOverlay({
             />
           </label>
           <label className="settings-row" htmlFor="setting-compact-hints">
-            <span>Compact bottom hints</span>
+            <span>{t('settings.compactHints')}</span>
             <input
               id="setting-compact-hints"
               type="checkbox"
@@ -58,7 +165,7 @@ export function SettingsOverlay({
             />
           </label>
           <label className="settings-row" htmlFor="setting-default-danger">
-            <span>Default danger preview</span>
+            <span>{t('settings.defaultDanger')}</span>
             <input
               id="setting-default-danger"
               type="checkbox"
@@ -74,6 +181,138 @@ export function SettingsOverlay({
               }}
             />
           </label>
+          <label className="settings-row" htmlFor="setting-ghost-run">
+            <span>{t('settings.ghostRun')}</span>
+            <input
+              id="setting-ghost-run"
+              type="checkbox"
+              checked={uiSettings.showGhostRun}
+              onChange={(event) => {
+                setUiSettings((settings) => ({
+                  ...settings,
+                  showGhostRun: event.target.checked,
+                }))
+              }}
+            />
+          </label>
+          <label className="settings-row" htmlFor="setting-exit-compass">
+            <span>{t('settings.exitCompass')}</span>
+            <input
+              id="setting-exit-compass"
+              type="checkbox"
+              checked={uiSettings.exitCompass}
+              onChange={(event) => {
+                setUiSettings((settings) => ({
+                  ...settings,
+                  exitCompass: event.target.checked,
+                }))
+              }}
+            />
+          </label>
+          <label className="settings-row" htmlFor="setting-assist-detection">
+            <span>{t('settings.assistDetection')}</span>
+            <input
+              id="setting-assist-detection"
+              type="checkbox"
+              checked={uiSettings.assistDetectionWarnings}
+              onChange={(event) => {
+                setUiSettings((settings) => ({
+                  ...settings,
+                  assistDetectionWarnings: event.target.checked,
+                }))
+              }}
+            />
+          </label>
+          <label className="settings-row" htmlFor="setting-accessible-cues">
+            <span>{t('settings.accessibleCues')}</span>
+            <input
+              id="setting-accessible-cues"
+              type="checkbox"
+              checked={uiSettings.accessibleCues}
+              onChange={(event) => {
+                setUiSettings((settings) => ({
+                  ...settings,
+                  accessibleCues: event.target.checked,
+                }))
+              }}
+            />
+          </label>
+          <label className="settings-row" htmlFor="setting-fog-of-war">
+            <span>{t('settings.fogOfWar')}</span>
+            <input
+              id="setting-fog-of-war"
+              type="checkbox"
+              checked={uiSettings.fogOfWar}
+              onChange={(event) => {
+                setUiSettings((settings) => ({
+                  ...settings,
+                  fogOfWar: event.target.checked,
+                }))
+              }}
+            />
+          </label>
+          <label className="settings-row" htmlFor="setting-sound-cues">
+            <span>{t('settings.soundCues')}</span>
+            <input
+              id="setting-sound-cues"
+              type="checkbox"
+              checked={uiSettings.soundCues}
+              onChange={(event) => {
+                setUiSettings((settings) => ({
+                  ...settings,
+                  soundCues: event.target.checked,
+                }))
+              }}
+            />
+          </label>
+          <label className="settings-row" htmlFor="setting-reduce-motion">
+            <span>{t('settings.reduceMotion')}</span>
+            <input
+              id="setting-reduce-motion"
+              type="checkbox"
+              checked={uiSettings.reduceMotion}
+              onChange={(event) => {
+                setUiSettings((settings) => ({
+                  ...settings,
+                  reduceMotion: event.target.checked,
+                }))
+              }}
+            />
+          </label>
+          <label className="settings-row" htmlFor="setting-speedrun-timer">
+            <span>{t('settings.speedrunTimer')}</span>
+            <input
+              id="setting-speedrun-timer"
+              type="checkbox"
+              checked={uiSettings.speedrunTimer}
+              onChange={(event) => {
+                setUiSettings((settings) => ({
+                  ...settings,
+                  speedrunTimer: event.target.checked,
+                }))
+              }}
+            />
+          </label>
+          <label className="settings-row" htmlFor="setting-ironman-campaign">
+            <span>{t('settings.ironmanCampaign')}</span>
+            <input
+              id="setting-ironman-campaign"
+              type="checkbox"
+              checked={uiSettings.ironmanCampaign}
+              onChange={(event) => {
+                setUiSettings((settings) => ({
+                  ...settings,
+                  ironmanCampaign: event.target.checked,
+                }))
+              }}
+            />
+          </label>
+          {uiSettings.ironmanCampaign ? (
+            <p className="window-note ironman-warning">{t('ironman.warning')}</p>
+          ) : null}
+          <p className="window-note">
+            {savesToConfigFile ? t('settings.savedToConfig') : t('settings.savedInBrowser')}
+          </p>
         </div>
       </section>
     </div>
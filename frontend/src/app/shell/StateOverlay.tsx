@@ -5,6 +5,8 @@ import type { GamePhase } from '../../game/gameSlice'
 import type { Position3D } from '../../core/position'
 import type { InteractionConfig } from '../../game/interactions/types'
 import type { PublicPackDifficultyMeta } from '../../data/loader'
+import type { ReplayStats } from './useReplayStore'
+import { useTranslator } from './useTranslator'
 
 interface StateOverlayProps {
   isOpen: boolean
@@ -27,6 +29,8 @@ interface StateOverlayProps {
   contentPackDifficultyMeta?: PublicPackDifficultyMeta
   contentPackDifficultyFlavor?: string
   contentPackDifficultyTarget?: string
+  replayStats?: ReplayStats
+  bestTurns?: number
 }
 
 export function StateOverlay({
@@ -50,7 +54,12 @@ export function StateOverlay({
   contentPackDifficultyMeta,
   contentPackDifficultyFlavor,
   contentPackDifficultyTarget,
+  replayStats,
+  bestTurns,
 }: StateOverlayProps) {
+  const t = useTranslator()
+  const na = t('common.na')
+
   if (!isOpen) {
     return null
   }
@@ -59,147 +68,159 @@ export function StateOverlay({
     <div className="overlay-backdrop" role="dialog" aria-modal="true" aria-label="State Details">
       <section className="overlay-window" ref={overlayRef} tabIndex={-1}>
         <header className="overlay-header">
-          <h2>State Details</h2>
-          <p>Tab / Esc: close</p>
+          <h2>{t('state.title')}</h2>
+          <p>{t('common.closeHint', { keys: 'Tab' })}</p>
         </header>
         <div className="overlay-body state-overlay-body">
           <section className="state-block">
-            <h3 className="state-block-title">Core</h3>
+            <h3 className="state-block-title">{t('state.core')}</h3>
             <div className="metric-grid">
               <div className="metric-item">
-                <span className="metric-label">Board</span>
+                <span className="metric-label">{t('state.board')}</span>
                 <span className="metric-value">{boardWidth} x {boardHeight}</span>
               </div>
               <div className="metric-item">
-                <span className="metric-label">Depth</span>
+                <span className="metric-label">{t('state.depth')}</span>
                 <span className="metric-value">{timeDepth}</span>
               </div>
               <div className="metric-item">
-                <span className="metric-label">Turn</span>
+                <span className="metric-label">{t('hud.turn')}</span>
                 <span className="metric-value">{turn}</span>
               </div>
               <div className="metric-item">
-                <span className="metric-label">Time</span>
+                <span className="metric-label">{t('hud.time')}</span>
                 <span className="metric-value">{currentTime}</span>
               </div>
               <div className="metric-item">
-                <span className="metric-label">Phase</span>
-                <span className="metric-value">{phase}</span>
+                <span className="metric-label">{t('hud.phase')}</span>
+                <span className="metric-value">{t(`phase.${phase}`)}</span>
               </div>
               <div className="metric-item">
-                <span className="metric-label">Mode</span>
-                <span className="metric-value">{directionalActionMode}</span>
+                <span className="metric-label">{t('state.mode')}</span>
+                <span className="metric-value">{t(`mode.${directionalActionMode}`)}</span>
               </div>
             </div>
           </section>
 
           <section className="state-block">
-            <h3 className="state-block-title">Tools</h3>
+            <h3 className="state-block-title">{t('state.tools')}</h3>
             <div className="metric-grid">
               <div className="metric-item">
-                <span className="metric-label">Rift Delta</span>
+                <span className="metric-label">{t('hud.riftDelta')}</span>
                 <span className="metric-value">-{riftDefaultDelta}</span>
               </div>
               <div className="metric-item">
-                <span className="metric-label">Push Max</span>
+                <span className="metric-label">{t('state.pushMax')}</span>
                 <span className="metric-value">{interactionConfig.maxPushChain}</span>
               </div>
               <div className="metric-item">
-                <span className="metric-label">Pull</span>
-                <span className="metric-value">{interactionConfig.allowPull ? 'on' : 'off'}</span>
+                <span className="metric-label">{t('state.pull')}</span>
+                <span className="metric-value">{t(interactionConfig.allowPull ? 'common.on' : 'common.off')}</span>
               </div>
               <div className="metric-item">
-                <span className="metric-label">Danger</span>
-                <span className="metric-value">{showDangerPreview ? 'on' : 'off'}</span>
+                <span className="metric-label">{t('hud.danger')}</span>
+                <span className="metric-value">{t(showDangerPreview ? 'common.on' : 'common.off')}</span>
               </div>
             </div>
           </section>
 
           <section className="state-block">
-            <h3 className="state-block-title">Snapshot</h3>
+            <h3 className="state-block-title">{t('state.snapshot')}</h3>
             <div className="metric-grid metric-grid-single">
               <div className="metric-item">
-                <span className="metric-label">Slice Objects</span>
+                <span className="metric-label">{t('state.sliceObjects')}</span>
                 <span className="metric-value">{objectsAtCurrentTimeCount}</span>
               </div>
               <div className="metric-item metric-item-wide">
-                <span className="metric-label">Player</span>
+                <span className="metric-label">{t('state.player')}</span>
                 <span className="metric-value">
-                  {player ? `${player.x},${player.y},t=${player.t}` : 'N/A'}
+                  {player ? `${player.x},${player.y},t=${player.t}` : na}
                 </span>
               </div>
               <div className="metric-item">
-                <span className="metric-label">Content Pack</span>
+                <span className="metric-label">{t('state.wins')}</span>
+                <span className="metric-value">
+                  {replayStats
+                    ? t('state.winsValue', { wins: replayStats.wins, hinted: replayStats.hintedWins })
+                    : '0'}
+                </span>
+              </div>
+              <div className="metric-item">
+                <span className="metric-label">{t('state.bestTurns')}</span>
+                <span className="metric-value">{bestTurns ?? na}</span>
+              </div>
+              <div className="metric-item">
+                <span className="metric-label">{t('state.contentPack')}</span>
                 <span className="metric-value">{contentPackId}</span>
               </div>
               <div className="metric-item">
-                <span className="metric-label">Pack Class</span>
-                <span className="metric-value">{contentPackClass ?? 'n/a'}</span>
+                <span className="metric-label">{t('state.packClass')}</span>
+                <span className="metric-value">{contentPackClass ?? na}</span>
               </div>
               <div className="metric-item">
-                <span className="metric-label">Difficulty</span>
-                <span className="metric-value">{contentPackDifficulty ?? 'n/a'}</span>
+                <span className="metric-label">{t('state.difficulty')}</span>
+                <span className="metric-value">{contentPackDifficulty ?? na}</span>
               </div>
               <div className="metric-item">
-                <span className="metric-label">Diff Source</span>
-                <span className="metric-value">{contentPackDifficultyMeta?.source ?? 'n/a'}</span>
+                <span className="metric-label">{t('state.diffSource')}</span>
+                <span className="metric-value">{contentPackDifficultyMeta?.source ?? na}</span>
               </div>
               <div className="metric-item">
-                <span className="metric-label">Diff Score</span>
+                <span className="metric-label">{t('state.diffScore')}</span>
                 <span className="metric-value">
-                  {contentPackDifficultyMeta ? contentPackDifficultyMeta.score.toFixed(2) : 'n/a'}
+                  {contentPackDifficultyMeta ? contentPackDifficultyMeta.score.toFixed(2) : na}
                 </span>
               </div>
               <div className="metric-item">
-                <span className="metric-label">Diff Model</span>
-                <span className="metric-value">{contentPackDifficultyMeta?.modelVersion ?? 'n/a'}</span>
+                <span className="metric-label">{t('state.diffModel')}</span>
+                <span className="metric-value">{contentPackDifficultyMeta?.modelVersion ?? na}</span>
               </div>
               <div className="metric-item">
-                <span className="metric-label">Diff Target</span>
-                <span className="metric-value">{contentPackDifficultyTarget ?? 'n/a'}</span>
+                <span className="metric-label">{t('state.diffTarget')}</span>
+                <span className="metric-value">{contentPackDifficultyTarget ?? na}</span>
               </div>
               <div className="metric-item metric-item-wide">
-                <span className="metric-label">Flavor</span>
-                <span className="metric-value">{contentPackDifficultyFlavor ?? 'n/a'}</span>
+                <span className="metric-label">{t('state.flavor')}</span>
+                <span className="metric-value">{contentPackDifficultyFlavor ?? na}</span>
               </div>
               <div className="metric-item">
-                <span className="metric-label">Spatial</span>
+                <span className="metric-label">{t('state.spatial')}</span>
                 <span className="metric-value">
                   {contentPackDifficultyMeta
                     ? contentPackDifficultyMeta.vector.spatialPressure.toFixed(2)
-                    : 'n/a'}
+                    : na}
                 </span>
               </div>
               <div className="metric-item">
-                <span className="metric-label">Temporal</span>
+                <span className="metric-label">{t('state.temporal')}</span>
                 <span className="metric-value">
                   {contentPackDifficultyMeta
                     ? contentPackDifficultyMeta.vector.temporalPressure.toFixed(2)
-                    : 'n/a'}
+                    : na}
                 </span>
               </div>
               <div className="metric-item">
-                <span className="metric-label">Detect</span>
+                <span className="metric-label">{t('state.detect')}</span>
                 <span className="metric-value">
                   {contentPackDifficultyMeta
                     ? contentPackDifficultyMeta.vector.detectionPressure.toFixed(2)
-                    : 'n/a'}
+                    : na}
                 </span>
               </div>
               <div className="metric-item">
-                <span className="metric-label">Interact</span>
+                <span className="metric-label">{t('state.interact')}</span>
                 <span className="metric-value">
                   {contentPackDifficultyMeta
                     ? contentPackDifficultyMeta.vector.interactionComplexity.toFixed(2)
-                    : 'n/a'}
+                    : na}
                 </span>
               </div>
               <div className="metric-item">
-                <span className="metric-label">Paradox</span>
+                <span className="metric-label">{t('state.paradox')}</span>
                 <span className="metric-value">
                   {contentPackDifficultyMeta
                     ? contentPackDifficultyMeta.vector.paradoxRisk.toFixed(2)
-                    : 'n/a'}
+                    : na}
                 </span>
               </div>
             </div>
@@ -0,0 +1,26 @@
+import type { TutorialStepConfig } from '../../data/contracts'
+import { useTranslator } from './useTranslator'
+
+interface TutorialPanelProps {
+  steps: TutorialStepConfig[]
+  stepIndex: number
+}
+
+export function TutorialPanel({ steps, stepIndex }: TutorialPanelProps) {
+  const t = useTranslator()
+
+  if (steps.length === 0) {
+    return null
+  }
+
+  const step = steps[stepIndex]
+
+  return (
+    <aside className="tutorial-panel" aria-label="Tutorial" aria-live="polite">
+      <span className="tutorial-progress">
+        {t('tutorial.progress', { step: Math.min(stepIndex + 1, steps.length), total: steps.length })}
+      </span>
+      <p className="tutorial-prompt">{step ? step.prompt : t('tutorial.complete')}</p>
+    </aside>
+  )
+}
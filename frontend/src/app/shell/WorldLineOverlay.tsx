@@ -0,0 +1,75 @@
+import type { RefObject } from 'react'
+
+import { useTranslator } from './useTranslator'
+import type { WorldLineRow } from './worldLineTable'
+
+interface WorldLineOverlayProps {
+  isOpen: boolean
+  overlayRef: RefObject<HTMLElement | null>
+  rows: WorldLineRow[]
+  selectedIndex: number
+  /** Slice the board shows; rows there are marked. */
+  viewedTime: number
+  closeKeys: string
+  onJump: (index: number) => void
+}
+
+export function WorldLineOverlay({
+  isOpen,
+  overlayRef,
+  rows,
+  selectedIndex,
+  viewedTime,
+  closeKeys,
+  onJump,
+}: WorldLineOverlayProps) {
+  const t = useTranslator()
+
+  if (!isOpen) {
+    return null
+  }
+
+  return (
+    <div className="overlay-backdrop" role="dialog" aria-modal="true" aria-label="World Line">
+      <section className="overlay-window" ref={overlayRef} tabIndex={-1}>
+        <header className="overlay-header">
+          <h2>{t('worldLine.title')}</h2>
+          <p>{t('worldLine.controls', { keys: closeKeys })}</p>
+        </header>
+        <div className="overlay-body">
+          <div className="log-row world-line-row world-line-head">
+            <span>{t('worldLine.turn')}</span>
+            <span>x</span>
+            <span>y</span>
+            <span>t</span>
+            <span>{t('worldLine.via')}</span>
+          </div>
+          {rows.map((row, index) => (
+            <button
+              key={row.turn}
+              type="button"
+              className={[
+                'log-row',
+                'world-line-row',
+                index === selectedIndex ? 'is-selected' : '',
+                row.t === viewedTime ? 'is-viewed' : '',
+              ]
+                .filter(Boolean)
+                .join(' ')}
+              aria-current={index === selectedIndex}
+              onClick={() => {
+                onJump(index)
+              }}
+            >
+              <span className="log-turn">T{row.turn}</span>
+              <span>{row.x}</span>
+              <span>{row.y}</span>
+              <span>{row.t}</span>
+              <span>{t(`step.${row.via}`)}</span>
+            </button>
+          ))}
+        </div>
+      </section>
+    </div>
+  )
+}
@@ -1,14 +1,112 @@
 import type { InteractionHistoryEntry } from '../../game/gameSlice'
+import type { InteractionAction, OutcomeEvent } from '../../game/interactions/types'
+import type { Translator } from '../../render/i18n'
 
-export function actionSummary(entry: InteractionHistoryEntry): string {
-  const actionText =
-    entry.action.kind === 'Move' ||
-    entry.action.kind === 'Push' ||
-    entry.action.kind === 'Pull'
-      ? `${entry.action.kind.toLowerCase()} ${entry.action.direction ?? ''}`.trim()
-      : entry.action.kind === 'ApplyRift'
-        ? 'rift'
-        : entry.action.kind.toLowerCase()
-
-  return `${actionText} -> ${entry.outcome.kind.toLowerCase()}`
+export function actionText(t: Translator, action: InteractionAction): string {
+  switch (action.kind) {
+    case 'Move':
+    case 'Push':
+    case 'Pull':
+    case 'TimePush':
+    case 'Break':
+    case 'Interact':
+      return t(`action.${action.kind}`, { direction: t(`direction.${action.direction}`) })
+    case 'Throw':
+      return t('action.Throw', {
+        direction: t(`direction.${action.direction}`),
+        range: action.range,
+      })
+    case 'Wait':
+    case 'ApplyRift':
+    case 'SwitchCharacter':
+    case 'Climb':
+      return t(`action.${action.kind}`)
+    case 'MoveTo':
+      return t('action.MoveTo', { x: action.target.x, y: action.target.y, time: action.target.t })
+  }
+}
+
+function eventText(t: Translator, event: OutcomeEvent): string {
+  switch (event.kind) {
+    case 'Slid':
+      return t('event.Slid', { x: event.to.x, y: event.to.y })
+    case 'ObjectsMoved':
+      return t('event.ObjectsMoved', { count: event.objectIds.length })
+    case 'LureLanded':
+      return t('event.LureLanded', { x: event.at.x, y: event.at.y })
+    case 'NoiseMade':
+      return t('event.NoiseMade', { x: event.at.x, y: event.at.y })
+    case 'ObjectBroken':
+      return t('event.ObjectBroken', { objectId: event.objectId })
+    case 'ObjectUnmade':
+      return t('event.ObjectUnmade', { objectId: event.objectId, from: event.from })
+    case 'ObjectRestored':
+      return t('event.ObjectRestored', { objectId: event.objectId, from: event.from })
+    case 'PropagationWarning': {
+      const { warning } = event
+      const cell = { id: warning.id, ...warning.position }
+
+      return warning.kind === 'EntityCollision'
+        ? t('event.PropagationWarning.EntityCollision', { ...cell, blockerId: warning.blockerId })
+        : t('event.PropagationWarning.OutOfBounds', cell)
+    }
+    case 'WaterBridged':
+      return t('event.WaterBridged', { x: event.at.x, y: event.at.y })
+    case 'EnteredRegion':
+      return t('event.EnteredRegion', { name: event.name })
+    case 'TimelineForked':
+      return t('event.TimelineForked', {
+        timelineId: event.timelineId,
+        forkTime: event.forkTime,
+      })
+    case 'Paradox':
+      return t('event.Paradox', { reason: event.reason })
+    case 'Detected':
+      return t('event.Detected', { enemyId: event.enemyId })
+    case 'Glimpsed':
+      return t('event.Glimpsed', { enemyId: event.enemyId, suspicion: event.suspicion })
+    case 'MovedObjectNoticed':
+      return t('event.MovedObjectNoticed', { enemyId: event.enemyId, suspicion: event.suspicion })
+    case 'AlarmRaised':
+      return t('event.AlarmRaised', { to: event.lockdown.to })
+    case 'Interacted':
+      return t('event.Interacted', { objectId: event.objectId })
+    case 'LockdownLifted':
+      return t('event.LockdownLifted', { at: event.at })
+    case 'PatrolSwitched':
+      return t(`event.PatrolSwitched.${event.change.kind}`, {
+        enemyId: event.enemyId,
+        at: event.change.at,
+      })
+    case 'KnockedOut':
+      return t('event.KnockedOut', { enemyId: event.enemyId })
+    case 'BodyFound':
+      return t('event.BodyFound', { enemyId: event.enemyId, bodyId: event.bodyId })
+    case 'CharacterCaught':
+      return t('event.CharacterCaught', {
+        characterId: event.characterId,
+        enemyId: event.enemyId,
+      })
+    case 'ReachedExit':
+    case 'ControlPassed':
+      return t(`event.${event.kind}`, { characterId: event.characterId })
+    case 'Won':
+      return t('event.Won')
+  }
+}
+
+export function actionSummary(t: Translator, entry: InteractionHistoryEntry): string {
+  const summary = t('log.entry', {
+    action: actionText(t, entry.action),
+    outcome: t(`outcome.${entry.outcome.kind}`),
+  })
+
+  if (!entry.events || entry.events.length === 0) {
+    return summary
+  }
+
+  return t('log.withEvents', {
+    entry: summary,
+    events: entry.events.map((event) => eventText(t, event)).join(', '),
+  })
 }
@@ -0,0 +1,35 @@
+import { describe, expect, it } from 'vitest'
+
+import { createTranslator } from '../../render/i18n'
+import { pendingBreadcrumb } from './breadcrumbs'
+import { defaultKeymap } from './keymap'
+
+const t = createTranslator('en')
+
+describe('pendingBreadcrumb', () => {
+  it('shows a pending action mode and the key that cancels it', () => {
+    const idle = {
+      layer: 'Gameplay' as const,
+      mode: 'Move' as const,
+      examineCursor: null,
+      plannedSteps: 0,
+    }
+
+    expect(pendingBreadcrumb(idle, defaultKeymap, t)).toBeNull()
+    expect(pendingBreadcrumb({ ...idle, mode: 'Push' }, defaultKeymap, t)).toBe(
+      'Push > direction? (Esc: back to Move)',
+    )
+  })
+
+  it('shows the examine cursor and the queued plan', () => {
+    const base = { mode: 'Move' as const, plannedSteps: 3, examineCursor: { x: 2, y: 4 } }
+
+    expect(pendingBreadcrumb({ ...base, layer: 'Examine' }, defaultKeymap, t)).toBe(
+      'Examine (2,4) > move the cursor (Esc: close)',
+    )
+    expect(pendingBreadcrumb({ ...base, layer: 'PlanningOverlay' }, defaultKeymap, t)).toBe(
+      'Plan > 3 queued (Y: commit, Esc: discard)',
+    )
+    expect(pendingBreadcrumb({ ...base, layer: 'HelpOverlay' }, defaultKeymap, t)).toBeNull()
+  })
+})
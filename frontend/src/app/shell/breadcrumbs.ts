@@ -0,0 +1,44 @@
+import type { Position2D } from '../../core/position'
+import type { Translator } from '../../render/i18n'
+import type { DirectionalActionMode, InputLayer } from '../inputStateMachine'
+import { keysForCommand, type KeyBinding } from './keymap'
+
+export interface BreadcrumbInput {
+  layer: InputLayer
+  mode: DirectionalActionMode
+  examineCursor: Position2D | null
+  /** Actions queued in the turn plan. */
+  plannedSteps: number
+}
+
+/**
+ * Bottom-bar line for an interaction that is waiting on more keys: an action mode other than
+ * Move, the examine cursor or an open plan. Names the key that cancels it; null when nothing is
+ * pending.
+ */
+export function pendingBreadcrumb(
+  input: BreadcrumbInput,
+  keymap: KeyBinding[],
+  t: Translator,
+): string | null {
+  const cancel = keysForCommand(keymap, 'CloseLayer')
+
+  switch (input.layer) {
+    case 'Examine':
+      return input.examineCursor
+        ? t('breadcrumb.examine', { ...input.examineCursor, cancel })
+        : null
+    case 'PlanningOverlay':
+      return t('breadcrumb.plan', {
+        count: input.plannedSteps,
+        commit: keysForCommand(keymap, 'CommitPlan'),
+        cancel,
+      })
+    case 'Gameplay':
+      return input.mode === 'Move'
+        ? null
+        : t('breadcrumb.mode', { mode: t(`mode.${input.mode}`), cancel })
+    default:
+      return null
+  }
+}
@@ -1,15 +1,37 @@
+import { DEFAULT_PLAY_DIFFICULTY, type PlayDifficulty } from '../../data/playDifficulty'
+import { STANDARD_RULE_PROFILE } from '../../data/ruleProfiles'
+import { DEFAULT_LOCALE, type Locale } from '../../render/i18n'
+import { DEFAULT_KEY_REPEAT_DELAY_MS } from '../inputRepeat'
 import type { DirectionalActionMode } from '../inputStateMachine'
+import type { LevelMechanic } from './keymap'
 
 export interface DirectionalOption {
   mode: DirectionalActionMode
-  keyLabel: '1' | '2' | '3'
-  description: string
+  keyLabel: '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8'
+  /** Only offered when the level has this mechanic. */
+  mechanic?: LevelMechanic
 }
 
 export interface UiSettings {
   showIsoPanel: boolean
   compactHints: boolean
   defaultDangerPreview: boolean
+  showGhostRun: boolean
+  /** Heading and distance to the nearest exit when it is far or out of view. */
+  exitCompass: boolean
+  assistDetectionWarnings: boolean
+  accessibleCues: boolean
+  fogOfWar: boolean
+  soundCues: boolean
+  reduceMotion: boolean
+  speedrunTimer: boolean
+  /** Separate campaign where any detection wipes its progress. */
+  ironmanCampaign: boolean
+  playDifficulty: PlayDifficulty
+  /** Rule profile id from `rule-profiles.json`, applied when a level loads. */
+  ruleProfileId: string
+  keyRepeatDelayMs: number
+  language: Locale
 }
 
 export const DEFAULT_PACK_SEQUENCE = ['default', 'variant']
@@ -19,10 +41,28 @@ export const defaultUiSettings: UiSettings = {
   showIsoPanel: true,
   compactHints: false,
   defaultDangerPreview: false,
+  showGhostRun: true,
+  exitCompass: true,
+  assistDetectionWarnings: false,
+  accessibleCues: false,
+  fogOfWar: false,
+  soundCues: false,
+  reduceMotion: false,
+  speedrunTimer: false,
+  ironmanCampaign: false,
+  playDifficulty: DEFAULT_PLAY_DIFFICULTY,
+  ruleProfileId: STANDARD_RULE_PROFILE.id,
+  keyRepeatDelayMs: DEFAULT_KEY_REPEAT_DELAY_MS,
+  language: DEFAULT_LOCALE,
 }
 
 export const directionalOptions: DirectionalOption[] = [
-  { mode: 'Move', keyLabel: '1', description: 'Normal movement' },
-  { mode: 'Push', keyLabel: '2', description: 'Push chain forward' },
-  { mode: 'Pull', keyLabel: '3', description: 'Pull from behind' },
+  { mode: 'Move', keyLabel: '1' },
+  { mode: 'Push', keyLabel: '2' },
+  { mode: 'Pull', keyLabel: '3' },
+  { mode: 'TimePush', keyLabel: '4', mechanic: 'timePush' },
+  { mode: 'Throw', keyLabel: '5', mechanic: 'lures' },
+  { mode: 'Break', keyLabel: '6', mechanic: 'breakables' },
+  { mode: 'Peek', keyLabel: '7', mechanic: 'peek' },
+  { mode: 'Interact', keyLabel: '8', mechanic: 'interactables' },
 ]
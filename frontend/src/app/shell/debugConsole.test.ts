@@ -0,0 +1,77 @@
+import { describe, expect, it } from 'vitest'
+
+import type { UnknownAction } from '@reduxjs/toolkit'
+
+import { gameReducer, type GameState } from '../../game/gameSlice'
+import {
+  describeGameState,
+  executeDebugCommand,
+  parseDebugCommand,
+  resolveDebugConsoleEnabled,
+} from './debugConsole'
+
+describe('debugConsole', () => {
+  it('is only enabled by the debug query flag', () => {
+    expect(resolveDebugConsoleEnabled('')).toBe(false)
+    expect(resolveDebugConsoleEnabled('?debug')).toBe(true)
+    expect(resolveDebugConsoleEnabled('?pack=x&debug=1')).toBe(true)
+    expect(resolveDebugConsoleEnabled('?debug=false')).toBe(false)
+  })
+
+  it('parses the console commands', () => {
+    expect(parseDebugCommand('teleport 3 4')).toEqual({
+      ok: true,
+      value: { kind: 'Teleport', position: { x: 3, y: 4 } },
+    })
+    expect(parseDebugCommand('  sett 5 ')).toEqual({ ok: true, value: { kind: 'SetTime', t: 5 } })
+    expect(parseDebugCommand('spawn wall 2 2')).toEqual({
+      ok: true,
+      value: { kind: 'Spawn', archetype: 'wall', position: { x: 2, y: 2 } },
+    })
+    expect(parseDebugCommand('detect off')).toEqual({
+      ok: true,
+      value: { kind: 'Detect', enabled: false },
+    })
+    expect(parseDebugCommand('dump state')).toEqual({ ok: true, value: { kind: 'DumpState' } })
+    expect(parseDebugCommand('perf on')).toEqual({ ok: true, value: { kind: 'Perf', enabled: true } })
+    expect(parseDebugCommand('group pair')).toEqual({
+      ok: true,
+      value: { kind: 'Group', groupId: 'pair' },
+    })
+  })
+
+  it('reports usage for malformed commands', () => {
+    expect(parseDebugCommand('teleport 3')).toEqual({ ok: false, error: 'usage: teleport <x> <y>' })
+    expect(parseDebugCommand('sett soon')).toEqual({ ok: false, error: 'usage: sett <t>' })
+    expect(parseDebugCommand('detect maybe')).toEqual({ ok: false, error: 'usage: detect on|off' })
+    expect(parseDebugCommand('fly')).toEqual({ ok: false, error: 'unknown command: fly (try help)' })
+    expect(parseDebugCommand('   ').ok).toBe(false)
+  })
+
+  it('dumps a summary of the game state', () => {
+    const state = gameReducer(undefined, { type: 'init' })
+    const lines = describeGameState(state)
+
+    expect(lines[1]).toContain('player=(5,5,t0)')
+    expect(lines[3]).toContain('box.main(8,6)')
+  })
+
+  it('runs commands in the slice being played', () => {
+    let state: GameState = gameReducer(undefined, { type: 'init' })
+    const getState = () => state
+    const dispatch = (action: UnknownAction) => {
+      state = gameReducer(state, action)
+    }
+
+    executeDebugCommand({ kind: 'SetTime', t: 3 }, getState, dispatch)
+    expect(state.worldLine.path.at(-1)).toEqual({ x: 5, y: 5, t: 3 })
+
+    executeDebugCommand({ kind: 'Teleport', position: { x: 1, y: 2 } }, getState, dispatch)
+    expect(state.worldLine.path.at(-1)).toEqual({ x: 1, y: 2, t: 3 })
+
+    expect(
+      executeDebugCommand({ kind: 'Detect', enabled: false }, getState, dispatch)[0],
+    ).toContain('enabled=false')
+    expect(state.detectionConfig.enabled).toBe(false)
+  })
+})
@@ -0,0 +1,188 @@
+import type { UnknownAction } from '@reduxjs/toolkit'
+
+import { groupIds, groupMembersAt } from '../../core/groups'
+import type { Position2D } from '../../core/position'
+import type { Result } from '../../core/result'
+import { objectsAtTime } from '../../core/timeCube'
+import { currentPosition } from '../../core/worldLine'
+import {
+  configureDetectionConfig,
+  debugSpawn,
+  debugTeleport,
+  type GameState,
+} from '../../game/gameSlice'
+
+/** Lines the console keeps; older output scrolls away. */
+export const DEBUG_CONSOLE_MAX_LINES = 40
+
+export type DebugCommand =
+  | { kind: 'Teleport'; position: Position2D }
+  | { kind: 'SetTime'; t: number }
+  | { kind: 'Spawn'; archetype: string; position: Position2D }
+  | { kind: 'Detect'; enabled: boolean }
+  | { kind: 'Perf'; enabled: boolean }
+  | { kind: 'DumpState' }
+  /** Members of `groupId` in the slice being played; every group when it is null. */
+  | { kind: 'Group'; groupId: string | null }
+  | { kind: 'Help' }
+
+export const DEBUG_COMMAND_USAGE = [
+  'teleport <x> <y>',
+  'sett <t>',
+  'spawn <archetype> <x> <y>',
+  'detect on|off',
+  'perf on|off',
+  'dump state',
+  'group [id]',
+  'help',
+]
+
+/** The console is a development aid: `?debug` in the page URL turns it on. */
+export function resolveDebugConsoleEnabled(search: string): boolean {
+  const value = new URLSearchParams(search).get('debug')
+
+  return value !== null && value !== '0' && value !== 'false'
+}
+
+function parseInteger(value: string | undefined): number | null {
+  return value !== undefined && /^-?\d+$/.test(value) ? Number(value) : null
+}
+
+function parseCell(x: string | undefined, y: string | undefined): Position2D | null {
+  const parsedX = parseInteger(x)
+  const parsedY = parseInteger(y)
+
+  return parsedX === null || parsedY === null ? null : { x: parsedX, y: parsedY }
+}
+
+export function parseDebugCommand(line: string): Result<DebugCommand, string> {
+  const [name, ...args] = line.trim().split(/\s+/)
+
+  switch (name?.toLowerCase()) {
+    case 'teleport': {
+      const position = parseCell(args[0], args[1])
+
+      return position && args.length === 2
+        ? { ok: true, value: { kind: 'Teleport', position } }
+        : { ok: false, error: 'usage: teleport <x> <y>' }
+    }
+    case 'sett': {
+      const t = parseInteger(args[0])
+
+      return t !== null && args.length === 1
+        ? { ok: true, value: { kind: 'SetTime', t } }
+        : { ok: false, error: 'usage: sett <t>' }
+    }
+    case 'spawn': {
+      const position = parseCell(args[1], args[2])
+
+      return args[0] && position && args.length === 3
+        ? { ok: true, value: { kind: 'Spawn', archetype: args[0], position } }
+        : { ok: false, error: 'usage: spawn <archetype> <x> <y>' }
+    }
+    case 'detect':
+      return args.length === 1 && (args[0] === 'on' || args[0] === 'off')
+        ? { ok: true, value: { kind: 'Detect', enabled: args[0] === 'on' } }
+        : { ok: false, error: 'usage: detect on|off' }
+    case 'perf':
+      return args.length === 1 && (args[0] === 'on' || args[0] === 'off')
+        ? { ok: true, value: { kind: 'Perf', enabled: args[0] === 'on' } }
+        : { ok: false, error: 'usage: perf on|off' }
+    case 'dump':
+      return args.length === 1 && args[0] === 'state'
+        ? { ok: true, value: { kind: 'DumpState' } }
+        : { ok: false, error: 'usage: dump state' }
+    case 'group':
+      return args.length <= 1
+        ? { ok: true, value: { kind: 'Group', groupId: args[0] ?? null } }
+        : { ok: false, error: 'usage: group [id]' }
+    case 'help':
+      return { ok: true, value: { kind: 'Help' } }
+    case '':
+    case undefined:
+      return { ok: false, error: 'empty command' }
+    default:
+      return { ok: false, error: `unknown command: ${name} (try help)` }
+  }
+}
+
+/** Summary printed by `dump state`; the full state also goes to the browser console. */
+export function describeGameState(state: GameState): string[] {
+  const player = currentPosition(state.worldLine)
+  const objects = objectsAtTime(state.cube, state.currentTime)
+
+  return [
+    `pack=${state.contentPackId} phase=${state.phase} turn=${state.turn} t=${state.currentTime}`,
+    `board=${state.boardWidth}x${state.boardHeight}x${state.timeDepth} player=${
+      player ? `(${player.x},${player.y},t${player.t})` : 'none'
+    }`,
+    `detection=${state.detectionConfig.enabled ? 'on' : 'off'} history=${state.history.length}`,
+    `objects@t${state.currentTime}: ${
+      objects
+        .map((object) => `${object.id}(${object.position.x},${object.position.y})`)
+        .join(' ') || 'none'
+    }`,
+  ]
+}
+
+/** One line per group member in slice `t`, or one line per group when `groupId` is null. */
+export function describeGroups(state: GameState, groupId: string | null): string[] {
+  const t = state.currentTime
+
+  if (groupId === null) {
+    const lines = groupIds(state.cube).map(
+      (id) => `${id}: ${groupMembersAt(state.cube, id, t).length} member(s) @t${t}`,
+    )
+
+    return lines.length > 0 ? lines : ['no groups']
+  }
+
+  const members = groupMembersAt(state.cube, groupId, t)
+
+  return members.length > 0
+    ? members.map((member) => `${member.id}(${member.position.x},${member.position.y})`)
+    : [`group ${groupId} has no members @t${t}`]
+}
+
+/**
+ * Runs one parsed command against the store and returns the lines to print. Cells are taken in
+ * the slice being played; `sett` keeps the player's cell and only changes the slice. `perf` only
+ * reports here: the performance window is shell state, so the caller toggles it.
+ */
+export function executeDebugCommand(
+  command: DebugCommand,
+  getState: () => GameState,
+  dispatch: (action: UnknownAction) => unknown,
+): string[] {
+  const game = getState()
+  const player = currentPosition(game.worldLine)
+
+  switch (command.kind) {
+    case 'Teleport':
+      dispatch(debugTeleport({ ...command.position, t: game.currentTime }))
+      return [getState().status]
+    case 'SetTime':
+      dispatch(debugTeleport({ x: player?.x ?? 0, y: player?.y ?? 0, t: command.t }))
+      return [getState().status]
+    case 'Spawn':
+      dispatch(
+        debugSpawn({
+          archetype: command.archetype,
+          position: { ...command.position, t: game.currentTime },
+        }),
+      )
+      return [getState().status]
+    case 'Detect':
+      dispatch(configureDetectionConfig({ enabled: command.enabled }))
+      return [getState().status]
+    case 'Perf':
+      return [`performance window ${command.enabled ? 'on' : 'off'}`]
+    case 'DumpState':
+      console.info('[debug] game state', game)
+      return describeGameState(game)
+    case 'Group':
+      return describeGroups(game, command.groupId)
+    case 'Help':
+      return DEBUG_COMMAND_USAGE
+  }
+}
@@ -0,0 +1,26 @@
+import { describe, expect, it } from 'vitest'
+
+import { gameReducer } from '../../game/gameSlice'
+import { exitCompass } from './exitCompass'
+
+describe('exitCompass', () => {
+  const { cube } = gameReducer(undefined, { type: 'init' })
+
+  it('points at a far exit with its Manhattan distance', () => {
+    expect(exitCompass(cube, { x: 5, y: 5, t: 0 })).toEqual({ point: 'SE', distance: 10 })
+    expect(exitCompass(cube, { x: 1, y: 9, t: 0 })).toEqual({ point: 'E', distance: 10 })
+    expect(exitCompass(cube, { x: 10, y: 2, t: 0 })).toEqual({ point: 'S', distance: 8 })
+  })
+
+  it('stays quiet near an exit in view but not near a hidden one', () => {
+    expect(exitCompass(cube, { x: 8, y: 9, t: 0 })).toBeNull()
+    expect(exitCompass(cube, { x: 8, y: 9, t: 0 }, () => false)).toEqual({
+      point: 'SE',
+      distance: 3,
+    })
+    expect(exitCompass(cube, { x: 10, y: 10, t: 0 }, () => false)).toEqual({
+      point: null,
+      distance: 0,
+    })
+  })
+})
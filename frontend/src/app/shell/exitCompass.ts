@@ -0,0 +1,66 @@
+import { hasComponent } from '../../core/components'
+import { wrappedAxisDelta, type Position2D, type Position3D } from '../../core/position'
+import { objectsAtTime, wrappingOf, type TimeCube } from '../../core/timeCube'
+
+export type CompassPoint = 'N' | 'NE' | 'E' | 'SE' | 'S' | 'SW' | 'W' | 'NW'
+
+/** Exits closer than this only get a compass when they are out of view. */
+export const EXIT_COMPASS_MIN_DISTANCE = 6
+
+export interface ExitCompass {
+  /** Null while standing on the exit. */
+  point: CompassPoint | null
+  /** Manhattan distance, the short way round on a torus. */
+  distance: number
+}
+
+function compassPoint(dx: number, dy: number): CompassPoint | null {
+  // An axis counts when it is at least half the other one, so 4 east and 2 south reads SE.
+  const vertical = Math.abs(dy) * 2 >= Math.abs(dx)
+  const horizontal = Math.abs(dx) * 2 >= Math.abs(dy)
+  const north = vertical && dy < 0 ? 'N' : ''
+  const south = vertical && dy > 0 ? 'S' : ''
+  const east = horizontal && dx > 0 ? 'E' : ''
+  const west = horizontal && dx < 0 ? 'W' : ''
+  const point = `${north}${south}${east}${west}`
+
+  return point === '' ? null : (point as CompassPoint)
+}
+
+/**
+ * Heading and distance from the player to the nearest exit in the player's slice. Null when the
+ * slice has no exit, or when the nearest one is both close and in view.
+ */
+export function exitCompass(
+  cube: TimeCube,
+  player: Position3D,
+  isInView: (position: Position2D) => boolean = () => true,
+): ExitCompass | null {
+  const wrapping = wrappingOf(cube)
+  let nearest: { dx: number; dy: number; position: Position2D } | null = null
+
+  for (const object of objectsAtTime(cube, player.t)) {
+    if (!hasComponent(object.archetype.components, 'Exit')) {
+      continue
+    }
+
+    const dx = wrappedAxisDelta(player.x, object.position.x, wrapping.width)
+    const dy = wrappedAxisDelta(player.y, object.position.y, wrapping.height)
+
+    if (!nearest || Math.abs(dx) + Math.abs(dy) < Math.abs(nearest.dx) + Math.abs(nearest.dy)) {
+      nearest = { dx, dy, position: object.position }
+    }
+  }
+
+  if (!nearest) {
+    return null
+  }
+
+  const distance = Math.abs(nearest.dx) + Math.abs(nearest.dy)
+
+  if (distance < EXIT_COMPASS_MIN_DISTANCE && isInView(nearest.position)) {
+    return null
+  }
+
+  return { point: compassPoint(nearest.dx, nearest.dy), distance }
+}
@@ -0,0 +1,156 @@
+import { describe, expect, it } from 'vitest'
+
+import { createObjectRegistry, resolveObjectInstance, type ObjectInstance } from '../../core/objects'
+import { createTimeCube, placeObjects } from '../../core/timeCube'
+import { createTranslator } from '../../render/i18n'
+import {
+  applyKeymapOverrides,
+  bindingsForMechanics,
+  buildBottomBarHints,
+  commandForKey,
+  defaultKeymap,
+  detectLevelMechanics,
+} from './keymap'
+
+const registry = createObjectRegistry({
+  box: {
+    kind: 'box',
+    components: [{ kind: 'BlocksMovement' }, { kind: 'Pushable' }, { kind: 'TimePersistent' }],
+    render: {},
+  },
+  rift: {
+    kind: 'rift',
+    components: [{ kind: 'Rift', target: { x: 1, y: 1, t: 0 }, bidirectional: false }],
+    render: {},
+  },
+})
+
+const t = createTranslator('en')
+
+function resolve(instance: ObjectInstance) {
+  const resolved = resolveObjectInstance(registry, instance)
+  if (!resolved.ok) {
+    throw new Error('resolution failed in test fixture')
+  }
+
+  return resolved.value
+}
+
+describe('keymap', () => {
+  it('resolves commands case-insensitively for letter keys', () => {
+    expect(commandForKey(defaultKeymap, 'W')).toBe('North')
+    expect(commandForKey(defaultKeymap, 'ArrowLeft')).toBe('West')
+    expect(commandForKey(defaultKeymap, '?')).toBe('ToggleHelp')
+    expect(commandForKey(defaultKeymap, 'X')).toBe('ToggleExamine')
+    expect(commandForKey(defaultKeymap, 'j')).toBeNull()
+  })
+
+  it('binds every key to at most one command', () => {
+    const keys = defaultKeymap.flatMap((binding) => binding.keys.map((key) => key.toLowerCase()))
+
+    expect(new Set(keys).size).toBe(keys.length)
+  })
+
+  it('rebinds overridden commands and frees their keys elsewhere', () => {
+    const keymap = applyKeymapOverrides(defaultKeymap, { Wait: ['Enter', 'r'] })
+
+    expect(commandForKey(keymap, 'R')).toBe('Wait')
+    expect(keymap.find((binding) => binding.command === 'Restart')?.keys).toEqual([])
+    expect(commandForKey(keymap, 'w')).toBe('North')
+  })
+
+  it('detects push and rift-tile mechanics from level objects', () => {
+    const placed = placeObjects(createTimeCube(4, 4, 3), [
+      resolve({ id: 'box.1', archetype: 'box', position: { x: 2, y: 2, t: 0 } }),
+    ])
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    expect(detectLevelMechanics(placed.value, true)).toEqual({
+      push: true,
+      pull: false,
+      timePush: false,
+      riftTiles: false,
+      slippery: false,
+      lures: false,
+      characters: false,
+      elevation: false,
+      breakables: false,
+      water: false,
+      shadows: false,
+      branching: false,
+      peek: false,
+      interactables: false,
+      knockouts: false,
+    })
+
+    const withRift = placeObjects(placed.value, [
+      resolve({ id: 'rift.1', archetype: 'rift', position: { x: 3, y: 3, t: 1 } }),
+    ])
+    expect(withRift.ok && detectLevelMechanics(withRift.value, true).riftTiles).toBe(true)
+  })
+
+  it('hides mechanic-specific bindings when the level lacks them', () => {
+    const mechanics = {
+      push: false,
+      pull: false,
+      timePush: false,
+      riftTiles: false,
+      slippery: false,
+      lures: false,
+      characters: false,
+      elevation: false,
+      breakables: false,
+      water: false,
+      shadows: false,
+      branching: false,
+      peek: false,
+      interactables: false,
+      knockouts: false,
+    }
+    const commands = bindingsForMechanics(defaultKeymap, mechanics).map((binding) => binding.command)
+
+    expect(commands).not.toContain('SelectPush')
+    expect(commands).not.toContain('PushChainUp')
+    expect(commands).not.toContain('SelectThrow')
+    expect(commands).not.toContain('SwitchCharacter')
+    expect(commands).not.toContain('Climb')
+    expect(commands).not.toContain('SelectBreak')
+    expect(commands).not.toContain('CycleTimeline')
+    expect(commands).not.toContain('SelectPeek')
+    expect(commands).not.toContain('SelectInteract')
+    expect(commands).toContain('Rift')
+    expect(buildBottomBarHints(defaultKeymap, mechanics, false, t)).not.toContain('- Push Max -')
+  })
+
+  it('collapses direction keys into one bottom-bar entry', () => {
+    const hints = buildBottomBarHints(
+      defaultKeymap,
+      {
+        push: true,
+        pull: true,
+        timePush: true,
+        riftTiles: true,
+        slippery: true,
+        lures: true,
+        characters: true,
+        elevation: true,
+        breakables: true,
+        water: true,
+        shadows: true,
+        branching: true,
+        peek: true,
+        interactables: true,
+        knockouts: true,
+      },
+      true,
+      t,
+    )
+
+    expect(hints[0]).toBe('WASD/↑←↓→ Direction')
+    expect(hints).toContain('? Help')
+    expect(hints).not.toContain('L Log')
+  })
+})
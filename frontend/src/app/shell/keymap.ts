@@ -0,0 +1,294 @@
+import type { Component } from '../../core/components'
+import type { Direction2D } from '../../core/position'
+import { objectsWithComponent, type TimeCube } from '../../core/timeCube'
+import type { Translator } from '../../render/i18n'
+
+export type KeyCommand =
+  | 'ToggleActionMenu'
+  | 'ToggleLevels'
+  | 'ToggleState'
+  | 'ToggleLog'
+  | 'ToggleSettings'
+  | 'ToggleHelp'
+  | 'TogglePlan'
+  | 'CommitPlan'
+  | 'UndoPlanStep'
+  | 'ToggleExamine'
+  | 'ToggleWorldLine'
+  | 'SnapshotSlice'
+  | 'SnapshotAllSlices'
+  | 'CloseLayer'
+  | 'North'
+  | 'West'
+  | 'South'
+  | 'East'
+  | 'SelectMove'
+  | 'SelectPush'
+  | 'SelectPull'
+  | 'SelectTimePush'
+  | 'SelectThrow'
+  | 'SelectBreak'
+  | 'SelectPeek'
+  | 'SelectInteract'
+  | 'Wait'
+  | 'Rift'
+  | 'SwitchCharacter'
+  | 'Climb'
+  | 'Hint'
+  | 'Restart'
+  | 'ResumeSession'
+  | 'ToggleDanger'
+  | 'ToggleLayerView'
+  | 'ToggleTimelineDiff'
+  | 'CycleTimeline'
+  | 'NextPack'
+  | 'RiftDeltaDown'
+  | 'RiftDeltaUp'
+  | 'PushChainDown'
+  | 'PushChainUp'
+  | 'ToggleHotseat'
+  | 'Quit'
+
+export type KeyBindingGroup = 'Movement' | 'Actions' | 'Interface' | 'Tuning'
+
+/** Level feature a binding only matters for; bindings without one are always shown. */
+export type LevelMechanic =
+  | 'push'
+  | 'pull'
+  | 'timePush'
+  | 'riftTiles'
+  | 'slippery'
+  | 'lures'
+  | 'characters'
+  | 'elevation'
+  | 'breakables'
+  | 'water'
+  | 'shadows'
+  | 'branching'
+  | 'peek'
+  | 'interactables'
+  | 'knockouts'
+
+export interface KeyBinding {
+  command: KeyCommand
+  keys: string[]
+  group: KeyBindingGroup
+  mechanic?: LevelMechanic
+  /** Shown in the compact bottom bar as well as the full one. */
+  compact?: boolean
+  /** Held-key repeats re-issue the command (throttled by the key repeat delay setting). */
+  repeatable?: boolean
+}
+
+export type LevelMechanics = Record<LevelMechanic, boolean>
+
+export const KEY_BINDING_GROUPS: KeyBindingGroup[] = ['Movement', 'Actions', 'Interface', 'Tuning']
+
+export const DIRECTION_BY_COMMAND: Partial<Record<KeyCommand, Direction2D>> = {
+  North: 'north',
+  West: 'west',
+  South: 'south',
+  East: 'east',
+}
+
+export const defaultKeymap: KeyBinding[] = [
+  { command: 'North', keys: ['w', 'ArrowUp'], group: 'Movement', repeatable: true },
+  { command: 'West', keys: ['a', 'ArrowLeft'], group: 'Movement', repeatable: true },
+  { command: 'South', keys: ['s', 'ArrowDown'], group: 'Movement', repeatable: true },
+  { command: 'East', keys: ['d', 'ArrowRight'], group: 'Movement', repeatable: true },
+  { command: 'SelectMove', keys: ['1'], group: 'Movement' },
+  { command: 'SelectPush', keys: ['2'], group: 'Movement', mechanic: 'push' },
+  { command: 'SelectPull', keys: ['3'], group: 'Movement', mechanic: 'pull' },
+  { command: 'SelectTimePush', keys: ['4'], group: 'Movement', mechanic: 'timePush' },
+  { command: 'SelectThrow', keys: ['5'], group: 'Movement', mechanic: 'lures' },
+  { command: 'SelectBreak', keys: ['6'], group: 'Movement', mechanic: 'breakables' },
+  { command: 'SelectPeek', keys: ['7'], group: 'Movement', mechanic: 'peek' },
+  { command: 'SelectInteract', keys: ['8'], group: 'Movement', mechanic: 'interactables' },
+  { command: 'Wait', keys: ['Enter'], group: 'Actions', compact: true, repeatable: true },
+  { command: 'Rift', keys: [' '], group: 'Actions', compact: true },
+  {
+    command: 'SwitchCharacter',
+    keys: ['c'],
+    group: 'Actions',
+    mechanic: 'characters',
+    compact: true,
+  },
+  { command: 'Climb', keys: ['e'], group: 'Actions', mechanic: 'elevation', compact: true },
+  { command: 'Hint', keys: ['h'], group: 'Actions' },
+  { command: 'Restart', keys: ['r'], group: 'Actions', compact: true },
+  { command: 'ResumeSession', keys: ['u'], group: 'Actions' },
+  { command: 'ToggleActionMenu', keys: ['f'], group: 'Interface', compact: true },
+  { command: 'ToggleLevels', keys: ['g'], group: 'Interface', compact: true },
+  { command: 'ToggleState', keys: ['Tab'], group: 'Interface', compact: true },
+  { command: 'ToggleLog', keys: ['l'], group: 'Interface' },
+  { command: 'ToggleSettings', keys: ['m'], group: 'Interface', compact: true },
+  { command: 'ToggleHelp', keys: ['?'], group: 'Interface', compact: true },
+  { command: 'TogglePlan', keys: ['b'], group: 'Interface' },
+  { command: 'CommitPlan', keys: ['y'], group: 'Interface' },
+  { command: 'UndoPlanStep', keys: ['Backspace'], group: 'Interface' },
+  { command: 'ToggleExamine', keys: ['x'], group: 'Interface' },
+  { command: 'ToggleWorldLine', keys: ['j'], group: 'Interface' },
+  { command: 'SnapshotSlice', keys: ['o'], group: 'Interface' },
+  { command: 'SnapshotAllSlices', keys: ['i'], group: 'Interface' },
+  { command: 'CloseLayer', keys: ['Escape'], group: 'Interface' },
+  { command: 'ToggleDanger', keys: ['p'], group: 'Tuning' },
+  { command: 'ToggleLayerView', keys: ['z'], group: 'Tuning', mechanic: 'elevation' },
+  { command: 'ToggleTimelineDiff', keys: ['t'], group: 'Tuning' },
+  { command: 'CycleTimeline', keys: ['n'], group: 'Tuning', mechanic: 'branching' },
+  { command: 'NextPack', keys: ['v'], group: 'Tuning' },
+  { command: 'RiftDeltaDown', keys: ['['], group: 'Tuning' },
+  { command: 'RiftDeltaUp', keys: [']'], group: 'Tuning' },
+  { command: 'PushChainDown', keys: ['-'], group: 'Tuning', mechanic: 'push' },
+  { command: 'PushChainUp', keys: ['='], group: 'Tuning', mechanic: 'push' },
+  { command: 'ToggleHotseat', keys: ['k'], group: 'Tuning', mechanic: 'characters' },
+  { command: 'Quit', keys: ['q'], group: 'Tuning' },
+]
+
+function normalizeKey(key: string): string {
+  return key.length === 1 ? key.toLowerCase() : key
+}
+
+export function isKeyCommand(value: string): value is KeyCommand {
+  return defaultKeymap.some((binding) => binding.command === value)
+}
+
+/**
+ * Rebinds the commands listed in `overrides`. A key taken by an override is dropped from
+ * whichever other command had it, so it is never bound twice.
+ */
+export function applyKeymapOverrides(
+  keymap: KeyBinding[],
+  overrides: Partial<Record<KeyCommand, string[]>>,
+): KeyBinding[] {
+  const claimed = new Set(
+    Object.values(overrides).flatMap((keys) => (keys ?? []).map(normalizeKey)),
+  )
+
+  return keymap.map((binding) => {
+    const keys = overrides[binding.command]
+
+    return keys
+      ? { ...binding, keys }
+      : { ...binding, keys: binding.keys.filter((key) => !claimed.has(normalizeKey(key))) }
+  })
+}
+
+export function commandForKey(keymap: KeyBinding[], key: string): KeyCommand | null {
+  const normalized = normalizeKey(key)
+
+  for (const binding of keymap) {
+    if (binding.keys.some((candidate) => normalizeKey(candidate) === normalized)) {
+      return binding.command
+    }
+  }
+
+  return null
+}
+
+export function isRepeatableCommand(keymap: KeyBinding[], command: KeyCommand): boolean {
+  return keymap.some((binding) => binding.command === command && binding.repeatable === true)
+}
+
+export function formatKey(key: string): string {
+  switch (key) {
+    case ' ':
+      return 'Space'
+    case 'Escape':
+      return 'Esc'
+    case 'ArrowUp':
+      return '↑'
+    case 'ArrowDown':
+      return '↓'
+    case 'ArrowLeft':
+      return '←'
+    case 'ArrowRight':
+      return '→'
+    default:
+      return key.length === 1 ? key.toUpperCase() : key
+  }
+}
+
+export function formatBindingKeys(binding: KeyBinding): string {
+  return binding.keys.map(formatKey).join(' / ')
+}
+
+export function bindingLabel(t: Translator, binding: KeyBinding): string {
+  return t(`command.${binding.command}.label`)
+}
+
+export function bindingDescription(t: Translator, binding: KeyBinding): string {
+  return t(`command.${binding.command}.description`)
+}
+
+export function keysForCommand(keymap: KeyBinding[], command: KeyCommand): string {
+  const binding = keymap.find((candidate) => candidate.command === command)
+  return binding ? formatBindingKeys(binding) : 'unbound'
+}
+
+export function detectLevelMechanics(
+  cube: TimeCube,
+  allowPull: boolean,
+  allowTimePush = false,
+  lureSupply = 0,
+  characterCount = 1,
+  branching = false,
+  peekBudget = 0,
+  knockouts = false,
+): LevelMechanics {
+  const has = (kind: Component['kind']) => objectsWithComponent(cube, kind).length > 0
+  const push = has('Pushable')
+
+  return {
+    push,
+    pull: allowPull && has('Pullable'),
+    timePush: allowTimePush && push,
+    riftTiles: has('Rift'),
+    slippery: has('Slippery'),
+    lures: lureSupply > 0,
+    characters: characterCount > 1,
+    elevation: has('Catwalk'),
+    breakables: has('Breakable'),
+    water: has('Water'),
+    shadows: has('Shadow'),
+    branching,
+    peek: peekBudget > 0,
+    // Knocking a guard out is an Interact too, so those levels get the Use mode as well.
+    interactables: has('Interactable') || knockouts,
+    knockouts,
+  }
+}
+
+export function bindingsForMechanics(
+  keymap: KeyBinding[],
+  mechanics: LevelMechanics,
+): KeyBinding[] {
+  return keymap.filter((binding) => !binding.mechanic || mechanics[binding.mechanic])
+}
+
+/** Bottom-bar labels: direction keys collapse into one entry, everything else is listed as-is. */
+export function buildBottomBarHints(
+  keymap: KeyBinding[],
+  mechanics: LevelMechanics,
+  compact: boolean,
+  t: Translator,
+): string[] {
+  const visible = bindingsForMechanics(keymap, mechanics)
+  const directions = visible.filter((binding) => binding.command in DIRECTION_BY_COMMAND)
+  const hints: string[] = []
+
+  if (directions.length > 0) {
+    const primary = directions.map((binding) => formatKey(binding.keys[0] ?? '')).join('')
+    const secondary = directions.map((binding) => formatKey(binding.keys[1] ?? '')).join('')
+    hints.push(`${primary}${secondary ? `/${secondary}` : ''} ${t('bottomBar.direction')}`)
+  }
+
+  for (const binding of visible) {
+    if (binding.command in DIRECTION_BY_COMMAND || (compact && !binding.compact)) {
+      continue
+    }
+
+    hints.push(`${formatBindingKeys(binding)} ${bindingLabel(t, binding)}`)
+  }
+
+  return hints
+}
@@ -0,0 +1,60 @@
+import { describe, expect, it } from 'vitest'
+
+import type { ProgressionEntry } from '../../data/progression'
+import {
+  cycleFilterValue,
+  DEFAULT_LEVEL_FILTER,
+  levelFilterOptions,
+  nextLevelSelectTab,
+  visibleEntryIndices,
+} from './levelFilter'
+import type { PackDisplayMeta } from './useContentPackLoading'
+
+const entries: ProgressionEntry[] = [
+  { packId: 'intro', difficulty: 'easy' },
+  { packId: 'vault', tags: ['heist'] },
+  { packId: 'rooftops' },
+]
+const packMetaById: Record<string, PackDisplayMeta> = {
+  vault: { difficulty: 'expert', tags: ['night'] },
+  rooftops: { difficulty: 'normal', tags: ['night'] },
+}
+
+describe('level filter', () => {
+  it('lists difficulties in tier order and tags alphabetically', () => {
+    expect(levelFilterOptions(entries, packMetaById)).toEqual({
+      difficulties: ['easy', 'normal', 'expert'],
+      tags: ['heist', 'night'],
+    })
+  })
+
+  it('filters by difficulty and tag, merging entry and manifest metadata', () => {
+    expect(visibleEntryIndices(entries, packMetaById, DEFAULT_LEVEL_FILTER)).toEqual([0, 1, 2])
+    expect(
+      visibleEntryIndices(entries, packMetaById, { ...DEFAULT_LEVEL_FILTER, tag: 'night' }),
+    ).toEqual([1, 2])
+    expect(
+      visibleEntryIndices(entries, packMetaById, { ...DEFAULT_LEVEL_FILTER, difficulty: 'easy' }),
+    ).toEqual([0])
+  })
+
+  it('sorts by difficulty while keeping track indices', () => {
+    expect(
+      visibleEntryIndices(entries, packMetaById, { ...DEFAULT_LEVEL_FILTER, sort: 'difficulty' }),
+    ).toEqual([0, 2, 1])
+  })
+
+  it('cycles through each option and back to all', () => {
+    expect(cycleFilterValue(['a', 'b'], null)).toBe('a')
+    expect(cycleFilterValue(['a', 'b'], 'a')).toBe('b')
+    expect(cycleFilterValue(['a', 'b'], 'b')).toBeNull()
+    expect(cycleFilterValue([], null)).toBeNull()
+  })
+
+  it('cycles the level select tabs in order', () => {
+    expect(nextLevelSelectTab('tracks')).toBe('community')
+    expect(nextLevelSelectTab('community')).toBe('records')
+    expect(nextLevelSelectTab('records')).toBe('challenge')
+    expect(nextLevelSelectTab('challenge')).toBe('tracks')
+  })
+})
@@ -0,0 +1,112 @@
+import type { ProgressionEntry } from '../../data/progression'
+import type { PackDisplayMeta } from './useContentPackLoading'
+
+export type LevelSort = 'track' | 'difficulty'
+
+/**
+ * Level select shows the progression tracks, the user levels directory, personal bests or the
+ * challenge mutators applied to whatever level loads next.
+ */
+export type LevelSelectTab = 'tracks' | 'community' | 'records' | 'challenge'
+
+export const LEVEL_SELECT_TABS: LevelSelectTab[] = ['tracks', 'community', 'records', 'challenge']
+
+export function nextLevelSelectTab(tab: LevelSelectTab): LevelSelectTab {
+  return LEVEL_SELECT_TABS[(LEVEL_SELECT_TABS.indexOf(tab) + 1) % LEVEL_SELECT_TABS.length]
+}
+
+/** Level-select narrowing; null fields match everything. */
+export interface LevelFilter {
+  difficulty: string | null
+  tag: string | null
+  sort: LevelSort
+}
+
+export const DEFAULT_LEVEL_FILTER: LevelFilter = { difficulty: null, tag: null, sort: 'track' }
+
+const DIFFICULTY_ORDER = ['easy', 'normal', 'hard', 'expert']
+
+export function entryDifficulty(
+  entry: ProgressionEntry,
+  meta: PackDisplayMeta | undefined,
+): string | null {
+  return entry.difficulty ?? meta?.difficulty ?? null
+}
+
+export function entryTags(entry: ProgressionEntry, meta: PackDisplayMeta | undefined): string[] {
+  return [...new Set([...(entry.tags ?? []), ...(meta?.tags ?? [])])]
+}
+
+function difficultyRank(difficulty: string | null): number {
+  const index = difficulty ? DIFFICULTY_ORDER.indexOf(difficulty) : -1
+
+  return index < 0 ? DIFFICULTY_ORDER.length : index
+}
+
+/** Difficulties and tags present in a track, in display order, for cycling the filters. */
+export function levelFilterOptions(
+  entries: ProgressionEntry[],
+  packMetaById: Record<string, PackDisplayMeta>,
+): { difficulties: string[]; tags: string[] } {
+  const difficulties = new Set<string>()
+  const tags = new Set<string>()
+
+  for (const entry of entries) {
+    const meta = packMetaById[entry.packId]
+    const difficulty = entryDifficulty(entry, meta)
+
+    if (difficulty) {
+      difficulties.add(difficulty)
+    }
+
+    for (const tag of entryTags(entry, meta)) {
+      tags.add(tag)
+    }
+  }
+
+  return {
+    difficulties: [...difficulties].sort((a, b) => difficultyRank(a) - difficultyRank(b)),
+    tags: [...tags].sort(),
+  }
+}
+
+/**
+ * Indices into `entries` that pass the filter, in display order. Indices stay those of the track
+ * so selection and unlock state keep working on the unfiltered list.
+ */
+export function visibleEntryIndices(
+  entries: ProgressionEntry[],
+  packMetaById: Record<string, PackDisplayMeta>,
+  filter: LevelFilter,
+): number[] {
+  const indices = entries.flatMap((entry, index) => {
+    const meta = packMetaById[entry.packId]
+
+    if (filter.difficulty !== null && entryDifficulty(entry, meta) !== filter.difficulty) {
+      return []
+    }
+
+    if (filter.tag !== null && !entryTags(entry, meta).includes(filter.tag)) {
+      return []
+    }
+
+    return [index]
+  })
+
+  if (filter.sort === 'difficulty') {
+    const rankOf = (index: number) =>
+      difficultyRank(entryDifficulty(entries[index], packMetaById[entries[index].packId]))
+
+    // Array sort is stable, so equal difficulties keep their track order.
+    indices.sort((a, b) => rankOf(a) - rankOf(b))
+  }
+
+  return indices
+}
+
+/** Next value in `options` after `current`, wrapping through null ("all"). */
+export function cycleFilterValue(options: string[], current: string | null): string | null {
+  const index = current === null ? -1 : options.indexOf(current)
+
+  return index + 1 < options.length ? options[index + 1] : null
+}
@@ -0,0 +1,45 @@
+import { describe, expect, it } from 'vitest'
+
+import { createMemoryTraceSink, setTraceSink } from '../../core/trace'
+import { gameReducer, performInteraction } from '../../game/gameSlice'
+import { entitiesPerSlice, perfSample } from './perfHud'
+
+describe('perfHud', () => {
+  it('counts the entities standing in each slice', () => {
+    const state = gameReducer(undefined, { type: 'init' })
+    const counts = entitiesPerSlice(state.cube)
+
+    expect(counts).toHaveLength(state.timeDepth)
+    expect(counts[0]).toBe(5)
+  })
+
+  it('times an action and its stages and counts the slices it changed', () => {
+    const before = gameReducer(undefined, { type: 'init' })
+    const sink = createMemoryTraceSink()
+    let after = before
+
+    setTraceSink(sink)
+    try {
+      after = gameReducer(before, performInteraction({ kind: 'Wait' }))
+    } finally {
+      setTraceSink(null)
+    }
+
+    const sample = perfSample(sink.records, before.cube, after)
+
+    expect(sample.spans.map((entry) => entry.span)).toEqual([
+      'interaction',
+      'propagate.dependencies',
+      'propagate.detours',
+      'propagate.lures',
+      'propagate.chasers',
+      'paradox',
+      'detection',
+    ])
+    expect(sample.applyUs).toBeGreaterThanOrEqual(0)
+    expect(sample.slicesUpdated).toBe(0)
+    expect(sample.propagationWarnings).toBe(0)
+    expect(sample.cubeBytes).toBeGreaterThan(0)
+    expect(sample.stateBytes).toBeGreaterThan(sample.cubeBytes)
+  })
+})
@@ -0,0 +1,67 @@
+import { objectsAtTime, type TimeCube } from '../../core/timeCube'
+import type { TraceRecord } from '../../core/trace'
+import type { GameState } from '../../game/gameSlice'
+import { diffCubeObjects } from '../../game/stateDiff'
+
+export interface PerfSpanTiming {
+  span: string
+  us: number
+}
+
+/** What the last action cost; read from the trace spans it ran and the state it left. */
+export interface PerfSample {
+  /** Time inside the outermost spans (one `pipeline` per action applied), in microseconds. */
+  applyUs: number
+  /** Stages one level down, in the order they first ran; repeats (slide detection) add up. */
+  spans: PerfSpanTiming[]
+  /** Slices where some object moved, appeared or disappeared. */
+  slicesUpdated: number
+  /** Guards that kept their old cells because their re-resolved route collided. */
+  propagationWarnings: number
+  /** Serialized sizes of the state and its cube, the data an immutable update copies from. */
+  stateBytes: number
+  cubeBytes: number
+}
+
+export function entitiesPerSlice(cube: TimeCube): number[] {
+  return Array.from({ length: cube.timeDepth }, (_, t) => objectsAtTime(cube, t).length)
+}
+
+function toMicroseconds(ms: number): number {
+  return Math.round(ms * 1000)
+}
+
+export function perfSample(
+  records: TraceRecord[],
+  cubeBefore: TimeCube,
+  state: GameState,
+): PerfSample {
+  const spans = new Map<string, number>()
+  let applyMs = 0
+  let propagationWarnings = 0
+
+  for (const record of records) {
+    if (record.depth === 0) {
+      applyMs += record.durationMs
+    } else if (record.depth === 1) {
+      spans.set(record.span, (spans.get(record.span) ?? 0) + record.durationMs)
+
+      if (typeof record.fields.warnings === 'number') {
+        propagationWarnings += record.fields.warnings
+      }
+    }
+  }
+
+  const changedSlices = new Set(
+    diffCubeObjects(cubeBefore, state.cube).map((change) => change.t),
+  )
+
+  return {
+    applyUs: toMicroseconds(applyMs),
+    spans: [...spans].map(([span, ms]) => ({ span, us: toMicroseconds(ms) })),
+    slicesUpdated: changedSlices.size,
+    propagationWarnings,
+    stateBytes: JSON.stringify(state).length,
+    cubeBytes: JSON.stringify(state.cube).length,
+  }
+}
@@ -0,0 +1,55 @@
+import { describe, expect, it } from 'vitest'
+
+import type { Region } from '../../core/regions'
+import { createTranslator } from '../../render/i18n'
+import { compareReplays, describeReplayComparison } from './replayDiff'
+import type { ReplayRecord } from './useReplayStore'
+
+const regions: Region[] = [
+  { id: 'courtyard', name: 'Courtyard', x: 0, y: 0, width: 3, height: 3 },
+  { id: 'hall', name: 'Hall', x: 0, y: 5, width: 3, height: 3 },
+]
+
+function run(path: [number, number][], rifts = 0): ReplayRecord {
+  return {
+    packId: 'default',
+    turns: path.length - 1,
+    actions: [
+      ...Array.from({ length: path.length - 1 - rifts }, () => ({ kind: 'Wait' as const })),
+      ...Array.from({ length: rifts }, () => ({ kind: 'ApplyRift' as const })),
+    ],
+    path: path.map(([x, y], t) => ({ x, y, t })),
+    hintsUsed: 0,
+  }
+}
+
+describe('compareReplays', () => {
+  it('reports turns saved, rift use and the regions each route crossed', () => {
+    const best = run([[1, 1], [1, 2], [1, 3], [1, 4], [1, 5]], 1)
+    const fresh = run([[5, 4], [4, 4], [4, 5]])
+
+    const comparison = compareReplays(fresh, best, regions)
+
+    expect(comparison.turnsSaved).toBe(2)
+    expect(comparison.riftDelta).toBe(-1)
+    expect(comparison.avoidedRegions.map((region) => region.id)).toEqual(['courtyard', 'hall'])
+    expect(comparison.newRegions).toEqual([])
+    expect(describeReplayComparison(createTranslator('en'), comparison)).toEqual([
+      '2 turn(s) faster than your best',
+      '1 fewer rift(s)',
+      'You avoided Courtyard, Hall this time',
+    ])
+  })
+
+  it('says so when the new run is slower or ties', () => {
+    const best = run([[4, 4], [4, 4]])
+    const t = createTranslator('en')
+
+    expect(describeReplayComparison(t, compareReplays(best, best, regions))).toEqual([
+      'Same turn count as your best',
+    ])
+    expect(
+      describeReplayComparison(t, compareReplays(run([[1, 1], [4, 4], [4, 4]]), best, regions)),
+    ).toEqual(['1 turn(s) slower than your best', 'You went through Courtyard this time'])
+  })
+})
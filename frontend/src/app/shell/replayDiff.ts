@@ -0,0 +1,76 @@
+import { regionsAt, type Region } from '../../core/regions'
+import type { Translator } from '../../render/i18n'
+import type { ReplayRecord } from './useReplayStore'
+
+/** How a fresh win differs from the best run stored before it. */
+export interface ReplayComparison {
+  /** Positive when the new run needed fewer turns than the best. */
+  turnsSaved: number
+  /** Rifts in the new run minus rifts in the best. */
+  riftDelta: number
+  /** Regions the best run passed through and the new run never entered. */
+  avoidedRegions: Region[]
+  /** Regions the new run entered that the best never did. */
+  newRegions: Region[]
+}
+
+function riftCount(record: ReplayRecord): number {
+  return record.actions.filter((action) => action.kind === 'ApplyRift').length
+}
+
+function regionsVisited(record: ReplayRecord, regions: Region[]): Set<string> {
+  return new Set(
+    record.path.flatMap((position) => regionsAt(regions, position).map((region) => region.id)),
+  )
+}
+
+export function compareReplays(
+  run: ReplayRecord,
+  best: ReplayRecord,
+  regions: Region[],
+): ReplayComparison {
+  const visitedNow = regionsVisited(run, regions)
+  const visitedBest = regionsVisited(best, regions)
+
+  return {
+    turnsSaved: best.turns - run.turns,
+    riftDelta: riftCount(run) - riftCount(best),
+    avoidedRegions: regions.filter(
+      (region) => visitedBest.has(region.id) && !visitedNow.has(region.id),
+    ),
+    newRegions: regions.filter(
+      (region) => visitedNow.has(region.id) && !visitedBest.has(region.id),
+    ),
+  }
+}
+
+/** Victory-window lines, most important first: turns, then rifts, then route changes. */
+export function describeReplayComparison(t: Translator, comparison: ReplayComparison): string[] {
+  const lines = [
+    comparison.turnsSaved > 0
+      ? t('replay.faster', { count: comparison.turnsSaved })
+      : comparison.turnsSaved < 0
+        ? t('replay.slower', { count: -comparison.turnsSaved })
+        : t('replay.tied'),
+  ]
+
+  if (comparison.riftDelta !== 0) {
+    lines.push(
+      comparison.riftDelta < 0
+        ? t('replay.fewerRifts', { count: -comparison.riftDelta })
+        : t('replay.moreRifts', { count: comparison.riftDelta }),
+    )
+  }
+
+  if (comparison.avoidedRegions.length > 0) {
+    const names = comparison.avoidedRegions.map((region) => region.name).join(', ')
+    lines.push(t('replay.avoided', { names }))
+  }
+
+  if (comparison.newRegions.length > 0) {
+    const names = comparison.newRegions.map((region) => region.name).join(', ')
+    lines.push(t('replay.visited', { names }))
+  }
+
+  return lines
+}
@@ -0,0 +1,56 @@
+import { describe, expect, it } from 'vitest'
+
+import {
+  formatElapsed,
+  IDLE_SPEEDRUN_CLOCK,
+  speedrunElapsedMs,
+  syncSpeedrunClock,
+  trackSplits,
+} from './speedrun'
+import { createEmptyReplaySnapshot } from './useReplayStore'
+
+describe('speedrun clock', () => {
+  it('starts on the first turn, stops when the run ends and resets on restart', () => {
+    const idle = syncSpeedrunClock(IDLE_SPEEDRUN_CLOCK, 0, 'Playing', 100)
+    const running = syncSpeedrunClock(idle, 1, 'Playing', 250)
+    const still = syncSpeedrunClock(running, 2, 'Playing', 400)
+    const won = syncSpeedrunClock(still, 3, 'Won', 1_250)
+
+    expect(idle).toBe(IDLE_SPEEDRUN_CLOCK)
+    expect(still).toBe(running)
+    expect(speedrunElapsedMs(running, 900)).toBe(650)
+    expect(speedrunElapsedMs(won, 9_000)).toBe(1_000)
+    expect(syncSpeedrunClock(won, 0, 'Playing', 2_000)).toEqual(IDLE_SPEEDRUN_CLOCK)
+  })
+
+  it('formats elapsed time as minutes, seconds and hundredths', () => {
+    expect(formatElapsed(0)).toBe('0:00.00')
+    expect(formatElapsed(61_239)).toBe('1:01.23')
+    expect(formatElapsed(3_725_000)).toBe('62:05.00')
+  })
+})
+
+describe('trackSplits', () => {
+  it('adds up best times level by level and drops the total after a gap', () => {
+    const snapshot = createEmptyReplaySnapshot()
+    snapshot.statsByPackId = {
+      one: { wins: 1, hintedWins: 0, bestTimeMs: 20_000 },
+      two: { wins: 2, hintedWins: 0, bestTimeMs: 15_000 },
+    }
+    const track = {
+      id: 'main',
+      entries: [{ packId: 'one', title: 'First' }, { packId: 'two' }, { packId: 'three' }],
+    }
+
+    const complete = trackSplits({ ...track, entries: track.entries.slice(0, 2) }, snapshot)
+    const partial = trackSplits(track, snapshot)
+
+    expect(complete.sumOfBestMs).toBe(35_000)
+    expect(complete.splits.map((split) => [split.title, split.cumulativeMs])).toEqual([
+      ['First', 20_000],
+      ['two', 35_000],
+    ])
+    expect(partial.splits[2]).toMatchObject({ bestTimeMs: null, cumulativeMs: null })
+    expect(partial.sumOfBestMs).toBeNull()
+  })
+})
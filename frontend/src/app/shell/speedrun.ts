@@ -0,0 +1,100 @@
+import type { ProgressionTrack } from '../../data/progression'
+import type { GamePhase } from '../../game/interactions/types'
+import type { ReplaySnapshot } from './useReplayStore'
+
+/** How often the sidebar timer redraws while a run is live. */
+export const SPEEDRUN_TICK_MS = 50
+
+/** Real-time span of one run; both ends are `performance.now()` readings. */
+export interface SpeedrunClock {
+  startedAt: number | null
+  stoppedAt: number | null
+}
+
+export const IDLE_SPEEDRUN_CLOCK: SpeedrunClock = { startedAt: null, stoppedAt: null }
+
+/**
+ * Follow the game after a store update: turn 0 means the run has not started (or was
+ * restarted), the first committed turn starts the clock and leaving `Playing` stops it.
+ * Returns the same clock when nothing changed.
+ */
+export function syncSpeedrunClock(
+  clock: SpeedrunClock,
+  turn: number,
+  phase: GamePhase,
+  now: number,
+): SpeedrunClock {
+  if (turn === 0) {
+    return clock.startedAt === null ? clock : IDLE_SPEEDRUN_CLOCK
+  }
+
+  if (clock.startedAt === null) {
+    return { startedAt: now, stoppedAt: phase === 'Playing' ? null : now }
+  }
+
+  if (phase !== 'Playing' && clock.stoppedAt === null) {
+    return { ...clock, stoppedAt: now }
+  }
+
+  return clock
+}
+
+export function speedrunElapsedMs(clock: SpeedrunClock, now: number): number {
+  if (clock.startedAt === null) {
+    return 0
+  }
+
+  return Math.max(0, (clock.stoppedAt ?? now) - clock.startedAt)
+}
+
+/** `m:ss.cc`; minutes keep counting past an hour. */
+export function formatElapsed(ms: number): string {
+  const centiseconds = Math.floor(ms / 10)
+  const minutes = Math.floor(centiseconds / 6000)
+  const seconds = String(Math.floor(centiseconds / 100) % 60).padStart(2, '0')
+
+  return `${minutes}:${seconds}.${String(centiseconds % 100).padStart(2, '0')}`
+}
+
+/** Personal bests for one level of a track, plus the running total of best times up to it. */
+export interface LevelSplit {
+  packId: string
+  title: string
+  bestTurns: number | null
+  bestTimeMs: number | null
+  /** Null once any earlier level (or this one) has no recorded time. */
+  cumulativeMs: number | null
+}
+
+export interface TrackSplits {
+  trackId: string
+  title: string
+  splits: LevelSplit[]
+  /** Sum of best times across the track; null until every level has one. */
+  sumOfBestMs: number | null
+}
+
+export function trackSplits(track: ProgressionTrack, snapshot: ReplaySnapshot): TrackSplits {
+  let cumulativeMs: number | null = 0
+
+  const splits = track.entries.map((entry): LevelSplit => {
+    const bestTimeMs = snapshot.statsByPackId[entry.packId]?.bestTimeMs ?? null
+
+    cumulativeMs = cumulativeMs === null || bestTimeMs === null ? null : cumulativeMs + bestTimeMs
+
+    return {
+      packId: entry.packId,
+      title: entry.title ?? entry.packId,
+      bestTurns: snapshot.bestByPackId[entry.packId]?.turns ?? null,
+      bestTimeMs,
+      cumulativeMs,
+    }
+  })
+
+  return {
+    trackId: track.id,
+    title: track.title ?? track.id,
+    splits,
+    sumOfBestMs: splits.length > 0 ? cumulativeMs : null,
+  }
+}
@@ -1,17 +1,29 @@
-import { useEffect } from 'react'
+import { useEffect, useRef } from 'react'
 
 import {
+  isCommunityPackId,
   loadBootContentFromPublic,
+  loadCommunityLevelsFromPublic,
   loadContentPackManifestFromPublic,
+  type CommunityLevelEntry,
   type PublicContentPackClass,
   type PublicPackDifficultyMeta,
 } from '../../data/loader'
+import type { DetectionConfig } from '../../core/detection'
+import type { MutatorId } from '../../data/mutators'
+import type { PlayDifficulty } from '../../data/playDifficulty'
+import type { RuleProfile } from '../../data/ruleProfiles'
 import type { AppDispatch } from '../../game/store'
 import { applyLoadedContent, setContentPackId, setStatus } from '../../game/gameSlice'
+import type { Translator } from '../../render/i18n'
 
 export interface PackDisplayMeta {
+  name?: string
   class?: PublicContentPackClass
   difficulty?: string
+  tags?: string[]
+  author?: string
+  description?: string
   /** Optional detailed difficulty metadata from public pack manifest. */
   difficultyMeta?: PublicPackDifficultyMeta
 }
@@ -35,8 +47,12 @@ export function useContentPackManifest(
 
       for (const pack of manifest.value.packs) {
         packMetaById[pack.id] = {
+          name: pack.name,
           class: pack.class,
           difficulty: pack.difficulty,
+          tags: pack.tags,
+          author: pack.author ?? pack.source?.author,
+          description: pack.description,
           difficultyMeta: pack.difficultyMeta,
         }
       }
@@ -54,13 +70,33 @@ export function useContentPackManifest(
   }, [setAvailablePackIds, setPackMetaById])
 }
 
+/** Scans the community levels once at startup; each entry carries its own validation result. */
+export function useCommunityLevels(setCommunityLevels: (levels: CommunityLevelEntry[]) => void) {
+  useEffect(() => {
+    let cancelled = false
+
+    void (async () => {
+      const levels = await loadCommunityLevelsFromPublic('/data')
+
+      if (!cancelled) {
+        setCommunityLevels(levels)
+      }
+    })()
+
+    return () => {
+      cancelled = true
+    }
+  }, [setCommunityLevels])
+}
+
 export function useEnsureSelectedContentPack(
   dispatch: AppDispatch,
   availablePackIds: string[],
   contentPackId: string,
 ) {
   useEffect(() => {
-    if (availablePackIds.length === 0) {
+    // Community levels live outside the manifest and are picked from level select directly.
+    if (availablePackIds.length === 0 || isCommunityPackId(contentPackId)) {
       return
     }
 
@@ -70,27 +106,65 @@ export function useEnsureSelectedContentPack(
   }, [availablePackIds, contentPackId, dispatch])
 }
 
-export function useLoadSelectedContentPack(dispatch: AppDispatch, contentPackId: string) {
+export function useLoadSelectedContentPack(
+  dispatch: AppDispatch,
+  contentPackId: string,
+  t: Translator,
+  difficulty: PlayDifficulty,
+  ruleProfile: RuleProfile,
+  /** `[detection]` from `config.toml`; replaces each level's defaults. */
+  detectionOverrides: Partial<DetectionConfig>,
+  /** Challenge mutators; changing them restarts the level under the new set. */
+  mutators: MutatorId[],
+  /** Runs after a load is applied, e.g. to replay a resumed session on top of it. */
+  onLoaded?: (packId: string) => void,
+) {
+  // Kept in a ref so switching language does not reload (and reset) the current pack.
+  const translatorRef = useRef(t)
+  const onLoadedRef = useRef(onLoaded)
+
+  useEffect(() => {
+    translatorRef.current = t
+  }, [t])
+
+  useEffect(() => {
+    onLoadedRef.current = onLoaded
+  }, [onLoaded])
+
   useEffect(() => {
     let cancelled = false
 
     void (async () => {
-      const loaded = await loadBootContentFromPublic({ packId: contentPackId })
+      const loaded = await loadBootContentFromPublic({
+        packId: contentPackId,
+        difficulty,
+        ruleProfile,
+        detectionOverrides,
+        mutators,
+      })
 
       if (cancelled) {
         return
       }
 
       if (!loaded.ok) {
-        dispatch(setStatus(`Content load failed (${contentPackId}): ${loaded.error.kind}`))
+        dispatch(
+          setStatus(
+            translatorRef.current('status.contentLoadFailed', {
+              packId: contentPackId,
+              kind: loaded.error.kind,
+            }),
+          ),
+        )
         return
       }
 
       dispatch(applyLoadedContent({ packId: contentPackId, content: loaded.value }))
+      onLoadedRef.current?.(contentPackId)
     })()
 
     return () => {
       cancelled = true
     }
-  }, [contentPackId, dispatch])
+  }, [contentPackId, detectionOverrides, difficulty, dispatch, mutators, ruleProfile])
 }
@@ -0,0 +1,63 @@
+import { describe, expect, it } from 'vitest'
+
+import {
+  applyIronmanClear,
+  applyIronmanDetection,
+  createEmptyIronmanStats,
+  isSelectedTrackComplete,
+  parseStoredIronmanStats,
+  unlockedIronmanAchievements,
+} from './useIronmanCampaign'
+import {
+  applyCompletionToProgressionSnapshot,
+  createDefaultProgressionSnapshot,
+} from './useProgressionState'
+import type { ProgressionManifest } from '../../data/progression'
+
+const fixtureManifest: ProgressionManifest = {
+  schemaVersion: 1,
+  defaultTrack: 'main',
+  tracks: [
+    {
+      id: 'main',
+      entries: [{ packId: 'default' }, { packId: 'variant' }],
+    },
+  ],
+}
+
+describe('useIronmanCampaign helpers', () => {
+  it('keeps the best streak when a detection resets the current one', () => {
+    const cleared = [1, 2, 3].reduce(
+      (stats) => applyIronmanClear(stats, false),
+      createEmptyIronmanStats(),
+    )
+    const detected = applyIronmanDetection(cleared)
+
+    expect(detected).toEqual({ currentStreak: 0, bestStreak: 3, resets: 1, completedTracks: 0 })
+    expect(unlockedIronmanAchievements(detected)).toEqual(['firstClear', 'streak3'])
+    expect(unlockedIronmanAchievements(applyIronmanClear(detected, true))).toContain(
+      'flawlessTrack',
+    )
+  })
+
+  it('parses stored stats and falls back to empty on invalid payload', () => {
+    expect(
+      parseStoredIronmanStats(
+        JSON.stringify({ currentStreak: 2, bestStreak: 1, resets: 4, completedTracks: 0 }),
+      ),
+    ).toEqual({ currentStreak: 2, bestStreak: 2, resets: 4, completedTracks: 0 })
+    expect(parseStoredIronmanStats(JSON.stringify({ currentStreak: -1 }))).toEqual(
+      createEmptyIronmanStats(),
+    )
+    expect(parseStoredIronmanStats('{')).toEqual(createEmptyIronmanStats())
+  })
+
+  it('detects when the selected track is fully cleared', () => {
+    const start = createDefaultProgressionSnapshot(fixtureManifest)
+    const first = applyCompletionToProgressionSnapshot(fixtureManifest, start, 'default')
+    const both = applyCompletionToProgressionSnapshot(fixtureManifest, first, 'variant')
+
+    expect(isSelectedTrackComplete(fixtureManifest, first)).toBe(false)
+    expect(isSelectedTrackComplete(fixtureManifest, both)).toBe(true)
+  })
+})
@@ -0,0 +1,144 @@
+import { useCallback, useEffect, useState } from 'react'
+
+import type { ProgressionManifest } from '../../data/progression'
+import type { ProgressionSnapshot } from './useProgressionState'
+
+export const IRONMAN_PROGRESSION_STORAGE_KEY = 'hwu.web.progression.ironman.v1'
+export const IRONMAN_STATS_STORAGE_KEY = 'hwu.web.ironman.v1'
+
+/** Iron-man campaign counters, kept apart from the regular replay stats. */
+export interface IronmanStats {
+  /** Levels cleared since the last detection. */
+  currentStreak: number
+  bestStreak: number
+  /** Detections that wiped campaign progress. */
+  resets: number
+  /** Tracks finished without a single detection. */
+  completedTracks: number
+}
+
+export type IronmanAchievementId = 'firstClear' | 'streak3' | 'streak5' | 'flawlessTrack'
+
+export interface IronmanAchievement {
+  id: IronmanAchievementId
+  unlocked: (stats: IronmanStats) => boolean
+}
+
+/** Iron-man only achievements, in the order they are shown. */
+export const IRONMAN_ACHIEVEMENTS: IronmanAchievement[] = [
+  { id: 'firstClear', unlocked: (stats) => stats.bestStreak >= 1 },
+  { id: 'streak3', unlocked: (stats) => stats.bestStreak >= 3 },
+  { id: 'streak5', unlocked: (stats) => stats.bestStreak >= 5 },
+  { id: 'flawlessTrack', unlocked: (stats) => stats.completedTracks >= 1 },
+]
+
+export interface UseIronmanStatsResult {
+  ironmanStats: IronmanStats
+  recordIronmanClear: (trackComplete: boolean) => void
+  recordIronmanDetection: () => void
+}
+
+export function createEmptyIronmanStats(): IronmanStats {
+  return { currentStreak: 0, bestStreak: 0, resets: 0, completedTracks: 0 }
+}
+
+function isCount(value: unknown): value is number {
+  return typeof value === 'number' && Number.isInteger(value) && value >= 0
+}
+
+export function parseStoredIronmanStats(raw: string | null): IronmanStats {
+  if (!raw) {
+    return createEmptyIronmanStats()
+  }
+
+  try {
+    const parsed = JSON.parse(raw) as Partial<IronmanStats>
+
+    if (
+      !isCount(parsed.currentStreak) ||
+      !isCount(parsed.bestStreak) ||
+      !isCount(parsed.resets) ||
+      !isCount(parsed.completedTracks)
+    ) {
+      return createEmptyIronmanStats()
+    }
+
+    return {
+      currentStreak: parsed.currentStreak,
+      bestStreak: Math.max(parsed.bestStreak, parsed.currentStreak),
+      resets: parsed.resets,
+      completedTracks: parsed.completedTracks,
+    }
+  } catch {
+    return createEmptyIronmanStats()
+  }
+}
+
+export function applyIronmanClear(stats: IronmanStats, trackComplete: boolean): IronmanStats {
+  const currentStreak = stats.currentStreak + 1
+
+  return {
+    ...stats,
+    currentStreak,
+    bestStreak: Math.max(stats.bestStreak, currentStreak),
+    completedTracks: stats.completedTracks + (trackComplete ? 1 : 0),
+  }
+}
+
+export function applyIronmanDetection(stats: IronmanStats): IronmanStats {
+  return { ...stats, currentStreak: 0, resets: stats.resets + 1 }
+}
+
+export function unlockedIronmanAchievements(stats: IronmanStats): IronmanAchievementId[] {
+  return IRONMAN_ACHIEVEMENTS.filter((achievement) => achievement.unlocked(stats)).map(
+    (achievement) => achievement.id,
+  )
+}
+
+/** True once every entry of the snapshot's selected track is complete. */
+export function isSelectedTrackComplete(
+  manifest: ProgressionManifest,
+  snapshot: ProgressionSnapshot,
+): boolean {
+  const track = manifest.tracks.find((candidate) => candidate.id === snapshot.selectedTrackId)
+
+  return (
+    !!track &&
+    track.entries.length > 0 &&
+    track.entries.every((entry) => snapshot.completedPackIds.includes(entry.packId))
+  )
+}
+
+function loadStoredStats(): IronmanStats {
+  if (typeof window === 'undefined') {
+    return createEmptyIronmanStats()
+  }
+
+  return parseStoredIronmanStats(window.localStorage.getItem(IRONMAN_STATS_STORAGE_KEY))
+}
+
+export function useIronmanStats(): UseIronmanStatsResult {
+  const [ironmanStats, setIronmanStats] = useState(loadStoredStats)
+
+  useEffect(() => {
+    if (typeof window === 'undefined') {
+      return
+    }
+
+    window.localStorage.setItem(IRONMAN_STATS_STORAGE_KEY, JSON.stringify(ironmanStats))
+  }, [ironmanStats])
+
+  const recordIronmanClear = useCallback((trackComplete: boolean) => {
+    setIronmanStats((stats) => applyIronmanClear(stats, trackComplete))
+  }, [])
+
+  const recordIronmanDetection = useCallback(() => {
+    setIronmanStats(applyIronmanDetection)
+  }, [])
+
+  return {
+    ironmanStats,
+    recordIronmanClear,
+    recordIronmanDetection,
+  }
+}
@@ -1,57 +1,66 @@
-import { useEffect } from 'react'
+import { useEffect, useRef } from 'react'
 import type { Dispatch, SetStateAction } from 'react'
 
-import type { Direction2D } from '../../core/position'
+import type { Layer } from '../../core/elevation'
+import { isInBounds, movePosition, type Direction2D, type Position2D } from '../../core/position'
+import { nextTimelineId, timelineById, type TimelineSet } from '../../core/timelineSet'
 import type { AppDispatch } from '../../game/store'
 import {
-  applyRift,
   configureRiftSettings,
+  requestHint,
   restart,
   setContentPackId,
+  setHotseat,
   setInteractionConfig,
   setStatus,
-  waitTurn,
 } from '../../game/gameSlice'
+import type { InteractionAction } from '../../game/interactions/types'
+import { createKeyRepeatGate, gateKeyPress } from '../inputRepeat'
 import {
+  cancelDirectionalMode,
   closeTopLayer,
   pushDirectionalInput,
   selectDirectionalMode,
   toggleActionMenu,
+  toggleDebugConsole,
+  toggleExamineMode,
+  toggleHelpOverlay,
   toggleLogOverlay,
+  togglePlanningOverlay,
   toggleProgressionOverlay,
   toggleStateOverlay,
   toggleSystemMenu,
+  toggleWorldLineOverlay,
   type DirectionalActionMode,
   type InputStateMachine,
 } from '../inputStateMachine'
+import type { CommunityLevelEntry } from '../../data/loader'
+import { MUTATOR_IDS, toggleMutator, type MutatorId } from '../../data/mutators'
 import type { ProgressionManifest } from '../../data/progression'
+import {
+  commandForKey,
+  DIRECTION_BY_COMMAND,
+  isRepeatableCommand,
+  type KeyBinding,
+} from './keymap'
+import {
+  cycleFilterValue,
+  levelFilterOptions,
+  nextLevelSelectTab,
+  visibleEntryIndices,
+  type LevelFilter,
+  type LevelSelectTab,
+} from './levelFilter'
+import type { PackDisplayMeta } from './useContentPackLoading'
 import type { ProgressionSnapshot } from './useProgressionState'
-
-function directionForKey(key: string): Direction2D | null {
-  switch (key) {
-    case 'w':
-    case 'W':
-    case 'ArrowUp':
-      return 'north'
-    case 'a':
-    case 'A':
-    case 'ArrowLeft':
-      return 'west'
-    case 's':
-    case 'S':
-    case 'ArrowDown':
-      return 'south'
-    case 'd':
-    case 'D':
-    case 'ArrowRight':
-      return 'east'
-    default:
-      return null
-  }
-}
+import { rowIndexForTime, type WorldLineRow } from './worldLineTable'
+import type { Translator } from '../../render/i18n'
 
 interface UseKeyboardControlsInput {
   dispatch: AppDispatch
+  t: Translator
+  keymap: KeyBinding[]
+  repeatDelayMs: number
   inputMachine: InputStateMachine
   isActionMenuOpen: boolean
   isProgressionOverlayOpen: boolean
@@ -59,18 +68,79 @@ interface UseKeyboardControlsInput {
   contentPackId: string
   riftDefaultDelta: number
   interactionMaxPushChain: number
+  hotseat: boolean
+  /** Rule profile gates; when off the matching toggles only report why. */
+  ruleProfileName: string
+  allowPlanning: boolean
+  allowPreview: boolean
   progressionManifest: ProgressionManifest | null
   progressionState: ProgressionSnapshot | null
   setSelectedTrack: (trackId: string) => void
   setCurrentEntryIndex: (index: number) => void
+  packMetaById: Record<string, PackDisplayMeta>
+  levelFilter: LevelFilter
+  setLevelFilter: Dispatch<SetStateAction<LevelFilter>>
+  levelSelectTab: LevelSelectTab
+  setLevelSelectTab: Dispatch<SetStateAction<LevelSelectTab>>
+  communityLevels: CommunityLevelEntry[]
+  communityIndex: number
+  setCommunityIndex: Dispatch<SetStateAction<number>>
+  /** Challenge tab: active mutators, today's pick and the highlighted row. */
+  mutators: MutatorId[]
+  setMutators: Dispatch<SetStateAction<MutatorId[]>>
+  dailyChallenge: MutatorId[]
+  challengeIndex: number
+  setChallengeIndex: Dispatch<SetStateAction<number>>
   applyMachineTransition: (nextMachine: InputStateMachine) => void
   dispatchDirectionalIntent: (intent: { mode: DirectionalActionMode; direction: Direction2D }) => void
+  issueAction: (action: InteractionAction) => void
+  queuePlanIntent: (intent: { mode: DirectionalActionMode; direction: Direction2D }) => void
+  queuePlanAction: (action: InteractionAction) => void
+  undoPlanStep: () => void
+  commitPlan: () => void
+  discardPlan: () => void
+  resolvePendingAction: (confirmed: boolean) => void
   setShowDangerPreview: Dispatch<SetStateAction<boolean>>
+  /** Which layer the board draws on levels with catwalks. */
+  layerView: Layer
+  setLayerView: Dispatch<SetStateAction<Layer>>
+  showTimelineDiff: boolean
+  setShowTimelineDiff: Dispatch<SetStateAction<boolean>>
+  /** Cells changed since the last rift into the past; null before the first one. */
+  timelineChangeCount: number | null
+  /** Branching mode's timelines; null under rules that overwrite the past. */
+  timelines: TimelineSet | null
+  /** Branch the board shows instead of the active one; null to follow play. */
+  viewedTimelineId: string | null
+  setViewedTimelineId: Dispatch<SetStateAction<string | null>>
+  boardWidth: number
+  boardHeight: number
+  /** Where examine mode opens its cursor; null before the level has a player. */
+  player: Position2D | null
+  /** Cell under the examine cursor; null outside examine mode. */
+  examineCursor: Position2D | null
+  setExamineCursor: Dispatch<SetStateAction<Position2D | null>>
+  /** World line table rows and the highlighted one; Enter shows that row's slice. */
+  worldLineRows: WorldLineRow[]
+  worldLineIndex: number
+  setWorldLineIndex: Dispatch<SetStateAction<number>>
+  /** Slice the board shows instead of the present; null to follow play. */
+  viewedTime: number | null
+  viewWorldLineRow: (index: number) => void
+  /** Set by the `?debug` page flag; the backquote key then opens the debug console. */
+  debugConsoleEnabled: boolean
+  /** Loads the autosaved session's level and replays it; reports when there is none. */
+  resumeLastSession: () => void
+  /** Downloads the viewed slice, or every slice, as a text file. */
+  exportSnapshot: (allSlices: boolean) => void
 }
 
 export function useKeyboardControls(input: UseKeyboardControlsInput) {
   const {
     dispatch,
+    t,
+    keymap,
+    repeatDelayMs,
     inputMachine,
     isActionMenuOpen,
     isProgressionOverlayOpen,
@@ -78,55 +148,283 @@ export function useKeyboardControls(input: UseKeyboardControlsInput) {
     contentPackId,
     riftDefaultDelta,
     interactionMaxPushChain,
+    hotseat,
+    ruleProfileName,
+    allowPlanning,
+    allowPreview,
     progressionManifest,
     progressionState,
     setSelectedTrack,
     setCurrentEntryIndex,
+    packMetaById,
+    levelFilter,
+    setLevelFilter,
+    levelSelectTab,
+    setLevelSelectTab,
+    communityLevels,
+    communityIndex,
+    setCommunityIndex,
+    mutators,
+    setMutators,
+    dailyChallenge,
+    challengeIndex,
+    setChallengeIndex,
     applyMachineTransition,
     dispatchDirectionalIntent,
+    issueAction,
+    queuePlanIntent,
+    queuePlanAction,
+    undoPlanStep,
+    commitPlan,
+    discardPlan,
+    resolvePendingAction,
     setShowDangerPreview,
+    layerView,
+    setLayerView,
+    showTimelineDiff,
+    setShowTimelineDiff,
+    timelineChangeCount,
+    timelines,
+    viewedTimelineId,
+    setViewedTimelineId,
+    boardWidth,
+    boardHeight,
+    player,
+    examineCursor,
+    setExamineCursor,
+    worldLineRows,
+    worldLineIndex,
+    setWorldLineIndex,
+    viewedTime,
+    viewWorldLineRow,
+    debugConsoleEnabled,
+    resumeLastSession,
+    exportSnapshot,
   } = input
+  const repeatGateRef = useRef(createKeyRepeatGate())
 
   useEffect(() => {
     const onKeyDown = (event: KeyboardEvent) => {
-      if (event.repeat) {
+      // The console's text field owns the keyboard; only its close keys are handled here.
+      if (inputMachine.layer === 'DebugConsole') {
+        if (event.key === 'Escape' || event.key === '`') {
+          event.preventDefault()
+          applyMachineTransition(toggleDebugConsole(inputMachine))
+        }
+
+        return
+      }
+
+      if (debugConsoleEnabled && event.key === '`') {
+        const next = toggleDebugConsole(inputMachine)
+
+        if (next !== inputMachine) {
+          event.preventDefault()
+          applyMachineTransition(next)
+        }
+
         return
       }
 
-      const direction = directionForKey(event.key)
+      const command = commandForKey(keymap, event.key)
+      const decision = gateKeyPress(
+        repeatGateRef.current,
+        { key: event.key, repeat: event.repeat, timestamp: event.timeStamp },
+        {
+          repeatable: command !== null && isRepeatableCommand(keymap, command),
+          repeatDelayMs,
+        },
+      )
+      repeatGateRef.current = decision.gate
+
+      if (!decision.accepted) {
+        return
+      }
 
-      if (event.key === 'f' || event.key === 'F') {
+      if (inputMachine.layer === 'ConfirmPrompt') {
+        event.preventDefault()
+
+        // A held Enter must not confirm the prompt it just opened.
+        if (event.repeat) {
+          return
+        }
+
+        if (event.key === 'y' || event.key === 'Y' || event.key === 'Enter') {
+          resolvePendingAction(true)
+        } else if (event.key === 'n' || event.key === 'N' || event.key === 'Escape') {
+          resolvePendingAction(false)
+        }
+
+        return
+      }
+
+      const direction = command ? (DIRECTION_BY_COMMAND[command] ?? null) : null
+
+      if (command === 'TogglePlan') {
+        if (!allowPlanning && inputMachine.layer !== 'PlanningOverlay') {
+          event.preventDefault()
+          dispatch(setStatus(t('status.planningDisabled', { rules: ruleProfileName })))
+          return
+        }
+
+        const next = togglePlanningOverlay(inputMachine)
+
+        if (next !== inputMachine) {
+          event.preventDefault()
+          discardPlan()
+          applyMachineTransition(next)
+        }
+
+        return
+      }
+
+      if (inputMachine.layer === 'PlanningOverlay') {
+        event.preventDefault()
+
+        if (command === 'CloseLayer') {
+          discardPlan()
+          applyMachineTransition(closeTopLayer(inputMachine))
+          return
+        }
+
+        if (direction) {
+          const result = pushDirectionalInput(inputMachine, direction)
+
+          if (result.planned) {
+            queuePlanIntent(result.planned)
+          }
+
+          return
+        }
+
+        if (command === 'Wait') {
+          queuePlanAction({ kind: 'Wait' })
+        } else if (command === 'Rift') {
+          queuePlanAction({ kind: 'ApplyRift' })
+        } else if (command === 'UndoPlanStep') {
+          undoPlanStep()
+        } else if (command === 'CommitPlan' && !event.repeat) {
+          commitPlan()
+          applyMachineTransition(closeTopLayer(inputMachine))
+        }
+
+        return
+      }
+
+      if (command === 'ToggleExamine') {
+        const next = toggleExamineMode(inputMachine)
+
+        if (next !== inputMachine) {
+          const opening = next.layer === 'Examine'
+
+          event.preventDefault()
+          setExamineCursor(opening ? { x: player?.x ?? 0, y: player?.y ?? 0 } : null)
+          dispatch(setStatus(t(opening ? 'status.examineOn' : 'status.examineOff')))
+          applyMachineTransition(next)
+        }
+
+        return
+      }
+
+      if (inputMachine.layer === 'Examine') {
+        event.preventDefault()
+
+        if (command === 'CloseLayer') {
+          setExamineCursor(null)
+          dispatch(setStatus(t('status.examineOff')))
+          applyMachineTransition(closeTopLayer(inputMachine))
+          return
+        }
+
+        if (direction && examineCursor) {
+          const next = movePosition(examineCursor, direction)
+
+          if (isInBounds(next, boardWidth, boardHeight)) {
+            setExamineCursor(next)
+          }
+        }
+
+        return
+      }
+
+      if (command === 'ToggleWorldLine') {
+        const next = toggleWorldLineOverlay(inputMachine)
+
+        if (next !== inputMachine) {
+          event.preventDefault()
+          if (next.layer === 'WorldLineOverlay') {
+            setWorldLineIndex(rowIndexForTime(worldLineRows, viewedTime))
+          }
+          applyMachineTransition(next)
+        }
+
+        return
+      }
+
+      if (inputMachine.layer === 'WorldLineOverlay') {
+        event.preventDefault()
+
+        if (command === 'CloseLayer') {
+          applyMachineTransition(closeTopLayer(inputMachine))
+          return
+        }
+
+        if (direction === 'north' || direction === 'south') {
+          const step = direction === 'north' ? -1 : 1
+          const lastIndex = Math.max(0, worldLineRows.length - 1)
+          setWorldLineIndex((index) => Math.min(lastIndex, Math.max(0, index + step)))
+          return
+        }
+
+        if (event.key === 'Enter' && !event.repeat) {
+          viewWorldLineRow(worldLineIndex)
+          applyMachineTransition(closeTopLayer(inputMachine))
+        }
+
+        return
+      }
+
+      if (command === 'ToggleActionMenu') {
         event.preventDefault()
         applyMachineTransition(toggleActionMenu(inputMachine))
         return
       }
 
-      if (event.key === 'Tab') {
+      if (command === 'ToggleState') {
         event.preventDefault()
         applyMachineTransition(toggleStateOverlay(inputMachine))
         return
       }
 
-      if (event.key === 'l' || event.key === 'L') {
+      if (command === 'ToggleLog') {
         event.preventDefault()
         applyMachineTransition(toggleLogOverlay(inputMachine))
         return
       }
 
-      if (event.key === 'm' || event.key === 'M') {
+      if (command === 'ToggleSettings') {
         event.preventDefault()
         applyMachineTransition(toggleSystemMenu(inputMachine))
         return
       }
 
-      if (event.key === 'g' || event.key === 'G') {
+      if (command === 'ToggleLevels') {
         event.preventDefault()
         applyMachineTransition(toggleProgressionOverlay(inputMachine))
         return
       }
 
-      if (event.key === 'Escape') {
-        const next = closeTopLayer(inputMachine)
+      if (command === 'ToggleHelp') {
+        event.preventDefault()
+        applyMachineTransition(toggleHelpOverlay(inputMachine))
+        return
+      }
+
+      if (command === 'CloseLayer') {
+        const next =
+          inputMachine.layer === 'Gameplay'
+            ? cancelDirectionalMode(inputMachine)
+            : closeTopLayer(inputMachine)
 
         if (next !== inputMachine) {
           event.preventDefault()
@@ -136,28 +434,116 @@ export function useKeyboardControls(input: UseKeyboardControlsInput) {
       }
 
       if (isActionMenuOpen) {
-        if (event.key === '1') {
+        if (command === 'SelectMove') {
           event.preventDefault()
           applyMachineTransition(selectDirectionalMode(inputMachine, 'Move'))
           return
         }
 
-        if (event.key === '2') {
+        if (command === 'SelectPush') {
           event.preventDefault()
           applyMachineTransition(selectDirectionalMode(inputMachine, 'Push'))
           return
         }
 
-        if (event.key === '3') {
+        if (command === 'SelectPull') {
           event.preventDefault()
           applyMachineTransition(selectDirectionalMode(inputMachine, 'Pull'))
           return
         }
+
+        if (command === 'SelectTimePush') {
+          event.preventDefault()
+          applyMachineTransition(selectDirectionalMode(inputMachine, 'TimePush'))
+          return
+        }
+
+        if (command === 'SelectThrow') {
+          event.preventDefault()
+          applyMachineTransition(selectDirectionalMode(inputMachine, 'Throw'))
+          return
+        }
+
+        if (command === 'SelectBreak') {
+          event.preventDefault()
+          applyMachineTransition(selectDirectionalMode(inputMachine, 'Break'))
+          return
+        }
+
+        if (command === 'SelectPeek') {
+          event.preventDefault()
+          applyMachineTransition(selectDirectionalMode(inputMachine, 'Peek'))
+          return
+        }
+
+        if (command === 'SelectInteract') {
+          event.preventDefault()
+          applyMachineTransition(selectDirectionalMode(inputMachine, 'Interact'))
+          return
+        }
       }
 
       if (isProgressionOverlayOpen) {
         event.preventDefault()
 
+        if (command === 'ResumeSession') {
+          resumeLastSession()
+          applyMachineTransition(closeTopLayer(inputMachine))
+          return
+        }
+
+        if (event.key === '4') {
+          setLevelSelectTab(nextLevelSelectTab)
+          return
+        }
+
+        if (levelSelectTab === 'records') {
+          return
+        }
+
+        // Every change reloads the current level under the new set.
+        if (levelSelectTab === 'challenge') {
+          if (direction === 'north' || direction === 'south') {
+            const step = direction === 'north' ? -1 : 1
+            const lastIndex = MUTATOR_IDS.length - 1
+            setChallengeIndex((index) => Math.min(lastIndex, Math.max(0, index + step)))
+            return
+          }
+
+          if (event.key === 'Enter') {
+            setMutators(toggleMutator(mutators, MUTATOR_IDS[challengeIndex]))
+          } else if (event.key === '1') {
+            setMutators(dailyChallenge)
+          } else if (event.key === '2') {
+            setMutators([])
+          }
+
+          return
+        }
+
+        if (levelSelectTab === 'community') {
+          if (direction === 'north' || direction === 'south') {
+            const step = direction === 'north' ? -1 : 1
+            const lastIndex = Math.max(0, communityLevels.length - 1)
+            setCommunityIndex((index) => Math.min(lastIndex, Math.max(0, index + step)))
+            return
+          }
+
+          const level = communityLevels[communityIndex]
+
+          if (event.key === 'Enter' && level) {
+            if (level.error) {
+              dispatch(setStatus(t('status.communityLevelInvalid', { file: level.fileName })))
+              return
+            }
+
+            dispatch(setContentPackId(level.packId))
+            applyMachineTransition(closeTopLayer(inputMachine))
+          }
+
+          return
+        }
+
         if (!progressionManifest || !progressionState) {
           return
         }
@@ -168,8 +554,32 @@ export function useKeyboardControls(input: UseKeyboardControlsInput) {
         )
         const activeTrackIndex = selectedTrackIndex < 0 ? 0 : selectedTrackIndex
         const selectedTrack = progressionManifest.tracks[activeTrackIndex]
+        const entries = selectedTrack?.entries ?? []
+        const visible = visibleEntryIndices(entries, packMetaById, levelFilter)
+        const visiblePosition = visible.indexOf(progressionState.currentEntryIndex)
+
+        // 1/2/3 cycle the difficulty filter, the tag filter and the sort order.
+        if (event.key === '1' || event.key === '2' || event.key === '3') {
+          const options = levelFilterOptions(entries, packMetaById)
+          const nextFilter: LevelFilter =
+            event.key === '1'
+              ? {
+                  ...levelFilter,
+                  difficulty: cycleFilterValue(options.difficulties, levelFilter.difficulty),
+                }
+              : event.key === '2'
+                ? { ...levelFilter, tag: cycleFilterValue(options.tags, levelFilter.tag) }
+                : { ...levelFilter, sort: levelFilter.sort === 'track' ? 'difficulty' : 'track' }
+          const nextVisible = visibleEntryIndices(entries, packMetaById, nextFilter)
+
+          setLevelFilter(nextFilter)
+          if (nextVisible.length > 0 && !nextVisible.includes(progressionState.currentEntryIndex)) {
+            setCurrentEntryIndex(nextVisible[0])
+          }
+          return
+        }
 
-        if (event.key === 'ArrowLeft' || event.key === 'a' || event.key === 'A') {
+        if (direction === 'west') {
           if (trackCount > 1) {
             const nextTrackIndex = activeTrackIndex <= 0 ? trackCount - 1 : activeTrackIndex - 1
             setSelectedTrack(progressionManifest.tracks[nextTrackIndex].id)
@@ -177,7 +587,7 @@ export function useKeyboardControls(input: UseKeyboardControlsInput) {
           return
         }
 
-        if (event.key === 'ArrowRight' || event.key === 'd' || event.key === 'D') {
+        if (direction === 'east') {
           if (trackCount > 1) {
             const nextTrackIndex = activeTrackIndex >= trackCount - 1 ? 0 : activeTrackIndex + 1
             setSelectedTrack(progressionManifest.tracks[nextTrackIndex].id)
@@ -185,24 +595,21 @@ export function useKeyboardControls(input: UseKeyboardControlsInput) {
           return
         }
 
-        if (event.key === 'ArrowUp' || event.key === 'w' || event.key === 'W') {
-          if (selectedTrack) {
-            const nextIndex = Math.max(0, progressionState.currentEntryIndex - 1)
-            setCurrentEntryIndex(nextIndex)
+        if (direction === 'north') {
+          if (visible.length > 0) {
+            setCurrentEntryIndex(visible[Math.max(0, visiblePosition - 1)])
           }
           return
         }
 
-        if (event.key === 'ArrowDown' || event.key === 's' || event.key === 'S') {
-          if (selectedTrack) {
-            const maxIndex = Math.max(0, selectedTrack.entries.length - 1)
-            const nextIndex = Math.min(maxIndex, progressionState.currentEntryIndex + 1)
-            setCurrentEntryIndex(nextIndex)
+        if (direction === 'south') {
+          if (visible.length > 0) {
+            setCurrentEntryIndex(visible[Math.min(visible.length - 1, visiblePosition + 1)])
           }
           return
         }
 
-        if (event.key === 'Enter' && selectedTrack) {
+        if (event.key === 'Enter' && selectedTrack && visiblePosition >= 0) {
           const entry = selectedTrack.entries[progressionState.currentEntryIndex]
 
           if (!entry) {
@@ -213,7 +620,7 @@ export function useKeyboardControls(input: UseKeyboardControlsInput) {
             entry.packId !== contentPackId &&
             !progressionState.unlockedPackIds.includes(entry.packId)
           ) {
-            dispatch(setStatus(`Level locked: ${entry.packId}`))
+            dispatch(setStatus(t('status.levelLocked', { packId: entry.packId })))
             return
           }
 
@@ -239,13 +646,85 @@ export function useKeyboardControls(input: UseKeyboardControlsInput) {
         return
       }
 
-      if (event.key === 'p' || event.key === 'P') {
+      if (command === 'ToggleDanger') {
         event.preventDefault()
+
+        if (!allowPreview) {
+          dispatch(setStatus(t('status.previewDisabled', { rules: ruleProfileName })))
+          return
+        }
+
         setShowDangerPreview((enabled) => !enabled)
         return
       }
 
-      if (event.key === 'v' || event.key === 'V') {
+      if (command === 'ToggleLayerView') {
+        event.preventDefault()
+        const nextLayer = layerView === 'ground' ? 'catwalk' : 'ground'
+
+        setLayerView(nextLayer)
+        dispatch(setStatus(t('status.layerView', { layer: t(`layer.${nextLayer}`) })))
+        return
+      }
+
+      if (command === 'ToggleTimelineDiff') {
+        event.preventDefault()
+
+        if (timelineChangeCount === null) {
+          dispatch(setStatus(t('status.timelineDiffUnavailable')))
+          return
+        }
+
+        setShowTimelineDiff(!showTimelineDiff)
+        dispatch(
+          setStatus(
+            showTimelineDiff
+              ? t('status.timelineDiffOff')
+              : t('status.timelineDiffOn', { count: timelineChangeCount }),
+          ),
+        )
+        return
+      }
+
+      if (command === 'SnapshotSlice' || command === 'SnapshotAllSlices') {
+        event.preventDefault()
+        exportSnapshot(command === 'SnapshotAllSlices')
+        return
+      }
+
+      if (command === 'CycleTimeline') {
+        event.preventDefault()
+
+        if (!timelines) {
+          dispatch(setStatus(t('status.branchingOff', { rules: ruleProfileName })))
+          return
+        }
+
+        const nextId = nextTimelineId(timelines, viewedTimelineId ?? timelines.activeId)
+        const branch = timelineById(timelines, nextId)
+
+        setViewedTimelineId(nextId === timelines.activeId ? null : nextId)
+        dispatch(
+          setStatus(
+            nextId === timelines.activeId || !branch
+              ? t('status.timelineActive', { timelineId: timelines.activeId })
+              : t('status.timelineView', {
+                  timelineId: branch.id,
+                  forkTime: branch.forkTime,
+                  state: t(branch.consistent ? 'timeline.consistent' : 'timeline.inconsistent'),
+                }),
+          ),
+        )
+        return
+      }
+
+      if (command === 'Hint') {
+        event.preventDefault()
+        dispatch(requestHint())
+        return
+      }
+
+      if (command === 'NextPack') {
         event.preventDefault()
         if (availablePackIds.length > 0) {
           const currentIndex = availablePackIds.findIndex((id) => id === contentPackId)
@@ -255,37 +734,55 @@ export function useKeyboardControls(input: UseKeyboardControlsInput) {
         return
       }
 
-      if (event.key === ' ') {
+      if (command === 'Rift') {
         event.preventDefault()
-        dispatch(applyRift(undefined))
+        issueAction({ kind: 'ApplyRift' })
         return
       }
 
-      if (event.key === '[') {
+      if (command === 'RiftDeltaDown') {
         event.preventDefault()
         dispatch(configureRiftSettings({ defaultDelta: Math.max(1, riftDefaultDelta - 1) }))
         return
       }
 
-      if (event.key === ']') {
+      if (command === 'RiftDeltaUp') {
         event.preventDefault()
         dispatch(configureRiftSettings({ defaultDelta: riftDefaultDelta + 1 }))
         return
       }
 
-      if (event.key === 'Enter') {
+      if (command === 'Wait') {
         event.preventDefault()
-        dispatch(waitTurn())
+        issueAction({ kind: 'Wait' })
         return
       }
 
-      if (event.key === 'r' || event.key === 'R') {
+      if (command === 'Climb') {
+        event.preventDefault()
+        issueAction({ kind: 'Climb' })
+        return
+      }
+
+      if (command === 'SwitchCharacter') {
+        event.preventDefault()
+        issueAction({ kind: 'SwitchCharacter' })
+        return
+      }
+
+      if (command === 'Restart') {
         event.preventDefault()
         dispatch(restart())
         return
       }
 
-      if (event.key === '-') {
+      if (command === 'ResumeSession') {
+        event.preventDefault()
+        resumeLastSession()
+        return
+      }
+
+      if (command === 'PushChainDown') {
         event.preventDefault()
         dispatch(
           setInteractionConfig({
@@ -295,7 +792,7 @@ export function useKeyboardControls(input: UseKeyboardControlsInput) {
         return
       }
 
-      if (event.key === '=') {
+      if (command === 'PushChainUp') {
         event.preventDefault()
         dispatch(
           setInteractionConfig({
@@ -305,9 +802,15 @@ export function useKeyboardControls(input: UseKeyboardControlsInput) {
         return
       }
 
-      if (event.key === 'q' || event.key === 'Q') {
+      if (command === 'ToggleHotseat') {
+        event.preventDefault()
+        dispatch(setHotseat(!hotseat))
+        return
+      }
+
+      if (command === 'Quit') {
         event.preventDefault()
-        dispatch(setStatus('Quit is not wired in web build.'))
+        dispatch(setStatus(t('status.quitUnavailable')))
       }
     }
 
@@ -318,19 +821,67 @@ export function useKeyboardControls(input: UseKeyboardControlsInput) {
     }
   }, [
     availablePackIds,
+    communityIndex,
+    communityLevels,
+    commitPlan,
     contentPackId,
+    debugConsoleEnabled,
+    discardPlan,
     dispatch,
+    allowPlanning,
+    allowPreview,
     applyMachineTransition,
+    boardHeight,
+    boardWidth,
+    challengeIndex,
+    dailyChallenge,
     dispatchDirectionalIntent,
+    examineCursor,
+    exportSnapshot,
+    hotseat,
     inputMachine,
+    issueAction,
     interactionMaxPushChain,
     isActionMenuOpen,
     isProgressionOverlayOpen,
+    keymap,
+    layerView,
+    levelFilter,
+    levelSelectTab,
+    mutators,
+    packMetaById,
+    player,
     progressionManifest,
     progressionState,
+    queuePlanAction,
+    queuePlanIntent,
+    repeatDelayMs,
+    resolvePendingAction,
+    resumeLastSession,
     riftDefaultDelta,
+    ruleProfileName,
+    setChallengeIndex,
+    setCommunityIndex,
     setCurrentEntryIndex,
+    setExamineCursor,
+    setLevelFilter,
+    setLayerView,
+    setLevelSelectTab,
+    setMutators,
     setSelectedTrack,
     setShowDangerPreview,
+    setShowTimelineDiff,
+    setViewedTimelineId,
+    setWorldLineIndex,
+    showTimelineDiff,
+    t,
+    timelineChangeCount,
+    timelines,
+    undoPlanStep,
+    viewedTime,
+    viewedTimelineId,
+    viewWorldLineRow,
+    worldLineIndex,
+    worldLineRows,
   ])
 }
@@ -219,6 +219,7 @@ export function parseStoredProgressionSnapshot(
 
 function loadStoredSnapshot(
   manifest: ProgressionManifest,
+  storageKey: string,
 ): ProgressionSnapshot {
   if (typeof window === 'undefined') {
     return createDefaultProgressionSnapshot(manifest)
@@ -226,11 +227,14 @@ function loadStoredSnapshot(
 
   return parseStoredProgressionSnapshot(
     manifest,
-    window.localStorage.getItem(PROGRESSION_STORAGE_KEY),
+    window.localStorage.getItem(storageKey),
   )
 }
 
-export function useProgressionState(): UseProgressionStateResult {
+/** `storageKey` keeps separate campaigns (such as iron-man) from sharing progress. */
+export function useProgressionState(
+  storageKey: string = PROGRESSION_STORAGE_KEY,
+): UseProgressionStateResult {
   const [progressionManifest, setProgressionManifest] = useState<ProgressionManifest | null>(null)
   const [progressionState, setProgressionState] = useState<ProgressionSnapshot | null>(null)
   const [progressionError, setProgressionError] = useState<string | null>(null)
@@ -250,7 +254,7 @@ export function useProgressionState(): UseProgressionStateResult {
         return
       }
 
-      const restored = loadStoredSnapshot(loaded.value)
+      const restored = loadStoredSnapshot(loaded.value, storageKey)
 
       setProgressionManifest(loaded.value)
       setProgressionState(restored)
@@ -260,7 +264,7 @@ export function useProgressionState(): UseProgressionStateResult {
     return () => {
       cancelled = true
     }
-  }, [])
+  }, [storageKey])
 
   useEffect(() => {
     if (!progressionManifest || !progressionState || typeof window === 'undefined') {
@@ -269,10 +273,10 @@ export function useProgressionState(): UseProgressionStateResult {
 
     const normalized = normalizeProgressionSnapshot(progressionManifest, progressionState)
     window.localStorage.setItem(
-      PROGRESSION_STORAGE_KEY,
+      storageKey,
       JSON.stringify(normalized),
     )
-  }, [progressionManifest, progressionState])
+  }, [progressionManifest, progressionState, storageKey])
 
   const setSelectedTrack = (trackId: string) => {
     if (!progressionManifest || !progressionState) {
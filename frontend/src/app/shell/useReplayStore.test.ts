@@ -0,0 +1,95 @@
+import { describe, expect, it } from 'vitest'
+
+import { asTurn } from '../../core/coords'
+import {
+  applyWinToReplaySnapshot,
+  bestReplayKey,
+  createEmptyReplaySnapshot,
+  ghostPositionAtTurn,
+  parseStoredReplaySnapshot,
+  type ReplayRecord,
+} from './useReplayStore'
+
+function record(turns: number): ReplayRecord {
+  const path = Array.from({ length: turns + 1 }, (_, index) => ({ x: index, y: 0, t: index }))
+
+  return {
+    packId: 'default',
+    turns,
+    actions: Array.from({ length: turns }, () => ({ kind: 'Move' as const, direction: 'east' as const })),
+    path,
+    hintsUsed: 0,
+  }
+}
+
+describe('useReplayStore helpers', () => {
+  it('keeps only the fastest winning run per pack', () => {
+    const first = applyWinToReplaySnapshot(createEmptyReplaySnapshot(), record(5))
+    const slower = applyWinToReplaySnapshot(first, record(7))
+    const faster = applyWinToReplaySnapshot(slower, record(3))
+
+    expect(slower.bestByPackId.default.turns).toBe(5)
+    expect(faster.bestByPackId.default.turns).toBe(3)
+    expect(faster.statsByPackId.default).toEqual({ wins: 3, hintedWins: 0 })
+  })
+
+  it('counts hinted wins separately', () => {
+    const hinted = applyWinToReplaySnapshot(createEmptyReplaySnapshot(), {
+      ...record(4),
+      hintsUsed: 2,
+    })
+
+    expect(hinted.statsByPackId.default).toEqual({ wins: 1, hintedWins: 1 })
+    expect(hinted.bestByPackId.default.hintsUsed).toBe(2)
+  })
+
+  it('keeps the fastest time even when it is not the fewest-turns run', () => {
+    const timed = applyWinToReplaySnapshot(createEmptyReplaySnapshot(), {
+      ...record(4),
+      elapsedMs: 9_000,
+    })
+    const quicker = applyWinToReplaySnapshot(timed, { ...record(6), elapsedMs: 7_500 })
+    const untimed = applyWinToReplaySnapshot(quicker, record(5))
+
+    expect(untimed.statsByPackId.default).toEqual({ wins: 3, hintedWins: 0, bestTimeMs: 7_500 })
+    expect(untimed.bestByPackId.default).toMatchObject({ turns: 4, elapsedMs: 9_000 })
+    expect(
+      parseStoredReplaySnapshot(JSON.stringify(untimed)).statsByPackId.default.bestTimeMs,
+    ).toBe(7_500)
+  })
+
+  it('keeps a separate best run per difficulty', () => {
+    const normal = applyWinToReplaySnapshot(createEmptyReplaySnapshot(), record(3))
+    const hard = applyWinToReplaySnapshot(normal, { ...record(6), playDifficulty: 'Hard' })
+
+    expect(hard.bestByPackId[bestReplayKey('default')].turns).toBe(3)
+    expect(hard.bestByPackId[bestReplayKey('default', 'Hard')]).toMatchObject({
+      turns: 6,
+      playDifficulty: 'Hard',
+    })
+    expect(hard.statsByPackId.default.wins).toBe(2)
+  })
+
+  it('resolves ghost positions and clamps after the run ends', () => {
+    const best = record(2)
+
+    expect(ghostPositionAtTurn(best, asTurn(1))).toEqual({ x: 1, y: 0, t: 1 })
+    expect(ghostPositionAtTurn(best, asTurn(9))).toEqual({ x: 2, y: 0, t: 2 })
+    expect(ghostPositionAtTurn(best, asTurn(-1))).toBeNull()
+  })
+
+  it('parses stored snapshots and drops malformed records', () => {
+    const parsed = parseStoredReplaySnapshot(
+      JSON.stringify({
+        bestByPackId: {
+          default: { ...record(2), seed: 'default' },
+          broken: { turns: 'x' },
+        },
+      }),
+    )
+
+    expect(Object.keys(parsed.bestByPackId)).toEqual(['default'])
+    expect(parsed.bestByPackId.default.seed).toBe('default')
+    expect(parseStoredReplaySnapshot('{oops')).toEqual(createEmptyReplaySnapshot())
+  })
+})
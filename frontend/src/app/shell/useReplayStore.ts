@@ -0,0 +1,274 @@
+import { useCallback, useEffect, useState } from 'react'
+
+import type { Turn } from '../../core/coords'
+import type { Position3D } from '../../core/position'
+import type { Region } from '../../core/regions'
+import { normalizeMutators, type MutatorId } from '../../data/mutators'
+import {
+  DEFAULT_PLAY_DIFFICULTY,
+  isPlayDifficulty,
+  type PlayDifficulty,
+} from '../../data/playDifficulty'
+import type { InteractionAction } from '../../game/interactions/types'
+import { compareReplays, type ReplayComparison } from './replayDiff'
+
+export const REPLAY_STORAGE_KEY = 'hwu.web.replays.v1'
+
+/** Best winning run recorded for a content pack at one difficulty. */
+export interface ReplayRecord {
+  packId: string
+  turns: number
+  actions: InteractionAction[]
+  path: Position3D[]
+  hintsUsed: number
+  /** Real time from first turn to the win, when the speedrun timer was on. */
+  elapsedMs?: number
+  /** RNG seed the run was played with; a replay must reuse it to stay deterministic. */
+  seed?: string
+  /** Challenge mutators the run was played under; missing for a plain run. */
+  mutators?: MutatorId[]
+  /** Difficulty the run was played at; missing in records from before difficulties. */
+  playDifficulty?: PlayDifficulty
+  /** Rule profile the run was played under; missing in records from before rule profiles. */
+  ruleProfileId?: string
+}
+
+/** Win counters per pack; hinted wins are tracked separately from clean ones. */
+export interface ReplayStats {
+  wins: number
+  hintedWins: number
+  /** Fastest timed win, independent of which run holds the turn record. */
+  bestTimeMs?: number
+}
+
+export interface ReplaySnapshot {
+  /** Keyed by `bestReplayKey`, so each difficulty keeps its own best run. */
+  bestByPackId: Record<string, ReplayRecord>
+  statsByPackId: Record<string, ReplayStats>
+}
+
+/** Latest recorded win next to the best run it was measured against. */
+export interface LastWinComparison {
+  packId: string
+  turns: number
+  comparison: ReplayComparison
+}
+
+export interface UseReplayStoreResult {
+  replaySnapshot: ReplaySnapshot
+  /** Null until a win is recorded on a pack that already had a best run. */
+  lastWinComparison: LastWinComparison | null
+  /** `regions` lets the comparison tell which named areas the two routes crossed. */
+  recordWin: (record: ReplayRecord, regions?: Region[]) => void
+}
+
+/** Normal keeps the bare pack id, so best runs saved before difficulties still count for it. */
+export function bestReplayKey(
+  packId: string,
+  difficulty: PlayDifficulty = DEFAULT_PLAY_DIFFICULTY,
+): string {
+  return difficulty === DEFAULT_PLAY_DIFFICULTY ? packId : `${packId}@${difficulty}`
+}
+
+export function createEmptyReplaySnapshot(): ReplaySnapshot {
+  return { bestByPackId: {}, statsByPackId: {} }
+}
+
+function isPosition3D(value: unknown): value is Position3D {
+  if (!value || typeof value !== 'object') {
+    return false
+  }
+
+  const candidate = value as Partial<Position3D>
+
+  return (
+    typeof candidate.x === 'number' &&
+    typeof candidate.y === 'number' &&
+    typeof candidate.t === 'number'
+  )
+}
+
+function parseReplayRecord(key: string, value: unknown): ReplayRecord | null {
+  if (!value || typeof value !== 'object') {
+    return null
+  }
+
+  const candidate = value as Partial<ReplayRecord>
+
+  if (
+    typeof candidate.turns !== 'number' ||
+    !Array.isArray(candidate.actions) ||
+    !Array.isArray(candidate.path) ||
+    !candidate.path.every(isPosition3D)
+  ) {
+    return null
+  }
+
+  return {
+    packId: typeof candidate.packId === 'string' ? candidate.packId : key,
+    turns: candidate.turns,
+    actions: candidate.actions,
+    path: candidate.path,
+    hintsUsed: typeof candidate.hintsUsed === 'number' ? candidate.hintsUsed : 0,
+    ...(typeof candidate.elapsedMs === 'number' ? { elapsedMs: candidate.elapsedMs } : {}),
+    ...(typeof candidate.seed === 'string' ? { seed: candidate.seed } : {}),
+    ...(Array.isArray(candidate.mutators) && candidate.mutators.length > 0
+      ? { mutators: normalizeMutators(candidate.mutators) }
+      : {}),
+    ...(isPlayDifficulty(candidate.playDifficulty)
+      ? { playDifficulty: candidate.playDifficulty }
+      : {}),
+    ...(typeof candidate.ruleProfileId === 'string'
+      ? { ruleProfileId: candidate.ruleProfileId }
+      : {}),
+  }
+}
+
+function parseReplayStats(value: unknown): ReplayStats | null {
+  if (!value || typeof value !== 'object') {
+    return null
+  }
+
+  const candidate = value as Partial<ReplayStats>
+
+  if (typeof candidate.wins !== 'number' || typeof candidate.hintedWins !== 'number') {
+    return null
+  }
+
+  return {
+    wins: candidate.wins,
+    hintedWins: candidate.hintedWins,
+    ...(typeof candidate.bestTimeMs === 'number' ? { bestTimeMs: candidate.bestTimeMs } : {}),
+  }
+}
+
+export function parseStoredReplaySnapshot(raw: string | null): ReplaySnapshot {
+  if (!raw) {
+    return createEmptyReplaySnapshot()
+  }
+
+  try {
+    const parsed = JSON.parse(raw) as Partial<ReplaySnapshot>
+
+    if (!parsed.bestByPackId || typeof parsed.bestByPackId !== 'object') {
+      return createEmptyReplaySnapshot()
+    }
+
+    const bestByPackId: Record<string, ReplayRecord> = {}
+
+    for (const [key, value] of Object.entries(parsed.bestByPackId)) {
+      const record = parseReplayRecord(key, value)
+
+      if (record) {
+        bestByPackId[key] = record
+      }
+    }
+
+    const statsByPackId: Record<string, ReplayStats> = {}
+
+    if (parsed.statsByPackId && typeof parsed.statsByPackId === 'object') {
+      for (const [packId, value] of Object.entries(parsed.statsByPackId)) {
+        const stats = parseReplayStats(value)
+
+        if (stats) {
+          statsByPackId[packId] = stats
+        }
+      }
+    }
+
+    return { bestByPackId, statsByPackId }
+  } catch {
+    return createEmptyReplaySnapshot()
+  }
+}
+
+/**
+ * Counts the win and keeps the faster time, then keeps the record as best only if it beats
+ * (fewer turns than) the stored best for its pack and difficulty.
+ */
+export function applyWinToReplaySnapshot(
+  snapshot: ReplaySnapshot,
+  record: ReplayRecord,
+): ReplaySnapshot {
+  const previousStats = snapshot.statsByPackId[record.packId] ?? { wins: 0, hintedWins: 0 }
+  const bestTimeMs =
+    record.elapsedMs === undefined
+      ? previousStats.bestTimeMs
+      : Math.min(record.elapsedMs, previousStats.bestTimeMs ?? Infinity)
+  const statsByPackId = {
+    ...snapshot.statsByPackId,
+    [record.packId]: {
+      wins: previousStats.wins + 1,
+      hintedWins: previousStats.hintedWins + (record.hintsUsed > 0 ? 1 : 0),
+      ...(bestTimeMs === undefined ? {} : { bestTimeMs }),
+    },
+  }
+  const key = bestReplayKey(record.packId, record.playDifficulty)
+  const previous = snapshot.bestByPackId[key]
+
+  if (previous && previous.turns <= record.turns) {
+    return { ...snapshot, statsByPackId }
+  }
+
+  return {
+    bestByPackId: {
+      ...snapshot.bestByPackId,
+      [key]: record,
+    },
+    statsByPackId,
+  }
+}
+
+/** Ghost position for a turn; the ghost rests on its final position once its run is over. */
+export function ghostPositionAtTurn(record: ReplayRecord, turn: Turn): Position3D | null {
+  if (record.path.length === 0 || turn < 0) {
+    return null
+  }
+
+  return record.path[Math.min(turn, record.path.length - 1)]
+}
+
+function loadStoredSnapshot(): ReplaySnapshot {
+  if (typeof window === 'undefined') {
+    return createEmptyReplaySnapshot()
+  }
+
+  return parseStoredReplaySnapshot(window.localStorage.getItem(REPLAY_STORAGE_KEY))
+}
+
+export function useReplayStore(): UseReplayStoreResult {
+  const [replaySnapshot, setReplaySnapshot] = useState(loadStoredSnapshot)
+  const [lastWinComparison, setLastWinComparison] = useState<LastWinComparison | null>(null)
+
+  useEffect(() => {
+    if (typeof window === 'undefined') {
+      return
+    }
+
+    window.localStorage.setItem(REPLAY_STORAGE_KEY, JSON.stringify(replaySnapshot))
+  }, [replaySnapshot])
+
+  const recordWin = useCallback(
+    (record: ReplayRecord, regions: Region[] = []) => {
+      const best = replaySnapshot.bestByPackId[bestReplayKey(record.packId, record.playDifficulty)]
+
+      setLastWinComparison(
+        best
+          ? {
+              packId: record.packId,
+              turns: record.turns,
+              comparison: compareReplays(record, best, regions),
+            }
+          : null,
+      )
+      setReplaySnapshot((snapshot) => applyWinToReplaySnapshot(snapshot, record))
+    },
+    [replaySnapshot],
+  )
+
+  return {
+    replaySnapshot,
+    lastWinComparison,
+    recordWin,
+  }
+}
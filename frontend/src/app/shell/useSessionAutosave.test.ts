@@ -0,0 +1,73 @@
+import { describe, expect, it } from 'vitest'
+
+import { gameReducer, performInteraction, resumeSession, waitTurn } from '../../game/gameSlice'
+import {
+  AUTOSAVE_EVERY_TURNS,
+  autosaveDecision,
+  parseStoredSession,
+  sessionFromGame,
+} from './useSessionAutosave'
+
+const now = new Date('2026-03-01T00:00:00.000Z')
+
+function playedTurns(turns: number) {
+  let state = gameReducer(undefined, { type: 'init' })
+
+  for (let turn = 0; turn < turns; turn += 1) {
+    state = gameReducer(state, waitTurn())
+  }
+
+  return state
+}
+
+describe('useSessionAutosave helpers', () => {
+  it('saves every few turns and keeps the previous save on a fresh level', () => {
+    expect(autosaveDecision(playedTurns(0), 0, now)).toEqual({ kind: 'Keep' })
+    expect(autosaveDecision(playedTurns(AUTOSAVE_EVERY_TURNS - 1), 0, now)).toEqual({
+      kind: 'Keep',
+    })
+
+    const decision = autosaveDecision(playedTurns(AUTOSAVE_EVERY_TURNS), 0, now)
+
+    expect(decision.kind).toBe('Save')
+    expect(decision.kind === 'Save' && decision.session.actions).toHaveLength(
+      AUTOSAVE_EVERY_TURNS,
+    )
+    expect(autosaveDecision(playedTurns(AUTOSAVE_EVERY_TURNS), AUTOSAVE_EVERY_TURNS, now)).toEqual({
+      kind: 'Keep',
+    })
+  })
+
+  it('clears the save once the run is over', () => {
+    const ended = { ...playedTurns(2), phase: 'Detected' as const }
+
+    expect(autosaveDecision(ended, 0, now)).toEqual({ kind: 'Clear' })
+    expect(sessionFromGame(ended, now)).toBeNull()
+  })
+
+  it('round-trips a session through storage and rejects malformed ones', () => {
+    const session = sessionFromGame(playedTurns(3), now)
+
+    expect(parseStoredSession(JSON.stringify(session))).toEqual(session)
+    expect(parseStoredSession('{"version":1}')).toBeNull()
+    expect(parseStoredSession('not json')).toBeNull()
+    expect(parseStoredSession(null)).toBeNull()
+  })
+
+  it('resumes a saved session by replaying it on the loaded level', () => {
+    const played = gameReducer(
+      playedTurns(2),
+      performInteraction({ kind: 'Move', direction: 'east' }),
+    )
+    const session = sessionFromGame(played, now)
+    const resumed = gameReducer(
+      gameReducer(undefined, { type: 'init' }),
+      resumeSession(session?.actions ?? []),
+    )
+
+    expect(resumed.turn).toBe(3)
+    expect(resumed.worldLine.path).toEqual(played.worldLine.path)
+    expect(resumed.restartsRemaining).toBe(played.restartsRemaining)
+    expect(resumed.status).toBe('Resumed at turn 3')
+  })
+})
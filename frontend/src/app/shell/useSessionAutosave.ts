@@ -0,0 +1,167 @@
+import { useEffect, useState } from 'react'
+
+import { normalizeMutators, type MutatorId } from '../../data/mutators'
+import { isPlayDifficulty, type PlayDifficulty } from '../../data/playDifficulty'
+import type { GameState } from '../../game/gameSlice'
+import type { InteractionAction } from '../../game/interactions/types'
+import type { AppStore } from '../../game/store'
+
+export const SESSION_STORAGE_KEY = 'hwu.web.session.v1'
+
+/** Committed turns between autosaves; leaving the page saves too. */
+export const AUTOSAVE_EVERY_TURNS = 5
+
+/**
+ * A run in progress, kept as the actions that produced it. Resuming loads the same pack under the
+ * same rules and difficulty and replays them, so the save stays small and independent of the
+ * shape of the game state.
+ */
+export interface SavedSession {
+  version: 1
+  packId: string
+  ruleProfileId: string
+  playDifficulty: PlayDifficulty
+  /** Challenge mutators the run was started with; missing in saves from before mutators. */
+  mutators?: MutatorId[]
+  turn: number
+  actions: InteractionAction[]
+  savedAt: string
+}
+
+export type AutosaveDecision =
+  | { kind: 'Save'; session: SavedSession }
+  | { kind: 'Clear' }
+  | { kind: 'Keep' }
+
+/** Null while there is nothing worth resuming: no turn played yet, or the run is over. */
+export function sessionFromGame(game: GameState, now: Date): SavedSession | null {
+  if (game.turn === 0 || game.phase !== 'Playing') {
+    return null
+  }
+
+  return {
+    version: 1,
+    packId: game.contentPackId,
+    ruleProfileId: game.ruleProfile.id,
+    playDifficulty: game.playDifficulty,
+    ...(game.mutators.length > 0 ? { mutators: [...game.mutators] } : {}),
+    turn: game.turn,
+    actions: game.history.map((entry) => entry.action),
+    savedAt: now.toISOString(),
+  }
+}
+
+/**
+ * What to do with the stored session after a store update. A finished run clears it; a fresh level
+ * (turn 0) leaves the previous session alone so it can still be resumed.
+ */
+export function autosaveDecision(
+  game: GameState,
+  lastSavedTurn: number,
+  now: Date,
+): AutosaveDecision {
+  if (game.phase === 'Won' || game.phase === 'Detected' || game.phase === 'Paradox') {
+    return { kind: 'Clear' }
+  }
+
+  const turnsSinceSave = game.turn < lastSavedTurn ? game.turn : game.turn - lastSavedTurn
+  const session = sessionFromGame(game, now)
+
+  return session && turnsSinceSave >= AUTOSAVE_EVERY_TURNS
+    ? { kind: 'Save', session }
+    : { kind: 'Keep' }
+}
+
+export function parseStoredSession(raw: string | null): SavedSession | null {
+  if (!raw) {
+    return null
+  }
+
+  try {
+    const parsed = JSON.parse(raw) as Partial<SavedSession>
+
+    if (
+      parsed.version !== 1 ||
+      typeof parsed.packId !== 'string' ||
+      typeof parsed.ruleProfileId !== 'string' ||
+      !isPlayDifficulty(parsed.playDifficulty) ||
+      (parsed.mutators !== undefined && !Array.isArray(parsed.mutators)) ||
+      typeof parsed.turn !== 'number' ||
+      !Array.isArray(parsed.actions) ||
+      typeof parsed.savedAt !== 'string'
+    ) {
+      return null
+    }
+
+    return {
+      ...(parsed as SavedSession),
+      ...(parsed.mutators !== undefined ? { mutators: normalizeMutators(parsed.mutators) } : {}),
+    }
+  } catch {
+    return null
+  }
+}
+
+function loadStoredSession(): SavedSession | null {
+  if (typeof window === 'undefined') {
+    return null
+  }
+
+  return parseStoredSession(window.localStorage.getItem(SESSION_STORAGE_KEY))
+}
+
+export interface UseSessionAutosaveResult {
+  /** Latest saved session, offered as "resume last session"; null when there is none. */
+  savedSession: SavedSession | null
+}
+
+export function useSessionAutosave(store: AppStore): UseSessionAutosaveResult {
+  const [savedSession, setSavedSession] = useState(loadStoredSession)
+
+  useEffect(() => {
+    let lastSavedTurn = store.getState().game.turn
+    let cleared = false
+
+    const save = (session: SavedSession) => {
+      window.localStorage.setItem(SESSION_STORAGE_KEY, JSON.stringify(session))
+      lastSavedTurn = session.turn
+      cleared = false
+      setSavedSession(session)
+    }
+
+    const unsubscribe = store.subscribe(() => {
+      const game = store.getState().game
+      const decision = autosaveDecision(game, lastSavedTurn, new Date())
+
+      if (game.turn < lastSavedTurn) {
+        lastSavedTurn = 0
+      }
+
+      if (decision.kind === 'Save') {
+        save(decision.session)
+      } else if (decision.kind === 'Clear' && !cleared) {
+        window.localStorage.removeItem(SESSION_STORAGE_KEY)
+        cleared = true
+        setSavedSession(null)
+      }
+    })
+
+    // Closing or reloading the tab is the web's "quit": keep what was played since the last save.
+    const onPageHide = () => {
+      const session = sessionFromGame(store.getState().game, new Date())
+
+      if (session) {
+        save(session)
+      }
+    }
+
+    window.addEventListener('pagehide', onPageHide)
+
+    return () => {
+      unsubscribe()
+      window.removeEventListener('pagehide', onPageHide)
+    }
+  }, [store])
+
+  return { savedSession }
+}
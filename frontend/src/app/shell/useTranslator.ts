@@ -0,0 +1,9 @@
+import { createContext, useContext } from 'react'
+
+import { createTranslator, DEFAULT_LOCALE, type Translator } from '../../render/i18n'
+
+export const TranslatorContext = createContext<Translator>(createTranslator(DEFAULT_LOCALE))
+
+export function useTranslator(): Translator {
+  return useContext(TranslatorContext)
+}
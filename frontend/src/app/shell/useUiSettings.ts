@@ -1,35 +1,67 @@
 import { useEffect, useState } from 'react'
 
+import { isPlayDifficulty } from '../../data/playDifficulty'
+import { RULE_PROFILES } from '../../data/ruleProfiles'
+import { isLocale } from '../../render/i18n'
+import { KEY_REPEAT_DELAY_OPTIONS_MS } from '../inputRepeat'
 import { defaultUiSettings, UI_SETTINGS_STORAGE_KEY, type UiSettings } from './constants'
 
-function loadUiSettings(): UiSettings {
+/** Fills in and validates each field; anything missing or invalid keeps its default. */
+export function sanitizeUiSettings(parsed: Partial<UiSettings>): UiSettings {
+  return {
+    showIsoPanel: parsed.showIsoPanel ?? defaultUiSettings.showIsoPanel,
+    compactHints: parsed.compactHints ?? defaultUiSettings.compactHints,
+    defaultDangerPreview: parsed.defaultDangerPreview ?? defaultUiSettings.defaultDangerPreview,
+    showGhostRun: parsed.showGhostRun ?? defaultUiSettings.showGhostRun,
+    exitCompass: parsed.exitCompass ?? defaultUiSettings.exitCompass,
+    assistDetectionWarnings:
+      parsed.assistDetectionWarnings ?? defaultUiSettings.assistDetectionWarnings,
+    accessibleCues: parsed.accessibleCues ?? defaultUiSettings.accessibleCues,
+    fogOfWar: parsed.fogOfWar ?? defaultUiSettings.fogOfWar,
+    soundCues: parsed.soundCues ?? defaultUiSettings.soundCues,
+    reduceMotion: parsed.reduceMotion ?? defaultUiSettings.reduceMotion,
+    speedrunTimer: parsed.speedrunTimer ?? defaultUiSettings.speedrunTimer,
+    ironmanCampaign: parsed.ironmanCampaign ?? defaultUiSettings.ironmanCampaign,
+    playDifficulty: isPlayDifficulty(parsed.playDifficulty)
+      ? parsed.playDifficulty
+      : defaultUiSettings.playDifficulty,
+    ruleProfileId: RULE_PROFILES.some((profile) => profile.id === parsed.ruleProfileId)
+      ? (parsed.ruleProfileId as string)
+      : defaultUiSettings.ruleProfileId,
+    keyRepeatDelayMs:
+      typeof parsed.keyRepeatDelayMs === 'number' &&
+      KEY_REPEAT_DELAY_OPTIONS_MS.includes(parsed.keyRepeatDelayMs)
+        ? parsed.keyRepeatDelayMs
+        : defaultUiSettings.keyRepeatDelayMs,
+    language: isLocale(parsed.language) ? parsed.language : defaultUiSettings.language,
+  }
+}
+
+function loadStoredUiSettings(): Partial<UiSettings> {
   if (typeof window === 'undefined') {
-    return defaultUiSettings
+    return {}
   }
 
   const raw = window.localStorage.getItem(UI_SETTINGS_STORAGE_KEY)
 
   if (!raw) {
-    return defaultUiSettings
+    return {}
   }
 
   try {
-    const parsed = JSON.parse(raw) as Partial<UiSettings>
-
-    return {
-      showIsoPanel: parsed.showIsoPanel ?? defaultUiSettings.showIsoPanel,
-      compactHints: parsed.compactHints ?? defaultUiSettings.compactHints,
-      defaultDangerPreview: parsed.defaultDangerPreview ?? defaultUiSettings.defaultDangerPreview,
-    }
+    return JSON.parse(raw) as Partial<UiSettings>
   } catch {
-    return defaultUiSettings
+    return {}
   }
 }
 
-export function useUiSettings() {
-  const [uiSettings, setUiSettings] = useState(loadUiSettings)
+/** `overrides` (config file, then page flags) win over the settings stored in the browser. */
+export function useUiSettings(overrides: Partial<UiSettings> = {}) {
+  const [uiSettings, setUiSettings] = useState(() =>
+    sanitizeUiSettings({ ...loadStoredUiSettings(), ...overrides }),
+  )
   const [showDangerPreview, setShowDangerPreview] = useState(
-    () => loadUiSettings().defaultDangerPreview,
+    () => uiSettings.defaultDangerPreview,
   )
 
   useEffect(() => {
@@ -0,0 +1,33 @@
+import { describe, expect, it } from 'vitest'
+
+import { createWorldLine, extendNormal, extendViaRift } from '../../core/worldLine'
+import { rowIndexForTime, worldLineRows } from './worldLineTable'
+
+describe('worldLineRows', () => {
+  it('lists every position with the step that reached it', () => {
+    const walked = extendNormal(createWorldLine({ x: 1, y: 1, t: 0 }), { x: 2, y: 1, t: 1 })
+
+    expect(walked.ok).toBe(true)
+    if (!walked.ok) {
+      return
+    }
+
+    const rifted = extendViaRift(walked.value, { x: 2, y: 1, t: 0 })
+
+    expect(rifted.ok).toBe(true)
+    if (!rifted.ok) {
+      return
+    }
+
+    const rows = worldLineRows(rifted.value)
+
+    expect(rows).toEqual([
+      { turn: 0, x: 1, y: 1, t: 0, via: 'Start' },
+      { turn: 1, x: 2, y: 1, t: 1, via: 'Normal' },
+      { turn: 2, x: 2, y: 1, t: 0, via: 'Rift' },
+    ])
+    expect(rowIndexForTime(rows, 0)).toBe(2)
+    expect(rowIndexForTime(rows, 1)).toBe(1)
+    expect(rowIndexForTime(rows, null)).toBe(2)
+  })
+})
@@ -0,0 +1,34 @@
+import type { WorldLineState, WorldLineStepKind } from '../../core/worldLine'
+
+export interface WorldLineRow {
+  /** Index into the path; turn 0 is the start. */
+  turn: number
+  x: number
+  y: number
+  t: number
+  via: WorldLineStepKind
+}
+
+/** One row per world line position, oldest first, with the kind of step that reached it. */
+export function worldLineRows(worldLine: WorldLineState): WorldLineRow[] {
+  return worldLine.path.map((position, turn) => ({
+    turn,
+    x: position.x,
+    y: position.y,
+    t: position.t,
+    via: worldLine.steps[turn] ?? 'Normal',
+  }))
+}
+
+/** Row whose slice the board shows: the latest one at `time`, or the last row for the present. */
+export function rowIndexForTime(rows: WorldLineRow[], time: number | null): number {
+  if (time !== null) {
+    for (let index = rows.length - 1; index >= 0; index -= 1) {
+      if (rows[index].t === time) {
+        return index
+      }
+    }
+  }
+
+  return Math.max(0, rows.length - 1)
+}
@@ -0,0 +1,36 @@
+import { describe, expect, it } from 'vitest'
+
+import { gameReducer, waitTurn } from '../game/gameSlice'
+import { formatSnapshot, snapshotFileName } from './snapshot'
+
+function snapshotInput() {
+  const state = gameReducer(gameReducer(undefined, { type: 'init' }), waitTurn())
+
+  return { ...state, packId: 'community/vault' }
+}
+
+describe('formatSnapshot', () => {
+  it('writes the viewed slice under a header', () => {
+    const lines = formatSnapshot(snapshotInput(), false).split('\n')
+
+    expect(lines[0]).toBe('he-walks-unseen snapshot: community/vault')
+    expect(lines[1]).toBe('turn 1, t=1, 12x12')
+    expect(lines[4]).toBe('t=1')
+    expect(lines[5 + 5][5]).toBe('@')
+    expect(lines.filter((line) => line.startsWith('t='))).toEqual(['t=1'])
+  })
+
+  it('writes every slice on request', () => {
+    const input = snapshotInput()
+    const text = formatSnapshot(input, true)
+
+    expect(text.split('\n').filter((line) => line.startsWith('t='))).toHaveLength(input.timeDepth)
+    expect(text).toContain('t=0\n')
+  })
+
+  it('names files after the pack and slice', () => {
+    const name = snapshotFileName(snapshotInput(), false, new Date('2026-01-02T03:04:05.000Z'))
+
+    expect(name).toBe('hwu-snapshot-community-vault-t1-2026-01-02T03-04-05-000Z.txt')
+  })
+})
@@ -0,0 +1,46 @@
+import { renderSliceAscii, type AsciiBoardInput } from '../render/ascii'
+
+export interface SnapshotInput extends AsciiBoardInput {
+  packId: string
+  turn: number
+  timeDepth: number
+  /** Slice the board shows; the only one written unless every slice is asked for. */
+  currentTime: number
+}
+
+const SNAPSHOT_LEGEND = 'legend: @ you, o past self, # wall, > exit, B box, E guard, . empty'
+
+/**
+ * Plain-text dump of the board for bug reports and level docs: a short header, then each slice
+ * as `renderSliceAscii` draws it.
+ */
+export function formatSnapshot(input: SnapshotInput, allSlices: boolean): string {
+  const times = allSlices
+    ? Array.from({ length: input.timeDepth }, (_, t) => t)
+    : [input.currentTime]
+  const header = [
+    `he-walks-unseen snapshot: ${input.packId}`,
+    `turn ${input.turn}, t=${input.currentTime}, ${input.boardWidth}x${input.boardHeight}`,
+    SNAPSHOT_LEGEND,
+  ]
+  const slices = times.map((t) => [`t=${t}`, ...renderSliceAscii(input, t)].join('\n'))
+
+  return `${[header.join('\n'), ...slices].join('\n\n')}\n`
+}
+
+export function snapshotFileName(input: SnapshotInput, allSlices: boolean, now: Date): string {
+  const slice = allSlices ? 'all' : `t${input.currentTime}`
+  const packId = input.packId.replace(/[^A-Za-z0-9_-]+/g, '-')
+
+  return `hwu-snapshot-${packId}-${slice}-${now.toISOString().replace(/[:.]/g, '-')}.txt`
+}
+
+export function downloadSnapshot(fileName: string, text: string): void {
+  const url = URL.createObjectURL(new Blob([text], { type: 'text/plain' }))
+  const link = document.createElement('a')
+
+  link.href = url
+  link.download = fileName
+  link.click()
+  URL.revokeObjectURL(url)
+}
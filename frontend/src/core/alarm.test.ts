@@ -0,0 +1,78 @@
+import { describe, expect, it } from 'vitest'
+
+import { blocksMovementAt, liftLockdown, lockdownAt, raiseLockdown } from './alarm'
+import { evaluateDetectionV1 } from './detection'
+import type { ResolvedObjectInstance } from './objects'
+import { createTimeCube, isBlocked, placeObjects } from './timeCube'
+import { createWorldLine } from './worldLine'
+
+const config = { threshold: 2, lockdownTurns: 3, glimpseMargin: 2, rangeBonus: 1 }
+
+const guard: ResolvedObjectInstance = {
+  id: 'enemy.alpha',
+  archetypeKey: 'enemy',
+  position: { x: 0, y: 0, t: 0 },
+  archetype: {
+    kind: 'enemy',
+    components: [
+      { kind: 'BlocksMovement' },
+      { kind: 'TimePersistent' },
+      { kind: 'Patrol', path: [{ x: 0, y: 0 }], loops: true },
+    ],
+    render: {},
+  },
+}
+
+const gate: ResolvedObjectInstance = {
+  id: 'gate.east',
+  archetypeKey: 'gate',
+  position: { x: 4, y: 4, t: 0 },
+  archetype: {
+    kind: 'gate',
+    components: [{ kind: 'Gate' }, { kind: 'TimePersistent' }],
+    render: {},
+  },
+}
+
+describe('lockdown', () => {
+  it('shuts gates only in the slices it covers, clipped to the cube', () => {
+    const placed = placeObjects(createTimeCube(6, 6, 5), [gate])
+
+    if (!placed.ok) {
+      throw new Error('placement failed')
+    }
+
+    const locked = raiseLockdown(placed.value, 3, config)
+
+    expect(locked.lockdowns).toEqual([{ from: 3, to: 5, rangeBonus: 1 }])
+    expect(lockdownAt(locked, 2)).toBeNull()
+    expect(blocksMovementAt(locked, gate, 2)).toBe(false)
+    expect(blocksMovementAt(locked, gate, 3)).toBe(true)
+    expect(isBlocked(locked, { x: 4, y: 4, t: 4 })).toBe(true)
+    expect(isBlocked(placed.value, { x: 4, y: 4, t: 4 })).toBe(false)
+    expect(liftLockdown(locked, 4).lockdowns).toEqual([{ from: 3, to: 4, rangeBonus: 1 }])
+    expect(liftLockdown(locked, 3).lockdowns).toEqual([])
+  })
+
+  it('lets guards see further while it lasts', () => {
+    const placed = placeObjects(createTimeCube(6, 6, 5), [guard])
+
+    if (!placed.ok) {
+      throw new Error('placement failed')
+    }
+
+    const input = {
+      worldLine: createWorldLine({ x: 0, y: 3, t: 0 }),
+      currentTime: 1,
+      config: { enabled: true, delayTurns: 1, maxDistance: 2 },
+    }
+
+    expect(evaluateDetectionV1({ ...input, cube: placed.value }).detected).toBe(false)
+    expect(evaluateDetectionV1({ ...input, cube: placed.value, rangeBonus: 1 }).detected).toBe(
+      true,
+    )
+    expect(
+      evaluateDetectionV1({ ...input, cube: raiseLockdown(placed.value, 1, config) }).detected,
+    ).toBe(true)
+  })
+})
@@ -0,0 +1,80 @@
+import { hasComponent } from './components'
+import type { ResolvedObjectInstance } from './objects'
+import type { TimeCube } from './timeCube'
+
+/** Level rules for alarms; levels without them never lock down. */
+export interface AlarmConfig {
+  /** Turns of being glimpsed that sound the alarm. */
+  threshold: number
+  /** Slices a lockdown lasts, starting with the one the alarm sounds in. */
+  lockdownTurns: number
+  /** A guard glimpses the player up to this many cells past its vision range. */
+  glimpseMargin: number
+  /** Added to every guard's vision range while the level is locked down. */
+  rangeBonus: number
+}
+
+export const DEFAULT_ALARM_GLIMPSE_MARGIN = 2
+export const DEFAULT_ALARM_RANGE_BONUS = 2
+
+/** Suspicion built up toward the next alarm; it starts over once the alarm sounds. */
+export interface AlarmState {
+  suspicion: number
+}
+
+/** Slices `from` up to but not including `to` in which gates are shut and guards see further. */
+export interface Lockdown {
+  from: number
+  to: number
+  rangeBonus: number
+}
+
+export function lockdownAt(cube: TimeCube, t: number): Lockdown | null {
+  return cube.lockdowns.find((lockdown) => t >= lockdown.from && t < lockdown.to) ?? null
+}
+
+/** Vision bonus lockdowns give guards in slice `t`; 0 outside them. */
+export function lockdownRangeBonusAt(cube: TimeCube, t: number): number {
+  return lockdownAt(cube, t)?.rangeBonus ?? 0
+}
+
+/** Walls always block; gates only in a locked-down slice. */
+export function blocksMovementAt(
+  cube: TimeCube,
+  object: ResolvedObjectInstance,
+  t: number,
+): boolean {
+  const { components } = object.archetype
+
+  return (
+    hasComponent(components, 'BlocksMovement') ||
+    (hasComponent(components, 'Gate') && lockdownAt(cube, t) !== null)
+  )
+}
+
+/** Ends every lockdown running in slice `t` at `t`; earlier slices stay locked down. */
+export function liftLockdown(cube: TimeCube, t: number): TimeCube {
+  const lockdowns = cube.lockdowns
+    .map((lockdown) => (t >= lockdown.from && t < lockdown.to ? { ...lockdown, to: t } : lockdown))
+    .filter((lockdown) => lockdown.to > lockdown.from)
+
+  return { ...cube, lockdowns }
+}
+
+/**
+ * Locks the level down from slice `from` on, clipped to the cube's time depth. Found bodies raise
+ * one too, so only the length and the vision bonus are needed.
+ */
+export function raiseLockdown(
+  cube: TimeCube,
+  from: number,
+  config: Pick<AlarmConfig, 'lockdownTurns' | 'rangeBonus'>,
+): TimeCube {
+  const lockdown = {
+    from,
+    to: Math.min(cube.timeDepth, from + config.lockdownTurns),
+    rangeBonus: config.rangeBonus,
+  }
+
+  return { ...cube, lockdowns: [...cube.lockdowns, lockdown] }
+}
@@ -0,0 +1,81 @@
+import type { ExtractionComponent } from './components'
+import { currentLayer } from './elevation'
+import type { Position3D } from './position'
+import { hasExit, objectsAt, type TimeCube } from './timeCube'
+import { createWorldLine, currentPosition, type WorldLineState } from './worldLine'
+
+/** Id of the character every level starts with, at `map.start`. */
+export const PLAYER_CHARACTER_ID = 'player'
+
+/** An extra player-controlled character declared by the level. */
+export interface CharacterStart {
+  id: string
+  start: Position3D
+}
+
+/**
+ * A character that is not being controlled. Its world line stays as it was left until the player
+ * switches back; the controlled character's world line is always the one in `worldLine`.
+ */
+export interface ParkedCharacter {
+  id: string
+  worldLine: WorldLineState
+}
+
+export function parkCharacters(starts: CharacterStart[]): ParkedCharacter[] {
+  return starts.map((character) => ({
+    id: character.id,
+    worldLine: createWorldLine(character.start),
+  }))
+}
+
+/** Exits are on the ground; standing on a catwalk above one does not count. */
+export function isAtExit(cube: TimeCube, worldLine: WorldLineState): boolean {
+  const position = currentPosition(worldLine)
+
+  return position !== null && hasExit(cube, position) && currentLayer(worldLine) === 'ground'
+}
+
+/** Turns the controlled character must hold its exit before it counts; 0 off extraction points. */
+export function extractionTurnsAt(cube: TimeCube, worldLine: WorldLineState): number {
+  const position = currentPosition(worldLine)
+
+  if (position === null || !isAtExit(cube, worldLine)) {
+    return 0
+  }
+
+  return Math.max(
+    0,
+    ...objectsAt(cube, position).flatMap((object) =>
+      object.archetype.components
+        .filter((component): component is ExtractionComponent => component.kind === 'Extraction')
+        .map((component) => component.turns),
+    ),
+  )
+}
+
+/**
+ * Multi-character levels are only won once every character stands on an exit. Characters caught in
+ * hotseat play are out of the run and no longer count.
+ */
+export function allCharactersAtExit(
+  cube: TimeCube,
+  worldLine: WorldLineState,
+  parked: ParkedCharacter[],
+  caughtIds: string[] = [],
+): boolean {
+  const waiting = parked.filter((character) => !caughtIds.includes(character.id))
+
+  return [worldLine, ...waiting.map((character) => character.worldLine)].every((line) =>
+    isAtExit(cube, line),
+  )
+}
+
+/** Hotseat seats alternate along the character order: `player` is seat 1, the next seat 2. */
+export function hotseatSeat(starts: CharacterStart[], characterId: string): number {
+  const index = [PLAYER_CHARACTER_ID, ...starts.map((character) => character.id)].indexOf(
+    characterId,
+  )
+
+  return (Math.max(0, index) % 2) + 1
+}
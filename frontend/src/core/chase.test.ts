@@ -0,0 +1,104 @@
+import { describe, expect, it } from 'vitest'
+
+import { propagateChasers } from './chase'
+import type { ResolvedObjectInstance } from './objects'
+import { createTimeCube, objectPositionAt, placeObjects } from './timeCube'
+import { createWorldLine } from './worldLine'
+
+function chaserObject(
+  id: string,
+  x: number,
+  y: number,
+  alertDistance: number,
+): ResolvedObjectInstance {
+  return {
+    id,
+    archetypeKey: 'enemy',
+    position: { x, y, t: 0 },
+    archetype: {
+      kind: 'enemy',
+      components: [
+        { kind: 'BlocksMovement' },
+        { kind: 'TimePersistent' },
+        { kind: 'Guard', guard: 'Chaser', alertDistance },
+      ],
+      render: {},
+    },
+  }
+}
+
+describe('propagateChasers', () => {
+  it('walks an alerted chaser toward the last sighting and waits there', () => {
+    const placed = placeObjects(createTimeCube(8, 8, 8), [chaserObject('enemy.chaser', 0, 0, 5)])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const chased = propagateChasers({
+      cube: placed.value,
+      worldLine: createWorldLine({ x: 3, y: 0, t: 0 }),
+      config: { enabled: true, delayTurns: 1, maxDistance: 0 },
+    })
+
+    expect(chased.ok).toBe(true)
+    if (!chased.ok) {
+      return
+    }
+
+    expect(chased.value.warnings).toEqual([])
+    expect(objectPositionAt(chased.value.cube, 'enemy.chaser', 1)).toEqual({ x: 0, y: 0, t: 1 })
+    expect(objectPositionAt(chased.value.cube, 'enemy.chaser', 2)).toEqual({ x: 1, y: 0, t: 2 })
+    expect(objectPositionAt(chased.value.cube, 'enemy.chaser', 4)).toEqual({ x: 3, y: 0, t: 4 })
+    expect(objectPositionAt(chased.value.cube, 'enemy.chaser', 7)).toEqual({ x: 3, y: 0, t: 7 })
+  })
+
+  it('leaves chasers on schedule when the player stays out of alert range', () => {
+    const placed = placeObjects(createTimeCube(8, 8, 8), [chaserObject('enemy.chaser', 0, 0, 2)])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const chased = propagateChasers({
+      cube: placed.value,
+      worldLine: createWorldLine({ x: 3, y: 0, t: 0 }),
+      config: { enabled: true, delayTurns: 1, maxDistance: 0 },
+    })
+
+    expect(chased.ok && chased.value.cube).toBe(placed.value)
+  })
+
+  it('walks over tiles that do not block movement', () => {
+    const exit: ResolvedObjectInstance = {
+      id: 'exit.a',
+      archetypeKey: 'exit',
+      position: { x: 1, y: 0, t: 0 },
+      archetype: { kind: 'exit', components: [{ kind: 'TimePersistent' }], render: {} },
+    }
+    const placed = placeObjects(createTimeCube(8, 8, 8), [
+      chaserObject('enemy.chaser', 0, 0, 5),
+      exit,
+    ])
+
+    if (!placed.ok) {
+      throw new Error('placement failed')
+    }
+
+    const chased = propagateChasers({
+      cube: placed.value,
+      worldLine: createWorldLine({ x: 3, y: 0, t: 0 }),
+      config: { enabled: true, delayTurns: 1, maxDistance: 0 },
+    })
+
+    if (!chased.ok) {
+      throw new Error('chase failed')
+    }
+
+    expect(chased.value.warnings).toEqual([])
+    expect(objectPositionAt(chased.value.cube, 'enemy.chaser', 2)).toEqual({ x: 1, y: 0, t: 2 })
+    expect(objectPositionAt(chased.value.cube, 'enemy.chaser', 4)).toEqual({ x: 3, y: 0, t: 4 })
+  })
+})
@@ -0,0 +1,221 @@
+import { blocksMovementAt } from './alarm'
+import { hasComponent, type ChaserGuardComponent } from './components'
+import { hasLineOfSight, type DetectionConfig } from './detection'
+import { isVisibleFromGround, layerAtTurn } from './elevation'
+import { chaserOf } from './guards'
+import { isShadowedAt } from './lighting'
+import {
+  isInBounds,
+  movePosition,
+  wrappedAxisDelta,
+  wrappedDistance,
+  wrapPosition,
+  type Direction2D,
+  type Position2D,
+} from './position'
+import {
+  propagationWarningFor,
+  type GuardPropagation,
+  type PropagationWarning,
+} from './propagation'
+import type { Result } from './result'
+import {
+  allObjects,
+  isInSlice,
+  objectPositionAt,
+  objectsAt,
+  retraceObject,
+  timeBefore,
+  wrappingOf,
+  type CubeError,
+  type RelocationError,
+  type TimeCube,
+} from './timeCube'
+import { positionsAtTime, type WorldLineState } from './worldLine'
+
+export interface ChasePropagationInput {
+  cube: TimeCube
+  worldLine: WorldLineState
+  config: DetectionConfig
+  configByEnemyId?: Record<string, DetectionConfig>
+}
+
+interface ChaseTrail {
+  startTime: number
+  path: Position2D[]
+}
+
+/** Player cell the chaser at `position` notices in slice `t`, using its detection delay. */
+function sightingAt(
+  input: ChasePropagationInput,
+  cube: TimeCube,
+  chaser: ChaserGuardComponent,
+  config: DetectionConfig,
+  position: Position2D,
+  t: number,
+): Position2D | null {
+  if (!config.enabled || config.delayTurns < 1) {
+    return null
+  }
+
+  const observedTime = timeBefore(cube, t, config.delayTurns)
+
+  if (observedTime === null) {
+    return null
+  }
+
+  const sightings = positionsAtTime(input.worldLine, observedTime).filter(
+    (entry) =>
+      wrappedDistance(position, entry.position, wrappingOf(cube)) <= chaser.alertDistance &&
+      !isShadowedAt(cube, entry.position) &&
+      isVisibleFromGround(cube, entry.position, layerAtTurn(input.worldLine, entry.turn)) &&
+      hasLineOfSight({ cube, from: position, to: entry.position, atTime: t }),
+  )
+
+  if (sightings.length === 0) {
+    return null
+  }
+
+  const latest = sightings.reduce((best, entry) => (entry.turn > best.turn ? entry : best))
+  return { x: latest.position.x, y: latest.position.y }
+}
+
+/** Guards walk over exits, rifts, floor tiles and anything else that does not block movement. */
+export function isFreeFor(cube: TimeCube, id: string, position: Position2D, t: number): boolean {
+  return (
+    isInBounds(position, cube.width, cube.height) &&
+    objectsAt(cube, { x: position.x, y: position.y, t }).every(
+      (object) => object.id === id || !blocksMovementAt(cube, object, t),
+    )
+  )
+}
+
+/** Ids a guard may share a cell with when its trail is retraced; see `isFreeFor`. */
+export function walkableIds(cube: TimeCube): string[] {
+  return allObjects(cube)
+    .filter((object) => !hasComponent(object.archetype.components, 'BlocksMovement'))
+    .map((object) => object.id)
+}
+
+/** Greedy step toward `target`, closing the wider axis gap first; stays put when cornered. */
+export function stepToward(
+  cube: TimeCube,
+  id: string,
+  from: Position2D,
+  target: Position2D,
+  t: number,
+): Position2D | null {
+  const wrapping = wrappingOf(cube)
+  const dx = wrappedAxisDelta(from.x, target.x, wrapping.width)
+  const dy = wrappedAxisDelta(from.y, target.y, wrapping.height)
+  const horizontal: Direction2D[] = dx > 0 ? ['east'] : dx < 0 ? ['west'] : []
+  const vertical: Direction2D[] = dy > 0 ? ['south'] : dy < 0 ? ['north'] : []
+  const directions =
+    Math.abs(dx) >= Math.abs(dy) ? [...horizontal, ...vertical] : [...vertical, ...horizontal]
+  const candidates = [
+    ...directions.map((direction) => wrapPosition(movePosition(from, direction), wrapping)),
+    from,
+  ]
+
+  return candidates.find((candidate) => isFreeFor(cube, id, candidate, t)) ?? null
+}
+
+function chaseTrail(
+  input: ChasePropagationInput,
+  cube: TimeCube,
+  id: string,
+  chaser: ChaserGuardComponent,
+): ChaseTrail | null {
+  const config = input.configByEnemyId?.[id] ?? input.config
+  const start = objectPositionAt(cube, id, 0)
+
+  if (!start) {
+    return null
+  }
+
+  let position: Position2D = { x: start.x, y: start.y }
+  let lastKnown: Position2D | null = null
+  let startTime: number | null = null
+  const path: Position2D[] = []
+
+  for (let t = 0; t + 1 < cube.timeDepth && isInSlice(cube, id, t + 1); t += 1) {
+    lastKnown = sightingAt(input, cube, chaser, config, position, t) ?? lastKnown
+
+    if (!lastKnown) {
+      const scheduled = objectPositionAt(cube, id, t + 1)
+
+      if (!scheduled) {
+        return null
+      }
+
+      position = { x: scheduled.x, y: scheduled.y }
+      continue
+    }
+
+    const next = stepToward(cube, id, position, lastKnown, t + 1)
+
+    if (!next) {
+      break
+    }
+
+    if (startTime === null) {
+      startTime = t + 1
+    }
+
+    path.push(next)
+    position = next
+  }
+
+  return startTime === null ? null : { startTime, path }
+}
+
+/**
+ * Re-resolves every Chaser against the current world line. A chaser follows its scheduled
+ * (patrol or static) cells until it sees the player within `alertDistance`, then walks one cell
+ * per slice toward the last position it saw. Sightings only accumulate as the world line grows,
+ * so rerunning after each turn is deterministic. A chase that cannot be written into the cube
+ * leaves that chaser on its old cells and is reported as a warning.
+ */
+export function propagateChasers(
+  input: ChasePropagationInput,
+): Result<GuardPropagation, CubeError | RelocationError> {
+  let cube = input.cube
+  const warnings: PropagationWarning[] = []
+
+  for (const object of allObjects(input.cube)) {
+    const chaser = chaserOf(object.archetype.components)
+
+    if (!chaser) {
+      continue
+    }
+
+    const trail = chaseTrail(input, cube, object.id, chaser)
+
+    if (!trail) {
+      continue
+    }
+
+    const retraced = retraceObject(
+      cube,
+      object.id,
+      trail.startTime,
+      trail.path,
+      walkableIds(cube),
+    )
+
+    if (!retraced.ok) {
+      const warning = propagationWarningFor(object.id, retraced.error)
+
+      if (!warning) {
+        return retraced
+      }
+
+      warnings.push(warning)
+      continue
+    }
+
+    cube = retraced.value
+  }
+
+  return { ok: true, value: { cube, warnings } }
+}
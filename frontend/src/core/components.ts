@@ -1,4 +1,4 @@
-import type { Position2D, Position3D } from './position'
+import type { Direction2D, Position2D, Position3D } from './position'
 
 export type MarkerComponentKind =
   | 'BlocksMovement'
@@ -7,15 +7,52 @@ export type MarkerComponentKind =
   | 'Exit'
   | 'Pushable'
   | 'Pullable'
+  /** Pushable only with momentum (previous step in the push direction) and never in a chain. */
+  | 'Heavy'
+  /** Floor tile: whatever enters it keeps sliding in the same direction, one cell per slice. */
+  | 'Slippery'
+  /** Climbable between the ground and the catwalk above; only valid on a `Catwalk` cell. */
+  | 'Ladder'
+  /** Box that a push can set on top of another stackable box; pulling takes the top one off. */
+  | 'Stackable'
+  /** Blocking tile only a box can fill: a box pushed in sinks and the cell becomes floor. */
+  | 'Water'
+  /** Floor tile no one sees into: whoever stands on it is never detected. */
+  | 'Shadow'
+  /**
+   * Fixed in time: never time-pushed, and never pushed, pulled or broken in a slice the player
+   * already lived through and came back to by rift. Ordinary play in the present still moves it.
+   */
+  | 'Anchored'
+  /** Open passage that shuts like a wall while the level is locked down by an alarm. */
+  | 'Gate'
 
 export type MarkerComponent = {
   kind: MarkerComponentKind
 }
 
+/**
+ * Lever pulled on a patrol from slice `at` on: `reverse` turns it back along its route, `pause`
+ * holds it in place and `resume` sets it walking again.
+ */
+export type PatrolSwitch = {
+  at: number
+  kind: 'reverse' | 'pause' | 'resume'
+}
+
 export type PatrolComponent = {
   kind: 'Patrol'
   path: Position2D[]
   loops: boolean
+  /** Slices spent at each waypoint per visit, index-aligned with `path`; 1 when omitted. */
+  dwell?: number[]
+  /**
+   * Ascending slices at which the guard hesitates: it keeps its previous cell and the rest of
+   * the route runs one slice later. Rolled once at load from the level seed.
+   */
+  holds?: number[]
+  /** Lever pulls on this guard by slice, same-slice pulls in play order; runtime only. */
+  switches?: PatrolSwitch[]
 }
 
 export type RiftComponent = {
@@ -24,8 +61,233 @@ export type RiftComponent = {
   bidirectional: boolean
 }
 
-export type Component = MarkerComponent | PatrolComponent | RiftComponent
+/** Sentries stand still and turn through `facings`, one per slice, watching only the faced cone. */
+export type SentryGuardComponent = {
+  kind: 'Guard'
+  guard: 'Sentry'
+  facings: Direction2D[]
+}
+
+/** Chasers leave their patrol to walk toward the player once seen within `alertDistance`. */
+export type ChaserGuardComponent = {
+  kind: 'Guard'
+  guard: 'Chaser'
+  alertDistance: number
+}
+
+/**
+ * Echoes retrace an earlier attempt of the player (`attempt` counts from 1). Their `Patrol` holds
+ * one cell per slice, and `facings[t]` is the way they walked into slice `t`. Only spawned at
+ * runtime, never authored.
+ */
+export type EchoGuardComponent = {
+  kind: 'Guard'
+  guard: 'Echo'
+  attempt: number
+  facings: Direction2D[]
+}
+
+export type GuardComponent =
+  | SentryGuardComponent
+  | { kind: 'Guard'; guard: 'Patroller' }
+  | ChaserGuardComponent
+  | EchoGuardComponent
+
+/**
+ * Fixed camera: turns to the next entry of `facings` every `turnEvery` slices and only watches the
+ * faced cone. `vision` replaces the rules-wide detection range and delay for this camera.
+ */
+export type CameraComponent = {
+  kind: 'Camera'
+  facings: Direction2D[]
+  turnEvery: number
+  vision?: { delayTurns: number; maxDistance: number }
+}
+
+/**
+ * Bodiless moving light: the beam center follows `path` like a patrol (same `loops`/`dwell`
+ * rules) and lights a circle around it, or a cone from the light's own cell toward it.
+ */
+export type SearchlightComponent = {
+  kind: 'Searchlight'
+  shape: 'circle' | 'cone'
+  radius: number
+  path: Position2D[]
+  loops: boolean
+  dwell?: number[]
+}
+
+/** Physical height in cells. Only objects at least `EYE_HEIGHT` tall hide what is behind them. */
+export type HeightComponent = {
+  kind: 'Height'
+  height: number
+}
+
+/**
+ * Lamp lighting every cell within `radius` (Manhattan) while it burns, from `activeFrom` up to
+ * but excluding `activeUntil`. Only matters in dark levels (`darkVisionFactor`).
+ */
+export type LightSourceComponent = {
+  kind: 'LightSource'
+  radius: number
+  activeFrom?: number
+  activeUntil?: number
+}
+
+/**
+ * Raised walkway over the ground cell. Walking it is a second layer: ground objects neither block
+ * nor hide anyone up there, and ground guards only see onto the catwalk past an `open` railing.
+ */
+export type CatwalkComponent = {
+  kind: 'Catwalk'
+  railing: 'open' | 'closed'
+}
+
+/**
+ * Wall that gives way after `hits` blows from the `Break` action. From the slice of the last blow
+ * on it is gone for good; earlier slices keep it.
+ */
+export type BreakableComponent = {
+  kind: 'Breakable'
+  hits: number
+}
+
+/**
+ * Grandfather-paradox link: the object only exists while `source` is still in slice `at`. Take
+ * the source out of that slice (break it in the past) and this one is gone from `at + 1` on; it
+ * returns if the source does.
+ */
+export type DependsOnComponent = {
+  kind: 'DependsOn'
+  source: string
+  at: number
+}
+
+/**
+ * Per-entity look: `glyph` is drawn in place of the icon and `color` replaces the tile fill, so
+ * levels can tell guard types or décor apart without new archetype kinds.
+ */
+export type AppearanceComponent = {
+  kind: 'Appearance'
+  glyph?: string
+  color?: string
+}
+
+/**
+ * Object covering `width` x `height` cells, like a double door or a large crate. Its position is
+ * the north-west cell; it blocks, hides and is pushed as one block.
+ */
+export type FootprintComponent = {
+  kind: 'Footprint'
+  width: number
+  height: number
+}
+
+/**
+ * Linked instances sharing `id`, set from an instance's `group`. A push moves the whole group one
+ * cell at once, and the blow that breaks one member brings down every member in that slice.
+ */
+export type GroupComponent = {
+  kind: 'Group'
+  id: string
+}
+
+/**
+ * Makes an exit an extraction point: the player has to hold its cell for `turns` more turns after
+ * reaching it, unseen, before the level is won.
+ */
+export type ExtractionComponent = {
+  kind: 'Extraction'
+  turns: number
+}
+
+/** What a lever does to the patrols of its `targets` each time it is pulled. */
+export type LeverEffect = 'reverse' | 'pause'
+
+/**
+ * Fixture the player uses from the next cell with the `Interact` action. A `Terminal` ends the
+ * running lockdown from that slice on and clears the alarm's suspicion. A `Lever` turns every
+ * target guard back (`reverse`) or stops and restarts it (`pause`) from that slice on.
+ */
+export type InteractableComponent =
+  | { kind: 'Interactable'; interaction: 'Terminal' }
+  | { kind: 'Interactable'; interaction: 'Lever'; effect: LeverEffect; targets: string[] }
+
+/**
+ * Guard `of`, knocked out and left lying where it fell. Bodies are pushed and pulled like boxes;
+ * a guard that sees one alerts the level.
+ */
+export type BodyComponent = {
+  kind: 'Body'
+  of: string
+}
+
+/** What using an `Interactable` sets off; each kind has its own trigger. */
+export type InteractionKind = InteractableComponent['interaction']
+
+export type Component =
+  | MarkerComponent
+  | PatrolComponent
+  | RiftComponent
+  | GuardComponent
+  | CameraComponent
+  | SearchlightComponent
+  | HeightComponent
+  | LightSourceComponent
+  | CatwalkComponent
+  | BreakableComponent
+  | DependsOnComponent
+  | AppearanceComponent
+  | FootprintComponent
+  | GroupComponent
+  | ExtractionComponent
+  | InteractableComponent
+  | BodyComponent
+
+/** Guards see over anything shorter than this, so low cover blocks movement but not sight. */
+export const EYE_HEIGHT = 2
 
 export function hasComponent(components: Component[], kind: Component['kind']): boolean {
   return components.some((component) => component.kind === kind)
 }
+
+export function appearanceOf(components: Component[]): AppearanceComponent | null {
+  for (const component of components) {
+    if (component.kind === 'Appearance') {
+      return component
+    }
+  }
+
+  return null
+}
+
+export function interactableOf(components: Component[]): InteractableComponent | null {
+  for (const component of components) {
+    if (component.kind === 'Interactable') {
+      return component
+    }
+  }
+
+  return null
+}
+
+/** Décor has an appearance and nothing else, so no gameplay rule can ever apply to it. */
+export function isDecor(components: Component[]): boolean {
+  return (
+    components.length > 0 && components.every((component) => component.kind === 'Appearance')
+  )
+}
+
+/**
+ * Whether an object occludes line of sight. A declared `Height` decides on its own; objects
+ * without one fall back to the `BlocksVision` marker.
+ */
+export function blocksVision(components: Component[]): boolean {
+  for (const component of components) {
+    if (component.kind === 'Height') {
+      return component.height >= EYE_HEIGHT
+    }
+  }
+
+  return hasComponent(components, 'BlocksVision')
+}
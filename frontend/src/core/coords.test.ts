@@ -0,0 +1,24 @@
+import { describe, expect, it } from 'vitest'
+
+import { toTimeCoord, toTurn, toXCoord, toYCoord } from './coords'
+
+describe('coordinate conversions', () => {
+  it('accepts integers inside the axis', () => {
+    expect(toXCoord(0, 12)).toEqual({ ok: true, value: 0 })
+    expect(toYCoord(11, 12)).toEqual({ ok: true, value: 11 })
+    expect(toTimeCoord(3, 4)).toEqual({ ok: true, value: 3 })
+    expect(toTurn(2, 3)).toEqual({ ok: true, value: 2 })
+  })
+
+  it('names the axis a value falls outside of', () => {
+    expect(toTimeCoord(4, 4)).toEqual({
+      ok: false,
+      error: { kind: 'CoordOutOfRange', axis: 't', value: 4, limit: 4 },
+    })
+    expect(toTurn(-1, 3)).toEqual({
+      ok: false,
+      error: { kind: 'CoordOutOfRange', axis: 'turn', value: -1, limit: 3 },
+    })
+    expect(toXCoord(1.5, 12).ok).toBe(false)
+  })
+})
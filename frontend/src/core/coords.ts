@@ -0,0 +1,58 @@
+import type { Result } from './result'
+
+declare const coordBrand: unique symbol
+
+type Coord<Axis extends string> = number & { readonly [coordBrand]: Axis }
+
+export type XCoord = Coord<'x'>
+export type YCoord = Coord<'y'>
+/** A slice of the cube. */
+export type TimeCoord = Coord<'t'>
+/**
+ * An index into a world line's path, 0 being the start. Rifts move the player between slices
+ * without a turn passing in between, so a turn is not a time and the two must not be swapped.
+ */
+export type Turn = Coord<'turn'>
+
+export type CoordAxis = 'x' | 'y' | 't' | 'turn'
+
+export type CoordError = { kind: 'CoordOutOfRange'; axis: CoordAxis; value: number; limit: number }
+
+function checked<C extends number>(
+  axis: CoordAxis,
+  value: number,
+  limit: number,
+): Result<C, CoordError> {
+  if (!Number.isInteger(value) || value < 0 || value >= limit) {
+    return { ok: false, error: { kind: 'CoordOutOfRange', axis, value, limit } }
+  }
+
+  return { ok: true, value: value as C }
+}
+
+export function toXCoord(value: number, width: number): Result<XCoord, CoordError> {
+  return checked('x', value, width)
+}
+
+export function toYCoord(value: number, height: number): Result<YCoord, CoordError> {
+  return checked('y', value, height)
+}
+
+export function toTimeCoord(value: number, timeDepth: number): Result<TimeCoord, CoordError> {
+  return checked('t', value, timeDepth)
+}
+
+/** `pathLength` is the length of the world line the turn indexes. */
+export function toTurn(value: number, pathLength: number): Result<Turn, CoordError> {
+  return checked('turn', value, pathLength)
+}
+
+/** Unchecked, for values that are a slice by construction (a position's `t`, a slice index). */
+export function asTimeCoord(value: number): TimeCoord {
+  return value as TimeCoord
+}
+
+/** Unchecked, for values that are a path index by construction (a loop over the path). */
+export function asTurn(value: number): Turn {
+  return value as Turn
+}
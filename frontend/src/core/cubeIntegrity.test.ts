@@ -0,0 +1,138 @@
+import { describe, expect, it } from 'vitest'
+
+import { bootstrapLevelObjects, defaultLevelObjectsConfig } from '../game/levelObjects'
+import { formatCubeIntegrityIssue, validateTimeCube } from './cubeIntegrity'
+import { propagatePatrolDetours } from './detour'
+import { createTimeCube, placeObjects, removeObjectFromTime, type TimeCube } from './timeCube'
+
+function defaultCube(): TimeCube {
+  const bootstrapped = bootstrapLevelObjects(12, 12, 6, defaultLevelObjectsConfig)
+
+  if (!bootstrapped.ok) {
+    throw new Error('default level failed to bootstrap')
+  }
+
+  return bootstrapped.value.cube
+}
+
+describe('validateTimeCube', () => {
+  it('accepts a freshly bootstrapped level with a projected patrol', () => {
+    expect(validateTimeCube(defaultCube())).toEqual({ ok: true, issues: [] })
+  })
+
+  it('reports a persistent object missing from a later slice and a stale kind index', () => {
+    const cube = defaultCube()
+    const slice = cube.slices[3]
+    const broken: TimeCube = {
+      ...cube,
+      slices: cube.slices.map((entry) =>
+        entry === slice
+          ? { ...slice, objectIds: slice.objectIds.filter((id) => id !== 'box.main') }
+          : entry,
+      ),
+    }
+
+    const report = validateTimeCube(broken)
+
+    expect(report.ok).toBe(false)
+    expect(report.issues.map(formatCubeIntegrityIssue)).toEqual([
+      'ObjectIdsMismatch: id=box.main, t=3',
+      'KindIndexMismatch: kind=box, t=3',
+      'MissingPersistentObject: id=box.main, t=3',
+    ])
+  })
+
+  it('reports patrollers that left their route unless patrol checks are off', () => {
+    const placed = placeObjects(createTimeCube(4, 4, 2), [
+      {
+        id: 'enemy.a',
+        archetypeKey: 'enemy',
+        position: { x: 0, y: 0, t: 0 },
+        archetype: {
+          kind: 'enemy',
+          components: [
+            { kind: 'TimePersistent' },
+            { kind: 'Patrol', path: [{ x: 0, y: 0 }, { x: 1, y: 0 }], loops: true },
+          ],
+          render: {},
+        },
+      },
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    expect(validateTimeCube(placed.value).issues).toEqual([
+      {
+        kind: 'PatrolDrift',
+        id: 'enemy.a',
+        t: 1,
+        expected: { x: 1, y: 0 },
+        actual: { x: 0, y: 0 },
+      },
+    ])
+    expect(validateTimeCube(placed.value, { checkPatrols: false }).ok).toBe(true)
+  })
+
+  it('accepts patrols detouring around a box and objects removed from a slice on', () => {
+    const placed = placeObjects(createTimeCube(4, 4, 4), [
+      {
+        id: 'enemy.a',
+        archetypeKey: 'enemy',
+        position: { x: 0, y: 0, t: 0 },
+        archetype: {
+          kind: 'enemy',
+          components: [
+            { kind: 'BlocksMovement' },
+            { kind: 'TimePersistent' },
+            {
+              kind: 'Patrol',
+              path: [{ x: 0, y: 0 }, { x: 1, y: 0 }, { x: 2, y: 0 }, { x: 1, y: 0 }],
+              loops: true,
+            },
+          ],
+          render: {},
+        },
+      },
+      {
+        id: 'box.a',
+        archetypeKey: 'box',
+        position: { x: 1, y: 0, t: 0 },
+        archetype: {
+          kind: 'box',
+          components: [{ kind: 'BlocksMovement' }, { kind: 'Pushable' }, { kind: 'TimePersistent' }],
+          render: {},
+        },
+      },
+    ])
+
+    if (!placed.ok) {
+      throw new Error('placement failed')
+    }
+
+    const detoured = propagatePatrolDetours(placed.value)
+
+    if (!detoured.ok) {
+      throw new Error('detour failed')
+    }
+
+    expect(validateTimeCube(detoured.value)).toEqual({ ok: true, issues: [] })
+
+    const removed = removeObjectFromTime(detoured.value, 'box.a', 2)
+
+    if (!removed.ok) {
+      throw new Error('removal failed')
+    }
+
+    const rerouted = propagatePatrolDetours(removed.value)
+
+    if (!rerouted.ok) {
+      throw new Error('detour failed')
+    }
+
+    expect(rerouted.value.objectsById['box.a'].removedFrom).toBe(2)
+    expect(validateTimeCube(rerouted.value)).toEqual({ ok: true, issues: [] })
+  })
+})
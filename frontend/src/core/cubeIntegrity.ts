@@ -0,0 +1,190 @@
+import { PLAYER_CHARACTER_ID } from './characters'
+import { hasComponent, type PatrolComponent } from './components'
+import { footprintCells } from './footprint'
+import { detouredPatrolTrail } from './detour'
+import { chaserOf } from './guards'
+import { patrolPositionAt } from './patrol'
+import type { ResolvedObjectInstance } from './objects'
+import type { Position2D } from './position'
+import { allObjects, objectPositionAt, type TimeCube } from './timeCube'
+
+export type CubeIntegrityIssue =
+  | { kind: 'UnknownIndexedId'; id: string; t: number }
+  | { kind: 'DuplicateInSlice'; id: string; t: number }
+  | { kind: 'ObjectIdsMismatch'; id: string; t: number }
+  | { kind: 'KindIndexMismatch'; archetypeKind: string; t: number }
+  | { kind: 'MissingPersistentObject'; id: string; t: number }
+  | { kind: 'PlayerInCube'; id: string }
+  | { kind: 'PatrolDrift'; id: string; t: number; expected: Position2D; actual: Position2D | null }
+
+export interface CubeIntegrityReport {
+  ok: boolean
+  issues: CubeIntegrityIssue[]
+}
+
+export interface CubeIntegrityOptions {
+  /**
+   * Compare patrollers with their route. Lures legitimately pull patrols off it, so callers turn
+   * this off while lures are on the board. Chasers are never compared.
+   */
+  checkPatrols?: boolean
+}
+
+function sameMembers(a: string[], b: string[]): boolean {
+  return a.length === b.length && [...a].sort().join('\n') === [...b].sort().join('\n')
+}
+
+function sliceIssues(cube: TimeCube, t: number): CubeIntegrityIssue[] {
+  const slice = cube.slices[t]
+  const issues: CubeIntegrityIssue[] = []
+  const cellCounts = new Map<string, number>()
+
+  for (const ids of Object.values(slice.spatialIndex)) {
+    for (const id of ids) {
+      const object = cube.objectsById[id]
+
+      if (!object) {
+        issues.push({ kind: 'UnknownIndexedId', id, t })
+      }
+
+      // A multi-tile object is indexed once per cell it covers.
+      const cells = object ? footprintCells(object.archetype.components, object.position).length : 1
+      const count = (cellCounts.get(id) ?? 0) + 1
+
+      if (count > cells) {
+        issues.push({ kind: 'DuplicateInSlice', id, t })
+      }
+
+      cellCounts.set(id, count)
+    }
+  }
+
+  const indexed = new Set(cellCounts.keys())
+
+  const listed = new Set(slice.objectIds)
+
+  for (const id of new Set([...indexed, ...listed])) {
+    if (indexed.has(id) !== listed.has(id)) {
+      issues.push({ kind: 'ObjectIdsMismatch', id, t })
+    }
+  }
+
+  const expectedByKind: Record<string, string[]> = {}
+
+  for (const id of slice.objectIds) {
+    const kind = cube.objectsById[id]?.archetype.kind
+
+    if (kind !== undefined) {
+      expectedByKind[kind] = [...(expectedByKind[kind] ?? []), id]
+    }
+  }
+
+  const kinds = new Set([...Object.keys(expectedByKind), ...Object.keys(slice.idsByKind)])
+
+  for (const kind of kinds) {
+    if (!sameMembers(expectedByKind[kind] ?? [], slice.idsByKind[kind] ?? [])) {
+      issues.push({ kind: 'KindIndexMismatch', archetypeKind: kind, t })
+    }
+  }
+
+  return issues
+}
+
+/**
+ * Slice a time-persistent object should stay in the cube until: the end, or the slice it was
+ * removed from. On time-loop levels a removal carries round the seam and empties every slice,
+ * except for unmade dependents, which only leave the slices downstream of their cause.
+ */
+function persistsUntil(cube: TimeCube, object: ResolvedObjectInstance): number {
+  if (object.removedFrom === undefined) {
+    return cube.timeDepth
+  }
+
+  return cube.timeWraps && object.unmadeBy === undefined ? 0 : object.removedFrom
+}
+
+/**
+ * Cross-slice invariants of a cube: every index agrees with the others, time-persistent objects
+ * stay from the slice they were last placed in until the end or their removal, the player is
+ * never a cube object, and patrollers stand where their route, lever switches and detours
+ * around boxes say.
+ */
+export function validateTimeCube(
+  cube: TimeCube,
+  options: CubeIntegrityOptions = {},
+): CubeIntegrityReport {
+  const issues: CubeIntegrityIssue[] = []
+
+  for (let t = 0; t < cube.timeDepth; t += 1) {
+    issues.push(...sliceIssues(cube, t))
+  }
+
+  for (const object of allObjects(cube)) {
+    const components = object.archetype.components
+
+    if (object.archetype.kind === PLAYER_CHARACTER_ID) {
+      issues.push({ kind: 'PlayerInCube', id: object.id })
+    }
+
+    if (!hasComponent(components, 'TimePersistent')) {
+      continue
+    }
+
+    const until = persistsUntil(cube, object)
+
+    for (let t = Math.max(0, object.position.t); t < until; t += 1) {
+      if (!cube.slices[t].objectIds.includes(object.id)) {
+        issues.push({ kind: 'MissingPersistentObject', id: object.id, t })
+      }
+    }
+
+    const patrol = components.find(
+      (component): component is PatrolComponent => component.kind === 'Patrol',
+    )
+
+    if (options.checkPatrols === false || !patrol || chaserOf(components)) {
+      continue
+    }
+
+    const trail = detouredPatrolTrail(cube, object.id)
+
+    for (let t = 0; t < until; t += 1) {
+      const expected = trail ? (trail[t] ?? null) : patrolPositionAt(patrol, t)
+      const actual = objectPositionAt(cube, object.id, t)
+
+      if (expected && (!actual || actual.x !== expected.x || actual.y !== expected.y)) {
+        issues.push({
+          kind: 'PatrolDrift',
+          id: object.id,
+          t,
+          expected,
+          actual: actual ? { x: actual.x, y: actual.y } : null,
+        })
+      }
+    }
+  }
+
+  return { ok: issues.length === 0, issues }
+}
+
+export function formatCubeIntegrityIssue(issue: CubeIntegrityIssue): string {
+  switch (issue.kind) {
+    case 'UnknownIndexedId':
+    case 'DuplicateInSlice':
+    case 'ObjectIdsMismatch':
+    case 'MissingPersistentObject':
+      return `${issue.kind}: id=${issue.id}, t=${issue.t}`
+    case 'KindIndexMismatch':
+      return `${issue.kind}: kind=${issue.archetypeKind}, t=${issue.t}`
+    case 'PlayerInCube':
+      return `${issue.kind}: id=${issue.id}`
+    case 'PatrolDrift': {
+      const actual = issue.actual ? `(${issue.actual.x},${issue.actual.y})` : 'absent'
+
+      return (
+        `${issue.kind}: id=${issue.id}, t=${issue.t}, ` +
+        `expected=(${issue.expected.x},${issue.expected.y}), actual=${actual}`
+      )
+    }
+  }
+}
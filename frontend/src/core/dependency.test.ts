@@ -0,0 +1,155 @@
+import { describe, expect, it } from 'vitest'
+
+import type { Component } from './components'
+import { dependencyOrder, propagateDependencies } from './dependency'
+import type { ResolvedObjectInstance } from './objects'
+import {
+  createTimeCube,
+  isBlocked,
+  placeObjects,
+  removeObjectFromTime,
+  restoreObjectFromTime,
+} from './timeCube'
+
+function wall(id: string, x: number, extra: Component[] = []): ResolvedObjectInstance {
+  return {
+    id,
+    archetypeKey: id,
+    position: { x, y: 1, t: 0 },
+    archetype: {
+      kind: 'wall',
+      components: [{ kind: 'BlocksMovement' }, { kind: 'TimePersistent' }, ...extra],
+      render: {},
+    },
+  }
+}
+
+function chain(): ResolvedObjectInstance[] {
+  return [
+    wall('lamp', 3, [{ kind: 'DependsOn', source: 'gate', at: 2 }]),
+    wall('gate', 2, [{ kind: 'DependsOn', source: 'generator', at: 0 }]),
+    wall('generator', 1, [{ kind: 'Breakable', hits: 1 }]),
+  ]
+}
+
+describe('dependency propagation', () => {
+  it('orders dependents after their causes', () => {
+    const placed = placeObjects(createTimeCube(5, 3, 5), chain())
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    expect(dependencyOrder(placed.value).map((object) => object.id)).toEqual(['gate', 'lamp'])
+  })
+
+  it('keeps dependents while their cause is still there', () => {
+    const placed = placeObjects(createTimeCube(5, 3, 5), chain())
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const removed = removeObjectFromTime(placed.value, 'generator', 1)
+
+    expect(removed.ok).toBe(true)
+    if (!removed.ok) {
+      return
+    }
+
+    const propagated = propagateDependencies(removed.value)
+
+    expect(propagated.ok).toBe(true)
+    if (propagated.ok) {
+      expect(propagated.value.unmade).toEqual([])
+      expect(isBlocked(propagated.value.cube, { x: 2, y: 1, t: 4 })).toBe(true)
+    }
+  })
+
+  it('unmakes the whole chain once the cause is removed in the past', () => {
+    const placed = placeObjects(createTimeCube(5, 3, 5), chain())
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const removed = removeObjectFromTime(placed.value, 'generator', 0)
+
+    expect(removed.ok).toBe(true)
+    if (!removed.ok) {
+      return
+    }
+
+    const propagated = propagateDependencies(removed.value)
+
+    expect(propagated.ok).toBe(true)
+    if (!propagated.ok) {
+      return
+    }
+
+    expect(propagated.value.unmade).toEqual([
+      { id: 'gate', source: 'generator', from: 1 },
+      { id: 'lamp', source: 'gate', from: 3 },
+    ])
+    expect(isBlocked(propagated.value.cube, { x: 2, y: 1, t: 0 })).toBe(true)
+    expect(isBlocked(propagated.value.cube, { x: 2, y: 1, t: 1 })).toBe(false)
+    expect(isBlocked(propagated.value.cube, { x: 3, y: 1, t: 2 })).toBe(true)
+    expect(isBlocked(propagated.value.cube, { x: 3, y: 1, t: 3 })).toBe(false)
+
+    const again = propagateDependencies(propagated.value.cube)
+
+    expect(again.ok && again.value.unmade).toEqual([])
+  })
+
+  it('brings the chain back once its cause is back', () => {
+    const placed = placeObjects(createTimeCube(5, 3, 5), chain())
+
+    if (!placed.ok) {
+      throw new Error('placement failed')
+    }
+
+    const removed = removeObjectFromTime(placed.value, 'generator', 0)
+    const unmade = removed.ok ? propagateDependencies(removed.value) : null
+
+    if (!unmade?.ok) {
+      throw new Error('propagation failed')
+    }
+
+    const back = restoreObjectFromTime(unmade.value.cube, 'generator', 0)
+    const restored = back.ok ? propagateDependencies(back.value) : null
+
+    if (!restored?.ok) {
+      throw new Error('propagation failed')
+    }
+
+    expect(restored.value.unmade).toEqual([])
+    expect(restored.value.restored).toEqual([
+      { id: 'gate', source: 'generator', from: 1 },
+      { id: 'lamp', source: 'gate', from: 3 },
+    ])
+    expect(isBlocked(restored.value.cube, { x: 2, y: 1, t: 4 })).toBe(true)
+    expect(isBlocked(restored.value.cube, { x: 3, y: 1, t: 4 })).toBe(true)
+    expect(restored.value.cube.objectsById.gate.unmadeBy).toBeUndefined()
+  })
+
+  it('only unmakes the slices downstream of the cause on a time loop', () => {
+    const placed = placeObjects({ ...createTimeCube(5, 3, 5), timeWraps: true }, chain())
+
+    if (!placed.ok) {
+      throw new Error('placement failed')
+    }
+
+    const removed = removeObjectFromTime(placed.value, 'generator', 0)
+    const propagated = removed.ok ? propagateDependencies(removed.value) : null
+
+    if (!propagated?.ok) {
+      throw new Error('propagation failed')
+    }
+
+    expect(isBlocked(propagated.value.cube, { x: 3, y: 1, t: 2 })).toBe(true)
+    expect(isBlocked(propagated.value.cube, { x: 3, y: 1, t: 3 })).toBe(false)
+  })
+})
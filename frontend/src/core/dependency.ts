@@ -0,0 +1,164 @@
+import type { DependsOnComponent } from './components'
+import type { ResolvedObjectInstance } from './objects'
+import type { Result } from './result'
+import {
+  allObjects,
+  downstreamTimes,
+  objectPositionAt,
+  removeObjectFromTime,
+  restoreObjectFromTime,
+  type CubeError,
+  type RelocationError,
+  type TimeCube,
+} from './timeCube'
+
+/** A dependent that lost its cause this pass, or got it back; gone (or back) from `from` on. */
+export interface UnmadeObject {
+  id: string
+  source: string
+  from: number
+}
+
+export interface DependencyPropagation {
+  cube: TimeCube
+  unmade: UnmadeObject[]
+  restored: UnmadeObject[]
+}
+
+export function dependencyOf(object: ResolvedObjectInstance): DependsOnComponent | null {
+  for (const component of object.archetype.components) {
+    if (component.kind === 'DependsOn') {
+      return component
+    }
+  }
+
+  return null
+}
+
+/**
+ * Dependents ordered causes first, so a chain (generator, gate powered by it, lamp wired to the
+ * gate) resolves in a single pass. Objects on a cycle are left out; validation rejects cycles.
+ */
+export function dependencyOrder(cube: TimeCube): ResolvedObjectInstance[] {
+  const ordered: ResolvedObjectInstance[] = []
+  const done = new Set<string>()
+  const visiting = new Set<string>()
+
+  const visit = (object: ResolvedObjectInstance): boolean => {
+    if (done.has(object.id)) {
+      return true
+    }
+
+    if (visiting.has(object.id)) {
+      return false
+    }
+
+    const dependency = dependencyOf(object)
+
+    if (!dependency) {
+      done.add(object.id)
+      return true
+    }
+
+    visiting.add(object.id)
+    const source = cube.objectsById[dependency.source]
+    const acyclic = source ? visit(source) : true
+    visiting.delete(object.id)
+
+    if (!acyclic) {
+      return false
+    }
+
+    done.add(object.id)
+    ordered.push(object)
+    return true
+  }
+
+  for (const object of allObjects(cube)) {
+    visit(object)
+  }
+
+  return ordered
+}
+
+function presentFrom(cube: TimeCube, id: string, times: number[]): boolean {
+  return times.some((t) => objectPositionAt(cube, id, t) !== null)
+}
+
+/** Marks `id` as unmade by `source`, or clears the mark when `source` is undefined. */
+function markUnmade(cube: TimeCube, id: string, source: string | undefined): TimeCube {
+  const object = { ...cube.objectsById[id], unmadeBy: source }
+
+  if (source === undefined) {
+    delete object.unmadeBy
+  }
+
+  return { ...cube, objectsById: { ...cube.objectsById, [id]: object } }
+}
+
+/**
+ * Recomputes every dependent from its placement each turn. One whose source is missing from its
+ * `at` slice is taken out of the slices downstream of it, never round the seam of a time loop;
+ * one unmade earlier whose source is back returns, unless something now blocks its cell. Causes
+ * come first, so their own dependents follow in the same pass. Dependents already gone or
+ * already back are not reported again.
+ */
+export function propagateDependencies(
+  cube: TimeCube,
+): Result<DependencyPropagation, CubeError | RelocationError> {
+  let next = cube
+  const unmade: UnmadeObject[] = []
+  const restored: UnmadeObject[] = []
+
+  for (const object of dependencyOrder(cube)) {
+    const dependency = dependencyOf(object)
+
+    if (!dependency) {
+      continue
+    }
+
+    const from = dependency.at + 1
+
+    if (from >= next.timeDepth) {
+      continue
+    }
+
+    const times = downstreamTimes(next, from)
+    const sourcePresent = objectPositionAt(next, dependency.source, dependency.at) !== null
+
+    if (sourcePresent) {
+      if (next.objectsById[object.id]?.unmadeBy === undefined) {
+        continue
+      }
+
+      const back = restoreObjectFromTime(next, object.id, from, times)
+
+      if (!back.ok) {
+        if (back.error.kind === 'TargetOccupied') {
+          continue
+        }
+
+        return back
+      }
+
+      next = markUnmade(back.value, object.id, undefined)
+      restored.push({ id: object.id, source: dependency.source, from })
+      continue
+    }
+
+    if (!presentFrom(next, object.id, times)) {
+      continue
+    }
+
+    const removed = removeObjectFromTime(next, object.id, from, times)
+
+    if (!removed.ok) {
+      return removed
+    }
+
+    next = markUnmade(removed.value, object.id, dependency.source)
+    unmade.push({ id: object.id, source: dependency.source, from })
+  }
+
+  return { ok: true, value: { cube: next, unmade, restored } }
+}
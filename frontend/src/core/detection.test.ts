@@ -1,8 +1,13 @@
 import { describe, expect, it } from 'vitest'
 
 import { createWorldLine, extendNormal } from './worldLine'
-import { createTimeCube, placeObjects } from './timeCube'
-import { evaluateDetectionV1, hasLineOfSight, traceLineCells } from './detection'
+import { createTimeCube, moveObjectThroughTime, placeObjects } from './timeCube'
+import {
+  evaluateDetectionV1,
+  hasLineOfSight,
+  movedObjectNoticesAt,
+  traceLineCells,
+} from './detection'
 import type { ResolvedObjectInstance } from './objects'
 
 function enemyObject(id: string, x: number, y: number): ResolvedObjectInstance {
@@ -22,6 +27,44 @@ function enemyObject(id: string, x: number, y: number): ResolvedObjectInstance {
   }
 }
 
+function sentryObject(id: string, x: number, y: number): ResolvedObjectInstance {
+  return {
+    id,
+    archetypeKey: 'sentry',
+    position: { x, y, t: 0 },
+    archetype: {
+      kind: 'enemy',
+      components: [
+        { kind: 'BlocksMovement' },
+        { kind: 'TimePersistent' },
+        { kind: 'Guard', guard: 'Sentry', facings: ['north', 'south'] },
+      ],
+      render: {},
+    },
+  }
+}
+
+function cameraObject(id: string, x: number, y: number): ResolvedObjectInstance {
+  return {
+    id,
+    archetypeKey: 'camera',
+    position: { x, y, t: 0 },
+    archetype: {
+      kind: 'camera',
+      components: [
+        { kind: 'TimePersistent' },
+        {
+          kind: 'Camera',
+          facings: ['east', 'south'],
+          turnEvery: 3,
+          vision: { delayTurns: 1, maxDistance: 5 },
+        },
+      ],
+      render: {},
+    },
+  }
+}
+
 function visionBlockerObject(id: string, x: number, y: number): ResolvedObjectInstance {
   return {
     id,
@@ -38,6 +81,41 @@ function visionBlockerObject(id: string, x: number, y: number): ResolvedObjectIn
   }
 }
 
+function crateObject(id: string, x: number, y: number, height: number): ResolvedObjectInstance {
+  return {
+    id,
+    archetypeKey: 'crate',
+    position: { x, y, t: 0 },
+    archetype: {
+      kind: 'box',
+      components: [
+        { kind: 'BlocksMovement' },
+        { kind: 'TimePersistent' },
+        { kind: 'Height', height },
+      ],
+      render: {},
+    },
+  }
+}
+
+function lampObject(
+  id: string,
+  x: number,
+  y: number,
+  activeUntil?: number,
+): ResolvedObjectInstance {
+  return {
+    id,
+    archetypeKey: 'lamp',
+    position: { x, y, t: 0 },
+    archetype: {
+      kind: 'lamp',
+      components: [{ kind: 'TimePersistent' }, { kind: 'LightSource', radius: 1, activeUntil }],
+      render: {},
+    },
+  }
+}
+
 describe('traceLineCells', () => {
   it('returns deterministic horizontal, vertical, and diagonal traces', () => {
     expect(traceLineCells({ x: 1, y: 1 }, { x: 4, y: 1 })).toEqual([
@@ -194,6 +272,165 @@ describe('evaluateDetectionV1', () => {
     expect(report.events[0]?.enemyId).toBe('enemy.beta')
   })
 
+  it('limits sentries to the cone they face in the current slice', () => {
+    const placed = placeObjects(createTimeCube(8, 8, 6), [sentryObject('enemy.sentry', 3, 3)])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const worldLine = createWorldLine({ x: 3, y: 5, t: 0 })
+    const config = { enabled: true, delayTurns: 1, maxDistance: 3 }
+    const facingSouth = evaluateDetectionV1({ cube: placed.value, worldLine, currentTime: 1, config })
+    const facingNorth = evaluateDetectionV1({
+      cube: placed.value,
+      worldLine: createWorldLine({ x: 3, y: 5, t: 1 }),
+      currentTime: 2,
+      config,
+    })
+
+    expect(facingSouth.detected).toBe(true)
+    expect(facingNorth.detected).toBe(false)
+  })
+
+  it('turns cameras on their schedule and uses their own vision range', () => {
+    const placed = placeObjects(createTimeCube(8, 8, 8), [cameraObject('camera.hall', 1, 1)])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const config = { enabled: true, delayTurns: 1, maxDistance: 1 }
+    const seenAt = (player: { x: number; y: number }, time: number) =>
+      evaluateDetectionV1({
+        cube: placed.value,
+        worldLine: createWorldLine({ ...player, t: time - 1 }),
+        currentTime: time,
+        config,
+      }).detected
+
+    expect(seenAt({ x: 5, y: 1 }, 2)).toBe(true)
+    expect(seenAt({ x: 5, y: 1 }, 3)).toBe(false)
+    expect(seenAt({ x: 1, y: 5 }, 3)).toBe(true)
+    expect(seenAt({ x: 1, y: 5 }, 6)).toBe(false)
+  })
+
+  it('detects players standing in a searchlight beam at the observed slice', () => {
+    const placed = placeObjects(createTimeCube(8, 8, 6), [
+      {
+        id: 'light.yard',
+        archetypeKey: 'light',
+        position: { x: 0, y: 0, t: 0 },
+        archetype: {
+          kind: 'searchlight',
+          components: [
+            { kind: 'TimePersistent' },
+            {
+              kind: 'Searchlight',
+              shape: 'circle',
+              radius: 0,
+              path: [
+                { x: 3, y: 3 },
+                { x: 4, y: 3 },
+              ],
+              loops: true,
+            },
+          ],
+          render: {},
+        },
+      },
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const config = { enabled: true, delayTurns: 1, maxDistance: 0 }
+    const lit = evaluateDetectionV1({
+      cube: placed.value,
+      worldLine: createWorldLine({ x: 4, y: 3, t: 1 }),
+      currentTime: 2,
+      config,
+    })
+    const dark = evaluateDetectionV1({
+      cube: placed.value,
+      worldLine: createWorldLine({ x: 4, y: 3, t: 2 }),
+      currentTime: 3,
+      config,
+    })
+
+    expect(lit.events).toEqual([
+      {
+        enemyId: 'light.yard',
+        enemyPosition: { x: 4, y: 3, t: 1 },
+        observedPlayer: { x: 4, y: 3, t: 1 },
+        observedTurn: 0,
+      },
+    ])
+    expect(dark.detected).toBe(false)
+  })
+
+  it('shortens vision into unlit cells in dark levels', () => {
+    const placed = placeObjects(createTimeCube(8, 8, 6), [
+      enemyObject('enemy.alpha', 0, 0),
+      lampObject('lamp.1', 4, 0, 2),
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const config = { enabled: true, delayTurns: 1, maxDistance: 4, darkVisionFactor: 0.5 }
+    const seenAt = (currentTime: number) =>
+      evaluateDetectionV1({
+        cube: placed.value,
+        worldLine: createWorldLine({ x: 3, y: 0, t: currentTime - 1 }),
+        currentTime,
+        config,
+      }).detected
+
+    // (3,0) is lit while the lamp burns (t < 2) and dark afterwards, where range drops to 2.
+    expect(seenAt(2)).toBe(true)
+    expect(seenAt(3)).toBe(false)
+  })
+
+  it('never sees a player standing on a shadow tile', () => {
+    const placed = placeObjects(createTimeCube(8, 8, 6), [
+      enemyObject('enemy.alpha', 2, 2),
+      {
+        id: 'shadow.1',
+        archetypeKey: 'shadow',
+        position: { x: 2, y: 3, t: 0 },
+        archetype: {
+          kind: 'floor',
+          components: [{ kind: 'Shadow' }, { kind: 'TimePersistent' }],
+          render: {},
+        },
+      },
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const config = { enabled: true, delayTurns: 1, maxDistance: 4 }
+    const seenFrom = (x: number, y: number) =>
+      evaluateDetectionV1({
+        cube: placed.value,
+        worldLine: createWorldLine({ x, y, t: 0 }),
+        currentTime: 1,
+        config,
+      }).detected
+
+    expect(seenFrom(2, 3)).toBe(false)
+    expect(seenFrom(2, 4)).toBe(true)
+  })
+
   it('blocks detection when line of sight is occluded by BlocksVision', () => {
     const cube = createTimeCube(8, 8, 6)
     const placed = placeObjects(cube, [
@@ -218,6 +455,22 @@ describe('evaluateDetectionV1', () => {
     expect(report.events).toHaveLength(0)
   })
 
+  it('lets guards see over cover lower than eye height', () => {
+    const cube = createTimeCube(8, 8, 6)
+    const low = placeObjects(cube, [crateObject('crate.low', 2, 3, 1)])
+    const tall = placeObjects(cube, [crateObject('crate.tall', 2, 3, 2)])
+
+    expect(low.ok && tall.ok).toBe(true)
+    if (!low.ok || !tall.ok) {
+      return
+    }
+
+    const sight = { from: { x: 2, y: 2 }, to: { x: 2, y: 4 }, atTime: 0 }
+
+    expect(hasLineOfSight({ cube: low.value, ...sight })).toBe(true)
+    expect(hasLineOfSight({ cube: tall.value, ...sight })).toBe(false)
+  })
+
   it('supports diagonal LOS and blocks when a diagonal blocker exists on trace', () => {
     const cube = createTimeCube(8, 8, 6)
     const placedOpen = placeObjects(cube, [enemyObject('enemy.alpha', 1, 1)])
@@ -251,4 +504,100 @@ describe('evaluateDetectionV1', () => {
     })
     expect(blockedLos).toBe(false)
   })
+
+  it('lets precog sentinels see where the world line will be', () => {
+    const placed = placeObjects(createTimeCube(8, 8, 6), [enemyObject('enemy.alpha', 2, 2)])
+
+    if (!placed.ok) {
+      throw new Error('placement failed')
+    }
+
+    const input = { cube: placed.value, worldLine: createWorldLine({ x: 2, y: 3, t: 1 }) }
+    const config = { enabled: true, delayTurns: 1, maxDistance: 2 }
+    const precog = { ...config, precog: true }
+
+    expect(evaluateDetectionV1({ ...input, currentTime: 0, config }).detected).toBe(false)
+    expect(evaluateDetectionV1({ ...input, currentTime: 0, config: precog }).events).toEqual([
+      {
+        enemyId: 'enemy.alpha',
+        enemyPosition: { x: 2, y: 2, t: 0 },
+        observedPlayer: { x: 2, y: 3, t: 1 },
+        observedTurn: 0,
+      },
+    ])
+    expect(evaluateDetectionV1({ ...input, currentTime: 1, config: precog }).detected).toBe(false)
+    expect(
+      evaluateDetectionV1({
+        ...input,
+        currentTime: 0,
+        config,
+        configByEnemyId: { 'enemy.alpha': precog },
+      }).detected,
+    ).toBe(true)
+  })
+
+  it('sees the short way round a torus map', () => {
+    const flat = createTimeCube(8, 8, 6)
+    const torus = { ...flat, xWraps: true }
+    const enemies = [enemyObject('enemy.alpha', 7, 2)]
+    const worldLine = createWorldLine({ x: 0, y: 2, t: 0 })
+    const config = { enabled: true, delayTurns: 1, maxDistance: 2 }
+    const onFlat = placeObjects(flat, enemies)
+    const onTorus = placeObjects(torus, enemies)
+    const screened = placeObjects(torus, [visionBlockerObject('screen.seam', 0, 2)])
+
+    expect(onFlat.ok && onTorus.ok && screened.ok).toBe(true)
+    if (!onFlat.ok || !onTorus.ok || !screened.ok) {
+      return
+    }
+
+    expect(
+      evaluateDetectionV1({ cube: onFlat.value, worldLine, currentTime: 1, config }).detected,
+    ).toBe(false)
+    expect(
+      evaluateDetectionV1({ cube: onTorus.value, worldLine, currentTime: 1, config }).detected,
+    ).toBe(true)
+
+    const sight = { from: { x: 6, y: 2 }, to: { x: 1, y: 2 }, atTime: 0 }
+
+    expect(hasLineOfSight({ cube: onTorus.value, ...sight })).toBe(true)
+    expect(hasLineOfSight({ cube: screened.value, ...sight })).toBe(false)
+  })
+})
+
+describe('movedObjectNoticesAt', () => {
+  it('notices a box that is not where the delayed view left it, only with object memory', () => {
+    const box: ResolvedObjectInstance = {
+      id: 'box.1',
+      archetypeKey: 'box',
+      position: { x: 2, y: 0, t: 0 },
+      archetype: {
+        kind: 'box',
+        components: [{ kind: 'BlocksMovement' }, { kind: 'Pushable' }, { kind: 'TimePersistent' }],
+        render: {},
+      },
+    }
+    const placed = placeObjects(createTimeCube(5, 5, 4), [enemyObject('enemy.alpha', 0, 0), box])
+
+    if (!placed.ok) {
+      throw new Error('placement failed')
+    }
+
+    const moved = moveObjectThroughTime(placed.value, 'box.1', 2, { x: 2, y: 1 })
+
+    if (!moved.ok) {
+      throw new Error('move failed')
+    }
+
+    const config = { enabled: true, delayTurns: 1, maxDistance: 4 }
+    const remembering = { ...config, objectMemory: true }
+
+    expect(movedObjectNoticesAt({ cube: moved.value, currentTime: 2, config })).toEqual([])
+    expect(
+      movedObjectNoticesAt({ cube: moved.value, currentTime: 2, config: remembering }),
+    ).toEqual([{ enemyId: 'enemy.alpha', cell: { x: 2, y: 0, t: 2 } }])
+    expect(
+      movedObjectNoticesAt({ cube: moved.value, currentTime: 3, config: remembering }),
+    ).toEqual([])
+  })
 })
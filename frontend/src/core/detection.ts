@@ -1,13 +1,44 @@
-import { hasComponent } from './components'
+import { lockdownRangeBonusAt } from './alarm'
+import { blocksVision, hasComponent } from './components'
+import { cameraOf, enemiesAtTime, isInFacingCone, watchFacingAt } from './guards'
 import type { ResolvedObjectInstance } from './objects'
-import { manhattanDistance, type Position2D, type Position3D } from './position'
-import { objectsAt, objectsAtTime, type TimeCube } from './timeCube'
+import { isLitAt, isShadowedAt } from './lighting'
+import { isVisibleFromGround, layerAtTurn } from './elevation'
+import { isLitBy, searchlightCenterAt, searchlightOf } from './searchlight'
+import { isStackAt } from './stacking'
+import {
+  nearestWrappedCopy,
+  wrappedDistance,
+  wrapPosition,
+  type Position2D,
+  type Position3D,
+} from './position'
+import {
+  objectsAt,
+  objectsAtTime,
+  timeAfter,
+  timeBefore,
+  wrappingOf,
+  type TimeCube,
+} from './timeCube'
 import { positionsAtTime, type WorldLineState } from './worldLine'
 
 export interface DetectionConfig {
   enabled: boolean
   delayTurns: number
   maxDistance: number
+  /** Set in dark levels: range into cells no lamp lights is `maxDistance * darkVisionFactor`. */
+  darkVisionFactor?: number
+  /** Hard mode: the guard grows suspicious when a box it saw has moved since its delayed view. */
+  objectMemory?: boolean
+  /** Precog sentinel: sees where the player is `delayTurns` slices after its own, not before. */
+  precog?: boolean
+}
+
+/** A cell whose boxes differ between `enemyId`'s delayed view and what it sees now. */
+export interface MovedObjectNotice {
+  enemyId: string
+  cell: Position3D
 }
 
 export interface DetectionEvent {
@@ -27,11 +58,45 @@ function isValidConfig(config: DetectionConfig): boolean {
   return config.delayTurns >= 1 && config.maxDistance >= 0
 }
 
-function isDetectorObject(object: ResolvedObjectInstance): boolean {
-  return (
-    object.archetype.kind === 'enemy' ||
-    hasComponent(object.archetype.components, 'Patrol')
-  )
+/** Sentries and cameras only see into the cone they face at `time`; others see all around. */
+function isFacingTarget(detector: ResolvedObjectInstance, target: Position2D, time: number): boolean {
+  const facing = watchFacingAt(detector.archetype.components, time)
+
+  if (facing === undefined) {
+    return true
+  }
+
+  return facing !== null && isInFacingCone(detector.position, facing, target)
+}
+
+/** Per-instance overrides win, then the camera's own vision, then the rules-wide config. */
+function detectorConfigFor(
+  detector: ResolvedObjectInstance,
+  config: DetectionConfig,
+  configByEnemyId: Record<string, DetectionConfig> | undefined,
+): DetectionConfig {
+  const override = configByEnemyId?.[detector.id]
+
+  if (override) {
+    return override
+  }
+
+  const vision = cameraOf(detector.archetype.components)?.vision
+  return vision ? { ...config, ...vision } : config
+}
+
+/** Vision range toward `target`; unlit cells shrink it when the level is dark. */
+function visionRangeAt(
+  cube: TimeCube,
+  config: DetectionConfig,
+  target: Position2D,
+  time: number,
+): number {
+  if (config.darkVisionFactor === undefined || isLitAt(cube, target, time)) {
+    return config.maxDistance
+  }
+
+  return config.maxDistance * config.darkVisionFactor
 }
 
 function isVisionOccludedAt(
@@ -39,8 +104,11 @@ function isVisionOccludedAt(
   cell: Position2D,
   time: number,
 ): boolean {
-  return objectsAt(cube, { x: cell.x, y: cell.y, t: time }).some((object) =>
-    hasComponent(object.archetype.components, 'BlocksVision'),
+  const position = { x: cell.x, y: cell.y, t: time }
+
+  return (
+    objectsAt(cube, position).some((object) => blocksVision(object.archetype.components)) ||
+    isStackAt(cube, position)
   )
 }
 
@@ -110,7 +178,10 @@ export function hasLineOfSight(input: {
   to: Position2D
   atTime: number
 }): boolean {
-  const cells = traceLineCells(input.from, input.to)
+  // On torus maps the sight line takes the short way round and folds back onto the map.
+  const wrapping = wrappingOf(input.cube)
+  const to = nearestWrappedCopy(input.from, input.to, wrapping)
+  const cells = traceLineCells(input.from, to).map((cell) => wrapPosition(cell, wrapping))
 
   // Endpoints are actor cells (detector/player target) and should not self-occlude.
   for (let index = 1; index < cells.length - 1; index += 1) {
@@ -122,34 +193,114 @@ export function hasLineOfSight(input: {
   return true
 }
 
+/**
+ * Searchlights catch the player standing in a lit cell. The watcher learns of it `delayTurns`
+ * later, so the beam position is taken at the observed (earlier) slice. Cones are occluded by
+ * vision blockers between the mount and the player; circles are not.
+ */
+function evaluateSearchlights(input: {
+  cube: TimeCube
+  worldLine: WorldLineState
+  currentTime: number
+  config: DetectionConfig
+  configByEnemyId?: Record<string, DetectionConfig>
+}): DetectionEvent[] {
+  const events: DetectionEvent[] = []
+
+  for (const object of objectsAtTime(input.cube, input.currentTime)) {
+    const light = searchlightOf(object.archetype.components)
+    const lightConfig = input.configByEnemyId?.[object.id] ?? input.config
+
+    if (!light || !lightConfig.enabled || !isValidConfig(lightConfig)) {
+      continue
+    }
+
+    const observedTime = timeBefore(input.cube, input.currentTime, lightConfig.delayTurns)
+
+    if (observedTime === null) {
+      continue
+    }
+
+    const center = searchlightCenterAt(light, object.position, observedTime)
+
+    for (const observedPlayer of positionsAtTime(input.worldLine, observedTime)) {
+      if (
+        isShadowedAt(input.cube, observedPlayer.position) ||
+        !isLitBy(light, object.position, observedPlayer.position, observedTime)
+      ) {
+        continue
+      }
+
+      const layer = layerAtTurn(input.worldLine, observedPlayer.turn)
+
+      if (!isVisibleFromGround(input.cube, observedPlayer.position, layer)) {
+        continue
+      }
+
+      if (
+        light.shape === 'cone' &&
+        !hasLineOfSight({
+          cube: input.cube,
+          from: { x: object.position.x, y: object.position.y },
+          to: { x: observedPlayer.position.x, y: observedPlayer.position.y },
+          atTime: observedTime,
+        })
+      ) {
+        continue
+      }
+
+      events.push({
+        enemyId: object.id,
+        enemyPosition: { x: center.x, y: center.y, t: observedTime },
+        observedPlayer: observedPlayer.position,
+        observedTurn: observedPlayer.turn,
+      })
+    }
+  }
+
+  return events
+}
+
+/**
+ * Guards, cameras and searchlights that see the player at `currentTime`. Guards see `rangeBonus`
+ * cells further than their config, on top of any lockdown bonus in that slice.
+ */
 export function evaluateDetectionV1(input: {
   cube: TimeCube
   worldLine: WorldLineState
   currentTime: number
   config: DetectionConfig
   configByEnemyId?: Record<string, DetectionConfig>
+  rangeBonus?: number
 }): DetectionReport {
   const { cube, worldLine, currentTime, config, configByEnemyId } = input
+  const wrapping = wrappingOf(cube)
+  const rangeBonus = (input.rangeBonus ?? 0) + lockdownRangeBonusAt(cube, currentTime)
 
-  const detectors = objectsAtTime(cube, currentTime).filter(isDetectorObject)
+  const detectors = enemiesAtTime(cube, currentTime)
+  const events: DetectionEvent[] = evaluateSearchlights(input)
 
   if (detectors.length === 0) {
-    return { detected: false, atTime: currentTime, events: [] }
+    return { detected: events.length > 0, atTime: currentTime, events }
   }
 
   const observedPlayersByTime: Record<number, ReturnType<typeof positionsAtTime>> = {}
-  const events: DetectionEvent[] = []
 
   for (const detector of detectors) {
-    const detectorConfig = configByEnemyId?.[detector.id] ?? config
+    const detectorConfig = detectorConfigFor(detector, config, configByEnemyId)
 
     if (!detectorConfig.enabled || !isValidConfig(detectorConfig)) {
       continue
     }
 
-    const observedTime = currentTime - detectorConfig.delayTurns
+    // On time-loop levels the delay reaches back across the seam into the last slices. Precog
+    // sentinels look the other way, at where the player's world line has already been or will
+    // be: only revisiting a slice puts anyone there.
+    const observedTime = detectorConfig.precog
+      ? timeAfter(cube, currentTime, detectorConfig.delayTurns)
+      : timeBefore(cube, currentTime, detectorConfig.delayTurns)
 
-    if (observedTime < 0) {
+    if (observedTime === null) {
       continue
     }
 
@@ -164,7 +315,29 @@ export function evaluateDetectionV1(input: {
     }
 
     for (const observedPlayer of observedPlayers) {
-      if (manhattanDistance(detector.position, observedPlayer.position) > detectorConfig.maxDistance) {
+      // Shadow tiles hide the player whatever cone or range reaches them.
+      if (isShadowedAt(cube, observedPlayer.position)) {
+        continue
+      }
+
+      const layer = layerAtTurn(worldLine, observedPlayer.turn)
+
+      // Guards stand on the ground; a closed catwalk railing hides whoever walks behind it.
+      if (!isVisibleFromGround(cube, observedPlayer.position, layer)) {
+        continue
+      }
+
+      const range =
+        visionRangeAt(cube, detectorConfig, observedPlayer.position, observedTime) + rangeBonus
+
+      if (wrappedDistance(detector.position, observedPlayer.position, wrapping) > range) {
+        continue
+      }
+
+      // Judge the facing cone against the nearest copy of the player on torus maps.
+      const nearest = nearestWrappedCopy(detector.position, observedPlayer.position, wrapping)
+
+      if (!isFacingTarget(detector, nearest, currentTime)) {
         continue
       }
 
@@ -194,3 +367,111 @@ export function evaluateDetectionV1(input: {
     events,
   }
 }
+
+/** Range, cone and line of sight from `detector` to `cell` in the slice it stands in. */
+function seesCell(
+  cube: TimeCube,
+  detector: ResolvedObjectInstance,
+  config: DetectionConfig,
+  cell: Position3D,
+): boolean {
+  const wrapping = wrappingOf(cube)
+  const range = visionRangeAt(cube, config, cell, cell.t) + lockdownRangeBonusAt(cube, cell.t)
+
+  return (
+    wrappedDistance(detector.position, cell, wrapping) <= range &&
+    isFacingTarget(detector, nearestWrappedCopy(detector.position, cell, wrapping), cell.t) &&
+    hasLineOfSight({ cube, from: detector.position, to: cell, atTime: cell.t })
+  )
+}
+
+/**
+ * First guard or camera that sees `cell` in its own slice, with the same range, cone and line of
+ * sight rules as for the player but no delay: what lies there is still there when it looks.
+ */
+export function enemySeeingCell(input: {
+  cube: TimeCube
+  cell: Position3D
+  config: DetectionConfig
+  configByEnemyId?: Record<string, DetectionConfig>
+}): string | null {
+  const { cube, cell, config, configByEnemyId } = input
+
+  for (const detector of enemiesAtTime(cube, cell.t)) {
+    const detectorConfig = detectorConfigFor(detector, config, configByEnemyId)
+
+    if (
+      detectorConfig.enabled &&
+      isValidConfig(detectorConfig) &&
+      seesCell(cube, detector, detectorConfig, cell)
+    ) {
+      return detector.id
+    }
+  }
+
+  return null
+}
+
+/** Ids of the pushable and pullable objects in slice `time`, keyed by cell. */
+function movableIdsByCell(cube: TimeCube, time: number): Map<string, string[]> {
+  const byCell = new Map<string, string[]>()
+
+  for (const object of objectsAtTime(cube, time)) {
+    const components = object.archetype.components
+
+    if (!hasComponent(components, 'Pushable') && !hasComponent(components, 'Pullable')) {
+      continue
+    }
+
+    const key = `${object.position.x},${object.position.y}`
+    byCell.set(key, [...(byCell.get(key) ?? []), object.id].sort())
+  }
+
+  return byCell
+}
+
+/**
+ * Guards with `objectMemory` that see, at `currentTime`, a cell whose boxes are not where they
+ * were in the slice their delayed view shows. Each guard reports the first such cell.
+ */
+export function movedObjectNoticesAt(input: {
+  cube: TimeCube
+  currentTime: number
+  config: DetectionConfig
+  configByEnemyId?: Record<string, DetectionConfig>
+}): MovedObjectNotice[] {
+  const { cube, currentTime, config, configByEnemyId } = input
+  const current = movableIdsByCell(cube, currentTime)
+  const notices: MovedObjectNotice[] = []
+
+  for (const detector of enemiesAtTime(cube, currentTime)) {
+    const detectorConfig = detectorConfigFor(detector, config, configByEnemyId)
+
+    if (!detectorConfig.objectMemory || !detectorConfig.enabled || !isValidConfig(detectorConfig)) {
+      continue
+    }
+
+    const observedTime = timeBefore(cube, currentTime, detectorConfig.delayTurns)
+
+    if (observedTime === null) {
+      continue
+    }
+
+    const remembered = movableIdsByCell(cube, observedTime)
+    const changed = [...new Set([...remembered.keys(), ...current.keys()])].filter(
+      (key) => (remembered.get(key) ?? []).join() !== (current.get(key) ?? []).join(),
+    )
+
+    for (const key of changed) {
+      const [x, y] = key.split(',').map(Number)
+      const cell = { x, y, t: currentTime }
+
+      if (seesCell(cube, detector, detectorConfig, cell)) {
+        notices.push({ enemyId: detector.id, cell })
+        break
+      }
+    }
+  }
+
+  return notices
+}
@@ -0,0 +1,124 @@
+import { describe, expect, it } from 'vitest'
+
+import { findPathInSlice, propagatePatrolDetours } from './detour'
+import type { ResolvedObjectInstance } from './objects'
+import { createTimeCube, objectPositionAt, placeObjects } from './timeCube'
+
+const LOOP = [
+  { x: 1, y: 1 },
+  { x: 2, y: 1 },
+  { x: 3, y: 1 },
+  { x: 3, y: 2 },
+  { x: 2, y: 2 },
+  { x: 1, y: 2 },
+]
+
+function guard(): ResolvedObjectInstance {
+  return {
+    id: 'enemy.alpha',
+    archetypeKey: 'enemy',
+    position: { x: 1, y: 1, t: 0 },
+    archetype: {
+      kind: 'enemy',
+      components: [
+        { kind: 'BlocksMovement' },
+        { kind: 'TimePersistent' },
+        { kind: 'Patrol', path: LOOP, loops: true },
+      ],
+      render: {},
+    },
+  }
+}
+
+function block(id: string, x: number, y: number): ResolvedObjectInstance {
+  return {
+    id,
+    archetypeKey: 'box',
+    position: { x, y, t: 0 },
+    archetype: {
+      kind: 'box',
+      components: [{ kind: 'BlocksMovement' }, { kind: 'TimePersistent' }],
+      render: {},
+    },
+  }
+}
+
+function trail(objects: ResolvedObjectInstance[]) {
+  const placed = placeObjects(createTimeCube(6, 6, 8), [guard(), ...objects])
+
+  if (!placed.ok) {
+    throw new Error('placement failed')
+  }
+
+  const detoured = propagatePatrolDetours(placed.value)
+
+  if (!detoured.ok) {
+    return detoured
+  }
+
+  return {
+    ok: true as const,
+    value: Array.from({ length: 8 }, (_, t) => {
+      const position = objectPositionAt(detoured.value, 'enemy.alpha', t)
+      return position ? { x: position.x, y: position.y } : null
+    }),
+  }
+}
+
+describe('patrol detours', () => {
+  it('finds the shortest way around a blocked cell within one slice', () => {
+    const placed = placeObjects(createTimeCube(6, 6, 4), [block('box.a', 3, 1)])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const from = { x: 2, y: 1 }
+
+    expect(findPathInSlice(placed.value, 'enemy.alpha', from, { x: 4, y: 1 }, 2)).toEqual([
+      { x: 2, y: 0 },
+      { x: 3, y: 0 },
+      { x: 4, y: 0 },
+      { x: 4, y: 1 },
+    ])
+    expect(findPathInSlice(placed.value, 'enemy.alpha', from, { x: 3, y: 1 }, 2)).toBeNull()
+  })
+
+  it('keeps an unobstructed patrol on its route', () => {
+    expect(trail([])).toEqual({
+      ok: true,
+      value: [...LOOP, LOOP[0], LOOP[1]],
+    })
+  })
+
+  it('walks around a box in the way and rejoins the route on schedule', () => {
+    expect(trail([block('box.a', 3, 1)])).toEqual({
+      ok: true,
+      value: [
+        { x: 1, y: 1 },
+        { x: 2, y: 1 },
+        { x: 2, y: 2 },
+        { x: 3, y: 2 },
+        { x: 2, y: 2 },
+        { x: 1, y: 2 },
+        { x: 1, y: 1 },
+        { x: 2, y: 1 },
+      ],
+    })
+  })
+
+  it('waits in place when the box leaves no way around', () => {
+    const walls = [
+      block('box.a', 2, 1),
+      block('wall.n', 1, 0),
+      block('wall.w', 0, 1),
+      block('wall.s', 1, 2),
+    ]
+
+    expect(trail(walls)).toEqual({
+      ok: true,
+      value: Array.from({ length: 8 }, () => ({ x: 1, y: 1 })),
+    })
+  })
+})
@@ -0,0 +1,312 @@
+import { hasComponent } from './components'
+import { lurableRoute } from './lure'
+import type { ResolvedObjectInstance } from './objects'
+import { patrolPositionAt, type PatrolRoute } from './patrol'
+import {
+  isInBounds,
+  movePosition,
+  wrappedDistance,
+  wrapPosition,
+  type Direction2D,
+  type Position2D,
+} from './position'
+import type { Result } from './result'
+import {
+  allObjects,
+  isInSlice,
+  objectPositionAt,
+  objectsAt,
+  retraceObject,
+  wrappingOf,
+  type CubeError,
+  type RelocationError,
+  type TimeCube,
+} from './timeCube'
+
+const SEARCH_ORDER: Direction2D[] = ['north', 'east', 'south', 'west']
+
+interface Detour {
+  /** Cells still to walk, one per slice; the last one is back on the route. */
+  steps: Position2D[]
+  /** Route time of the cell the detour rejoins. */
+  rejoinRouteTime: number
+}
+
+function cellKey(cell: Position2D): string {
+  return `${cell.x},${cell.y}`
+}
+
+/**
+ * Objects a patrolling guard may share a cell with: whatever it walks over, and other patrols,
+ * which already crossed paths before any box was moved.
+ */
+function walkOverIds(cube: TimeCube): string[] {
+  return allObjects(cube)
+    .filter(
+      (object) =>
+        !hasComponent(object.archetype.components, 'BlocksMovement') ||
+        detouringRoute(object) !== null,
+    )
+    .map((object) => object.id)
+}
+
+function isPassableFor(
+  cube: TimeCube,
+  id: string,
+  cell: Position2D,
+  t: number,
+  walkOver: string[],
+): boolean {
+  return (
+    isInBounds(cell, cube.width, cube.height) &&
+    objectsAt(cube, { x: cell.x, y: cell.y, t }).every(
+      (object) => object.id === id || walkOver.includes(object.id),
+    )
+  )
+}
+
+/**
+ * A* through slice `t` from `from` to `to` over cells `id` may stand on (empty or holding only
+ * `walkOver` ids), searched north, east, south, west so equal routes always resolve the same way.
+ * Returns the cells after `from`, ending on `to`, or null when `to` cannot be reached.
+ */
+export function findPathInSlice(
+  cube: TimeCube,
+  id: string,
+  from: Position2D,
+  to: Position2D,
+  t: number,
+  walkOver: string[] = [],
+): Position2D[] | null {
+  const wrapping = wrappingOf(cube)
+  const goal = cellKey(to)
+  const previous = new Map<string, Position2D>()
+  const cost = new Map<string, number>([[cellKey(from), 0]])
+  const closed = new Set<string>()
+  const open: Position2D[] = [{ x: from.x, y: from.y }]
+  const score = (cell: Position2D) =>
+    (cost.get(cellKey(cell)) ?? 0) + wrappedDistance(cell, to, wrapping)
+
+  while (open.length > 0) {
+    let best = 0
+
+    for (let index = 1; index < open.length; index += 1) {
+      if (score(open[index]) < score(open[best])) {
+        best = index
+      }
+    }
+
+    const [cell] = open.splice(best, 1)
+    const key = cellKey(cell)
+
+    if (key === goal) {
+      const path: Position2D[] = []
+      let step: Position2D | undefined = cell
+
+      while (step && cellKey(step) !== cellKey(from)) {
+        path.unshift(step)
+        step = previous.get(cellKey(step))
+      }
+
+      return path
+    }
+
+    if (closed.has(key)) {
+      continue
+    }
+
+    closed.add(key)
+
+    for (const direction of SEARCH_ORDER) {
+      const next = wrapPosition(movePosition(cell, direction), wrapping)
+      const nextKey = cellKey(next)
+      const nextCost = (cost.get(key) ?? 0) + 1
+
+      if (
+        closed.has(nextKey) ||
+        nextCost >= (cost.get(nextKey) ?? Infinity) ||
+        !isPassableFor(cube, id, next, t, walkOver)
+      ) {
+        continue
+      }
+
+      cost.set(nextKey, nextCost)
+      previous.set(nextKey, cell)
+      open.push(next)
+    }
+  }
+
+  return null
+}
+
+/**
+ * Patrol route of a guard that walks around whatever blocks it. Static posts, chasers, cameras
+ * and searchlights keep their cells.
+ */
+function detouringRoute(object: ResolvedObjectInstance): PatrolRoute | null {
+  const route = lurableRoute(object)
+  return route && hasComponent(object.archetype.components, 'Patrol') ? route : null
+}
+
+/** Guards whose cells a moved box may take; `propagatePatrolDetours` walks them around it. */
+export function detouringGuardIds(cube: TimeCube): string[] {
+  return allObjects(cube)
+    .filter((object) => detouringRoute(object) !== null)
+    .map((object) => object.id)
+}
+
+/**
+ * Way around the blocked route cell at `routeTime`: the shortest path through slice `t` to the
+ * first later route cell that is reachable there.
+ */
+function planDetour(
+  cube: TimeCube,
+  id: string,
+  route: PatrolRoute,
+  position: Position2D,
+  routeTime: number,
+  t: number,
+  walkOver: string[],
+): Detour | null {
+  const blocked = patrolPositionAt(route, routeTime)
+
+  for (let ahead = 1; ahead < cube.timeDepth; ahead += 1) {
+    const rejoin = patrolPositionAt(route, routeTime + ahead)
+
+    if (!rejoin || (blocked && cellKey(rejoin) === cellKey(blocked))) {
+      continue
+    }
+
+    const steps = findPathInSlice(cube, id, position, rejoin, t, walkOver)
+
+    if (steps) {
+      return { steps: steps.length > 0 ? steps : [position], rejoinRouteTime: routeTime + ahead }
+    }
+  }
+
+  return null
+}
+
+/**
+ * Full trajectory from t=1 up to the first slice the guard is gone from (knocked out), or null
+ * for a guard missing from slice 0. A guard whose next route cell is taken walks the A* detour to
+ * the next reachable route cell and carries on from there later than scheduled; with no way
+ * around it waits for the cell to clear.
+ */
+function detourTrail(
+  cube: TimeCube,
+  id: string,
+  route: PatrolRoute,
+  walkOver: string[],
+): Result<Position2D[] | null, RelocationError> {
+  const start = objectPositionAt(cube, id, 0)
+
+  if (!start) {
+    return { ok: true, value: null }
+  }
+
+  let position: Position2D = { x: start.x, y: start.y }
+  let offset = 0
+  let detour: Detour | null = null
+  const path: Position2D[] = []
+
+  for (let t = 1; t < cube.timeDepth && isInSlice(cube, id, t); t += 1) {
+    if (!detour) {
+      const scheduled = patrolPositionAt(route, t - offset) ?? position
+
+      if (isPassableFor(cube, id, scheduled, t, walkOver)) {
+        path.push(scheduled)
+        position = scheduled
+        continue
+      }
+
+      detour = planDetour(cube, id, route, position, t - offset, t, walkOver)
+    }
+
+    const step = detour?.steps[0]
+
+    if (detour && step && isPassableFor(cube, id, step, t, walkOver)) {
+      position = step
+      detour = { ...detour, steps: detour.steps.slice(1) }
+
+      if (detour.steps.length === 0) {
+        offset = t - detour.rejoinRouteTime
+        detour = null
+      }
+    } else if (isPassableFor(cube, id, position, t, walkOver)) {
+      // Waiting in place: the route (or the detour) resumes a slice later.
+      offset += detour ? 0 : 1
+    } else {
+      const occupant = objectsAt(cube, { ...position, t }).find(
+        (object) => object.id !== id && !walkOver.includes(object.id),
+      )
+
+      return {
+        ok: false,
+        error: { kind: 'TargetOccupied', id: occupant?.id ?? id, x: position.x, y: position.y, t },
+      }
+    }
+
+    path.push(position)
+  }
+
+  return { ok: true, value: path }
+}
+
+/**
+ * Cells guard `id` takes slice by slice once detours around boxes are worked out, from slice 0 up
+ * to the first slice it is gone from. Null for objects that do not detour or cannot be resolved.
+ */
+export function detouredPatrolTrail(cube: TimeCube, id: string): Position2D[] | null {
+  const object = cube.objectsById[id]
+  const route = object ? detouringRoute(object) : null
+  const start = objectPositionAt(cube, id, 0)
+
+  if (!route || !start) {
+    return null
+  }
+
+  const trail = detourTrail(cube, id, route, walkOverIds(cube))
+
+  return trail.ok && trail.value ? [{ x: start.x, y: start.y }, ...trail.value] : null
+}
+
+/**
+ * Re-resolves every patrolling guard against the boxes currently in its way. Trails are rebuilt
+ * from the undisturbed route each turn, so moving a box back out of the way restores the patrol.
+ * Fails when a guard is boxed in on a cell something else now takes.
+ */
+export function propagatePatrolDetours(
+  cube: TimeCube,
+): Result<TimeCube, CubeError | RelocationError> {
+  const walkOver = walkOverIds(cube)
+  let next = cube
+
+  for (const object of allObjects(cube)) {
+    const route = detouringRoute(object)
+
+    if (!route) {
+      continue
+    }
+
+    const trail = detourTrail(next, object.id, route, walkOver)
+
+    if (!trail.ok) {
+      return trail
+    }
+
+    if (!trail.value) {
+      continue
+    }
+
+    const retraced = retraceObject(next, object.id, 1, trail.value, walkOver)
+
+    if (!retraced.ok) {
+      return retraced
+    }
+
+    next = retraced.value
+  }
+
+  return { ok: true, value: next }
+}
@@ -0,0 +1,102 @@
+import { describe, expect, it } from 'vitest'
+
+import { evaluateDetectionV1 } from './detection'
+import { addEchoes, echoTrail } from './echo'
+import { watchFacingAt } from './guards'
+import { createTimeCube, objectPositionAt, objectsAt, placeObjects } from './timeCube'
+import { createWorldLine } from './worldLine'
+
+describe('echoTrail', () => {
+  it('follows the attempt slice by slice and holds its last cell', () => {
+    const trail = echoTrail(
+      [
+        { x: 1, y: 1, t: 0 },
+        { x: 2, y: 1, t: 1 },
+        { x: 2, y: 2, t: 2 },
+      ],
+      5,
+    )
+
+    expect(trail?.cells).toEqual([
+      { x: 1, y: 1 },
+      { x: 2, y: 1 },
+      { x: 2, y: 2 },
+      { x: 2, y: 2 },
+      { x: 2, y: 2 },
+    ])
+    expect(trail?.facings).toEqual(['east', 'east', 'south', 'south', 'south'])
+  })
+
+  it('keeps the first visit to a slice revisited through a rift', () => {
+    const trail = echoTrail(
+      [
+        { x: 0, y: 0, t: 0 },
+        { x: 0, y: 1, t: 1 },
+        { x: 3, y: 3, t: 0 },
+        { x: 3, y: 2, t: 1 },
+      ],
+      2,
+    )
+
+    expect(trail?.cells).toEqual([
+      { x: 0, y: 0 },
+      { x: 0, y: 1 },
+    ])
+  })
+})
+
+describe('addEchoes', () => {
+  it('places a ghost that retraces the attempt and watches ahead', () => {
+    const placed = placeObjects(createTimeCube(6, 6, 4), [
+      {
+        id: 'exit.main',
+        archetypeKey: 'exit',
+        position: { x: 3, y: 0, t: 0 },
+        archetype: {
+          kind: 'exit',
+          components: [{ kind: 'Exit' }, { kind: 'TimePersistent' }],
+          render: {},
+        },
+      },
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const echoed = addEchoes(placed.value, [
+      [
+        { x: 0, y: 0, t: 0 },
+        { x: 1, y: 0, t: 1 },
+        { x: 2, y: 0, t: 2 },
+        { x: 3, y: 0, t: 3 },
+      ],
+    ])
+
+    expect(echoed.ok).toBe(true)
+    if (!echoed.ok) {
+      return
+    }
+
+    const cube = echoed.value
+
+    expect(objectPositionAt(cube, 'echo.1', 2)).toEqual({ x: 2, y: 0, t: 2 })
+    expect(objectsAt(cube, { x: 3, y: 0, t: 3 }).map((object) => object.id)).toEqual([
+      'exit.main',
+      'echo.1',
+    ])
+    expect(watchFacingAt(cube.objectsById['echo.1'].archetype.components, 2)).toBe('east')
+
+    const config = { enabled: true, delayTurns: 1, maxDistance: 3 }
+    const ahead = createWorldLine({ x: 4, y: 0, t: 1 })
+    const behind = createWorldLine({ x: 0, y: 0, t: 1 })
+
+    expect(evaluateDetectionV1({ cube, worldLine: ahead, currentTime: 2, config }).detected).toBe(
+      true,
+    )
+    expect(evaluateDetectionV1({ cube, worldLine: behind, currentTime: 2, config }).detected).toBe(
+      false,
+    )
+  })
+})
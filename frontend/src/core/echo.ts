@@ -0,0 +1,138 @@
+import type { Component } from './components'
+import { echoOf } from './guards'
+import type { ObjectArchetype, ResolvedObjectInstance } from './objects'
+import { stepDirection, type Direction2D, type Position2D, type Position3D } from './position'
+import type { Result } from './result'
+import {
+  placeObjects,
+  retraceObject,
+  type CubeError,
+  type RelocationError,
+  type TimeCube,
+} from './timeCube'
+
+export const ECHO_ID_PREFIX = 'echo.'
+
+const DEFAULT_ECHO_FACING: Direction2D = 'south'
+
+/** Where an echo stands and which way it faces in every slice of the cube. */
+export interface EchoTrail {
+  cells: Position2D[]
+  facings: Direction2D[]
+}
+
+/**
+ * Spreads a world line over the slices of the cube: each slice takes the first cell the attempt
+ * stood on there. Slices it never reached hold the cell of the nearest earlier one (or, before
+ * the attempt started, its first cell), so the echo stays where the attempt ended. Facings follow
+ * the steps between slices and keep the last one while the echo stands still. Null for an empty
+ * path.
+ */
+export function echoTrail(path: Position3D[], timeDepth: number): EchoTrail | null {
+  if (path.length === 0 || timeDepth < 1) {
+    return null
+  }
+
+  const visited: (Position2D | null)[] = Array.from({ length: timeDepth }, () => null)
+
+  for (const position of path) {
+    if (position.t >= 0 && position.t < timeDepth && !visited[position.t]) {
+      visited[position.t] = { x: position.x, y: position.y }
+    }
+  }
+
+  const cells: Position2D[] = []
+  let last: Position2D = { x: path[0].x, y: path[0].y }
+
+  for (let t = 0; t < timeDepth; t += 1) {
+    last = visited[t] ?? last
+    cells.push(last)
+  }
+
+  const firstStep = cells
+    .slice(1)
+    .map((cell, index) => stepDirection(cells[index], cell))
+    .find((facing) => facing !== null)
+  const facings: Direction2D[] = []
+  let facing = firstStep ?? DEFAULT_ECHO_FACING
+
+  for (let t = 0; t < timeDepth; t += 1) {
+    facing = (t > 0 ? stepDirection(cells[t - 1], cells[t]) : null) ?? facing
+    facings.push(facing)
+  }
+
+  return { cells, facings }
+}
+
+/**
+ * Echoes are ghosts: they never block movement, so a repeated attempt can always walk through
+ * them, but they watch the cone ahead like a Sentry.
+ */
+export function echoArchetype(attempt: number, trail: EchoTrail): ObjectArchetype {
+  const components: Component[] = [
+    { kind: 'TimePersistent' },
+    { kind: 'Patrol', path: trail.cells, loops: true },
+    { kind: 'Guard', guard: 'Echo', attempt, facings: trail.facings },
+  ]
+
+  return {
+    kind: 'enemy',
+    components,
+    render: { fill: '#ececec', stroke: '#8a8a8a', symbol: 'enemy' },
+  }
+}
+
+/**
+ * Adds one echo per past attempt (oldest first, ids `echo.1`, `echo.2`, ...) to a freshly
+ * bootstrapped cube. Echoes may share cells with anything, since the attempt they retrace did.
+ */
+export function addEchoes(
+  cube: TimeCube,
+  paths: Position3D[][],
+): Result<TimeCube, CubeError | RelocationError> {
+  let next = cube
+
+  for (let index = 0; index < paths.length; index += 1) {
+    const trail = echoTrail(paths[index], cube.timeDepth)
+
+    if (!trail) {
+      continue
+    }
+
+    const attempt = index + 1
+    const echo: ResolvedObjectInstance = {
+      id: `${ECHO_ID_PREFIX}${attempt}`,
+      archetypeKey: 'echo',
+      position: { ...trail.cells[0], t: 0 },
+      archetype: echoArchetype(attempt, trail),
+    }
+    const placed = placeObjects(next, [echo])
+
+    if (!placed.ok) {
+      return placed
+    }
+
+    const retraced = retraceObject(
+      placed.value,
+      echo.id,
+      0,
+      trail.cells,
+      Object.keys(placed.value.objectsById),
+    )
+
+    if (!retraced.ok) {
+      return retraced
+    }
+
+    next = retraced.value
+  }
+
+  return { ok: true, value: next }
+}
+
+/** Echoes in the cube; boxes may be pushed or pulled onto their cells. */
+export function echoIds(cube: TimeCube): string[] {
+  return Object.keys(cube.objectsById).filter(
+    (id) => echoOf(cube.objectsById[id].archetype.components) !== null,
+  )
+}
@@ -0,0 +1,104 @@
+import { describe, expect, it } from 'vitest'
+
+import { asTurn } from './coords'
+import { evaluateDetectionV1 } from './detection'
+import { currentLayer, isVisibleFromGround, layerAtTurn } from './elevation'
+import type { ResolvedObjectInstance } from './objects'
+import { createTimeCube, placeObjects } from './timeCube'
+import { createWorldLine, extendNormal, extendViaRift, type WorldLineState } from './worldLine'
+
+function catwalkObject(
+  id: string,
+  x: number,
+  railing: 'open' | 'closed',
+  ladder = false,
+): ResolvedObjectInstance {
+  return {
+    id,
+    archetypeKey: 'catwalk',
+    position: { x, y: 1, t: 0 },
+    archetype: {
+      kind: 'catwalk',
+      components: [
+        { kind: 'TimePersistent' },
+        { kind: 'Catwalk', railing },
+        ...(ladder ? [{ kind: 'Ladder' as const }] : []),
+      ],
+      render: {},
+    },
+  }
+}
+
+function guardObject(x: number, y: number): ResolvedObjectInstance {
+  return {
+    id: 'enemy.alpha',
+    archetypeKey: 'enemy',
+    position: { x, y, t: 0 },
+    archetype: {
+      kind: 'enemy',
+      components: [{ kind: 'TimePersistent' }, { kind: 'Patrol', path: [{ x, y }], loops: true }],
+      render: {},
+    },
+  }
+}
+
+function climbedLine(): WorldLineState {
+  const climbed = extendNormal(createWorldLine({ x: 1, y: 1, t: 0 }), { x: 1, y: 1, t: 1 }, 'Climb')
+
+  if (!climbed.ok) {
+    throw new Error('climb step rejected')
+  }
+
+  return climbed.value
+}
+
+describe('elevation', () => {
+  it('switches layer on every climb and lands rifts on the ground', () => {
+    const up = climbedLine()
+    const walked = extendNormal(up, { x: 2, y: 1, t: 2 })
+
+    expect(walked.ok).toBe(true)
+    if (!walked.ok) {
+      return
+    }
+
+    expect(layerAtTurn(walked.value, asTurn(0))).toBe('ground')
+    expect(currentLayer(walked.value)).toBe('catwalk')
+
+    const rifted = extendViaRift(walked.value, { x: 2, y: 1, t: 0 })
+
+    expect(rifted.ok && currentLayer(rifted.value)).toBe('ground')
+  })
+
+  it('hides the player behind closed railings from guards below', () => {
+    const placed = placeObjects(createTimeCube(6, 6, 6), [
+      catwalkObject('catwalk.1', 1, 'closed', true),
+      catwalkObject('catwalk.2', 2, 'open'),
+      guardObject(1, 2),
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const cube = placed.value
+    const config = { enabled: true, delayTurns: 1, maxDistance: 3 }
+    const behindClosed = extendNormal(climbedLine(), { x: 1, y: 1, t: 2 })
+    const atOpen = extendNormal(climbedLine(), { x: 2, y: 1, t: 2 })
+
+    expect(behindClosed.ok && atOpen.ok).toBe(true)
+    if (!behindClosed.ok || !atOpen.ok) {
+      return
+    }
+
+    expect(isVisibleFromGround(cube, { x: 1, y: 1, t: 2 }, 'catwalk')).toBe(false)
+    expect(isVisibleFromGround(cube, { x: 1, y: 1, t: 2 }, 'ground')).toBe(true)
+    expect(
+      evaluateDetectionV1({ cube, worldLine: behindClosed.value, currentTime: 3, config }).detected,
+    ).toBe(false)
+    expect(
+      evaluateDetectionV1({ cube, worldLine: atOpen.value, currentTime: 3, config }).detected,
+    ).toBe(true)
+  })
+})
@@ -0,0 +1,67 @@
+import type { CatwalkComponent, Component } from './components'
+import type { Turn } from './coords'
+import type { Position3D } from './position'
+import { objectsAt, type TimeCube } from './timeCube'
+import { headTurn, type WorldLineState } from './worldLine'
+
+/** Height the player walks at: the map floor or the catwalks above it. */
+export type Layer = 'ground' | 'catwalk'
+
+export function catwalkOf(components: Component[]): CatwalkComponent | null {
+  for (const component of components) {
+    if (component.kind === 'Catwalk') {
+      return component
+    }
+  }
+
+  return null
+}
+
+/** Catwalk spanning `position`, or null when the cell has none. */
+export function catwalkAt(cube: TimeCube, position: Position3D): CatwalkComponent | null {
+  for (const object of objectsAt(cube, position)) {
+    const catwalk = catwalkOf(object.archetype.components)
+
+    if (catwalk) {
+      return catwalk
+    }
+  }
+
+  return null
+}
+
+export function hasLadderAt(cube: TimeCube, position: Position3D): boolean {
+  return objectsAt(cube, position).some((object) =>
+    object.archetype.components.some((component) => component.kind === 'Ladder'),
+  )
+}
+
+/**
+ * Layer of the self at path index `turn`. Every `Climb` step switches layer; a rift always lands
+ * on the ground, since rift targets are ground cells.
+ */
+export function layerAtTurn(worldLine: WorldLineState, turn: Turn): Layer {
+  let layer: Layer = 'ground'
+
+  for (const step of worldLine.steps.slice(0, turn + 1)) {
+    if (step === 'Climb') {
+      layer = layer === 'ground' ? 'catwalk' : 'ground'
+    } else if (step === 'Rift') {
+      layer = 'ground'
+    }
+  }
+
+  return layer
+}
+
+export function currentLayer(worldLine: WorldLineState): Layer {
+  return layerAtTurn(worldLine, headTurn(worldLine))
+}
+
+/**
+ * Whether ground-level watchers (guards, cameras, searchlights) can see a self at `position` on
+ * `layer`. Sight only crosses from the ground onto the catwalk where its railing is open.
+ */
+export function isVisibleFromGround(cube: TimeCube, position: Position3D, layer: Layer): boolean {
+  return layer === 'ground' || catwalkAt(cube, position)?.railing === 'open'
+}
@@ -0,0 +1,87 @@
+import { describe, expect, it } from 'vitest'
+
+import { fogCellKey, fogViewAt, isSceneryObject, recordExplored, visibleCellsFrom } from './fog'
+import type { ResolvedObjectInstance } from './objects'
+import { createTimeCube, placeObjects } from './timeCube'
+
+function wallObject(id: string, x: number, y: number): ResolvedObjectInstance {
+  return {
+    id,
+    archetypeKey: 'wall',
+    position: { x, y, t: 0 },
+    archetype: {
+      kind: 'wall',
+      components: [
+        { kind: 'BlocksMovement' },
+        { kind: 'BlocksVision' },
+        { kind: 'TimePersistent' },
+      ],
+      render: {},
+    },
+  }
+}
+
+function enemyObject(id: string, x: number, y: number): ResolvedObjectInstance {
+  return {
+    id,
+    archetypeKey: 'enemy',
+    position: { x, y, t: 0 },
+    archetype: {
+      kind: 'enemy',
+      components: [
+        { kind: 'BlocksMovement' },
+        { kind: 'TimePersistent' },
+        { kind: 'Patrol', path: [{ x, y }], loops: true },
+      ],
+      render: {},
+    },
+  }
+}
+
+describe('fog of war', () => {
+  const placed = placeObjects(createTimeCube(5, 1, 3), [wallObject('wall.mid', 2, 0)])
+
+  it('sees up to a wall but not past it', () => {
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    expect(visibleCellsFrom(placed.value, { x: 0, y: 0 }, 0)).toEqual([
+      { x: 0, y: 0 },
+      { x: 1, y: 0 },
+      { x: 2, y: 0 },
+    ])
+  })
+
+  it('accumulates explored cells per slice and remembers them once out of sight', () => {
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const fromLeft = recordExplored({}, placed.value, { x: 0, y: 0 }, 1)
+    const explored = recordExplored(fromLeft, placed.value, { x: 4, y: 0 }, 1)
+
+    expect(explored[0]).toBeUndefined()
+    expect(explored[1]).toHaveLength(5)
+
+    const view = fogViewAt(placed.value, fromLeft, { x: 4, y: 0 }, 1)
+
+    expect(view.visible.has(fogCellKey({ x: 3, y: 0 }))).toBe(true)
+    expect(view.remembered).toEqual([
+      { x: 0, y: 0 },
+      { x: 1, y: 0 },
+    ])
+    expect(view.unexplored).toEqual([])
+    expect(fogViewAt(placed.value, {}, { x: 4, y: 0 }, 2).unexplored).toEqual([
+      { x: 0, y: 0 },
+      { x: 1, y: 0 },
+    ])
+  })
+
+  it('keeps only scenery in remembered cells', () => {
+    expect(isSceneryObject(wallObject('wall.a', 0, 0))).toBe(true)
+    expect(isSceneryObject(enemyObject('enemy.a', 0, 0))).toBe(false)
+  })
+})
@@ -0,0 +1,128 @@
+import { hasComponent } from './components'
+import { hasLineOfSight } from './detection'
+import type { ResolvedObjectInstance } from './objects'
+import {
+  isInBounds,
+  movePosition,
+  wrapPosition,
+  type Direction2D,
+  type Position2D,
+  type Position3D,
+} from './position'
+import { isBlocked, wrappingOf, type TimeCube } from './timeCube'
+
+/** Explored cells per slice, keyed by `x,y`. Kept as arrays so game state stays serializable. */
+export type ExploredByTime = Record<number, string[]>
+
+export function fogCellKey(cell: Position2D): string {
+  return `${cell.x},${cell.y}`
+}
+
+/**
+ * Cells the player can see from `from` in slice `t`: every in-bounds cell with line of sight,
+ * using the same occlusion as guards. Blockers themselves are visible; what is behind them is not.
+ */
+export function visibleCellsFrom(cube: TimeCube, from: Position2D, t: number): Position2D[] {
+  const cells: Position2D[] = []
+
+  for (let y = 0; y < cube.height; y += 1) {
+    for (let x = 0; x < cube.width; x += 1) {
+      if (hasLineOfSight({ cube, from, to: { x, y }, atTime: t })) {
+        cells.push({ x, y })
+      }
+    }
+  }
+
+  return cells
+}
+
+/** Adds what the player sees from `from` at `t` to the explored set; other slices are untouched. */
+export function recordExplored(
+  explored: ExploredByTime,
+  cube: TimeCube,
+  from: Position2D,
+  t: number,
+): ExploredByTime {
+  const known = new Set(explored[t] ?? [])
+
+  for (const cell of visibleCellsFrom(cube, from, t)) {
+    known.add(fogCellKey(cell))
+  }
+
+  return { ...explored, [t]: [...known] }
+}
+
+/**
+ * Cell a peek in `direction` looks from: the neighbour of `from` in its own slice. Null when that
+ * cell is off the board or something there blocks movement, since there is no corner to lean past.
+ */
+export function peekOrigin(
+  cube: TimeCube,
+  from: Position3D,
+  direction: Direction2D,
+): Position2D | null {
+  const origin = wrapPosition(movePosition(from, direction), wrappingOf(cube))
+
+  if (!isInBounds(origin, cube.width, cube.height) || isBlocked(cube, { ...origin, t: from.t })) {
+    return null
+  }
+
+  return origin
+}
+
+/**
+ * Remembered cells only show scenery. Anything that can move (guards, patrols, boxes) is left out,
+ * since its remembered position would be stale.
+ */
+export function isSceneryObject(object: ResolvedObjectInstance): boolean {
+  const components = object.archetype.components
+
+  return (
+    object.archetype.kind !== 'enemy' &&
+    !hasComponent(components, 'Patrol') &&
+    !hasComponent(components, 'Guard') &&
+    !hasComponent(components, 'Pushable') &&
+    !hasComponent(components, 'Pullable')
+  )
+}
+
+export interface FogView {
+  /** `x,y` keys of cells in line of sight right now. */
+  visible: Set<string>
+  /** `x,y` keys of every cell seen in this slice so far, including the visible ones. */
+  explored: Set<string>
+  /** Cells never seen in this slice; drawn fully covered. */
+  unexplored: Position2D[]
+  /** Cells seen before but not now; drawn dimmed with scenery only. */
+  remembered: Position2D[]
+}
+
+export function fogViewAt(
+  cube: TimeCube,
+  explored: ExploredByTime,
+  from: Position2D,
+  t: number,
+): FogView {
+  const visible = new Set(visibleCellsFrom(cube, from, t).map(fogCellKey))
+  const known = new Set([...(explored[t] ?? []), ...visible])
+  const unexplored: Position2D[] = []
+  const remembered: Position2D[] = []
+
+  for (let y = 0; y < cube.height; y += 1) {
+    for (let x = 0; x < cube.width; x += 1) {
+      const key = fogCellKey({ x, y })
+
+      if (visible.has(key)) {
+        continue
+      }
+
+      if (known.has(key)) {
+        remembered.push({ x, y })
+      } else {
+        unexplored.push({ x, y })
+      }
+    }
+  }
+
+  return { visible, explored: known, unexplored, remembered }
+}
@@ -0,0 +1,54 @@
+import type { Component, FootprintComponent } from './components'
+import { movePosition, type Direction2D, type Position2D } from './position'
+
+export function footprintOf(components: Component[]): FootprintComponent | null {
+  for (const component of components) {
+    if (component.kind === 'Footprint') {
+      return component
+    }
+  }
+
+  return null
+}
+
+/** Covers more than its own cell. */
+export function isMultiTile(components: Component[]): boolean {
+  const footprint = footprintOf(components)
+  return footprint !== null && footprint.width * footprint.height > 1
+}
+
+/**
+ * Cells a footprint anchored at `anchor` covers, row by row from the anchor; just the anchor when
+ * there is no footprint. Footprints never wrap around the map edge, so callers check the cells
+ * against the board themselves.
+ */
+export function coveredCells(
+  footprint: Pick<FootprintComponent, 'width' | 'height'> | null,
+  anchor: Position2D,
+): Position2D[] {
+  const cells: Position2D[] = []
+
+  for (let dy = 0; dy < (footprint?.height ?? 1); dy += 1) {
+    for (let dx = 0; dx < (footprint?.width ?? 1); dx += 1) {
+      cells.push({ x: anchor.x + dx, y: anchor.y + dy })
+    }
+  }
+
+  return cells
+}
+
+export function footprintCells(components: Component[], anchor: Position2D): Position2D[] {
+  return coveredCells(footprintOf(components), anchor)
+}
+
+/**
+ * Cells a push in `direction` moves a block covering `cells` into that it does not already cover:
+ * the row or column just past its leading edge.
+ */
+export function leadingEdgeCells(cells: Position2D[], direction: Direction2D): Position2D[] {
+  const covered = new Set(cells.map((cell) => `${cell.x},${cell.y}`))
+
+  return cells
+    .map((cell) => movePosition(cell, direction))
+    .filter((cell) => !covered.has(`${cell.x},${cell.y}`))
+}
@@ -0,0 +1,35 @@
+import type { Component } from './components'
+import type { ResolvedObjectInstance } from './objects'
+import { allObjects, objectsAtTime, objectsWith, type TimeCube } from './timeCube'
+
+export function groupOf(components: Component[]): string | null {
+  for (const component of components) {
+    if (component.kind === 'Group') {
+      return component.id
+    }
+  }
+
+  return null
+}
+
+/** Every object of group `groupId`, whichever slices it occupies, in placement order. */
+export function groupMembers(cube: TimeCube, groupId: string): ResolvedObjectInstance[] {
+  return objectsWith(cube, (object) => groupOf(object.archetype.components) === groupId)
+}
+
+/** Members of group `groupId` still in slice `t`, at their cells there. */
+export function groupMembersAt(
+  cube: TimeCube,
+  groupId: string,
+  t: number,
+): ResolvedObjectInstance[] {
+  return objectsAtTime(cube, t).filter(
+    (object) => groupOf(object.archetype.components) === groupId,
+  )
+}
+
+/** Group ids used in the cube, each once, in placement order of their first member. */
+export function groupIds(cube: TimeCube): string[] {
+  const ids = allObjects(cube).flatMap((object) => groupOf(object.archetype.components) ?? [])
+  return [...new Set(ids)]
+}
@@ -0,0 +1,63 @@
+import { describe, expect, it } from 'vitest'
+
+import { enemiesAtTime, enemyPositionAt, isEnemy } from './guards'
+import type { ResolvedObjectInstance } from './objects'
+import { createTimeCube, moveObjectThroughTime, placeObjects } from './timeCube'
+
+function objectOf(
+  id: string,
+  kind: string,
+  components: ResolvedObjectInstance['archetype']['components'],
+  x: number,
+  y: number,
+): ResolvedObjectInstance {
+  return {
+    id,
+    archetypeKey: id,
+    position: { x, y, t: 0 },
+    archetype: { kind, components: [{ kind: 'TimePersistent' }, ...components], render: {} },
+  }
+}
+
+const objects = [
+  objectOf('chaser', 'guard', [{ kind: 'Guard', guard: 'Chaser', alertDistance: 3 }], 1, 1),
+  objectOf('camera', 'camera', [{ kind: 'Camera', facings: ['east'], turnEvery: 1 }], 4, 0),
+  objectOf('box', 'box', [{ kind: 'Pushable' }], 2, 2),
+]
+
+describe('enemiesAtTime', () => {
+  it('lists guards and cameras but not props', () => {
+    expect(objects.map(isEnemy)).toEqual([true, true, false])
+
+    const placed = placeObjects(createTimeCube(5, 5, 4), objects)
+    expect(placed.ok).toBe(true)
+
+    if (!placed.ok) {
+      return
+    }
+
+    expect(enemiesAtTime(placed.value, 0).map((enemy) => enemy.id)).toEqual(['chaser', 'camera'])
+  })
+
+  it('reads each enemy from the cube as propagation left it', () => {
+    const placed = placeObjects(createTimeCube(5, 5, 4), objects)
+
+    if (!placed.ok) {
+      throw new Error('placement failed')
+    }
+
+    const moved = moveObjectThroughTime(placed.value, 'chaser', 2, { x: 3, y: 1 })
+
+    if (!moved.ok) {
+      throw new Error('move failed')
+    }
+
+    const chaserAt = (t: number) =>
+      enemiesAtTime(moved.value, t).find((enemy) => enemy.id === 'chaser')?.position
+
+    expect(chaserAt(1)).toEqual({ x: 1, y: 1, t: 1 })
+    expect(chaserAt(3)).toEqual({ x: 3, y: 1, t: 3 })
+    expect(enemyPositionAt(moved.value, 'chaser', 2)).toEqual({ x: 3, y: 1, t: 2 })
+    expect(enemyPositionAt(moved.value, 'box', 2)).toBeNull()
+  })
+})
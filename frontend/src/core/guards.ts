@@ -0,0 +1,161 @@
+import {
+  hasComponent,
+  type CameraComponent,
+  type ChaserGuardComponent,
+  type Component,
+  type EchoGuardComponent,
+  type GuardComponent,
+  type SentryGuardComponent,
+} from './components'
+import type { ResolvedObjectInstance } from './objects'
+import type { Direction2D, Position2D, Position3D } from './position'
+import { objectPositionAt, objectsAtTime, type TimeCube } from './timeCube'
+
+function modulo(value: number, divisor: number): number {
+  return ((value % divisor) + divisor) % divisor
+}
+
+export function guardOf(components: Component[]): GuardComponent | null {
+  for (const component of components) {
+    if (component.kind === 'Guard') {
+      return component
+    }
+  }
+
+  return null
+}
+
+export function sentryOf(components: Component[]): SentryGuardComponent | null {
+  const guard = guardOf(components)
+  return guard?.guard === 'Sentry' ? guard : null
+}
+
+export function chaserOf(components: Component[]): ChaserGuardComponent | null {
+  const guard = guardOf(components)
+  return guard?.guard === 'Chaser' ? guard : null
+}
+
+export function echoOf(components: Component[]): EchoGuardComponent | null {
+  const guard = guardOf(components)
+  return guard?.guard === 'Echo' ? guard : null
+}
+
+/** Sentries cycle through their facings one slice at a time, starting from the first at t=0. */
+export function sentryFacingAt(sentry: SentryGuardComponent, t: number): Direction2D | null {
+  if (sentry.facings.length === 0) {
+    return null
+  }
+
+  return sentry.facings[modulo(t, sentry.facings.length)]
+}
+
+/** Echoes face the way they walked; past the end of the attempt they keep the last facing. */
+export function echoFacingAt(echo: EchoGuardComponent, t: number): Direction2D | null {
+  if (echo.facings.length === 0) {
+    return null
+  }
+
+  return echo.facings[Math.min(Math.max(0, t), echo.facings.length - 1)]
+}
+
+export function cameraOf(components: Component[]): CameraComponent | null {
+  for (const component of components) {
+    if (component.kind === 'Camera') {
+      return component
+    }
+  }
+
+  return null
+}
+
+/** Cameras hold each facing for `turnEvery` slices before turning to the next one. */
+export function cameraFacingAt(camera: CameraComponent, t: number): Direction2D | null {
+  if (camera.facings.length === 0) {
+    return null
+  }
+
+  const hold = Math.max(1, camera.turnEvery)
+  return camera.facings[modulo(Math.floor(t / hold), camera.facings.length)]
+}
+
+/**
+ * Facing of a directional watcher (Sentry, Echo or Camera) at `t`. `undefined` means the object
+ * watches all around; `null` means it is directional but has no facing to watch.
+ */
+export function watchFacingAt(components: Component[], t: number): Direction2D | null | undefined {
+  const sentry = sentryOf(components)
+
+  if (sentry) {
+    return sentryFacingAt(sentry, t)
+  }
+
+  const echo = echoOf(components)
+
+  if (echo) {
+    return echoFacingAt(echo, t)
+  }
+
+  const camera = cameraOf(components)
+  return camera ? cameraFacingAt(camera, t) : undefined
+}
+
+/**
+ * Objects that watch for the player: enemies, patrols, guards and cameras. Detection, facing ticks
+ * and the legend all ask this, so a new kind of watcher is added here once.
+ */
+export function isEnemy(object: ResolvedObjectInstance): boolean {
+  const components = object.archetype.components
+
+  return (
+    object.archetype.kind === 'enemy' ||
+    hasComponent(components, 'Patrol') ||
+    hasComponent(components, 'Guard') ||
+    hasComponent(components, 'Camera')
+  )
+}
+
+/**
+ * Enemies in slice `t`, each where the cube has it. Patrols, detours, lures and chases all write
+ * their result into the cube, so callers never work positions out from patrol data themselves.
+ */
+export function enemiesAtTime(cube: TimeCube, t: number): ResolvedObjectInstance[] {
+  return objectsAtTime(cube, t).filter(isEnemy)
+}
+
+/** Cell of enemy `id` in slice `t`; null when it is absent there or is not an enemy. */
+export function enemyPositionAt(cube: TimeCube, id: string, t: number): Position3D | null {
+  const object = cube.objectsById[id]
+  return object && isEnemy(object) ? objectPositionAt(cube, id, t) : null
+}
+
+/**
+ * 90-degree view cone: `to` must lie ahead of `from` along `facing`, at least as far forward as it
+ * is sideways. The guard's own cell is never in view.
+ */
+export function isInFacingCone(from: Position2D, facing: Direction2D, to: Position2D): boolean {
+  const dx = to.x - from.x
+  const dy = to.y - from.y
+  let forward = 0
+  let lateral = 0
+
+  switch (facing) {
+    case 'north':
+      forward = -dy
+      lateral = Math.abs(dx)
+      break
+    case 'south':
+      forward = dy
+      lateral = Math.abs(dx)
+      break
+    case 'east':
+      forward = dx
+      lateral = Math.abs(dy)
+      break
+    case 'west':
+      forward = -dx
+      lateral = Math.abs(dy)
+      break
+  }
+
+  return forward > 0 && lateral <= forward
+}
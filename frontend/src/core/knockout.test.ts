@@ -0,0 +1,57 @@
+import { describe, expect, it } from 'vitest'
+
+import { bodySightingsAt, isKnockable, knockOut } from './knockout'
+import type { ResolvedObjectInstance } from './objects'
+import { createTimeCube, isBlocked, objectPositionAt, placeObjects } from './timeCube'
+
+function objectOf(
+  id: string,
+  components: ResolvedObjectInstance['archetype']['components'],
+  x: number,
+  y: number,
+): ResolvedObjectInstance {
+  return {
+    id,
+    archetypeKey: id,
+    position: { x, y, t: 0 },
+    archetype: {
+      kind: 'guard',
+      components: [{ kind: 'TimePersistent' }, ...components],
+      render: {},
+    },
+  }
+}
+
+const sleeper = objectOf('sleeper', [{ kind: 'Guard', guard: 'Sentry', facings: ['east'] }], 1, 1)
+const watcher = objectOf('watcher', [{ kind: 'Guard', guard: 'Sentry', facings: ['west'] }], 3, 1)
+const camera = objectOf('camera', [{ kind: 'Camera', facings: ['east'], turnEvery: 1 }], 0, 0)
+const config = { enabled: true, delayTurns: 1, maxDistance: 2 }
+
+describe('knockOut', () => {
+  it('swaps the guard for a body from the slice it goes down in', () => {
+    expect([sleeper, camera].map(isKnockable)).toEqual([true, false])
+
+    const placed = placeObjects(createTimeCube(5, 5, 4), [sleeper, watcher])
+
+    if (!placed.ok) {
+      throw new Error('placement failed')
+    }
+
+    const knocked = knockOut(placed.value, 'sleeper', 2)
+
+    if (!knocked.ok) {
+      throw new Error('knockout failed')
+    }
+
+    const { cube, bodyId } = knocked.value
+
+    expect(bodyId).toBe('sleeper.body')
+    expect(objectPositionAt(cube, 'sleeper', 1)).toEqual({ x: 1, y: 1, t: 1 })
+    expect(objectPositionAt(cube, 'sleeper', 2)).toBeNull()
+    expect(objectPositionAt(cube, bodyId, 1)).toBeNull()
+    expect(isBlocked(cube, { x: 1, y: 1, t: 3 })).toBe(true)
+    expect(bodySightingsAt({ cube, t: 1, config })).toEqual([])
+    expect(bodySightingsAt({ cube, t: 2, config })).toEqual([{ bodyId, enemyId: 'watcher' }])
+    expect(knockOut(cube, 'sleeper', 3).ok).toBe(false)
+  })
+})
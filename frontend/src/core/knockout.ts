@@ -0,0 +1,129 @@
+import { hasComponent, type BodyComponent, type Component } from './components'
+import { enemySeeingCell, type DetectionConfig } from './detection'
+import { isMultiTile } from './footprint'
+import { guardOf, isEnemy } from './guards'
+import type { ObjectArchetype, ResolvedObjectInstance } from './objects'
+import type { Result } from './result'
+import {
+  objectPositionAt,
+  objectsAtTime,
+  placeObjectFromTime,
+  removeObjectFromTime,
+  type CubeError,
+  type RelocationError,
+  type TimeCube,
+} from './timeCube'
+
+/** Level rules for knockouts; on levels without them guards cannot be knocked out. */
+export interface KnockoutConfig {
+  /** Slices the lockdown a found body raises lasts, starting with the one it is found in. */
+  alertTurns: number
+  /** Added to every guard's vision range during that lockdown. */
+  rangeBonus: number
+}
+
+/** A body seen in its slice by `enemyId`. */
+export interface BodySighting {
+  bodyId: string
+  enemyId: string
+}
+
+export function bodyIdFor(enemyId: string): string {
+  return `${enemyId}.body`
+}
+
+export function bodyOf(components: Component[]): BodyComponent | null {
+  for (const component of components) {
+    if (component.kind === 'Body') {
+      return component
+    }
+  }
+
+  return null
+}
+
+/** Guards can be knocked out; cameras and searchlights are machines and echoes are memories. */
+export function isKnockable(object: ResolvedObjectInstance): boolean {
+  const components = object.archetype.components
+
+  return (
+    isEnemy(object) &&
+    hasComponent(components, 'TimePersistent') &&
+    !hasComponent(components, 'Camera') &&
+    !hasComponent(components, 'Searchlight') &&
+    guardOf(components)?.guard !== 'Echo' &&
+    !isMultiTile(components)
+  )
+}
+
+/** Moved like a box, drawn where the guard was with its look. */
+function bodyArchetype(guard: ResolvedObjectInstance): ObjectArchetype {
+  return {
+    kind: 'body',
+    components: [
+      { kind: 'Body', of: guard.id },
+      { kind: 'BlocksMovement' },
+      { kind: 'Pushable' },
+      { kind: 'Pullable' },
+      { kind: 'TimePersistent' },
+      { kind: 'Appearance', glyph: 'x' },
+    ],
+    render: guard.archetype.render,
+  }
+}
+
+/**
+ * Knocks guard `enemyId` out in slice `t`: it is gone from that slice on and its body lies on the
+ * cell it stood on. Earlier slices keep the guard on its rounds.
+ */
+export function knockOut(
+  cube: TimeCube,
+  enemyId: string,
+  t: number,
+): Result<{ cube: TimeCube; bodyId: string }, CubeError | RelocationError> {
+  const guard = cube.objectsById[enemyId]
+  const position = objectPositionAt(cube, enemyId, t)
+
+  if (!guard || !position) {
+    return { ok: false, error: { kind: 'EntityNotInSlice', id: enemyId, t } }
+  }
+
+  const removed = removeObjectFromTime(cube, enemyId, t)
+
+  if (!removed.ok) {
+    return removed
+  }
+
+  const bodyId = bodyIdFor(enemyId)
+  const placed = placeObjectFromTime(removed.value, {
+    id: bodyId,
+    archetypeKey: 'body',
+    position,
+    archetype: bodyArchetype(guard),
+  })
+
+  return placed.ok ? { ok: true, value: { cube: placed.value, bodyId } } : placed
+}
+
+/** Bodies lying in slice `t` that a guard or camera sees there, each with the first to see it. */
+export function bodySightingsAt(input: {
+  cube: TimeCube
+  t: number
+  config: DetectionConfig
+  configByEnemyId?: Record<string, DetectionConfig>
+}): BodySighting[] {
+  return objectsAtTime(input.cube, input.t).flatMap((object): BodySighting[] => {
+    if (!bodyOf(object.archetype.components)) {
+      return []
+    }
+
+    const enemyId = enemySeeingCell({
+      cube: input.cube,
+      cell: object.position,
+      config: input.config,
+      configByEnemyId: input.configByEnemyId,
+    })
+
+    return enemyId ? [{ bodyId: object.id, enemyId }] : []
+  })
+}
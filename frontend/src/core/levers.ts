@@ -0,0 +1,78 @@
+import type { LeverEffect, PatrolComponent, PatrolSwitch } from './components'
+import { isPatrolPausedAt, patrolPositionAt } from './patrol'
+import type { Position2D } from './position'
+import type { Result } from './result'
+import {
+  isInSlice,
+  retraceObject,
+  type CubeError,
+  type RelocationError,
+  type TimeCube,
+} from './timeCube'
+
+export type LeverError = CubeError | RelocationError | { kind: 'NoPatrol'; id: string }
+
+function patrolOf(cube: TimeCube, id: string): PatrolComponent | null {
+  for (const component of cube.objectsById[id]?.archetype.components ?? []) {
+    if (component.kind === 'Patrol') {
+      return component
+    }
+  }
+
+  return null
+}
+
+/** Switch a lever with `effect` adds to a patrol in slice `at`; `pause` restarts a stopped one. */
+function leverSwitchFor(patrol: PatrolComponent, effect: LeverEffect, at: number): PatrolSwitch {
+  if (effect === 'reverse') {
+    return { at, kind: 'reverse' }
+  }
+
+  return { at, kind: isPatrolPausedAt(patrol, at) ? 'resume' : 'pause' }
+}
+
+/**
+ * Pulls a lever on guard `id` in slice `at`: the switch joins its patrol and only its cells from
+ * `at` on are worked out again, so earlier slices and every other object stay as they were.
+ */
+export function switchPatrolFrom(
+  cube: TimeCube,
+  id: string,
+  effect: LeverEffect,
+  at: number,
+): Result<{ cube: TimeCube; change: PatrolSwitch }, LeverError> {
+  const object = cube.objectsById[id]
+  const patrol = patrolOf(cube, id)
+
+  if (!object || !patrol) {
+    return { ok: false, error: { kind: 'NoPatrol', id } }
+  }
+
+  const change = leverSwitchFor(patrol, effect, at)
+  const switches = [...(patrol.switches ?? []), change].sort((a, b) => a.at - b.at)
+  const switched: PatrolComponent = { ...patrol, switches }
+  const trail: Position2D[] = []
+
+  for (let t = at; t < cube.timeDepth && isInSlice(cube, id, t); t += 1) {
+    trail.push(patrolPositionAt(switched, t) ?? patrol.path[0])
+  }
+
+  const rewritten: TimeCube = {
+    ...cube,
+    objectsById: {
+      ...cube.objectsById,
+      [id]: {
+        ...object,
+        archetype: {
+          ...object.archetype,
+          components: object.archetype.components.map((component) =>
+            component === patrol ? switched : component,
+          ),
+        },
+      },
+    },
+  }
+  const retraced = retraceObject(rewritten, id, at, trail)
+
+  return retraced.ok ? { ok: true, value: { cube: retraced.value, change } } : retraced
+}
@@ -0,0 +1,66 @@
+import { hasComponent, type Component, type LightSourceComponent } from './components'
+import { wrappedDistance, type Position2D, type Position3D, type Wrapping } from './position'
+import { objectsAt, objectsAtTime, wrappingOf, type TimeCube } from './timeCube'
+
+export function lightSourceOf(components: Component[]): LightSourceComponent | null {
+  for (const component of components) {
+    if (component.kind === 'LightSource') {
+      return component
+    }
+  }
+
+  return null
+}
+
+/** Lamps burn from `activeFrom` (default 0) up to but excluding `activeUntil` (default forever). */
+export function isLightActiveAt(light: LightSourceComponent, t: number): boolean {
+  return t >= (light.activeFrom ?? 0) && (light.activeUntil === undefined || t < light.activeUntil)
+}
+
+interface Lamp {
+  position: Position2D
+  radius: number
+}
+
+function lampsAt(cube: TimeCube, t: number): Lamp[] {
+  return objectsAtTime(cube, t).flatMap((object) => {
+    const light = lightSourceOf(object.archetype.components)
+
+    return light && isLightActiveAt(light, t)
+      ? [{ position: object.position, radius: light.radius }]
+      : []
+  })
+}
+
+function isLitBy(lamps: Lamp[], cell: Position2D, wrapping: Wrapping): boolean {
+  return lamps.some((lamp) => wrappedDistance(lamp.position, cell, wrapping) <= lamp.radius)
+}
+
+/** Whether any burning lamp in slice `t` reaches `cell` (Manhattan radius, not occluded). */
+export function isLitAt(cube: TimeCube, cell: Position2D, t: number): boolean {
+  return isLitBy(lampsAt(cube, t), cell, wrappingOf(cube))
+}
+
+/** Cells no lamp reaches in slice `t`, row by row. */
+export function darkCellsAt(cube: TimeCube, t: number): Position2D[] {
+  const lamps = lampsAt(cube, t)
+  const wrapping = wrappingOf(cube)
+  const cells: Position2D[] = []
+
+  for (let y = 0; y < cube.height; y += 1) {
+    for (let x = 0; x < cube.width; x += 1) {
+      if (!isLitBy(lamps, { x, y }, wrapping)) {
+        cells.push({ x, y })
+      }
+    }
+  }
+
+  return cells
+}
+
+/** Whether a `Shadow` tile covers `position`; no guard, camera or searchlight sees into it. */
+export function isShadowedAt(cube: TimeCube, position: Position3D): boolean {
+  return objectsAt(cube, position).some((object) =>
+    hasComponent(object.archetype.components, 'Shadow'),
+  )
+}
@@ -0,0 +1,130 @@
+import { describe, expect, it } from 'vitest'
+
+import { propagatePatrolDetours } from './detour'
+import { DEFAULT_LURE_SETTINGS, propagateLures } from './lure'
+import type { ResolvedObjectInstance } from './objects'
+import type { Position2D } from './position'
+import { createTimeCube, objectPositionAt, placeObjects, type TimeCube } from './timeCube'
+
+const CORRIDOR = [
+  { x: 1, y: 1 },
+  { x: 2, y: 1 },
+  { x: 3, y: 1 },
+  { x: 4, y: 1 },
+  { x: 5, y: 1 },
+]
+
+const LOOP = [
+  { x: 1, y: 1 },
+  { x: 2, y: 1 },
+  { x: 3, y: 1 },
+  { x: 3, y: 2 },
+  { x: 2, y: 2 },
+  { x: 1, y: 2 },
+]
+
+function guard(path: Position2D[], loops: boolean): ResolvedObjectInstance {
+  return {
+    id: 'enemy.alpha',
+    archetypeKey: 'enemy',
+    position: { x: 1, y: 1, t: 0 },
+    archetype: {
+      kind: 'enemy',
+      components: [
+        { kind: 'BlocksMovement' },
+        { kind: 'TimePersistent' },
+        { kind: 'Patrol', path, loops },
+      ],
+      render: {},
+    },
+  }
+}
+
+function block(id: string, x: number, y: number): ResolvedObjectInstance {
+  return {
+    id,
+    archetypeKey: 'box',
+    position: { x, y, t: 0 },
+    archetype: {
+      kind: 'box',
+      components: [{ kind: 'BlocksMovement' }, { kind: 'TimePersistent' }],
+      render: {},
+    },
+  }
+}
+
+function guardTrail(cube: TimeCube) {
+  return Array.from({ length: cube.timeDepth }, (_, t) => {
+    const position = objectPositionAt(cube, 'enemy.alpha', t)
+    return position ? { x: position.x, y: position.y } : null
+  })
+}
+
+describe('lure propagation', () => {
+  it('keeps a guard waiting at a box on its route after a lure elsewhere', () => {
+    // The box at (4, 1) closes the corridor; the lure lands behind the guard, away from it.
+    const placed = placeObjects(createTimeCube(7, 3, 8), [
+      guard(CORRIDOR, false),
+      block('box.a', 4, 1),
+      block('wall.n', 4, 0),
+      block('wall.s', 4, 2),
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const lured = propagateLures({
+      cube: placed.value,
+      lures: [{ id: 'lure.0', position: { x: 0, y: 1, t: 1 } }],
+      settings: { ...DEFAULT_LURE_SETTINGS, duration: 1 },
+    })
+
+    expect(lured.ok).toBe(true)
+    if (!lured.ok) {
+      return
+    }
+
+    expect(guardTrail(lured.value.cube)).toEqual([
+      { x: 1, y: 1 },
+      { x: 0, y: 1 },
+      { x: 1, y: 1 },
+      { x: 2, y: 1 },
+      { x: 3, y: 1 },
+      { x: 3, y: 1 },
+      { x: 3, y: 1 },
+      { x: 3, y: 1 },
+    ])
+  })
+
+  it('keeps the detour of a guard no lure reaches', () => {
+    const placed = placeObjects(createTimeCube(6, 6, 8), [guard(LOOP, true), block('box.a', 3, 1)])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const detoured = propagatePatrolDetours(placed.value)
+
+    expect(detoured.ok).toBe(true)
+    if (!detoured.ok) {
+      return
+    }
+
+    const lured = propagateLures({
+      cube: detoured.value,
+      lures: [{ id: 'lure.0', position: { x: 5, y: 5, t: 1 }, radius: 1 }],
+      settings: DEFAULT_LURE_SETTINGS,
+    })
+
+    expect(lured.ok).toBe(true)
+    if (!lured.ok) {
+      return
+    }
+
+    expect(guardTrail(lured.value.cube)).toEqual(guardTrail(detoured.value))
+    expect(guardTrail(lured.value.cube)[2]).toEqual({ x: 2, y: 2 })
+  })
+})
@@ -0,0 +1,268 @@
+import { isFreeFor, stepToward, walkableIds } from './chase'
+import { hasComponent, type PatrolComponent } from './components'
+import type { ResolvedObjectInstance } from './objects'
+import { patrolPositionAt, type PatrolRoute } from './patrol'
+import {
+  isInBounds,
+  movePosition,
+  wrappedDistance,
+  wrapPosition,
+  type Direction2D,
+  type Position2D,
+  type Position3D,
+} from './position'
+import {
+  propagationWarningFor,
+  type GuardPropagation,
+  type PropagationWarning,
+} from './propagation'
+import type { Result } from './result'
+import {
+  allObjects,
+  isBlocked,
+  isInSlice,
+  objectPositionAt,
+  retraceObject,
+  wrappingOf,
+  type CubeError,
+  type RelocationError,
+  type TimeCube,
+} from './timeCube'
+
+export interface LureSettings {
+  /** Lures the player starts the level with; each throw consumes one. */
+  supply: number
+  /** Furthest a lure can be thrown, in cells. */
+  maxRange: number
+  /** Guards within this Manhattan distance of the landing cell hear it land. */
+  radius: number
+  /** Slices a distracted guard keeps walking toward the lure. */
+  duration: number
+}
+
+export const DEFAULT_LURE_SETTINGS: LureSettings = {
+  supply: 0,
+  maxRange: 4,
+  radius: 4,
+  duration: 4,
+}
+
+/** Hearing range of a blow on a breakable wall unless the rules set `breakNoiseRadius`. */
+export const DEFAULT_BREAK_NOISE_RADIUS = 6
+
+/**
+ * A lure lying on the board from `position.t` onward. Noises (a wall giving way) are heard the
+ * same way but only within their own `radius`.
+ */
+export interface ThrownLure {
+  id: string
+  position: Position3D
+  radius?: number
+}
+
+export interface LureResources {
+  remaining: number
+  thrown: ThrownLure[]
+}
+
+export interface LurePropagationInput {
+  cube: TimeCube
+  lures: ThrownLure[]
+  settings: LureSettings
+}
+
+interface Distraction {
+  lure: ThrownLure
+  until: number
+}
+
+interface Resume {
+  cell: Position2D
+  routeTime: number
+}
+
+/**
+ * Cell a lure thrown from `from` comes to rest on: it flies up to `range` cells in `direction`
+ * through slice `from.t` and drops in front of the first wall or the board edge. Null when the
+ * adjacent cell is already blocked.
+ */
+export function lureLandingCell(
+  cube: TimeCube,
+  from: Position3D,
+  direction: Direction2D,
+  range: number,
+): Position3D | null {
+  let landing: Position3D | null = null
+  let cursor: Position2D = { x: from.x, y: from.y }
+
+  for (let step = 0; step < range; step += 1) {
+    cursor = wrapPosition(movePosition(cursor, direction), wrappingOf(cube))
+    const cell = { x: cursor.x, y: cursor.y, t: from.t }
+
+    if (!isInBounds(cursor, cube.width, cube.height) || isBlocked(cube, cell)) {
+      break
+    }
+
+    landing = cell
+  }
+
+  return landing
+}
+
+/**
+ * Route a guard walks when nothing distracts it. Patrols follow their waypoints; other enemies
+ * stand on their t=0 cell. Sentries, cameras, searchlights and chasers are never lured.
+ */
+export function lurableRoute(object: ResolvedObjectInstance): PatrolRoute | null {
+  const components = object.archetype.components
+
+  if (
+    !hasComponent(components, 'TimePersistent') ||
+    hasComponent(components, 'Camera') ||
+    hasComponent(components, 'Searchlight') ||
+    components.some(
+      (component) => component.kind === 'Guard' && component.guard !== 'Patroller',
+    )
+  ) {
+    return null
+  }
+
+  const patrol = components.find(
+    (component): component is PatrolComponent => component.kind === 'Patrol',
+  )
+
+  if (patrol) {
+    return patrol
+  }
+
+  if (object.archetype.kind !== 'enemy') {
+    return null
+  }
+
+  return { path: [{ x: object.position.x, y: object.position.y }], loops: true }
+}
+
+/**
+ * Full trajectory from t=1 while the guard is in the cube. A lure landing at `L` within `radius`
+ * of the guard pulls it one cell per slice toward the landing cell for `duration` slices;
+ * afterwards it walks back to where it left its route and resumes from that waypoint, so the
+ * whole patrol runs late from then on. Null when no lure reaches the guard, so the trajectory
+ * already in the cube (detours included) is kept.
+ */
+function lureTrail(
+  input: LurePropagationInput,
+  cube: TimeCube,
+  id: string,
+  route: PatrolRoute,
+): Position2D[] | null {
+  const start = objectPositionAt(cube, id, 0)
+
+  if (!start) {
+    return null
+  }
+
+  let position: Position2D = { x: start.x, y: start.y }
+  let offset = 0
+  let distraction: Distraction | null = null
+  let resume: Resume | null = null
+  let lured = false
+  const path: Position2D[] = []
+
+  for (let t = 1; t < cube.timeDepth && isInSlice(cube, id, t); t += 1) {
+    for (const lure of input.lures) {
+      if (
+        lure.position.t === t &&
+        wrappedDistance(position, lure.position, wrappingOf(cube)) <=
+          (lure.radius ?? input.settings.radius)
+      ) {
+        resume = resume ?? { cell: position, routeTime: t - 1 - offset }
+        distraction = { lure, until: t + input.settings.duration }
+        lured = true
+      }
+    }
+
+    if (distraction && t >= distraction.until) {
+      distraction = null
+    }
+
+    let next: Position2D | null
+
+    if (distraction) {
+      next = stepToward(cube, id, position, distraction.lure.position, t)
+    } else if (resume) {
+      next = stepToward(cube, id, position, resume.cell, t)
+
+      if (next && next.x === resume.cell.x && next.y === resume.cell.y) {
+        offset = t - resume.routeTime
+        resume = null
+      }
+    } else {
+      const scheduled = patrolPositionAt(route, t - offset) ?? position
+
+      if (isFreeFor(cube, id, scheduled, t)) {
+        next = scheduled
+      } else {
+        // Waiting for the cell to clear: the route resumes a slice later, never skipping it.
+        next = stepToward(cube, id, position, position, t)
+        offset += 1
+      }
+    }
+
+    if (!next) {
+      break
+    }
+
+    path.push(next)
+    position = next
+  }
+
+  return lured ? path : null
+}
+
+/**
+ * Re-resolves every lurable guard against all lures thrown so far. Trajectories of guards a lure
+ * reaches are rebuilt from their undisturbed route every turn, so the result only depends on the
+ * lure list; guards no lure reaches keep their detoured trajectory. A trajectory that cannot be
+ * written into the cube is skipped with a warning, as for chasers.
+ */
+export function propagateLures(
+  input: LurePropagationInput,
+): Result<GuardPropagation, CubeError | RelocationError> {
+  if (input.lures.length === 0) {
+    return { ok: true, value: { cube: input.cube, warnings: [] } }
+  }
+
+  let cube = input.cube
+  const warnings: PropagationWarning[] = []
+
+  for (const object of allObjects(input.cube)) {
+    const route = lurableRoute(object)
+
+    if (!route) {
+      continue
+    }
+
+    const trail = lureTrail(input, cube, object.id, route)
+
+    if (!trail) {
+      continue
+    }
+
+    const retraced = retraceObject(cube, object.id, 1, trail, walkableIds(cube))
+
+    if (!retraced.ok) {
+      const warning = propagationWarningFor(object.id, retraced.error)
+
+      if (!warning) {
+        return retraced
+      }
+
+      warnings.push(warning)
+      continue
+    }
+
+    cube = retraced.value
+  }
+
+  return { ok: true, value: { cube, warnings } }
+}
@@ -1,6 +1,11 @@
 import { describe, expect, it } from 'vitest'
 
-import { createObjectRegistry, resolveArchetype, resolveObjectInstance } from './objects'
+import {
+  createObjectRegistry,
+  resolveArchetype,
+  resolveObjectInstance,
+  resolveObjectRender,
+} from './objects'
 
 describe('object registry', () => {
   it('resolves known archetypes', () => {
@@ -57,4 +62,14 @@ describe('object registry', () => {
       expect(resolved.value.archetype.render.glyph).toBe('B')
     }
   })
+
+  it('lays an Appearance glyph and color over the archetype render', () => {
+    const render = resolveObjectRender({
+      kind: 'enemy',
+      components: [{ kind: 'BlocksMovement' }, { kind: 'Appearance', glyph: 'S', color: '#a33' }],
+      render: { fill: '#ccc', stroke: '#111', symbol: 'enemy' },
+    })
+
+    expect(render).toEqual({ fill: '#a33', stroke: '#111', symbol: 'enemy', glyph: 'S' })
+  })
 })
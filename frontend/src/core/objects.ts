@@ -1,5 +1,6 @@
 import type { Position3D } from './position'
-import type { Component } from './components'
+import { appearanceOf, type Component } from './components'
+import type { Region } from './regions'
 import type { Result } from './result'
 
 export interface ObjectRender {
@@ -33,11 +34,21 @@ export interface ResolvedObjectInstance {
   archetypeKey: string
   position: Position3D
   archetype: ObjectArchetype
+  /** Slice `removeObjectFromTime` took the object out from (broken, sunk, knocked out, unmade). */
+  removedFrom?: number
+  /** Source whose absence unmade this dependent; cleared once its dependency restores it. */
+  unmadeBy?: string
 }
 
 export interface LevelObjectsConfig {
   archetypes: Record<string, ObjectArchetype>
   instances: ObjectInstance[]
+  regions?: Region[]
+  /** Makes the cube a time loop; see `TimeCube.timeWraps`. */
+  timeWraps?: boolean
+  /** Makes the map a torus along x and/or y; see `TimeCube.xWraps`. */
+  xWraps?: boolean
+  yWraps?: boolean
 }
 
 export function createObjectRegistry(archetypes: Record<string, ObjectArchetype>): ObjectRegistry {
@@ -95,3 +106,18 @@ export function resolveObjectInstance(
     },
   }
 }
+
+/** Archetype render with an `Appearance` component's glyph and color laid over it. */
+export function resolveObjectRender(archetype: ObjectArchetype): ObjectRender {
+  const appearance = appearanceOf(archetype.components)
+
+  if (!appearance) {
+    return archetype.render
+  }
+
+  return {
+    ...archetype.render,
+    ...(appearance.glyph !== undefined ? { glyph: appearance.glyph } : {}),
+    ...(appearance.color !== undefined ? { fill: appearance.color } : {}),
+  }
+}
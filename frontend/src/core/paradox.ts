@@ -170,13 +170,15 @@ export function mergeCausalAnchors(input: {
 
 function isAnchorSatisfied(
   cube: TimeCube,
-  worldLine: WorldLineState,
+  worldLines: WorldLineState[],
   anchor: CausalAnchor,
 ): ParadoxViolation | null {
   const requirement = anchor.requirement
 
   if (requirement.kind === 'PlayerAt') {
-    if (!worldLine.visited[positionKey(requirement.position)]) {
+    const key = positionKey(requirement.position)
+
+    if (!worldLines.some((worldLine) => worldLine.visited[key])) {
       return {
         anchorId: anchor.id,
         requirement,
@@ -213,12 +215,15 @@ function isAnchorSatisfied(
 export function evaluateParadoxV1(input: {
   cube: TimeCube
   worldLine: WorldLineState
+  /** World lines of the other characters; a `PlayerAt` anchor holds if any character was there. */
+  otherWorldLines?: WorldLineState[]
   anchors: CausalAnchor[]
   anchorsByTime?: CausalAnchorIndexByTime
   checkedFromTime: number
   config: ParadoxConfig
 }): ParadoxReport {
   const { cube, worldLine, anchors, anchorsByTime, checkedFromTime, config } = input
+  const worldLines = [worldLine, ...(input.otherWorldLines ?? [])]
 
   if (!config.enabled) {
     return {
@@ -237,7 +242,7 @@ export function evaluateParadoxV1(input: {
   const violations: ParadoxViolation[] = []
 
   for (const anchor of filteredAnchors) {
-    const violation = isAnchorSatisfied(cube, worldLine, anchor)
+    const violation = isAnchorSatisfied(cube, worldLines, anchor)
 
     if (violation) {
       violations.push(violation)
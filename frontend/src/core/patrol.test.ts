@@ -0,0 +1,65 @@
+import { describe, expect, it } from 'vitest'
+
+import {
+  isPatrolPausedAt,
+  patrolPositionAt,
+  rollPatrolHolds,
+  type PatrolRoute,
+} from './patrol'
+import { createRng } from './rng'
+
+const route: PatrolRoute = {
+  path: [
+    { x: 0, y: 0 },
+    { x: 1, y: 0 },
+    { x: 2, y: 0 },
+  ],
+  loops: false,
+}
+
+describe('patrol hesitation', () => {
+  it('keeps the previous cell on a hold and delays the rest of the route', () => {
+    const held = { ...route, holds: [2] }
+
+    expect([0, 1, 2, 3, 4].map((t) => patrolPositionAt(held, t)?.x)).toEqual([0, 1, 1, 2, 1])
+  })
+
+  it('rolls the same holds for the same seed, only after arrivals', () => {
+    const holds = rollPatrolHolds(route, 0.5, createRng('level:enemy.a'), 40)
+
+    expect(holds).toEqual(rollPatrolHolds(route, 0.5, createRng('level:enemy.a'), 40))
+    expect(holds.length).toBeGreaterThan(0)
+    expect(holds.every((hold, index) => index === 0 || hold - holds[index - 1] >= 2)).toBe(true)
+    expect(rollPatrolHolds(route, 0, createRng('level:enemy.a'), 40)).toEqual([])
+  })
+
+  it('holds at every arrival when hesitation is certain', () => {
+    const dwelling = { ...route, dwell: [2, 1, 1] }
+
+    expect(rollPatrolHolds(dwelling, 1, createRng('x'), 8)).toEqual([1, 4, 6])
+  })
+})
+
+describe('patrol lever switches', () => {
+  it('walks the route backwards from a reverse switch on', () => {
+    const reversed = { ...route, switches: [{ at: 2, kind: 'reverse' as const }] }
+
+    expect([0, 1, 2, 3, 4].map((t) => patrolPositionAt(reversed, t)?.x)).toEqual([0, 1, 0, 1, 2])
+  })
+
+  it('stands still between a pause and the resume after it', () => {
+    const paused = {
+      ...route,
+      switches: [
+        { at: 2, kind: 'pause' as const },
+        { at: 4, kind: 'resume' as const },
+      ],
+    }
+
+    expect([0, 1, 2, 3, 4, 5].map((t) => patrolPositionAt(paused, t)?.x)).toEqual([
+      0, 1, 1, 1, 2, 1,
+    ])
+    expect(isPatrolPausedAt(paused, 3)).toBe(true)
+    expect(isPatrolPausedAt(paused, 4)).toBe(false)
+  })
+})
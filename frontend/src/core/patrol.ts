@@ -0,0 +1,290 @@
+import type { PatrolComponent } from './components'
+import {
+  movePosition,
+  NO_WRAPPING,
+  wrappedDistance,
+  wrapPosition,
+  type Direction2D,
+  type Position2D,
+  type Wrapping,
+} from './position'
+import type { Result } from './result'
+import { rollChance, type RngState } from './rng'
+
+export type PatrolRoute = Pick<PatrolComponent, 'path' | 'loops' | 'dwell' | 'holds' | 'switches'>
+
+function modulo(value: number, divisor: number): number {
+  return ((value % divisor) + divisor) % divisor
+}
+
+/** Waypoint indices visited in one period: forward for loops, forward then back for ping-pong. */
+function visitOrder(length: number, loops: boolean): number[] {
+  const forward = Array.from({ length }, (_, index) => index)
+
+  if (loops || length < 2) {
+    return forward
+  }
+
+  return [...forward, ...forward.slice(1, -1).reverse()]
+}
+
+interface RouteSchedule {
+  visits: number[]
+  durations: number[]
+  period: number
+}
+
+function routeSchedule(route: PatrolRoute): RouteSchedule {
+  const visits = visitOrder(route.path.length, route.loops)
+  const durations = visits.map((index) => Math.max(1, route.dwell?.[index] ?? 1))
+
+  return { visits, durations, period: durations.reduce((sum, duration) => sum + duration, 0) }
+}
+
+/**
+ * Route time once the hesitations up to slice `t` have delayed it. Lever switches make it run
+ * backwards or stand still from their slice on, so routes with any are walked slice by slice.
+ */
+function routeTimeAt(route: PatrolRoute, t: number): number {
+  if (!route.switches || route.switches.length === 0 || t <= 0) {
+    return t - (route.holds?.filter((hold) => hold <= t).length ?? 0)
+  }
+
+  let time = 0
+  let direction = 1
+  let paused = false
+
+  for (let slice = 1; slice <= t; slice += 1) {
+    for (const change of route.switches) {
+      if (change.at !== slice) {
+        continue
+      }
+
+      if (change.kind === 'reverse') {
+        direction = -direction
+      } else {
+        paused = change.kind === 'pause'
+      }
+    }
+
+    if (!paused && !route.holds?.includes(slice)) {
+      time += direction
+    }
+  }
+
+  return time
+}
+
+/** Whether lever switches up to slice `t` left the patrol standing still. */
+export function isPatrolPausedAt(route: PatrolRoute, t: number): boolean {
+  const last = route.switches
+    ?.filter((change) => change.kind !== 'reverse' && change.at <= t)
+    .at(-1)
+
+  return last?.kind === 'pause'
+}
+
+/**
+ * Cell of a patrol at time `t`. Each visit to waypoint `i` lasts `dwell[i]` slices (default 1),
+ * so a guard can linger at posts; the whole route repeats with a fixed period, shifted one slice
+ * later by every hold and turned or stopped by lever switches. Returns null for an empty path.
+ */
+export function patrolPositionAt(route: PatrolRoute, t: number): Position2D | null {
+  if (route.path.length === 0) {
+    return null
+  }
+
+  const { visits, durations, period } = routeSchedule(route)
+  let offset = modulo(routeTimeAt(route, t), period)
+
+  for (let visit = 0; visit < visits.length; visit += 1) {
+    if (offset < durations[visit]) {
+      return route.path[visits[visit]]
+    }
+
+    offset -= durations[visit]
+  }
+
+  return route.path[visits[visits.length - 1]]
+}
+
+/** True when route time `t` is the first slice of a waypoint visit. */
+function startsVisit(schedule: RouteSchedule, t: number): boolean {
+  let offset = modulo(t, schedule.period)
+
+  for (const duration of schedule.durations) {
+    if (offset === 0) {
+      return true
+    }
+
+    if (offset < duration) {
+      return false
+    }
+
+    offset -= duration
+  }
+
+  return false
+}
+
+/**
+ * Hesitation slices for a guard over the first `timeDepth` slices: on each waypoint arrival it
+ * lingers one extra slice with probability `chance`, rolled in order from `rng`. The same route,
+ * chance and seed always give the same holds, so they can be baked into the cube at load.
+ */
+export function rollPatrolHolds(
+  route: PatrolRoute,
+  chance: number,
+  rng: RngState,
+  timeDepth: number,
+): number[] {
+  if (route.path.length === 0 || chance <= 0) {
+    return []
+  }
+
+  const schedule = routeSchedule(route)
+  const holds: number[] = []
+  let state = rng
+
+  for (let t = 0; t < timeDepth - 1; t += 1) {
+    if (holds.at(-1) === t || !startsVisit(schedule, t - holds.length)) {
+      continue
+    }
+
+    const roll = rollChance(state, chance)
+    state = roll.rng
+
+    if (roll.value) {
+      holds.push(t + 1)
+    }
+  }
+
+  return holds
+}
+
+/** A step of a patrol route that jumps more than one cell in a single slice. */
+export interface PatrolGap {
+  /** Index of the waypoint the jump starts from; the loop's closing step starts at the last one. */
+  index: number
+  from: Position2D
+  to: Position2D
+}
+
+/**
+ * Consecutive waypoints that are not the same or orthogonally adjacent cells, including the
+ * step from the last waypoint back to the first for loops. Guards move one waypoint per slice,
+ * so any gap is a teleport. On torus maps a step across a wrapping edge is adjacent.
+ */
+export function patrolRouteGaps(
+  route: Pick<PatrolRoute, 'path' | 'loops'>,
+  wrapping: Wrapping = NO_WRAPPING,
+): PatrolGap[] {
+  const gaps: PatrolGap[] = []
+  const stepCount = route.loops && route.path.length > 1 ? route.path.length : route.path.length - 1
+
+  for (let index = 0; index < stepCount; index += 1) {
+    const from = route.path[index]
+    const to = route.path[(index + 1) % route.path.length]
+
+    if (wrappedDistance(from, to, wrapping) > 1) {
+      gaps.push({ index, from, to })
+    }
+  }
+
+  return gaps
+}
+
+const SEARCH_ORDER: Direction2D[] = ['north', 'east', 'south', 'west']
+
+function cellKey(cell: Position2D): string {
+  return `${cell.x},${cell.y}`
+}
+
+/** Cells strictly between `from` and `to` on a shortest walkable route, or null if none exists. */
+function stepsBetween(
+  from: Position2D,
+  to: Position2D,
+  walkable: (cell: Position2D) => boolean,
+  wrapping: Wrapping,
+): Position2D[] | null {
+  if (wrappedDistance(from, to, wrapping) <= 1) {
+    return []
+  }
+
+  const previous = new Map<string, Position2D | null>([[cellKey(from), null]])
+  const queue: Position2D[] = [from]
+
+  for (let head = 0; head < queue.length; head += 1) {
+    const cell = queue[head]
+
+    for (const direction of SEARCH_ORDER) {
+      const next = wrapPosition(movePosition(cell, direction), wrapping)
+      const key = cellKey(next)
+
+      if (previous.has(key) || !walkable(next)) {
+        continue
+      }
+
+      previous.set(key, cell)
+
+      if (key === cellKey(to)) {
+        const between: Position2D[] = []
+        let step = previous.get(key) ?? null
+
+        while (step && cellKey(step) !== cellKey(from)) {
+          between.unshift(step)
+          step = previous.get(cellKey(step)) ?? null
+        }
+
+        return between
+      }
+
+      queue.push(next)
+    }
+  }
+
+  return null
+}
+
+/**
+ * Fills every gap of a route with the shortest walkable cells between its waypoints (searched
+ * north, east, south, west, so the result is stable). Inserted cells get a dwell of 1 and the
+ * authored waypoints keep theirs. `walkable` must also reject cells outside the map; on torus
+ * maps the search crosses wrapping edges. Fails with the first step that has no walkable
+ * connection.
+ */
+export function expandPatrolRoute(
+  route: PatrolRoute,
+  walkable: (cell: Position2D) => boolean,
+  wrapping: Wrapping = NO_WRAPPING,
+): Result<PatrolRoute, PatrolGap> {
+  const path: Position2D[] = []
+  const dwell: number[] = []
+  const stepCount = route.loops && route.path.length > 1 ? route.path.length : route.path.length - 1
+
+  for (let index = 0; index < route.path.length; index += 1) {
+    const from = route.path[index]
+
+    path.push(from)
+    dwell.push(route.dwell?.[index] ?? 1)
+
+    if (index >= stepCount) {
+      continue
+    }
+
+    const to = route.path[(index + 1) % route.path.length]
+    const between = stepsBetween(from, to, walkable, wrapping)
+
+    if (!between) {
+      return { ok: false, error: { index, from, to } }
+    }
+
+    path.push(...between)
+    dwell.push(...between.map(() => 1))
+  }
+
+  return {
+    ok: true,
+    value: route.dwell ? { path, loops: route.loops, dwell } : { path, loops: route.loops },
+  }
+}
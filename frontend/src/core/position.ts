@@ -36,3 +36,77 @@ export function isInBounds(position: Position2D, width: number, height = width):
 export function manhattanDistance(a: Position2D, b: Position2D): number {
   return Math.abs(a.x - b.x) + Math.abs(a.y - b.y)
 }
+
+/**
+ * Axes a level wraps around: each entry is the axis size when it wraps (torus maps, time loops)
+ * and null when the axis has hard edges.
+ */
+export interface Wrapping {
+  width: number | null
+  height: number | null
+  timeDepth: number | null
+}
+
+export const NO_WRAPPING: Wrapping = { width: null, height: null, timeDepth: null }
+
+function wrapAxis(value: number, size: number | null): number {
+  return size === null ? value : ((value % size) + size) % size
+}
+
+/** Fold a position back onto the map along wrapping axes; other axes are left as they are. */
+export function wrapPosition<P extends Position2D>(position: P, wrapping: Wrapping): P {
+  return {
+    ...position,
+    x: wrapAxis(position.x, wrapping.width),
+    y: wrapAxis(position.y, wrapping.height),
+  }
+}
+
+/** Signed step count from `from` to `to` along one axis, the short way round when it wraps. */
+export function wrappedAxisDelta(from: number, to: number, size: number | null): number {
+  const delta = to - from
+
+  if (size === null || Math.abs(delta) * 2 <= size) {
+    return delta
+  }
+
+  return delta > 0 ? delta - size : delta + size
+}
+
+export function wrappedDistance(a: Position2D, b: Position2D, wrapping: Wrapping): number {
+  return (
+    Math.abs(wrappedAxisDelta(a.x, b.x, wrapping.width)) +
+    Math.abs(wrappedAxisDelta(a.y, b.y, wrapping.height))
+  )
+}
+
+/** The copy of `to` closest to `from`, which may lie just off the map on a torus. */
+export function nearestWrappedCopy(from: Position2D, to: Position2D, wrapping: Wrapping): Position2D {
+  return {
+    x: from.x + wrappedAxisDelta(from.x, to.x, wrapping.width),
+    y: from.y + wrappedAxisDelta(from.y, to.y, wrapping.height),
+  }
+}
+
+/**
+ * Direction of a step from `from` to `to`, the short way round on a torus. Diagonal steps take
+ * the wider axis (x on a tie); null when the two cells are the same.
+ */
+export function stepDirection(
+  from: Position2D,
+  to: Position2D,
+  wrapping: Wrapping = NO_WRAPPING,
+): Direction2D | null {
+  const dx = wrappedAxisDelta(from.x, to.x, wrapping.width)
+  const dy = wrappedAxisDelta(from.y, to.y, wrapping.height)
+
+  if (dx === 0 && dy === 0) {
+    return null
+  }
+
+  if (Math.abs(dx) >= Math.abs(dy)) {
+    return dx > 0 ? 'east' : 'west'
+  }
+
+  return dy > 0 ? 'south' : 'north'
+}
@@ -0,0 +1,34 @@
+import { describe, expect, it } from 'vitest'
+
+import { formatPropagationWarning, propagationWarningFor } from './propagation'
+
+describe('propagationWarningFor', () => {
+  it('turns a blocked cell into a collision with the blocker', () => {
+    const warning = propagationWarningFor('enemy.alpha', {
+      kind: 'TargetOccupied',
+      id: 'box.main',
+      x: 8,
+      y: 6,
+      t: 3,
+    })
+
+    expect(warning).toEqual({
+      kind: 'EntityCollision',
+      id: 'enemy.alpha',
+      blockerId: 'box.main',
+      position: { x: 8, y: 6, t: 3 },
+    })
+    expect(warning && formatPropagationWarning(warning)).toBe(
+      'enemy.alpha would walk into box.main at (8,6,t=3)',
+    )
+  })
+
+  it('keeps off-map cells and leaves other errors to fail the pass', () => {
+    const warning = propagationWarningFor('enemy.alpha', { kind: 'OutOfBounds', x: -1, y: 2, t: 4 })
+
+    expect(warning && formatPropagationWarning(warning)).toBe(
+      'enemy.alpha would leave the map at (-1,2,t=4)',
+    )
+    expect(propagationWarningFor('enemy.alpha', { kind: 'EntityNotFound', id: 'x' })).toBeNull()
+  })
+})
@@ -0,0 +1,44 @@
+import type { Position3D } from './position'
+import type { CubeError, RelocationError, TimeCube } from './timeCube'
+
+/**
+ * A guard whose re-resolved trajectory could not be written into the cube. The guard keeps the
+ * cells it had before the pass, and the pass carries on with the others.
+ */
+export type PropagationWarning =
+  | { kind: 'EntityCollision'; id: string; blockerId: string; position: Position3D }
+  | { kind: 'OutOfBounds'; id: string; position: Position3D }
+
+/** Result of a pass that re-resolves guard trajectories (lures, chasers). */
+export interface GuardPropagation {
+  cube: TimeCube
+  warnings: PropagationWarning[]
+}
+
+/** Collisions and off-map cells become warnings; null for errors that still fail the pass. */
+export function propagationWarningFor(
+  id: string,
+  error: CubeError | RelocationError,
+): PropagationWarning | null {
+  switch (error.kind) {
+    case 'TargetOccupied':
+      return {
+        kind: 'EntityCollision',
+        id,
+        blockerId: error.id,
+        position: { x: error.x, y: error.y, t: error.t },
+      }
+    case 'OutOfBounds':
+      return { kind: 'OutOfBounds', id, position: { x: error.x, y: error.y, t: error.t } }
+    default:
+      return null
+  }
+}
+
+export function formatPropagationWarning(warning: PropagationWarning): string {
+  const { x, y, t } = warning.position
+
+  return warning.kind === 'EntityCollision'
+    ? `${warning.id} would walk into ${warning.blockerId} at (${x},${y},t=${t})`
+    : `${warning.id} would leave the map at (${x},${y},t=${t})`
+}
@@ -0,0 +1,22 @@
+import { describe, expect, it } from 'vitest'
+
+import { regionForDisplay, regionsAt, type Region } from './regions'
+
+const courtyard: Region = { id: 'courtyard', name: 'Courtyard', x: 0, y: 0, width: 6, height: 4 }
+const vault: Region = { id: 'vault', name: 'Vault', x: 4, y: 2, width: 2, height: 2 }
+
+describe('regions', () => {
+  it('finds every region covering a cell, excluding the far edges', () => {
+    expect(regionsAt([courtyard, vault], { x: 5, y: 3 }).map((region) => region.id)).toEqual([
+      'courtyard',
+      'vault',
+    ])
+    expect(regionsAt([courtyard, vault], { x: 6, y: 3 })).toEqual([])
+  })
+
+  it('shows the innermost region', () => {
+    expect(regionForDisplay([vault, courtyard], { x: 4, y: 2 })).toBe(vault)
+    expect(regionForDisplay([courtyard, vault], { x: 1, y: 1 })).toBe(courtyard)
+    expect(regionForDisplay([courtyard], { x: 9, y: 9 })).toBeNull()
+  })
+})
@@ -0,0 +1,41 @@
+import type { Position2D } from './position'
+
+/** Named rectangle of the map ("courtyard", "vault"); the same in every slice. */
+export interface Region {
+  id: string
+  name: string
+  x: number
+  y: number
+  width: number
+  height: number
+}
+
+export function regionContains(region: Region, position: Position2D): boolean {
+  return (
+    position.x >= region.x &&
+    position.y >= region.y &&
+    position.x < region.x + region.width &&
+    position.y < region.y + region.height
+  )
+}
+
+/** Regions covering `position`, in declaration order; regions may overlap or nest. */
+export function regionsAt(regions: Region[], position: Position2D): Region[] {
+  return regions.filter((region) => regionContains(region, position))
+}
+
+/**
+ * Innermost region at `position` for display: the smallest one, and of equal areas the one
+ * declared last. Null outside every region.
+ */
+export function regionForDisplay(regions: Region[], position: Position2D): Region | null {
+  let best: Region | null = null
+
+  for (const region of regionsAt(regions, position)) {
+    if (!best || region.width * region.height <= best.width * best.height) {
+      best = region
+    }
+  }
+
+  return best
+}
@@ -1,6 +1,8 @@
 import { describe, expect, it } from 'vitest'
 
-import { resolveRift, type ResolveRiftInput } from './rift'
+import { createObjectRegistry, resolveObjectInstance, type ObjectInstance } from './objects'
+import { resolveRift, riftLinksAt, type ResolveRiftInput } from './rift'
+import { createTimeCube, placeObjects, type TimeCube } from './timeCube'
 
 function baseInput(): ResolveRiftInput {
   return {
@@ -56,3 +58,56 @@ describe('resolveRift', () => {
     }
   })
 })
+
+const registry = createObjectRegistry({
+  oneWay: {
+    kind: 'rift',
+    components: [{ kind: 'Rift', target: { x: 3, y: 0, t: 2 }, bidirectional: false }],
+    render: {},
+  },
+  twoWay: {
+    kind: 'rift',
+    components: [{ kind: 'Rift', target: { x: 3, y: 3, t: 2 }, bidirectional: true }],
+    render: {},
+  },
+})
+
+function cubeWith(instances: ObjectInstance[]): TimeCube {
+  const resolved = instances.map((instance) => {
+    const result = resolveObjectInstance(registry, instance)
+    if (!result.ok) {
+      throw new Error('resolution failed in test fixture')
+    }
+
+    return result.value
+  })
+  const placed = placeObjects(createTimeCube(4, 4, 4), resolved)
+  if (!placed.ok) {
+    throw new Error('placement failed in test fixture')
+  }
+
+  return placed.value
+}
+
+describe('riftLinksAt', () => {
+  const cube = cubeWith([
+    { id: 'rift.oneWay', archetype: 'oneWay', position: { x: 0, y: 0, t: 0 } },
+    { id: 'rift.twoWay', archetype: 'twoWay', position: { x: 0, y: 3, t: 0 } },
+  ])
+
+  it('offers the forward link when standing on a rift tile', () => {
+    expect(riftLinksAt(cube, { x: 0, y: 3, t: 0 })).toEqual([
+      { objectId: 'rift.twoWay', target: { x: 3, y: 3, t: 2 }, reverse: false },
+    ])
+  })
+
+  it('offers travel back from the landing cell of a bidirectional rift', () => {
+    expect(riftLinksAt(cube, { x: 3, y: 3, t: 2 })).toEqual([
+      { objectId: 'rift.twoWay', target: { x: 0, y: 3, t: 0 }, reverse: true },
+    ])
+  })
+
+  it('offers nothing at the landing cell of a one-way rift', () => {
+    expect(riftLinksAt(cube, { x: 3, y: 0, t: 2 })).toEqual([])
+  })
+})
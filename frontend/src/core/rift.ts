@@ -1,5 +1,6 @@
 import { isInBounds, type Position2D, type Position3D } from './position'
 import type { Result } from './result'
+import { objectsAt, objectsWithComponent, type TimeCube } from './timeCube'
 
 export interface RiftSettings {
   defaultDelta: number
@@ -95,3 +96,55 @@ export function resolveRift(input: ResolveRiftInput): Result<RiftResolution, Rif
     },
   }
 }
+
+export interface RiftTileLink {
+  objectId: string
+  target: Position3D
+  /** True when travelling back along a bidirectional rift from its landing cell. */
+  reverse: boolean
+}
+
+function samePosition(a: Position3D, b: Position3D): boolean {
+  return a.x === b.x && a.y === b.y && a.t === b.t
+}
+
+/**
+ * Rift tiles usable from `position`: forward through any rift standing here, then back to the
+ * source of any bidirectional rift that lands exactly here. Targets are de-duplicated.
+ */
+export function riftLinksAt(cube: TimeCube, position: Position3D): RiftTileLink[] {
+  const links: RiftTileLink[] = []
+  const seen = new Set<string>()
+  const push = (link: RiftTileLink) => {
+    const key = `${link.target.x},${link.target.y},${link.target.t}`
+
+    if (seen.has(key) || samePosition(link.target, position)) {
+      return
+    }
+
+    seen.add(key)
+    links.push(link)
+  }
+
+  for (const object of objectsAt(cube, position)) {
+    for (const component of object.archetype.components) {
+      if (component.kind === 'Rift') {
+        push({ objectId: object.id, target: component.target, reverse: false })
+      }
+    }
+  }
+
+  for (const object of objectsWithComponent(cube, 'Rift')) {
+    for (const component of object.archetype.components) {
+      if (
+        component.kind === 'Rift' &&
+        component.bidirectional &&
+        samePosition(component.target, position)
+      ) {
+        push({ objectId: object.id, target: object.position, reverse: true })
+      }
+    }
+  }
+
+  return links
+}
@@ -0,0 +1,52 @@
+import { describe, expect, it } from 'vitest'
+
+import { createSeededRng } from '../data/generation/random'
+import { createRng, nextFloat, nextInt, rollChance, type RngState } from './rng'
+
+function floats(rng: RngState, count: number): number[] {
+  const values: number[] = []
+  let state = rng
+
+  for (let index = 0; index < count; index += 1) {
+    const draw = nextFloat(state)
+    values.push(draw.value)
+    state = draw.rng
+  }
+
+  return values
+}
+
+describe('rng', () => {
+  it('replays the same sequence from the same seed without mutating the state', () => {
+    const rng = createRng('level-7')
+
+    expect(floats(rng, 4)).toEqual(floats(createRng('level-7'), 4))
+    expect(floats(rng, 4)).not.toEqual(floats(createRng('level-8'), 4))
+    expect(rng).toEqual(createRng('level-7'))
+  })
+
+  it('matches the map generator sequence for the same seed', () => {
+    const generator = createSeededRng(42)
+
+    expect(floats(createRng(42), 3)).toEqual([
+      generator.nextFloat(),
+      generator.nextFloat(),
+      generator.nextFloat(),
+    ])
+  })
+
+  it('keeps integer draws in range and honours certain chances', () => {
+    let rng = createRng('range')
+
+    for (let index = 0; index < 50; index += 1) {
+      const draw = nextInt(rng, 2, 4)
+      expect(draw.value).toBeGreaterThanOrEqual(2)
+      expect(draw.value).toBeLessThanOrEqual(4)
+      rng = draw.rng
+    }
+
+    expect(nextInt(rng, 5, 1).value).toBe(5)
+    expect(rollChance(rng, 1).value).toBe(true)
+    expect(rollChance(rng, 0).value).toBe(false)
+  })
+})
@@ -0,0 +1,70 @@
+/**
+ * Seeded randomness for stochastic mechanics. The generator state is plain data so it can live
+ * in the game state, be replayed from a stored seed, and be cloned by the solver's simulations.
+ */
+export interface RngState {
+  seed: string
+  /** mulberry32 accumulator; advanced by every draw. */
+  state: number
+}
+
+export interface RngDraw<T> {
+  value: T
+  rng: RngState
+}
+
+/** FNV-1a hash of the seed text, so string and numeric seeds share one space. */
+export function hashSeed(seed: string | number): number {
+  const text = String(seed)
+  let hash = 2166136261
+
+  for (let index = 0; index < text.length; index += 1) {
+    hash ^= text.charCodeAt(index)
+    hash = Math.imul(hash, 16777619)
+  }
+
+  return hash >>> 0
+}
+
+export function createRng(seed: string | number): RngState {
+  return { seed: String(seed), state: hashSeed(seed) }
+}
+
+/** One mulberry32 step: returns a float in [0, 1) and the advanced accumulator. */
+export function mulberry32Step(state: number): { value: number; state: number } {
+  const next = (state + 0x6d2b79f5) >>> 0
+  let mixed = Math.imul(next ^ (next >>> 15), 1 | next)
+  mixed ^= mixed + Math.imul(mixed ^ (mixed >>> 7), 61 | mixed)
+
+  return { value: ((mixed ^ (mixed >>> 14)) >>> 0) / 4294967296, state: next }
+}
+
+export function nextFloat(rng: RngState): RngDraw<number> {
+  const step = mulberry32Step(rng.state)
+
+  return { value: step.value, rng: { seed: rng.seed, state: step.state } }
+}
+
+/** Uniform integer in `[minInclusive, maxInclusive]`; an empty range yields `minInclusive`. */
+export function nextInt(
+  rng: RngState,
+  minInclusive: number,
+  maxInclusive: number,
+): RngDraw<number> {
+  const draw = nextFloat(rng)
+
+  if (maxInclusive < minInclusive) {
+    return { value: minInclusive, rng: draw.rng }
+  }
+
+  const span = maxInclusive - minInclusive + 1
+
+  return { value: minInclusive + Math.floor(draw.value * span), rng: draw.rng }
+}
+
+/** True with the given probability (clamped to 0..1). */
+export function rollChance(rng: RngState, probability: number): RngDraw<boolean> {
+  const draw = nextFloat(rng)
+
+  return { value: draw.value < Math.min(1, Math.max(0, probability)), rng: draw.rng }
+}
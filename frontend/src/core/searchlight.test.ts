@@ -0,0 +1,42 @@
+import { describe, expect, it } from 'vitest'
+
+import type { SearchlightComponent } from './components'
+import { isLitBy, litCellsAt, searchlightCenterAt } from './searchlight'
+
+const sweep: SearchlightComponent = {
+  kind: 'Searchlight',
+  shape: 'circle',
+  radius: 1,
+  path: [
+    { x: 2, y: 2 },
+    { x: 4, y: 2 },
+  ],
+  loops: true,
+}
+
+describe('searchlight', () => {
+  it('moves the beam center along its path', () => {
+    expect(searchlightCenterAt(sweep, { x: 0, y: 0 }, 0)).toEqual({ x: 2, y: 2 })
+    expect(searchlightCenterAt(sweep, { x: 0, y: 0 }, 1)).toEqual({ x: 4, y: 2 })
+    expect(searchlightCenterAt({ ...sweep, path: [] }, { x: 0, y: 0 }, 1)).toEqual({ x: 0, y: 0 })
+  })
+
+  it('lights a diamond around the center for circles', () => {
+    expect(litCellsAt(sweep, { x: 0, y: 0 }, 0, 6, 6)).toEqual([
+      { x: 2, y: 1 },
+      { x: 1, y: 2 },
+      { x: 2, y: 2 },
+      { x: 3, y: 2 },
+      { x: 2, y: 3 },
+    ])
+  })
+
+  it('lights a cone from the mount toward the center', () => {
+    const cone: SearchlightComponent = { ...sweep, shape: 'cone', radius: 3 }
+
+    expect(isLitBy(cone, { x: 0, y: 2 }, { x: 3, y: 2 }, 0)).toBe(true)
+    expect(isLitBy(cone, { x: 0, y: 2 }, { x: 2, y: 3 }, 0)).toBe(true)
+    expect(isLitBy(cone, { x: 0, y: 2 }, { x: 1, y: 4 }, 0)).toBe(false)
+    expect(isLitBy(cone, { x: 0, y: 2 }, { x: 4, y: 2 }, 0)).toBe(false)
+  })
+})
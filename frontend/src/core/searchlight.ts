@@ -0,0 +1,85 @@
+import type { Component, SearchlightComponent } from './components'
+import { isInFacingCone } from './guards'
+import { patrolPositionAt } from './patrol'
+import type { Direction2D, Position2D } from './position'
+
+export function searchlightOf(components: Component[]): SearchlightComponent | null {
+  for (const component of components) {
+    if (component.kind === 'Searchlight') {
+      return component
+    }
+  }
+
+  return null
+}
+
+/** Where the beam is aimed at `t`; lights with an empty path stay on their mount. */
+export function searchlightCenterAt(
+  light: SearchlightComponent,
+  mount: Position2D,
+  t: number,
+): Position2D {
+  return patrolPositionAt(light, t) ?? { x: mount.x, y: mount.y }
+}
+
+function dominantDirection(from: Position2D, to: Position2D): Direction2D | null {
+  const dx = to.x - from.x
+  const dy = to.y - from.y
+
+  if (dx === 0 && dy === 0) {
+    return null
+  }
+
+  if (Math.abs(dx) >= Math.abs(dy)) {
+    return dx > 0 ? 'east' : 'west'
+  }
+
+  return dy > 0 ? 'south' : 'north'
+}
+
+/**
+ * Whether `cell` is lit at `t`. Circles light every cell within `radius` (Manhattan) of the beam
+ * center. Cones shine from the mount toward the center and light the 90-degree cone up to
+ * `radius` cells away.
+ */
+export function isLitBy(
+  light: SearchlightComponent,
+  mount: Position2D,
+  cell: Position2D,
+  t: number,
+): boolean {
+  const center = searchlightCenterAt(light, mount, t)
+
+  if (light.shape === 'circle') {
+    return Math.abs(cell.x - center.x) + Math.abs(cell.y - center.y) <= light.radius
+  }
+
+  const facing = dominantDirection(mount, center)
+
+  return (
+    facing !== null &&
+    Math.abs(cell.x - mount.x) + Math.abs(cell.y - mount.y) <= light.radius &&
+    isInFacingCone(mount, facing, cell)
+  )
+}
+
+/** Every in-bounds cell lit at `t`, row by row. */
+export function litCellsAt(
+  light: SearchlightComponent,
+  mount: Position2D,
+  t: number,
+  width: number,
+  height: number,
+): Position2D[] {
+  const cells: Position2D[] = []
+
+  for (let y = 0; y < height; y += 1) {
+    for (let x = 0; x < width; x += 1) {
+      if (isLitBy(light, mount, { x, y }, t)) {
+        cells.push({ x, y })
+      }
+    }
+  }
+
+  return cells
+}
@@ -0,0 +1,85 @@
+import { describe, expect, it } from 'vitest'
+
+import { hasLineOfSight } from './detection'
+import type { ResolvedObjectInstance } from './objects'
+import { isStackAt, stackAt, stackedCells, stackingPushTarget } from './stacking'
+import { applyRelocationsFromTime, createTimeCube, objectsAtTime, placeObjects } from './timeCube'
+
+function boxObject(id: string, x: number, stackable = true): ResolvedObjectInstance {
+  return {
+    id,
+    archetypeKey: 'box',
+    position: { x, y: 2, t: 0 },
+    archetype: {
+      kind: 'box',
+      components: [
+        { kind: 'BlocksMovement' },
+        { kind: 'Pushable' },
+        { kind: 'Pullable' },
+        { kind: 'TimePersistent' },
+        ...(stackable ? [{ kind: 'Stackable' as const }] : []),
+      ],
+      render: {},
+    },
+  }
+}
+
+describe('stacking', () => {
+  it('finds a stacking push only between two lone stackable boxes', () => {
+    const placed = placeObjects(createTimeCube(6, 6, 4), [
+      boxObject('box.a', 2),
+      boxObject('box.b', 3),
+      boxObject('crate', 1, false),
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    expect(stackingPushTarget(placed.value, { x: 2, y: 2, t: 1 }, 'east')).toEqual({
+      boxId: 'box.a',
+      baseId: 'box.b',
+      to: { x: 3, y: 2, t: 1 },
+    })
+    expect(stackingPushTarget(placed.value, { x: 3, y: 2, t: 1 }, 'east')).toBeNull()
+    expect(stackingPushTarget(placed.value, { x: 1, y: 2, t: 1 }, 'east')).toBeNull()
+  })
+
+  it('orders a stack bottom first and lets it block sight lines', () => {
+    const placed = placeObjects(createTimeCube(6, 6, 4), [
+      boxObject('box.a', 2),
+      boxObject('box.b', 3),
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const stacked = applyRelocationsFromTime(
+      placed.value,
+      1,
+      [{ id: 'box.a', from: { x: 2, y: 2, t: 1 }, to: { x: 3, y: 2, t: 1 } }],
+      ['box.b'],
+    )
+
+    expect(stacked.ok).toBe(true)
+    if (!stacked.ok) {
+      return
+    }
+
+    const cube = stacked.value
+    const sight = { cube, from: { x: 0, y: 2 }, to: { x: 5, y: 2 } }
+
+    expect(stackAt(cube, { x: 3, y: 2, t: 1 }).map((object) => object.id)).toEqual([
+      'box.b',
+      'box.a',
+    ])
+    expect(isStackAt(cube, { x: 3, y: 2, t: 0 })).toBe(false)
+    expect(isStackAt(cube, { x: 3, y: 2, t: 3 })).toBe(true)
+    expect(hasLineOfSight({ ...sight, atTime: 0 })).toBe(true)
+    expect(hasLineOfSight({ ...sight, atTime: 1 })).toBe(false)
+    expect(stackedCells(objectsAtTime(cube, 2))).toEqual([{ x: 3, y: 2, t: 2 }])
+  })
+})
@@ -0,0 +1,84 @@
+import { EYE_HEIGHT, hasComponent } from './components'
+import type { ResolvedObjectInstance } from './objects'
+import {
+  isInBounds,
+  movePosition,
+  wrapPosition,
+  type Direction2D,
+  type Position3D,
+} from './position'
+import { objectsAt, wrappingOf, type TimeCube } from './timeCube'
+
+/** A pushed `Stackable` box climbs onto the next one and the pair moves and blocks as one. */
+export interface StackingPush {
+  boxId: string
+  baseId: string
+  to: Position3D
+}
+
+function isStackable(object: ResolvedObjectInstance): boolean {
+  return hasComponent(object.archetype.components, 'Stackable')
+}
+
+/**
+ * Stackable boxes sharing the cell, bottom first. A box lands on top by being relocated into the
+ * cell last, so the slice's spatial index already records the order of the stack.
+ */
+export function stackAt(cube: TimeCube, position: Position3D): ResolvedObjectInstance[] {
+  return objectsAt(cube, position).filter(isStackable)
+}
+
+/** Two boxes reach eye height, so a stack hides what is behind it like a wall. */
+export function isStackAt(cube: TimeCube, position: Position3D): boolean {
+  return stackAt(cube, position).length >= EYE_HEIGHT
+}
+
+/** Cells among `objects` that hold a full stack, for drawing the stack marker. */
+export function stackedCells(objects: ResolvedObjectInstance[]): Position3D[] {
+  const counts = new Map<string, { position: Position3D; count: number }>()
+
+  for (const object of objects.filter(isStackable)) {
+    const key = `${object.position.x},${object.position.y},${object.position.t}`
+    const entry = counts.get(key) ?? { position: object.position, count: 0 }
+    counts.set(key, { ...entry, count: entry.count + 1 })
+  }
+
+  return [...counts.values()]
+    .filter((entry) => entry.count >= EYE_HEIGHT)
+    .map((entry) => entry.position)
+}
+
+/**
+ * The stacking move for a push into `boxCell`: a lone stackable box there climbs onto a lone
+ * stackable box right behind it. Null when the push is an ordinary chain push instead.
+ */
+export function stackingPushTarget(
+  cube: TimeCube,
+  boxCell: Position3D,
+  direction: Direction2D,
+): StackingPush | null {
+  const blockers = objectsAt(cube, boxCell).filter((object) =>
+    hasComponent(object.archetype.components, 'BlocksMovement'),
+  )
+
+  if (blockers.length !== 1 || !isStackable(blockers[0])) {
+    return null
+  }
+
+  const beyond = wrapPosition(movePosition(boxCell, direction), wrappingOf(cube))
+
+  if (!isInBounds(beyond, cube.width, cube.height)) {
+    return null
+  }
+
+  const to = { x: beyond.x, y: beyond.y, t: boxCell.t }
+  const bases = objectsAt(cube, to).filter((object) =>
+    hasComponent(object.archetype.components, 'BlocksMovement'),
+  )
+
+  if (bases.length !== 1 || !isStackable(bases[0])) {
+    return null
+  }
+
+  return { boxId: blockers[0].id, baseId: bases[0].id, to }
+}
@@ -4,11 +4,22 @@ import type { ResolvedObjectInstance } from './objects'
 import {
   applyRelocationsFromTime,
   createTimeCube,
+  decorAtTime,
   hasExit,
   isBlocked,
+  moveObjectThroughTime,
+  objectPositionAt,
   objectsAt,
   objectsAtTime,
+  objectsOfKind,
+  objectsOfKindAtTime,
+  objectsWithComponent,
   placeObjects,
+  propagationTimes,
+  relocateThroughTime,
+  removeObjectFromTime,
+  timeAfter,
+  timeBefore,
 } from './timeCube'
 
 function sampleObjects(): ResolvedObjectInstance[] {
@@ -80,6 +91,67 @@ describe('timeCube object occupancy', () => {
     expect(objectsAtTime(placed.value, 2).map((obj) => obj.id)).toEqual(['wall.a', 'exit.a'])
   })
 
+  it('queries objects by kind and component, per slice through the kind index', () => {
+    const box: ResolvedObjectInstance = {
+      id: 'box.a',
+      archetypeKey: 'box',
+      position: { x: 3, y: 0, t: 1 },
+      archetype: { kind: 'box', components: [{ kind: 'Pushable' }], render: {} },
+    }
+    const placed = placeObjects(createTimeCube(4, 4, 3), [...sampleObjects(), box])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const cube = placed.value
+
+    expect(objectsOfKind(cube, 'wall').map((obj) => obj.id)).toEqual(['wall.a'])
+    expect(objectsWithComponent(cube, 'TimePersistent').map((obj) => obj.id)).toEqual([
+      'wall.a',
+      'exit.a',
+    ])
+    expect(objectsOfKindAtTime(cube, 0, 'box')).toEqual([])
+    expect(objectsOfKindAtTime(cube, 1, 'box')).toEqual([box])
+    expect(objectsOfKindAtTime(cube, 2, 'exit')[0].position).toEqual({ x: 2, y: 2, t: 2 })
+
+    const moved = relocateThroughTime(cube, 'box.a', 1, { x: 0, y: 3, t: 2 })
+
+    expect(moved.ok).toBe(true)
+    if (moved.ok) {
+      expect(moved.value.slices[1].idsByKind.box).toEqual([])
+      expect(objectsOfKindAtTime(moved.value, 2, 'box')[0].position).toEqual({ x: 0, y: 3, t: 2 })
+    }
+  })
+
+  it('keeps décor in its own layer, out of gameplay queries', () => {
+    const rubble: ResolvedObjectInstance = {
+      id: 'decor.rubble',
+      archetypeKey: 'rubble',
+      position: { x: 1, y: 1, t: 0 },
+      archetype: {
+        kind: 'decor',
+        components: [{ kind: 'Appearance', glyph: ',' }],
+        render: {},
+      },
+    }
+    const placed = placeObjects(createTimeCube(4, 4, 3), [...sampleObjects(), rubble])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    expect(objectsAt(placed.value, { x: 1, y: 1, t: 2 }).map((obj) => obj.id)).toEqual(['wall.a'])
+    expect(placed.value.objectsById['decor.rubble']).toBeUndefined()
+    expect(decorAtTime(placed.value, 2)).toEqual([{ ...rubble, position: { x: 1, y: 1, t: 2 } }])
+    expect(placeObjects(placed.value, [rubble])).toEqual({
+      ok: false,
+      error: { kind: 'EntityAlreadyExists', id: 'decor.rubble', t: 0 },
+    })
+  })
+
   it('relocates objects from a target time forward', () => {
     const cube = createTimeCube(6, 6, 5)
     const placed = placeObjects(cube, [
@@ -118,4 +190,283 @@ describe('timeCube object occupancy', () => {
     expect(objectsAt(relocated.value, { x: 2, y: 2, t: 4 }).map((obj) => obj.id)).toContain('box.a')
     expect(objectsAt(relocated.value, { x: 1, y: 2, t: 4 }).map((obj) => obj.id)).toHaveLength(0)
   })
+
+  it('clears copies a later push left behind when the box is pushed earlier', () => {
+    const placed = placeObjects(createTimeCube(6, 6, 6), [
+      {
+        id: 'box.a',
+        archetypeKey: 'box',
+        position: { x: 1, y: 2, t: 0 },
+        archetype: {
+          kind: 'box',
+          components: [{ kind: 'BlocksMovement' }, { kind: 'Pushable' }, { kind: 'TimePersistent' }],
+          render: {},
+        },
+      },
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const later = applyRelocationsFromTime(placed.value, 4, [
+      { id: 'box.a', from: { x: 1, y: 2, t: 4 }, to: { x: 1, y: 3, t: 4 } },
+    ])
+
+    expect(later.ok).toBe(true)
+    if (!later.ok) {
+      return
+    }
+
+    const earlier = applyRelocationsFromTime(later.value, 2, [
+      { id: 'box.a', from: { x: 1, y: 2, t: 2 }, to: { x: 2, y: 2, t: 2 } },
+    ])
+
+    expect(earlier.ok).toBe(true)
+    if (!earlier.ok) {
+      return
+    }
+
+    expect(objectPositionAt(earlier.value, 'box.a', 1)).toEqual({ x: 1, y: 2, t: 1 })
+    expect(objectPositionAt(earlier.value, 'box.a', 5)).toEqual({ x: 2, y: 2, t: 5 })
+    expect(objectsAt(earlier.value, { x: 1, y: 3, t: 5 })).toHaveLength(0)
+    expect(objectsAtTime(earlier.value, 5).map((object) => object.id)).toEqual(['box.a'])
+  })
+
+  it('moves an object through time only when every slice has room for it', () => {
+    const placed = placeObjects(createTimeCube(6, 6, 4), [
+      {
+        id: 'box.a',
+        archetypeKey: 'box',
+        position: { x: 1, y: 2, t: 0 },
+        archetype: {
+          kind: 'box',
+          components: [{ kind: 'BlocksMovement' }, { kind: 'Pushable' }, { kind: 'TimePersistent' }],
+          render: {},
+        },
+      },
+      {
+        id: 'token.a',
+        archetypeKey: 'token',
+        position: { x: 3, y: 3, t: 1 },
+        archetype: { kind: 'token', components: [], render: {} },
+      },
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    expect(moveObjectThroughTime(placed.value, 'box.a', 0, { x: 3, y: 3 })).toEqual({
+      ok: false,
+      error: { kind: 'TargetOccupied', id: 'token.a', x: 3, y: 3, t: 1 },
+    })
+
+    const token = moveObjectThroughTime(placed.value, 'token.a', 1, { x: 4, y: 4 })
+
+    expect(token.ok).toBe(true)
+    if (!token.ok) {
+      return
+    }
+
+    expect(objectPositionAt(token.value, 'token.a', 1)).toEqual({ x: 4, y: 4, t: 1 })
+    expect(objectPositionAt(token.value, 'token.a', 2)).toBeNull()
+  })
+
+  it('carries relocations around the seam on time-loop levels', () => {
+    const cube = { ...createTimeCube(6, 6, 5), timeWraps: true }
+    const placed = placeObjects(cube, [
+      {
+        id: 'box.a',
+        archetypeKey: 'box',
+        position: { x: 1, y: 2, t: 0 },
+        archetype: {
+          kind: 'box',
+          components: [{ kind: 'BlocksMovement' }, { kind: 'Pushable' }, { kind: 'TimePersistent' }],
+          render: {},
+        },
+      },
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    expect(propagationTimes(placed.value, 3)).toEqual([3, 4, 0, 1, 2])
+    expect(propagationTimes(createTimeCube(6, 6, 5), 3)).toEqual([3, 4])
+    expect(timeBefore(placed.value, 1, 2)).toBe(4)
+    expect(timeBefore(createTimeCube(6, 6, 5), 1, 2)).toBeNull()
+    expect(timeAfter(placed.value, 3, 2)).toBe(0)
+    expect(timeAfter(createTimeCube(6, 6, 5), 3, 2)).toBeNull()
+
+    const relocated = applyRelocationsFromTime(placed.value, 3, [
+      { id: 'box.a', from: { x: 1, y: 2, t: 3 }, to: { x: 2, y: 2, t: 3 } },
+    ])
+
+    expect(relocated.ok).toBe(true)
+    if (!relocated.ok) {
+      return
+    }
+
+    expect(objectsAt(relocated.value, { x: 2, y: 2, t: 1 }).map((obj) => obj.id)).toEqual(['box.a'])
+    expect(objectsAt(relocated.value, { x: 1, y: 2, t: 0 })).toHaveLength(0)
+  })
+
+  it('moves an object through time, rewriting slices from the earlier of departure and arrival', () => {
+    const placed = placeObjects(createTimeCube(6, 6, 6), [
+      {
+        id: 'box.a',
+        archetypeKey: 'box',
+        position: { x: 1, y: 2, t: 0 },
+        archetype: {
+          kind: 'box',
+          components: [{ kind: 'BlocksMovement' }, { kind: 'Pushable' }, { kind: 'TimePersistent' }],
+          render: {},
+        },
+      },
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const idsAt = (cube: typeof placed.value, x: number, y: number, t: number) =>
+      objectsAt(cube, { x, y, t }).map((obj) => obj.id)
+
+    const forward = relocateThroughTime(placed.value, 'box.a', 2, { x: 4, y: 4, t: 4 })
+    expect(forward.ok).toBe(true)
+    if (forward.ok) {
+      expect(forward.value.slices[1]).toBe(placed.value.slices[1])
+      expect(idsAt(forward.value, 1, 2, 1)).toContain('box.a')
+      expect(idsAt(forward.value, 1, 2, 2)).toHaveLength(0)
+      expect(idsAt(forward.value, 4, 4, 3)).toHaveLength(0)
+      expect(idsAt(forward.value, 4, 4, 4)).toContain('box.a')
+      expect(idsAt(forward.value, 4, 4, 5)).toContain('box.a')
+      expect(forward.value.objectsById['box.a'].position).toEqual({ x: 4, y: 4, t: 4 })
+    }
+
+    const backward = relocateThroughTime(placed.value, 'box.a', 4, { x: 4, y: 4, t: 1 })
+    expect(backward.ok).toBe(true)
+    if (backward.ok) {
+      expect(backward.value.slices[0]).toBe(placed.value.slices[0])
+      expect(idsAt(backward.value, 1, 2, 0)).toContain('box.a')
+      expect(idsAt(backward.value, 1, 2, 1)).toHaveLength(0)
+      expect(idsAt(backward.value, 4, 4, 1)).toContain('box.a')
+      expect(idsAt(backward.value, 4, 4, 5)).toContain('box.a')
+    }
+  })
+
+  it('carries a relocation round the seam of a wrapping cube', () => {
+    const placed = placeObjects({ ...createTimeCube(6, 6, 6), timeWraps: true }, [
+      {
+        id: 'box.a',
+        archetypeKey: 'box',
+        position: { x: 1, y: 2, t: 0 },
+        archetype: {
+          kind: 'box',
+          components: [{ kind: 'BlocksMovement' }, { kind: 'Pushable' }, { kind: 'TimePersistent' }],
+          render: {},
+        },
+      },
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const idsAt = (cube: typeof placed.value, x: number, y: number, t: number) =>
+      objectsAt(cube, { x, y, t }).map((obj) => obj.id)
+
+    const forward = relocateThroughTime(placed.value, 'box.a', 2, { x: 4, y: 4, t: 4 })
+    expect(forward.ok).toBe(true)
+    if (forward.ok) {
+      expect(idsAt(forward.value, 1, 2, 1)).toHaveLength(0)
+      expect(idsAt(forward.value, 4, 4, 0)).toContain('box.a')
+      expect(idsAt(forward.value, 4, 4, 1)).toContain('box.a')
+      expect(idsAt(forward.value, 4, 4, 2)).toHaveLength(0)
+      expect(idsAt(forward.value, 4, 4, 3)).toHaveLength(0)
+      expect(idsAt(forward.value, 4, 4, 5)).toContain('box.a')
+    }
+
+    const backward = relocateThroughTime(placed.value, 'box.a', 4, { x: 4, y: 4, t: 1 })
+    expect(backward.ok).toBe(true)
+    if (backward.ok) {
+      expect(idsAt(backward.value, 1, 2, 0)).toHaveLength(0)
+      expect(idsAt(backward.value, 4, 4, 0)).toContain('box.a')
+      expect(idsAt(backward.value, 4, 4, 5)).toContain('box.a')
+    }
+  })
+
+  it('indexes a multi-tile object under every cell it covers and lists it once', () => {
+    const table: ResolvedObjectInstance = {
+      id: 'table.a',
+      archetypeKey: 'table',
+      position: { x: 1, y: 0, t: 0 },
+      archetype: {
+        kind: 'table',
+        components: [
+          { kind: 'BlocksMovement' },
+          { kind: 'TimePersistent' },
+          { kind: 'Footprint', width: 2, height: 1 },
+        ],
+        render: {},
+      },
+    }
+    const placed = placeObjects(createTimeCube(4, 4, 3), [...sampleObjects(), table])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    expect(isBlocked(placed.value, { x: 2, y: 0, t: 2 })).toBe(true)
+    expect(objectsAt(placed.value, { x: 2, y: 0, t: 0 })[0].position).toEqual({ x: 1, y: 0, t: 0 })
+    expect(objectsAtTime(placed.value, 1).filter((obj) => obj.id === 'table.a')).toHaveLength(1)
+    expect(moveObjectThroughTime(placed.value, 'table.a', 1, { x: 3, y: 0 })).toEqual({
+      ok: false,
+      error: { kind: 'OutOfBounds', x: 4, y: 0, t: 1 },
+    })
+
+    const moved = moveObjectThroughTime(placed.value, 'table.a', 1, { x: 0, y: 3 })
+
+    expect(moved.ok).toBe(true)
+    if (!moved.ok) {
+      return
+    }
+
+    expect(objectPositionAt(moved.value, 'table.a', 2)).toEqual({ x: 0, y: 3, t: 2 })
+    expect(isBlocked(moved.value, { x: 1, y: 3, t: 1 })).toBe(true)
+    expect(isBlocked(moved.value, { x: 2, y: 0, t: 1 })).toBe(false)
+    expect(placeObjects(createTimeCube(2, 2, 1), [table]).ok).toBe(false)
+  })
+
+  it('removes an object from a slice onward and keeps its earlier history', () => {
+    const placed = placeObjects(createTimeCube(4, 4, 4), sampleObjects())
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const removed = removeObjectFromTime(placed.value, 'wall.a', 2)
+
+    expect(removed.ok).toBe(true)
+    if (!removed.ok) {
+      return
+    }
+
+    expect(isBlocked(removed.value, { x: 1, y: 1, t: 1 })).toBe(true)
+    expect(isBlocked(removed.value, { x: 1, y: 1, t: 2 })).toBe(false)
+    expect(isBlocked(removed.value, { x: 1, y: 1, t: 3 })).toBe(false)
+    expect(objectsOfKindAtTime(removed.value, 3, 'wall')).toHaveLength(0)
+    expect(removeObjectFromTime(placed.value, 'ghost', 0)).toEqual({
+      ok: false,
+      error: { kind: 'EntityNotFound', id: 'ghost' },
+    })
+  })
 })
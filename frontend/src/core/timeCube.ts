@@ -1,12 +1,17 @@
-import { hasComponent } from './components'
-import type { Position2D, Position3D } from './position'
+import { blocksMovementAt, type Lockdown } from './alarm'
+import { hasComponent, isDecor, type Component } from './components'
+import { footprintCells, isMultiTile } from './footprint'
+import type { Position2D, Position3D, Wrapping } from './position'
 import type { ResolvedObjectInstance } from './objects'
+import type { Region } from './regions'
 import type { Result } from './result'
 
 export interface TimeSlice {
   t: number
   objectIds: string[]
   spatialIndex: Record<string, string[]>
+  /** Ids present in this slice keyed by archetype kind (`wall`, `box`, `enemy`, ...). */
+  idsByKind: Record<string, string[]>
 }
 
 export interface TimeCube {
@@ -15,6 +20,20 @@ export interface TimeCube {
   timeDepth: number
   slices: TimeSlice[]
   objectsById: Record<string, ResolvedObjectInstance>
+  /**
+   * Décor layer: appearance-only objects drawn under everything in every slice. They stay out of
+   * `objectsById` and the spatial index, so blocking, vision and relocation never see them.
+   */
+  decor: ResolvedObjectInstance[]
+  /** Named map areas from the level, queried with `regionsAt`. */
+  regions: Region[]
+  /** Time-loop level: the slice after the last one is slice 0. */
+  timeWraps: boolean
+  /** Torus map: walking off the east (or south) edge comes back in on the west (or north). */
+  xWraps: boolean
+  yWraps: boolean
+  /** Slices an alarm locked down, in the order they were raised. */
+  lockdowns: Lockdown[]
 }
 
 export type CubeError =
@@ -57,6 +76,7 @@ export function createTimeCube(width: number, height: number, timeDepth: number)
       t,
       objectIds: [],
       spatialIndex: {},
+      idsByKind: {},
     })
   }
 
@@ -66,6 +86,12 @@ export function createTimeCube(width: number, height: number, timeDepth: number)
     timeDepth,
     slices,
     objectsById: {},
+    decor: [],
+    regions: [],
+    timeWraps: false,
+    xWraps: false,
+    yWraps: false,
+    lockdowns: [],
   }
 }
 
@@ -86,16 +112,32 @@ function isInCubeBounds(cube: TimeCube, position: Position3D): boolean {
   )
 }
 
-function addToSlice(slice: TimeSlice, objectId: string, position: Position3D): TimeSlice {
-  const key = spatialKey(position)
-  const existingIds = slice.spatialIndex[key] ?? []
+/** First cell of `id`'s footprint at `anchor` that is off the map, or null when it all fits. */
+function cellOffPlane(cube: TimeCube, id: string, anchor: Position2D): Position2D | null {
+  const components = cube.objectsById[id]?.archetype.components ?? []
+  return footprintCells(components, anchor).find((cell) => !isInPlaneBounds(cube, cell)) ?? null
+}
+
+function addToSlice(
+  slice: TimeSlice,
+  object: ResolvedObjectInstance,
+  position: Position3D,
+): TimeSlice {
+  const spatialIndex = { ...slice.spatialIndex }
+  const kind = object.archetype.kind
+
+  for (const cell of footprintCells(object.archetype.components, position)) {
+    const key = spatialKey(cell)
+    spatialIndex[key] = [...(spatialIndex[key] ?? []), object.id]
+  }
 
   return {
     ...slice,
-    objectIds: [...slice.objectIds, objectId],
-    spatialIndex: {
-      ...slice.spatialIndex,
-      [key]: [...existingIds, objectId],
+    objectIds: [...slice.objectIds, object.id],
+    spatialIndex,
+    idsByKind: {
+      ...slice.idsByKind,
+      [kind]: [...(slice.idsByKind[kind] ?? []), object.id],
     },
   }
 }
@@ -113,7 +155,7 @@ function placeSingleObject(cube: TimeCube, object: ResolvedObjectInstance): Resu
     }
   }
 
-  if (cube.objectsById[object.id]) {
+  if (cube.objectsById[object.id] || cube.decor.some((decor) => decor.id === object.id)) {
     return {
       ok: false,
       error: {
@@ -124,6 +166,21 @@ function placeSingleObject(cube: TimeCube, object: ResolvedObjectInstance): Resu
     }
   }
 
+  if (isDecor(object.archetype.components)) {
+    return { ok: true, value: { ...cube, decor: [...cube.decor, object] } }
+  }
+
+  const offPlane = footprintCells(object.archetype.components, object.position).find(
+    (cell) => !isInPlaneBounds(cube, cell),
+  )
+
+  if (offPlane) {
+    return {
+      ok: false,
+      error: { kind: 'OutOfBounds', x: offPlane.x, y: offPlane.y, t: object.position.t },
+    }
+  }
+
   const isTimePersistent = hasComponent(object.archetype.components, 'TimePersistent')
   let nextCube: TimeCube = {
     ...cube,
@@ -137,7 +194,7 @@ function placeSingleObject(cube: TimeCube, object: ResolvedObjectInstance): Resu
     const nextSlices = nextCube.slices.map((slice) =>
       addToSlice(
         slice,
-        object.id,
+        object,
         {
           x: object.position.x,
           y: object.position.y,
@@ -148,7 +205,7 @@ function placeSingleObject(cube: TimeCube, object: ResolvedObjectInstance): Resu
     nextCube = { ...nextCube, slices: nextSlices }
   } else {
     const nextSlices = nextCube.slices.map((slice) =>
-      slice.t === object.position.t ? addToSlice(slice, object.id, object.position) : slice,
+      slice.t === object.position.t ? addToSlice(slice, object, object.position) : slice,
     )
     nextCube = { ...nextCube, slices: nextSlices }
   }
@@ -175,6 +232,7 @@ export function placeObjects(
   return { ok: true, value: nextCube }
 }
 
+/** Objects covering `position`. Multi-tile objects report their anchor, not the queried cell. */
 export function objectsAt(cube: TimeCube, position: Position3D): ResolvedObjectInstance[] {
   if (!isInCubeBounds(cube, position)) {
     return []
@@ -191,14 +249,19 @@ export function objectsAt(cube: TimeCube, position: Position3D): ResolvedObjectI
         return null
       }
 
+      const anchor = isMultiTile(object.archetype.components)
+        ? objectPositionAt(cube, id, position.t)
+        : null
+
       return {
         ...object,
-        position: { x: position.x, y: position.y, t: position.t },
+        position: anchor ?? { x: position.x, y: position.y, t: position.t },
       } satisfies ResolvedObjectInstance
     })
     .filter((object): object is ResolvedObjectInstance => Boolean(object))
 }
 
+/** Every object in slice `t`, once each; multi-tile objects are listed at their anchor. */
 export function objectsAtTime(cube: TimeCube, t: number): ResolvedObjectInstance[] {
   if (t < 0 || t >= cube.timeDepth) {
     return []
@@ -206,6 +269,7 @@ export function objectsAtTime(cube: TimeCube, t: number): ResolvedObjectInstance
 
   const slice = cube.slices[t]
   const results: ResolvedObjectInstance[] = []
+  const multiTileSeen = new Set<string>()
 
   for (const [key, ids] of Object.entries(slice.spatialIndex)) {
     const position = parseSpatialKey(key)
@@ -221,6 +285,18 @@ export function objectsAtTime(cube: TimeCube, t: number): ResolvedObjectInstance
         continue
       }
 
+      if (isMultiTile(object.archetype.components)) {
+        const anchor = multiTileSeen.has(id) ? null : objectPositionAt(cube, id, t)
+
+        multiTileSeen.add(id)
+
+        if (anchor) {
+          results.push({ ...object, position: anchor })
+        }
+
+        continue
+      }
+
       results.push({
         ...object,
         position: { x: position.x, y: position.y, t },
@@ -231,10 +307,59 @@ export function objectsAtTime(cube: TimeCube, t: number): ResolvedObjectInstance
   return results
 }
 
+/** Every object in the cube, whichever slices it occupies, in placement order. */
+export function allObjects(cube: TimeCube): ResolvedObjectInstance[] {
+  return Object.values(cube.objectsById)
+}
+
+/** Objects matching `predicate`, across all slices; positions are where each was placed. */
+export function objectsWith(
+  cube: TimeCube,
+  predicate: (object: ResolvedObjectInstance) => boolean,
+): ResolvedObjectInstance[] {
+  return allObjects(cube).filter(predicate)
+}
+
+export function objectsWithComponent(
+  cube: TimeCube,
+  kind: Component['kind'],
+): ResolvedObjectInstance[] {
+  return objectsWith(cube, (object) => hasComponent(object.archetype.components, kind))
+}
+
+export function objectsOfKind(cube: TimeCube, kind: string): ResolvedObjectInstance[] {
+  return objectsWith(cube, (object) => object.archetype.kind === kind)
+}
+
+/** Objects of one archetype kind in slice `t`, at their cells in that slice, via the kind index. */
+export function objectsOfKindAtTime(
+  cube: TimeCube,
+  t: number,
+  kind: string,
+): ResolvedObjectInstance[] {
+  if (t < 0 || t >= cube.timeDepth) {
+    return []
+  }
+
+  return (cube.slices[t].idsByKind[kind] ?? []).flatMap((id) => {
+    const object = cube.objectsById[id]
+    const position = object ? objectPositionAt(cube, id, t) : null
+
+    return object && position ? [{ ...object, position }] : []
+  })
+}
+
+/** Décor for slice `t`; the same tiles show in every slice. */
+export function decorAtTime(cube: TimeCube, t: number): ResolvedObjectInstance[] {
+  if (t < 0 || t >= cube.timeDepth) {
+    return []
+  }
+
+  return cube.decor.map((decor) => ({ ...decor, position: { ...decor.position, t } }))
+}
+
 export function isBlocked(cube: TimeCube, position: Position3D): boolean {
-  return objectsAt(cube, position).some((object) =>
-    hasComponent(object.archetype.components, 'BlocksMovement'),
-  )
+  return objectsAt(cube, position).some((object) => blocksMovementAt(cube, object, position.t))
 }
 
 export function hasExit(cube: TimeCube, position: Position3D): boolean {
@@ -254,17 +379,53 @@ export function getObjectById(
   return { ok: true, value: object }
 }
 
-function cloneSlice(slice: TimeSlice): TimeSlice {
-  const nextIndex: TimeSlice['spatialIndex'] = {}
+/**
+ * Cell holding `id` in slice `t`, or null when the object is absent from that slice. Multi-tile
+ * objects answer with their anchor, the north-west cell of everything they cover.
+ */
+export function objectPositionAt(cube: TimeCube, id: string, t: number): Position3D | null {
+  if (t < 0 || t >= cube.timeDepth) {
+    return null
+  }
 
-  for (const [key, ids] of Object.entries(slice.spatialIndex)) {
+  const multiTile = isMultiTile(cube.objectsById[id]?.archetype.components ?? [])
+  let anchor: Position2D | null = null
+
+  for (const [key, ids] of Object.entries(cube.slices[t].spatialIndex)) {
+    if (!ids.includes(id)) {
+      continue
+    }
+
+    const position = parseSpatialKey(key)
+
+    if (!multiTile || !position) {
+      return position ? { x: position.x, y: position.y, t } : null
+    }
+
+    anchor = anchor
+      ? { x: Math.min(anchor.x, position.x), y: Math.min(anchor.y, position.y) }
+      : position
+  }
+
+  return anchor ? { x: anchor.x, y: anchor.y, t } : null
+}
+
+function cloneIndex(index: Record<string, string[]>): Record<string, string[]> {
+  const nextIndex: Record<string, string[]> = {}
+
+  for (const [key, ids] of Object.entries(index)) {
     nextIndex[key] = [...ids]
   }
 
+  return nextIndex
+}
+
+function cloneSlice(slice: TimeSlice): TimeSlice {
   return {
     t: slice.t,
     objectIds: [...slice.objectIds],
-    spatialIndex: nextIndex,
+    spatialIndex: cloneIndex(slice.spatialIndex),
+    idsByKind: cloneIndex(slice.idsByKind),
   }
 }
 
@@ -272,106 +433,512 @@ function removeId(ids: string[], id: string): string[] {
   return ids.filter((entry) => entry !== id)
 }
 
-export function applyRelocationsFromTime(
+/** Takes `id` off every cell of a cloned slice; its slice membership is left alone. */
+function dropFromIndex(slice: TimeSlice, id: string): void {
+  for (const [key, ids] of Object.entries(slice.spatialIndex)) {
+    if (!ids.includes(id)) {
+      continue
+    }
+
+    const remaining = removeId(ids, id)
+
+    if (remaining.length === 0) {
+      delete slice.spatialIndex[key]
+    } else {
+      slice.spatialIndex[key] = remaining
+    }
+  }
+}
+
+/** Which axes of the cube wrap around; see `Wrapping`. */
+export function wrappingOf(cube: TimeCube): Wrapping {
+  return {
+    width: cube.xWraps ? cube.width : null,
+    height: cube.yWraps ? cube.height : null,
+    timeDepth: cube.timeWraps ? cube.timeDepth : null,
+  }
+}
+
+/** Slice `delta` slices before `t`, across the seam on time-loop levels; null before slice 0. */
+export function timeBefore(cube: TimeCube, t: number, delta: number): number | null {
+  const earlier = t - delta
+
+  if (earlier >= 0) {
+    return earlier
+  }
+
+  return cube.timeWraps ? (earlier % cube.timeDepth) + cube.timeDepth : null
+}
+
+/** Slice `delta` slices after `t`, across the seam on time-loop levels; null past the last one. */
+export function timeAfter(cube: TimeCube, t: number, delta: number): number | null {
+  const later = t + delta
+
+  if (later < cube.timeDepth) {
+    return later
+  }
+
+  return cube.timeWraps ? later % cube.timeDepth : null
+}
+
+/**
+ * Slices a change made at `startTime` carries into, in order: up to the last slice, then on
+ * time-loop levels on around the seam back to just before `startTime`. Like a rift into the past,
+ * the wrapped part rewrites earlier slices and paradox checks judge what that broke.
+ */
+export function propagationTimes(cube: TimeCube, startTime: number): number[] {
+  const times: number[] = []
+
+  for (let t = startTime; t < cube.timeDepth; t += 1) {
+    times.push(t)
+  }
+
+  if (cube.timeWraps) {
+    for (let t = 0; t < startTime; t += 1) {
+      times.push(t)
+    }
+  }
+
+  return times
+}
+
+/** Slices from `startTime` to the last and never round the seam: what lies downstream of it. */
+export function downstreamTimes(cube: TimeCube, startTime: number): number[] {
+  return propagationTimes({ ...cube, timeWraps: false }, startTime)
+}
+
+/**
+ * Moves `id` to `to` from `startTime` on. Wherever earlier moves left it in the slices the change
+ * carries into, it is taken out first, then put back at `to` in all of them (or only at
+ * `startTime` when it is not time-persistent); slices before `startTime` keep its history. `to`
+ * must be empty except for ids in `tolerated`. Nothing is written unless every slice takes it.
+ */
+export function moveObjectThroughTime(
   cube: TimeCube,
+  id: string,
   startTime: number,
-  relocations: ObjectRelocation[],
+  to: Position2D,
+  tolerated: string[] = [],
 ): Result<TimeCube, CubeError | RelocationError> {
   if (startTime < 0 || startTime >= cube.timeDepth) {
     return { ok: false, error: { kind: 'InvalidRelocationTime', t: startTime } }
   }
 
-  if (relocations.length === 0) {
-    return { ok: true, value: cube }
+  const object = cube.objectsById[id]
+
+  if (!object) {
+    return { ok: false, error: { kind: 'EntityNotFound', id } }
   }
 
-  const nextSlices = cube.slices.map(cloneSlice)
-  const relocatedIds = new Set(relocations.map((relocation) => relocation.id))
-  const nextObjectsById = { ...cube.objectsById }
+  const offPlane = cellOffPlane(cube, id, to)
 
-  for (let t = startTime; t < cube.timeDepth; t += 1) {
-    const slice = nextSlices[t]
+  if (offPlane) {
+    return { ok: false, error: { kind: 'OutOfBounds', x: offPlane.x, y: offPlane.y, t: startTime } }
+  }
+
+  const isTimePersistent = hasComponent(object.archetype.components, 'TimePersistent')
+  const kind = object.archetype.kind
+  const toCells = footprintCells(object.archetype.components, to)
+  const nextSlices = [...cube.slices]
 
-    for (const relocation of relocations) {
-      if (!isInPlaneBounds(cube, relocation.to)) {
+  for (const t of propagationTimes(cube, startTime)) {
+    const slice = cloneSlice(cube.slices[t])
+
+    dropFromIndex(slice, id)
+
+    if (!isTimePersistent && t !== startTime) {
+      slice.objectIds = removeId(slice.objectIds, id)
+      slice.idsByKind[kind] = removeId(slice.idsByKind[kind] ?? [], id)
+      nextSlices[t] = slice
+      continue
+    }
+
+    for (const cell of toCells) {
+      const toKey = spatialKey(cell)
+      const targetIds = slice.spatialIndex[toKey] ?? []
+      const occupants = targetIds.filter((occupant) => !tolerated.includes(occupant))
+
+      if (occupants.length > 0) {
         return {
           ok: false,
-          error: {
-            kind: 'OutOfBounds',
-            x: relocation.to.x,
-            y: relocation.to.y,
-            t,
-          },
+          error: { kind: 'TargetOccupied', id: occupants[0], x: cell.x, y: cell.y, t },
         }
       }
 
-      const toKey = spatialKey(relocation.to)
-      const targetIds = slice.spatialIndex[toKey] ?? []
-      const remaining = targetIds.filter((id) => !relocatedIds.has(id))
+      slice.spatialIndex[toKey] = [...targetIds, id]
+    }
+
+    if (!slice.objectIds.includes(id)) {
+      slice.objectIds.push(id)
+      slice.idsByKind[kind] = [...(slice.idsByKind[kind] ?? []), id]
+    }
+
+    nextSlices[t] = slice
+  }
+
+  return {
+    ok: true,
+    value: {
+      ...cube,
+      slices: nextSlices,
+      objectsById: {
+        ...cube.objectsById,
+        [id]: { ...object, position: { x: to.x, y: to.y, t: startTime } },
+      },
+    },
+  }
+}
+
+/**
+ * Moves objects that stand at `from` in slice `startTime` with `moveObjectThroughTime`, so their
+ * later slices follow the new cell whatever earlier moves left there. Targets must be empty except
+ * for ids in `tolerated`, which stay put underneath (a box stacked onto another), and for the other
+ * moved objects, which leave them in the same change.
+ */
+export function applyRelocationsFromTime(
+  cube: TimeCube,
+  startTime: number,
+  relocations: ObjectRelocation[],
+  tolerated: string[] = [],
+): Result<TimeCube, CubeError | RelocationError> {
+  if (startTime < 0 || startTime >= cube.timeDepth) {
+    return { ok: false, error: { kind: 'InvalidRelocationTime', t: startTime } }
+  }
+
+  const movedIds = relocations.map((relocation) => relocation.id)
+  let next = cube
+
+  for (const relocation of relocations) {
+    const sourceIds = cube.slices[startTime].spatialIndex[spatialKey(relocation.from)] ?? []
+
+    if (!sourceIds.includes(relocation.id)) {
+      return { ok: false, error: { kind: 'EntityNotInSlice', id: relocation.id, t: startTime } }
+    }
 
-      if (remaining.length > 0) {
+    const moved = moveObjectThroughTime(next, relocation.id, startTime, relocation.to, [
+      ...tolerated,
+      ...movedIds,
+    ])
+
+    if (!moved.ok) {
+      return moved
+    }
+
+    next = moved.value
+  }
+
+  return { ok: true, value: next }
+}
+
+/**
+ * Takes `id` out of every slice a change at `startTime` carries into (see `propagationTimes`), or
+ * only `times` when given; other slices keep the object, so its history there still resolves
+ * through `objectsById`. The object records `startTime` as `removedFrom`.
+ */
+export function removeObjectFromTime(
+  cube: TimeCube,
+  id: string,
+  startTime: number,
+  times: number[] = propagationTimes(cube, startTime),
+): Result<TimeCube, CubeError | RelocationError> {
+  if (startTime < 0 || startTime >= cube.timeDepth) {
+    return { ok: false, error: { kind: 'InvalidRelocationTime', t: startTime } }
+  }
+
+  const object = cube.objectsById[id]
+
+  if (!object) {
+    return { ok: false, error: { kind: 'EntityNotFound', id } }
+  }
+
+  const kind = object.archetype.kind
+  const nextSlices = [...cube.slices]
+
+  for (const t of times) {
+    const slice = cloneSlice(cube.slices[t])
+
+    dropFromIndex(slice, id)
+    slice.objectIds = removeId(slice.objectIds, id)
+    slice.idsByKind[kind] = removeId(slice.idsByKind[kind] ?? [], id)
+    nextSlices[t] = slice
+  }
+
+  return {
+    ok: true,
+    value: {
+      ...cube,
+      slices: nextSlices,
+      objectsById: { ...cube.objectsById, [id]: { ...object, removedFrom: startTime } },
+    },
+  }
+}
+
+/**
+ * Undoes `removeObjectFromTime`: puts `id` back on its cell in `times` (by default every slice a
+ * change at `startTime` carries into) and forgets `removedFrom`. Fails when something that blocks
+ * movement took the cell meanwhile.
+ */
+export function restoreObjectFromTime(
+  cube: TimeCube,
+  id: string,
+  startTime: number,
+  times: number[] = propagationTimes(cube, startTime),
+): Result<TimeCube, CubeError | RelocationError> {
+  const removed = cube.objectsById[id]
+
+  if (!removed) {
+    return { ok: false, error: { kind: 'EntityNotFound', id } }
+  }
+
+  const object = { ...removed }
+  delete object.removedFrom
+  const nextSlices = [...cube.slices]
+
+  for (const t of times.filter((time) => !isInSlice(cube, id, time))) {
+    const position = { x: object.position.x, y: object.position.y, t }
+
+    for (const cell of footprintCells(object.archetype.components, position)) {
+      const blocker = objectsAt(cube, { ...cell, t }).find((other) =>
+        blocksMovementAt(cube, other, t),
+      )
+
+      if (blocker) {
         return {
           ok: false,
-          error: {
-            kind: 'TargetOccupied',
-            id: remaining[0],
-            x: relocation.to.x,
-            y: relocation.to.y,
-            t,
-          },
+          error: { kind: 'TargetOccupied', id: blocker.id, x: cell.x, y: cell.y, t },
         }
       }
     }
 
-    for (const relocation of relocations) {
-      const fromKey = spatialKey(relocation.from)
-      const sourceIds = slice.spatialIndex[fromKey] ?? []
+    nextSlices[t] = addToSlice(cube.slices[t], object, position)
+  }
 
-      if (!sourceIds.includes(relocation.id)) {
+  return {
+    ok: true,
+    value: { ...cube, slices: nextSlices, objectsById: { ...cube.objectsById, [id]: object } },
+  }
+}
+
+/**
+ * Puts a new object into every slice a change at `object.position.t` carries into, the reverse of
+ * `removeObjectFromTime`; earlier slices never held it. Its cells must be free of anything that
+ * blocks movement in all of them.
+ */
+export function placeObjectFromTime(
+  cube: TimeCube,
+  object: ResolvedObjectInstance,
+): Result<TimeCube, CubeError | RelocationError> {
+  const { position } = object
+
+  if (!isInCubeBounds(cube, position)) {
+    return {
+      ok: false,
+      error: { kind: 'OutOfBounds', x: position.x, y: position.y, t: position.t },
+    }
+  }
+
+  if (cube.objectsById[object.id]) {
+    return { ok: false, error: { kind: 'EntityAlreadyExists', id: object.id, t: position.t } }
+  }
+
+  const nextCube: TimeCube = {
+    ...cube,
+    objectsById: { ...cube.objectsById, [object.id]: object },
+  }
+  const nextSlices = [...cube.slices]
+
+  for (const t of propagationTimes(cube, position.t)) {
+    for (const cell of footprintCells(object.archetype.components, position)) {
+      if (!isInPlaneBounds(cube, cell)) {
+        return { ok: false, error: { kind: 'OutOfBounds', x: cell.x, y: cell.y, t } }
+      }
+
+      const blocker = objectsAt(cube, { ...cell, t }).find((other) =>
+        blocksMovementAt(cube, other, t),
+      )
+
+      if (blocker) {
         return {
           ok: false,
-          error: {
-            kind: 'EntityNotInSlice',
-            id: relocation.id,
-            t,
-          },
+          error: { kind: 'TargetOccupied', id: blocker.id, x: cell.x, y: cell.y, t },
         }
       }
+    }
 
-      const nextSourceIds = removeId(sourceIds, relocation.id)
+    nextSlices[t] = addToSlice(cube.slices[t], object, { ...position, t })
+  }
 
-      if (nextSourceIds.length === 0) {
-        delete slice.spatialIndex[fromKey]
-      } else {
-        slice.spatialIndex[fromKey] = nextSourceIds
-      }
+  return { ok: true, value: { ...nextCube, slices: nextSlices } }
+}
+
+/** Whether `id` is in slice `t` at all, wherever it stands there. */
+export function isInSlice(cube: TimeCube, id: string, t: number): boolean {
+  return t >= 0 && t < cube.timeDepth && cube.slices[t].objectIds.includes(id)
+}
+
+/**
+ * Rewrites where `id` sits slice by slice: `path[i]` is its cell at `startTime + i`. Unlike
+ * relocations, each entry only touches its own slice, so later slices are not carried along.
+ * Target cells must be empty except for ids in `tolerated`; cells it already holds are kept.
+ */
+export function retraceObject(
+  cube: TimeCube,
+  id: string,
+  startTime: number,
+  path: Position2D[],
+  tolerated: string[] = [],
+): Result<TimeCube, CubeError | RelocationError> {
+  const object = cube.objectsById[id]
+
+  if (!object) {
+    return { ok: false, error: { kind: 'EntityNotFound', id } }
+  }
+
+  if (startTime < 0 || startTime + path.length > cube.timeDepth) {
+    return { ok: false, error: { kind: 'InvalidRelocationTime', t: startTime } }
+  }
+
+  if (path.length === 0) {
+    return { ok: true, value: cube }
+  }
+
+  const nextSlices = [...cube.slices]
+
+  for (let index = 0; index < path.length; index += 1) {
+    const t = startTime + index
+    const to = path[index]
+    const offPlane = cellOffPlane(cube, id, to)
+
+    if (offPlane) {
+      return { ok: false, error: { kind: 'OutOfBounds', x: offPlane.x, y: offPlane.y, t } }
     }
 
-    for (const relocation of relocations) {
-      const toKey = spatialKey(relocation.to)
-      const targetIds = slice.spatialIndex[toKey] ?? []
-      slice.spatialIndex[toKey] = [...targetIds, relocation.id]
+    const from = objectPositionAt(cube, id, t)
+
+    if (!from) {
+      return { ok: false, error: { kind: 'EntityNotInSlice', id, t } }
+    }
+
+    if (from.x === to.x && from.y === to.y) {
+      continue
+    }
+
+    const slice = cloneSlice(cube.slices[t])
+
+    dropFromIndex(slice, id)
+
+    for (const cell of footprintCells(object.archetype.components, to)) {
+      const toKey = spatialKey(cell)
+      const occupants = (slice.spatialIndex[toKey] ?? []).filter(
+        (occupant) => !tolerated.includes(occupant),
+      )
+
+      if (occupants.length > 0) {
+        return {
+          ok: false,
+          error: { kind: 'TargetOccupied', id: occupants[0], x: cell.x, y: cell.y, t },
+        }
+      }
+
+      slice.spatialIndex[toKey] = [...(slice.spatialIndex[toKey] ?? []), id]
     }
+
+    nextSlices[t] = slice
   }
 
-  for (const relocation of relocations) {
-    const object = nextObjectsById[relocation.id]
+  return {
+    ok: true,
+    value: {
+      ...cube,
+      slices: nextSlices,
+      objectsById: {
+        ...cube.objectsById,
+        [id]: { ...object, position: { x: path[0].x, y: path[0].y, t: startTime } },
+      },
+    },
+  }
+}
 
-    if (!object) {
-      return { ok: false, error: { kind: 'EntityNotFound', id: relocation.id } }
+/**
+ * Sends an object through a rift: it leaves every slice from `departureTime` on and reappears at
+ * `arrival` from `arrival.t` on (or only in that slice when it is not time-persistent). When the
+ * arrival is earlier than the departure, the object's history from `arrival.t` is rewritten too;
+ * paradox checks decide whether that history was still relied upon. Like `retraceObject`, slices
+ * before the first one it rewrites are shared with `cube` untouched; on a wrapping cube the change
+ * carries round the seam into them instead, as in `moveObjectThroughTime`.
+ */
+export function relocateThroughTime(
+  cube: TimeCube,
+  id: string,
+  departureTime: number,
+  arrival: Position3D,
+): Result<TimeCube, CubeError | RelocationError> {
+  if (departureTime < 0 || departureTime >= cube.timeDepth) {
+    return { ok: false, error: { kind: 'InvalidRelocationTime', t: departureTime } }
+  }
+
+  if (!isInCubeBounds(cube, arrival)) {
+    return { ok: false, error: { kind: 'OutOfBounds', x: arrival.x, y: arrival.y, t: arrival.t } }
+  }
+
+  const object = cube.objectsById[id]
+
+  if (!object) {
+    return { ok: false, error: { kind: 'EntityNotFound', id } }
+  }
+
+  const offPlane = cellOffPlane(cube, id, arrival)
+
+  if (offPlane) {
+    return { ok: false, error: { kind: 'OutOfBounds', x: offPlane.x, y: offPlane.y, t: arrival.t } }
+  }
+
+  const isTimePersistent = hasComponent(object.archetype.components, 'TimePersistent')
+  const kind = object.archetype.kind
+  const arrivalCells = footprintCells(object.archetype.components, arrival)
+  const nextSlices = [...cube.slices]
+  const times = propagationTimes(cube, Math.min(departureTime, arrival.t))
+  const arrivalIndex = times.indexOf(arrival.t)
+
+  for (const [index, t] of times.entries()) {
+    const slice = cloneSlice(cube.slices[t])
+    nextSlices[t] = slice
+
+    dropFromIndex(slice, id)
+    slice.objectIds = removeId(slice.objectIds, id)
+    slice.idsByKind[kind] = removeId(slice.idsByKind[kind] ?? [], id)
+
+    if (index < arrivalIndex || (!isTimePersistent && t !== arrival.t)) {
+      continue
     }
 
-    nextObjectsById[relocation.id] = {
-      ...object,
-      position: { x: relocation.to.x, y: relocation.to.y, t: startTime },
+    for (const cell of arrivalCells) {
+      const arrivalKey = spatialKey(cell)
+      const targetIds = slice.spatialIndex[arrivalKey] ?? []
+      const blocker = targetIds.find((entry) => {
+        const other = cube.objectsById[entry]
+        return other ? hasComponent(other.archetype.components, 'BlocksMovement') : false
+      })
+
+      if (blocker) {
+        return {
+          ok: false,
+          error: { kind: 'TargetOccupied', id: blocker, x: cell.x, y: cell.y, t },
+        }
+      }
+
+      slice.spatialIndex[arrivalKey] = [...targetIds, id]
     }
+
+    slice.objectIds.push(id)
+    slice.idsByKind[kind].push(id)
   }
 
   return {
     ok: true,
     value: {
       ...cube,
-      objectsById: nextObjectsById,
+      objectsById: { ...cube.objectsById, [id]: { ...object, position: arrival } },
       slices: nextSlices,
     },
   }
@@ -0,0 +1,45 @@
+import { describe, expect, it } from 'vitest'
+
+import { createTimeCube } from './timeCube'
+import {
+  activeTimeline,
+  createTimelineSet,
+  forkTimeline,
+  markActiveTimelineInconsistent,
+  nextTimelineId,
+  ORIGINAL_TIMELINE_ID,
+  timelineById,
+  updateActiveTimeline,
+} from './timelineSet'
+
+describe('timeline set', () => {
+  it('keeps the old timeline when the past is altered and plays on in the fork', () => {
+    const original = createTimeCube(4, 4, 6)
+    const played = createTimeCube(4, 4, 6)
+    const altered = createTimeCube(4, 4, 6)
+    const set = updateActiveTimeline(createTimelineSet(original), played)
+    const forked = forkTimeline(set, played, altered, 2)
+
+    expect(forked.activeId).toBe('timeline-1')
+    expect(timelineById(forked, ORIGINAL_TIMELINE_ID)?.cube).toBe(played)
+    expect(activeTimeline(forked)).toEqual({
+      id: 'timeline-1',
+      parentId: ORIGINAL_TIMELINE_ID,
+      forkTime: 2,
+      cube: altered,
+      consistent: true,
+    })
+  })
+
+  it('taints only the active branch and cycles through every branch', () => {
+    const cube = createTimeCube(4, 4, 6)
+    const tainted = markActiveTimelineInconsistent(createTimelineSet(cube))
+    const forked = forkTimeline(tainted, cube, cube, 1)
+
+    expect(timelineById(forked, ORIGINAL_TIMELINE_ID)?.consistent).toBe(false)
+    expect(activeTimeline(forked).consistent).toBe(true)
+    expect(nextTimelineId(forked, ORIGINAL_TIMELINE_ID)).toBe('timeline-1')
+    expect(nextTimelineId(forked, 'timeline-1')).toBe(ORIGINAL_TIMELINE_ID)
+    expect(timelineById(forked, 'timeline-9')).toBeNull()
+  })
+})
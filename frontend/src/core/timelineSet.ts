@@ -0,0 +1,88 @@
+import type { TimeCube } from './timeCube'
+
+/** One version of the cube; every branch but the first forked off its parent. */
+export interface TimelineBranch {
+  id: string
+  parentId: string | null
+  /** First slice that differs from the parent; 0 for the original timeline. */
+  forkTime: number
+  cube: TimeCube
+  /** Cleared once a paradox breaks the player's history here; exits in it no longer win. */
+  consistent: boolean
+}
+
+/**
+ * Branching mode: altering the past forks a new branch instead of overwriting the cube, so every
+ * timeline the player made stays around. Play always continues in the active branch.
+ */
+export interface TimelineSet {
+  branches: TimelineBranch[]
+  activeId: string
+}
+
+export const ORIGINAL_TIMELINE_ID = 'timeline-0'
+
+export function createTimelineSet(cube: TimeCube): TimelineSet {
+  return {
+    branches: [
+      { id: ORIGINAL_TIMELINE_ID, parentId: null, forkTime: 0, cube, consistent: true },
+    ],
+    activeId: ORIGINAL_TIMELINE_ID,
+  }
+}
+
+export function timelineById(set: TimelineSet, id: string): TimelineBranch | null {
+  return set.branches.find((branch) => branch.id === id) ?? null
+}
+
+export function activeTimeline(set: TimelineSet): TimelineBranch {
+  return timelineById(set, set.activeId) ?? set.branches[0]
+}
+
+function replaceActive(
+  set: TimelineSet,
+  update: (branch: TimelineBranch) => TimelineBranch,
+): TimelineSet {
+  return {
+    ...set,
+    branches: set.branches.map((branch) => (branch.id === set.activeId ? update(branch) : branch)),
+  }
+}
+
+/**
+ * Keeps the active branch as `before` and continues play in a new branch holding `after`, which
+ * first differs from it at `forkTime`. A fresh fork starts out consistent.
+ */
+export function forkTimeline(
+  set: TimelineSet,
+  before: TimeCube,
+  after: TimeCube,
+  forkTime: number,
+): TimelineSet {
+  const parent = activeTimeline(set)
+  const id = `timeline-${set.branches.length}`
+  const preserved = replaceActive(set, (branch) => ({ ...branch, cube: before }))
+
+  return {
+    branches: [
+      ...preserved.branches,
+      { id, parentId: parent.id, forkTime, cube: after, consistent: true },
+    ],
+    activeId: id,
+  }
+}
+
+/** Records the active branch's cube after a turn that left the past alone. */
+export function updateActiveTimeline(set: TimelineSet, cube: TimeCube): TimelineSet {
+  return replaceActive(set, (branch) => ({ ...branch, cube }))
+}
+
+export function markActiveTimelineInconsistent(set: TimelineSet): TimelineSet {
+  return replaceActive(set, (branch) => ({ ...branch, consistent: false }))
+}
+
+/** Branch after `id` in fork order, wrapping back to the original timeline. */
+export function nextTimelineId(set: TimelineSet, id: string): string {
+  const index = set.branches.findIndex((branch) => branch.id === id)
+  return set.branches[(index + 1) % set.branches.length].id
+}
@@ -0,0 +1,64 @@
+import { afterEach, describe, expect, it } from 'vitest'
+
+import { createMemoryTraceSink, formatTraceLine, setTraceSink, traceSpan } from './trace'
+
+describe('trace', () => {
+  afterEach(() => {
+    setTraceSink(null)
+  })
+
+  it('is a plain call while no sink is set', () => {
+    expect(traceSpan('idle', {}, () => 3)).toBe(3)
+  })
+
+  it('records nested spans innermost first with summarized fields', () => {
+    const sink = createMemoryTraceSink()
+
+    setTraceSink(sink)
+    const value = traceSpan(
+      'outer',
+      { turn: 1 },
+      () => traceSpan('inner', { id: 'box.main' }, () => 'moved'),
+      (result) => ({ result }),
+    )
+
+    expect(value).toBe('moved')
+    expect(sink.records.map(({ span, depth, fields }) => ({ span, depth, fields }))).toEqual([
+      { span: 'inner', depth: 1, fields: { id: 'box.main' } },
+      { span: 'outer', depth: 0, fields: { turn: 1, result: 'moved' } },
+    ])
+  })
+
+  it('restores the depth when a span throws', () => {
+    const sink = createMemoryTraceSink()
+
+    setTraceSink(sink)
+    expect(() =>
+      traceSpan('broken', {}, () => {
+        throw new Error('boom')
+      }),
+    ).toThrow('boom')
+    traceSpan('after', {}, () => null)
+
+    expect(sink.records).toHaveLength(1)
+    expect(sink.records[0].depth).toBe(0)
+  })
+
+  it('formats one JSON object per line', () => {
+    const line = formatTraceLine({
+      span: 'detection',
+      depth: 1,
+      durationMs: 0.12345,
+      fields: { time: 4, detected: false },
+    })
+
+    expect(line.endsWith('\n')).toBe(true)
+    expect(JSON.parse(line)).toEqual({
+      span: 'detection',
+      depth: 1,
+      ms: 0.123,
+      time: 4,
+      detected: false,
+    })
+  })
+})
@@ -0,0 +1,80 @@
+/** Structured fields attached to a span: ids, positions, counts. Must survive `JSON.stringify`. */
+export type TraceFields = Record<string, unknown>
+
+/** One finished span; nested spans finish (and are recorded) before their parent. */
+export interface TraceRecord {
+  span: string
+  /** Nesting level; 0 for a span opened outside any other. */
+  depth: number
+  durationMs: number
+  fields: TraceFields
+}
+
+export interface TraceSink {
+  record(record: TraceRecord): void
+}
+
+let activeSink: TraceSink | null = null
+let depth = 0
+
+/** Routes spans to `sink`, or turns tracing off with null. Off by default. */
+export function setTraceSink(sink: TraceSink | null): void {
+  activeSink = sink
+}
+
+/**
+ * Runs `run` inside a named span. `summarize` adds fields that depend on the result (what was
+ * detected, which objects moved). With no sink set this is a plain call.
+ */
+export function traceSpan<T>(
+  span: string,
+  fields: TraceFields,
+  run: () => T,
+  summarize?: (value: T) => TraceFields,
+): T {
+  const sink = activeSink
+
+  if (!sink) {
+    return run()
+  }
+
+  const startedAt = performance.now()
+  const spanDepth = depth
+
+  depth += 1
+
+  try {
+    const value = run()
+
+    sink.record({
+      span,
+      depth: spanDepth,
+      durationMs: performance.now() - startedAt,
+      fields: summarize ? { ...fields, ...summarize(value) } : fields,
+    })
+    return value
+  } finally {
+    depth = spanDepth
+  }
+}
+
+/** One JSON object per line, for `--log-file`. */
+export function formatTraceLine(record: TraceRecord): string {
+  return `${JSON.stringify({
+    span: record.span,
+    depth: record.depth,
+    ms: Number(record.durationMs.toFixed(3)),
+    ...record.fields,
+  })}\n`
+}
+
+export function createMemoryTraceSink(): TraceSink & { records: TraceRecord[] } {
+  const records: TraceRecord[] = []
+
+  return {
+    records,
+    record(record) {
+      records.push(record)
+    },
+  }
+}
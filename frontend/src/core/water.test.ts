@@ -0,0 +1,64 @@
+import { describe, expect, it } from 'vitest'
+
+import type { ResolvedObjectInstance } from './objects'
+import { createTimeCube, isBlocked, objectsAt, placeObjects } from './timeCube'
+import { sinkIntoWater, waterAt } from './water'
+
+function tile(id: string, x: number, kind: 'water' | 'box'): ResolvedObjectInstance {
+  return {
+    id,
+    archetypeKey: kind,
+    position: { x, y: 1, t: 0 },
+    archetype: {
+      kind,
+      components: [
+        { kind: 'BlocksMovement' },
+        { kind: 'TimePersistent' },
+        kind === 'water' ? { kind: 'Water' } : { kind: 'Pushable' },
+      ],
+      render: {},
+    },
+  }
+}
+
+describe('water', () => {
+  it('finds water only where nothing else blocks the cell', () => {
+    const placed = placeObjects(createTimeCube(5, 3, 4), [
+      tile('water.1', 2, 'water'),
+      tile('box.a', 1, 'box'),
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    expect(waterAt(placed.value, { x: 2, y: 1, t: 0 })?.id).toBe('water.1')
+    expect(waterAt(placed.value, { x: 1, y: 1, t: 0 })).toBeNull()
+    expect(waterAt(placed.value, { x: 3, y: 1, t: 0 })).toBeNull()
+  })
+
+  it('turns the water cell into floor from the sinking slice on', () => {
+    const placed = placeObjects(createTimeCube(5, 3, 4), [
+      tile('water.1', 2, 'water'),
+      tile('box.a', 1, 'box'),
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const sunk = sinkIntoWater(placed.value, 'box.a', 'water.1', 2)
+
+    expect(sunk.ok).toBe(true)
+    if (!sunk.ok) {
+      return
+    }
+
+    expect(isBlocked(sunk.value, { x: 2, y: 1, t: 1 })).toBe(true)
+    expect(isBlocked(sunk.value, { x: 2, y: 1, t: 2 })).toBe(false)
+    expect(objectsAt(sunk.value, { x: 1, y: 1, t: 3 })).toEqual([])
+    expect(sinkIntoWater(placed.value, 'box.a', 'water.missing', 2).ok).toBe(false)
+  })
+})
@@ -0,0 +1,37 @@
+import { hasComponent } from './components'
+import type { ResolvedObjectInstance } from './objects'
+import type { Position3D } from './position'
+import type { Result } from './result'
+import {
+  objectsAt,
+  removeObjectFromTime,
+  type CubeError,
+  type RelocationError,
+  type TimeCube,
+} from './timeCube'
+
+/**
+ * The water tile filling `position`, if water is all that blocks it. The player cannot wade in,
+ * but a box pushed there sinks and leaves walkable floor behind.
+ */
+export function waterAt(cube: TimeCube, position: Position3D): ResolvedObjectInstance | null {
+  const blockers = objectsAt(cube, position).filter((object) =>
+    hasComponent(object.archetype.components, 'BlocksMovement'),
+  )
+
+  return blockers.length === 1 && hasComponent(blockers[0].archetype.components, 'Water')
+    ? blockers[0]
+    : null
+}
+
+/** Sinks `boxId` into `waterId` from `startTime` on: both leave the cube and the cell is floor. */
+export function sinkIntoWater(
+  cube: TimeCube,
+  boxId: string,
+  waterId: string,
+  startTime: number,
+): Result<TimeCube, CubeError | RelocationError> {
+  const withoutBox = removeObjectFromTime(cube, boxId, startTime)
+
+  return withoutBox.ok ? removeObjectFromTime(withoutBox.value, waterId, startTime) : withoutBox
+}
@@ -1,14 +1,43 @@
 import { describe, expect, it } from 'vitest'
 
-import type { Position3D } from './position'
+import { asTurn } from './coords'
+import { NO_WRAPPING, type Position3D } from './position'
 import {
+  checkpoint,
   createWorldLine,
+  currentPositionAtTime,
   extendNormal,
   extendViaRift,
+  followingTime,
+  headTurn,
+  lastStepKind,
+  maxTime,
   positionsAtTime,
+  rollback,
+  timeAtTurn,
+  truncateToTurn,
   wouldIntersect,
+  type WorldLineState,
 } from './worldLine'
 
+const LOOP_OF_3 = { ...NO_WRAPPING, timeDepth: 3 }
+
+function walkEast(start: Position3D, steps: number): WorldLineState {
+  let worldLine = createWorldLine(start)
+
+  for (let step = 1; step <= steps; step += 1) {
+    const next = extendNormal(worldLine, { x: start.x + step, y: start.y, t: start.t + step })
+
+    if (!next.ok) {
+      throw new Error('walkEast expects a free corridor')
+    }
+
+    worldLine = next.value
+  }
+
+  return worldLine
+}
+
 describe('worldLine', () => {
   it('extends with a valid normal step', () => {
     const start: Position3D = { x: 1, y: 1, t: 0 }
@@ -91,4 +120,119 @@ describe('worldLine', () => {
     expect(atT1[1].turn).toBe(3)
     expect(wouldIntersect(rift.value, { x: 4, y: 1, t: 1 })).toBe(true)
   })
+
+  it('rolls back to a checkpoint and frees the dropped cells', () => {
+    const base = walkEast({ x: 0, y: 0, t: 0 }, 1)
+    const marker = checkpoint(base)
+    const explored = walkEast({ x: 0, y: 0, t: 0 }, 3)
+
+    const restored = rollback(explored, marker)
+
+    expect(restored.ok).toBe(true)
+    if (!restored.ok) {
+      return
+    }
+
+    expect(restored.value).toEqual(base)
+    expect(wouldIntersect(restored.value, { x: 3, y: 0, t: 3 })).toBe(false)
+  })
+
+  it('rejects a checkpoint the line no longer passes through', () => {
+    const marker = checkpoint(walkEast({ x: 0, y: 0, t: 0 }, 3))
+    const shorter = walkEast({ x: 0, y: 0, t: 0 }, 1)
+    const elsewhere = walkEast({ x: 5, y: 5, t: 0 }, 3)
+
+    expect(rollback(shorter, marker).ok).toBe(false)
+    expect(rollback(elsewhere, marker)).toEqual({
+      ok: false,
+      error: { kind: 'StaleCheckpoint', checkpoint: marker },
+    })
+  })
+
+  it('truncates to a turn for rewinding', () => {
+    const worldLine = walkEast({ x: 0, y: 0, t: 0 }, 3)
+    const rewound = truncateToTurn(worldLine, asTurn(1))
+
+    expect(rewound.ok).toBe(true)
+    if (rewound.ok) {
+      expect(rewound.value.path).toEqual([
+        { x: 0, y: 0, t: 0 },
+        { x: 1, y: 0, t: 1 },
+      ])
+      expect(Object.keys(rewound.value.visited)).toEqual(['0,0,0', '1,0,1'])
+    }
+
+    expect(truncateToTurn(worldLine, asTurn(4))).toEqual({
+      ok: false,
+      error: { kind: 'TurnOutOfRange', turn: 4, length: 4 },
+    })
+  })
+
+  it('records how each step was made and drops it on rollback', () => {
+    const start = walkEast({ x: 0, y: 0, t: 0 }, 1)
+    const slid = extendNormal(start, { x: 2, y: 0, t: 2 }, 'Slide')
+
+    expect(slid.ok).toBe(true)
+    if (!slid.ok) {
+      return
+    }
+
+    const rifted = extendViaRift(slid.value, { x: 0, y: 3, t: 0 })
+
+    expect(rifted.ok).toBe(true)
+    if (!rifted.ok) {
+      return
+    }
+
+    expect(rifted.value.steps).toEqual(['Start', 'Normal', 'Slide', 'Rift'])
+    expect(lastStepKind(rifted.value)).toBe('Rift')
+
+    const rewound = truncateToTurn(rifted.value, asTurn(1))
+
+    expect(rewound.ok && rewound.value.steps).toEqual(['Start', 'Normal'])
+  })
+
+  it('answers time queries after a rift back to an earlier slice', () => {
+    const walked = walkEast({ x: 0, y: 0, t: 0 }, 3)
+    const rifted = extendViaRift(walked, { x: 1, y: 2, t: 1 })
+
+    expect(rifted.ok).toBe(true)
+    if (!rifted.ok) {
+      return
+    }
+
+    expect(maxTime(rifted.value)).toBe(3)
+    expect(positionsAtTime(rifted.value, 1).map((entry) => entry.turn)).toEqual([1, 4])
+    expect(currentPositionAtTime(rifted.value, 1)).toEqual({
+      position: { x: 1, y: 2, t: 1 },
+      turn: 4,
+    })
+    expect(currentPositionAtTime(rifted.value, 2)?.turn).toBe(2)
+    expect(currentPositionAtTime(rifted.value, 5)).toBeNull()
+    expect(headTurn(rifted.value)).toBe(4)
+    expect(timeAtTurn(rifted.value, headTurn(rifted.value))).toBe(1)
+    expect(timeAtTurn(rifted.value, asTurn(5))).toBeNull()
+  })
+
+  it('crosses the seam back to slice 0 only on time-loop levels', () => {
+    const walked = walkEast({ x: 0, y: 0, t: 0 }, 2)
+
+    expect(followingTime(2)).toBe(3)
+    expect(followingTime(2, 3)).toBe(0)
+    expect(extendNormal(walked, { x: 3, y: 0, t: 0 }).ok).toBe(false)
+
+    const wrapped = extendNormal(walked, { x: 3, y: 0, t: 0 }, 'Normal', LOOP_OF_3)
+
+    expect(wrapped.ok && wrapped.value.path.at(-1)).toEqual({ x: 3, y: 0, t: 0 })
+    expect(wrapped.ok && positionsAtTime(wrapped.value, 0).map((entry) => entry.turn)).toEqual([
+      0, 3,
+    ])
+    expect(
+      wrapped.ok && extendNormal(wrapped.value, { x: 2, y: 0, t: 1 }, 'Normal', LOOP_OF_3).ok,
+    ).toBe(true)
+    expect(extendNormal(walked, { x: 0, y: 0, t: 0 }, 'Normal', LOOP_OF_3)).toMatchObject({
+      ok: false,
+      error: { kind: 'SelfIntersection' },
+    })
+  })
 })
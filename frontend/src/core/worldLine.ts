@@ -1,8 +1,21 @@
-import { manhattanDistance, type Position3D } from './position'
+import { asTimeCoord, asTurn, type TimeCoord, type Turn } from './coords'
+import { NO_WRAPPING, wrappedDistance, type Position3D, type Wrapping } from './position'
 import type { Result } from './result'
 
+/**
+ * How the step into a path entry was made. `Slide` is a forced step the player did not choose
+ * (ice); `Rewound` is the first step taken after the line was cut back to an earlier turn;
+ * `Climb` goes up or down a ladder in place, switching layer (see `layerAtTurn`).
+ */
+export type WorldLineStepKind = 'Start' | 'Normal' | 'Rift' | 'Slide' | 'Rewound' | 'Climb'
+
+/** Step kinds of one-slice moves, which all go through `extendNormal`'s adjacency rule. */
+export type NormalStepKind = Extract<WorldLineStepKind, 'Normal' | 'Slide' | 'Rewound' | 'Climb'>
+
 export interface WorldLineState {
   path: Position3D[]
+  // Parallel to `path`: steps[i] says how path[i] was reached.
+  steps: WorldLineStepKind[]
   // Sparse membership index: key exists only when (x,y,t) has been visited.
   // We store literal true (instead of boolean flags) for O(1) checks and
   // Redux-friendly serializable state.
@@ -13,10 +26,22 @@ export type WorldLineError =
   | { kind: 'EmptyWorldLine' }
   | { kind: 'SelfIntersection'; position: Position3D }
   | { kind: 'InvalidNormalStep'; from: Position3D; to: Position3D }
+  | { kind: 'StaleCheckpoint'; checkpoint: WorldLineCheckpoint }
+  | { kind: 'TurnOutOfRange'; turn: number; length: number }
+
+/**
+ * Marker for a world line's current head. Holding one costs nothing; rolling back to it drops
+ * every later step, so search and preview code can explore by extending and rolling back instead
+ * of cloning the line up front.
+ */
+export interface WorldLineCheckpoint {
+  length: number
+  headKey: string | null
+}
 
 export interface PositionAtTime {
   position: Position3D
-  turn: number
+  turn: Turn
 }
 
 export function positionKey(position: Position3D): string {
@@ -26,6 +51,7 @@ export function positionKey(position: Position3D): string {
 export function createWorldLine(start: Position3D): WorldLineState {
   return {
     path: [start],
+    steps: ['Start'],
     visited: { [positionKey(start)]: true },
   }
 }
@@ -34,6 +60,14 @@ export function currentPosition(worldLine: WorldLineState): Position3D | null {
   return worldLine.path.at(-1) ?? null
 }
 
+/**
+ * Slice after `t`. `wrapDepth` is the time depth of a time-loop level, where the last slice is
+ * followed by slice 0, and null everywhere else.
+ */
+export function followingTime(t: number, wrapDepth: number | null = null): number {
+  return wrapDepth !== null && t + 1 >= wrapDepth ? 0 : t + 1
+}
+
 export function wouldIntersect(worldLine: WorldLineState, position: Position3D): boolean {
   return Boolean(worldLine.visited[positionKey(position)])
 }
@@ -41,6 +75,8 @@ export function wouldIntersect(worldLine: WorldLineState, position: Position3D):
 export function extendNormal(
   worldLine: WorldLineState,
   next: Position3D,
+  kind: NormalStepKind = 'Normal',
+  wrapping: Wrapping = NO_WRAPPING,
 ): Result<WorldLineState, WorldLineError> {
   const current = currentPosition(worldLine)
 
@@ -52,8 +88,8 @@ export function extendNormal(
     return { ok: false, error: { kind: 'SelfIntersection', position: next } }
   }
 
-  const isNextTime = next.t === current.t + 1
-  const isAdjacentOrWait = manhattanDistance(current, next) <= 1
+  const isNextTime = next.t === followingTime(current.t, wrapping.timeDepth)
+  const isAdjacentOrWait = wrappedDistance(current, next, wrapping) <= 1
 
   if (!isNextTime || !isAdjacentOrWait) {
     return {
@@ -66,6 +102,7 @@ export function extendNormal(
     ok: true,
     value: {
       path: [...worldLine.path, next],
+      steps: [...worldLine.steps, kind],
       visited: { ...worldLine.visited, [positionKey(next)]: true },
     },
   }
@@ -89,13 +126,120 @@ export function extendViaRift(
     ok: true,
     value: {
       path: [...worldLine.path, next],
+      steps: [...worldLine.steps, 'Rift'],
       visited: { ...worldLine.visited, [positionKey(next)]: true },
     },
   }
 }
 
+/** Path index of the current position; -1 (not a valid turn) for an empty line. */
+export function headTurn(worldLine: WorldLineState): Turn {
+  return asTurn(worldLine.path.length - 1)
+}
+
+/** Slice the self at `turn` stands in; null past the end of the path. */
+export function timeAtTurn(worldLine: WorldLineState, turn: Turn): TimeCoord | null {
+  const position = worldLine.path[turn]
+
+  return position ? asTimeCoord(position.t) : null
+}
+
+/** How the latest step was made; null for an empty line. */
+export function lastStepKind(worldLine: WorldLineState): WorldLineStepKind | null {
+  return worldLine.steps.at(-1) ?? null
+}
+
+/**
+ * Latest slice the line has reached. Time is not monotonic along the path: after a rift back
+ * this stays at the furthest slice visited, not the current position's t. Null when empty.
+ */
+export function maxTime(worldLine: WorldLineState): number | null {
+  if (worldLine.path.length === 0) {
+    return null
+  }
+
+  return Math.max(...worldLine.path.map((position) => position.t))
+}
+
+/**
+ * Every self standing in slice `t`, oldest turn first. A rift back in time can leave several;
+ * an empty list means the line never visited `t`.
+ */
 export function positionsAtTime(worldLine: WorldLineState, t: number): PositionAtTime[] {
   return worldLine.path
-    .map((position, turn) => ({ position, turn }))
+    .map((position, turn) => ({ position, turn: asTurn(turn) }))
     .filter((entry) => entry.position.t === t)
 }
+
+export function checkpoint(worldLine: WorldLineState): WorldLineCheckpoint {
+  const head = currentPosition(worldLine)
+
+  return { length: worldLine.path.length, headKey: head ? positionKey(head) : null }
+}
+
+function keepPrefix(worldLine: WorldLineState, length: number): WorldLineState {
+  const visited = { ...worldLine.visited }
+
+  for (const position of worldLine.path.slice(length)) {
+    delete visited[positionKey(position)]
+  }
+
+  return {
+    path: worldLine.path.slice(0, length),
+    steps: worldLine.steps.slice(0, length),
+    visited,
+  }
+}
+
+/**
+ * Return the line as it was at `marker`. Fails with StaleCheckpoint when the line no longer
+ * passes through the marked head, e.g. it was already rolled back past it or belongs to another
+ * branch.
+ */
+export function rollback(
+  worldLine: WorldLineState,
+  marker: WorldLineCheckpoint,
+): Result<WorldLineState, WorldLineError> {
+  const head = worldLine.path[marker.length - 1]
+  const headKey = head ? positionKey(head) : null
+
+  if (marker.length > worldLine.path.length || headKey !== marker.headKey) {
+    return { ok: false, error: { kind: 'StaleCheckpoint', checkpoint: marker } }
+  }
+
+  return { ok: true, value: keepPrefix(worldLine, marker.length) }
+}
+
+/** Keep the path up to and including `turn` (0 is the start) and forget everything after it. */
+export function truncateToTurn(
+  worldLine: WorldLineState,
+  turn: Turn,
+): Result<WorldLineState, WorldLineError> {
+  if (!Number.isInteger(turn) || turn < 0 || turn >= worldLine.path.length) {
+    return {
+      ok: false,
+      error: { kind: 'TurnOutOfRange', turn, length: worldLine.path.length },
+    }
+  }
+
+  return { ok: true, value: keepPrefix(worldLine, turn + 1) }
+}
+
+/**
+ * The self that is "current" at slice `t`: the occurrence with the highest turn, i.e. the most
+ * recent visit. Earlier selves at `t` are past echoes the player has already lived through.
+ */
+export function currentPositionAtTime(
+  worldLine: WorldLineState,
+  t: number,
+): PositionAtTime | null {
+  for (let turn = worldLine.path.length - 1; turn >= 0; turn -= 1) {
+    const position = worldLine.path[turn]
+
+    if (position.t === t) {
+      return { position, turn: asTurn(turn) }
+    }
+  }
+
+  return null
+}
@@ -0,0 +1,88 @@
+import { describe, expect, it } from 'vitest'
+
+import { parseAsciiLevel } from './asciiLevel'
+import { validateContentPack } from './validate'
+
+const VAULT = `id: vault
+name: The Vault
+time_depth: 8
+legend: X = box
+---
+#######
+#@..E>#
+#O.X.O#
+#######
+`
+
+describe('parseAsciiLevel', () => {
+  it('turns glyphs into instances of the legend archetypes', () => {
+    const parsed = parseAsciiLevel(VAULT)
+
+    expect(parsed.ok).toBe(true)
+    if (!parsed.ok) {
+      return
+    }
+
+    const level = parsed.value
+
+    expect(level.meta).toEqual({ id: 'vault', name: 'The Vault' })
+    expect(level.map).toEqual({ width: 7, height: 4, timeDepth: 8, start: { x: 1, y: 1, t: 0 } })
+    expect(Object.keys(level.archetypes).sort()).toEqual(['box', 'enemy', 'exit', 'rift', 'wall'])
+    expect(level.instances.filter((instance) => instance.archetype === 'wall')).toHaveLength(18)
+    expect(level.instances).toContainEqual({
+      id: 'box.1',
+      archetype: 'box',
+      position: { x: 3, y: 2, t: 0 },
+    })
+    expect(level.instances).toContainEqual({
+      id: 'rift.2',
+      archetype: 'rift',
+      position: { x: 5, y: 2, t: 0 },
+    })
+  })
+
+  it('produces a level the content validator accepts', () => {
+    const parsed = parseAsciiLevel(VAULT)
+
+    expect(parsed.ok).toBe(true)
+    if (!parsed.ok) {
+      return
+    }
+
+    const validated = validateContentPack({
+      level: parsed.value,
+      behavior: { schemaVersion: 1, policies: {}, assignments: {} },
+      theme: { schemaVersion: 1, id: 'mono', iconPackId: 'default-mono', cssVars: {} },
+      rules: {
+        schemaVersion: 1,
+        rift: { defaultDelta: 3, baseEnergyCost: 0 },
+        interaction: { maxPushChain: 4, allowPull: true },
+        detection: { enabled: true, delayTurns: 1, maxDistance: 2 },
+      },
+    })
+
+    expect(validated.ok).toBe(true)
+  })
+
+  it('reads a bare map without a header', () => {
+    const parsed = parseAsciiLevel('@.>\n', { id: 'corridor' })
+
+    expect(parsed.ok && parsed.value.meta).toEqual({ id: 'corridor', name: 'corridor' })
+  })
+
+  it('reports the line of the first problem', () => {
+    expect(parseAsciiLevel('name: x\n---\n#@#\n#?#')).toEqual({
+      ok: false,
+      error: { line: 4, message: 'glyph ? is not in the legend' },
+    })
+    expect(parseAsciiLevel('legend: @ = wall\n---\n@')).toEqual({
+      ok: false,
+      error: { line: 1, message: '@ cannot be remapped' },
+    })
+    expect(parseAsciiLevel('---\n@.@')).toEqual({
+      ok: false,
+      error: { line: 2, message: 'more than one @ start' },
+    })
+    expect(parseAsciiLevel('---\n###').ok).toBe(false)
+  })
+})
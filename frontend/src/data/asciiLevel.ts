@@ -0,0 +1,240 @@
+import type { Result } from '../core/result'
+import type { ContentArchetype, ContentInstance, LevelConfig } from './contracts'
+
+/** Sketched levels sit next to (or instead of) `<id>.level.json`. */
+export const ASCII_LEVEL_SUFFIX = '.level.txt'
+
+export interface AsciiLevelError {
+  line: number
+  message: string
+}
+
+const START_GLYPH = '@'
+const EMPTY_GLYPHS = new Set(['.', ' '])
+const HEADER_END = '---'
+const DEFAULT_TIME_DEPTH = 16
+
+/** Every `O` on the map joins one ring: stepping into a rift comes out at the next. */
+const ASCII_RIFT_LINK = 'ascii'
+
+/** Archetypes a legend can point at; only the ones a map uses end up in the level. */
+export const ASCII_ARCHETYPES: Record<string, ContentArchetype> = {
+  wall: {
+    kind: 'wall',
+    components: [{ kind: 'BlocksMovement' }, { kind: 'BlocksVision' }, { kind: 'TimePersistent' }],
+    render: { fill: '#f0f0f0', stroke: '#111111', symbol: 'wall' },
+  },
+  exit: {
+    kind: 'exit',
+    components: [{ kind: 'Exit' }, { kind: 'TimePersistent' }],
+    render: { fill: '#ffffff', stroke: '#111111', symbol: 'exit' },
+  },
+  box: {
+    kind: 'box',
+    components: [
+      { kind: 'BlocksMovement' },
+      { kind: 'Pushable' },
+      { kind: 'Pullable' },
+      { kind: 'TimePersistent' },
+    ],
+    render: { fill: '#d9d9d9', stroke: '#111111', symbol: 'box' },
+  },
+  enemy: {
+    kind: 'enemy',
+    components: [{ kind: 'BlocksMovement' }, { kind: 'TimePersistent' }],
+    render: { fill: '#c6c6c6', stroke: '#111111', symbol: 'enemy' },
+  },
+  rift: {
+    kind: 'rift',
+    components: [
+      { kind: 'TimePersistent' },
+      { kind: 'Rift', linkId: ASCII_RIFT_LINK, bidirectional: true },
+    ],
+    render: { fill: '#ffffff', stroke: '#111111', symbol: 'rift' },
+  },
+}
+
+/** Glyph → key in `ASCII_ARCHETYPES`; `@` (start) and `.`/space (empty) are fixed. */
+export const DEFAULT_ASCII_LEGEND: Record<string, string> = {
+  '#': 'wall',
+  '>': 'exit',
+  B: 'box',
+  E: 'enemy',
+  O: 'rift',
+}
+
+const META_KEYS = ['id', 'name', 'author', 'description', 'difficulty'] as const
+
+type MetaKey = (typeof META_KEYS)[number]
+
+function isMetaKey(key: string): key is MetaKey {
+  return (META_KEYS as readonly string[]).includes(key)
+}
+
+interface AsciiHeader {
+  meta: Partial<Record<MetaKey, string>>
+  timeDepth: number
+  legend: Record<string, string>
+}
+
+/** `key: value` lines; `legend: X = archetype` may repeat to add or remap glyphs. */
+function parseHeader(
+  lines: string[],
+  legend: Record<string, string>,
+): Result<AsciiHeader, AsciiLevelError> {
+  const header: AsciiHeader = { meta: {}, timeDepth: DEFAULT_TIME_DEPTH, legend: { ...legend } }
+
+  for (let index = 0; index < lines.length; index += 1) {
+    const raw = lines[index].trim()
+    const line = index + 1
+
+    if (raw === '') {
+      continue
+    }
+
+    const colon = raw.indexOf(':')
+
+    if (colon < 0) {
+      return { ok: false, error: { line, message: `expected key: value, got ${raw}` } }
+    }
+
+    const key = raw.slice(0, colon).trim()
+    const value = raw.slice(colon + 1).trim()
+
+    if (key === 'legend') {
+      const match = /^(\S)\s*=\s*(\S+)$/.exec(value)
+
+      if (!match) {
+        return { ok: false, error: { line, message: 'legend entries look like X = archetype' } }
+      }
+
+      if (match[1] === START_GLYPH || EMPTY_GLYPHS.has(match[1])) {
+        return { ok: false, error: { line, message: `${match[1]} cannot be remapped` } }
+      }
+
+      if (!ASCII_ARCHETYPES[match[2]]) {
+        return { ok: false, error: { line, message: `unknown archetype ${match[2]}` } }
+      }
+
+      header.legend[match[1]] = match[2]
+    } else if (key === 'time_depth') {
+      const depth = Number(value)
+
+      if (!Number.isInteger(depth) || depth < 1) {
+        return { ok: false, error: { line, message: 'time_depth must be an integer >= 1' } }
+      }
+
+      header.timeDepth = depth
+    } else if (isMetaKey(key)) {
+      header.meta[key] = value
+    } else {
+      return { ok: false, error: { line, message: `unknown header key ${key}` } }
+    }
+  }
+
+  return { ok: true, value: header }
+}
+
+/**
+ * Converts a text sketch into level JSON. An optional header of `key: value` lines (`id`, `name`,
+ * `author`, `description`, `difficulty`, `time_depth`, `legend`) ends at a `---` line; the rest
+ * is the map, one row per line. Everything stands in slice 0 and persists through time. The
+ * result still goes through `validateContentPack`.
+ */
+export function parseAsciiLevel(
+  source: string,
+  options: { id?: string; legend?: Record<string, string> } = {},
+): Result<LevelConfig, AsciiLevelError> {
+  const lines = source.split(/\r?\n/)
+  const headerEnd = lines.findIndex((line) => line.trim() === HEADER_END)
+  const header = parseHeader(headerEnd < 0 ? [] : lines.slice(0, headerEnd), {
+    ...DEFAULT_ASCII_LEGEND,
+    ...options.legend,
+  })
+
+  if (!header.ok) {
+    return header
+  }
+
+  const firstRow = headerEnd + 1
+  const rows = lines.slice(firstRow)
+
+  while (rows.length > 0 && rows[rows.length - 1].trim() === '') {
+    rows.pop()
+  }
+
+  if (rows.length === 0) {
+    return { ok: false, error: { line: firstRow + 1, message: 'the map has no rows' } }
+  }
+
+  const { legend, meta, timeDepth } = header.value
+  const archetypes: Record<string, ContentArchetype> = {}
+  const instances: ContentInstance[] = []
+  const counts: Record<string, number> = {}
+  let start: { x: number; y: number } | null = null
+
+  for (let y = 0; y < rows.length; y += 1) {
+    const line = firstRow + y + 1
+
+    for (let x = 0; x < rows[y].length; x += 1) {
+      const glyph = rows[y][x]
+
+      if (EMPTY_GLYPHS.has(glyph)) {
+        continue
+      }
+
+      if (glyph === START_GLYPH) {
+        if (start) {
+          return { ok: false, error: { line, message: 'more than one @ start' } }
+        }
+
+        start = { x, y }
+        continue
+      }
+
+      const archetype = legend[glyph]
+
+      if (!archetype) {
+        return { ok: false, error: { line, message: `glyph ${glyph} is not in the legend` } }
+      }
+
+      counts[archetype] = (counts[archetype] ?? 0) + 1
+      archetypes[archetype] = ASCII_ARCHETYPES[archetype]
+      instances.push({
+        id: `${archetype}.${counts[archetype]}`,
+        archetype,
+        position: { x, y, t: 0 },
+      })
+    }
+  }
+
+  if (!start) {
+    return { ok: false, error: { line: firstRow + 1, message: 'the map has no @ start' } }
+  }
+
+  const id = meta.id ?? options.id ?? 'ascii-level'
+
+  return {
+    ok: true,
+    value: {
+      schemaVersion: 1,
+      meta: {
+        ...meta,
+        id,
+        name: meta.name ?? id,
+      } as LevelConfig['meta'],
+      map: {
+        width: Math.max(...rows.map((row) => row.length)),
+        height: rows.length,
+        timeDepth,
+        start: { ...start, t: 0 },
+      },
+      archetypes,
+      instances,
+    },
+  }
+}
+
+export function formatAsciiLevelError(file: string, error: AsciiLevelError): string {
+  return `${file} line ${error.line}: ${error.message}`
+}
@@ -62,6 +62,23 @@ describe('behaviorResolver', () => {
     ])
   })
 
+  it('holds each waypoint for its dwell duration', () => {
+    const path = [
+      { x: 0, y: 0 },
+      { x: 1, y: 0 },
+      { x: 2, y: 0 },
+    ]
+    const resolveAt = (kind: 'PatrolLoop' | 'PatrolPingPong', time: number) =>
+      resolveBehaviorPosition({ policy: { kind, path, dwell: [3, 1, 2] }, origin: path[0], time })
+
+    expect([0, 1, 2, 3, 4, 5, 6].map((time) => resolveAt('PatrolLoop', time).x)).toEqual([
+      0, 0, 0, 1, 2, 2, 0,
+    ])
+    expect([3, 4, 5, 6, 7, 8, 9].map((time) => resolveAt('PatrolPingPong', time).x)).toEqual([
+      1, 2, 2, 1, 0, 0, 0,
+    ])
+  })
+
   it('maps patrol policies to patrol components', () => {
     expect(behaviorToPatrolComponent({ kind: 'Static' })).toBeNull()
     expect(
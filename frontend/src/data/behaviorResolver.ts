@@ -1,12 +1,9 @@
 import type { Position2D } from '../core/position'
 import type { Component } from '../core/components'
+import { patrolPositionAt } from '../core/patrol'
 import type { DetectionConfig } from '../core/detection'
 import type { BehaviorConfig, BehaviorPolicy } from './contracts'
 
-function modulo(value: number, divisor: number): number {
-  return ((value % divisor) + divisor) % divisor
-}
-
 export function resolveBehaviorPosition(input: {
   policy: BehaviorPolicy
   origin: Position2D
@@ -17,28 +14,14 @@ export function resolveBehaviorPosition(input: {
   switch (policy.kind) {
     case 'Static':
       return origin
-    case 'PatrolLoop': {
-      if (policy.path.length === 0) {
-        return origin
-      }
-
-      return policy.path[modulo(time, policy.path.length)]
-    }
-    case 'PatrolPingPong': {
-      if (policy.path.length === 0) {
-        return origin
-      }
-
-      if (policy.path.length === 1) {
-        return policy.path[0]
-      }
-
-      const period = policy.path.length * 2 - 2
-      const index = modulo(time, period)
-      const mapped = index < policy.path.length ? index : period - index
-
-      return policy.path[mapped]
-    }
+    case 'PatrolLoop':
+    case 'PatrolPingPong':
+      return (
+        patrolPositionAt(
+          { path: policy.path, loops: policy.kind === 'PatrolLoop', dwell: policy.dwell },
+          time,
+        ) ?? origin
+      )
     case 'ScriptedTimeline': {
       if (policy.points.length === 0) {
         return origin
@@ -69,9 +52,13 @@ export function resolveBehaviorPosition(input: {
 export function behaviorToPatrolComponent(policy: BehaviorPolicy): Extract<Component, { kind: 'Patrol' }> | null {
   switch (policy.kind) {
     case 'PatrolLoop':
-      return { kind: 'Patrol', path: policy.path, loops: true }
-    case 'PatrolPingPong':
-      return { kind: 'Patrol', path: policy.path, loops: false }
+    case 'PatrolPingPong': {
+      const loops = policy.kind === 'PatrolLoop'
+
+      return policy.dwell
+        ? { kind: 'Patrol', path: policy.path, loops, dwell: policy.dwell }
+        : { kind: 'Patrol', path: policy.path, loops }
+    }
     case 'Static':
     case 'ScriptedTimeline':
       return null
@@ -108,12 +95,13 @@ export function resolveEnemyDetectionConfig(input: {
 
   const assignedProfileKey = behavior.detectionAssignments?.[enemyId]
 
+  // Profiles tune the guard; level lighting (`darkVisionFactor`) still comes from the rules.
   if (assignedProfileKey && profiles[assignedProfileKey]) {
-    return profiles[assignedProfileKey]
+    return { ...rulesDefault, ...profiles[assignedProfileKey] }
   }
 
   if (behavior.defaultDetectionProfile && profiles[behavior.defaultDetectionProfile]) {
-    return profiles[behavior.defaultDetectionProfile]
+    return { ...rulesDefault, ...profiles[behavior.defaultDetectionProfile] }
   }
 
   return rulesDefault
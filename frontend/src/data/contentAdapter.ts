@@ -1,14 +1,35 @@
-import type { Component } from '../core/components'
+import {
+  DEFAULT_ALARM_GLIMPSE_MARGIN,
+  DEFAULT_ALARM_RANGE_BONUS,
+  type AlarmConfig,
+} from '../core/alarm'
+import {
+  appearanceOf,
+  type Component,
+  type PatrolComponent,
+  type RiftComponent,
+} from '../core/components'
 import type { DetectionConfig } from '../core/detection'
+import type { KnockoutConfig } from '../core/knockout'
+import {
+  DEFAULT_BREAK_NOISE_RADIUS,
+  DEFAULT_LURE_SETTINGS,
+  type LureSettings,
+} from '../core/lure'
 import type { LevelObjectsConfig, ObjectArchetype, ObjectInstance } from '../core/objects'
-import type { ContentComponent, ContentPack } from './contracts'
+import { rollPatrolHolds } from '../core/patrol'
+import type { Position3D } from '../core/position'
+import { createRng } from '../core/rng'
+import type { ContentComponent, ContentInstance, ContentPack } from './contracts'
 import {
   behaviorToPatrolComponent,
   resolveBehaviorPolicy,
   resolveEnemyDetectionConfig,
 } from './behaviorResolver'
+import { resolveRiftLinkTargets } from './riftLinks'
 
-function toCoreComponent(component: ContentComponent): Component {
+/** Linked rifts have no archetype-wide target; they are attached per instance instead. */
+function toCoreComponent(component: ContentComponent): Component | null {
   switch (component.kind) {
     case 'BlocksMovement':
     case 'BlocksVision':
@@ -16,15 +37,82 @@ function toCoreComponent(component: ContentComponent): Component {
     case 'Exit':
     case 'Pushable':
     case 'Pullable':
+    case 'Heavy':
+    case 'Slippery':
+    case 'Ladder':
+    case 'Stackable':
+    case 'Water':
+    case 'Shadow':
+    case 'Anchored':
+    case 'Gate':
       return { kind: component.kind }
     case 'Patrol':
-      return { kind: 'Patrol', path: component.path, loops: component.loops }
+      return component.dwell
+        ? { kind: 'Patrol', path: component.path, loops: component.loops, dwell: component.dwell }
+        : { kind: 'Patrol', path: component.path, loops: component.loops }
+    case 'Guard':
+    case 'Camera':
+    case 'Searchlight':
+    case 'Height':
+    case 'LightSource':
+    case 'Catwalk':
+    case 'Breakable':
+    case 'DependsOn':
+    case 'Appearance':
+    case 'Footprint':
+    case 'Extraction':
+    case 'Interactable':
+      return { ...component }
     case 'Rift':
-      return { kind: 'Rift', target: component.target, bidirectional: component.bidirectional }
+      return component.target
+        ? { kind: 'Rift', target: component.target, bidirectional: component.bidirectional }
+        : null
   }
 }
 
+function linkedRiftComponents(
+  content: ContentPack,
+  instance: ContentInstance,
+  linkTargets: Record<string, Position3D>,
+): RiftComponent[] {
+  const archetype = content.level.archetypes[instance.archetype]
+  const target = linkTargets[instance.id]
+
+  if (!archetype || !target) {
+    return []
+  }
+
+  return archetype.components.flatMap((component): RiftComponent[] =>
+    component.kind === 'Rift' && component.linkId !== undefined
+      ? [{ kind: 'Rift', target, bidirectional: component.bidirectional }]
+      : [],
+  )
+}
+
+/** Each guard rolls its own hesitations, seeded by the level seed and its instance id. */
+function withPatrolHolds(
+  content: ContentPack,
+  instanceId: string,
+  patrol: PatrolComponent,
+  hesitation: number | undefined,
+): PatrolComponent {
+  if (!hesitation) {
+    return patrol
+  }
+
+  const holds = rollPatrolHolds(
+    patrol,
+    hesitation,
+    createRng(`${deriveRulesRngSeed(content)}:${instanceId}`),
+    content.level.map.timeDepth,
+  )
+
+  return holds.length > 0 ? { ...patrol, holds } : patrol
+}
+
 function applyBehaviorComponents(
+  content: ContentPack,
+  instanceId: string,
   baseComponents: Component[],
   policy: ContentPack['behavior']['policies'][string] | undefined,
 ): Component[] {
@@ -35,20 +123,59 @@ function applyBehaviorComponents(
   const nonPatrol = baseComponents.filter((component) => component.kind !== 'Patrol')
   const patrol = behaviorToPatrolComponent(policy)
 
-  return patrol ? [...nonPatrol, patrol] : nonPatrol
+  if (!patrol) {
+    return nonPatrol
+  }
+
+  const hesitation = 'hesitation' in policy ? policy.hesitation : undefined
+
+  return [...nonPatrol, withPatrolHolds(content, instanceId, patrol, hesitation)]
+}
+
+function hasInstanceAppearance(instance: ContentInstance): boolean {
+  return instance.glyph !== undefined || instance.color !== undefined
+}
+
+/** Instance glyph/color win over the archetype's Appearance field by field. */
+function applyInstanceAppearance(components: Component[], instance: ContentInstance): Component[] {
+  if (!hasInstanceAppearance(instance)) {
+    return components
+  }
+
+  const base = appearanceOf(components)
+
+  return [
+    ...components.filter((component) => component.kind !== 'Appearance'),
+    {
+      kind: 'Appearance',
+      ...base,
+      ...(instance.glyph !== undefined ? { glyph: instance.glyph } : {}),
+      ...(instance.color !== undefined ? { color: instance.color } : {}),
+    },
+  ]
+}
+
+function applyInstanceGroup(components: Component[], instance: ContentInstance): Component[] {
+  return instance.group === undefined
+    ? components
+    : [...components, { kind: 'Group', id: instance.group }]
 }
 
 /**
  * Convert validated content pack data into runtime `LevelObjectsConfig`.
- * This applies behavior policy overrides on top of level archetype components.
+ * This applies behavior policy overrides, resolved rift links, instance appearance and groups on
+ * top of level archetype components, and carries the level's regions and time-loop flag over to
+ * the cube.
  */
 export function buildLevelObjectsConfigFromContent(content: ContentPack): LevelObjectsConfig {
   const archetypes: Record<string, ObjectArchetype> = {}
+  const linkTargetsResult = resolveRiftLinkTargets(content.level)
+  const linkTargets = linkTargetsResult.ok ? linkTargetsResult.value : {}
 
   for (const [key, archetype] of Object.entries(content.level.archetypes)) {
     archetypes[key] = {
       kind: archetype.kind,
-      components: archetype.components.map(toCoreComponent),
+      components: archetype.components.flatMap((component) => toCoreComponent(component) ?? []),
       render: archetype.render,
     }
   }
@@ -56,8 +183,15 @@ export function buildLevelObjectsConfigFromContent(content: ContentPack): LevelO
   const instances: ObjectInstance[] = content.level.instances.map((instance) => {
     const behaviorPolicy = resolveBehaviorPolicy(content.behavior, instance.id) ?? undefined
     const baseArchetype = archetypes[instance.archetype]
+    const linkedRifts = linkedRiftComponents(content, instance, linkTargets)
 
-    if (!baseArchetype || !behaviorPolicy) {
+    if (
+      !baseArchetype ||
+      (!behaviorPolicy &&
+        linkedRifts.length === 0 &&
+        !hasInstanceAppearance(instance) &&
+        instance.group === undefined)
+    ) {
       return {
         id: instance.id,
         archetype: instance.archetype,
@@ -65,7 +199,21 @@ export function buildLevelObjectsConfigFromContent(content: ContentPack): LevelO
       }
     }
 
-    const overriddenComponents = applyBehaviorComponents(baseArchetype.components, behaviorPolicy)
+    const overriddenComponents = applyInstanceGroup(
+      applyInstanceAppearance(
+        [
+          ...applyBehaviorComponents(
+            content,
+            instance.id,
+            baseArchetype.components,
+            behaviorPolicy,
+          ),
+          ...linkedRifts,
+        ],
+        instance,
+      ),
+      instance,
+    )
 
     return {
       id: instance.id,
@@ -80,6 +228,10 @@ export function buildLevelObjectsConfigFromContent(content: ContentPack): LevelO
   return {
     archetypes,
     instances,
+    ...(content.level.regions ? { regions: content.level.regions } : {}),
+    ...(content.level.map.timeWraps ? { timeWraps: true } : {}),
+    ...(content.level.map.xWraps ? { xWraps: true } : {}),
+    ...(content.level.map.yWraps ? { yWraps: true } : {}),
   }
 }
 
@@ -87,15 +239,75 @@ export function buildLevelObjectsConfigFromContent(content: ContentPack): LevelO
  * Derive global detection config from content rules.
  */
 export function deriveRulesDetectionConfig(content: ContentPack): DetectionConfig {
-  return {
+  const { darkVisionFactor, objectMemory } = content.rules.detection
+  const config: DetectionConfig = {
     enabled: content.rules.detection.enabled,
     delayTurns: content.rules.detection.delayTurns,
     maxDistance: content.rules.detection.maxDistance,
+    ...(darkVisionFactor === undefined ? {} : { darkVisionFactor }),
   }
+
+  return objectMemory ? { ...config, objectMemory } : config
+}
+
+export function deriveRulesLureSettings(content: ContentPack): LureSettings {
+  return { ...DEFAULT_LURE_SETTINGS, ...content.rules.interaction.lures }
+}
+
+export function deriveRulesBreakNoiseRadius(content: ContentPack): number {
+  return content.rules.interaction.breakNoiseRadius ?? DEFAULT_BREAK_NOISE_RADIUS
+}
+
+/** Null when the level has no alarm. */
+export function deriveRulesAlarmConfig(content: ContentPack): AlarmConfig | null {
+  const alarm = content.rules.alarm
+
+  return alarm
+    ? {
+        threshold: alarm.threshold,
+        lockdownTurns: alarm.lockdownTurns,
+        glimpseMargin: alarm.glimpseMargin ?? DEFAULT_ALARM_GLIMPSE_MARGIN,
+        rangeBonus: alarm.rangeBonus ?? DEFAULT_ALARM_RANGE_BONUS,
+      }
+    : null
+}
+
+/** Null when guards cannot be knocked out; found bodies lock down with the alarm's vision bonus. */
+export function deriveRulesKnockoutConfig(content: ContentPack): KnockoutConfig | null {
+  const knockouts = content.rules.knockouts
+
+  return knockouts
+    ? {
+        alertTurns: knockouts.alertTurns,
+        rangeBonus: content.rules.alarm?.rangeBonus ?? DEFAULT_ALARM_RANGE_BONUS,
+      }
+    : null
+}
+
+/** Echoes kept at once; 0 when the level does not spawn them. */
+export function deriveRulesEchoLimit(content: ContentPack): number {
+  return content.rules.echoes?.max ?? 0
+}
+
+/** Explicit rules seed, else the level id, so unseeded levels are still reproducible. */
+export function deriveRulesRngSeed(content: ContentPack): string {
+  return content.rules.rng?.seed ?? content.level.meta.id
+}
+
+/** Enemies, cameras and searchlights can all take per-instance detection profiles. */
+function hasOwnVision(archetype: ContentPack['level']['archetypes'][string]): boolean {
+  return archetype.components.some(
+    (component) => component.kind === 'Camera' || component.kind === 'Searchlight',
+  )
+}
+
+function isWatcherArchetype(archetype: ContentPack['level']['archetypes'][string]): boolean {
+  return archetype.kind === 'enemy' || hasOwnVision(archetype)
 }
 
 /**
- * Resolve per-enemy detection overrides from behavior profiles + assignments.
+ * Resolve per-enemy detection overrides from behavior profiles + assignments. Cameras and
+ * searchlights keep their own vision unless a profile is assigned to them by instance id.
  */
 export function buildEnemyDetectionConfigByIdFromContent(
   content: ContentPack,
@@ -114,14 +326,14 @@ export function buildEnemyDetectionConfigByIdFromContent(
   for (const instance of content.level.instances) {
     const archetype = content.level.archetypes[instance.archetype]
 
-    if (!archetype || archetype.kind !== 'enemy') {
+    if (!archetype || !isWatcherArchetype(archetype)) {
       continue
     }
 
     const assignedProfileKey = content.behavior.detectionAssignments?.[instance.id]
     const hasAssignedProfile = Boolean(assignedProfileKey && profiles[assignedProfileKey])
 
-    if (!hasAssignedProfile && !hasDefaultProfile) {
+    if (!hasAssignedProfile && (!hasDefaultProfile || hasOwnVision(archetype))) {
       continue
     }
 
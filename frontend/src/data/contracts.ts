@@ -1,4 +1,6 @@
-import type { Position2D, Position3D } from '../core/position'
+import type { CharacterStart } from '../core/characters'
+import type { Direction2D, Position2D, Position3D } from '../core/position'
+import type { Region } from '../core/regions'
 
 export type MarkerComponentKind =
   | 'BlocksMovement'
@@ -7,11 +9,56 @@ export type MarkerComponentKind =
   | 'Exit'
   | 'Pushable'
   | 'Pullable'
+  | 'Heavy'
+  | 'Slippery'
+  | 'Ladder'
+  | 'Stackable'
+  | 'Water'
+  | 'Shadow'
+  | 'Anchored'
+  | 'Gate'
 
 export type ContentComponent =
   | { kind: MarkerComponentKind }
-  | { kind: 'Patrol'; path: Position2D[]; loops: boolean }
-  | { kind: 'Rift'; target: Position3D; bidirectional: boolean }
+  | { kind: 'Patrol'; path: Position2D[]; loops: boolean; dwell?: number[] }
+  /** Exactly one of `target` (fixed anchor) or `linkId` (ring of rifts sharing the id) is set. */
+  | { kind: 'Rift'; target?: Position3D; linkId?: string; bidirectional: boolean }
+  | { kind: 'Guard'; guard: 'Sentry'; facings: Direction2D[] }
+  | { kind: 'Guard'; guard: 'Patroller' }
+  | { kind: 'Guard'; guard: 'Chaser'; alertDistance: number }
+  | {
+      kind: 'Camera'
+      facings: Direction2D[]
+      turnEvery: number
+      vision?: { delayTurns: number; maxDistance: number }
+    }
+  | {
+      kind: 'Searchlight'
+      shape: 'circle' | 'cone'
+      radius: number
+      path: Position2D[]
+      loops: boolean
+      dwell?: number[]
+    }
+  /** Cells tall; objects below eye height (2) block movement but not vision. */
+  | { kind: 'Height'; height: number }
+  | { kind: 'LightSource'; radius: number; activeFrom?: number; activeUntil?: number }
+  /** Raised walkway; ground guards only see someone on it past an `open` railing. */
+  | { kind: 'Catwalk'; railing: 'open' | 'closed' }
+  /** Removed by the `Break` action after `hits` blows. */
+  | { kind: 'Breakable'; hits: number }
+  /** Exists only while instance `source` is still in slice `at`; see `DependsOnComponent`. */
+  | { kind: 'DependsOn'; source: string; at: number }
+  /** Single-character `glyph` drawn instead of the icon; `#rgb`/`#rrggbb` `color` for the tile. */
+  | { kind: 'Appearance'; glyph?: string; color?: string }
+  /** Covers `width` x `height` cells east and south of the instance position. */
+  | { kind: 'Footprint'; width: number; height: number }
+  /** On an `Exit`: turns the player must hold the cell, unseen, after reaching it. */
+  | { kind: 'Extraction'; turns: number }
+  /** Used from the next cell with the `Interact` action; `interaction` picks what it does. */
+  | { kind: 'Interactable'; interaction: 'Terminal' }
+  /** `targets` are patrolling guard instance ids the lever turns back or stops and restarts. */
+  | { kind: 'Interactable'; interaction: 'Lever'; effect: 'reverse' | 'pause'; targets: string[] }
 
 export interface ContentRender {
   symbol?: string
@@ -28,36 +75,126 @@ export interface ContentArchetype {
 
 export interface ContentInstance {
   id: string
+  /** Set by the author, or filled in with the template key when `template` is used instead. */
   archetype: string
+  template?: string
   position: Position3D
+  /** Per-instance Appearance overrides, laid over the archetype's own Appearance. */
+  glyph?: string
+  color?: string
+  /** Instances sharing a group are pushed as one block and break together. */
+  group?: string
+}
+
+/**
+ * Reusable variant of an archetype (a prefab). Instances naming it get an archetype derived from
+ * `extends`: each listed component replaces the base component of the same kind or is appended,
+ * and `render` fields override the base. `behavior` and `detectionProfile` are assigned to
+ * instances that have no assignment of their own.
+ */
+export interface ContentTemplate {
+  extends: string
+  components?: ContentComponent[]
+  render?: ContentRender
+  behavior?: string
+  detectionProfile?: string
+}
+
+/** Action kinds a tutorial gate may name; the interaction registry test keeps it in step. */
+export const ACTION_KINDS = [
+  'Move',
+  'Wait',
+  'ApplyRift',
+  'Push',
+  'Pull',
+  'TimePush',
+  'Throw',
+  'SwitchCharacter',
+  'Climb',
+  'Break',
+  'Interact',
+  'MoveTo',
+] as const
+
+export type ActionKind = (typeof ACTION_KINDS)[number]
+
+/** Condition that must be met by a committed turn before a tutorial prompt advances. */
+export type TutorialGate =
+  | { kind: 'Action'; action: ActionKind; direction?: Direction2D }
+  | { kind: 'ReachPosition'; position: Position2D }
+  | { kind: 'EnterRegion'; region: string }
+
+export interface TutorialStepConfig {
+  prompt: string
+  gate: TutorialGate
+}
+
+/** Display metadata shown in level select and on the victory screen. */
+export interface LevelMeta {
+  id: string
+  name: string
+  author?: string
+  description?: string
+  difficulty?: DifficultyTier
+  tags?: string[]
+  tutorial?: TutorialStepConfig[]
 }
 
 export interface LevelConfig {
   schemaVersion: 1
-  meta: {
-    id: string
-    name: string
-  }
+  meta: LevelMeta
   map: {
     width: number
     height: number
     timeDepth: number
     start: Position3D
+    /** Extra player-controlled characters (ids other than `player`); all must reach an exit. */
+    characters?: CharacterStart[]
+    /** Time-loop level: stepping past the last slice lands on slice 0. */
+    timeWraps?: boolean
+    /** Torus map: walking off the east edge comes back in on the west. */
+    xWraps?: boolean
+    /** Torus map: walking off the south edge comes back in on the north. */
+    yWraps?: boolean
   }
   archetypes: Record<string, ContentArchetype>
+  /** Resolved into `archetypes` and behavior assignments at load; see `ContentTemplate`. */
+  templates?: Record<string, ContentTemplate>
   instances: ContentInstance[]
+  /** Named rectangles shown in the HUD and usable as tutorial gates; ids must be unique. */
+  regions?: Region[]
 }
 
 export type BehaviorPolicy =
   | { kind: 'Static' }
-  | { kind: 'PatrolLoop'; path: Position2D[] }
-  | { kind: 'PatrolPingPong'; path: Position2D[] }
+  /**
+   * `dwell[i]` is how many slices the guard holds waypoint `i` per visit (default 1). Waypoints
+   * must be one step apart unless `expand` is set, which fills gaps with the shortest walkable
+   * route at load time. `hesitation` (0..1) is the chance the guard lingers one extra slice on
+   * arriving at a waypoint, rolled from the rules seed when the level loads.
+   */
+  | {
+      kind: 'PatrolLoop'
+      path: Position2D[]
+      dwell?: number[]
+      expand?: boolean
+      hesitation?: number
+    }
+  | {
+      kind: 'PatrolPingPong'
+      path: Position2D[]
+      dwell?: number[]
+      expand?: boolean
+      hesitation?: number
+    }
   | { kind: 'ScriptedTimeline'; points: Position3D[] }
 
 export interface BehaviorDetectionProfile {
   enabled: boolean
   delayTurns: number
   maxDistance: number
+  /** Precog sentinel: sees the player `delayTurns` slices ahead of its own slice. */
+  precog?: boolean
 }
 
 export interface BehaviorConfig {
@@ -105,11 +242,59 @@ export interface GameRulesConfig {
   interaction: {
     maxPushChain: number
     allowPull: boolean
+    /** Enables the temporal shove (`TimePush`); off unless the level opts in. */
+    allowTimePush?: boolean
+    /** Throwable lures; omitted fields fall back to the engine defaults and `supply` to 0. */
+    lures?: {
+      supply: number
+      maxRange?: number
+      radius?: number
+      duration?: number
+    }
+    /** Manhattan distance within which guards hear a `Break` blow; defaults to 6. */
+    breakNoiseRadius?: number
   }
   detection: {
     enabled: boolean
     delayTurns: number
     maxDistance: number
+    /** Makes the level dark: vision into cells no lamp reaches is scaled by this factor (0..1). */
+    darkVisionFactor?: number
+    /** Guards grow suspicious of boxes that moved since their delayed view; needs `alarm`. */
+    objectMemory?: boolean
+  }
+  hints?: {
+    budget: number
+  }
+  /** Peeks around a corner per level; each shows what an adjacent cell sees without a turn. */
+  peeks?: {
+    budget: number
+  }
+  /**
+   * Each turn a guard glimpses the player just past its range adds suspicion; at `threshold` the
+   * alarm sounds and the level locks down for `lockdownTurns` slices: gates shut and every guard
+   * sees `rangeBonus` cells further. `glimpseMargin` and `rangeBonus` default to 2.
+   */
+  alarm?: {
+    threshold: number
+    lockdownTurns: number
+    glimpseMargin?: number
+    rangeBonus?: number
+  }
+  /**
+   * `Interact` on an unaware guard knocks it out, leaving a body. A guard that sees a body locks
+   * the level down for `alertTurns` slices, with the alarm's `rangeBonus` when there is one.
+   */
+  knockouts?: {
+    alertTurns: number
+  }
+  /** Each restart adds an echo retracing the failed attempt; only the last `max` are kept. */
+  echoes?: {
+    max: number
+  }
+  /** Seed for stochastic mechanics; defaults to the level id so every level replays the same. */
+  rng?: {
+    seed: string
   }
 }
 
@@ -194,9 +379,23 @@ export type ContentLoadError =
   | { kind: 'InvalidSchemaVersion'; file: string; expected: number; actual: unknown }
   | { kind: 'MissingIconPackId'; themeId: string }
   | { kind: 'UnknownArchetypeReference'; instanceId: string; archetype: string }
+  | { kind: 'UnknownTemplateReference'; instanceId: string; template: string }
   | { kind: 'InvalidRiftTarget'; archetype: string; target: Position3D }
   | { kind: 'ConflictingRiftSource'; source: Position3D; archetype: string }
+  | { kind: 'UnresolvedRiftLink'; linkId: string; archetype: string }
+  /** `DependsOn` names a missing source, one absent from slice `at`, or closes a cycle. */
+  | { kind: 'InvalidDependency'; instanceId: string; source: string; message: string }
+  | { kind: 'InvalidArchetypeComponents'; archetype: string; message: string }
   | { kind: 'InvalidBehaviorPathPoint'; key: string; point: Position3D | Position2D }
+  /** Waypoint `index` is more than one cell from the next one (or unreachable when expanding). */
+  | { kind: 'DisjointPatrolStep'; key: string; index: number; from: Position2D; to: Position2D }
+  | {
+      kind: 'BlockedPatrolWaypoint'
+      key: string
+      index: number
+      point: Position2D
+      blockerId: string
+    }
   | { kind: 'UnknownBehaviorReference'; instanceId: string; behavior: string }
   | { kind: 'UnknownBehaviorAssignmentInstance'; instanceId: string }
   | { kind: 'UnknownDetectionProfileReference'; instanceId: string; profile: string }
@@ -205,3 +404,4 @@ export type ContentLoadError =
   | { kind: 'InvalidMapBounds'; width: number; height: number; timeDepth: number }
   | { kind: 'InvalidStartPosition'; start: Position3D }
   | { kind: 'UnsupportedBehaviorPolicy'; key: string; policyKind: string }
+  | { kind: 'InvalidTutorialStep'; index: number; message: string }
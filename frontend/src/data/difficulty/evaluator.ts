@@ -1,4 +1,4 @@
-import { manhattanDistance } from '../../core/position'
+import { manhattanDistance, type Position3D } from '../../core/position'
 import type {
   BehaviorDetectionProfile,
   ContentArchetype,
@@ -9,6 +9,7 @@ import type {
 } from '../contracts'
 import type { SolvabilitySearchOptions } from '../generation/solver'
 import { evaluateSolvabilityV1 } from '../generation/solver'
+import { contentRiftTarget, resolveRiftLinkTargets } from '../riftLinks'
 
 /** Raw metrics used by the difficulty model before normalization. */
 export interface DifficultyRawMetrics {
@@ -140,7 +141,10 @@ function estimateEnemyExposureEvents(pack: ContentPack): number {
       continue
     }
 
-    const isDetector = archetype.kind === 'enemy' || hasComponent(archetype, 'Patrol')
+    const isDetector =
+      archetype.kind === 'enemy' ||
+      hasComponent(archetype, 'Patrol') ||
+      hasComponent(archetype, 'Camera')
 
     if (!isDetector) {
       continue
@@ -162,7 +166,14 @@ function estimateEnemyExposureEvents(pack: ContentPack): number {
   return estimate
 }
 
+function riftLinkTargetsOf(pack: ContentPack): Record<string, Position3D> {
+  const resolved = resolveRiftLinkTargets(pack.level)
+
+  return resolved.ok ? resolved.value : {}
+}
+
 function estimateParadoxFragilityCount(pack: ContentPack): number {
+  const linkTargets = riftLinkTargetsOf(pack)
   let risk = 0
 
   for (const instance of pack.level.instances) {
@@ -177,7 +188,9 @@ function estimateParadoxFragilityCount(pack: ContentPack): number {
         continue
       }
 
-      if (component.target.t < instance.position.t) {
+      const target = contentRiftTarget(component, instance, linkTargets)
+
+      if (target && target.t < instance.position.t) {
         risk += 1
       }
 
@@ -191,6 +204,7 @@ function estimateParadoxFragilityCount(pack: ContentPack): number {
 }
 
 function estimateRequiredRiftCount(pack: ContentPack): number {
+  const linkTargets = riftLinkTargetsOf(pack)
   let count = 0
 
   for (const instance of pack.level.instances) {
@@ -201,7 +215,13 @@ function estimateRequiredRiftCount(pack: ContentPack): number {
     }
 
     for (const component of archetype.components) {
-      if (component.kind === 'Rift' && component.target.t !== instance.position.t) {
+      if (component.kind !== 'Rift') {
+        continue
+      }
+
+      const target = contentRiftTarget(component, instance, linkTargets)
+
+      if (target && target.t !== instance.position.t) {
         count += 1
       }
     }
@@ -1,7 +1,12 @@
 import { describe, expect, it } from 'vitest'
 
 import type { ContentPack } from '../contracts'
-import { evaluateSolvabilityV1 } from './solver'
+import {
+  createInitialSolverState,
+  evaluateSolvabilityV1,
+  findTightDetectionConstraints,
+  searchSolutionFromState,
+} from './solver'
 
 function basePack(): ContentPack {
   return {
@@ -146,3 +151,33 @@ describe('evaluateSolvabilityV1', () => {
     expect(report.shortestPathLength).toBe(1)
   })
 })
+
+describe('findTightDetectionConstraints', () => {
+  it('reports guards the route passes just out of range of', () => {
+    const pack = basePack()
+    pack.rules.detection = { enabled: true, delayTurns: 1, maxDistance: 2 }
+    pack.level.archetypes.guard = {
+      kind: 'enemy',
+      components: [{ kind: 'TimePersistent' }],
+      render: {},
+    }
+    pack.level.instances.push({ id: 'guard.0', archetype: 'guard', position: { x: 4, y: 1, t: 0 } })
+
+    const initial = createInitialSolverState(pack)
+
+    expect(initial).not.toBeNull()
+    if (!initial) {
+      return
+    }
+
+    const { actions } = searchSolutionFromState(initial)
+
+    expect(actions).not.toBeNull()
+    // The start is 3 cells from the guard: one more cell of range would have caught it.
+    expect(findTightDetectionConstraints(initial, actions ?? [])).toContainEqual({
+      step: 1,
+      enemyId: 'guard.0',
+      constraint: 'maxDistance',
+    })
+  })
+})
@@ -1,6 +1,14 @@
-import { hasComponent } from '../../core/components'
-import { evaluateDetectionV1 } from '../../core/detection'
-import { hasExit, objectsAt } from '../../core/timeCube'
+import { allCharactersAtExit, parkCharacters, PLAYER_CHARACTER_ID } from '../../core/characters'
+import { propagateChasers } from '../../core/chase'
+import { propagateDependencies } from '../../core/dependency'
+import { evaluateDetectionV1, type DetectionConfig } from '../../core/detection'
+import { propagatePatrolDetours } from '../../core/detour'
+import { currentLayer } from '../../core/elevation'
+import { propagateLures } from '../../core/lure'
+import type { PropagationWarning } from '../../core/propagation'
+import { riftLinksAt } from '../../core/rift'
+import { createRng } from '../../core/rng'
+import { objectsWithComponent } from '../../core/timeCube'
 import { createWorldLine, currentPosition } from '../../core/worldLine'
 import { bootstrapLevelObjects } from '../../game/levelObjects'
 import { executeRegisteredInteraction } from '../../game/interactions/registry'
@@ -8,7 +16,12 @@ import type { InteractionAction, InteractionState } from '../../game/interaction
 import {
   buildEnemyDetectionConfigByIdFromContent,
   buildLevelObjectsConfigFromContent,
+  deriveRulesAlarmConfig,
   deriveRulesDetectionConfig,
+  deriveRulesBreakNoiseRadius,
+  deriveRulesKnockoutConfig,
+  deriveRulesLureSettings,
+  deriveRulesRngSeed,
 } from '../contentAdapter'
 import type { ContentPack } from '../contracts'
 import type { SolvabilityReport } from './contracts'
@@ -35,14 +48,50 @@ function serializeState(state: InteractionState): string {
     .map(([id, object]) => `${id}:${object.position.x},${object.position.y},${object.position.t}`)
     .sort()
     .join('|')
+  const lures = [...state.lures.thrown, ...state.noises]
+    .map((lure) => `${lure.position.x},${lure.position.y},${lure.position.t}`)
+    .join('|')
+  const breakHits = Object.entries(state.breakHits)
+    .map(([id, hits]) => `${id}:${hits}`)
+    .sort()
+    .join('|')
+  const parked = state.parkedCharacters
+    .map((character) => {
+      const position = currentPosition(character.worldLine)
+
+      return position ? `${character.id}@${position.x},${position.y},${position.t}` : character.id
+    })
+    .join('|')
 
-  return `${current.x},${current.y},${current.t}::${objects}`
+  const layer = currentLayer(state.worldLine)
+  const active = `${state.activeCharacterId}@${current.x},${current.y},${current.t}:${layer}`
+
+  const counters = `${state.extractionTurns}:${state.alarm.suspicion}`
+  const lockdowns = state.cube.lockdowns
+    .map((lockdown) => `${lockdown.from}-${lockdown.to}`)
+    .join('|')
+  // Lever pulls live in the guards' patrols, not in where the guards were placed.
+  const switches = Object.values(state.cube.objectsById)
+    .flatMap((object) =>
+      object.archetype.components.flatMap((component) =>
+        component.kind === 'Patrol' && component.switches
+          ? [`${object.id}:${component.switches.map((change) => `${change.kind}@${change.at}`)}`]
+          : [],
+      ),
+    )
+    .sort()
+    .join('|')
+
+  return [active, objects, lures, breakHits, parked, counters, lockdowns, switches].join('::')
 }
 
 function baseActions(
   includePush: boolean,
   includePull: boolean,
+  includeTimePush: boolean,
   includeRift: boolean,
+  throwRange: number | null,
+  includeSwitch: boolean,
 ): InteractionAction[] {
   const actions: InteractionAction[] = [
     { kind: 'Move', direction: 'north' },
@@ -70,13 +119,49 @@ function baseActions(
     )
   }
 
+  if (includeTimePush) {
+    actions.push(
+      { kind: 'TimePush', direction: 'north' },
+      { kind: 'TimePush', direction: 'east' },
+      { kind: 'TimePush', direction: 'south' },
+      { kind: 'TimePush', direction: 'west' },
+    )
+  }
+
+  if (throwRange !== null) {
+    actions.push(
+      { kind: 'Throw', direction: 'north', range: throwRange },
+      { kind: 'Throw', direction: 'east', range: throwRange },
+      { kind: 'Throw', direction: 'south', range: throwRange },
+      { kind: 'Throw', direction: 'west', range: throwRange },
+    )
+  }
+
   if (includeRift) {
     actions.push({ kind: 'ApplyRift' })
   }
 
+  if (includeSwitch) {
+    actions.push({ kind: 'SwitchCharacter' })
+  }
+
   return actions
 }
 
+const BREAK_ACTIONS: InteractionAction[] = [
+  { kind: 'Break', direction: 'north' },
+  { kind: 'Break', direction: 'east' },
+  { kind: 'Break', direction: 'south' },
+  { kind: 'Break', direction: 'west' },
+]
+
+const INTERACT_ACTIONS: InteractionAction[] = [
+  { kind: 'Interact', direction: 'north' },
+  { kind: 'Interact', direction: 'east' },
+  { kind: 'Interact', direction: 'south' },
+  { kind: 'Interact', direction: 'west' },
+]
+
 function tunnelActionsAtCurrent(state: InteractionState): InteractionAction[] {
   const current = currentPosition(state.worldLine)
 
@@ -84,36 +169,16 @@ function tunnelActionsAtCurrent(state: InteractionState): InteractionAction[] {
     return []
   }
 
-  const dedupe = new Set<string>()
-  const actions: InteractionAction[] = []
-
-  for (const object of objectsAt(state.cube, current)) {
-    for (const component of object.archetype.components) {
-      if (component.kind !== 'Rift') {
-        continue
-      }
-
-      const key = `${component.target.x},${component.target.y},${component.target.t}`
-
-      if (dedupe.has(key)) {
-        continue
-      }
-
-      dedupe.add(key)
-      actions.push({
-        kind: 'ApplyRift',
-        instruction: {
-          kind: 'tunnel',
-          target: component.target,
-        },
-      })
-    }
-  }
-
-  return actions
+  return riftLinksAt(state.cube, current).map((link): InteractionAction => ({
+    kind: 'ApplyRift',
+    instruction: {
+      kind: 'tunnel',
+      target: link.target,
+    },
+  }))
 }
 
-function createInitialSolverState(pack: ContentPack): InteractionState | null {
+export function createInitialSolverState(pack: ContentPack): InteractionState | null {
   const objectsConfig = buildLevelObjectsConfigFromContent(pack)
   const bootstrapped = bootstrapLevelObjects(
     pack.level.map.width,
@@ -127,6 +192,7 @@ function createInitialSolverState(pack: ContentPack): InteractionState | null {
   }
 
   const detectionConfig = deriveRulesDetectionConfig(pack)
+  const lureSettings = deriveRulesLureSettings(pack)
 
   return {
     boardWidth: pack.level.map.width,
@@ -134,6 +200,10 @@ function createInitialSolverState(pack: ContentPack): InteractionState | null {
     timeDepth: pack.level.map.timeDepth,
     cube: bootstrapped.value.cube,
     worldLine: createWorldLine(pack.level.map.start),
+    activeCharacterId: PLAYER_CHARACTER_ID,
+    parkedCharacters: parkCharacters(pack.level.map.characters ?? []),
+    hotseat: false,
+    caughtCharacterIds: [],
     currentTime: pack.level.map.start.t,
     turn: 0,
     phase: 'Playing',
@@ -142,9 +212,20 @@ function createInitialSolverState(pack: ContentPack): InteractionState | null {
       baseEnergyCost: pack.rules.rift.baseEnergyCost,
     },
     riftResources: { energy: null },
+    lures: { remaining: lureSettings.supply, thrown: [] },
+    noises: [],
+    breakHits: {},
+    extractionTurns: 0,
+    alarmConfig: deriveRulesAlarmConfig(pack),
+    alarm: { suspicion: 0 },
+    knockoutConfig: deriveRulesKnockoutConfig(pack),
+    foundBodyIds: [],
     interactionConfig: {
       maxPushChain: pack.rules.interaction.maxPushChain,
       allowPull: pack.rules.interaction.allowPull,
+      allowTimePush: pack.rules.interaction.allowTimePush ?? false,
+      lure: lureSettings,
+      breakNoiseRadius: deriveRulesBreakNoiseRadius(pack),
     },
     history: [],
     detectionConfig,
@@ -154,6 +235,8 @@ function createInitialSolverState(pack: ContentPack): InteractionState | null {
     lastParadox: null,
     causalAnchors: [],
     causalAnchorsByTime: {},
+    timelines: null,
+    rng: createRng(deriveRulesRngSeed(pack)),
     status: 'solver',
   }
 }
@@ -179,56 +262,130 @@ function evaluateDetection(state: InteractionState): ReturnType<typeof evaluateD
 
 }
 
+/**
+ * One search move: the action plus the propagation that follows it; null when either fails.
+ * Guards that kept their old cells are appended to `warnings` when given.
+ */
+function stepSolverState(
+  state: InteractionState,
+  action: InteractionAction,
+  warnings?: PropagationWarning[],
+): InteractionState | null {
+  const next = cloneState(state)
+  const result = executeRegisteredInteraction(next, action)
+
+  if (!result.ok) {
+    return null
+  }
+
+  const nextCurrent = currentPosition(next.worldLine)
+
+  if (!nextCurrent) {
+    return null
+  }
+
+  const dependencies = propagateDependencies(next.cube)
+
+  if (!dependencies.ok) {
+    return null
+  }
+
+  const detoured = propagatePatrolDetours(dependencies.value.cube)
+
+  if (!detoured.ok) {
+    return null
+  }
+
+  const lured = propagateLures({
+    cube: detoured.value,
+    lures: [...next.lures.thrown, ...next.noises],
+    settings: next.interactionConfig.lure,
+  })
+
+  if (!lured.ok) {
+    return null
+  }
+
+  const chased = propagateChasers({
+    cube: lured.value.cube,
+    worldLine: next.worldLine,
+    config: next.detectionConfig,
+    configByEnemyId: next.enemyDetectionConfigById,
+  })
+
+  if (!chased.ok) {
+    return null
+  }
+
+  warnings?.push(...lured.value.warnings, ...chased.value.warnings)
+  next.cube = chased.value.cube
+  next.turn = state.turn + 1
+  next.currentTime = nextCurrent.t
+
+  return next
+}
+
 interface SearchNode {
   state: InteractionState
   depth: number
+  actions: InteractionAction[]
   requiredRiftCount: number
   requiredPushPullCount: number
   enemyExposureEvents: number
 }
 
-export function evaluateSolvabilityV1(
-  pack: ContentPack,
-  options: SolvabilitySearchOptions = {},
-): SolvabilityReport {
-  const initial = createInitialSolverState(pack)
+export interface SolverSearchResult {
+  report: SolvabilityReport
+  /** Shortest action sequence reaching an exit, or null when unsolved within budget. */
+  actions: InteractionAction[] | null
+}
 
-  if (!initial) {
-    return {
-      solved: false,
-      shortestPathLength: null,
-      visitedNodes: 0,
-      deadEndRatio: 0,
-      requiredRiftCount: 0,
-      requiredPushPullCount: 0,
-      enemyExposureEvents: 0,
-    }
+function unsolvedReport(visitedNodes: number, deadEndNodes: number): SolvabilityReport {
+  return {
+    solved: false,
+    shortestPathLength: null,
+    visitedNodes,
+    deadEndRatio: visitedNodes > 0 ? deadEndNodes / visitedNodes : 0,
+    requiredRiftCount: 0,
+    requiredPushPullCount: 0,
+    enemyExposureEvents: 0,
   }
+}
 
-  const maxDepth = Math.max(1, options.maxDepth ?? Math.min(48, pack.level.map.timeDepth * 2))
+export function searchSolutionFromState(
+  initial: InteractionState,
+  options: SolvabilitySearchOptions = {},
+): SolverSearchResult {
+  const maxDepth = Math.max(1, options.maxDepth ?? Math.min(48, initial.timeDepth * 2))
   const maxNodes = Math.max(128, options.maxNodes ?? 6000)
   const includePushPull = options.includePushPull ?? true
   const includeRift = options.includeRift ?? true
-  const hasPushable = Object.values(initial.cube.objectsById).some((object) =>
-    hasComponent(object.archetype.components, 'Pushable'),
-  )
+  const hasPushable = objectsWithComponent(initial.cube, 'Pushable').length > 0
   const hasPullable =
     initial.interactionConfig.allowPull &&
-    Object.values(initial.cube.objectsById).some((object) =>
-      hasComponent(object.archetype.components, 'Pullable'),
-    )
+    objectsWithComponent(initial.cube, 'Pullable').length > 0
   const includePush = includePushPull && hasPushable
   const includePull = includePushPull && hasPullable
+  const includeTimePush = includePush && initial.interactionConfig.allowTimePush
+  const throwRange =
+    initial.interactionConfig.lure.supply > 0 ? initial.interactionConfig.lure.maxRange : null
+  const includeSwitch = initial.parkedCharacters.length > 0
+  const includeClimb = objectsWithComponent(initial.cube, 'Ladder').length > 0
+  const includeBreak = objectsWithComponent(initial.cube, 'Breakable').length > 0
+  const includeInteract =
+    objectsWithComponent(initial.cube, 'Interactable').length > 0 || initial.knockoutConfig !== null
+  const root = cloneState(initial)
   const queue: SearchNode[] = [
     {
-      state: initial,
+      state: root,
       depth: 0,
+      actions: [],
       requiredRiftCount: 0,
       requiredPushPullCount: 0,
       enemyExposureEvents: 0,
     },
   ]
-  const visited = new Set<string>([serializeState(initial)])
+  const visited = new Set<string>([serializeState(root)])
   let visitedNodes = 0
   let deadEndNodes = 0
 
@@ -242,16 +399,26 @@ export function evaluateSolvabilityV1(
 
     visitedNodes += 1
 
-    if (hasExit(node.state.cube, current)) {
+    if (
+      allCharactersAtExit(
+        node.state.cube,
+        node.state.worldLine,
+        node.state.parkedCharacters,
+        node.state.caughtCharacterIds,
+      )
+    ) {
       const deadEndRatio = visitedNodes > 0 ? deadEndNodes / visitedNodes : 0
       return {
-        solved: true,
-        shortestPathLength: node.depth,
-        visitedNodes,
-        deadEndRatio,
-        requiredRiftCount: node.requiredRiftCount,
-        requiredPushPullCount: node.requiredPushPullCount,
-        enemyExposureEvents: node.enemyExposureEvents,
+        report: {
+          solved: true,
+          shortestPathLength: node.depth,
+          visitedNodes,
+          deadEndRatio,
+          requiredRiftCount: node.requiredRiftCount,
+          requiredPushPullCount: node.requiredPushPullCount,
+          enemyExposureEvents: node.enemyExposureEvents,
+        },
+        actions: node.actions,
       }
     }
 
@@ -260,28 +427,28 @@ export function evaluateSolvabilityV1(
     }
 
     const actions = [
-      ...baseActions(includePush, includePull, includeRift),
+      ...baseActions(
+        includePush,
+        includePull,
+        includeTimePush,
+        includeRift,
+        throwRange,
+        includeSwitch,
+      ),
       ...(includeRift ? tunnelActionsAtCurrent(node.state) : []),
+      ...(includeClimb ? [{ kind: 'Climb' } as const] : []),
+      ...(includeBreak ? BREAK_ACTIONS : []),
+      ...(includeInteract ? INTERACT_ACTIONS : []),
     ]
     let generatedSuccessor = false
 
     for (const action of actions) {
-      const next = cloneState(node.state)
-      const result = executeRegisteredInteraction(next, action)
-
-      if (!result.ok) {
-        continue
-      }
-
-      const nextCurrent = currentPosition(next.worldLine)
+      const next = stepSolverState(node.state, action)
 
-      if (!nextCurrent) {
+      if (!next) {
         continue
       }
 
-      next.turn = node.state.turn + 1
-      next.currentTime = nextCurrent.t
-
       const detection = evaluateDetection(next)
 
       if (detection.detected) {
@@ -299,10 +466,12 @@ export function evaluateSolvabilityV1(
       queue.push({
         state: next,
         depth: node.depth + 1,
+        actions: [...node.actions, action],
         requiredRiftCount:
           node.requiredRiftCount + (action.kind === 'ApplyRift' ? 1 : 0),
         requiredPushPullCount:
-          node.requiredPushPullCount + (action.kind === 'Push' || action.kind === 'Pull' ? 1 : 0),
+          node.requiredPushPullCount +
+          (action.kind === 'Push' || action.kind === 'Pull' || action.kind === 'TimePush' ? 1 : 0),
         enemyExposureEvents: node.enemyExposureEvents + detection.events.length,
       })
     }
@@ -312,15 +481,97 @@ export function evaluateSolvabilityV1(
     }
   }
 
-  const deadEndRatio = visitedNodes > 0 ? deadEndNodes / visitedNodes : 0
-
   return {
-    solved: false,
-    shortestPathLength: null,
-    visitedNodes,
-    deadEndRatio,
-    requiredRiftCount: 0,
-    requiredPushPullCount: 0,
-    enemyExposureEvents: 0,
+    report: unsolvedReport(visitedNodes, deadEndNodes),
+    actions: null,
   }
 }
+
+/** Detection settings a route can only just satisfy. */
+export type DetectionConstraint = 'maxDistance' | 'delayTurns'
+
+export interface TightDetectionConstraint {
+  /** 1-based index of the action after which the guard would have seen the player. */
+  step: number
+  enemyId: string
+  constraint: DetectionConstraint
+}
+
+const DETECTION_CONSTRAINTS: DetectionConstraint[] = ['maxDistance', 'delayTurns']
+
+function relaxDetection(config: DetectionConfig, constraint: DetectionConstraint): DetectionConfig {
+  return constraint === 'maxDistance'
+    ? { ...config, maxDistance: config.maxDistance + 1 }
+    : { ...config, delayTurns: Math.max(1, config.delayTurns - 1) }
+}
+
+/**
+ * States after each of `actions`, stepped as the search does; stops at the first that fails.
+ * Propagation warnings along the way are appended to `warnings` when given.
+ */
+export function replaySolverActions(
+  initial: InteractionState,
+  actions: InteractionAction[],
+  warnings?: PropagationWarning[],
+): InteractionState[] {
+  const states: InteractionState[] = []
+  let state = initial
+
+  for (const action of actions) {
+    const next = stepSolverState(state, action, warnings)
+
+    if (!next) {
+      break
+    }
+
+    states.push(next)
+    state = next
+  }
+
+  return states
+}
+
+/**
+ * Replays `actions` from `initial` and lists the guards that would have seen the player with one
+ * more cell of range or one turn less of delay, step by step.
+ */
+export function findTightDetectionConstraints(
+  initial: InteractionState,
+  actions: InteractionAction[],
+): TightDetectionConstraint[] {
+  const tight: TightDetectionConstraint[] = []
+
+  replaySolverActions(initial, actions).forEach((state, index) => {
+    for (const constraint of DETECTION_CONSTRAINTS) {
+      const relaxed = evaluateDetection({
+        ...state,
+        detectionConfig: relaxDetection(state.detectionConfig, constraint),
+        enemyDetectionConfigById: Object.fromEntries(
+          Object.entries(state.enemyDetectionConfigById).map(([id, config]) => [
+            id,
+            relaxDetection(config, constraint),
+          ]),
+        ),
+      })
+
+      for (const enemyId of new Set(relaxed.events.map((event) => event.enemyId))) {
+        tight.push({ step: index + 1, enemyId, constraint })
+      }
+    }
+  })
+
+  return tight
+}
+
+export function evaluateSolvabilityV1(
+  pack: ContentPack,
+  options: SolvabilitySearchOptions = {},
+): SolvabilityReport {
+  const initial = createInitialSolverState(pack)
+
+  if (!initial) {
+    return unsolvedReport(0, 0)
+  }
+
+  return searchSolutionFromState(initial, options).report
+}
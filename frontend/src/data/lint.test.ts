@@ -0,0 +1,119 @@
+import { describe, expect, it } from 'vitest'
+
+import { lintContentPack, type LintPackInput } from './lint'
+
+interface TestInstance {
+  id: string
+  archetype: string
+  position: { x: number; y: number; t: number }
+}
+
+/** 5x3 room: the player starts west of a wall with one gap and the exit is east of it. */
+function corridorPack(walls: { x: number; y: number }[] = [{ x: 2, y: 0 }, { x: 2, y: 2 }]) {
+  const instances: TestInstance[] = [
+    { id: 'exit', archetype: 'exit', position: { x: 4, y: 1, t: 0 } },
+    ...walls.map((wall, index) => ({
+      id: `wall.${index}`,
+      archetype: 'wall',
+      position: { ...wall, t: 0 },
+    })),
+  ]
+
+  return {
+    level: {
+      schemaVersion: 1,
+      meta: { id: 'corridor', name: 'Corridor' },
+      map: { width: 5, height: 3, timeDepth: 12, start: { x: 0, y: 1, t: 0 } },
+      archetypes: {
+        wall: {
+          kind: 'wall',
+          components: [{ kind: 'BlocksMovement' }, { kind: 'TimePersistent' }],
+          render: {},
+        },
+        exit: {
+          kind: 'exit',
+          components: [{ kind: 'Exit' }, { kind: 'TimePersistent' }],
+          render: {},
+        },
+        enemy: {
+          kind: 'enemy',
+          components: [{ kind: 'BlocksMovement' }, { kind: 'TimePersistent' }],
+          render: {},
+        },
+      },
+      instances,
+    },
+    behavior: {
+      schemaVersion: 1,
+      policies: {} as Record<string, unknown>,
+      assignments: {} as Record<string, string>,
+    },
+    theme: { schemaVersion: 1, id: 'mono', iconPackId: 'default-mono', cssVars: {} },
+    rules: {
+      schemaVersion: 1,
+      rift: { defaultDelta: 3, baseEnergyCost: 0 },
+      interaction: { maxPushChain: 4, allowPull: true },
+      detection: { enabled: false, delayTurns: 1, maxDistance: 2 },
+    },
+  }
+}
+
+function codes(input: LintPackInput) {
+  return lintContentPack(input).map((issue) => issue.code)
+}
+
+describe('lintContentPack', () => {
+  it('passes a solvable room', () => {
+    expect(lintContentPack(corridorPack())).toEqual([])
+  })
+
+  it('reports a walled-off exit through the solver and the flood fill', () => {
+    const issues = lintContentPack(
+      corridorPack([
+        { x: 2, y: 0 },
+        { x: 2, y: 1 },
+        { x: 2, y: 2 },
+      ]),
+    )
+
+    expect(issues.map((issue) => [issue.severity, issue.code])).toEqual([
+      ['error', 'NoReachableExit'],
+      ['warning', 'UnreachableExit'],
+    ])
+  })
+
+  it('reports broken patrol routes through validation', () => {
+    const input = corridorPack()
+
+    input.level.instances.push({
+      id: 'enemy.a',
+      archetype: 'enemy',
+      position: { x: 3, y: 0, t: 0 },
+    })
+    input.behavior.policies.route = {
+      kind: 'PatrolLoop',
+      path: [
+        { x: 3, y: 0 },
+        { x: 4, y: 0 },
+        { x: 4, y: 2 },
+      ],
+    }
+    input.behavior.assignments['enemy.a'] = 'route'
+
+    const issues = lintContentPack(input)
+
+    expect(issues.map((issue) => issue.code)).toEqual(['InvalidPack'])
+    expect(issues[0].message).toContain('DisjointPatrolStep: key=route, waypoint=1')
+  })
+
+  it('lists every out-of-bounds instance before the validation error', () => {
+    const input = corridorPack()
+
+    input.level.instances.push(
+      { id: 'wall.far', archetype: 'wall', position: { x: 9, y: 0, t: 0 } },
+      { id: 'wall.late', archetype: 'wall', position: { x: 0, y: 0, t: 40 } },
+    )
+
+    expect(codes(input)).toEqual(['OutOfBounds', 'OutOfBounds', 'InvalidPack'])
+  })
+})
@@ -0,0 +1,278 @@
+import { PLAYER_CHARACTER_ID } from '../core/characters'
+import { isInBounds, movePosition, type Position2D, type Position3D } from '../core/position'
+import { formatPropagationWarning, type PropagationWarning } from '../core/propagation'
+import type { ContentComponent, ContentPack, LevelConfig } from './contracts'
+import {
+  createInitialSolverState,
+  replaySolverActions,
+  searchSolutionFromState,
+  type SolvabilitySearchOptions,
+} from './generation/solver'
+import { formatContentLoadError } from './loader'
+import { contentRiftTarget, resolveRiftLinkTargets } from './riftLinks'
+import { staticBlockerIdsByCell, validateContentPack } from './validate'
+
+export type LintSeverity = 'error' | 'warning'
+
+export type LintCode =
+  | 'InvalidPack'
+  | 'OutOfBounds'
+  | 'ExtraPlayer'
+  | 'StartBlocked'
+  | 'NoReachableExit'
+  | 'RiftIntoWall'
+  | 'UnreachableExit'
+  | 'PropagationWarning'
+
+export interface LintIssue {
+  severity: LintSeverity
+  code: LintCode
+  message: string
+}
+
+export interface LintOptions {
+  /**
+   * How guard collisions met while replaying the solver's route are reported; `'off'` drops them.
+   * Defaults to `'warning'`.
+   */
+  propagationWarnings?: LintSeverity | 'off'
+}
+
+export interface LintPackInput {
+  level: unknown
+  behavior: unknown
+  rules: unknown
+  theme: unknown
+}
+
+function cellKey(position: Position2D): string {
+  return `${position.x},${position.y}`
+}
+
+function formatCell(position: Position2D | Position3D): string {
+  return 't' in position
+    ? `(${position.x},${position.y},t=${position.t})`
+    : `(${position.x},${position.y})`
+}
+
+function isRecord(value: unknown): value is Record<string, unknown> {
+  return typeof value === 'object' && value !== null
+}
+
+/**
+ * Validation stops at the first out-of-bounds instance; lint lists all of them from the raw file
+ * so one pass fixes every placement.
+ */
+function outOfBoundsIssues(level: unknown): LintIssue[] {
+  if (!isRecord(level) || !isRecord(level.map) || !Array.isArray(level.instances)) {
+    return []
+  }
+
+  const { width, height, timeDepth } = level.map
+
+  if (typeof width !== 'number' || typeof height !== 'number' || typeof timeDepth !== 'number') {
+    return []
+  }
+
+  return level.instances.flatMap((instance: unknown): LintIssue[] => {
+    if (!isRecord(instance) || !isRecord(instance.position)) {
+      return []
+    }
+
+    const { x, y, t } = instance.position
+
+    if (
+      typeof x === 'number' &&
+      typeof y === 'number' &&
+      typeof t === 'number' &&
+      isInBounds({ x, y }, width, height) &&
+      t >= 0 &&
+      t < timeDepth
+    ) {
+      return []
+    }
+
+    return [
+      {
+        severity: 'error',
+        code: 'OutOfBounds',
+        message:
+          `instance ${String(instance.id)} at ${JSON.stringify(instance.position)} is outside ` +
+          `${width}x${height}x${timeDepth}`,
+      },
+    ]
+  })
+}
+
+function componentsOf(level: LevelConfig, archetype: string): ContentComponent[] {
+  return level.archetypes[archetype]?.components ?? []
+}
+
+function hasKind(components: ContentComponent[], kind: ContentComponent['kind']): boolean {
+  return components.some((component) => component.kind === kind)
+}
+
+function playerIssues(pack: ContentPack, blocked: Set<string>): LintIssue[] {
+  const issues: LintIssue[] = []
+
+  for (const instance of pack.level.instances) {
+    if (pack.level.archetypes[instance.archetype]?.kind === PLAYER_CHARACTER_ID) {
+      issues.push({
+        severity: 'error',
+        code: 'ExtraPlayer',
+        message: `instance ${instance.id} is a player; the player comes from map.start only`,
+      })
+    }
+  }
+
+  const starts = [
+    { id: PLAYER_CHARACTER_ID, start: pack.level.map.start },
+    ...(pack.level.map.characters ?? []),
+  ]
+
+  for (const character of starts) {
+    if (blocked.has(cellKey(character.start))) {
+      issues.push({
+        severity: 'error',
+        code: 'StartBlocked',
+        message: `${character.id} starts inside a wall at ${formatCell(character.start)}`,
+      })
+    }
+  }
+
+  return issues
+}
+
+function riftIssues(pack: ContentPack, blocked: Set<string>): LintIssue[] {
+  const linkTargets = resolveRiftLinkTargets(pack.level)
+
+  if (!linkTargets.ok) {
+    return []
+  }
+
+  return pack.level.instances.flatMap((instance) =>
+    componentsOf(pack.level, instance.archetype).flatMap((component): LintIssue[] => {
+      if (component.kind !== 'Rift') {
+        return []
+      }
+
+      const target = contentRiftTarget(component, instance, linkTargets.value)
+
+      return target && blocked.has(cellKey(target))
+        ? [
+            {
+              severity: 'error',
+              code: 'RiftIntoWall',
+              message: `rift ${instance.id} lands inside a wall at ${formatCell(target)}`,
+            },
+          ]
+        : []
+    }),
+  )
+}
+
+/**
+ * Flood fill over walkable cells from the start, ignoring rifts and boxes. Exits outside it can
+ * still be reachable through a rift, so they are only warned about.
+ */
+function unreachableExitIssues(pack: ContentPack, blocked: Set<string>): LintIssue[] {
+  const { width, height, start } = pack.level.map
+  const reached = new Set<string>([cellKey(start)])
+  const queue: Position2D[] = [start]
+
+  for (let head = 0; head < queue.length; head += 1) {
+    const cell = queue[head]
+
+    for (const direction of ['north', 'south', 'east', 'west'] as const) {
+      const next = movePosition(cell, direction)
+      const key = cellKey(next)
+
+      if (isInBounds(next, width, height) && !blocked.has(key) && !reached.has(key)) {
+        reached.add(key)
+        queue.push(next)
+      }
+    }
+  }
+
+  return pack.level.instances.flatMap((instance): LintIssue[] =>
+    hasKind(componentsOf(pack.level, instance.archetype), 'Exit') &&
+    !reached.has(cellKey(instance.position))
+      ? [
+          {
+            severity: 'warning',
+            code: 'UnreachableExit',
+            message:
+              `exit ${instance.id} at ${formatCell(instance.position)} cannot be walked to ` +
+              'from the start',
+          },
+        ]
+      : [],
+  )
+}
+
+function propagationIssues(
+  warnings: PropagationWarning[],
+  severity: LintSeverity | 'off',
+): LintIssue[] {
+  if (severity === 'off') {
+    return []
+  }
+
+  const messages = new Set(warnings.map(formatPropagationWarning))
+
+  return [...messages].map((message) => ({ severity, code: 'PropagationWarning', message }))
+}
+
+/**
+ * Authoring checks beyond schema validation. Bounds problems are listed from the raw files; the
+ * rest needs a valid pack, and validation already rejects broken patrol routes. The solver decides
+ * whether any exit is reachable at all, and its route is replayed to catch guards that collide
+ * when lures or chases re-route them.
+ */
+export function lintContentPack(
+  input: LintPackInput,
+  solverOptions: SolvabilitySearchOptions = {},
+  options: LintOptions = {},
+): LintIssue[] {
+  const issues = outOfBoundsIssues(input.level)
+  const validated = validateContentPack(input)
+
+  if (!validated.ok) {
+    return [
+      ...issues,
+      { severity: 'error', code: 'InvalidPack', message: formatContentLoadError(validated.error) },
+    ]
+  }
+
+  const pack = validated.value
+  const blocked = new Set(staticBlockerIdsByCell(pack.level, pack.behavior).keys())
+
+  issues.push(
+    ...playerIssues(pack, blocked),
+    ...riftIssues(pack, blocked),
+  )
+
+  const initial = createInitialSolverState(pack)
+  const search = initial ? searchSolutionFromState(initial, solverOptions) : null
+
+  if (!search?.report.solved) {
+    const searched = search?.report.visitedNodes ?? 0
+
+    issues.push({
+      severity: 'error',
+      code: 'NoReachableExit',
+      message: `solver found no way to an exit (${searched} states searched)`,
+    })
+  }
+
+  if (initial && search?.actions) {
+    const warnings: PropagationWarning[] = []
+
+    replaySolverActions(initial, search.actions, warnings)
+    issues.push(...propagationIssues(warnings, options.propagationWarnings ?? 'warning'))
+  }
+
+  issues.push(...unreachableExitIssues(pack, blocked))
+
+  return issues
+}
@@ -2,6 +2,7 @@ import { afterEach, describe, expect, it, vi } from 'vitest'
 
 import {
   loadBootContentFromPublic,
+  loadCommunityLevelsFromPublic,
   loadContentPackManifestFromPublic,
   loadDefaultBootContent,
   loadIconPackFromPublic,
@@ -63,8 +64,24 @@ describe('loadBootContentFromPublic', () => {
             components: [{ kind: 'BlocksMovement' }, { kind: 'TimePersistent' }],
             render: {},
           },
+          camera: {
+            kind: 'camera',
+            components: [
+              { kind: 'TimePersistent' },
+              {
+                kind: 'Camera',
+                facings: ['east'],
+                turnEvery: 1,
+                vision: { delayTurns: 1, maxDistance: 5 },
+              },
+            ],
+            render: {},
+          },
         },
-        instances: [{ id: 'enemy.v', archetype: 'enemy', position: { x: 2, y: 2, t: 0 } }],
+        instances: [
+          { id: 'enemy.v', archetype: 'enemy', position: { x: 2, y: 2, t: 0 } },
+          { id: 'camera.v', archetype: 'camera', position: { x: 0, y: 0, t: 0 } },
+        ],
       },
       '/data/variant.behavior.json': {
         schemaVersion: 1,
@@ -126,6 +143,8 @@ describe('loadBootContentFromPublic', () => {
       delayTurns: 2,
       maxDistance: 6,
     })
+    // The default profile leaves the camera's own vision alone.
+    expect(loaded.value.enemyDetectionConfigById['camera.v']).toBeUndefined()
   })
 })
 
@@ -300,3 +319,81 @@ describe('loadIconPackFromPublic', () => {
     expect(Object.keys(pack.value.slots)).toContain('player')
   })
 })
+
+describe('loadCommunityLevelsFromPublic', () => {
+  const originalFetch = globalThis.fetch
+
+  afterEach(() => {
+    globalThis.fetch = originalFetch
+    vi.restoreAllMocks()
+  })
+
+  it('keeps broken files in the list with their validation error', async () => {
+    const payloadByPath: Record<string, unknown> = {
+      '/data/community/index.json': {
+        schemaVersion: 1,
+        packs: [{ id: 'good' }, { id: 'broken' }],
+      },
+      '/data/community/good.level.json': {
+        schemaVersion: 1,
+        meta: { id: 'good', name: 'Good Room', author: 'someone', tags: ['small'] },
+        map: { width: 4, height: 4, timeDepth: 4, start: { x: 0, y: 0, t: 0 } },
+        archetypes: {},
+        instances: [],
+      },
+      '/data/community/broken.level.json': {
+        schemaVersion: 1,
+        meta: { id: 'broken', name: 'Broken Room', difficulty: 'impossible' },
+        map: { width: 4, height: 4, timeDepth: 4, start: { x: 0, y: 0, t: 0 } },
+        archetypes: {},
+        instances: [],
+      },
+      '/data/icons/default-mono.pack.json': { schemaVersion: 1, id: 'default-mono', slots: {} },
+    }
+
+    for (const id of ['good', 'broken']) {
+      payloadByPath[`/data/community/${id}.behavior.json`] = {
+        schemaVersion: 1,
+        policies: {},
+        assignments: {},
+      }
+      payloadByPath[`/data/community/${id}.theme.json`] = {
+        schemaVersion: 1,
+        id: 't',
+        iconPackId: 'default-mono',
+        cssVars: {},
+      }
+      payloadByPath[`/data/community/${id}.rules.json`] = {
+        schemaVersion: 1,
+        rift: { defaultDelta: 2, baseEnergyCost: 0 },
+        interaction: { maxPushChain: 3, allowPull: true },
+        detection: { enabled: true, delayTurns: 1, maxDistance: 3 },
+      }
+    }
+
+    globalThis.fetch = vi.fn(async (input: RequestInfo | URL) => {
+      const payload = payloadByPath[String(input)]
+
+      if (!payload) {
+        return new Response(null, { status: 404 })
+      }
+
+      return new Response(JSON.stringify(payload), { status: 200 })
+    }) as typeof fetch
+
+    const levels = await loadCommunityLevelsFromPublic('/data')
+
+    expect(levels.map((level) => level.packId)).toEqual(['community/good', 'community/broken'])
+    expect(levels[0].error).toBeNull()
+    expect(levels[0].meta?.author).toBe('someone')
+    expect(levels[1].meta).toBeNull()
+    expect(levels[1].fileName).toBe('broken.level.json')
+    expect(levels[1].error).toContain('meta.difficulty')
+  })
+
+  it('returns no levels when the community index is missing', async () => {
+    globalThis.fetch = vi.fn(async () => new Response(null, { status: 404 })) as typeof fetch
+
+    expect(await loadCommunityLevelsFromPublic('/data')).toEqual([])
+  })
+})
@@ -1,13 +1,36 @@
+import type { AlarmConfig } from '../core/alarm'
+import type { CharacterStart } from '../core/characters'
 import type { DetectionConfig } from '../core/detection'
+import type { KnockoutConfig } from '../core/knockout'
+import type { LureSettings } from '../core/lure'
 import type { LevelObjectsConfig } from '../core/objects'
 import type { Result } from '../core/result'
 import type { RiftSettings } from '../core/rift'
-import type { ContentLoadError, ContentPack, IconPackConfig } from './contracts'
+import type {
+  ContentLoadError,
+  ContentPack,
+  IconPackConfig,
+  LevelMeta,
+  TutorialStepConfig,
+} from './contracts'
 import {
   buildEnemyDetectionConfigByIdFromContent,
   buildLevelObjectsConfigFromContent,
   deriveRulesDetectionConfig,
+  deriveRulesBreakNoiseRadius,
+  deriveRulesAlarmConfig,
+  deriveRulesEchoLimit,
+  deriveRulesKnockoutConfig,
+  deriveRulesLureSettings,
+  deriveRulesRngSeed,
 } from './contentAdapter'
+import { applyMutators, type MutatorId } from './mutators'
+import {
+  applyPlayDifficulty,
+  DEFAULT_PLAY_DIFFICULTY,
+  type PlayDifficulty,
+} from './playDifficulty'
+import { applyRuleProfile, STANDARD_RULE_PROFILE, type RuleProfile } from './ruleProfiles'
 import { validateContentPack, validateIconPackConfig, validateLevelSymbolSlots } from './validate'
 
 import defaultLevel from './content/default.level.json'
@@ -16,29 +39,138 @@ import defaultTheme from './content/default.theme.json'
 import defaultRules from './content/default.rules.json'
 import defaultIconPack from './content/default.icon-pack.json'
 
+export const DEFAULT_HINT_BUDGET = 3
+
 export interface LoadedBootContent {
   levelObjectsConfig: LevelObjectsConfig
   boardWidth: number
   boardHeight: number
   timeDepth: number
   startPosition: ContentPack['level']['map']['start']
+  characterStarts: CharacterStart[]
   iconPackId: string
   riftSettings: RiftSettings
   interactionConfig: {
     maxPushChain: number
     allowPull: boolean
+    allowTimePush: boolean
+    lure: LureSettings
+    breakNoiseRadius: number
+    allowWait?: boolean
   }
   detectionConfig: DetectionConfig
   enemyDetectionConfigById: Record<string, DetectionConfig>
   themeCssVars: Record<string, string>
+  hintBudget: number
+  /** Peeks allowed per level; 0 when the level does not grant any. */
+  peekBudget: number
+  /** Past attempts retraced by echo enemies after a restart; 0 turns echoes off. */
+  echoLimit: number
+  /** Null when the level has no alarm. */
+  alarmConfig: AlarmConfig | null
+  /** Null when guards cannot be knocked out. */
+  knockoutConfig: KnockoutConfig | null
+  rngSeed: string
+  /** Difficulty the detection and hint settings above were adjusted for. */
+  playDifficulty: PlayDifficulty
+  /** Rule profile the level was started under. */
+  ruleProfile: RuleProfile
+  /** Challenge mutators stacked on top of the profile; empty for a normal run. */
+  mutators: MutatorId[]
+  tutorialSteps: TutorialStepConfig[]
+  levelMeta: LevelDisplayMeta
 }
 
+/** Level metadata without the tutorial script, for level select and the victory screen. */
+export type LevelDisplayMeta = Omit<LevelMeta, 'tutorial'>
+
 export type PublicContentLoadError =
   | ContentLoadError
   | { kind: 'FetchFailed'; file: string; status?: number; message: string }
   | { kind: 'InvalidManifest'; message: string }
 
-function toLoadedBootContent(content: ContentPack): LoadedBootContent {
+/** One-line description of a content error, for CLI output and per-file errors in the UI. */
+export function formatContentLoadError(error: ContentLoadError): string {
+  switch (error.kind) {
+    case 'InvalidShape':
+      return `${error.kind} (${error.file}): ${error.message}`
+    case 'InvalidSchemaVersion':
+      return `${error.kind} (${error.file}): expected ${error.expected}, got ${String(error.actual)}`
+    case 'MissingIconPackId':
+      return `${error.kind}: theme=${error.themeId}`
+    case 'UnknownArchetypeReference':
+      return `${error.kind}: instance=${error.instanceId}, archetype=${error.archetype}`
+    case 'UnknownTemplateReference':
+      return `${error.kind}: instance=${error.instanceId}, template=${error.template}`
+    case 'InvalidRiftTarget':
+      return `${error.kind}: archetype=${error.archetype}`
+    case 'ConflictingRiftSource':
+      return `${error.kind}: archetype=${error.archetype}`
+    case 'UnresolvedRiftLink':
+      return `${error.kind}: link=${error.linkId}, archetype=${error.archetype}`
+    case 'InvalidDependency':
+      return (
+        `${error.kind}: instance=${error.instanceId}, source=${error.source}, ` +
+        `message=${error.message}`
+      )
+    case 'InvalidArchetypeComponents':
+      return `${error.kind}: archetype=${error.archetype}, message=${error.message}`
+    case 'InvalidBehaviorPathPoint':
+      return `${error.kind}: key=${error.key}`
+    case 'DisjointPatrolStep':
+      return (
+        `${error.kind}: key=${error.key}, waypoint=${error.index}, ` +
+        `(${error.from.x},${error.from.y}) -> (${error.to.x},${error.to.y})`
+      )
+    case 'BlockedPatrolWaypoint':
+      return (
+        `${error.kind}: key=${error.key}, waypoint=${error.index}, ` +
+        `(${error.point.x},${error.point.y}) blocked by ${error.blockerId}`
+      )
+    case 'UnknownBehaviorReference':
+      return `${error.kind}: instance=${error.instanceId}, behavior=${error.behavior}`
+    case 'UnknownBehaviorAssignmentInstance':
+      return `${error.kind}: instance=${error.instanceId}`
+    case 'UnknownDetectionProfileReference':
+      return `${error.kind}: instance=${error.instanceId}, profile=${error.profile}`
+    case 'InvalidDetectionProfile':
+      return `${error.kind}: key=${error.key}, message=${error.message}`
+    case 'InvalidIconSlotReference':
+      return `${error.kind}: archetype=${error.archetype}, symbol=${error.symbol}`
+    case 'InvalidMapBounds':
+      return `${error.kind}: ${error.width}x${error.height}, depth=${error.timeDepth}`
+    case 'InvalidStartPosition':
+      return `${error.kind}: (${error.start.x},${error.start.y},t=${error.start.t})`
+    case 'UnsupportedBehaviorPolicy':
+      return `${error.kind}: key=${error.key}, kind=${error.policyKind}`
+    case 'InvalidTutorialStep':
+      return `${error.kind}: index=${error.index}, message=${error.message}`
+  }
+}
+
+export function formatPublicContentLoadError(error: PublicContentLoadError): string {
+  switch (error.kind) {
+    case 'FetchFailed':
+      return `${error.kind} (${error.file}): ${error.message}`
+    case 'InvalidManifest':
+      return `${error.kind}: ${error.message}`
+    default:
+      return formatContentLoadError(error)
+  }
+}
+
+function toLevelDisplayMeta(meta: LevelMeta): LevelDisplayMeta {
+  return {
+    id: meta.id,
+    name: meta.name,
+    author: meta.author,
+    description: meta.description,
+    difficulty: meta.difficulty,
+    tags: meta.tags,
+  }
+}
+
+export function toLoadedBootContent(content: ContentPack): LoadedBootContent {
   const detectionConfig = deriveRulesDetectionConfig(content)
 
   return {
@@ -47,6 +179,7 @@ function toLoadedBootContent(content: ContentPack): LoadedBootContent {
     boardHeight: content.level.map.height,
     timeDepth: content.level.map.timeDepth,
     startPosition: content.level.map.start,
+    characterStarts: content.level.map.characters ?? [],
     iconPackId: content.theme.iconPackId,
     riftSettings: {
       defaultDelta: content.rules.rift.defaultDelta,
@@ -55,10 +188,24 @@ function toLoadedBootContent(content: ContentPack): LoadedBootContent {
     interactionConfig: {
       maxPushChain: content.rules.interaction.maxPushChain,
       allowPull: content.rules.interaction.allowPull,
+      allowTimePush: content.rules.interaction.allowTimePush ?? false,
+      lure: deriveRulesLureSettings(content),
+      breakNoiseRadius: deriveRulesBreakNoiseRadius(content),
     },
     detectionConfig,
     enemyDetectionConfigById: buildEnemyDetectionConfigByIdFromContent(content),
     themeCssVars: content.theme.cssVars,
+    hintBudget: content.rules.hints?.budget ?? DEFAULT_HINT_BUDGET,
+    peekBudget: content.rules.peeks?.budget ?? 0,
+    echoLimit: deriveRulesEchoLimit(content),
+    alarmConfig: deriveRulesAlarmConfig(content),
+    knockoutConfig: deriveRulesKnockoutConfig(content),
+    rngSeed: deriveRulesRngSeed(content),
+    playDifficulty: DEFAULT_PLAY_DIFFICULTY,
+    ruleProfile: STANDARD_RULE_PROFILE,
+    mutators: [],
+    tutorialSteps: content.level.meta.tutorial ?? [],
+    levelMeta: toLevelDisplayMeta(content.level.meta),
   }
 }
 
@@ -94,13 +241,22 @@ async function fetchJson(path: string): Promise<Result<unknown, PublicContentLoa
     const response = await fetch(path)
 
     if (!response.ok) {
+      // The dev server's level routes explain failures in an `error` field, e.g. a broken sketch.
+      const detail = await response
+        .json()
+        .then((body: unknown) => (body as { error?: unknown } | null)?.error)
+        .catch(() => undefined)
+
       return {
         ok: false,
         error: {
           kind: 'FetchFailed',
           file: path,
           status: response.status,
-          message: `HTTP ${response.status}`,
+          message:
+            typeof detail === 'string'
+              ? `HTTP ${response.status}: ${detail}`
+              : `HTTP ${response.status}`,
         },
       }
     }
@@ -119,10 +275,26 @@ async function fetchJson(path: string): Promise<Result<unknown, PublicContentLoa
   }
 }
 
+function withDetectionOverrides(
+  content: LoadedBootContent,
+  overrides: Partial<DetectionConfig> | undefined,
+): LoadedBootContent {
+  return overrides
+    ? { ...content, detectionConfig: { ...content.detectionConfig, ...overrides } }
+    : content
+}
+
 export async function loadBootContentFromPublic(
   options: {
     basePath?: string
     packId?: string
+    difficulty?: PlayDifficulty
+    /** Applied after the difficulty; a profile difficulty wins over `difficulty`. */
+    ruleProfile?: RuleProfile
+    /** Replaces the level's default detection values before the difficulty adjusts them. */
+    detectionOverrides?: Partial<DetectionConfig>
+    /** Challenge mutators, applied last so a profile cannot undo them. */
+    mutators?: MutatorId[]
   } = {},
 ): Promise<Result<LoadedBootContent, PublicContentLoadError>> {
   const basePath = options.basePath ?? '/data'
@@ -179,7 +351,16 @@ export async function loadBootContentFromPublic(
 
   return {
     ok: true,
-    value: toLoadedBootContent(validated.value),
+    value: applyMutators(
+      applyRuleProfile(
+        applyPlayDifficulty(
+          withDetectionOverrides(toLoadedBootContent(validated.value), options.detectionOverrides),
+          options.ruleProfile?.difficulty ?? options.difficulty ?? DEFAULT_PLAY_DIFFICULTY,
+        ),
+        options.ruleProfile ?? STANDARD_RULE_PROFILE,
+      ),
+      options.mutators ?? [],
+    ),
   }
 }
 
@@ -230,6 +411,9 @@ export interface PublicContentPackManifestEntry {
   /** Optional measured/authored difficulty internals for runtime UI/tooling. */
   difficultyMeta?: PublicPackDifficultyMeta
   tags?: string[]
+  /** Shown in level select; falls back to `source.author` when omitted. */
+  author?: string
+  description?: string
   source?: PublicContentPackSourceMeta
 }
 
@@ -540,6 +724,18 @@ function parsePackEntry(
     }
   }
 
+  for (const field of ['author', 'description'] as const) {
+    if (value[field] !== undefined && !isNonEmptyString(value[field])) {
+      return {
+        ok: false,
+        error: {
+          kind: 'InvalidManifest',
+          message: `Pack ${value.id} ${field} must be a non-empty string when provided`,
+        },
+      }
+    }
+  }
+
   const source = parsePackSourceMeta(value)
 
   if (!source.ok) {
@@ -561,6 +757,8 @@ function parsePackEntry(
       difficulty: value.difficulty,
       difficultyMeta: difficultyMeta.value,
       tags: value.tags,
+      author: value.author as string | undefined,
+      description: value.description as string | undefined,
       source: source.value,
     },
   }
@@ -619,3 +817,54 @@ export async function loadContentPackManifestFromPublic(
 
   return parsePublicContentPackManifest(manifest.value)
 }
+
+/** Pack ids of levels from the user levels directory carry this prefix. */
+export const COMMUNITY_PACK_PREFIX = 'community/'
+
+export function isCommunityPackId(packId: string): boolean {
+  return packId.startsWith(COMMUNITY_PACK_PREFIX)
+}
+
+/** One file from the user levels directory: its metadata when valid, otherwise why it failed. */
+export interface CommunityLevelEntry {
+  packId: string
+  fileName: string
+  meta: LevelDisplayMeta | null
+  error: string | null
+}
+
+/**
+ * Loads and validates every level listed in `<basePath>/community/index.json`. A missing index
+ * means there are no community levels; a broken file becomes an entry with an error instead of
+ * failing the whole list.
+ */
+export async function loadCommunityLevelsFromPublic(
+  basePath = '/data',
+): Promise<CommunityLevelEntry[]> {
+  const raw = await fetchJson(`${basePath}/${COMMUNITY_PACK_PREFIX}index.json`)
+
+  if (!raw.ok) {
+    return []
+  }
+
+  const manifest = parsePublicContentPackManifest(raw.value)
+
+  if (!manifest.ok) {
+    console.warn(`[community] ${formatPublicContentLoadError(manifest.error)}`)
+    return []
+  }
+
+  return Promise.all(
+    manifest.value.packs.map(async (pack): Promise<CommunityLevelEntry> => {
+      const packId = `${COMMUNITY_PACK_PREFIX}${pack.id}`
+      const loaded = await loadBootContentFromPublic({ basePath, packId })
+
+      return {
+        packId,
+        fileName: `${pack.id}.level.json`,
+        meta: loaded.ok ? loaded.value.levelMeta : null,
+        error: loaded.ok ? null : formatPublicContentLoadError(loaded.error),
+      }
+    }),
+  )
+}
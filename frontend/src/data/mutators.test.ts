@@ -0,0 +1,70 @@
+import { describe, expect, it } from 'vitest'
+
+import { loadDefaultBootContent } from './loader'
+import { applyMutators, dailyMutators, isMutatorId, toggleMutator } from './mutators'
+
+function defaultContent() {
+  const loaded = loadDefaultBootContent()
+
+  if (!loaded.ok) {
+    throw new Error('default content failed to load')
+  }
+
+  return {
+    ...loaded.value,
+    detectionConfig: { enabled: true, delayTurns: 2, maxDistance: 4 },
+    enemyDetectionConfigById: { 'enemy.alpha': { enabled: true, delayTurns: 1, maxDistance: 1 } },
+    hintBudget: 3,
+  }
+}
+
+describe('applyMutators', () => {
+  it('leaves content untouched without mutators', () => {
+    const content = defaultContent()
+
+    expect(applyMutators(content, [])).toBe(content)
+    expect(content.mutators).toEqual([])
+  })
+
+  it('stacks detection deltas and clamps the sum', () => {
+    const mutated = applyMutators(defaultContent(), ['hastyGuards', 'thickFog'])
+
+    expect(mutated.detectionConfig).toMatchObject({ delayTurns: 1, maxDistance: 2 })
+    expect(mutated.enemyDetectionConfigById['enemy.alpha']).toMatchObject({
+      delayTurns: 1,
+      maxDistance: 0,
+    })
+    expect(mutated.mutators).toEqual(['thickFog', 'hastyGuards'])
+  })
+
+  it('gives the same result whatever order the mutators are picked in', () => {
+    const content = defaultContent()
+
+    expect(applyMutators(content, ['eagleEyes', 'thickFog'])).toEqual(
+      applyMutators(content, ['thickFog', 'eagleEyes', 'thickFog']),
+    )
+  })
+
+  it('turns off waiting and hints', () => {
+    const mutated = applyMutators(defaultContent(), ['noWaiting', 'noHints'])
+
+    expect(mutated.interactionConfig.allowWait).toBe(false)
+    expect(mutated.hintBudget).toBe(0)
+  })
+})
+
+describe('mutator selection', () => {
+  it('toggles ids in canonical order', () => {
+    expect(toggleMutator(['noWaiting'], 'thickFog')).toEqual(['thickFog', 'noWaiting'])
+    expect(toggleMutator(['thickFog', 'noWaiting'], 'thickFog')).toEqual(['noWaiting'])
+  })
+
+  it('picks the same two distinct mutators for everyone on a day', () => {
+    const today = dailyMutators(new Date('2026-03-14T08:00:00.000Z'))
+
+    expect(today).toEqual(dailyMutators(new Date('2026-03-14T23:59:00.000Z')))
+    expect(today).toHaveLength(2)
+    expect(new Set(today).size).toBe(2)
+    expect(today.every(isMutatorId)).toBe(true)
+  })
+})
@@ -0,0 +1,114 @@
+import { createRng, nextInt } from '../core/rng'
+import type { LoadedBootContent } from './loader'
+import { modifyDetection } from './playDifficulty'
+
+/** Challenge modifiers that can be stacked on any level, after difficulty and rule profile. */
+export type MutatorId = 'thickFog' | 'hastyGuards' | 'eagleEyes' | 'noWaiting' | 'noHints'
+
+export const MUTATOR_IDS: MutatorId[] = [
+  'thickFog',
+  'hastyGuards',
+  'eagleEyes',
+  'noWaiting',
+  'noHints',
+]
+
+export interface MutatorEffect {
+  /** Added to the level and per-guard detection delay; the sum never goes below 1. */
+  delayTurns: number
+  /** Added to the level and per-guard vision distance; the sum never goes below 0. */
+  maxDistance: number
+  /** When false the Wait action is refused. */
+  allowWait: boolean
+  /** When false the hint budget drops to 0. */
+  hints: boolean
+}
+
+export const MUTATORS: Record<MutatorId, MutatorEffect> = {
+  thickFog: { delayTurns: 0, maxDistance: -2, allowWait: true, hints: true },
+  hastyGuards: { delayTurns: -1, maxDistance: 0, allowWait: true, hints: true },
+  eagleEyes: { delayTurns: 0, maxDistance: 1, allowWait: true, hints: true },
+  noWaiting: { delayTurns: 0, maxDistance: 0, allowWait: false, hints: true },
+  noHints: { delayTurns: 0, maxDistance: 0, allowWait: true, hints: false },
+}
+
+/** Mutators in today's daily challenge. */
+export const DAILY_MUTATOR_COUNT = 2
+
+export function isMutatorId(value: unknown): value is MutatorId {
+  return MUTATOR_IDS.includes(value as MutatorId)
+}
+
+/** Known ids only, without duplicates, in `MUTATOR_IDS` order so equal sets compare equal. */
+export function normalizeMutators(ids: readonly unknown[]): MutatorId[] {
+  return MUTATOR_IDS.filter((id) => ids.includes(id))
+}
+
+export function toggleMutator(ids: readonly MutatorId[], id: MutatorId): MutatorId[] {
+  return ids.includes(id)
+    ? ids.filter((current) => current !== id)
+    : normalizeMutators([...ids, id])
+}
+
+export function sameMutators(left: readonly MutatorId[], right: readonly MutatorId[]): boolean {
+  const a = normalizeMutators(left)
+  const b = normalizeMutators(right)
+
+  return a.length === b.length && a.every((id, index) => id === b[index])
+}
+
+/**
+ * Apply mutators to loaded content. Deltas are summed before clamping, so the result does not
+ * depend on the order they were picked in.
+ */
+export function applyMutators(
+  content: LoadedBootContent,
+  ids: readonly MutatorId[],
+): LoadedBootContent {
+  const mutators = normalizeMutators(ids)
+
+  if (mutators.length === 0) {
+    return content
+  }
+
+  const effects = mutators.map((id) => MUTATORS[id])
+  const modifier = {
+    delayTurns: effects.reduce((sum, effect) => sum + effect.delayTurns, 0),
+    maxDistance: effects.reduce((sum, effect) => sum + effect.maxDistance, 0),
+  }
+
+  return {
+    ...content,
+    detectionConfig: modifyDetection(content.detectionConfig, modifier),
+    enemyDetectionConfigById: Object.fromEntries(
+      Object.entries(content.enemyDetectionConfigById).map(([enemyId, config]) => [
+        enemyId,
+        modifyDetection(config, modifier),
+      ]),
+    ),
+    interactionConfig: {
+      ...content.interactionConfig,
+      allowWait:
+        (content.interactionConfig.allowWait ?? true) &&
+        effects.every((effect) => effect.allowWait),
+    },
+    hintBudget: effects.every((effect) => effect.hints) ? content.hintBudget : 0,
+    mutators,
+  }
+}
+
+/** The same pick for everyone on a given UTC day, seeded from the date. */
+export function dailyMutators(date: Date): MutatorId[] {
+  let rng = createRng(`daily:${date.toISOString().slice(0, 10)}`)
+  const remaining = [...MUTATOR_IDS]
+  const picked: MutatorId[] = []
+
+  while (picked.length < DAILY_MUTATOR_COUNT && remaining.length > 0) {
+    const draw = nextInt(rng, 0, remaining.length - 1)
+
+    rng = draw.rng
+    picked.push(...remaining.splice(draw.value, 1))
+  }
+
+  return normalizeMutators(picked)
+}
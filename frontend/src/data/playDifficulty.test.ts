@@ -0,0 +1,54 @@
+import { describe, expect, it } from 'vitest'
+
+import { loadDefaultBootContent } from './loader'
+import { applyPlayDifficulty } from './playDifficulty'
+
+function defaultContent() {
+  const loaded = loadDefaultBootContent()
+
+  if (!loaded.ok) {
+    throw new Error('default content failed to load')
+  }
+
+  return loaded.value
+}
+
+describe('applyPlayDifficulty', () => {
+  it('leaves Normal untouched', () => {
+    const content = defaultContent()
+
+    expect(applyPlayDifficulty(content, 'Normal')).toBe(content)
+    expect(content.playDifficulty).toBe('Normal')
+  })
+
+  it('softens detection and adds hints on Easy, and the reverse on Hard', () => {
+    const content = {
+      ...defaultContent(),
+      detectionConfig: { enabled: true, delayTurns: 1, maxDistance: 4 },
+      enemyDetectionConfigById: { 'enemy.alpha': { enabled: true, delayTurns: 2, maxDistance: 0 } },
+      hintBudget: 3,
+    }
+
+    const easy = applyPlayDifficulty(content, 'Easy')
+    const hard = applyPlayDifficulty(content, 'Hard')
+
+    expect(easy.detectionConfig).toMatchObject({ delayTurns: 2, maxDistance: 3 })
+    expect(easy.enemyDetectionConfigById['enemy.alpha']).toMatchObject({
+      delayTurns: 3,
+      maxDistance: 0,
+    })
+    expect(easy.hintBudget).toBe(5)
+    expect(easy.detectionConfig.objectMemory).toBeUndefined()
+    expect(hard.detectionConfig).toMatchObject({
+      delayTurns: 1,
+      maxDistance: 5,
+      objectMemory: true,
+    })
+    expect(hard.enemyDetectionConfigById['enemy.alpha']).toMatchObject({
+      delayTurns: 1,
+      objectMemory: true,
+    })
+    expect(hard.hintBudget).toBe(0)
+    expect(hard.playDifficulty).toBe('Hard')
+  })
+})
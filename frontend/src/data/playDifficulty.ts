@@ -0,0 +1,73 @@
+import type { DetectionConfig } from '../core/detection'
+import type { LoadedBootContent } from './loader'
+
+/** Player-chosen difficulty, layered on top of whatever the level itself asks for. */
+export type PlayDifficulty = 'Easy' | 'Normal' | 'Hard'
+
+export const PLAY_DIFFICULTIES: PlayDifficulty[] = ['Easy', 'Normal', 'Hard']
+export const DEFAULT_PLAY_DIFFICULTY: PlayDifficulty = 'Normal'
+
+export interface PlayDifficultyModifier {
+  /** Added to the level and per-guard detection delay; never below 1. */
+  delayTurns: number
+  /** Added to the level and per-guard vision distance; never below 0. */
+  maxDistance: number
+  /** Extra hints on top of the level budget. */
+  bonusHints: number
+  /** When false the level's hint budget drops to 0. */
+  hints: boolean
+  /** Turns on `objectMemory` for every guard, whatever the level says. */
+  objectMemory: boolean
+}
+
+export const PLAY_DIFFICULTY_MODIFIERS: Record<PlayDifficulty, PlayDifficultyModifier> = {
+  Easy: { delayTurns: 1, maxDistance: -1, bonusHints: 2, hints: true, objectMemory: false },
+  Normal: { delayTurns: 0, maxDistance: 0, bonusHints: 0, hints: true, objectMemory: false },
+  Hard: { delayTurns: -1, maxDistance: 1, bonusHints: 0, hints: false, objectMemory: true },
+}
+
+export function isPlayDifficulty(value: unknown): value is PlayDifficulty {
+  return PLAY_DIFFICULTIES.includes(value as PlayDifficulty)
+}
+
+/**
+ * Shifts delay and range by the modifier's deltas, keeping delay >= 1 and range >= 0, and turns
+ * on object memory when the modifier asks for it.
+ */
+export function modifyDetection(
+  config: DetectionConfig,
+  modifier: Pick<PlayDifficultyModifier, 'delayTurns' | 'maxDistance'> &
+    Partial<Pick<PlayDifficultyModifier, 'objectMemory'>>,
+): DetectionConfig {
+  return {
+    ...config,
+    delayTurns: Math.max(1, config.delayTurns + modifier.delayTurns),
+    maxDistance: Math.max(0, config.maxDistance + modifier.maxDistance),
+    ...(modifier.objectMemory ? { objectMemory: true } : {}),
+  }
+}
+
+/** Apply a difficulty to freshly loaded content; `Normal` returns the content unchanged. */
+export function applyPlayDifficulty(
+  content: LoadedBootContent,
+  difficulty: PlayDifficulty,
+): LoadedBootContent {
+  if (difficulty === 'Normal') {
+    return content
+  }
+
+  const modifier = PLAY_DIFFICULTY_MODIFIERS[difficulty]
+
+  return {
+    ...content,
+    detectionConfig: modifyDetection(content.detectionConfig, modifier),
+    enemyDetectionConfigById: Object.fromEntries(
+      Object.entries(content.enemyDetectionConfigById).map(([enemyId, config]) => [
+        enemyId,
+        modifyDetection(config, modifier),
+      ]),
+    ),
+    hintBudget: modifier.hints ? content.hintBudget + modifier.bonusHints : 0,
+    playDifficulty: difficulty,
+  }
+}
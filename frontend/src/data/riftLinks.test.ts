@@ -0,0 +1,73 @@
+import { describe, expect, it } from 'vitest'
+
+import type { LevelConfig } from './contracts'
+import { resolveRiftLinkTargets } from './riftLinks'
+
+function levelWith(
+  rift: LevelConfig['archetypes'][string]['components'][number],
+  positions: { x: number; y: number; t: number }[],
+): LevelConfig {
+  return {
+    schemaVersion: 1,
+    meta: { id: 'links', name: 'links' },
+    map: { width: 6, height: 6, timeDepth: 6, start: { x: 0, y: 0, t: 0 } },
+    archetypes: {
+      portal: { kind: 'rift', components: [rift], render: {} },
+    },
+    instances: positions.map((position, index) => ({
+      id: `portal.${index}`,
+      archetype: 'portal',
+      position,
+    })),
+  }
+}
+
+describe('resolveRiftLinkTargets', () => {
+  it('links rifts sharing an id into a ring in declaration order', () => {
+    const result = resolveRiftLinkTargets(
+      levelWith({ kind: 'Rift', linkId: 'ring', bidirectional: false }, [
+        { x: 1, y: 1, t: 0 },
+        { x: 4, y: 1, t: 2 },
+        { x: 4, y: 4, t: 5 },
+      ]),
+    )
+
+    expect(result).toEqual({
+      ok: true,
+      value: {
+        'portal.0': { x: 4, y: 1, t: 2 },
+        'portal.1': { x: 4, y: 4, t: 5 },
+        'portal.2': { x: 1, y: 1, t: 0 },
+      },
+    })
+  })
+
+  it('rejects a link id with a single rift', () => {
+    const result = resolveRiftLinkTargets(
+      levelWith({ kind: 'Rift', linkId: 'lonely', bidirectional: true }, [{ x: 1, y: 1, t: 0 }]),
+    )
+
+    expect(result.ok).toBe(false)
+    if (!result.ok) {
+      expect(result.error).toEqual({
+        kind: 'UnresolvedRiftLink',
+        linkId: 'lonely',
+        archetype: 'portal',
+      })
+    }
+  })
+
+  it('rejects rifts that set both a target and a link id', () => {
+    const result = resolveRiftLinkTargets(
+      levelWith(
+        { kind: 'Rift', target: { x: 2, y: 2, t: 0 }, linkId: 'both', bidirectional: false },
+        [{ x: 1, y: 1, t: 0 }],
+      ),
+    )
+
+    expect(result.ok).toBe(false)
+    if (!result.ok) {
+      expect(result.error.kind).toBe('InvalidShape')
+    }
+  })
+})
@@ -0,0 +1,91 @@
+import type { Position3D } from '../core/position'
+import type { Result } from '../core/result'
+import type { ContentComponent, ContentInstance, ContentLoadError, LevelConfig } from './contracts'
+
+export type ContentRiftComponent = Extract<ContentComponent, { kind: 'Rift' }>
+
+function riftComponentsOf(level: LevelConfig, instance: ContentInstance): ContentRiftComponent[] {
+  const archetype = level.archetypes[instance.archetype]
+
+  if (!archetype) {
+    return []
+  }
+
+  return archetype.components.filter(
+    (component): component is ContentRiftComponent => component.kind === 'Rift',
+  )
+}
+
+/**
+ * Resolve `linkId` rifts into concrete targets keyed by instance id.
+ * Instances sharing a link id form a ring in declaration order: each one leads to the next,
+ * and the last leads back to the first. A link needs at least two rift instances.
+ */
+export function resolveRiftLinkTargets(
+  level: LevelConfig,
+): Result<Record<string, Position3D>, ContentLoadError> {
+  const membersByLink = new Map<string, { instance: ContentInstance; archetype: string }[]>()
+
+  for (const instance of level.instances) {
+    for (const component of riftComponentsOf(level, instance)) {
+      const hasTarget = component.target !== undefined
+      const hasLink = component.linkId !== undefined
+
+      if (hasTarget === hasLink) {
+        return {
+          ok: false,
+          error: {
+            kind: 'InvalidShape',
+            file: 'level',
+            message: `Rift on ${instance.archetype} must set exactly one of target or linkId`,
+          },
+        }
+      }
+
+      if (component.linkId === undefined) {
+        continue
+      }
+
+      if (typeof component.linkId !== 'string' || component.linkId.length === 0) {
+        return {
+          ok: false,
+          error: {
+            kind: 'InvalidShape',
+            file: 'level',
+            message: `Rift linkId on ${instance.archetype} must be a non-empty string`,
+          },
+        }
+      }
+
+      const members = membersByLink.get(component.linkId) ?? []
+      members.push({ instance, archetype: instance.archetype })
+      membersByLink.set(component.linkId, members)
+    }
+  }
+
+  const targets: Record<string, Position3D> = {}
+
+  for (const [linkId, members] of membersByLink) {
+    if (members.length < 2) {
+      return {
+        ok: false,
+        error: { kind: 'UnresolvedRiftLink', linkId, archetype: members[0].archetype },
+      }
+    }
+
+    members.forEach((member, index) => {
+      targets[member.instance.id] = members[(index + 1) % members.length].instance.position
+    })
+  }
+
+  return { ok: true, value: targets }
+}
+
+/** Concrete target of a rift component on `instance`, using targets from `resolveRiftLinkTargets`. */
+export function contentRiftTarget(
+  component: ContentRiftComponent,
+  instance: ContentInstance,
+  linkTargets: Record<string, Position3D>,
+): Position3D | null {
+  return component.target ?? linkTargets[instance.id] ?? null
+}
@@ -0,0 +1,91 @@
+import { describe, expect, it } from 'vitest'
+
+import { loadDefaultBootContent } from './loader'
+import {
+  applyRuleProfile,
+  formatRuleProfileError,
+  parseRuleProfiles,
+  RULE_PROFILES,
+  RULE_PROFILES_ERROR,
+  ruleProfileById,
+  STANDARD_RULE_PROFILE,
+} from './ruleProfiles'
+
+describe('rule profiles', () => {
+  it('parses the bundled profiles with defaults filled in', () => {
+    expect(RULE_PROFILES_ERROR).toBeNull()
+    expect(ruleProfileById('standard')).toEqual(STANDARD_RULE_PROFILE)
+    expect(ruleProfileById('ironman')).toEqual({
+      id: 'ironman',
+      name: 'Ironman',
+      hints: false,
+      preview: false,
+      planning: false,
+      restarts: 1,
+      branching: false,
+    })
+    expect(ruleProfileById('branching')).toMatchObject({ branching: true, preview: true })
+    expect(ruleProfileById('missing')).toBe(STANDARD_RULE_PROFILE)
+  })
+
+  it('rejects conflicting options with a readable error', () => {
+    const parsed = parseRuleProfiles({
+      schemaVersion: 1,
+      profiles: [{ id: 'blind', name: 'Blind', preview: false, planning: true }],
+    })
+
+    expect(parsed.ok).toBe(false)
+
+    if (!parsed.ok) {
+      expect(parsed.error).toMatchObject({
+        kind: 'ConflictingRuleOptions',
+        options: ['preview', 'planning'],
+      })
+      expect(formatRuleProfileError(parsed.error)).toBe(
+        'ConflictingRuleOptions (blind: preview vs planning): ' +
+          'planning shows guard sight, so it needs preview',
+      )
+    }
+
+    expect(
+      parseRuleProfiles({
+        schemaVersion: 1,
+        profiles: [{ id: 'x', name: 'X', hints: false, hintBudget: 2 }],
+      }),
+    ).toMatchObject({ ok: false, error: { kind: 'ConflictingRuleOptions' } })
+  })
+
+  it('rejects duplicate ids and bad values', () => {
+    expect(
+      parseRuleProfiles({
+        schemaVersion: 1,
+        profiles: [
+          { id: 'a', name: 'A' },
+          { id: 'a', name: 'B' },
+        ],
+      }),
+    ).toMatchObject({ ok: false, error: { kind: 'DuplicateRuleProfile', profileId: 'a' } })
+    expect(
+      parseRuleProfiles({ schemaVersion: 1, profiles: [{ id: 'a', name: 'A', restarts: -1 }] }),
+    ).toMatchObject({ ok: false, error: { kind: 'InvalidRuleProfile', profileId: 'a' } })
+    expect(
+      parseRuleProfiles({ schemaVersion: 1, profiles: [{ id: 'a', name: 'A', branching: 'yes' }] }),
+    ).toMatchObject({ ok: false, error: { kind: 'InvalidRuleProfile', profileId: 'a' } })
+  })
+
+  it('overrides the hint budget when applied', () => {
+    const loaded = loadDefaultBootContent()
+
+    if (!loaded.ok) {
+      throw new Error('default content failed to load')
+    }
+
+    const content = { ...loaded.value, hintBudget: 3 }
+
+    expect(applyRuleProfile(content, ruleProfileById('ironman')).hintBudget).toBe(0)
+    expect(applyRuleProfile(content, STANDARD_RULE_PROFILE)).toMatchObject({
+      hintBudget: 3,
+      ruleProfile: STANDARD_RULE_PROFILE,
+    })
+  })
+})
@@ -0,0 +1,201 @@
+import type { Result } from '../core/result'
+import type { LoadedBootContent } from './loader'
+import { isPlayDifficulty, type PlayDifficulty } from './playDifficulty'
+import bundledRuleProfiles from './content/rule-profiles.json'
+
+/** Named set of rule overrides picked before a level starts (for example "ironman"). */
+export interface RuleProfile {
+  id: string
+  name: string
+  /** Forces a difficulty instead of the one chosen in settings. */
+  difficulty?: PlayDifficulty
+  hints: boolean
+  /** Replaces the level's hint budget; only allowed while hints are on. */
+  hintBudget?: number
+  /** Danger overlay, action preview and detection warnings. */
+  preview: boolean
+  /** Turn planning overlay; it shows guard sight, so it needs `preview`. */
+  planning: boolean
+  /** Restarts allowed per level load; null for unlimited. */
+  restarts: number | null
+  /** Altering the past forks a new timeline instead of overwriting it; paradoxes taint a branch. */
+  branching: boolean
+}
+
+export type RuleProfileError =
+  | { kind: 'InvalidRuleProfiles'; message: string }
+  | { kind: 'InvalidRuleProfile'; profileId: string; message: string }
+  | { kind: 'DuplicateRuleProfile'; profileId: string }
+  | {
+      kind: 'ConflictingRuleOptions'
+      profileId: string
+      options: [string, string]
+      message: string
+    }
+
+export const STANDARD_RULE_PROFILE: RuleProfile = {
+  id: 'standard',
+  name: 'Standard',
+  hints: true,
+  preview: true,
+  planning: true,
+  restarts: null,
+  branching: false,
+}
+
+function isRecord(value: unknown): value is Record<string, unknown> {
+  return typeof value === 'object' && value !== null && !Array.isArray(value)
+}
+
+function optionalBoolean(value: unknown): boolean | null | undefined {
+  if (value === undefined) {
+    return undefined
+  }
+
+  return typeof value === 'boolean' ? value : null
+}
+
+function parseRuleProfile(input: unknown): Result<RuleProfile, RuleProfileError> {
+  if (!isRecord(input) || typeof input.id !== 'string' || input.id.length === 0) {
+    return {
+      ok: false,
+      error: { kind: 'InvalidRuleProfiles', message: 'every profile needs a non-empty id' },
+    }
+  }
+
+  const profileId = input.id
+  const invalid = (message: string): Result<RuleProfile, RuleProfileError> => ({
+    ok: false,
+    error: { kind: 'InvalidRuleProfile', profileId, message },
+  })
+  const conflict = (
+    options: [string, string],
+    message: string,
+  ): Result<RuleProfile, RuleProfileError> => ({
+    ok: false,
+    error: { kind: 'ConflictingRuleOptions', profileId, options, message },
+  })
+
+  const hints = optionalBoolean(input.hints)
+  const preview = optionalBoolean(input.preview)
+  const planning = optionalBoolean(input.planning)
+  const branching = optionalBoolean(input.branching)
+
+  if (typeof input.name !== 'string' || input.name.length === 0) {
+    return invalid('name must be a non-empty string')
+  }
+
+  if (hints === null || preview === null || planning === null || branching === null) {
+    return invalid('hints, preview, planning and branching must be booleans when provided')
+  }
+
+  if (input.difficulty !== undefined && !isPlayDifficulty(input.difficulty)) {
+    return invalid('difficulty must be Easy|Normal|Hard')
+  }
+
+  if (
+    input.hintBudget !== undefined &&
+    (!Number.isInteger(input.hintBudget) || (input.hintBudget as number) < 0)
+  ) {
+    return invalid('hintBudget must be a non-negative integer')
+  }
+
+  if (
+    input.restarts !== undefined &&
+    input.restarts !== null &&
+    (!Number.isInteger(input.restarts) || (input.restarts as number) < 0)
+  ) {
+    return invalid('restarts must be a non-negative integer or null')
+  }
+
+  if (hints === false && input.hintBudget !== undefined) {
+    return conflict(['hints', 'hintBudget'], 'hintBudget is set but hints are off')
+  }
+
+  if (preview === false && planning === true) {
+    return conflict(['preview', 'planning'], 'planning shows guard sight, so it needs preview')
+  }
+
+  return {
+    ok: true,
+    value: {
+      id: profileId,
+      name: input.name,
+      ...(input.difficulty !== undefined ? { difficulty: input.difficulty as PlayDifficulty } : {}),
+      hints: hints ?? true,
+      ...(input.hintBudget !== undefined ? { hintBudget: input.hintBudget as number } : {}),
+      preview: preview ?? true,
+      planning: planning ?? preview ?? true,
+      restarts: (input.restarts as number | null | undefined) ?? null,
+      branching: branching ?? false,
+    },
+  }
+}
+
+/** Parse a rule profiles config file (`{ schemaVersion: 1, profiles: [...] }`). */
+export function parseRuleProfiles(input: unknown): Result<RuleProfile[], RuleProfileError> {
+  if (!isRecord(input) || input.schemaVersion !== 1 || !Array.isArray(input.profiles)) {
+    return {
+      ok: false,
+      error: {
+        kind: 'InvalidRuleProfiles',
+        message: 'expected { schemaVersion: 1, profiles: [...] }',
+      },
+    }
+  }
+
+  const profiles: RuleProfile[] = []
+
+  for (const entry of input.profiles) {
+    const parsed = parseRuleProfile(entry)
+
+    if (!parsed.ok) {
+      return parsed
+    }
+
+    if (profiles.some((profile) => profile.id === parsed.value.id)) {
+      return { ok: false, error: { kind: 'DuplicateRuleProfile', profileId: parsed.value.id } }
+    }
+
+    profiles.push(parsed.value)
+  }
+
+  return { ok: true, value: profiles }
+}
+
+export function formatRuleProfileError(error: RuleProfileError): string {
+  switch (error.kind) {
+    case 'InvalidRuleProfiles':
+      return `${error.kind}: ${error.message}`
+    case 'InvalidRuleProfile':
+      return `${error.kind} (${error.profileId}): ${error.message}`
+    case 'DuplicateRuleProfile':
+      return `${error.kind}: ${error.profileId}`
+    case 'ConflictingRuleOptions':
+      return `${error.kind} (${error.profileId}: ${error.options.join(' vs ')}): ${error.message}`
+  }
+}
+
+const bundled = parseRuleProfiles(bundledRuleProfiles)
+
+/** Bundled profiles; a broken config file falls back to the standard rules only. */
+export const RULE_PROFILES: RuleProfile[] = bundled.ok ? bundled.value : [STANDARD_RULE_PROFILE]
+export const RULE_PROFILES_ERROR: string | null = bundled.ok
+  ? null
+  : formatRuleProfileError(bundled.error)
+
+export function ruleProfileById(profileId: string): RuleProfile {
+  return RULE_PROFILES.find((profile) => profile.id === profileId) ?? STANDARD_RULE_PROFILE
+}
+
+/** Apply a profile's overrides to freshly loaded content. */
+export function applyRuleProfile(
+  content: LoadedBootContent,
+  profile: RuleProfile,
+): LoadedBootContent {
+  return {
+    ...content,
+    hintBudget: profile.hints ? (profile.hintBudget ?? content.hintBudget) : 0,
+    ruleProfile: profile,
+  }
+}
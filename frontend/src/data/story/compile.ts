@@ -187,7 +187,8 @@ export function compileStorySpecToPack(
     }
 
     const policyId = `enemy.policy.${sanitizeArchetypeKey(enemy.id)}`
-    policies[policyId] = enemy.movement
+    // Story paths are often sketched as corners; let the loader fill in the steps between them.
+    policies[policyId] = { ...enemy.movement, expand: true }
     assignments[enemy.id] = policyId
   }
 
@@ -0,0 +1,138 @@
+import { describe, expect, it } from 'vitest'
+
+import type { BehaviorConfig, ContentInstance, LevelConfig } from './contracts'
+import { resolveLevelTemplates } from './templates'
+
+function levelWith(instances: ContentInstance[]): LevelConfig {
+  return {
+    schemaVersion: 1,
+    meta: { id: 'templates', name: 'templates' },
+    map: { width: 6, height: 6, timeDepth: 6, start: { x: 0, y: 0, t: 0 } },
+    archetypes: {
+      enemy: {
+        kind: 'enemy',
+        components: [{ kind: 'BlocksMovement' }, { kind: 'Guard', guard: 'Patroller' }],
+        render: { glyph: 'E', fill: '#000000' },
+      },
+    },
+    templates: {
+      guard_slow: {
+        extends: 'enemy',
+        components: [{ kind: 'Guard', guard: 'Chaser', alertDistance: 2 }, { kind: 'Heavy' }],
+        render: { glyph: 'g' },
+        behavior: 'slow',
+        detectionProfile: 'nearsighted',
+      },
+    },
+    instances,
+  }
+}
+
+/** Templated instances omit `archetype` in the file; the resolver fills it in. */
+function templated(id: string, template: string, position: ContentInstance['position']) {
+  return { id, template, position } as ContentInstance
+}
+
+const behavior: BehaviorConfig = {
+  schemaVersion: 1,
+  policies: {
+    slow: { kind: 'PatrolLoop', path: [{ x: 1, y: 1 }], dwell: [2] },
+    fast: { kind: 'PatrolLoop', path: [{ x: 1, y: 1 }] },
+  },
+  assignments: { 'enemy.b': 'fast' },
+}
+
+describe('resolveLevelTemplates', () => {
+  it('derives an archetype per template and points instances at it', () => {
+    const result = resolveLevelTemplates(
+      levelWith([
+        templated('enemy.a', 'guard_slow', { x: 1, y: 1, t: 0 }),
+        templated('enemy.b', 'guard_slow', { x: 2, y: 1, t: 0 }),
+        { id: 'enemy.c', archetype: 'enemy', position: { x: 3, y: 1, t: 0 } },
+      ]),
+      behavior,
+    )
+
+    expect(result.ok).toBe(true)
+    if (!result.ok) {
+      return
+    }
+
+    const { level, behavior: resolved } = result.value
+
+    expect(level.templates).toBeUndefined()
+    expect(level.archetypes.guard_slow).toEqual({
+      kind: 'enemy',
+      components: [
+        { kind: 'BlocksMovement' },
+        { kind: 'Guard', guard: 'Chaser', alertDistance: 2 },
+        { kind: 'Heavy' },
+      ],
+      render: { glyph: 'g', fill: '#000000' },
+    })
+    expect(level.instances.map((instance) => instance.archetype)).toEqual([
+      'guard_slow',
+      'guard_slow',
+      'enemy',
+    ])
+    expect(resolved.assignments).toEqual({ 'enemy.a': 'slow', 'enemy.b': 'fast' })
+    expect(resolved.detectionAssignments).toEqual({
+      'enemy.a': 'nearsighted',
+      'enemy.b': 'nearsighted',
+    })
+  })
+
+  it('leaves levels without templates untouched', () => {
+    const level = levelWith([{ id: 'enemy.c', archetype: 'enemy', position: { x: 3, y: 1, t: 0 } }])
+    delete level.templates
+
+    expect(resolveLevelTemplates(level, behavior)).toEqual({ ok: true, value: { level, behavior } })
+  })
+
+  it('rejects an instance naming an unknown template', () => {
+    const result = resolveLevelTemplates(
+      levelWith([templated('enemy.a', 'guard_fast', { x: 1, y: 1, t: 0 })]),
+      behavior,
+    )
+
+    expect(result).toEqual({
+      ok: false,
+      error: { kind: 'UnknownTemplateReference', instanceId: 'enemy.a', template: 'guard_fast' },
+    })
+  })
+
+  it('rejects an instance that sets both archetype and template', () => {
+    const result = resolveLevelTemplates(
+      levelWith([
+        {
+          id: 'enemy.a',
+          archetype: 'enemy',
+          template: 'guard_slow',
+          position: { x: 1, y: 1, t: 0 },
+        },
+      ]),
+      behavior,
+    )
+
+    expect(result.ok).toBe(false)
+    if (!result.ok) {
+      expect(result.error.kind).toBe('InvalidShape')
+    }
+  })
+
+  it('rejects a template extending an unknown archetype', () => {
+    const level = levelWith([])
+    level.templates = { ghost: { extends: 'spectre' } }
+
+    const result = resolveLevelTemplates(level, behavior)
+
+    expect(result.ok).toBe(false)
+    if (!result.ok) {
+      expect(result.error).toEqual({
+        kind: 'InvalidShape',
+        file: 'level',
+        message: 'template ghost must extend a known archetype',
+      })
+    }
+  })
+})
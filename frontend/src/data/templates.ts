@@ -0,0 +1,144 @@
+import type { Result } from '../core/result'
+import type {
+  BehaviorConfig,
+  ContentArchetype,
+  ContentComponent,
+  ContentInstance,
+  ContentLoadError,
+  ContentTemplate,
+  LevelConfig,
+} from './contracts'
+
+function isObject(value: unknown): value is Record<string, unknown> {
+  return typeof value === 'object' && value !== null
+}
+
+function templateShapeError(message: string): { ok: false; error: ContentLoadError } {
+  return { ok: false, error: { kind: 'InvalidShape', file: 'level', message } }
+}
+
+function templateShapeIssue(key: string, template: unknown, level: LevelConfig): string | null {
+  if (!isObject(template)) {
+    return `template ${key} must be an object`
+  }
+
+  if (typeof template.extends !== 'string' || !level.archetypes[template.extends]) {
+    return `template ${key} must extend a known archetype`
+  }
+
+  if (template.components !== undefined && !Array.isArray(template.components)) {
+    return `template ${key} components must be an array`
+  }
+
+  if (template.render !== undefined && !isObject(template.render)) {
+    return `template ${key} render must be an object`
+  }
+
+  for (const field of ['behavior', 'detectionProfile'] as const) {
+    if (template[field] !== undefined && typeof template[field] !== 'string') {
+      return `template ${key} ${field} must be a string`
+    }
+  }
+
+  return null
+}
+
+/** Template components replace base components of the same kind; new kinds are appended. */
+function deriveArchetype(base: ContentArchetype, template: ContentTemplate): ContentArchetype {
+  const overrides = template.components ?? []
+  const overridden = new Set(overrides.map((component) => component.kind))
+  const components: ContentComponent[] = [
+    ...base.components.filter((component) => !overridden.has(component.kind)),
+    ...overrides,
+  ]
+
+  return { kind: base.kind, components, render: { ...base.render, ...template.render } }
+}
+
+/**
+ * Resolve `templates` into plain archetypes keyed by template name and point templated instances
+ * at them. Template behavior and detection profiles fill in assignments the behavior file leaves
+ * open. The returned level has no `templates` left, so later validation sees ordinary content.
+ */
+export function resolveLevelTemplates(
+  level: LevelConfig,
+  behavior: BehaviorConfig,
+): Result<{ level: LevelConfig; behavior: BehaviorConfig }, ContentLoadError> {
+  if (level.templates === undefined) {
+    return { ok: true, value: { level, behavior } }
+  }
+
+  if (!isObject(level.templates) || Array.isArray(level.templates)) {
+    return templateShapeError('templates must be an object')
+  }
+
+  const archetypes: Record<string, ContentArchetype> = { ...level.archetypes }
+
+  for (const [key, template] of Object.entries(level.templates)) {
+    const issue = templateShapeIssue(key, template, level)
+
+    if (issue) {
+      return templateShapeError(issue)
+    }
+
+    if (level.archetypes[key]) {
+      return templateShapeError(`template ${key} shares its name with an archetype`)
+    }
+
+    archetypes[key] = deriveArchetype(level.archetypes[template.extends], template)
+  }
+
+  const assignments = { ...behavior.assignments }
+  const detectionAssignments = { ...behavior.detectionAssignments }
+  const instances: ContentInstance[] = []
+
+  for (const instance of level.instances) {
+    if (instance.template === undefined) {
+      instances.push(instance)
+      continue
+    }
+
+    const template = level.templates[instance.template]
+
+    if (typeof instance.template !== 'string' || !template) {
+      return {
+        ok: false,
+        error: {
+          kind: 'UnknownTemplateReference',
+          instanceId: instance.id,
+          template: String(instance.template),
+        },
+      }
+    }
+
+    if (instance.archetype !== undefined) {
+      return templateShapeError(
+        `instance ${instance.id} must set only one of archetype or template`,
+      )
+    }
+
+    instances.push({ ...instance, archetype: instance.template })
+
+    if (template.behavior !== undefined && assignments[instance.id] === undefined) {
+      assignments[instance.id] = template.behavior
+    }
+
+    if (
+      template.detectionProfile !== undefined &&
+      detectionAssignments[instance.id] === undefined
+    ) {
+      detectionAssignments[instance.id] = template.detectionProfile
+    }
+  }
+
+  const resolvedLevel: LevelConfig = { ...level, archetypes, instances }
+  delete resolvedLevel.templates
+
+  const resolvedBehavior: BehaviorConfig = { ...behavior, assignments }
+
+  if (behavior.detectionAssignments !== undefined || Object.keys(detectionAssignments).length > 0) {
+    resolvedBehavior.detectionAssignments = detectionAssignments
+  }
+
+  return { ok: true, value: { level: resolvedLevel, behavior: resolvedBehavior } }
+}
@@ -0,0 +1,55 @@
+import { describe, expect, it } from 'vitest'
+
+import { formatToml, parseToml } from './toml'
+
+describe('parseToml', () => {
+  it('flattens tables into dotted keys', () => {
+    const parsed = parseToml(
+      [
+        'language = "es" # trailing comment',
+        '',
+        '[theme]',
+        '"--ui-bg" = "#101010"',
+        '',
+        '[keymap]',
+        'Wait = ["Enter", "."]',
+        '',
+        '[detection]',
+        'enabled = false',
+        'max_distance = 1_0',
+      ].join('\n'),
+    )
+
+    expect(parsed.ok).toBe(true)
+    if (!parsed.ok) {
+      return
+    }
+
+    expect([...parsed.value]).toEqual([
+      ['language', 'es'],
+      ['theme.--ui-bg', '#101010'],
+      ['keymap.Wait', ['Enter', '.']],
+      ['detection.enabled', false],
+      ['detection.max_distance', 10],
+    ])
+  })
+
+  it('reports the line of the first error', () => {
+    expect(parseToml('a = 1\nb = nope')).toEqual({
+      ok: false,
+      error: { line: 2, message: 'invalid value for b' },
+    })
+    expect(parseToml('[x]\na = 1\na = 2')).toEqual({
+      ok: false,
+      error: { line: 3, message: 'duplicate key x.a' },
+    })
+    expect(parseToml('[broken').ok).toBe(false)
+  })
+
+  it('round-trips through formatToml', () => {
+    const source = 'rules = "standard"\n\n[assist]\nghost_run = true\n\n[keymap]\nRift = [" ", "r"]\n'
+    const parsed = parseToml(source)
+
+    expect(parsed.ok && formatToml(parsed.value)).toBe(source)
+  })
+})
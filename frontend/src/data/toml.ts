@@ -0,0 +1,243 @@
+import type { Result } from '../core/result'
+
+export type TomlValue = string | number | boolean | Array<string | number | boolean>
+
+/**
+ * Flat view of a TOML document: `[section]` + `key = value` becomes `section.key`. Only the
+ * subset `config.toml` needs is read: one level of tables, strings, numbers, booleans and
+ * single-line arrays of those.
+ */
+export type TomlTable = Map<string, TomlValue>
+
+export interface TomlError {
+  line: number
+  message: string
+}
+
+const BARE_KEY = /^[A-Za-z0-9_-]+$/
+const ESCAPES: Record<string, string> = { '"': '"', '\\': '\\', n: '\n', t: '\t' }
+
+function readString(source: string, start: number): { value: string; end: number } | null {
+  let value = ''
+
+  for (let index = start + 1; index < source.length; index += 1) {
+    const char = source[index]
+
+    if (char === '"') {
+      return { value, end: index + 1 }
+    }
+
+    if (char === '\\') {
+      const escaped = source[index + 1]
+      const replacement = ESCAPES[escaped ?? '']
+
+      if (replacement === undefined) {
+        return null
+      }
+
+      value += replacement
+      index += 1
+      continue
+    }
+
+    value += char
+  }
+
+  return null
+}
+
+/** Drops a trailing `#` comment, leaving `#` inside strings alone. */
+function stripComment(line: string): string {
+  let inString = false
+
+  for (let index = 0; index < line.length; index += 1) {
+    const char = line[index]
+
+    if (char === '\\' && inString) {
+      index += 1
+    } else if (char === '"') {
+      inString = !inString
+    } else if (char === '#' && !inString) {
+      return line.slice(0, index)
+    }
+  }
+
+  return line
+}
+
+function parseScalar(raw: string): string | number | boolean | null {
+  if (raw === 'true' || raw === 'false') {
+    return raw === 'true'
+  }
+
+  if (raw.startsWith('"')) {
+    const parsed = readString(raw, 0)
+
+    return parsed && parsed.end === raw.length ? parsed.value : null
+  }
+
+  const number = Number(raw.replace(/_/g, ''))
+
+  return raw !== '' && Number.isFinite(number) ? number : null
+}
+
+/** Splits `a, "b, c", 3` on the commas outside strings. */
+function splitArrayItems(body: string): string[] {
+  const items: string[] = []
+  let inString = false
+  let current = ''
+
+  for (let index = 0; index < body.length; index += 1) {
+    const char = body[index]
+
+    if (char === '\\' && inString) {
+      current += char + (body[index + 1] ?? '')
+      index += 1
+      continue
+    }
+
+    if (char === '"') {
+      inString = !inString
+    }
+
+    if (char === ',' && !inString) {
+      items.push(current.trim())
+      current = ''
+      continue
+    }
+
+    current += char
+  }
+
+  if (current.trim() !== '') {
+    items.push(current.trim())
+  }
+
+  return items
+}
+
+function parseValue(raw: string): TomlValue | null {
+  if (!raw.startsWith('[')) {
+    return parseScalar(raw)
+  }
+
+  if (!raw.endsWith(']')) {
+    return null
+  }
+
+  const items = splitArrayItems(raw.slice(1, -1)).map(parseScalar)
+
+  return items.every((item) => item !== null) ? (items as Array<string | number | boolean>) : null
+}
+
+function parseKey(raw: string): string | null {
+  if (raw.startsWith('"')) {
+    const parsed = readString(raw, 0)
+
+    return parsed && parsed.end === raw.length ? parsed.value : null
+  }
+
+  return BARE_KEY.test(raw) ? raw : null
+}
+
+/** Splits `key = value` on the first `=` outside a quoted key. */
+function splitAssignment(line: string): [string, string] | null {
+  const keyEnd = line.startsWith('"') ? (readString(line, 0)?.end ?? -1) : 0
+  const equals = keyEnd < 0 ? -1 : line.indexOf('=', keyEnd)
+
+  return equals < 0 ? null : [line.slice(0, equals).trim(), line.slice(equals + 1).trim()]
+}
+
+export function parseToml(source: string): Result<TomlTable, TomlError> {
+  const table: TomlTable = new Map()
+  let section = ''
+  const lines = source.split(/\r?\n/)
+
+  for (let index = 0; index < lines.length; index += 1) {
+    const line = stripComment(lines[index]).trim()
+    const lineNumber = index + 1
+
+    if (line === '') {
+      continue
+    }
+
+    if (line.startsWith('[')) {
+      const name = line.endsWith(']') ? parseKey(line.slice(1, -1).trim()) : null
+
+      if (name === null) {
+        return { ok: false, error: { line: lineNumber, message: `invalid table header ${line}` } }
+      }
+
+      section = name
+      continue
+    }
+
+    const assignment = splitAssignment(line)
+    const key = assignment ? parseKey(assignment[0]) : null
+
+    if (!assignment || key === null) {
+      return {
+        ok: false,
+        error: { line: lineNumber, message: `expected key = value, got ${line}` },
+      }
+    }
+
+    const value = parseValue(assignment[1])
+
+    if (value === null) {
+      return { ok: false, error: { line: lineNumber, message: `invalid value for ${key}` } }
+    }
+
+    const path = section ? `${section}.${key}` : key
+
+    if (table.has(path)) {
+      return { ok: false, error: { line: lineNumber, message: `duplicate key ${path}` } }
+    }
+
+    table.set(path, value)
+  }
+
+  return { ok: true, value: table }
+}
+
+function formatScalar(value: string | number | boolean): string {
+  return typeof value === 'string' ? JSON.stringify(value) : String(value)
+}
+
+function formatKey(key: string): string {
+  return BARE_KEY.test(key) ? key : JSON.stringify(key)
+}
+
+/** Writes a flat table back out, top-level keys first, then one `[section]` per prefix. */
+export function formatToml(table: TomlTable): string {
+  const sections = new Map<string, Array<[string, TomlValue]>>()
+
+  for (const [path, value] of table) {
+    const dot = path.indexOf('.')
+    const section = dot < 0 ? '' : path.slice(0, dot)
+    const key = dot < 0 ? path : path.slice(dot + 1)
+    const entries = sections.get(section) ?? []
+
+    entries.push([key, value])
+    sections.set(section, entries)
+  }
+
+  const blocks: string[] = []
+  const ordered = [...sections.keys()].sort((a, b) => (a === '' ? -1 : b === '' ? 1 : 0))
+
+  for (const section of ordered) {
+    const lines = section ? [`[${formatKey(section)}]`] : []
+
+    for (const [key, value] of sections.get(section) ?? []) {
+      const formatted = Array.isArray(value)
+        ? `[${value.map(formatScalar).join(', ')}]`
+        : formatScalar(value)
+
+      lines.push(`${formatKey(key)} = ${formatted}`)
+    }
+
+    blocks.push(lines.join('\n'))
+  }
+
+  return `${blocks.join('\n\n')}\n`
+}
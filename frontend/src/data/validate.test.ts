@@ -72,6 +72,126 @@ describe('validateContentPack', () => {
     }
   })
 
+  it('rejects patrol routes that jump or enter walls', () => {
+    const withRoute = (path: { x: number; y: number }[]) => {
+      const input = minimalValidInputs()
+      const level = input.level as { instances: Array<Record<string, unknown>> }
+      const behavior = input.behavior as {
+        policies: Record<string, unknown>
+        assignments: Record<string, string>
+      }
+
+      level.instances.push({ id: 'guard', archetype: 'wall', position: { x: 0, y: 0, t: 0 } })
+      behavior.policies.route = { kind: 'PatrolPingPong', path }
+      behavior.assignments.guard = 'route'
+
+      return validateContentPack(input)
+    }
+
+    expect(
+      withRoute([
+        { x: 0, y: 0 },
+        { x: 0, y: 2 },
+      ]),
+    ).toEqual({
+      ok: false,
+      error: {
+        kind: 'DisjointPatrolStep',
+        key: 'route',
+        index: 0,
+        from: { x: 0, y: 0 },
+        to: { x: 0, y: 2 },
+      },
+    })
+    expect(
+      withRoute([
+        { x: 0, y: 1 },
+        { x: 1, y: 1 },
+      ]),
+    ).toEqual({
+      ok: false,
+      error: {
+        kind: 'BlockedPatrolWaypoint',
+        key: 'route',
+        index: 1,
+        point: { x: 1, y: 1 },
+        blockerId: 'wall.1',
+      },
+    })
+  })
+
+  it('lets patrol routes step across the wrapping edge of a torus map', () => {
+    const input = minimalValidInputs()
+    const level = input.level as {
+      map: Record<string, unknown>
+      instances: Array<Record<string, unknown>>
+    }
+    const behavior = input.behavior as {
+      policies: Record<string, unknown>
+      assignments: Record<string, string>
+    }
+
+    level.instances.push({ id: 'guard', archetype: 'wall', position: { x: 0, y: 2, t: 0 } })
+    behavior.policies.route = {
+      kind: 'PatrolLoop',
+      path: [
+        { x: 0, y: 2 },
+        { x: 3, y: 2 },
+      ],
+    }
+    behavior.assignments.guard = 'route'
+
+    expect(validateContentPack(input)).toMatchObject({
+      ok: false,
+      error: { kind: 'DisjointPatrolStep', index: 0 },
+    })
+
+    level.map.xWraps = true
+
+    expect(validateContentPack(input).ok).toBe(true)
+  })
+
+  it('expands patrol gaps around walls when asked', () => {
+    const input = minimalValidInputs()
+    const level = input.level as { instances: Array<Record<string, unknown>> }
+    const behavior = input.behavior as {
+      policies: Record<string, unknown>
+      assignments: Record<string, string>
+    }
+
+    level.instances.push({ id: 'guard', archetype: 'wall', position: { x: 0, y: 1, t: 0 } })
+    behavior.policies.route = {
+      kind: 'PatrolPingPong',
+      path: [
+        { x: 0, y: 1 },
+        { x: 2, y: 1 },
+      ],
+      dwell: [2, 3],
+      expand: true,
+    }
+    behavior.assignments.guard = 'route'
+
+    const result = validateContentPack(input)
+
+    expect(result.ok).toBe(true)
+    if (!result.ok) {
+      return
+    }
+
+    expect(result.value.behavior.policies.route).toEqual({
+      kind: 'PatrolPingPong',
+      path: [
+        { x: 0, y: 1 },
+        { x: 0, y: 0 },
+        { x: 1, y: 0 },
+        { x: 2, y: 0 },
+        { x: 2, y: 1 },
+      ],
+      dwell: [2, 1, 1, 1, 3],
+      expand: true,
+    })
+  })
+
   it('rejects unknown archetype references', () => {
     const input = minimalValidInputs()
     const level = input.level as {
@@ -174,6 +294,27 @@ describe('validateContentPack', () => {
     }
   })
 
+  it('accepts a boolean precog flag on detection profiles', () => {
+    const input = minimalValidInputs()
+    const behavior = input.behavior as { detectionProfiles?: Record<string, unknown> }
+    behavior.detectionProfiles = {
+      seer: { enabled: true, delayTurns: 2, maxDistance: 3, precog: true },
+    }
+
+    expect(validateContentPack(input).ok).toBe(true)
+
+    behavior.detectionProfiles = {
+      seer: { enabled: true, delayTurns: 2, maxDistance: 3, precog: 'yes' },
+    }
+
+    const result = validateContentPack(input)
+
+    expect(result.ok).toBe(false)
+    if (!result.ok) {
+      expect(result.error.kind).toBe('InvalidDetectionProfile')
+    }
+  })
+
   it('rejects theme without icon pack id', () => {
     const input = minimalValidInputs()
     const theme = input.theme as { iconPackId?: string }
@@ -246,6 +387,513 @@ describe('validateContentPack', () => {
       expect(result.error.kind).toBe('ConflictingRiftSource')
     }
   })
+
+  it('rejects bidirectional rifts that share a landing cell', () => {
+    const input = minimalValidInputs()
+    const level = input.level as {
+      archetypes: Record<string, unknown>
+      instances: Array<{ id: string; archetype: string; position: { x: number; y: number; t: number } }>
+    }
+    level.archetypes.rift = {
+      kind: 'rift',
+      components: [{ kind: 'Rift', target: { x: 3, y: 3, t: 2 }, bidirectional: true }],
+      render: {},
+    }
+    level.instances.push({ id: 'rift.a', archetype: 'rift', position: { x: 0, y: 3, t: 0 } })
+    level.instances.push({ id: 'rift.b', archetype: 'rift', position: { x: 3, y: 0, t: 0 } })
+
+    const result = validateContentPack(input)
+
+    expect(result.ok).toBe(false)
+    if (!result.ok) {
+      expect(result.error).toEqual({
+        kind: 'ConflictingRiftSource',
+        source: { x: 3, y: 3, t: 2 },
+        archetype: 'rift',
+      })
+    }
+  })
+
+  it('validates linked rifts against their resolved targets', () => {
+    const input = minimalValidInputs()
+    const level = input.level as {
+      archetypes: Record<string, unknown>
+      instances: Array<{ id: string; archetype: string; position: { x: number; y: number; t: number } }>
+    }
+    level.archetypes.portal = {
+      kind: 'rift',
+      components: [{ kind: 'Rift', linkId: 'pair', bidirectional: false }],
+      render: {},
+    }
+    level.instances.push({ id: 'portal.a', archetype: 'portal', position: { x: 0, y: 3, t: 0 } })
+
+    const unpaired = validateContentPack(input)
+
+    expect(unpaired.ok).toBe(false)
+    if (!unpaired.ok) {
+      expect(unpaired.error.kind).toBe('UnresolvedRiftLink')
+    }
+
+    level.instances.push({ id: 'portal.b', archetype: 'portal', position: { x: 3, y: 0, t: 2 } })
+
+    expect(validateContentPack(input).ok).toBe(true)
+  })
+
+  it('rejects sentries that patrol or have no facings', () => {
+    const input = minimalValidInputs()
+    const level = input.level as {
+      archetypes: Record<string, { kind: string; components: unknown[]; render: object }>
+    }
+    level.archetypes.sentry = {
+      kind: 'enemy',
+      components: [{ kind: 'Guard', guard: 'Sentry', facings: [] }, { kind: 'TimePersistent' }],
+      render: {},
+    }
+
+    const noFacings = validateContentPack(input)
+
+    expect(noFacings.ok).toBe(false)
+    if (!noFacings.ok) {
+      expect(noFacings.error).toEqual({
+        kind: 'InvalidArchetypeComponents',
+        archetype: 'sentry',
+        message: 'Sentry needs a non-empty facings list of north|south|east|west',
+      })
+    }
+
+    level.archetypes.sentry.components = [
+      { kind: 'Guard', guard: 'Sentry', facings: ['east'] },
+      { kind: 'Patrol', path: [{ x: 2, y: 2 }], loops: true },
+    ]
+    const patrolling = validateContentPack(input)
+
+    expect(patrolling.ok).toBe(false)
+    if (!patrolling.ok) {
+      expect(patrolling.error.kind).toBe('InvalidArchetypeComponents')
+    }
+
+    level.archetypes.sentry.components = [{ kind: 'Guard', guard: 'Sentry', facings: ['east'] }]
+
+    expect(validateContentPack(input).ok).toBe(true)
+  })
+
+  it('rejects low objects that claim to block vision', () => {
+    const input = minimalValidInputs()
+    const level = input.level as {
+      archetypes: Record<string, { kind: string; components: unknown[]; render: object }>
+    }
+    level.archetypes.wall.components.push({ kind: 'BlocksVision' }, { kind: 'Height', height: 1 })
+
+    const result = validateContentPack(input)
+
+    expect(result.ok).toBe(false)
+    if (!result.ok) {
+      expect(result.error).toEqual({
+        kind: 'InvalidArchetypeComponents',
+        archetype: 'wall',
+        message: 'BlocksVision needs Height >= 2; lower objects are seen over',
+      })
+    }
+  })
+
+  it('requires ladders to stand on a catwalk with a known railing', () => {
+    const input = minimalValidInputs()
+    const level = input.level as {
+      archetypes: Record<string, { kind: string; components: unknown[]; render: object }>
+    }
+    level.archetypes.ladder = { kind: 'ladder', components: [{ kind: 'Ladder' }], render: {} }
+
+    const bare = validateContentPack(input)
+
+    expect(bare.ok).toBe(false)
+    if (!bare.ok) {
+      expect(bare.error).toEqual({
+        kind: 'InvalidArchetypeComponents',
+        archetype: 'ladder',
+        message: 'Ladder requires Catwalk',
+      })
+    }
+
+    level.archetypes.ladder.components.push({ kind: 'Catwalk', railing: 'low' })
+    expect(validateContentPack(input).ok).toBe(false)
+
+    level.archetypes.ladder.components = [{ kind: 'Ladder' }, { kind: 'Catwalk', railing: 'open' }]
+    expect(validateContentPack(input).ok).toBe(true)
+  })
+
+  it('keeps footprints on the map and off objects that walk, turn or stack', () => {
+    const input = minimalValidInputs()
+    const level = input.level as {
+      archetypes: Record<string, { kind: string; components: unknown[]; render: object }>
+      instances: Array<{ id: string; archetype: string; position: object }>
+    }
+    level.archetypes.crate = {
+      kind: 'crate',
+      components: [
+        { kind: 'BlocksMovement' },
+        { kind: 'Pushable' },
+        { kind: 'Footprint', width: 2, height: 2 },
+      ],
+      render: {},
+    }
+    level.instances.push({ id: 'crate.1', archetype: 'crate', position: { x: 3, y: 2, t: 0 } })
+
+    const offMap = validateContentPack(input)
+
+    expect(offMap.ok).toBe(false)
+    if (!offMap.ok) {
+      expect(offMap.error).toEqual({
+        kind: 'InvalidShape',
+        file: 'level',
+        message: 'Instance crate.1 footprint leaves the map',
+      })
+    }
+
+    level.instances[1].position = { x: 2, y: 2, t: 0 }
+    expect(validateContentPack(input).ok).toBe(true)
+
+    level.archetypes.crate.components.push({ kind: 'Stackable' })
+    const stacking = validateContentPack(input)
+
+    expect(stacking.ok).toBe(false)
+    if (!stacking.ok) {
+      expect(stacking.error).toEqual({
+        kind: 'InvalidArchetypeComponents',
+        archetype: 'crate',
+        message: 'Footprint cannot be combined with Stackable',
+      })
+    }
+  })
+
+  it('requires breakable archetypes to block movement and take at least one hit', () => {
+    const input = minimalValidInputs()
+    const level = input.level as {
+      archetypes: Record<string, { kind: string; components: unknown[]; render: object }>
+    }
+    level.archetypes.crate = {
+      kind: 'crate',
+      components: [{ kind: 'Breakable', hits: 2 }],
+      render: {},
+    }
+
+    const loose = validateContentPack(input)
+
+    expect(loose.ok).toBe(false)
+    if (!loose.ok) {
+      expect(loose.error).toEqual({
+        kind: 'InvalidArchetypeComponents',
+        archetype: 'crate',
+        message: 'Breakable requires BlocksMovement',
+      })
+    }
+
+    level.archetypes.crate.components = [{ kind: 'BlocksMovement' }, { kind: 'Breakable', hits: 0 }]
+    expect(validateContentPack(input).ok).toBe(false)
+
+    level.archetypes.crate.components = [{ kind: 'BlocksMovement' }, { kind: 'Breakable', hits: 2 }]
+    expect(validateContentPack(input).ok).toBe(true)
+  })
+
+  it('only puts extraction holds on exits', () => {
+    const input = minimalValidInputs()
+    const level = input.level as {
+      archetypes: Record<string, { kind: string; components: unknown[]; render: object }>
+    }
+    level.archetypes.roof = {
+      kind: 'exit',
+      components: [{ kind: 'Extraction', turns: 3 }],
+      render: {},
+    }
+
+    const loose = validateContentPack(input)
+
+    expect(loose.ok).toBe(false)
+    if (!loose.ok) {
+      expect(loose.error).toEqual({
+        kind: 'InvalidArchetypeComponents',
+        archetype: 'roof',
+        message: 'Extraction requires Exit',
+      })
+    }
+
+    level.archetypes.roof.components = [{ kind: 'Exit' }, { kind: 'Extraction', turns: 0 }]
+    expect(validateContentPack(input).ok).toBe(false)
+
+    level.archetypes.roof.components = [{ kind: 'Exit' }, { kind: 'Extraction', turns: 3 }]
+    expect(validateContentPack(input).ok).toBe(true)
+  })
+
+  it('requires stackable boxes to be pushable and lower than eye height', () => {
+    const input = minimalValidInputs()
+    const level = input.level as {
+      archetypes: Record<string, { kind: string; components: unknown[]; render: object }>
+    }
+    level.archetypes.crate = {
+      kind: 'crate',
+      components: [{ kind: 'BlocksMovement' }, { kind: 'Stackable' }],
+      render: {},
+    }
+
+    const fixed = validateContentPack(input)
+
+    expect(fixed.ok).toBe(false)
+    if (!fixed.ok) {
+      expect(fixed.error).toEqual({
+        kind: 'InvalidArchetypeComponents',
+        archetype: 'crate',
+        message: 'Stackable requires Pushable',
+      })
+    }
+
+    level.archetypes.crate.components.push({ kind: 'Pushable' }, { kind: 'Height', height: 2 })
+
+    const tall = validateContentPack(input)
+
+    expect(tall.ok).toBe(false)
+    if (!tall.ok) {
+      expect(tall.error).toEqual({
+        kind: 'InvalidArchetypeComponents',
+        archetype: 'crate',
+        message: 'Stackable needs Height < 2; only a stack reaches eye height',
+      })
+    }
+
+    level.archetypes.crate.components.pop()
+    expect(validateContentPack(input).ok).toBe(true)
+  })
+
+  it('requires water to block movement and stay put', () => {
+    const input = minimalValidInputs()
+    const level = input.level as {
+      archetypes: Record<string, { kind: string; components: unknown[]; render: object }>
+    }
+    level.archetypes.water = { kind: 'water', components: [{ kind: 'Water' }], render: {} }
+
+    const shallow = validateContentPack(input)
+
+    expect(shallow.ok).toBe(false)
+    if (!shallow.ok) {
+      expect(shallow.error).toEqual({
+        kind: 'InvalidArchetypeComponents',
+        archetype: 'water',
+        message: 'Water must block movement and cannot be pushable',
+      })
+    }
+
+    level.archetypes.water.components.push({ kind: 'BlocksMovement' }, { kind: 'Pushable' })
+    expect(validateContentPack(input).ok).toBe(false)
+
+    level.archetypes.water.components.pop()
+    expect(validateContentPack(input).ok).toBe(true)
+  })
+
+  it('checks dependency sources, their start slice and cycles', () => {
+    const input = minimalValidInputs()
+    const level = input.level as {
+      archetypes: Record<string, { kind: string; components: unknown[]; render: object }>
+      instances: { id: string; archetype: string; position: { x: number; y: number; t: number } }[]
+    }
+    level.archetypes.gate = {
+      kind: 'gate',
+      components: [
+        { kind: 'BlocksMovement' },
+        { kind: 'TimePersistent' },
+        { kind: 'DependsOn', source: 'wall.1', at: 1 },
+      ],
+      render: {},
+    }
+    level.instances.push({ id: 'gate.1', archetype: 'gate', position: { x: 2, y: 2, t: 0 } })
+
+    expect(validateContentPack(input).ok).toBe(true)
+
+    level.archetypes.gate.components[2] = { kind: 'DependsOn', source: 'wall.9', at: 1 }
+    const missing = validateContentPack(input)
+
+    expect(missing.ok).toBe(false)
+    if (!missing.ok) {
+      expect(missing.error).toEqual({
+        kind: 'InvalidDependency',
+        instanceId: 'gate.1',
+        source: 'wall.9',
+        message: 'source must be another instance',
+      })
+    }
+
+    level.archetypes.gate.components[2] = { kind: 'DependsOn', source: 'wall.1', at: 4 }
+    expect(validateContentPack(input).ok).toBe(false)
+
+    level.archetypes.gate.components[2] = { kind: 'DependsOn', source: 'gate.2', at: 0 }
+    level.instances.push({ id: 'gate.2', archetype: 'gate', position: { x: 3, y: 3, t: 0 } })
+    const cycle = validateContentPack(input)
+
+    expect(cycle.ok).toBe(false)
+    if (!cycle.ok) {
+      expect(cycle.error).toMatchObject({
+        kind: 'InvalidDependency',
+        message: 'dependencies form a cycle',
+      })
+    }
+  })
+
+  it('rejects anchored archetypes that depend on a source', () => {
+    const input = minimalValidInputs()
+    const level = input.level as {
+      archetypes: Record<string, { kind: string; components: unknown[]; render: object }>
+    }
+    level.archetypes.wall.components.push({ kind: 'Anchored' })
+
+    expect(validateContentPack(input).ok).toBe(true)
+
+    level.archetypes.wall.components.push({ kind: 'DependsOn', source: 'wall.2', at: 0 })
+    const result = validateContentPack(input)
+
+    expect(result.ok).toBe(false)
+    if (!result.ok) {
+      expect(result.error).toEqual({
+        kind: 'InvalidArchetypeComponents',
+        archetype: 'wall',
+        message: 'Anchored objects cannot depend on a source the past can remove',
+      })
+    }
+  })
+
+  it('checks appearance glyphs and colors on archetypes and instances', () => {
+    const input = minimalValidInputs()
+    const level = input.level as {
+      archetypes: Record<string, { kind: string; components: unknown[]; render: object }>
+      instances: Record<string, unknown>[]
+    }
+    level.archetypes.wall.components.push({ kind: 'Appearance', glyph: '#', color: '#445566' })
+    level.instances[0].color = '#fff'
+
+    expect(validateContentPack(input).ok).toBe(true)
+
+    level.instances[0].glyph = 'ab'
+
+    const result = validateContentPack(input)
+
+    expect(result.ok).toBe(false)
+    if (!result.ok) {
+      expect(result.error).toEqual({
+        kind: 'InvalidShape',
+        file: 'level',
+        message: 'Instance wall.1: Appearance glyph must be a single character',
+      })
+    }
+  })
+
+  it('accepts regions inside the map and rejects overflowing or duplicate ones', () => {
+    const input = minimalValidInputs()
+    const level = input.level as { regions?: unknown[] }
+    level.regions = [{ id: 'vault', name: 'Vault', x: 2, y: 2, width: 2, height: 2 }]
+
+    expect(validateContentPack(input).ok).toBe(true)
+
+    level.regions.push({ id: 'yard', name: 'Yard', x: 0, y: 0, width: 5, height: 1 })
+
+    const overflow = validateContentPack(input)
+
+    expect(overflow.ok).toBe(false)
+    if (!overflow.ok) {
+      expect(overflow.error).toEqual({
+        kind: 'InvalidShape',
+        file: 'level',
+        message: 'region yard must be a non-empty rectangle inside the map',
+      })
+    }
+
+    level.regions[1] = { id: 'vault', name: 'Vault 2', x: 0, y: 0, width: 1, height: 1 }
+
+    const duplicate = validateContentPack(input)
+
+    expect(duplicate.ok).toBe(false)
+    if (!duplicate.ok) {
+      expect(duplicate.error).toMatchObject({ message: 'region vault is declared twice' })
+    }
+  })
+
+  it('rejects tutorial steps gated on unknown actions', () => {
+    const input = minimalValidInputs()
+    const level = input.level as { meta: Record<string, unknown> }
+    level.meta.tutorial = [
+      { prompt: 'Move east', gate: { kind: 'Action', action: 'Move', direction: 'east' } },
+      { prompt: 'Dance', gate: { kind: 'Action', action: 'Dance' } },
+    ]
+
+    const result = validateContentPack(input)
+
+    expect(result.ok).toBe(false)
+    if (!result.ok) {
+      expect(result.error).toEqual({
+        kind: 'InvalidTutorialStep',
+        index: 1,
+        message: 'unknown action Dance',
+      })
+    }
+  })
+
+  it('accepts level metadata and rejects an unknown difficulty tier', () => {
+    const input = minimalValidInputs()
+    const level = input.level as { meta: Record<string, unknown> }
+    level.meta.author = 'ana'
+    level.meta.description = 'A quiet room.'
+    level.meta.tags = ['intro']
+    level.meta.difficulty = 'easy'
+
+    expect(validateContentPack(input).ok).toBe(true)
+
+    level.meta.difficulty = 'legendary'
+    const result = validateContentPack(input)
+
+    expect(result.ok).toBe(false)
+    if (!result.ok) {
+      expect(result.error).toEqual({
+        kind: 'InvalidShape',
+        file: 'level',
+        message: 'meta.difficulty must be easy|normal|hard|expert',
+      })
+    }
+  })
+
+  it('accepts a rules echo limit and rejects a non-positive one', () => {
+    const input = minimalValidInputs()
+    const rules = input.rules as Record<string, unknown>
+    rules.echoes = { max: 3 }
+
+    expect(validateContentPack(input).ok).toBe(true)
+
+    rules.echoes = { max: 0 }
+    const result = validateContentPack(input)
+
+    expect(result.ok).toBe(false)
+    if (!result.ok) {
+      expect(result.error).toEqual({
+        kind: 'InvalidShape',
+        file: 'rules',
+        message: 'echoes.max must be a positive integer',
+      })
+    }
+  })
+
+  it('accepts a rules rng seed and rejects an empty one', () => {
+    const input = minimalValidInputs()
+    const rules = input.rules as Record<string, unknown>
+    rules.rng = { seed: 'night-shift' }
+
+    expect(validateContentPack(input).ok).toBe(true)
+
+    rules.rng = { seed: '' }
+    const result = validateContentPack(input)
+
+    expect(result.ok).toBe(false)
+    if (!result.ok) {
+      expect(result.error).toEqual({
+        kind: 'InvalidShape',
+        file: 'rules',
+        message: 'rng.seed must be a non-empty string',
+      })
+    }
+  })
 })
 
 describe('validateIconPackConfig', () => {
@@ -1,21 +1,30 @@
-import { isInBounds, type Position2D, type Position3D } from '../core/position'
+import { PLAYER_CHARACTER_ID } from '../core/characters'
+import { EYE_HEIGHT } from '../core/components'
+import { coveredCells } from '../core/footprint'
+import { expandPatrolRoute, patrolRouteGaps, type PatrolRoute } from '../core/patrol'
+import { isInBounds, type Position2D, type Position3D, type Wrapping } from '../core/position'
 import type { Result } from '../core/result'
-import type {
-  BehaviorConfig,
-  ContentLoadError,
-  ContentPack,
-  DifficultyDimensionWeights,
-  DifficultyModelConfig,
-  DifficultyModelConfigError,
-  DifficultyNormalizationConfig,
-  DifficultyRange,
-  DifficultyScoreWeights,
-  DifficultyTierBounds,
-  GameRulesConfig,
-  IconPackConfig,
-  LevelConfig,
-  ThemeConfig,
+import {
+  ACTION_KINDS,
+  type BehaviorConfig,
+  type ContentComponent,
+  type ContentInstance,
+  type ContentLoadError,
+  type ContentPack,
+  type DifficultyDimensionWeights,
+  type DifficultyModelConfig,
+  type DifficultyModelConfigError,
+  type DifficultyNormalizationConfig,
+  type DifficultyRange,
+  type DifficultyScoreWeights,
+  type DifficultyTierBounds,
+  type GameRulesConfig,
+  type IconPackConfig,
+  type LevelConfig,
+  type ThemeConfig,
 } from './contracts'
+import { contentRiftTarget, resolveRiftLinkTargets, type ContentRiftComponent } from './riftLinks'
+import { resolveLevelTemplates } from './templates'
 
 function isObject(value: unknown): value is Record<string, unknown> {
   return typeof value === 'object' && value !== null
@@ -109,6 +118,16 @@ function parseThemeConfig(input: unknown): Result<ThemeConfig, ContentLoadError>
   return { ok: true, value: input as unknown as ThemeConfig }
 }
 
+function isValidLureRules(lures: unknown): boolean {
+  if (!isObject(lures) || !isInteger(lures.supply) || lures.supply < 0) {
+    return false
+  }
+
+  return [lures.maxRange, lures.radius, lures.duration].every(
+    (value) => value === undefined || (isInteger(value) && value >= 1),
+  )
+}
+
 function parseRulesConfig(input: unknown): Result<GameRulesConfig, ContentLoadError> {
   if (!isObject(input)) {
     return {
@@ -129,6 +148,177 @@ function parseRulesConfig(input: unknown): Result<GameRulesConfig, ContentLoadEr
     }
   }
 
+  if (
+    input.hints !== undefined &&
+    (!isObject(input.hints) || !isInteger(input.hints.budget) || input.hints.budget < 0)
+  ) {
+    return {
+      ok: false,
+      error: {
+        kind: 'InvalidShape',
+        file: 'rules',
+        message: 'hints.budget must be a non-negative integer',
+      },
+    }
+  }
+
+  if (
+    input.peeks !== undefined &&
+    (!isObject(input.peeks) || !isInteger(input.peeks.budget) || input.peeks.budget < 0)
+  ) {
+    return {
+      ok: false,
+      error: {
+        kind: 'InvalidShape',
+        file: 'rules',
+        message: 'peeks.budget must be a non-negative integer',
+      },
+    }
+  }
+
+  if (
+    input.knockouts !== undefined &&
+    (!isObject(input.knockouts) ||
+      !isInteger(input.knockouts.alertTurns) ||
+      input.knockouts.alertTurns < 1)
+  ) {
+    return {
+      ok: false,
+      error: {
+        kind: 'InvalidShape',
+        file: 'rules',
+        message: 'knockouts.alertTurns must be a positive integer',
+      },
+    }
+  }
+
+  if (
+    input.alarm !== undefined &&
+    (!isObject(input.alarm) ||
+      !isInteger(input.alarm.threshold) ||
+      input.alarm.threshold < 1 ||
+      !isInteger(input.alarm.lockdownTurns) ||
+      input.alarm.lockdownTurns < 1 ||
+      (input.alarm.glimpseMargin !== undefined &&
+        (!isInteger(input.alarm.glimpseMargin) || input.alarm.glimpseMargin < 1)) ||
+      (input.alarm.rangeBonus !== undefined &&
+        (!isInteger(input.alarm.rangeBonus) || input.alarm.rangeBonus < 0)))
+  ) {
+    return {
+      ok: false,
+      error: {
+        kind: 'InvalidShape',
+        file: 'rules',
+        message:
+          'alarm needs positive integer threshold and lockdownTurns, a positive glimpseMargin ' +
+          'and a non-negative rangeBonus',
+      },
+    }
+  }
+
+  if (
+    input.echoes !== undefined &&
+    (!isObject(input.echoes) || !isInteger(input.echoes.max) || input.echoes.max < 1)
+  ) {
+    return {
+      ok: false,
+      error: {
+        kind: 'InvalidShape',
+        file: 'rules',
+        message: 'echoes.max must be a positive integer',
+      },
+    }
+  }
+
+  if (
+    input.rng !== undefined &&
+    (!isObject(input.rng) || typeof input.rng.seed !== 'string' || input.rng.seed.length === 0)
+  ) {
+    return {
+      ok: false,
+      error: {
+        kind: 'InvalidShape',
+        file: 'rules',
+        message: 'rng.seed must be a non-empty string',
+      },
+    }
+  }
+
+  if (
+    isObject(input.interaction) &&
+    input.interaction.allowTimePush !== undefined &&
+    typeof input.interaction.allowTimePush !== 'boolean'
+  ) {
+    return {
+      ok: false,
+      error: {
+        kind: 'InvalidShape',
+        file: 'rules',
+        message: 'interaction.allowTimePush must be a boolean',
+      },
+    }
+  }
+
+  const darkVisionFactor = isObject(input.detection) ? input.detection.darkVisionFactor : undefined
+
+  if (
+    darkVisionFactor !== undefined &&
+    (!isFiniteNumber(darkVisionFactor) || darkVisionFactor < 0 || darkVisionFactor > 1)
+  ) {
+    return {
+      ok: false,
+      error: {
+        kind: 'InvalidShape',
+        file: 'rules',
+        message: 'detection.darkVisionFactor must be a number between 0 and 1',
+      },
+    }
+  }
+
+  const objectMemory = isObject(input.detection) ? input.detection.objectMemory : undefined
+
+  if (objectMemory !== undefined && typeof objectMemory !== 'boolean') {
+    return {
+      ok: false,
+      error: {
+        kind: 'InvalidShape',
+        file: 'rules',
+        message: 'detection.objectMemory must be a boolean',
+      },
+    }
+  }
+
+  const breakNoiseRadius = isObject(input.interaction)
+    ? input.interaction.breakNoiseRadius
+    : undefined
+
+  if (
+    breakNoiseRadius !== undefined &&
+    (!isInteger(breakNoiseRadius) || breakNoiseRadius < 0)
+  ) {
+    return {
+      ok: false,
+      error: {
+        kind: 'InvalidShape',
+        file: 'rules',
+        message: 'interaction.breakNoiseRadius must be a non-negative integer',
+      },
+    }
+  }
+
+  const lures = isObject(input.interaction) ? input.interaction.lures : undefined
+
+  if (lures !== undefined && !isValidLureRules(lures)) {
+    return {
+      ok: false,
+      error: {
+        kind: 'InvalidShape',
+        file: 'rules',
+        message: 'interaction.lures fields must be integers (supply >= 0, the rest >= 1)',
+      },
+    }
+  }
+
   return { ok: true, value: input as unknown as GameRulesConfig }
 }
 
@@ -162,6 +352,61 @@ function validateMap(level: LevelConfig): Result<LevelConfig, ContentLoadError>
     }
   }
 
+  for (const flag of ['timeWraps', 'xWraps', 'yWraps'] as const) {
+    const value: unknown = level.map[flag]
+
+    if (value !== undefined && typeof value !== 'boolean') {
+      return {
+        ok: false,
+        error: { kind: 'InvalidShape', file: 'level', message: `map.${flag} must be a boolean` },
+      }
+    }
+  }
+
+  const characters: unknown = level.map.characters
+
+  if (characters === undefined) {
+    return { ok: true, value: level }
+  }
+
+  if (!Array.isArray(characters)) {
+    return {
+      ok: false,
+      error: { kind: 'InvalidShape', file: 'level', message: 'map.characters must be an array' },
+    }
+  }
+
+  const ids = new Set([PLAYER_CHARACTER_ID])
+
+  for (const character of characters) {
+    if (!isObject(character) || typeof character.id !== 'string' || ids.has(character.id)) {
+      return {
+        ok: false,
+        error: {
+          kind: 'InvalidShape',
+          file: 'level',
+          message: `map.characters ids must be unique strings other than "${PLAYER_CHARACTER_ID}"`,
+        },
+      }
+    }
+
+    ids.add(character.id)
+    const characterStart = character.start
+
+    if (
+      !isObject(characterStart) ||
+      !isInteger(characterStart.x) ||
+      !isInteger(characterStart.y) ||
+      !isInteger(characterStart.t) ||
+      !isPositionInLevel(level, { x: characterStart.x, y: characterStart.y, t: characterStart.t })
+    ) {
+      return {
+        ok: false,
+        error: { kind: 'InvalidStartPosition', start: characterStart as unknown as Position3D },
+      }
+    }
+  }
+
   return { ok: true, value: level }
 }
 
@@ -185,6 +430,21 @@ function isPosition2DInLevel(level: LevelConfig, position: Position2D): boolean
   )
 }
 
+/** Dwell lists must match the path one-to-one and hold each waypoint for at least one slice. */
+function patrolDwellIssue(path: Position2D[], dwell: unknown): string | null {
+  if (dwell === undefined) {
+    return null
+  }
+
+  if (!Array.isArray(dwell) || dwell.length !== path.length) {
+    return 'patrol dwell must list one duration per waypoint'
+  }
+
+  return dwell.every((duration) => isInteger(duration) && duration >= 1)
+    ? null
+    : 'patrol dwell durations must be integers >= 1'
+}
+
 function validateBehaviorPolicyPoints(
   level: LevelConfig,
   behavior: BehaviorConfig,
@@ -207,6 +467,29 @@ function validateBehaviorPolicyPoints(
             }
           }
         }
+
+        const dwellIssue = patrolDwellIssue(policy.path, policy.dwell)
+
+        if (dwellIssue) {
+          return {
+            ok: false,
+            error: { kind: 'InvalidShape', file: 'behavior', message: `${key}: ${dwellIssue}` },
+          }
+        }
+
+        if (
+          policy.hesitation !== undefined &&
+          (typeof policy.hesitation !== 'number' || policy.hesitation < 0 || policy.hesitation > 1)
+        ) {
+          return {
+            ok: false,
+            error: {
+              kind: 'InvalidShape',
+              file: 'behavior',
+              message: `${key}: patrol hesitation must be a number from 0 to 1`,
+            },
+          }
+        }
         break
       }
       case 'ScriptedTimeline': {
@@ -269,14 +552,16 @@ function validateDetectionProfiles(
       !Number.isInteger(profile.delayTurns) ||
       profile.delayTurns < 1 ||
       typeof profile.maxDistance !== 'number' ||
-      profile.maxDistance < 0
+      profile.maxDistance < 0 ||
+      (profile.precog !== undefined && typeof profile.precog !== 'boolean')
     ) {
       return {
         ok: false,
         error: {
           kind: 'InvalidDetectionProfile',
           key,
-          message: 'expected { enabled:boolean, delayTurns:int>=1, maxDistance:number>=0 }',
+          message:
+            'expected { enabled:boolean, delayTurns:int>=1, maxDistance:number>=0, precog?:boolean }',
         },
       }
     }
@@ -331,7 +616,15 @@ function validateArchetypeAndInstanceRefs(
   behavior: BehaviorConfig,
 ): Result<null, ContentLoadError> {
   const instanceIds = new Set(level.instances.map((instance) => instance.id))
+  const linkTargets = resolveRiftLinkTargets(level)
+
+  if (!linkTargets.ok) {
+    return linkTargets
+  }
+
   const riftBySource = new Map<string, string>()
+  // Landing cells of bidirectional rifts lead back to exactly one source.
+  const reverseRiftByLanding = new Map<string, string>()
 
   for (const instance of level.instances) {
     if (!level.archetypes[instance.archetype]) {
@@ -356,12 +649,55 @@ function validateArchetypeAndInstanceRefs(
       }
     }
 
+    const footprint = level.archetypes[instance.archetype].components.find(
+      (component): component is Extract<ContentComponent, { kind: 'Footprint' }> =>
+        component.kind === 'Footprint',
+    )
+
+    if (
+      footprint &&
+      !coveredCells(footprint, instance.position).every((cell) => isPosition2DInLevel(level, cell))
+    ) {
+      return {
+        ok: false,
+        error: {
+          kind: 'InvalidShape',
+          file: 'level',
+          message: `Instance ${instance.id} footprint leaves the map`,
+        },
+      }
+    }
+
+    if (
+      instance.group !== undefined &&
+      (typeof instance.group !== 'string' || instance.group.length === 0)
+    ) {
+      return {
+        ok: false,
+        error: {
+          kind: 'InvalidShape',
+          file: 'level',
+          message: `Instance ${instance.id} group must be a non-empty string`,
+        },
+      }
+    }
+
+    const appearance = appearanceIssue(instance.glyph, instance.color)
+
+    if (appearance) {
+      return {
+        ok: false,
+        error: {
+          kind: 'InvalidShape',
+          file: 'level',
+          message: `Instance ${instance.id}: ${appearance}`,
+        },
+      }
+    }
+
     const archetype = level.archetypes[instance.archetype]
     const riftComponents = archetype.components.filter(
-      (
-        component,
-      ): component is { kind: 'Rift'; target: Position3D; bidirectional: boolean } =>
-        component.kind === 'Rift',
+      (component): component is ContentRiftComponent => component.kind === 'Rift',
     )
 
     if (riftComponents.length === 0) {
@@ -371,18 +707,20 @@ function validateArchetypeAndInstanceRefs(
     const sourceKey = `${instance.position.x},${instance.position.y},${instance.position.t}`
 
     for (const component of riftComponents) {
-      if (!isPositionInLevel(level, component.target)) {
+      const target = contentRiftTarget(component, instance, linkTargets.value)
+
+      if (!target || !isPositionInLevel(level, target)) {
         return {
           ok: false,
           error: {
             kind: 'InvalidRiftTarget',
             archetype: instance.archetype,
-            target: component.target,
+            target: target ?? instance.position,
           },
         }
       }
 
-      const targetSignature = `${component.target.x},${component.target.y},${component.target.t},${component.bidirectional ? 'b' : 'u'}`
+      const targetSignature = `${target.x},${target.y},${target.t},${component.bidirectional ? 'b' : 'u'}`
       const existing = riftBySource.get(sourceKey)
 
       if (existing && existing !== targetSignature) {
@@ -397,6 +735,26 @@ function validateArchetypeAndInstanceRefs(
       }
 
       riftBySource.set(sourceKey, targetSignature)
+
+      if (!component.bidirectional) {
+        continue
+      }
+
+      const landingKey = `${target.x},${target.y},${target.t}`
+      const existingReverse = reverseRiftByLanding.get(landingKey)
+
+      if (existingReverse && existingReverse !== sourceKey) {
+        return {
+          ok: false,
+          error: {
+            kind: 'ConflictingRiftSource',
+            source: target,
+            archetype: instance.archetype,
+          },
+        }
+      }
+
+      reverseRiftByLanding.set(landingKey, sourceKey)
     }
   }
 
@@ -451,78 +809,911 @@ function validateArchetypeAndInstanceRefs(
   return { ok: true, value: null }
 }
 
-function validateArchetypeRenderSymbols(level: LevelConfig): Result<null, ContentLoadError> {
-  for (const [key, archetype] of Object.entries(level.archetypes)) {
-    const symbol = archetype.render.symbol
+function hasContentComponent(components: ContentComponent[], kind: ContentComponent['kind']) {
+  return components.some((component) => component.kind === kind)
+}
 
-    if (symbol !== undefined && (typeof symbol !== 'string' || symbol.length === 0)) {
-      return {
-        ok: false,
-        error: {
-          kind: 'InvalidShape',
-          file: 'level',
-          message: `Archetype ${key} render.symbol must be a non-empty string`,
-        },
+/**
+ * Cells blocked in every slice, keyed `x,y`, with the id of the blocking instance: persistent
+ * movement blockers that nothing moves. Boxes, patrollers and scripted objects are left out.
+ */
+export function staticBlockerIdsByCell(
+  level: LevelConfig,
+  behavior: BehaviorConfig,
+): Map<string, string> {
+  const blockers = new Map<string, string>()
+
+  for (const instance of level.instances) {
+    const components = level.archetypes[instance.archetype]?.components ?? []
+    const policyKey = behavior.assignments[instance.id]
+    const stationary = !policyKey || behavior.policies[policyKey]?.kind === 'Static'
+
+    if (
+      stationary &&
+      hasContentComponent(components, 'BlocksMovement') &&
+      hasContentComponent(components, 'TimePersistent') &&
+      !hasContentComponent(components, 'Pushable') &&
+      !hasContentComponent(components, 'Pullable') &&
+      !hasContentComponent(components, 'Patrol')
+    ) {
+      const footprint = components.find(
+        (component): component is Extract<ContentComponent, { kind: 'Footprint' }> =>
+          component.kind === 'Footprint',
+      )
+
+      for (const cell of coveredCells(footprint ?? null, instance.position)) {
+        blockers.set(`${cell.x},${cell.y}`, instance.id)
       }
     }
   }
 
-  return { ok: true, value: null }
+  return blockers
 }
 
-export function validateContentPack(input: {
-  level: unknown
-  behavior: unknown
-  theme: unknown
-  rules: unknown
-}): Result<ContentPack, ContentLoadError> {
-  const level = parseLevelConfig(input.level)
-
-  if (!level.ok) {
-    return level
+/** Torus and time-loop axes of the level, as the cube built from it will wrap them. */
+function levelWrapping(level: LevelConfig): Wrapping {
+  return {
+    width: level.map.xWraps ? level.map.width : null,
+    height: level.map.yWraps ? level.map.height : null,
+    timeDepth: level.map.timeWraps ? level.map.timeDepth : null,
   }
+}
 
-  const behavior = parseBehaviorConfig(input.behavior)
+function patrolRouteError(
+  key: string,
+  route: PatrolRoute,
+  blockers: Map<string, string>,
+  wrapping: Wrapping,
+): ContentLoadError | null {
+  for (const [index, point] of route.path.entries()) {
+    const blockerId = blockers.get(`${point.x},${point.y}`)
 
-  if (!behavior.ok) {
-    return behavior
+    if (blockerId) {
+      return { kind: 'BlockedPatrolWaypoint', key, index, point, blockerId }
+    }
   }
 
-  const theme = parseThemeConfig(input.theme)
+  const gap = patrolRouteGaps(route, wrapping)[0]
 
-  if (!theme.ok) {
-    return theme
-  }
+  return gap ? { kind: 'DisjointPatrolStep', key, ...gap } : null
+}
 
-  const rules = parseRulesConfig(input.rules)
+/**
+ * Guards step one waypoint per slice, so a route may not jump between cells or pass through
+ * walls. Policies with `expand` get their gaps filled first; the returned behavior carries the
+ * expanded paths so the runtime never sees the shorthand.
+ */
+function validatePatrolRoutes(
+  level: LevelConfig,
+  behavior: BehaviorConfig,
+): Result<BehaviorConfig, ContentLoadError> {
+  const blockers = staticBlockerIdsByCell(level, behavior)
+  const wrapping = levelWrapping(level)
+  const walkable = (cell: Position2D) =>
+    isPosition2DInLevel(level, cell) && !blockers.has(`${cell.x},${cell.y}`)
 
-  if (!rules.ok) {
-    return rules
-  }
+  for (const [key, archetype] of Object.entries(level.archetypes)) {
+    for (const component of archetype.components) {
+      const error =
+        component.kind === 'Patrol' ? patrolRouteError(key, component, blockers, wrapping) : null
+
+      if (error) {
+        return { ok: false, error }
+      }
+    }
+  }
+
+  const policies: BehaviorConfig['policies'] = {}
+
+  for (const [key, policy] of Object.entries(behavior.policies)) {
+    if (policy.kind !== 'PatrolLoop' && policy.kind !== 'PatrolPingPong') {
+      policies[key] = policy
+      continue
+    }
+
+    if (policy.expand !== undefined && typeof policy.expand !== 'boolean') {
+      return {
+        ok: false,
+        error: { kind: 'InvalidShape', file: 'behavior', message: `${key}: expand must be boolean` },
+      }
+    }
+
+    const route: PatrolRoute = {
+      path: policy.path,
+      loops: policy.kind === 'PatrolLoop',
+      dwell: policy.dwell,
+    }
+    const authoredError = patrolRouteError(key, route, blockers, wrapping)
+
+    // Gaps are what `expand` is for; a waypoint inside a wall is an error either way.
+    if (authoredError && !(policy.expand && authoredError.kind === 'DisjointPatrolStep')) {
+      return { ok: false, error: authoredError }
+    }
+
+    if (!policy.expand) {
+      policies[key] = policy
+      continue
+    }
+
+    const expanded = expandPatrolRoute(route, walkable, wrapping)
+
+    if (!expanded.ok) {
+      return { ok: false, error: { kind: 'DisjointPatrolStep', key, ...expanded.error } }
+    }
+
+    // Expanded paths have no gaps left, so validating the pack again leaves them unchanged.
+    policies[key] = policy.dwell
+      ? { ...policy, path: expanded.value.path, dwell: expanded.value.dwell }
+      : { ...policy, path: expanded.value.path }
+  }
+
+  return { ok: true, value: { ...behavior, policies } }
+}
+
+const DIFFICULTY_TIERS = new Set(['easy', 'normal', 'hard', 'expert'])
+
+function validateLevelMeta(level: LevelConfig): Result<null, ContentLoadError> {
+  const meta: unknown = level.meta
+  const invalid = (message: string): Result<null, ContentLoadError> => ({
+    ok: false,
+    error: { kind: 'InvalidShape', file: 'level', message },
+  })
+
+  if (!isObject(meta) || typeof meta.id !== 'string' || typeof meta.name !== 'string') {
+    return invalid('meta must include id and name strings')
+  }
+
+  for (const field of ['author', 'description'] as const) {
+    if (meta[field] !== undefined && (typeof meta[field] !== 'string' || meta[field] === '')) {
+      return invalid(`meta.${field} must be a non-empty string when provided`)
+    }
+  }
+
+  if (
+    meta.difficulty !== undefined &&
+    (typeof meta.difficulty !== 'string' || !DIFFICULTY_TIERS.has(meta.difficulty))
+  ) {
+    return invalid('meta.difficulty must be easy|normal|hard|expert')
+  }
+
+  if (
+    meta.tags !== undefined &&
+    (!Array.isArray(meta.tags) ||
+      !meta.tags.every((tag) => typeof tag === 'string' && tag.length > 0))
+  ) {
+    return invalid('meta.tags must be an array of non-empty strings')
+  }
+
+  return { ok: true, value: null }
+}
+
+const TUTORIAL_DIRECTIONS = new Set(['north', 'south', 'east', 'west'])
+const TUTORIAL_ACTIONS = new Set<string>(ACTION_KINDS)
+
+function regionIssue(region: unknown, level: LevelConfig, seen: Set<string>): string | null {
+  if (!isObject(region) || typeof region.id !== 'string' || region.id.length === 0) {
+    return 'regions need a non-empty string id'
+  }
+
+  if (seen.has(region.id)) {
+    return `region ${region.id} is declared twice`
+  }
+
+  if (typeof region.name !== 'string' || region.name.length === 0) {
+    return `region ${region.id} needs a non-empty name`
+  }
+
+  const { x, y, width, height } = region
+
+  if (!isInteger(x) || !isInteger(y) || !isInteger(width) || !isInteger(height)) {
+    return `region ${region.id} needs integer x, y, width and height`
+  }
+
+  if (
+    width < 1 ||
+    height < 1 ||
+    !isPosition2DInLevel(level, { x, y }) ||
+    !isPosition2DInLevel(level, { x: x + width - 1, y: y + height - 1 })
+  ) {
+    return `region ${region.id} must be a non-empty rectangle inside the map`
+  }
+
+  return null
+}
+
+function validateRegions(level: LevelConfig): Result<null, ContentLoadError> {
+  const regions: unknown = level.regions
+
+  if (regions === undefined) {
+    return { ok: true, value: null }
+  }
+
+  if (!Array.isArray(regions)) {
+    return {
+      ok: false,
+      error: { kind: 'InvalidShape', file: 'level', message: 'regions must be an array' },
+    }
+  }
+
+  const seen = new Set<string>()
+
+  for (const region of regions) {
+    const issue = regionIssue(region, level, seen)
+
+    if (issue) {
+      return { ok: false, error: { kind: 'InvalidShape', file: 'level', message: issue } }
+    }
+
+    seen.add((region as { id: string }).id)
+  }
+
+  return { ok: true, value: null }
+}
+
+/**
+ * Every `DependsOn` source must be another instance present in slice `at` when the level starts,
+ * and following sources from any instance must never lead back to it.
+ */
+function validateDependencies(level: LevelConfig): Result<null, ContentLoadError> {
+  const instancesById = new Map(level.instances.map((instance) => [instance.id, instance]))
+  const sourceOf = (instance: ContentInstance) =>
+    level.archetypes[instance.archetype]?.components.find(
+      (component): component is Extract<ContentComponent, { kind: 'DependsOn' }> =>
+        component.kind === 'DependsOn',
+    ) ?? null
+
+  for (const instance of level.instances) {
+    const dependency = sourceOf(instance)
+
+    if (!dependency) {
+      continue
+    }
+
+    const invalid = (message: string): Result<null, ContentLoadError> => ({
+      ok: false,
+      error: {
+        kind: 'InvalidDependency',
+        instanceId: instance.id,
+        source: dependency.source,
+        message,
+      },
+    })
+    const source = instancesById.get(dependency.source)
+
+    if (!source || source.id === instance.id) {
+      return invalid('source must be another instance')
+    }
+
+    const sourceComponents = level.archetypes[source.archetype]?.components ?? []
+    const persistent = hasContentComponent(sourceComponents, 'TimePersistent')
+    const presentAtStart = persistent
+      ? source.position.t <= dependency.at
+      : source.position.t === dependency.at
+
+    if (!presentAtStart) {
+      return invalid(`source is not in slice ${dependency.at} when the level starts`)
+    }
+
+    const seen = new Set([instance.id])
+    let next: ContentInstance | null = source
+
+    while (next) {
+      if (seen.has(next.id)) {
+        return invalid('dependencies form a cycle')
+      }
+
+      seen.add(next.id)
+      const nextSource = sourceOf(next)?.source
+      next = nextSource !== undefined ? (instancesById.get(nextSource) ?? null) : null
+    }
+  }
+
+  return { ok: true, value: null }
+}
+
+function validateTutorialSteps(level: LevelConfig): Result<null, ContentLoadError> {
+  const steps: unknown = level.meta.tutorial
+
+  if (steps === undefined) {
+    return { ok: true, value: null }
+  }
+
+  if (!Array.isArray(steps)) {
+    return {
+      ok: false,
+      error: { kind: 'InvalidTutorialStep', index: -1, message: 'meta.tutorial must be an array' },
+    }
+  }
+
+  for (let index = 0; index < steps.length; index += 1) {
+    const step: unknown = steps[index]
+
+    if (!isObject(step) || typeof step.prompt !== 'string' || step.prompt.length === 0) {
+      return {
+        ok: false,
+        error: { kind: 'InvalidTutorialStep', index, message: 'prompt must be a non-empty string' },
+      }
+    }
+
+    const gate = step.gate
+
+    if (!isObject(gate)) {
+      return {
+        ok: false,
+        error: { kind: 'InvalidTutorialStep', index, message: 'gate must be an object' },
+      }
+    }
+
+    if (gate.kind === 'Action') {
+      if (typeof gate.action !== 'string' || !TUTORIAL_ACTIONS.has(gate.action)) {
+        return {
+          ok: false,
+          error: {
+            kind: 'InvalidTutorialStep',
+            index,
+            message: `unknown action ${String(gate.action)}`,
+          },
+        }
+      }
+
+      if (
+        gate.direction !== undefined &&
+        (typeof gate.direction !== 'string' || !TUTORIAL_DIRECTIONS.has(gate.direction))
+      ) {
+        return {
+          ok: false,
+          error: { kind: 'InvalidTutorialStep', index, message: 'invalid direction' },
+        }
+      }
+
+      continue
+    }
+
+    if (gate.kind === 'EnterRegion') {
+      if (!level.regions?.some((region) => region.id === gate.region)) {
+        return {
+          ok: false,
+          error: {
+            kind: 'InvalidTutorialStep',
+            index,
+            message: `unknown region ${String(gate.region)}`,
+          },
+        }
+      }
+
+      continue
+    }
+
+    if (gate.kind === 'ReachPosition') {
+      const position = gate.position
+
+      if (
+        !isObject(position) ||
+        !isInteger(position.x) ||
+        !isInteger(position.y) ||
+        !isPosition2DInLevel(level, { x: position.x, y: position.y })
+      ) {
+        return {
+          ok: false,
+          error: { kind: 'InvalidTutorialStep', index, message: 'position must be inside the map' },
+        }
+      }
+
+      continue
+    }
+
+    return {
+      ok: false,
+      error: { kind: 'InvalidTutorialStep', index, message: `unknown gate kind ${String(gate.kind)}` },
+    }
+  }
+
+  return { ok: true, value: null }
+}
+
+function validateArchetypeRenderSymbols(level: LevelConfig): Result<null, ContentLoadError> {
+  for (const [key, archetype] of Object.entries(level.archetypes)) {
+    const symbol = archetype.render.symbol
+
+    if (symbol !== undefined && (typeof symbol !== 'string' || symbol.length === 0)) {
+      return {
+        ok: false,
+        error: {
+          kind: 'InvalidShape',
+          file: 'level',
+          message: `Archetype ${key} render.symbol must be a non-empty string`,
+        },
+      }
+    }
+  }
+
+  return { ok: true, value: null }
+}
+
+const GUARD_FACINGS: readonly string[] = ['north', 'south', 'east', 'west']
+
+function isFacingList(facings: unknown): boolean {
+  return (
+    Array.isArray(facings) &&
+    facings.length > 0 &&
+    facings.every((facing) => GUARD_FACINGS.includes(facing))
+  )
+}
+
+/** Shape problem with a Guard component, or null when it is well-formed. */
+function guardComponentIssue(
+  guard: Extract<ContentComponent, { kind: 'Guard' }>,
+  hasPatrol: boolean,
+): string | null {
+  switch (guard.guard) {
+    case 'Sentry':
+      if (!isFacingList(guard.facings)) {
+        return 'Sentry needs a non-empty facings list of north|south|east|west'
+      }
+
+      return hasPatrol ? 'Sentry is stationary and cannot patrol' : null
+    case 'Patroller':
+      return null
+    case 'Chaser':
+      return isInteger(guard.alertDistance) && guard.alertDistance >= 0
+        ? null
+        : 'Chaser alertDistance must be an integer >= 0'
+    default:
+      return `unknown guard type ${String((guard as { guard: unknown }).guard)}`
+  }
+}
+
+function cameraComponentIssue(
+  camera: Extract<ContentComponent, { kind: 'Camera' }>,
+  kinds: Set<ContentComponent['kind']>,
+): string | null {
+  if (!isFacingList(camera.facings)) {
+    return 'Camera needs a non-empty facings list of north|south|east|west'
+  }
+
+  if (!isInteger(camera.turnEvery) || camera.turnEvery < 1) {
+    return 'Camera turnEvery must be an integer >= 1'
+  }
+
+  if (
+    camera.vision !== undefined &&
+    (!isObject(camera.vision) ||
+      !isInteger(camera.vision.delayTurns) ||
+      camera.vision.delayTurns < 1 ||
+      !isFiniteNumber(camera.vision.maxDistance) ||
+      camera.vision.maxDistance < 0)
+  ) {
+    return 'Camera vision expects { delayTurns:int>=1, maxDistance:number>=0 }'
+  }
 
-  const mapValidation = validateMap(level.value)
+  return kinds.has('Patrol') || kinds.has('Guard') ? 'Camera cannot patrol or act as a guard' : null
+}
+
+function searchlightComponentIssue(
+  light: Extract<ContentComponent, { kind: 'Searchlight' }>,
+  level: LevelConfig,
+): string | null {
+  if (light.shape !== 'circle' && light.shape !== 'cone') {
+    return 'Searchlight shape must be circle or cone'
+  }
+
+  if (!isInteger(light.radius) || light.radius < 0) {
+    return 'Searchlight radius must be an integer >= 0'
+  }
+
+  if (!Array.isArray(light.path) || typeof light.loops !== 'boolean') {
+    return 'Searchlight expects path:Position2D[] and loops:boolean'
+  }
+
+  if (!light.path.every((point) => isPosition2DInLevel(level, point))) {
+    return 'Searchlight path leaves the map'
+  }
+
+  return patrolDwellIssue(light.path, light.dwell)
+}
+
+function heightComponentIssue(
+  height: Extract<ContentComponent, { kind: 'Height' }>,
+  kinds: Set<ContentComponent['kind']>,
+): string | null {
+  if (!isInteger(height.height) || height.height < 1) {
+    return 'Height must be an integer >= 1'
+  }
+
+  return height.height < EYE_HEIGHT && kinds.has('BlocksVision')
+    ? `BlocksVision needs Height >= ${EYE_HEIGHT}; lower objects are seen over`
+    : null
+}
+
+function lightSourceComponentIssue(
+  light: Extract<ContentComponent, { kind: 'LightSource' }>,
+): string | null {
+  if (!isInteger(light.radius) || light.radius < 0) {
+    return 'LightSource radius must be an integer >= 0'
+  }
+
+  const from = light.activeFrom ?? 0
+
+  if (!isInteger(from) || from < 0) {
+    return 'LightSource activeFrom must be an integer >= 0'
+  }
+
+  const until = light.activeUntil
+
+  return until === undefined || (isInteger(until) && until > from)
+    ? null
+    : 'LightSource activeUntil must be an integer after activeFrom'
+}
+
+/** Multi-tile objects sit still or are pushed whole; nothing that walks, turns or stacks. */
+function footprintComponentIssue(
+  footprint: Extract<ContentComponent, { kind: 'Footprint' }>,
+  kinds: Set<ContentComponent['kind']>,
+): string | null {
+  if (
+    !isInteger(footprint.width) ||
+    !isInteger(footprint.height) ||
+    footprint.width < 1 ||
+    footprint.height < 1
+  ) {
+    return 'Footprint width and height must be integers >= 1'
+  }
+
+  const clash = (
+    ['Patrol', 'Guard', 'Camera', 'Searchlight', 'Pullable', 'Stackable', 'Rift'] as const
+  ).find((kind) => kinds.has(kind))
+
+  return clash ? `Footprint cannot be combined with ${clash}` : null
+}
+
+const APPEARANCE_COLOR = /^#([0-9a-f]{3}|[0-9a-f]{6})$/i
+
+/** Shared by the Appearance component and the per-instance glyph/color fields. */
+function appearanceIssue(glyph: unknown, color: unknown): string | null {
+  if (glyph !== undefined && (typeof glyph !== 'string' || [...glyph].length !== 1)) {
+    return 'Appearance glyph must be a single character'
+  }
+
+  if (color !== undefined && (typeof color !== 'string' || !APPEARANCE_COLOR.test(color))) {
+    return 'Appearance color must be #rgb or #rrggbb'
+  }
+
+  return null
+}
+
+function interactableComponentIssue(
+  component: Extract<ContentComponent, { kind: 'Interactable' }>,
+  level: LevelConfig,
+): string | null {
+  if (component.interaction === 'Terminal') {
+    return null
+  }
+
+  if (component.interaction !== 'Lever') {
+    return 'Interactable interaction must be Terminal or Lever'
+  }
+
+  if (component.effect !== 'reverse' && component.effect !== 'pause') {
+    return 'Lever effect must be reverse or pause'
+  }
+
+  if (!Array.isArray(component.targets) || component.targets.length === 0) {
+    return 'Lever targets must be a non-empty list of instance ids'
+  }
+
+  const missing = component.targets.find(
+    (target) => !level.instances.some((instance) => instance.id === target),
+  )
+
+  return missing === undefined ? null : `Lever target ${missing} is not an instance`
+}
+
+function componentIssue(
+  component: ContentComponent,
+  kinds: Set<ContentComponent['kind']>,
+  level: LevelConfig,
+): string | null {
+  switch (component.kind) {
+    case 'Guard':
+      return guardComponentIssue(component, kinds.has('Patrol'))
+    case 'Camera':
+      return cameraComponentIssue(component, kinds)
+    case 'Patrol':
+      return patrolDwellIssue(component.path, component.dwell)
+    case 'Searchlight':
+      return searchlightComponentIssue(component, level)
+    case 'Height':
+      return heightComponentIssue(component, kinds)
+    case 'LightSource':
+      return lightSourceComponentIssue(component)
+    case 'Catwalk':
+      return component.railing === 'open' || component.railing === 'closed'
+        ? null
+        : 'Catwalk railing must be open or closed'
+    case 'Breakable':
+      return isInteger(component.hits) && component.hits >= 1
+        ? null
+        : 'Breakable hits must be an integer >= 1'
+    case 'DependsOn':
+      if (typeof component.source !== 'string' || component.source.length === 0) {
+        return 'DependsOn source must be a non-empty instance id'
+      }
+
+      return isInteger(component.at) && component.at >= 0 && component.at < level.map.timeDepth
+        ? null
+        : 'DependsOn at must be a slice inside the time depth'
+    case 'Appearance':
+      return appearanceIssue(component.glyph, component.color)
+    case 'Footprint':
+      return footprintComponentIssue(component, kinds)
+    case 'Extraction':
+      if (!kinds.has('Exit')) {
+        return 'Extraction requires Exit'
+      }
+
+      return isInteger(component.turns) && component.turns >= 1
+        ? null
+        : 'Extraction turns must be an integer >= 1'
+    case 'Interactable':
+      return interactableComponentIssue(component, level)
+    default:
+      return null
+  }
+}
+
+function validateArchetypeComponents(level: LevelConfig): Result<null, ContentLoadError> {
+  for (const [key, archetype] of Object.entries(level.archetypes)) {
+    const kinds = new Set(archetype.components.map((component) => component.kind))
+
+    for (const component of archetype.components) {
+      const issue = componentIssue(component, kinds, level)
+
+      if (issue) {
+        return {
+          ok: false,
+          error: { kind: 'InvalidArchetypeComponents', archetype: key, message: issue },
+        }
+      }
+    }
+
+    if (kinds.has('Heavy') && !kinds.has('Pushable')) {
+      return {
+        ok: false,
+        error: {
+          kind: 'InvalidArchetypeComponents',
+          archetype: key,
+          message: 'Heavy requires Pushable',
+        },
+      }
+    }
+
+    if (kinds.has('Ladder') && !kinds.has('Catwalk')) {
+      return {
+        ok: false,
+        error: {
+          kind: 'InvalidArchetypeComponents',
+          archetype: key,
+          message: 'Ladder requires Catwalk',
+        },
+      }
+    }
+
+    if (kinds.has('Stackable') && !kinds.has('Pushable')) {
+      return {
+        ok: false,
+        error: {
+          kind: 'InvalidArchetypeComponents',
+          archetype: key,
+          message: 'Stackable requires Pushable',
+        },
+      }
+    }
+
+    const height = archetype.components.find(
+      (component): component is Extract<ContentComponent, { kind: 'Height' }> =>
+        component.kind === 'Height',
+    )
+
+    if (kinds.has('Stackable') && height && height.height >= EYE_HEIGHT) {
+      return {
+        ok: false,
+        error: {
+          kind: 'InvalidArchetypeComponents',
+          archetype: key,
+          message: `Stackable needs Height < ${EYE_HEIGHT}; only a stack reaches eye height`,
+        },
+      }
+    }
+
+    if (kinds.has('Water') && (!kinds.has('BlocksMovement') || kinds.has('Pushable'))) {
+      return {
+        ok: false,
+        error: {
+          kind: 'InvalidArchetypeComponents',
+          archetype: key,
+          message: 'Water must block movement and cannot be pushable',
+        },
+      }
+    }
+
+    if (kinds.has('Gate') && kinds.has('BlocksMovement')) {
+      return {
+        ok: false,
+        error: {
+          kind: 'InvalidArchetypeComponents',
+          archetype: key,
+          message: 'Gate is open outside a lockdown and cannot block movement',
+        },
+      }
+    }
+
+    if (kinds.has('Breakable') && !kinds.has('BlocksMovement')) {
+      return {
+        ok: false,
+        error: {
+          kind: 'InvalidArchetypeComponents',
+          archetype: key,
+          message: 'Breakable requires BlocksMovement',
+        },
+      }
+    }
+
+    if (kinds.has('Anchored') && kinds.has('DependsOn')) {
+      return {
+        ok: false,
+        error: {
+          kind: 'InvalidArchetypeComponents',
+          archetype: key,
+          message: 'Anchored objects cannot depend on a source the past can remove',
+        },
+      }
+    }
+
+    if (archetype.components.filter((component) => component.kind === 'DependsOn').length > 1) {
+      return {
+        ok: false,
+        error: {
+          kind: 'InvalidArchetypeComponents',
+          archetype: key,
+          message: 'an archetype may depend on at most one source',
+        },
+      }
+    }
+
+    if (kinds.has('Catwalk') && kinds.has('BlocksMovement')) {
+      return {
+        ok: false,
+        error: {
+          kind: 'InvalidArchetypeComponents',
+          archetype: key,
+          message: 'Catwalk cannot block movement on the ground below it',
+        },
+      }
+    }
+
+    if (kinds.has('Slippery') && kinds.has('BlocksMovement')) {
+      return {
+        ok: false,
+        error: {
+          kind: 'InvalidArchetypeComponents',
+          archetype: key,
+          message: 'Slippery floor cannot block movement',
+        },
+      }
+    }
+
+    if (kinds.has('Shadow') && kinds.has('BlocksMovement')) {
+      return {
+        ok: false,
+        error: {
+          kind: 'InvalidArchetypeComponents',
+          archetype: key,
+          message: 'Shadow floor cannot block movement',
+        },
+      }
+    }
+  }
+
+  return { ok: true, value: null }
+}
+
+export function validateContentPack(input: {
+  level: unknown
+  behavior: unknown
+  theme: unknown
+  rules: unknown
+}): Result<ContentPack, ContentLoadError> {
+  const parsedLevel = parseLevelConfig(input.level)
+
+  if (!parsedLevel.ok) {
+    return parsedLevel
+  }
+
+  const parsedBehavior = parseBehaviorConfig(input.behavior)
+
+  if (!parsedBehavior.ok) {
+    return parsedBehavior
+  }
+
+  const templated = resolveLevelTemplates(parsedLevel.value, parsedBehavior.value)
+
+  if (!templated.ok) {
+    return templated
+  }
+
+  const { level, behavior } = templated.value
+
+  const theme = parseThemeConfig(input.theme)
+
+  if (!theme.ok) {
+    return theme
+  }
+
+  const rules = parseRulesConfig(input.rules)
+
+  if (!rules.ok) {
+    return rules
+  }
+
+  const metaValidation = validateLevelMeta(level)
+
+  if (!metaValidation.ok) {
+    return metaValidation
+  }
+
+  const mapValidation = validateMap(level)
 
   if (!mapValidation.ok) {
     return mapValidation
   }
 
-  const refValidation = validateArchetypeAndInstanceRefs(level.value, behavior.value)
+  const componentValidation = validateArchetypeComponents(level)
+
+  if (!componentValidation.ok) {
+    return componentValidation
+  }
+
+  const refValidation = validateArchetypeAndInstanceRefs(level, behavior)
 
   if (!refValidation.ok) {
     return refValidation
   }
 
-  const symbolValidation = validateArchetypeRenderSymbols(level.value)
+  const dependencyValidation = validateDependencies(level)
+
+  if (!dependencyValidation.ok) {
+    return dependencyValidation
+  }
+
+  const symbolValidation = validateArchetypeRenderSymbols(level)
 
   if (!symbolValidation.ok) {
     return symbolValidation
   }
 
+  const regionValidation = validateRegions(level)
+
+  if (!regionValidation.ok) {
+    return regionValidation
+  }
+
+  const tutorialValidation = validateTutorialSteps(level)
+
+  if (!tutorialValidation.ok) {
+    return tutorialValidation
+  }
+
+  const patrolValidation = validatePatrolRoutes(level, behavior)
+
+  if (!patrolValidation.ok) {
+    return patrolValidation
+  }
+
   return {
     ok: true,
     value: {
-      level: level.value,
-      behavior: behavior.value,
+      level: level,
+      behavior: patrolValidation.value,
       theme: theme.value,
       rules: rules.value,
     },
@@ -0,0 +1,42 @@
+import { describe, expect, it } from 'vitest'
+
+import { asTurn } from '../core/coords'
+import { objectPositionAt } from '../core/timeCube'
+import { frameAtTurn, gameFrames } from './frames'
+import { gameReducer, waitTurn } from './gameSlice'
+
+describe('gameFrames', () => {
+  it('yields one frame per turn with the slice its self stands in', () => {
+    const start = gameReducer(undefined, { type: 'init' })
+    const state = gameReducer(gameReducer(start, waitTurn()), waitTurn())
+    const frames = [...gameFrames(state)]
+
+    expect(frames.map((frame) => [frame.turn, frame.time])).toEqual([
+      [0, 0],
+      [1, 1],
+      [2, 2],
+    ])
+    expect(frames[0].player).toEqual({ x: 5, y: 5, t: 0 })
+    expect(frames[0].objects).toHaveLength(5)
+
+    const guard = frames[2].objects.find((object) => object.id === 'enemy.alpha')
+
+    expect(guard?.position).toEqual(objectPositionAt(state.cube, 'enemy.alpha', 2))
+  })
+
+  it('lists the other selves sharing a slice and stops at the end of the line', () => {
+    const state = gameReducer(undefined, { type: 'init' })
+    const looped = {
+      ...state,
+      worldLine: {
+        ...state.worldLine,
+        path: [...state.worldLine.path, { x: 6, y: 5, t: 1 }, { x: 6, y: 6, t: 0 }],
+      },
+    }
+
+    expect(frameAtTurn(looped, asTurn(2))?.otherSelves).toEqual([
+      { position: { x: 5, y: 5, t: 0 }, turn: 0 },
+    ])
+    expect(frameAtTurn(looped, asTurn(3))).toBeNull()
+  })
+})
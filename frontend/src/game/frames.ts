@@ -0,0 +1,79 @@
+import { asTimeCoord, asTurn, type TimeCoord, type Turn } from '../core/coords'
+import type { ResolvedObjectInstance } from '../core/objects'
+import type { Position2D, Position3D } from '../core/position'
+import { litCellsAt, searchlightOf } from '../core/searchlight'
+import { objectsAtTime } from '../core/timeCube'
+import { positionsAtTime, type PositionAtTime } from '../core/worldLine'
+import type { InteractionState } from './interactions/types'
+
+export type FrameInput = Pick<InteractionState, 'cube' | 'worldLine' | 'boardWidth' | 'boardHeight'>
+
+/**
+ * What one turn of the world line looks like: the slice its self stands in, read from the cube
+ * as the pipeline left it, so patrols, detours, lures and chases are already resolved there.
+ */
+export interface GameFrame {
+  turn: Turn
+  time: TimeCoord
+  player: Position3D
+  /** The line's other selves in the same slice, oldest first. */
+  otherSelves: PositionAtTime[]
+  objects: ResolvedObjectInstance[]
+  /** Cells searchlights sweep in the slice, each cell once. */
+  litCells: Position2D[]
+}
+
+function litCellsInSlice(input: FrameInput, objects: ResolvedObjectInstance[]): Position2D[] {
+  const cells = new Map<string, Position2D>()
+
+  for (const object of objects) {
+    const light = searchlightOf(object.archetype.components)
+
+    if (!light) {
+      continue
+    }
+
+    const { position } = object
+    const lit = litCellsAt(light, position, position.t, input.boardWidth, input.boardHeight)
+
+    for (const cell of lit) {
+      cells.set(`${cell.x},${cell.y}`, cell)
+    }
+  }
+
+  return [...cells.values()]
+}
+
+/** The frame of path index `turn`; null past the end of the world line. */
+export function frameAtTurn(input: FrameInput, turn: Turn): GameFrame | null {
+  const player = input.worldLine.path[turn]
+
+  if (!player) {
+    return null
+  }
+
+  const objects = objectsAtTime(input.cube, player.t)
+
+  return {
+    turn,
+    time: asTimeCoord(player.t),
+    player,
+    otherSelves: positionsAtTime(input.worldLine, player.t).filter((self) => self.turn !== turn),
+    objects,
+    litCells: litCellsInSlice(input, objects),
+  }
+}
+
+/**
+ * One frame per turn of the world line, from the start, for replaying a finished run as text
+ * (`hwu --frames`). The board views still read the current slice from the cube directly.
+ */
+export function* gameFrames(input: FrameInput): Generator<GameFrame> {
+  for (let turn = 0; turn < input.worldLine.path.length; turn += 1) {
+    const frame = frameAtTurn(input, asTurn(turn))
+
+    if (frame) {
+      yield frame
+    }
+  }
+}
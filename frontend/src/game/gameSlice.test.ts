@@ -1,22 +1,46 @@
 import { describe, expect, it } from 'vitest'
 
 import { loadDefaultBootContent } from '../data/loader'
-import { objectsAt } from '../core/timeCube'
+import { applyRuleProfile, ruleProfileById } from '../data/ruleProfiles'
+import { raiseLockdown } from '../core/alarm'
+import type { Component } from '../core/components'
+import { hasLineOfSight } from '../core/detection'
+import type { ResolvedObjectInstance } from '../core/objects'
+import type { Direction2D, Position3D } from '../core/position'
+import { createRng, nextFloat } from '../core/rng'
+import { createMemoryTraceSink, setTraceSink } from '../core/trace'
+import { isBlocked, objectPositionAt, objectsAt, placeObjects } from '../core/timeCube'
+import { createWorldLine } from '../core/worldLine'
 import {
   applyLoadedContent,
   applyRift,
+  breakWall,
+  climb,
   configureDetectionConfig,
   configureParadoxConfig,
   configureRiftSettings,
+  debugSpawn,
+  debugTeleport,
   gameReducer,
+  interact,
   movePlayer2D,
+  moveTo,
+  peek,
+  performInteraction,
   pullPlayer2D,
   pushPlayer2D,
+  requestHint,
   restart,
   setContentPackId,
+  setHotseat,
   setInteractionConfig,
+  switchCharacter,
+  throwLure,
+  timePushPlayer2D,
   waitTurn,
 } from './gameSlice'
+import { simulateInteractionPipeline } from './interactions/pipeline'
+import { changedCells, diffCubeObjects } from './stateDiff'
 
 describe('gameSlice', () => {
   it('increments turn and time on normal movement', () => {
@@ -63,6 +87,408 @@ describe('gameSlice', () => {
     )
   })
 
+  it('walks off the west edge onto the east edge of a torus map', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const atEdge = { ...initial, worldLine: createWorldLine({ x: 0, y: 1, t: 0 }) }
+    const torus = { ...atEdge, cube: { ...atEdge.cube, xWraps: true } }
+
+    expect(gameReducer(atEdge, movePlayer2D('west')).turn).toBe(0)
+    expect(gameReducer(torus, movePlayer2D('west')).worldLine.path.at(-1)).toEqual({
+      x: 11,
+      y: 1,
+      t: 1,
+    })
+  })
+
+  it('climbs onto a catwalk and only walks along it until climbing down', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const catwalkAt = (x: number, ladder: boolean) => ({
+      id: `catwalk.${x}`,
+      archetypeKey: 'catwalk',
+      position: { x, y: 2, t: 0 },
+      archetype: {
+        kind: 'catwalk',
+        components: [
+          { kind: 'TimePersistent' as const },
+          { kind: 'Catwalk' as const, railing: 'closed' as const },
+          ...(ladder ? [{ kind: 'Ladder' as const }] : []),
+        ],
+        render: {},
+      },
+    })
+    const placed = placeObjects(initial.cube, [catwalkAt(1, true), catwalkAt(2, false)])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const atLadder = {
+      ...initial,
+      cube: placed.value,
+      worldLine: createWorldLine({ x: 1, y: 2, t: 0 }),
+    }
+    const up = gameReducer(atLadder, climb())
+    const along = gameReducer(up, movePlayer2D('east'))
+    const off = gameReducer(along, movePlayer2D('south'))
+
+    expect(up.history.at(-1)?.outcome).toEqual({
+      kind: 'Climbed',
+      to: { x: 1, y: 2, t: 1 },
+      layer: 'catwalk',
+    })
+    expect(along.worldLine.path.at(-1)).toEqual({ x: 2, y: 2, t: 2 })
+    expect(off.turn).toBe(along.turn)
+    expect(off.status).toBe('No catwalk there')
+    expect(gameReducer(along, climb()).status).toBe('No ladder here')
+  })
+
+  it('breaks a wall after its hits and lets nearby guards hear every blow', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const placed = placeObjects(initial.cube, [
+      {
+        id: 'crate.west',
+        archetypeKey: 'crate',
+        position: { x: 4, y: 5, t: 0 },
+        archetype: {
+          kind: 'crate',
+          components: [
+            { kind: 'BlocksMovement' },
+            { kind: 'TimePersistent' },
+            { kind: 'Breakable', hits: 2 },
+          ],
+          render: {},
+        },
+      },
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const start = { ...initial, cube: placed.value }
+
+    expect(gameReducer(start, breakWall('south')).status).toBe('Nothing to break there')
+
+    const first = gameReducer(start, breakWall('west'))
+    const second = gameReducer(first, breakWall('west'))
+
+    expect(first.status).toBe('Turn 1: hit crate.west (1/2) at t=1')
+    expect(isBlocked(first.cube, { x: 4, y: 5, t: 2 })).toBe(true)
+    expect(second.phase).toBe('Playing')
+    expect(second.history.at(-1)?.events).toEqual([
+      { kind: 'NoiseMade', at: { x: 4, y: 5, t: 2 } },
+      { kind: 'ObjectBroken', objectId: 'crate.west' },
+    ])
+    expect(isBlocked(second.cube, { x: 4, y: 5, t: 1 })).toBe(true)
+    expect(isBlocked(second.cube, { x: 4, y: 5, t: 2 })).toBe(false)
+    expect(second.noises).toHaveLength(2)
+    // The patrol would be at (3, 9) by t=2; it heads for the noise instead.
+    expect(objectsAt(second.cube, { x: 3, y: 9, t: 2 }).map((obj) => obj.id)).not.toContain(
+      'enemy.alpha',
+    )
+    expect(gameReducer(second, movePlayer2D('west')).worldLine.path.at(-1)).toEqual({
+      x: 4,
+      y: 5,
+      t: 3,
+    })
+  })
+
+  it('stacks a pushed box onto another and pulls the top one back off', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const box = (id: string, x: number) => ({
+      id,
+      archetypeKey: 'crate',
+      position: { x, y: 5, t: 0 },
+      archetype: {
+        kind: 'crate',
+        components: [
+          { kind: 'BlocksMovement' as const },
+          { kind: 'Pushable' as const },
+          { kind: 'Pullable' as const },
+          { kind: 'TimePersistent' as const },
+          { kind: 'Stackable' as const },
+        ],
+        render: {},
+      },
+    })
+    const placed = placeObjects(initial.cube, [box('crate.top', 4), box('crate.base', 3)])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const stacked = gameReducer({ ...initial, cube: placed.value }, pushPlayer2D('west'))
+    const sight = { cube: stacked.cube, from: { x: 2, y: 5 }, to: { x: 4, y: 5 } }
+
+    expect(stacked.status).toBe('Turn 1: stacked crate.top on crate.base at (3, 5, t=1)')
+    expect(objectsAt(stacked.cube, { x: 3, y: 5, t: 1 }).map((obj) => obj.id)).toEqual([
+      'crate.base',
+      'crate.top',
+    ])
+    expect(hasLineOfSight({ ...sight, atTime: 0 })).toBe(true)
+    expect(hasLineOfSight({ ...sight, atTime: 1 })).toBe(false)
+    expect(gameReducer(stacked, pushPlayer2D('west')).status).toBe('Stacked boxes cannot be pushed')
+
+    const unstacked = gameReducer(stacked, pullPlayer2D('east'))
+
+    expect(objectsAt(unstacked.cube, { x: 4, y: 5, t: 2 }).map((obj) => obj.id)).toEqual([
+      'crate.top',
+    ])
+    expect(objectsAt(unstacked.cube, { x: 3, y: 5, t: 2 }).map((obj) => obj.id)).toEqual([
+      'crate.base',
+    ])
+  })
+
+  it('sinks a box pushed into water and walks across the bridge it makes', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const placed = placeObjects(initial.cube, [
+      {
+        id: 'crate.float',
+        archetypeKey: 'crate',
+        position: { x: 4, y: 5, t: 0 },
+        archetype: {
+          kind: 'crate',
+          components: [
+            { kind: 'BlocksMovement' },
+            { kind: 'Pushable' },
+            { kind: 'TimePersistent' },
+          ],
+          render: {},
+        },
+      },
+      {
+        id: 'water.1',
+        archetypeKey: 'water',
+        position: { x: 3, y: 5, t: 0 },
+        archetype: {
+          kind: 'water',
+          components: [{ kind: 'BlocksMovement' }, { kind: 'TimePersistent' }, { kind: 'Water' }],
+          render: {},
+        },
+      },
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const sunk = gameReducer({ ...initial, cube: placed.value }, pushPlayer2D('west'))
+
+    expect(sunk.status).toBe(
+      'Turn 1: pushed 1 object(s); lead box sank into the water at (3, 5, t=1)',
+    )
+    expect(sunk.history.at(-1)?.events).toEqual([
+      { kind: 'ObjectsMoved', objectIds: ['crate.float'] },
+      { kind: 'WaterBridged', at: { x: 3, y: 5, t: 1 } },
+    ])
+    expect(isBlocked(sunk.cube, { x: 3, y: 5, t: 0 })).toBe(true)
+    expect(isBlocked(sunk.cube, { x: 3, y: 5, t: 4 })).toBe(false)
+    expect(objectsAt(sunk.cube, { x: 3, y: 5, t: 4 })).toEqual([])
+    expect(gameReducer(sunk, movePlayer2D('west')).worldLine.path.at(-1)).toEqual({
+      x: 3,
+      y: 5,
+      t: 2,
+    })
+  })
+
+  it('pushes a multi-tile crate as one block until its leading edge is blocked', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const placed = placeObjects(initial.cube, [
+      {
+        id: 'crate.big',
+        archetypeKey: 'crate',
+        position: { x: 6, y: 4, t: 0 },
+        archetype: {
+          kind: 'crate',
+          components: [
+            { kind: 'BlocksMovement' },
+            { kind: 'Pushable' },
+            { kind: 'TimePersistent' },
+            { kind: 'Footprint', width: 2, height: 2 },
+          ],
+          render: {},
+        },
+      },
+      {
+        id: 'wall.stop',
+        archetypeKey: 'wall',
+        position: { x: 9, y: 4, t: 0 },
+        archetype: {
+          kind: 'wall',
+          components: [{ kind: 'BlocksMovement' }, { kind: 'TimePersistent' }],
+          render: {},
+        },
+      },
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const pushed = gameReducer({ ...initial, cube: placed.value }, pushPlayer2D('east'))
+
+    expect(pushed.status).toBe('Turn 1: pushed crate.big to (7, 4, t=1)')
+    expect(objectsAt(pushed.cube, { x: 8, y: 5, t: 1 }).map((obj) => obj.position)).toEqual([
+      { x: 7, y: 4, t: 1 },
+    ])
+    expect(isBlocked(pushed.cube, { x: 6, y: 4, t: 0 })).toBe(true)
+    expect(isBlocked(pushed.cube, { x: 6, y: 4, t: 1 })).toBe(false)
+    expect(isBlocked(pushed.cube, { x: 8, y: 4, t: 3 })).toBe(true)
+
+    const refused = gameReducer(pushed, pushPlayer2D('east'))
+
+    expect(refused.status).toBe('No space to push')
+    expect(refused.worldLine).toEqual(pushed.worldLine)
+  })
+
+  it('pushes a group as one block and breaks its members together', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const linked = (
+      id: string,
+      x: number,
+      y: number,
+      group: string,
+      extra: Component[],
+    ): ResolvedObjectInstance => ({
+      id,
+      archetypeKey: 'crate',
+      position: { x, y, t: 0 },
+      archetype: {
+        kind: 'crate',
+        components: [
+          { kind: 'BlocksMovement' },
+          { kind: 'TimePersistent' },
+          { kind: 'Group', id: group },
+          ...extra,
+        ],
+        render: {},
+      },
+    })
+    const placed = placeObjects(initial.cube, [
+      linked('crate.a', 6, 5, 'pair', [{ kind: 'Pushable' }]),
+      linked('crate.b', 6, 7, 'pair', [{ kind: 'Pushable' }]),
+      linked('urn.a', 4, 5, 'urns', [{ kind: 'Breakable', hits: 1 }]),
+      linked('urn.b', 2, 2, 'urns', [{ kind: 'Breakable', hits: 1 }]),
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const start = { ...initial, cube: placed.value }
+    const pushed = gameReducer(start, pushPlayer2D('east'))
+
+    expect(pushed.status).toBe('Turn 1: pushed group pair (2 object(s)) east')
+    expect(isBlocked(pushed.cube, { x: 7, y: 7, t: 1 })).toBe(true)
+    expect(isBlocked(pushed.cube, { x: 6, y: 7, t: 1 })).toBe(false)
+
+    const broken = gameReducer(start, breakWall('west'))
+
+    expect(broken.status).toBe('Turn 1: broke urn.a and 1 linked object(s) at t=1')
+    expect(broken.history.at(-1)?.events).toEqual([
+      { kind: 'NoiseMade', at: { x: 4, y: 5, t: 1 } },
+      { kind: 'ObjectBroken', objectId: 'urn.a' },
+      { kind: 'ObjectBroken', objectId: 'urn.b' },
+    ])
+    expect(isBlocked(broken.cube, { x: 2, y: 2, t: 0 })).toBe(true)
+    expect(isBlocked(broken.cube, { x: 2, y: 2, t: 1 })).toBe(false)
+  })
+
+  it('walks the patrol around a box pushed into its path', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const placed = placeObjects(initial.cube, [
+      {
+        id: 'crate.path',
+        archetypeKey: 'crate',
+        position: { x: 4, y: 8, t: 0 },
+        archetype: {
+          kind: 'crate',
+          components: [
+            { kind: 'BlocksMovement' },
+            { kind: 'Pushable' },
+            { kind: 'TimePersistent' },
+          ],
+          render: {},
+        },
+      },
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const unseen = gameReducer(
+      { ...initial, cube: placed.value },
+      configureDetectionConfig({ enabled: false }),
+    )
+    const s1 = gameReducer(unseen, movePlayer2D('south'))
+    const s2 = gameReducer(s1, movePlayer2D('south'))
+    const s3 = gameReducer(s2, movePlayer2D('south'))
+    const pushed = gameReducer(s3, pushPlayer2D('west'))
+
+    expect(pushed.status).toBe('Turn 4: pushed 1 object(s) to (4, 8, t=4)')
+    expect(objectsAt(pushed.cube, { x: 3, y: 8, t: 5 }).map((obj) => obj.id)).toEqual([
+      'crate.path',
+    ])
+    // Scheduled onto (3, 8) at t=5, the guard cuts through (2, 9) and is back on its loop at t=6.
+    expect(objectsAt(pushed.cube, { x: 2, y: 9, t: 5 }).map((obj) => obj.id)).toContain(
+      'enemy.alpha',
+    )
+    expect(objectsAt(pushed.cube, { x: 3, y: 9, t: 6 }).map((obj) => obj.id)).toContain(
+      'enemy.alpha',
+    )
+    expect(objectsAt(pushed.cube, { x: 2, y: 8, t: 8 }).map((obj) => obj.id)).toContain(
+      'enemy.alpha',
+    )
+  })
+
+  it('unmakes dependents once their cause is broken in the slice they rely on', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const fixture = (
+      id: string,
+      x: number,
+      y: number,
+      components: Component[],
+    ): ResolvedObjectInstance => ({
+      id,
+      archetypeKey: id,
+      position: { x, y, t: 0 },
+      archetype: {
+        kind: id,
+        components: [{ kind: 'BlocksMovement' }, { kind: 'TimePersistent' }, ...components],
+        render: {},
+      },
+    })
+    const placed = placeObjects(initial.cube, [
+      fixture('generator', 4, 5, [{ kind: 'Breakable', hits: 1 }]),
+      fixture('gate', 7, 2, [{ kind: 'DependsOn', source: 'generator', at: 1 }]),
+      fixture('lamp', 8, 2, [{ kind: 'DependsOn', source: 'generator', at: 0 }]),
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const broken = gameReducer({ ...initial, cube: placed.value }, breakWall('west'))
+
+    expect(broken.history.at(-1)?.events).toContainEqual({
+      kind: 'ObjectUnmade',
+      objectId: 'gate',
+      from: 2,
+    })
+    expect(isBlocked(broken.cube, { x: 7, y: 2, t: 1 })).toBe(true)
+    expect(isBlocked(broken.cube, { x: 7, y: 2, t: 2 })).toBe(false)
+    expect(isBlocked(broken.cube, { x: 8, y: 2, t: 5 })).toBe(true)
+  })
+
   it('rifts to past time and increments turn', () => {
     const initial = gameReducer(undefined, { type: 'init' })
     const moved = gameReducer(initial, movePlayer2D('east'))
@@ -80,6 +506,73 @@ describe('gameSlice', () => {
     expect(rifted.worldLine.path.at(-1)).toEqual({ x: 7, y: 5, t: 0 })
   })
 
+  it('keeps the pre-rift cube so the timeline diff can show what a past push changed', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const unseen = gameReducer(initial, configureDetectionConfig({ enabled: false }))
+    const s1 = gameReducer(unseen, movePlayer2D('east'))
+    const s2 = gameReducer(s1, movePlayer2D('east'))
+
+    expect(s2.timelineBaseline).toBeNull()
+
+    const rifted = gameReducer(s2, applyRift({ kind: 'tunnel', target: { x: 7, y: 5, t: 0 } }))
+
+    expect(rifted.timelineBaseline).toBe(s2.cube)
+
+    const s4 = gameReducer(rifted, movePlayer2D('south'))
+    const pushed = gameReducer(s4, pushPlayer2D('east'))
+
+    expect(pushed.timelineBaseline).toBe(s2.cube)
+
+    const changes = changedCells(diffCubeObjects(s2.cube, pushed.cube))
+
+    expect(changes.some((position) => position.t < 2)).toBe(false)
+    expect(changes).toContainEqual({ x: 8, y: 6, t: 2 })
+    expect(changes).toContainEqual({ x: 9, y: 6, t: 2 })
+    expect(gameReducer(pushed, restart()).timelineBaseline).toBeNull()
+  })
+
+  it('keeps anchored boxes out of time pushes and pushes made from the past', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const box = initial.cube.objectsById['box.main']
+    const anchored = {
+      ...initial,
+      cube: {
+        ...initial.cube,
+        objectsById: {
+          ...initial.cube.objectsById,
+          'box.main': {
+            ...box,
+            archetype: {
+              ...box.archetype,
+              components: [...box.archetype.components, { kind: 'Anchored' as const }],
+            },
+          },
+        },
+      },
+    }
+    const unseen = gameReducer(anchored, configureDetectionConfig({ enabled: false }))
+    const s1 = gameReducer(unseen, movePlayer2D('east'))
+    const s2 = gameReducer(s1, movePlayer2D('east'))
+    const s3 = gameReducer(s2, movePlayer2D('south'))
+    const enabled = gameReducer(s3, setInteractionConfig({ allowTimePush: true }))
+
+    expect(gameReducer(enabled, timePushPlayer2D('east')).status).toBe(
+      'box.main is anchored in time',
+    )
+    expect(gameReducer(s3, pushPlayer2D('east')).worldLine.path.at(-1)).toEqual({
+      x: 8,
+      y: 6,
+      t: 4,
+    })
+
+    const rifted = gameReducer(s2, applyRift({ kind: 'tunnel', target: { x: 7, y: 5, t: 0 } }))
+    const s4 = gameReducer(rifted, movePlayer2D('south'))
+    const refused = gameReducer(s4, pushPlayer2D('east'))
+
+    expect(refused.turn).toBe(s4.turn)
+    expect(refused.status).toBe('box.main is anchored in time')
+  })
+
   it('blocks self-intersection when rifting to an occupied (x,y,t)', () => {
     const initial = gameReducer(undefined, { type: 'init' })
 
@@ -108,6 +601,46 @@ describe('gameSlice', () => {
     expect(defaultRift.worldLine.path.at(-1)).toEqual({ x: 8, y: 5, t: 1 })
   })
 
+  it('keeps the default jump on one-way rift tiles and takes two-way ones back', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const configured = gameReducer(initial, configureRiftSettings({ defaultDelta: 2 }))
+    const m1 = gameReducer(configured, movePlayer2D('east'))
+    const m2 = gameReducer(m1, movePlayer2D('east'))
+    const m3 = gameReducer(m2, movePlayer2D('east'))
+    const riftAt = (id: string, x: number, y: number, target: Position3D, twoWay: boolean) => {
+      const placed = placeObjects(m3.cube, [
+        {
+          id,
+          archetypeKey: 'rift',
+          position: { x, y, t: 0 },
+          archetype: {
+            kind: 'rift',
+            components: [{ kind: 'TimePersistent' }, { kind: 'Rift', target, bidirectional: twoWay }],
+            render: {},
+          },
+        },
+      ])
+
+      if (!placed.ok) {
+        throw new Error('placement failed')
+      }
+
+      return { ...m3, cube: placed.value }
+    }
+
+    const oneWay = gameReducer(
+      riftAt('rift.oneWay', 8, 5, { x: 1, y: 1, t: 0 }, false),
+      applyRift(undefined),
+    )
+    const twoWay = gameReducer(
+      riftAt('rift.twoWay', 2, 2, { x: 8, y: 5, t: 3 }, true),
+      applyRift(undefined),
+    )
+
+    expect(oneWay.worldLine.path.at(-1)).toEqual({ x: 8, y: 5, t: 1 })
+    expect(twoWay.worldLine.path.at(-1)).toEqual({ x: 2, y: 2, t: 0 })
+  })
+
   it('sets phase to Won when entering exit tile', () => {
     const initial = gameReducer(undefined, { type: 'init' })
 
@@ -118,6 +651,266 @@ describe('gameSlice', () => {
     expect(won.status).toContain('reached exit')
   })
 
+  it('wins on an extraction exit only after holding it for its turns', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const placed = placeObjects(initial.cube, [
+      {
+        id: 'exit.roof',
+        archetypeKey: 'extraction',
+        position: { x: 6, y: 5, t: 0 },
+        archetype: {
+          kind: 'exit',
+          components: [
+            { kind: 'Exit' },
+            { kind: 'TimePersistent' },
+            { kind: 'Extraction', turns: 2 },
+          ],
+          render: {},
+        },
+      },
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const arrived = gameReducer({ ...initial, cube: placed.value }, movePlayer2D('east'))
+    const held = gameReducer(arrived, waitTurn())
+
+    expect(arrived.status).toBe('Turn 1: holding the exit (0/2)')
+    expect(held.status).toBe('Turn 2: holding the exit (1/2)')
+    expect(held.phase).toBe('Playing')
+
+    const left = gameReducer(held, movePlayer2D('west'))
+    const back = gameReducer(left, movePlayer2D('east'))
+
+    expect(left.extractionTurns).toBe(0)
+    expect(back.status).toBe('Turn 4: holding the exit (0/2)')
+
+    const won = gameReducer(held, waitTurn())
+
+    expect(won.phase).toBe('Won')
+    expect(won.status).toBe('Turn 3: reached exit at (6, 5, t=3)')
+    expect(gameReducer(won, restart()).extractionTurns).toBe(0)
+  })
+
+  it('sounds the alarm after enough glimpses and shuts gates for the lockdown', () => {
+    const initial = gameReducer(
+      undefined,
+      configureDetectionConfig({ enabled: true, delayTurns: 1, maxDistance: 2 }),
+    )
+    const placed = placeObjects(initial.cube, [
+      {
+        id: 'gate.west',
+        archetypeKey: 'gate',
+        position: { x: 4, y: 5, t: 0 },
+        archetype: {
+          kind: 'gate',
+          components: [{ kind: 'Gate' }, { kind: 'TimePersistent' }],
+          render: {},
+        },
+      },
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const start = {
+      ...initial,
+      cube: placed.value,
+      alarmConfig: { threshold: 2, lockdownTurns: 3, glimpseMargin: 4, rangeBonus: 2 },
+    }
+    const glimpsed = gameReducer(start, waitTurn())
+    const raised = gameReducer(glimpsed, waitTurn())
+
+    expect(glimpsed.status).toContain('glimpsed by enemy.alpha (1/2)')
+    expect(raised.phase).toBe('Playing')
+    expect(raised.status).toContain('alarm raised by enemy.alpha; lockdown until t=5')
+    expect(raised.alarm.suspicion).toBe(0)
+    expect(raised.history.at(-1)?.events).toContainEqual({
+      kind: 'AlarmRaised',
+      lockdown: { from: 2, to: 5, rangeBonus: 2 },
+    })
+    expect(gameReducer(start, movePlayer2D('west')).worldLine.path.at(-1)).toEqual({
+      x: 4,
+      y: 5,
+      t: 1,
+    })
+    expect(gameReducer(raised, movePlayer2D('west')).status).toBe('Blocked by object')
+  })
+
+  it('uses a terminal next to the player to lift the lockdown from that slice on', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const placed = placeObjects(initial.cube, [
+      {
+        id: 'terminal.east',
+        archetypeKey: 'terminal',
+        position: { x: 6, y: 5, t: 0 },
+        archetype: {
+          kind: 'terminal',
+          components: [
+            { kind: 'Interactable', interaction: 'Terminal' },
+            { kind: 'BlocksMovement' },
+            { kind: 'TimePersistent' },
+          ],
+          render: {},
+        },
+      },
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const config = { threshold: 2, lockdownTurns: 4, glimpseMargin: 2, rangeBonus: 2 }
+    const start = {
+      ...initial,
+      cube: raiseLockdown(placed.value, 0, config),
+      alarm: { suspicion: 1 },
+    }
+
+    expect(gameReducer(start, interact('north')).status).toBe('Nothing to use there')
+
+    const used = gameReducer(start, interact('east'))
+
+    expect(used.turn).toBe(1)
+    expect(used.status).toBe('Turn 1: used terminal.east; lockdown lifted from t=1')
+    expect(used.cube.lockdowns).toEqual([{ from: 0, to: 1, rangeBonus: 2 }])
+    expect(used.alarm.suspicion).toBe(0)
+    expect(used.history.at(-1)?.events).toEqual([
+      { kind: 'Interacted', objectId: 'terminal.east', interaction: 'Terminal' },
+      { kind: 'LockdownLifted', at: 1 },
+    ])
+    expect(gameReducer(used, interact('east')).status).toBe(
+      'Turn 2: used terminal.east; no alarm to reset',
+    )
+  })
+
+  it('pulls a lever that turns a linked patrol back from that slice on', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const placed = placeObjects(initial.cube, [
+      {
+        id: 'lever.east',
+        archetypeKey: 'lever',
+        position: { x: 6, y: 5, t: 0 },
+        archetype: {
+          kind: 'lever',
+          components: [
+            {
+              kind: 'Interactable',
+              interaction: 'Lever',
+              effect: 'reverse',
+              targets: ['enemy.alpha'],
+            },
+            { kind: 'BlocksMovement' },
+            { kind: 'TimePersistent' },
+          ],
+          render: {},
+        },
+      },
+    ])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const start = { ...initial, cube: placed.value }
+    const pulled = gameReducer(start, interact('east'))
+
+    expect(pulled.status).toBe('Turn 1: used lever.east; enemy.alpha turns back')
+    expect(pulled.history.at(-1)?.events).toContainEqual({
+      kind: 'PatrolSwitched',
+      enemyId: 'enemy.alpha',
+      change: { at: 1, kind: 'reverse' },
+    })
+    expect(objectPositionAt(start.cube, 'enemy.alpha', 1)).toEqual({ x: 3, y: 8, t: 1 })
+    expect(objectPositionAt(pulled.cube, 'enemy.alpha', 0)).toEqual({ x: 2, y: 8, t: 0 })
+    expect(objectPositionAt(pulled.cube, 'enemy.alpha', 1)).toEqual({ x: 2, y: 9, t: 1 })
+    expect(objectPositionAt(pulled.cube, 'enemy.alpha', 2)).toEqual({ x: 3, y: 9, t: 2 })
+  })
+
+  it('knocks out an unaware guard, leaving a body that pushes like a box', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const sentry = (
+      id: string,
+      x: number,
+      y: number,
+      facing: Direction2D,
+    ): ResolvedObjectInstance => ({
+      id,
+      archetypeKey: 'guard',
+      position: { x, y, t: 0 },
+      archetype: {
+        kind: 'guard',
+        components: [
+          { kind: 'Guard', guard: 'Sentry', facings: [facing] },
+          { kind: 'BlocksMovement' },
+          { kind: 'TimePersistent' },
+        ],
+        render: {},
+      },
+    })
+    const withGuards = (...guards: ResolvedObjectInstance[]) => {
+      const placed = placeObjects(initial.cube, guards)
+
+      if (!placed.ok) {
+        throw new Error('placement failed')
+      }
+
+      return {
+        ...initial,
+        cube: placed.value,
+        knockoutConfig: { alertTurns: 3, rangeBonus: 2 },
+      }
+    }
+
+    const start = withGuards(sentry('guard.east', 6, 5, 'east'))
+
+    expect(gameReducer({ ...start, knockoutConfig: null }, interact('east')).status).toBe(
+      'Nothing to use there',
+    )
+    expect(
+      gameReducer(withGuards(sentry('guard.east', 6, 5, 'west')), interact('east')).status,
+    ).toBe('guard.east sees you coming')
+
+    const knocked = gameReducer(start, interact('east'))
+
+    expect(knocked.status).toBe('Turn 1: knocked out guard.east')
+    expect(knocked.history.at(-1)?.events).toEqual([
+      { kind: 'KnockedOut', enemyId: 'guard.east', bodyId: 'guard.east.body' },
+    ])
+    expect(objectPositionAt(knocked.cube, 'guard.east', 0)).toEqual({ x: 6, y: 5, t: 0 })
+    expect(objectPositionAt(knocked.cube, 'guard.east', 1)).toBeNull()
+    expect(objectPositionAt(knocked.cube, 'guard.east.body', 0)).toBeNull()
+    expect(objectPositionAt(knocked.cube, 'guard.east.body', 1)).toEqual({ x: 6, y: 5, t: 1 })
+
+    const pushed = gameReducer(knocked, pushPlayer2D('east'))
+
+    expect(pushed.turn).toBe(2)
+    expect(objectPositionAt(pushed.cube, 'guard.east.body', 2)).toEqual({ x: 7, y: 5, t: 2 })
+
+    const watched = withGuards(
+      sentry('guard.east', 6, 5, 'east'),
+      sentry('guard.north', 6, 3, 'south'),
+    )
+    const found = gameReducer(watched, interact('east'))
+
+    expect(found.status).toBe(
+      'Turn 1: knocked out guard.east; guard.north found guard.east.body; lockdown until t=4',
+    )
+    expect(found.history.at(-1)?.events).toEqual([
+      { kind: 'KnockedOut', enemyId: 'guard.east', bodyId: 'guard.east.body' },
+      { kind: 'BodyFound', bodyId: 'guard.east.body', enemyId: 'guard.north' },
+      { kind: 'AlarmRaised', lockdown: { from: 1, to: 4, rangeBonus: 2 } },
+    ])
+    expect(found.foundBodyIds).toEqual(['guard.east.body'])
+  })
+
   it('stops movement after win until restart', () => {
     const initial = gameReducer(undefined, { type: 'init' })
     const won = gameReducer(initial, applyRift({ kind: 'tunnel', target: { x: 10, y: 10, t: 1 } }))
@@ -133,6 +926,137 @@ describe('gameSlice', () => {
     expect(moved.phase).toBe('Playing')
   })
 
+  it('seeds the rng from the level and rewinds it on restart', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const advanced = { ...initial, rng: nextFloat(initial.rng).rng }
+
+    expect(initial.rng).toEqual(createRng('default-level'))
+    expect(gameReducer(advanced, restart()).rng).toEqual(initial.rng)
+  })
+
+  it('spawns an echo of each failed attempt on restart, up to the level limit', () => {
+    const initial = { ...gameReducer(undefined, { type: 'init' }), echoLimit: 1 }
+    const first = gameReducer(gameReducer(initial, movePlayer2D('east')), restart())
+
+    expect(first.echoPaths).toHaveLength(1)
+    expect(first.status).toBe('Restarted; 1 echo retraces past attempts')
+    expect(objectsAt(first.cube, { x: 6, y: 5, t: 1 }).map((obj) => obj.id)).toContain('echo.1')
+
+    const second = gameReducer(gameReducer(first, movePlayer2D('south')), restart())
+
+    expect(second.echoPaths).toHaveLength(1)
+    expect(objectsAt(second.cube, { x: 5, y: 6, t: 1 }).map((obj) => obj.id)).toContain('echo.1')
+    expect(gameReducer(second, restart()).echoPaths).toEqual(second.echoPaths)
+  })
+
+  it('limits restarts to what the rule profile allows', () => {
+    const loaded = loadDefaultBootContent()
+
+    if (!loaded.ok) {
+      throw new Error('default content failed to load')
+    }
+
+    const ironman = ruleProfileById('ironman')
+    const initial = gameReducer(
+      undefined,
+      applyLoadedContent({ packId: 'default', content: applyRuleProfile(loaded.value, ironman) }),
+    )
+    const once = gameReducer(gameReducer(initial, movePlayer2D('east')), restart())
+    const moved = gameReducer(once, movePlayer2D('east'))
+    const blocked = gameReducer(moved, restart())
+
+    expect(initial.restartsRemaining).toBe(1)
+    expect(once.turn).toBe(0)
+    expect(once.restartsRemaining).toBe(0)
+    expect(blocked.turn).toBe(1)
+    expect(blocked.status).toBe('No restarts left under Ironman rules')
+    expect(initial.hintBudget).toBe(0)
+  })
+
+  it('forks a new timeline when a branching run alters the past', () => {
+    const loaded = loadDefaultBootContent()
+
+    if (!loaded.ok) {
+      throw new Error('default content failed to load')
+    }
+
+    const content = applyRuleProfile(loaded.value, ruleProfileById('branching'))
+    const initial = gameReducer(
+      gameReducer(undefined, applyLoadedContent({ packId: 'default', content })),
+      configureDetectionConfig({ enabled: false }),
+    )
+    const s1 = gameReducer(initial, movePlayer2D('east'))
+    const s2 = gameReducer(s1, movePlayer2D('east'))
+    const rifted = gameReducer(s2, applyRift({ kind: 'tunnel', target: { x: 7, y: 5, t: 0 } }))
+    const s4 = gameReducer(rifted, movePlayer2D('south'))
+
+    expect(s4.timelines?.branches).toHaveLength(1)
+
+    const pushed = gameReducer(s4, pushPlayer2D('east'))
+    const original = pushed.timelines?.branches[0].cube
+
+    expect(pushed.timelines?.activeId).toBe('timeline-1')
+    expect(pushed.timelines?.branches[1]).toMatchObject({
+      parentId: 'timeline-0',
+      forkTime: 2,
+      consistent: true,
+    })
+    expect(pushed.history.at(-1)?.events).toContainEqual({
+      kind: 'TimelineForked',
+      timelineId: 'timeline-1',
+      forkTime: 2,
+    })
+    expect(original && objectsAt(original, { x: 8, y: 6, t: 2 }).map((obj) => obj.id)).toContain(
+      'box.main',
+    )
+    expect(objectsAt(pushed.cube, { x: 9, y: 6, t: 2 }).map((obj) => obj.id)).toContain('box.main')
+    expect(gameReducer(pushed, restart()).timelines?.branches).toHaveLength(1)
+  })
+
+  it('taints the timeline on a paradox and only wins at an exit in a consistent one', () => {
+    const loaded = loadDefaultBootContent()
+
+    if (!loaded.ok) {
+      throw new Error('default content failed to load')
+    }
+
+    const content = applyRuleProfile(loaded.value, ruleProfileById('branching'))
+    const initial = gameReducer(
+      gameReducer(undefined, applyLoadedContent({ packId: 'default', content })),
+      configureDetectionConfig({ enabled: false }),
+    )
+    const seeded = {
+      ...initial,
+      causalAnchors: [
+        {
+          id: 'seed-object-anchor',
+          requirement: {
+            kind: 'ObjectAt' as const,
+            objectId: 'box.main',
+            position: { x: 8, y: 6, t: 1 },
+            sourceTurn: 0,
+          },
+        },
+      ],
+    }
+    const staged = gameReducer(
+      seeded,
+      applyRift({ kind: 'tunnel', target: { x: 8, y: 5, t: 0 } }),
+    )
+    const tainted = gameReducer(staged, pullPlayer2D('north'))
+
+    expect(tainted.phase).toBe('Playing')
+    expect(tainted.timelines?.branches[0].consistent).toBe(false)
+
+    const atExit = gameReducer(
+      tainted,
+      applyRift({ kind: 'tunnel', target: { x: 10, y: 10, t: 2 } }),
+    )
+
+    expect(atExit.phase).toBe('Playing')
+    expect(atExit.status).toBe('Turn 3: exits only count in a consistent timeline')
+  })
+
   it('pushes a pullable box and propagates occupancy to future slices', () => {
     const initial = gameReducer(undefined, { type: 'init' })
     const s1 = gameReducer(initial, movePlayer2D('east'))
@@ -145,6 +1069,337 @@ describe('gameSlice', () => {
     expect(pushed.worldLine.path.at(-1)).toEqual({ x: 8, y: 6, t: 4 })
     expect(objectsAt(pushed.cube, { x: 9, y: 6, t: 4 }).map((obj) => obj.id)).toContain('box.main')
     expect(objectsAt(pushed.cube, { x: 9, y: 6, t: 8 }).map((obj) => obj.id)).toContain('box.main')
+    expect(pushed.history.at(-1)?.events).toEqual([
+      { kind: 'ObjectsMoved', objectIds: ['box.main'] },
+    ])
+  })
+
+  it('sends a pushed box through a rift tile to the rift target slice', () => {
+    const loaded = loadDefaultBootContent()
+    expect(loaded.ok).toBe(true)
+    if (!loaded.ok) {
+      return
+    }
+
+    const { levelObjectsConfig } = loaded.value
+    const content = {
+      ...loaded.value,
+      levelObjectsConfig: {
+        archetypes: {
+          ...levelObjectsConfig.archetypes,
+          rift: {
+            kind: 'rift',
+            components: [
+              { kind: 'TimePersistent' as const },
+              { kind: 'Rift' as const, target: { x: 9, y: 1, t: 6 }, bidirectional: false },
+            ],
+            render: {},
+          },
+        },
+        instances: [
+          ...levelObjectsConfig.instances,
+          { id: 'rift.box', archetype: 'rift', position: { x: 9, y: 6, t: 0 } },
+        ],
+      },
+    }
+    const initial = gameReducer(
+      gameReducer(undefined, { type: 'init' }),
+      applyLoadedContent({ packId: 'default', content }),
+    )
+    const s1 = gameReducer(initial, movePlayer2D('east'))
+    const s2 = gameReducer(s1, movePlayer2D('east'))
+    const s3 = gameReducer(s2, movePlayer2D('south'))
+    const pushed = gameReducer(s3, pushPlayer2D('east'))
+
+    expect(pushed.turn).toBe(4)
+    expect(pushed.worldLine.path.at(-1)).toEqual({ x: 8, y: 6, t: 4 })
+    expect(objectsAt(pushed.cube, { x: 8, y: 6, t: 3 }).map((obj) => obj.id)).toContain('box.main')
+    expect(objectsAt(pushed.cube, { x: 9, y: 6, t: 4 }).map((obj) => obj.id)).not.toContain('box.main')
+    expect(objectsAt(pushed.cube, { x: 9, y: 1, t: 5 }).map((obj) => obj.id)).not.toContain('box.main')
+    expect(objectsAt(pushed.cube, { x: 9, y: 1, t: 6 }).map((obj) => obj.id)).toContain('box.main')
+
+    const box = s3.cube.objectsById['box.main']
+    const anchoredBox = {
+      ...box,
+      archetype: {
+        ...box.archetype,
+        components: [...box.archetype.components, { kind: 'Anchored' as const }],
+      },
+    }
+    const objectsById = { ...s3.cube.objectsById, 'box.main': anchoredBox }
+    const refused = gameReducer({ ...s3, cube: { ...s3.cube, objectsById } }, pushPlayer2D('east'))
+
+    expect(refused.turn).toBe(3)
+    expect(refused.status).toBe('box.main is anchored in time')
+  })
+
+  it('time-pushes a box one slice ahead when the level enables it', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const s1 = gameReducer(initial, movePlayer2D('east'))
+    const s2 = gameReducer(s1, movePlayer2D('east'))
+    const s3 = gameReducer(s2, movePlayer2D('south'))
+
+    const disabled = gameReducer(s3, timePushPlayer2D('east'))
+    expect(disabled.turn).toBe(3)
+    expect(disabled.status).toBe('Time push is disabled')
+
+    const enabled = gameReducer(s3, setInteractionConfig({ allowTimePush: true }))
+    const shoved = gameReducer(enabled, timePushPlayer2D('east'))
+
+    expect(shoved.turn).toBe(4)
+    expect(shoved.worldLine.path.at(-1)).toEqual({ x: 8, y: 6, t: 4 })
+    expect(objectsAt(shoved.cube, { x: 8, y: 6, t: 3 }).map((obj) => obj.id)).toContain('box.main')
+    expect(objectsAt(shoved.cube, { x: 8, y: 6, t: 4 }).map((obj) => obj.id)).not.toContain('box.main')
+    expect(objectsAt(shoved.cube, { x: 8, y: 6, t: 5 }).map((obj) => obj.id)).toContain('box.main')
+  })
+
+  it('pushes heavy boxes only with momentum from the previous step', () => {
+    const loaded = loadDefaultBootContent()
+    expect(loaded.ok).toBe(true)
+    if (!loaded.ok) {
+      return
+    }
+
+    const { levelObjectsConfig } = loaded.value
+    const box = levelObjectsConfig.archetypes.box
+    const content = {
+      ...loaded.value,
+      levelObjectsConfig: {
+        ...levelObjectsConfig,
+        archetypes: {
+          ...levelObjectsConfig.archetypes,
+          box: { ...box, components: [...box.components, { kind: 'Heavy' as const }] },
+        },
+      },
+    }
+    const initial = gameReducer(
+      gameReducer(
+        gameReducer(undefined, { type: 'init' }),
+        applyLoadedContent({ packId: 'default', content }),
+      ),
+      configureDetectionConfig({ enabled: false }),
+    )
+
+    const sideways = [
+      movePlayer2D('east'),
+      movePlayer2D('east'),
+      movePlayer2D('south'),
+    ].reduce(gameReducer, initial)
+    const stalled = gameReducer(sideways, pushPlayer2D('east'))
+
+    expect(stalled.turn).toBe(3)
+    expect(stalled.status).toBe('Heavy box needs a running start')
+
+    const runUp = [
+      movePlayer2D('south'),
+      movePlayer2D('south'),
+      movePlayer2D('south'),
+      movePlayer2D('east'),
+      movePlayer2D('east'),
+      movePlayer2D('east'),
+      movePlayer2D('north'),
+    ].reduce(gameReducer, initial)
+    const pushed = gameReducer(runUp, pushPlayer2D('north'))
+
+    expect(pushed.turn).toBe(8)
+    expect(objectsAt(pushed.cube, { x: 8, y: 5, t: 8 }).map((obj) => obj.id)).toContain('box.main')
+  })
+
+  it('slides the player and pushed boxes across slippery floor', () => {
+    const loaded = loadDefaultBootContent()
+    expect(loaded.ok).toBe(true)
+    if (!loaded.ok) {
+      return
+    }
+
+    const { levelObjectsConfig } = loaded.value
+    const content = {
+      ...loaded.value,
+      levelObjectsConfig: {
+        archetypes: {
+          ...levelObjectsConfig.archetypes,
+          ice: {
+            kind: 'ice',
+            components: [{ kind: 'TimePersistent' as const }, { kind: 'Slippery' as const }],
+            render: {},
+          },
+        },
+        instances: [
+          ...levelObjectsConfig.instances,
+          { id: 'ice.west.1', archetype: 'ice', position: { x: 4, y: 5, t: 0 } },
+          { id: 'ice.west.2', archetype: 'ice', position: { x: 3, y: 5, t: 0 } },
+          { id: 'ice.east.1', archetype: 'ice', position: { x: 9, y: 6, t: 0 } },
+          { id: 'ice.east.2', archetype: 'ice', position: { x: 10, y: 6, t: 0 } },
+        ],
+      },
+    }
+    const initial = gameReducer(
+      gameReducer(
+        gameReducer(undefined, { type: 'init' }),
+        applyLoadedContent({ packId: 'default', content }),
+      ),
+      configureDetectionConfig({ enabled: false }),
+    )
+
+    const slid = gameReducer(initial, movePlayer2D('west'))
+
+    expect(slid.turn).toBe(1)
+    expect(slid.currentTime).toBe(3)
+    expect(slid.worldLine.path).toEqual([
+      { x: 5, y: 5, t: 0 },
+      { x: 4, y: 5, t: 1 },
+      { x: 3, y: 5, t: 2 },
+      { x: 2, y: 5, t: 3 },
+    ])
+    expect(slid.history.at(-1)?.anchors.map((anchor) => anchor.id)).toContain('turn-1-slide-1')
+
+    const pushed = [
+      movePlayer2D('east'),
+      movePlayer2D('east'),
+      movePlayer2D('south'),
+      pushPlayer2D('east'),
+    ].reduce(gameReducer, initial)
+
+    expect(pushed.worldLine.path.at(-1)).toEqual({ x: 8, y: 6, t: 4 })
+    expect(objectsAt(pushed.cube, { x: 9, y: 6, t: 4 }).map((obj) => obj.id)).toContain('box.main')
+    expect(objectsAt(pushed.cube, { x: 10, y: 6, t: 5 }).map((obj) => obj.id)).toContain('box.main')
+    expect(objectsAt(pushed.cube, { x: 11, y: 6, t: 6 }).map((obj) => obj.id)).toContain('box.main')
+    expect(objectsAt(pushed.cube, { x: 11, y: 6, t: 9 }).map((obj) => obj.id)).toContain('box.main')
+  })
+
+  it('throws a lure that draws a nearby patrol off its route', () => {
+    const loaded = loadDefaultBootContent()
+    expect(loaded.ok).toBe(true)
+    if (!loaded.ok) {
+      return
+    }
+
+    const content = {
+      ...loaded.value,
+      interactionConfig: {
+        ...loaded.value.interactionConfig,
+        lure: { supply: 1, maxRange: 4, radius: 4, duration: 4 },
+      },
+    }
+    const initial = gameReducer(
+      gameReducer(undefined, { type: 'init' }),
+      applyLoadedContent({ packId: 'default', content }),
+    )
+
+    const thrown = gameReducer(initial, throwLure('west'))
+
+    expect(thrown.turn).toBe(1)
+    expect(thrown.worldLine.path.at(-1)).toEqual({ x: 5, y: 5, t: 1 })
+    expect(thrown.lures).toEqual({
+      remaining: 0,
+      thrown: [{ id: 'lure.0', position: { x: 1, y: 5, t: 1 } }],
+    })
+    expect(objectsAt(thrown.cube, { x: 2, y: 6, t: 2 }).map((obj) => obj.id)).toContain('enemy.alpha')
+    expect(objectsAt(thrown.cube, { x: 1, y: 5, t: 4 }).map((obj) => obj.id)).toContain('enemy.alpha')
+    // Back at its post by t=8, the patrol picks up its route eight slices late.
+    expect(objectsAt(thrown.cube, { x: 2, y: 8, t: 8 }).map((obj) => obj.id)).toContain('enemy.alpha')
+    expect(objectsAt(thrown.cube, { x: 3, y: 8, t: 9 }).map((obj) => obj.id)).toContain('enemy.alpha')
+
+    const empty = gameReducer(thrown, throwLure('west'))
+
+    expect(empty.turn).toBe(1)
+    expect(empty.status).toBe('No lures left')
+  })
+
+  it('switches between characters and wins only once all of them reach an exit', () => {
+    const loaded = loadDefaultBootContent()
+    expect(loaded.ok).toBe(true)
+    if (!loaded.ok) {
+      return
+    }
+
+    const content = {
+      ...loaded.value,
+      characterStarts: [{ id: 'scout', start: { x: 6, y: 5, t: 0 } }],
+    }
+    const initial = gameReducer(
+      gameReducer(undefined, { type: 'init' }),
+      applyLoadedContent({ packId: 'default', content }),
+    )
+
+    const first = gameReducer(
+      initial,
+      applyRift({ kind: 'tunnel', target: { x: 10, y: 10, t: 1 } }),
+    )
+
+    expect(first.phase).toBe('Playing')
+    expect(first.status).toContain('the others still need one')
+
+    const switched = gameReducer(first, switchCharacter())
+
+    expect(switched.turn).toBe(2)
+    expect(switched.activeCharacterId).toBe('scout')
+    expect(switched.currentTime).toBe(0)
+    expect(switched.worldLine.path.at(-1)).toEqual({ x: 6, y: 5, t: 0 })
+    expect(switched.parkedCharacters.map((character) => character.id)).toEqual(['player'])
+
+    const won = gameReducer(
+      switched,
+      applyRift({ kind: 'tunnel', target: { x: 10, y: 10, t: 2 } }),
+    )
+
+    expect(won.phase).toBe('Won')
+
+    const reset = gameReducer(won, restart())
+
+    expect(reset.activeCharacterId).toBe('player')
+    expect(reset.parkedCharacters[0]?.worldLine.path).toEqual([{ x: 6, y: 5, t: 0 }])
+  })
+
+  it('passes control after every action in hotseat and only catches the seen character', () => {
+    const loaded = loadDefaultBootContent()
+    expect(loaded.ok).toBe(true)
+    if (!loaded.ok) {
+      return
+    }
+
+    const content = {
+      ...loaded.value,
+      characterStarts: [{ id: 'scout', start: { x: 6, y: 5, t: 0 } }],
+    }
+    const initial = gameReducer(
+      gameReducer(
+        gameReducer(undefined, { type: 'init' }),
+        applyLoadedContent({ packId: 'default', content }),
+      ),
+      setHotseat(true),
+    )
+
+    const passed = gameReducer(initial, waitTurn())
+
+    expect(passed.activeCharacterId).toBe('scout')
+    expect(passed.currentTime).toBe(0)
+    expect(passed.status).toContain("scout's turn")
+    expect(gameReducer(passed, setHotseat(false)).hotseat).toBe(true)
+
+    const watched = gameReducer(
+      initial,
+      configureDetectionConfig({ enabled: true, delayTurns: 1, maxDistance: 8 }),
+    )
+    const caught = gameReducer(watched, waitTurn())
+
+    expect(caught.phase).toBe('Playing')
+    expect(caught.caughtCharacterIds).toEqual(['player'])
+    expect(caught.activeCharacterId).toBe('scout')
+    expect(caught.status).toContain('player caught by enemy.alpha; scout plays on')
+
+    const lost = gameReducer(caught, waitTurn())
+
+    expect(lost.phase).toBe('Detected')
+  })
+
+  it('refuses to switch when the level has a single character', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+
+    const blocked = gameReducer(initial, switchCharacter())
+
+    expect(blocked.turn).toBe(0)
+    expect(blocked.status).toBe('No other character to switch to')
   })
 
   it('pulls a box from behind into previous player cell', () => {
@@ -176,6 +1431,59 @@ describe('gameSlice', () => {
     expect(objectsAt(blocked.cube, { x: 8, y: 6, t: 4 }).map((obj) => obj.id)).toContain('box.main')
   })
 
+  it('refuses to wait when waiting is disabled', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const configured = gameReducer(initial, setInteractionConfig({ allowWait: false }))
+    const blocked = gameReducer(configured, waitTurn())
+
+    expect(blocked.turn).toBe(0)
+    expect(blocked.currentTime).toBe(0)
+    expect(blocked.status).toBe('Waiting is disabled')
+  })
+
+  it('expands moveTo into committed single steps', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+
+    const moved = gameReducer(initial, moveTo({ x: 7, y: 5, t: 2 }))
+    const step = { kind: 'MoveTo', target: { x: 7, y: 5, t: 2 } }
+
+    expect(moved.turn).toBe(2)
+    expect(moved.worldLine.path.at(-1)).toEqual({ x: 7, y: 5, t: 2 })
+    expect(moved.history.map((entry) => entry.action)).toEqual([step, step])
+    expect(moved.history.map((entry) => entry.outcome.to)).toEqual([
+      { x: 6, y: 5, t: 1 },
+      { x: 7, y: 5, t: 2 },
+    ])
+  })
+
+  it('walks a same-slice moveTo target to the earliest slice that reaches it', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+
+    const moved = gameReducer(initial, moveTo({ x: 7, y: 5, t: 0 }))
+    const here = gameReducer(initial, moveTo({ x: 5, y: 5, t: 0 }))
+
+    expect(moved.turn).toBe(2)
+    expect(moved.worldLine.path.at(-1)).toEqual({ x: 7, y: 5, t: 2 })
+    expect(moved.history.map((entry) => entry.action)).toEqual([
+      { kind: 'MoveTo', target: { x: 7, y: 5, t: 2 } },
+      { kind: 'MoveTo', target: { x: 7, y: 5, t: 2 } },
+    ])
+    expect(here.turn).toBe(0)
+    expect(here.status).toBe('Move to: already there')
+  })
+
+  it('commits nothing when moveTo cannot reach its target', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+
+    const walled = gameReducer(initial, moveTo({ x: 5, y: 4, t: 1 }))
+    const tooFar = gameReducer(initial, moveTo({ x: 9, y: 5, t: 2 }))
+
+    expect(walled.turn).toBe(0)
+    expect(walled.status).toBe('Move to: no path to (5, 4, t=1)')
+    expect(tooFar.turn).toBe(0)
+    expect(tooFar.history).toHaveLength(0)
+  })
+
   it('records successful interactions in history', () => {
     const initial = gameReducer(undefined, { type: 'init' })
     const moved = gameReducer(initial, movePlayer2D('east'))
@@ -224,6 +1532,7 @@ describe('gameSlice', () => {
     expect(detected.lastDetection?.detected).toBe(true)
     expect(detected.lastDetection?.events[0]?.enemyId).toBe('enemy.alpha')
     expect(detected.status).toContain('detected by enemy.alpha')
+    expect(detected.history.at(-1)?.events?.map((event) => event.kind)).toEqual(['Detected'])
   })
 
   it('uses per-enemy detection overrides when provided by content', () => {
@@ -415,4 +1724,115 @@ describe('gameSlice', () => {
     expect(applied.enemyDetectionConfigById).toEqual({})
     expect(applied.status).toBe('Loaded content pack: default')
   })
+
+  it('suggests a solver move as a hint and spends hint budget', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+
+    const hinted = gameReducer(initial, requestHint())
+
+    expect(hinted.hintsUsed).toBe(1)
+    expect(hinted.lastHint).not.toBeNull()
+    expect(hinted.status).toContain('Hint 1/')
+
+    const moved = gameReducer(hinted, waitTurn())
+
+    expect(moved.lastHint).toBeNull()
+    expect(moved.hintsUsed).toBe(1)
+  })
+
+  it('refuses hints once the level budget is spent', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const exhausted = { ...initial, hintsUsed: initial.hintBudget }
+
+    const next = gameReducer(exhausted, requestHint())
+
+    expect(next.hintsUsed).toBe(initial.hintBudget)
+    expect(next.lastHint).toBeNull()
+    expect(next.status).toContain('No hints left')
+  })
+
+  it('peeks around a corner without passing a turn, within the level budget', () => {
+    const initial = { ...gameReducer(undefined, { type: 'init' }), peekBudget: 1 }
+
+    expect(initial.exploredByTime[0]).not.toContain('5,0')
+
+    const blocked = gameReducer(initial, peek('north'))
+
+    expect(blocked.peeksUsed).toBe(0)
+    expect(blocked.status).toBe('Nothing to peek past north')
+
+    const peeked = gameReducer(initial, peek('east'))
+
+    expect(peeked.peeksUsed).toBe(1)
+    expect(peeked.exploredByTime[0]).toContain('5,0')
+    expect(peeked.turn).toBe(0)
+    expect(peeked.worldLine).toEqual(initial.worldLine)
+    expect(peeked.status).toBe('Peek 1/1: looked east from (6, 5)')
+
+    const spent = gameReducer(peeked, peek('west'))
+
+    expect(spent.peeksUsed).toBe(1)
+    expect(spent.status).toContain('No peeks left')
+  })
+
+  it('simulates an interaction without mutating the committed state', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+
+    const simulated = simulateInteractionPipeline(initial, { kind: 'Move', direction: 'east' })
+    const committed = gameReducer(initial, performInteraction({ kind: 'Move', direction: 'east' }))
+
+    expect(initial.turn).toBe(0)
+    expect(simulated.turn).toBe(1)
+    expect(committed.worldLine.path.at(-1)).toEqual(simulated.worldLine.path.at(-1))
+    expect(committed.status).toBe(simulated.status)
+  })
+
+  it('teleports and spawns from the debug console without spending a turn', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const teleported = gameReducer(initial, debugTeleport({ x: 1, y: 1, t: 4 }))
+
+    expect(teleported.worldLine.path.at(-1)).toEqual({ x: 1, y: 1, t: 4 })
+    expect(teleported.currentTime).toBe(4)
+    expect(teleported.turn).toBe(0)
+    expect(gameReducer(initial, debugTeleport({ x: 40, y: 1, t: 0 })).status).toContain(
+      'outside the cube',
+    )
+
+    const spawned = gameReducer(
+      teleported,
+      debugSpawn({ archetype: 'wall', position: { x: 2, y: 2, t: 4 } }),
+    )
+
+    expect(spawned.status).toContain('debug.wall.1')
+    expect(isBlocked(spawned.cube, { x: 2, y: 2, t: 4 })).toBe(true)
+    const unknown = gameReducer(
+      spawned,
+      debugSpawn({ archetype: 'ufo', position: { x: 2, y: 3, t: 4 } }),
+    )
+
+    expect(unknown.status).toBe('Debug: unknown archetype ufo')
+  })
+
+  it('traces the pipeline, its propagation passes and detection', () => {
+    const sink = createMemoryTraceSink()
+
+    setTraceSink(sink)
+    try {
+      gameReducer(gameReducer(undefined, { type: 'init' }), performInteraction({ kind: 'Wait' }))
+    } finally {
+      setTraceSink(null)
+    }
+
+    expect(sink.records.map((record) => record.span)).toEqual([
+      'interaction',
+      'propagate.dependencies',
+      'propagate.detours',
+      'propagate.lures',
+      'propagate.chasers',
+      'paradox',
+      'detection',
+      'pipeline',
+    ])
+    expect(sink.records.at(-1)?.fields).toMatchObject({ action: 'Wait', committed: true })
+  })
 })
@@ -1,15 +1,41 @@
-import { createSlice, type PayloadAction } from '@reduxjs/toolkit'
+import { createSlice, current, type PayloadAction } from '@reduxjs/toolkit'
 
+import { parkCharacters, PLAYER_CHARACTER_ID, type CharacterStart } from '../core/characters'
+import { formatCubeIntegrityIssue, validateTimeCube } from '../core/cubeIntegrity'
 import type { DetectionConfig } from '../core/detection'
+import { addEchoes } from '../core/echo'
+import { peekOrigin, recordExplored, type ExploredByTime } from '../core/fog'
+import { DEFAULT_BREAK_NOISE_RADIUS, DEFAULT_LURE_SETTINGS } from '../core/lure'
 import type { ParadoxConfig } from '../core/paradox'
-import type { Direction2D, Position3D } from '../core/position'
+import { isInBounds, type Direction2D, type Position3D } from '../core/position'
+import { regionForDisplay } from '../core/regions'
 import type { RiftInstruction, RiftResources, RiftSettings } from '../core/rift'
-import { createWorldLine } from '../core/worldLine'
-import type { LevelObjectsConfig, ObjectRegistry } from '../core/objects'
-import { loadDefaultBootContent, type LoadedBootContent } from '../data/loader'
+import { createRng } from '../core/rng'
+import { createTimelineSet } from '../core/timelineSet'
+import { createWorldLine, currentPosition, extendViaRift } from '../core/worldLine'
+import {
+  resolveObjectInstance,
+  type LevelObjectsConfig,
+  type ObjectRegistry,
+} from '../core/objects'
+import type { TutorialStepConfig } from '../data/contracts'
+import {
+  DEFAULT_HINT_BUDGET,
+  loadDefaultBootContent,
+  type LevelDisplayMeta,
+  type LoadedBootContent,
+} from '../data/loader'
+import type { MutatorId } from '../data/mutators'
+import { DEFAULT_PLAY_DIFFICULTY, type PlayDifficulty } from '../data/playDifficulty'
+import { STANDARD_RULE_PROFILE, type RuleProfile } from '../data/ruleProfiles'
+import { computeHint, describeHintAction, type HintSuggestion } from './hints'
 import { bootstrapLevelObjects, defaultLevelObjectsConfig } from './levelObjects'
+import { statusTranslator } from './interactions/common'
+import { formatMoveToError } from './interactions/moveTo'
 import { runInteractionPipeline } from './interactions/pipeline'
 import { resolveBootstrapPolicy } from './bootstrapPolicy'
+import { rehearseMoveTo } from './turnPlan'
+import { advanceTutorialStep } from './tutorial'
 import type {
   GamePhase,
   InteractionAction,
@@ -17,7 +43,8 @@ import type {
   InteractionHistoryEntry,
   InteractionState,
 } from './interactions/types'
-import type { TimeCube } from '../core/timeCube'
+import { placeObjects, type TimeCube } from '../core/timeCube'
+import { createTranslator, DEFAULT_LOCALE, type Locale } from '../render/i18n'
 
 const bootContent = loadDefaultBootContent()
 const BOOT_POLICY = resolveBootstrapPolicy(import.meta.env)
@@ -33,6 +60,9 @@ const DEFAULT_TIME_DEPTH = bootContent.ok ? bootContent.value.timeDepth : FALLBA
 const DEFAULT_START_POSITION: Position3D = bootContent.ok
   ? bootContent.value.startPosition
   : FALLBACK_START_POSITION
+const DEFAULT_CHARACTER_STARTS: CharacterStart[] = bootContent.ok
+  ? bootContent.value.characterStarts
+  : []
 const DEFAULT_RIFT_SETTINGS: RiftSettings = bootContent.ok
   ? bootContent.value.riftSettings
   : {
@@ -47,6 +77,9 @@ const DEFAULT_INTERACTION_CONFIG: InteractionConfig = bootContent.ok
   : {
       maxPushChain: 4,
       allowPull: true,
+      allowTimePush: false,
+      lure: { ...DEFAULT_LURE_SETTINGS },
+      breakNoiseRadius: DEFAULT_BREAK_NOISE_RADIUS,
     }
 const DEFAULT_DETECTION_CONFIG: DetectionConfig = bootContent.ok
   ? bootContent.value.detectionConfig
@@ -70,18 +103,29 @@ const DEFAULT_THEME_CSS_VARS: Record<string, string> = bootContent.ok
   ? bootContent.value.themeCssVars
   : {}
 const DEFAULT_ICON_PACK_ID = bootContent.ok ? bootContent.value.iconPackId : 'default-mono'
+const DEFAULT_LEVEL_HINT_BUDGET = bootContent.ok ? bootContent.value.hintBudget : DEFAULT_HINT_BUDGET
+const DEFAULT_RNG_SEED = bootContent.ok ? bootContent.value.rngSeed : 'fallback'
+const DEFAULT_TUTORIAL_STEPS: TutorialStepConfig[] = bootContent.ok
+  ? bootContent.value.tutorialSteps
+  : []
+const DEFAULT_LEVEL_META: LevelDisplayMeta = bootContent.ok
+  ? bootContent.value.levelMeta
+  : { id: 'fallback', name: 'Fallback' }
+// Boot runs before the shell has picked a language, so these are always in the default one.
+const bootMessage = createTranslator(DEFAULT_LOCALE)
 const BOOT_FAILURE_STATUS = bootContent.ok
   ? null
-  : `Boot content failed (${bootContent.error.kind}); gameplay disabled until valid content is loaded`
+  : bootMessage('status.bootFailed', { kind: bootContent.error.kind })
 const BOOT_FALLBACK_STATUS = bootContent.ok
   ? null
-  : `Boot content failed (${bootContent.error.kind}); using dev fallback level`
+  : bootMessage('status.bootFallback', { kind: bootContent.error.kind })
 
 export interface GameState extends InteractionState {
   objectRegistry: ObjectRegistry
   contentPackId: string
   levelObjectsConfig: LevelObjectsConfig | null
   startPosition: Position3D
+  characterStarts: CharacterStart[]
   defaultRiftSettings: RiftSettings
   defaultInteractionConfig: InteractionConfig
   defaultDetectionConfig: DetectionConfig
@@ -89,6 +133,29 @@ export interface GameState extends InteractionState {
   defaultParadoxConfig: ParadoxConfig
   themeCssVars: Record<string, string>
   iconPackId: string
+  hintBudget: number
+  hintsUsed: number
+  peekBudget: number
+  peeksUsed: number
+  /** Past attempts kept for echo enemies; 0 when the level has none. */
+  echoLimit: number
+  /** World lines of earlier attempts, oldest first; each restart adds one echo retracing it. */
+  echoPaths: Position3D[][]
+  /** Difficulty the loaded level was adjusted for. */
+  playDifficulty: PlayDifficulty
+  ruleProfile: RuleProfile
+  /** Challenge mutators the loaded level was started with. */
+  mutators: MutatorId[]
+  /** Restarts left under the rule profile; null when unlimited. */
+  restartsRemaining: number | null
+  lastHint: HintSuggestion | null
+  tutorialSteps: TutorialStepConfig[]
+  levelMeta: LevelDisplayMeta
+  tutorialStepIndex: number
+  /** Cells the player has seen in each slice, for the fog-of-war view. */
+  exploredByTime: ExploredByTime
+  /** Cube before the last rift into the past, which the timeline diff compares against. */
+  timelineBaseline: TimeCube | null
 }
 
 function bootstrapObjectState(): {
@@ -108,11 +175,18 @@ function bootstrapObjectState(): {
           t,
           objectIds: [],
           spatialIndex: {},
+          idsByKind: {},
         })),
         objectsById: {},
+        decor: [],
+        regions: [],
+        timeWraps: false,
+        xWraps: false,
+        yWraps: false,
+        lockdowns: [],
       },
       phase: 'BootError',
-      status: BOOT_FAILURE_STATUS ?? 'Boot content failed',
+      status: BOOT_FAILURE_STATUS ?? bootMessage('status.bootContentFailed'),
     }
   }
 
@@ -142,15 +216,22 @@ function bootstrapObjectState(): {
         t,
         objectIds: [],
         spatialIndex: {},
+        idsByKind: {},
       })),
       objectsById: {},
+      decor: [],
+      regions: [],
+      timeWraps: false,
+      xWraps: false,
+      yWraps: false,
+      lockdowns: [],
     },
     phase: 'BootError',
-    status: 'Object bootstrap failed; gameplay disabled until valid content is loaded',
+    status: bootMessage('status.objectBootstrapFailed'),
   }
 }
 
-function bootstrapObjectStateForContent(content: LoadedBootContent): {
+function bootstrapObjectStateForContent(state: GameState, content: LoadedBootContent): {
   ok: true
   objectRegistry: ObjectRegistry
   cube: TimeCube
@@ -166,7 +247,7 @@ function bootstrapObjectStateForContent(content: LoadedBootContent): {
   )
 
   if (!bootstrap.ok) {
-    return { ok: false, message: 'Loaded content bootstrap failed' }
+    return { ok: false, message: statusTranslator(state)('status.contentBootstrapFailed') }
   }
 
   return {
@@ -186,11 +267,19 @@ function createInitialState(): GameState {
     objectRegistry: objectState.objectRegistry,
     cube: objectState.cube,
     worldLine: createWorldLine(DEFAULT_START_POSITION),
+    activeCharacterId: PLAYER_CHARACTER_ID,
+    parkedCharacters: parkCharacters(DEFAULT_CHARACTER_STARTS),
+    hotseat: false,
+    caughtCharacterIds: [],
     currentTime: DEFAULT_START_POSITION.t,
     turn: 0,
     phase: objectState.phase,
     riftSettings: { ...DEFAULT_RIFT_SETTINGS },
     riftResources: { ...DEFAULT_RIFT_RESOURCES },
+    lures: { remaining: DEFAULT_INTERACTION_CONFIG.lure.supply, thrown: [] },
+    noises: [],
+    breakHits: {},
+    extractionTurns: 0,
     interactionConfig: { ...DEFAULT_INTERACTION_CONFIG },
     defaultRiftSettings: { ...DEFAULT_RIFT_SETTINGS },
     defaultInteractionConfig: { ...DEFAULT_INTERACTION_CONFIG },
@@ -203,12 +292,40 @@ function createInitialState(): GameState {
     contentPackId: DEFAULT_CONTENT_PACK_ID,
     levelObjectsConfig: DEFAULT_LEVEL_OBJECTS_CONFIG,
     startPosition: DEFAULT_START_POSITION,
+    characterStarts: DEFAULT_CHARACTER_STARTS,
     themeCssVars: { ...DEFAULT_THEME_CSS_VARS },
     iconPackId: DEFAULT_ICON_PACK_ID,
+    hintBudget: DEFAULT_LEVEL_HINT_BUDGET,
+    hintsUsed: 0,
+    peekBudget: bootContent.ok ? bootContent.value.peekBudget : 0,
+    peeksUsed: 0,
+    echoLimit: bootContent.ok ? bootContent.value.echoLimit : 0,
+    alarmConfig: bootContent.ok ? bootContent.value.alarmConfig : null,
+    alarm: { suspicion: 0 },
+    knockoutConfig: bootContent.ok ? bootContent.value.knockoutConfig : null,
+    foundBodyIds: [],
+    echoPaths: [],
+    playDifficulty: DEFAULT_PLAY_DIFFICULTY,
+    ruleProfile: STANDARD_RULE_PROFILE,
+    mutators: [],
+    restartsRemaining: STANDARD_RULE_PROFILE.restarts,
+    lastHint: null,
+    tutorialSteps: DEFAULT_TUTORIAL_STEPS,
+    levelMeta: DEFAULT_LEVEL_META,
+    tutorialStepIndex: 0,
+    exploredByTime: recordExplored(
+      {},
+      objectState.cube,
+      DEFAULT_START_POSITION,
+      DEFAULT_START_POSITION.t,
+    ),
+    timelineBaseline: null,
     lastDetection: null,
     lastParadox: null,
     causalAnchors: [],
     causalAnchorsByTime: {},
+    timelines: null,
+    rng: createRng(DEFAULT_RNG_SEED),
     history: [],
     status: objectState.status,
   }
@@ -216,8 +333,138 @@ function createInitialState(): GameState {
 
 const initialState: GameState = createInitialState()
 
+/** Adds the player's current view to the explored set of their slice. */
+function recordPlayerView(state: GameState): void {
+  const player = currentPosition(state.worldLine)
+
+  if (player) {
+    state.exploredByTime = recordExplored(state.exploredByTime, state.cube, player, player.t)
+  }
+}
+
+/** Appends "entered <region>" to the status when a committed turn crosses into a new region. */
+function announceRegionChange(
+  state: GameState,
+  committed: InteractionHistoryEntry,
+  before: Position3D | null,
+): void {
+  const after = currentPosition(state.worldLine)
+
+  if (!after || state.phase !== 'Playing') {
+    return
+  }
+
+  const entered = regionForDisplay(state.cube.regions, after)
+  const left = before ? regionForDisplay(state.cube.regions, before) : null
+
+  if (entered && entered.id !== left?.id) {
+    committed.events?.push({ kind: 'EnteredRegion', regionId: entered.id, name: entered.name })
+    const announcement = statusTranslator(state)('status.enteredRegion', { name: entered.name })
+    state.status = `${state.status}; ${announcement}`
+  }
+}
+
+/** Debug builds re-check the cube after every committed turn and log what broke. */
+function checkCubeIntegrity(state: GameState): void {
+  const report = validateTimeCube(state.cube, { checkPatrols: state.lures.thrown.length === 0 })
+
+  if (!report.ok) {
+    console.warn(`[cube] ${report.issues.map(formatCubeIntegrityIssue).join('; ')}`)
+  }
+}
+
 function runAction(state: GameState, action: InteractionAction): void {
+  state.lastHint = null
+  const historyLength = state.history.length
+  const position = currentPosition(state.worldLine)
+  const before = position ? { ...position } : null
+  const cubeBefore = state.cube
   runInteractionPipeline(state, action)
+  const committed = state.history[historyLength]
+
+  if (committed) {
+    if (committed.outcome.kind === 'Rifted' && before && committed.outcome.to.t < before.t) {
+      state.timelineBaseline = cubeBefore
+    }
+
+    if (import.meta.env.DEV) {
+      checkCubeIntegrity(state)
+    }
+
+    announceRegionChange(state, committed, before)
+    recordPlayerView(state)
+    state.tutorialStepIndex = advanceTutorialStep(
+      state.tutorialSteps,
+      state.tutorialStepIndex,
+      committed,
+      state.cube.regions,
+    )
+  }
+}
+
+/** Puts the loaded level back to its first turn; false (status set) if it cannot be rebuilt. */
+function resetRun(state: GameState): boolean {
+  const restartConfig =
+    state.levelObjectsConfig ??
+    (BOOT_POLICY.allowDevFallbackLevel ? defaultLevelObjectsConfig : null)
+
+  if (!restartConfig) {
+    state.phase = 'BootError'
+    state.status = statusTranslator(state)('status.restartNoLevel')
+    return false
+  }
+
+  const objectState = bootstrapLevelObjects(
+    state.boardWidth,
+    state.boardHeight,
+    state.timeDepth,
+    restartConfig,
+  )
+
+  if (!objectState.ok) {
+    state.phase = 'BootError'
+    state.status = statusTranslator(state)('status.restartFailed')
+    return false
+  }
+
+  // A failed echo placement only costs the echoes, never the restart.
+  const echoed = addEchoes(objectState.value.cube, state.echoPaths)
+
+  state.objectRegistry = objectState.value.objectRegistry
+  state.cube = echoed.ok ? echoed.value : objectState.value.cube
+  state.worldLine = createWorldLine(state.startPosition)
+  state.activeCharacterId = PLAYER_CHARACTER_ID
+  state.parkedCharacters = parkCharacters(state.characterStarts)
+  state.caughtCharacterIds = []
+  state.currentTime = state.startPosition.t
+  state.turn = 0
+  state.phase = 'Playing'
+  state.riftSettings = { ...state.defaultRiftSettings }
+  state.riftResources = { ...DEFAULT_RIFT_RESOURCES }
+  state.interactionConfig = { ...state.defaultInteractionConfig }
+  state.lures = { remaining: state.interactionConfig.lure.supply, thrown: [] }
+  state.noises = []
+  state.breakHits = {}
+  state.extractionTurns = 0
+  state.alarm = { suspicion: 0 }
+  state.foundBodyIds = []
+  state.detectionConfig = { ...state.defaultDetectionConfig }
+  state.enemyDetectionConfigById = { ...state.defaultEnemyDetectionConfigById }
+  state.lastDetection = null
+  state.paradoxConfig = { ...state.defaultParadoxConfig }
+  state.lastParadox = null
+  state.causalAnchors = []
+  state.causalAnchorsByTime = {}
+  state.timelines = state.ruleProfile.branching ? createTimelineSet(state.cube) : null
+  state.rng = createRng(state.rng.seed)
+  state.history = []
+  state.lastHint = null
+  state.tutorialStepIndex = 0
+  state.exploredByTime = {}
+  recordPlayerView(state)
+  state.timelineBaseline = null
+  state.phase = 'Playing'
+  return true
 }
 
 const gameSlice = createSlice({
@@ -239,21 +486,106 @@ const gameSlice = createSlice({
     pullPlayer2D(state, action: PayloadAction<Direction2D>) {
       runAction(state, { kind: 'Pull', direction: action.payload })
     },
+    timePushPlayer2D(state, action: PayloadAction<Direction2D>) {
+      runAction(state, { kind: 'TimePush', direction: action.payload })
+    },
+    switchCharacter(state) {
+      runAction(state, { kind: 'SwitchCharacter' })
+    },
+    climb(state) {
+      runAction(state, { kind: 'Climb' })
+    },
+    breakWall(state, action: PayloadAction<Direction2D>) {
+      runAction(state, { kind: 'Break', direction: action.payload })
+    },
+    interact(state, action: PayloadAction<Direction2D>) {
+      runAction(state, { kind: 'Interact', direction: action.payload })
+    },
+    throwLure(state, action: PayloadAction<Direction2D>) {
+      runAction(state, {
+        kind: 'Throw',
+        direction: action.payload,
+        range: state.interactionConfig.lure.maxRange,
+      })
+    },
+    /**
+     * Walk to a cell in this or a later slice, one registered `MoveTo` turn per step; nothing is
+     * committed unless every step holds.
+     */
+    moveTo(state, action: PayloadAction<Position3D>) {
+      const message = statusTranslator(state)
+      const rehearsal = rehearseMoveTo(current(state), action.payload)
+
+      if (!rehearsal.ok) {
+        state.lastHint = null
+        state.status = message('interaction.moveTo', {
+          reason: formatMoveToError(message, rehearsal.error),
+        })
+        return
+      }
+
+      if (rehearsal.value.length === 0) {
+        state.status = message('interaction.moveToArrived')
+        return
+      }
+
+      for (const step of rehearsal.value) {
+        runAction(state, step)
+      }
+    },
+    /** Play a queued plan in order, stopping at the first step that does not commit. */
+    commitTurnPlan(state, action: PayloadAction<InteractionAction[]>) {
+      for (const step of action.payload) {
+        const historyLength = state.history.length
+        runAction(state, step)
+
+        if (state.history.length === historyLength || state.phase !== 'Playing') {
+          return
+        }
+      }
+    },
+    performInteraction(state, action: PayloadAction<InteractionAction>) {
+      runAction(state, action.payload)
+    },
+    setHotseat(state, action: PayloadAction<boolean>) {
+      const message = statusTranslator(state)
+
+      if (state.turn > 0) {
+        state.status = message('status.hotseatLocked')
+        return
+      }
+
+      state.hotseat = action.payload
+      state.status = message(action.payload ? 'status.hotseatOn' : 'status.hotseatOff')
+    },
     configureRiftSettings(state, action: PayloadAction<Partial<RiftSettings>>) {
       state.riftSettings = { ...state.riftSettings, ...action.payload }
-      state.status = `Rift settings updated (delta=${state.riftSettings.defaultDelta}, cost=${state.riftSettings.baseEnergyCost})`
+      state.status = statusTranslator(state)('status.riftSettings', {
+        delta: state.riftSettings.defaultDelta,
+        cost: state.riftSettings.baseEnergyCost,
+      })
     },
     setInteractionConfig(state, action: PayloadAction<Partial<InteractionConfig>>) {
       state.interactionConfig = { ...state.interactionConfig, ...action.payload }
-      state.status = `Interaction config updated (maxPushChain=${state.interactionConfig.maxPushChain}, allowPull=${state.interactionConfig.allowPull})`
+      state.status = statusTranslator(state)('status.interactionConfig', {
+        maxPushChain: state.interactionConfig.maxPushChain,
+        allowPull: String(state.interactionConfig.allowPull),
+        allowTimePush: String(state.interactionConfig.allowTimePush),
+      })
     },
     configureDetectionConfig(state, action: PayloadAction<Partial<DetectionConfig>>) {
       state.detectionConfig = { ...state.detectionConfig, ...action.payload }
-      state.status = `Detection config updated (enabled=${state.detectionConfig.enabled}, delay=${state.detectionConfig.delayTurns}, range=${state.detectionConfig.maxDistance})`
+      state.status = statusTranslator(state)('status.detectionConfig', {
+        enabled: String(state.detectionConfig.enabled),
+        delay: state.detectionConfig.delayTurns,
+        range: state.detectionConfig.maxDistance,
+      })
     },
     configureParadoxConfig(state, action: PayloadAction<Partial<ParadoxConfig>>) {
       state.paradoxConfig = { ...state.paradoxConfig, ...action.payload }
-      state.status = `Paradox config updated (enabled=${state.paradoxConfig.enabled})`
+      state.status = statusTranslator(state)('status.paradoxConfig', {
+        enabled: String(state.paradoxConfig.enabled),
+      })
     },
     setContentPackId(state, action: PayloadAction<string>) {
       if (state.contentPackId === action.payload) {
@@ -261,13 +593,13 @@ const gameSlice = createSlice({
       }
 
       state.contentPackId = action.payload
-      state.status = `Loading content pack: ${action.payload}`
+      state.status = statusTranslator(state)('status.loadingPack', { packId: action.payload })
     },
     applyLoadedContent(
       state,
       action: PayloadAction<{ packId: string; content: LoadedBootContent }>,
     ) {
-      const bootstrapped = bootstrapObjectStateForContent(action.payload.content)
+      const bootstrapped = bootstrapObjectStateForContent(state, action.payload.content)
 
       if (!bootstrapped.ok) {
         state.status = bootstrapped.message
@@ -280,9 +612,13 @@ const gameSlice = createSlice({
       state.boardHeight = action.payload.content.boardHeight
       state.timeDepth = action.payload.content.timeDepth
       state.startPosition = action.payload.content.startPosition
+      state.characterStarts = action.payload.content.characterStarts
       state.objectRegistry = bootstrapped.objectRegistry
       state.cube = bootstrapped.cube
       state.worldLine = createWorldLine(action.payload.content.startPosition)
+      state.activeCharacterId = PLAYER_CHARACTER_ID
+      state.parkedCharacters = parkCharacters(action.payload.content.characterStarts)
+      state.caughtCharacterIds = []
       state.currentTime = action.payload.content.startPosition.t
       state.turn = 0
       state.phase = 'Playing'
@@ -291,6 +627,10 @@ const gameSlice = createSlice({
       state.riftSettings = { ...action.payload.content.riftSettings }
       state.defaultInteractionConfig = { ...action.payload.content.interactionConfig }
       state.interactionConfig = { ...action.payload.content.interactionConfig }
+      state.lures = { remaining: state.interactionConfig.lure.supply, thrown: [] }
+      state.noises = []
+      state.breakHits = {}
+      state.extractionTurns = 0
       state.defaultDetectionConfig = { ...action.payload.content.detectionConfig }
       state.detectionConfig = { ...action.payload.content.detectionConfig }
       state.defaultEnemyDetectionConfigById = { ...action.payload.content.enemyDetectionConfigById }
@@ -298,60 +638,223 @@ const gameSlice = createSlice({
       state.paradoxConfig = { ...state.defaultParadoxConfig }
       state.themeCssVars = { ...action.payload.content.themeCssVars }
       state.iconPackId = action.payload.content.iconPackId
+      state.hintBudget = action.payload.content.hintBudget
+      state.hintsUsed = 0
+      state.peekBudget = action.payload.content.peekBudget
+      state.peeksUsed = 0
+      state.echoLimit = action.payload.content.echoLimit
+      state.alarmConfig = action.payload.content.alarmConfig
+      state.alarm = { suspicion: 0 }
+      state.knockoutConfig = action.payload.content.knockoutConfig
+      state.foundBodyIds = []
+      state.echoPaths = []
+      state.playDifficulty = action.payload.content.playDifficulty
+      state.ruleProfile = action.payload.content.ruleProfile
+      state.mutators = [...action.payload.content.mutators]
+      state.restartsRemaining = action.payload.content.ruleProfile.restarts
+      state.lastHint = null
+      state.tutorialSteps = action.payload.content.tutorialSteps
+      state.levelMeta = action.payload.content.levelMeta
+      state.tutorialStepIndex = 0
+      state.exploredByTime = {}
+      recordPlayerView(state)
+      state.timelineBaseline = null
       state.lastDetection = null
       state.lastParadox = null
       state.causalAnchors = []
       state.causalAnchorsByTime = {}
+      state.timelines = state.ruleProfile.branching ? createTimelineSet(bootstrapped.cube) : null
+      state.rng = createRng(action.payload.content.rngSeed)
       state.history = []
-      state.status = `Loaded content pack: ${action.payload.packId}`
+      state.status = statusTranslator(state)('status.loadedPack', { packId: action.payload.packId })
     },
     restart(state) {
-      const restartConfig =
-        state.levelObjectsConfig ??
-        (BOOT_POLICY.allowDevFallbackLevel ? defaultLevelObjectsConfig : null)
+      const message = statusTranslator(state)
 
-      if (!restartConfig) {
-        state.phase = 'BootError'
-        state.status = 'Restart blocked: no level loaded. Load a valid content pack.'
+      if (state.restartsRemaining === 0) {
+        state.status = message('status.noRestarts', { rules: state.ruleProfile.name })
         return
       }
 
-      const objectState = bootstrapLevelObjects(
-        state.boardWidth,
-        state.boardHeight,
-        state.timeDepth,
-        restartConfig,
-      )
+      const attempt = current(state.worldLine).path
 
-      if (!objectState.ok) {
-        state.phase = 'BootError'
-        state.status = 'Restart failed: object bootstrap error'
+      if (state.echoLimit > 0 && state.turn > 0) {
+        state.echoPaths = [...state.echoPaths, attempt].slice(-state.echoLimit)
+      }
+
+      if (!resetRun(state)) {
         return
       }
 
-      state.objectRegistry = objectState.value.objectRegistry
-      state.cube = objectState.value.cube
-      state.worldLine = createWorldLine(state.startPosition)
-      state.currentTime = state.startPosition.t
-      state.turn = 0
-      state.phase = 'Playing'
-      state.riftSettings = { ...state.defaultRiftSettings }
-      state.riftResources = { ...DEFAULT_RIFT_RESOURCES }
-      state.interactionConfig = { ...state.defaultInteractionConfig }
-      state.detectionConfig = { ...state.defaultDetectionConfig }
-      state.enemyDetectionConfigById = { ...state.defaultEnemyDetectionConfigById }
-      state.lastDetection = null
-      state.paradoxConfig = { ...state.defaultParadoxConfig }
-      state.lastParadox = null
-      state.causalAnchors = []
-      state.causalAnchorsByTime = {}
-      state.history = []
-      state.phase = 'Playing'
-      state.status = 'Restarted'
+      const echoes = state.echoPaths.length
+
+      state.status =
+        echoes === 0
+          ? message('status.restarted')
+          : echoes === 1
+            ? message('status.restartedEcho')
+            : message('status.restartedEchoes', { count: echoes })
+
+      if (state.restartsRemaining !== null) {
+        state.restartsRemaining -= 1
+      }
+    },
+    /** Replays a saved session's actions on the loaded level; does not count as a restart. */
+    resumeSession(state, action: PayloadAction<InteractionAction[]>) {
+      if (!resetRun(state)) {
+        return
+      }
+
+      for (const step of action.payload) {
+        const historyLength = state.history.length
+        runAction(state, step)
+
+        if (state.history.length === historyLength || state.phase !== 'Playing') {
+          break
+        }
+      }
+
+      state.status = statusTranslator(state)('status.resumed', { turn: state.turn })
+    },
+    requestHint(state) {
+      const message = statusTranslator(state)
+
+      if (state.phase !== 'Playing') {
+        state.status = message('status.hintsNotPlaying')
+        return
+      }
+
+      if (state.hintsUsed >= state.hintBudget) {
+        state.status = message('status.noHints', { budget: state.hintBudget })
+        return
+      }
+
+      const hint = computeHint(current(state))
+
+      if (!hint.ok) {
+        state.lastHint = null
+        state.status = message(
+          hint.error.kind === 'AlreadySolved' ? 'status.hintSolved' : 'status.hintNoRoute',
+        )
+        return
+      }
+
+      const { action: hintAction, target, remainingSteps } = hint.value
+
+      state.hintsUsed += 1
+      state.lastHint = hint.value
+      state.status = message('status.hint', {
+        used: state.hintsUsed,
+        budget: state.hintBudget,
+        action: describeHintAction(message, hintAction),
+        x: target.x,
+        y: target.y,
+        time: target.t,
+        steps: remainingSteps,
+      })
+    },
+    /** Reveals what the neighbouring cell in a direction can see; no turn passes. */
+    peek(state, action: PayloadAction<Direction2D>) {
+      const message = statusTranslator(state)
+
+      if (state.phase !== 'Playing') {
+        state.status = message('status.peekNotPlaying')
+        return
+      }
+
+      if (state.peeksUsed >= state.peekBudget) {
+        state.status = message('status.noPeeks', { budget: state.peekBudget })
+        return
+      }
+
+      const player = currentPosition(state.worldLine)
+      const cube = current(state).cube
+      const origin = player ? peekOrigin(cube, player, action.payload) : null
+
+      if (!player || !origin) {
+        state.status = message('status.nothingToPeek', {
+          direction: message(`direction.${action.payload}`),
+        })
+        return
+      }
+
+      state.exploredByTime = recordExplored(state.exploredByTime, cube, origin, player.t)
+      state.peeksUsed += 1
+      state.status = message('status.peeked', {
+        used: state.peeksUsed,
+        budget: state.peekBudget,
+        direction: message(`direction.${action.payload}`),
+        x: origin.x,
+        y: origin.y,
+      })
+    },
+    /** Debug console: put the player anywhere in the cube, outside the rules; no turn passes. */
+    debugTeleport(state, action: PayloadAction<Position3D>) {
+      const message = statusTranslator(state)
+      const target = action.payload
+
+      if (
+        !isInBounds(target, state.boardWidth, state.boardHeight) ||
+        target.t < 0 ||
+        target.t >= state.timeDepth
+      ) {
+        state.status = message('status.debugOutside', { x: target.x, y: target.y, time: target.t })
+        return
+      }
+
+      const extended = extendViaRift(current(state).worldLine, target)
+
+      if (!extended.ok) {
+        state.status = message('status.debugTeleportFailed', { kind: extended.error.kind })
+        return
+      }
+
+      state.worldLine = extended.value
+      state.currentTime = target.t
+      recordPlayerView(state)
+      state.status = message('status.debugTeleported', { x: target.x, y: target.y, time: target.t })
+    },
+    /** Debug console: place a registry archetype into the cube from the given slice. */
+    debugSpawn(state, action: PayloadAction<{ archetype: string; position: Position3D }>) {
+      const message = statusTranslator(state)
+      const { archetype, position } = action.payload
+      const cube = current(state).cube
+      let serial = 1
+
+      while (cube.objectsById[`debug.${archetype}.${serial}`]) {
+        serial += 1
+      }
+
+      const id = `debug.${archetype}.${serial}`
+      const resolved = resolveObjectInstance(state.objectRegistry, { id, archetype, position })
+
+      if (!resolved.ok) {
+        state.status = message('status.debugUnknownArchetype', { archetype })
+        return
+      }
+
+      const placed = placeObjects(cube, [resolved.value])
+
+      if (!placed.ok) {
+        state.status = message('status.debugSpawnFailed', { kind: placed.error.kind })
+        return
+      }
+
+      state.cube = placed.value
+      state.status = message('status.debugSpawned', {
+        objectId: id,
+        x: position.x,
+        y: position.y,
+        time: position.t,
+      })
     },
     setStatus(state, action: PayloadAction<string>) {
       state.status = action.payload
     },
+    /** Language for status lines from here on; the current line keeps its language. */
+    setLocale(state, action: PayloadAction<Locale>) {
+      state.locale = action.payload
+    },
   },
 })
 
@@ -361,6 +864,16 @@ export const {
   applyRift,
   pushPlayer2D,
   pullPlayer2D,
+  timePushPlayer2D,
+  switchCharacter,
+  climb,
+  breakWall,
+  interact,
+  throwLure,
+  moveTo,
+  commitTurnPlan,
+  performInteraction,
+  setHotseat,
   configureRiftSettings,
   setInteractionConfig,
   configureDetectionConfig,
@@ -368,7 +881,13 @@ export const {
   setContentPackId,
   applyLoadedContent,
   restart,
+  resumeSession,
+  requestHint,
+  peek,
+  debugTeleport,
+  debugSpawn,
   setStatus,
+  setLocale,
 } = gameSlice.actions
 export const gameReducer = gameSlice.reducer
 
@@ -0,0 +1,83 @@
+import type { Position3D } from '../core/position'
+import type { Result } from '../core/result'
+import {
+  searchSolutionFromState,
+  type SolvabilitySearchOptions,
+} from '../data/generation/solver'
+import type { Translator } from '../render/i18n'
+import { executeRegisteredInteraction } from './interactions/registry'
+import type { InteractionAction, InteractionState } from './interactions/types'
+
+/** Next solver-suggested action and the cell it leads to. */
+export interface HintSuggestion {
+  action: InteractionAction
+  target: Position3D
+  remainingSteps: number
+}
+
+export type HintError =
+  | { kind: 'NoSolutionFound' }
+  | { kind: 'AlreadySolved' }
+
+export function computeHint(
+  state: InteractionState,
+  options: SolvabilitySearchOptions = {},
+): Result<HintSuggestion, HintError> {
+  const search = searchSolutionFromState(state, options)
+
+  if (!search.actions) {
+    return { ok: false, error: { kind: 'NoSolutionFound' } }
+  }
+
+  const [action] = search.actions
+
+  if (!action) {
+    return { ok: false, error: { kind: 'AlreadySolved' } }
+  }
+
+  const probe = structuredClone(state)
+  const result = executeRegisteredInteraction(probe, action)
+
+  if (!result.ok) {
+    return { ok: false, error: { kind: 'NoSolutionFound' } }
+  }
+
+  return {
+    ok: true,
+    value: {
+      action,
+      target: result.outcome.to,
+      remainingSteps: search.actions.length,
+    },
+  }
+}
+
+export function describeHintAction(message: Translator, action: InteractionAction): string {
+  switch (action.kind) {
+    case 'Move':
+    case 'Push':
+    case 'Pull':
+    case 'TimePush':
+    case 'Break':
+    case 'Interact':
+      return message(`action.${action.kind}`, {
+        direction: message(`direction.${action.direction}`),
+      })
+    case 'Throw':
+      return message('action.Throw', {
+        direction: message(`direction.${action.direction}`),
+        range: action.range,
+      })
+    case 'Wait':
+    case 'SwitchCharacter':
+    case 'Climb':
+    case 'ApplyRift':
+      return message(`action.${action.kind}`)
+    case 'MoveTo':
+      return message('action.MoveTo', {
+        x: action.target.x,
+        y: action.target.y,
+        time: action.target.t,
+      })
+  }
+}
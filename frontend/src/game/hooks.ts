@@ -1,6 +1,7 @@
-import { useDispatch, useSelector, type TypedUseSelectorHook } from 'react-redux'
+import { useDispatch, useSelector, useStore, type TypedUseSelectorHook } from 'react-redux'
 
-import type { AppDispatch, RootState } from './store'
+import type { AppDispatch, AppStore, RootState } from './store'
 
 export const useAppDispatch = useDispatch.withTypes<AppDispatch>()
 export const useAppSelector: TypedUseSelectorHook<RootState> = useSelector
+export const useAppStore = useStore.withTypes<AppStore>()
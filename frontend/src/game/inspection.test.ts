@@ -0,0 +1,77 @@
+import { describe, expect, it } from 'vitest'
+
+import type { ResolvedObjectInstance } from '../core/objects'
+import { createTimeCube, placeObjects } from '../core/timeCube'
+import { componentSummary, INSPECTION_SCHEDULE_SLICES, inspectCell } from './inspection'
+import { bootstrapLevelObjects } from './levelObjects'
+
+const rift: ResolvedObjectInstance = {
+  id: 'rift.a',
+  archetypeKey: 'rift',
+  position: { x: 1, y: 1, t: 0 },
+  archetype: {
+    kind: 'rift',
+    components: [
+      { kind: 'TimePersistent' },
+      { kind: 'Anchored' },
+      { kind: 'Rift', target: { x: 3, y: 2, t: 0 }, bidirectional: true },
+    ],
+    render: {},
+  },
+}
+
+describe('inspection', () => {
+  it('reports components and the rift target of what is under the cursor', () => {
+    const placed = placeObjects(createTimeCube(4, 4, 4), [rift])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    expect(inspectCell(placed.value, { x: 1, y: 1, t: 2 })).toEqual([
+      {
+        id: 'rift.a',
+        archetypeKind: 'rift',
+        components: rift.archetype.components,
+        schedule: [],
+        route: null,
+        rift: { target: { x: 3, y: 2, t: 0 }, bidirectional: true },
+      },
+    ])
+    expect(inspectCell(placed.value, { x: 2, y: 1, t: 2 })).toEqual([])
+  })
+
+  it('lists the next slices of a patrol from the examined slice', () => {
+    const booted = bootstrapLevelObjects(12, 12, 24)
+
+    expect(booted.ok).toBe(true)
+    if (!booted.ok) {
+      return
+    }
+
+    const [inspection] = inspectCell(booted.value.cube, { x: 2, y: 8, t: 0 })
+
+    expect(inspection.id).toBe('enemy.alpha')
+    expect(inspection.route).toEqual([
+      { x: 2, y: 8 },
+      { x: 3, y: 8 },
+      { x: 3, y: 9 },
+      { x: 2, y: 9 },
+    ])
+    expect(inspection.schedule).toHaveLength(INSPECTION_SCHEDULE_SLICES)
+    expect(inspection.schedule.slice(0, 3)).toEqual([
+      { x: 2, y: 8, t: 0 },
+      { x: 3, y: 8, t: 1 },
+      { x: 3, y: 9, t: 2 },
+    ])
+  })
+
+  it('summarizes components with their fields', () => {
+    expect(rift.archetype.components.map(componentSummary)).toEqual([
+      'TimePersistent',
+      'Anchored',
+      'Rift target=(3,2,t0) bidirectional=true',
+    ])
+  })
+})
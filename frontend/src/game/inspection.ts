@@ -0,0 +1,85 @@
+import type { Component, PatrolComponent, RiftComponent } from '../core/components'
+import type { Position3D } from '../core/position'
+import { objectPositionAt, objectsAt, type TimeCube } from '../core/timeCube'
+
+/** Slices of a patrol's upcoming schedule listed in the examine panel. */
+export const INSPECTION_SCHEDULE_SLICES = 6
+
+/** What the examine cursor reports about one object under it. */
+export interface EntityInspection {
+  id: string
+  archetypeKind: string
+  components: Component[]
+  /** Where the object stands from the examined slice on; empty unless it patrols. */
+  schedule: Position3D[]
+  /** Waypoints of its route, for patrols; null otherwise. */
+  route: PatrolComponent['path'] | null
+  rift: Pick<RiftComponent, 'target' | 'bidirectional'> | null
+}
+
+function formatValue(value: unknown): string {
+  if (Array.isArray(value)) {
+    return value.map(formatValue).join(' ')
+  }
+
+  if (typeof value === 'object' && value !== null) {
+    const { x, y, t } = value as Partial<Position3D>
+
+    if (typeof x === 'number' && typeof y === 'number') {
+      return typeof t === 'number' ? `(${x},${y},t${t})` : `(${x},${y})`
+    }
+
+    return JSON.stringify(value)
+  }
+
+  return String(value)
+}
+
+/** One line per component for the examine panel, e.g. `Breakable hits=2`. */
+export function componentSummary(component: Component): string {
+  const fields = Object.entries(component)
+    .filter(([key]) => key !== 'kind')
+    .map(([key, value]) => `${key}=${formatValue(value)}`)
+
+  return fields.length > 0 ? `${component.kind} ${fields.join(' ')}` : component.kind
+}
+
+function upcomingPositions(cube: TimeCube, id: string, fromTime: number): Position3D[] {
+  const schedule: Position3D[] = []
+  const lastTime = Math.min(cube.timeDepth, fromTime + INSPECTION_SCHEDULE_SLICES)
+
+  for (let t = fromTime; t < lastTime; t += 1) {
+    const position = objectPositionAt(cube, id, t)
+
+    if (position) {
+      schedule.push(position)
+    }
+  }
+
+  return schedule
+}
+
+/**
+ * Everything in the cell under the cursor in slice `position.t`: components, the slices ahead of
+ * a patrol (as the cube has them, detours and lures included) and where a rift leads.
+ */
+export function inspectCell(cube: TimeCube, position: Position3D): EntityInspection[] {
+  return objectsAt(cube, position).map((object) => {
+    const components = object.archetype.components
+    const patrol = components.find(
+      (component): component is PatrolComponent => component.kind === 'Patrol',
+    )
+    const rift = components.find(
+      (component): component is RiftComponent => component.kind === 'Rift',
+    )
+
+    return {
+      id: object.id,
+      archetypeKind: object.archetype.kind,
+      components,
+      schedule: patrol ? upcomingPositions(cube, object.id, position.t) : [],
+      route: patrol ? patrol.path : null,
+      rift: rift ? { target: rift.target, bidirectional: rift.bidirectional } : null,
+    }
+  })
+}
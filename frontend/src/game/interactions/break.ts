@@ -0,0 +1,170 @@
+import type { BreakableComponent } from '../../core/components'
+import { currentLayer } from '../../core/elevation'
+import { groupMembersAt, groupOf } from '../../core/groups'
+import { movePosition, wrapPosition } from '../../core/position'
+import { objectsAt, removeObjectFromTime, wrappingOf } from '../../core/timeCube'
+import {
+  blockingObjectsAt,
+  extendWorldLineOrError,
+  refuseAnchored,
+  statusTranslator,
+  waitStep,
+} from './common'
+import type { InteractionHandler } from './types'
+
+/**
+ * Strike the breakable object next to the player; like a wait, the player holds their cell for
+ * one slice. Every blow is a noise guards may hear, and the last one removes the object from that
+ * slice onward, along with the rest of its group.
+ */
+export const breakInteractionHandler: InteractionHandler<'Break'> = {
+  kind: 'Break',
+  execute(state, action) {
+    const message = statusTranslator(state)
+
+    if (currentLayer(state.worldLine) !== 'ground') {
+      return {
+        ok: false,
+        error: { kind: 'NotOnGround' },
+        status: message('interaction.offGroundWalls'),
+      }
+    }
+
+    const wrapping = wrappingOf(state.cube)
+    const step = waitStep(state.worldLine, state.timeDepth, wrapping)
+
+    if (!step.ok) {
+      switch (step.error.kind) {
+        case 'TimeBoundary':
+          return { ok: false, error: step.error, status: message('interaction.timeBoundary') }
+        default:
+          return {
+            ok: false,
+            error: step.error,
+            status: message('interaction.internalError', { action: 'Break' }),
+          }
+      }
+    }
+
+    if (blockingObjectsAt(state.cube, step.value.next).length > 0) {
+      return {
+        ok: false,
+        error: { kind: 'BlockedByObject' },
+        status: message('interaction.blockedByObject'),
+      }
+    }
+
+    const cell = wrapPosition(movePosition(step.value.next, action.direction), wrapping)
+    const at = { x: cell.x, y: cell.y, t: step.value.next.t }
+    let target: { id: string; breakable: BreakableComponent; group: string | null } | null = null
+
+    for (const object of objectsAt(state.cube, at)) {
+      const breakable = object.archetype.components.find(
+        (component): component is BreakableComponent => component.kind === 'Breakable',
+      )
+
+      if (breakable) {
+        target = { id: object.id, breakable, group: groupOf(object.archetype.components) }
+        break
+      }
+    }
+
+    if (!target) {
+      return {
+        ok: false,
+        error: { kind: 'NothingToBreak' },
+        status: message('interaction.nothingToBreak'),
+      }
+    }
+
+    const targetId = target.id
+    const linkedIds = target.group
+      ? groupMembersAt(state.cube, target.group, at.t)
+          .map((member) => member.id)
+          .filter((id) => id !== targetId)
+      : []
+    const anchored = refuseAnchored(state, [target.id, ...linkedIds], at.t)
+
+    if (anchored) {
+      return anchored
+    }
+
+    const worldLineResult = extendWorldLineOrError(
+      state.worldLine,
+      step.value.next,
+      'Normal',
+      wrapping,
+    )
+
+    if (!worldLineResult.ok) {
+      return {
+        ok: false,
+        error: worldLineResult.error,
+        status:
+          worldLineResult.error.kind === 'SelfIntersection'
+            ? message('interaction.selfIntersection')
+            : message('interaction.invalidStep', { action: 'Break' }),
+      }
+    }
+
+    const hits = (state.breakHits[target.id] ?? 0) + 1
+    const destroyed = hits >= target.breakable.hits
+
+    if (destroyed) {
+      let cube = state.cube
+
+      for (const id of [target.id, ...linkedIds]) {
+        const removed = removeObjectFromTime(cube, id, at.t)
+
+        if (!removed.ok) {
+          return {
+            ok: false,
+            error: { kind: 'Internal', message: removed.error.kind },
+            status: message('interaction.internalError', { action: 'Break' }),
+          }
+        }
+
+        cube = removed.value
+      }
+
+      state.cube = cube
+    }
+
+    state.worldLine = worldLineResult.value
+    state.breakHits = { ...state.breakHits, [target.id]: hits }
+    state.noises = [
+      ...state.noises,
+      {
+        id: `noise.${state.noises.length}`,
+        position: at,
+        radius: state.interactionConfig.breakNoiseRadius,
+      },
+    ]
+
+    return {
+      ok: true,
+      outcome: {
+        kind: 'Broke',
+        to: step.value.next,
+        objectId: target.id,
+        at,
+        destroyed,
+        ...(destroyed && linkedIds.length > 0 ? { linkedIds } : {}),
+      },
+      status: !destroyed
+        ? message('interaction.hit', {
+            objectId: target.id,
+            hits,
+            total: target.breakable.hits,
+            time: at.t,
+          })
+        : linkedIds.length > 0
+          ? message('interaction.brokeLinked', {
+              objectId: target.id,
+              count: linkedIds.length,
+              time: at.t,
+            })
+          : message('interaction.broke', { objectId: target.id, time: at.t }),
+    }
+  },
+}
@@ -0,0 +1,72 @@
+import { currentLayer, hasLadderAt } from '../../core/elevation'
+import { wrappingOf } from '../../core/timeCube'
+import { blockingObjectsAt, extendWorldLineOrError, statusTranslator, waitStep } from './common'
+import type { InteractionHandler } from './types'
+
+/** Go up or down the ladder under the player; like a wait, it takes one slice in place. */
+export const climbInteractionHandler: InteractionHandler<'Climb'> = {
+  kind: 'Climb',
+  execute(state) {
+    const message = statusTranslator(state)
+
+    const step = waitStep(state.worldLine, state.timeDepth, wrappingOf(state.cube))
+
+    if (!step.ok) {
+      switch (step.error.kind) {
+        case 'TimeBoundary':
+          return { ok: false, error: step.error, status: message('interaction.timeBoundary') }
+        default:
+          return {
+            ok: false,
+            error: step.error,
+            status: message('interaction.internalError', { action: 'Climb' }),
+          }
+      }
+    }
+
+    if (
+      !hasLadderAt(state.cube, step.value.current) ||
+      !hasLadderAt(state.cube, step.value.next)
+    ) {
+      return { ok: false, error: { kind: 'NoLadder' }, status: message('interaction.noLadder') }
+    }
+
+    const layer = currentLayer(state.worldLine) === 'ground' ? 'catwalk' : 'ground'
+
+    if (layer === 'ground' && blockingObjectsAt(state.cube, step.value.next).length > 0) {
+      return {
+        ok: false,
+        error: { kind: 'BlockedByObject' },
+        status: message('interaction.blockedByObject'),
+      }
+    }
+
+    const worldLineResult = extendWorldLineOrError(
+      state.worldLine,
+      step.value.next,
+      'Climb',
+      wrappingOf(state.cube),
+    )
+
+    if (!worldLineResult.ok) {
+      return {
+        ok: false,
+        error: worldLineResult.error,
+        status:
+          worldLineResult.error.kind === 'SelfIntersection'
+            ? message('interaction.selfIntersection')
+            : message('interaction.invalidStep', { action: 'Climb' }),
+      }
+    }
+
+    state.worldLine = worldLineResult.value
+
+    return {
+      ok: true,
+      outcome: { kind: 'Climbed', to: step.value.next, layer },
+      status: message(layer === 'catwalk' ? 'interaction.climbedUp' : 'interaction.climbedDown', {
+        time: step.value.next.t,
+      }),
+    }
+  },
+}
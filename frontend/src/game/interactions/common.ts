@@ -1,8 +1,29 @@
+import { blocksMovementAt } from '../../core/alarm'
 import { hasComponent } from '../../core/components'
-import { isInBounds, movePosition, type Direction2D, type Position2D, type Position3D } from '../../core/position'
-import { currentPosition, extendNormal, type WorldLineState } from '../../core/worldLine'
-import { objectsAt, type TimeCube } from '../../core/timeCube'
-import type { InteractionResult } from './types'
+import { currentLayer } from '../../core/elevation'
+import {
+  isInBounds,
+  movePosition,
+  NO_WRAPPING,
+  wrappedAxisDelta,
+  wrapPosition,
+  type Direction2D,
+  type Position2D,
+  type Position3D,
+  type Wrapping,
+} from '../../core/position'
+import {
+  currentPosition,
+  extendNormal,
+  followingTime,
+  maxTime,
+  wouldIntersect,
+  type NormalStepKind,
+  type WorldLineState,
+} from '../../core/worldLine'
+import { hasExit, objectsAt, wrappingOf, type TimeCube } from '../../core/timeCube'
+import { createTranslator, DEFAULT_LOCALE, type Translator } from '../../render/i18n'
+import type { InteractionHandlerResult, InteractionResult, InteractionState } from './types'
 
 export function oppositeDirection(direction: Direction2D): Direction2D {
   switch (direction) {
@@ -23,6 +44,7 @@ export function nextNormalStep(
   boardHeight: number,
   timeDepth: number,
   direction: Direction2D,
+  wrapping: Wrapping = NO_WRAPPING,
 ): InteractionResult<{ current: Position3D; next: Position3D }> {
   const current = currentPosition(worldLine)
 
@@ -30,13 +52,13 @@ export function nextNormalStep(
     return { ok: false, error: { kind: 'Internal', message: 'Empty world line' } }
   }
 
-  const spatial = movePosition(current, direction)
+  const spatial = wrapPosition(movePosition(current, direction), wrapping)
 
   if (!isInBounds(spatial, boardWidth, boardHeight)) {
     return { ok: false, error: { kind: 'OutOfBounds' } }
   }
 
-  const nextTime = current.t + 1
+  const nextTime = followingTime(current.t, wrapping.timeDepth)
 
   if (nextTime >= timeDepth) {
     return { ok: false, error: { kind: 'TimeBoundary' } }
@@ -51,9 +73,44 @@ export function nextNormalStep(
   }
 }
 
+/** Direction of the last world-line step if it was a normal one-slice move, otherwise null. */
+export function lastStepDirection(
+  worldLine: WorldLineState,
+  wrapping: Wrapping = NO_WRAPPING,
+): Direction2D | null {
+  const current = worldLine.path.at(-1)
+  const previous = worldLine.path.at(-2)
+
+  if (!current || !previous || current.t !== followingTime(previous.t, wrapping.timeDepth)) {
+    return null
+  }
+
+  const dx = wrappedAxisDelta(previous.x, current.x, wrapping.width)
+  const dy = wrappedAxisDelta(previous.y, current.y, wrapping.height)
+
+  if (dx === 0 && dy === -1) {
+    return 'north'
+  }
+
+  if (dx === 0 && dy === 1) {
+    return 'south'
+  }
+
+  if (dx === 1 && dy === 0) {
+    return 'east'
+  }
+
+  if (dx === -1 && dy === 0) {
+    return 'west'
+  }
+
+  return null
+}
+
 export function waitStep(
   worldLine: WorldLineState,
   timeDepth: number,
+  wrapping: Wrapping = NO_WRAPPING,
 ): InteractionResult<{ current: Position3D; next: Position3D }> {
   const current = currentPosition(worldLine)
 
@@ -61,7 +118,7 @@ export function waitStep(
     return { ok: false, error: { kind: 'Internal', message: 'Empty world line' } }
   }
 
-  const nextTime = current.t + 1
+  const nextTime = followingTime(current.t, wrapping.timeDepth)
 
   if (nextTime >= timeDepth) {
     return { ok: false, error: { kind: 'TimeBoundary' } }
@@ -79,8 +136,10 @@ export function waitStep(
 export function extendWorldLineOrError(
   worldLine: WorldLineState,
   next: Position3D,
+  kind: NormalStepKind = 'Normal',
+  wrapping: Wrapping = NO_WRAPPING,
 ): InteractionResult<WorldLineState> {
-  const result = extendNormal(worldLine, next)
+  const result = extendNormal(worldLine, next, kind, wrapping)
 
   if (!result.ok) {
     if (result.error.kind === 'SelfIntersection') {
@@ -93,12 +152,96 @@ export function extendWorldLineOrError(
   return { ok: true, value: result.value }
 }
 
+/** Status lines come from the message catalog, in the language the shell set on the state. */
+export function statusTranslator(state: Pick<InteractionState, 'locale'>): Translator {
+  return createTranslator(state.locale ?? DEFAULT_LOCALE)
+}
+
+/** Boxes sit on the ground: push, pull and time push are refused while up on a catwalk. */
+export function refuseOffGround(state: InteractionState): InteractionHandlerResult | null {
+  return currentLayer(state.worldLine) === 'ground'
+    ? null
+    : {
+        ok: false,
+        error: { kind: 'NotOnGround' },
+        status: statusTranslator(state)('interaction.offGroundBoxes'),
+      }
+}
+
+/**
+ * Refuses touching an `Anchored` object in slice `t` when that slice is one the player already
+ * lived through, i.e. when acting from the past after a rift. Pass `always` for time effects that
+ * anchored objects never take, whatever the slice.
+ */
+export function refuseAnchored(
+  state: InteractionState,
+  objectIds: string[],
+  t: number,
+  always = false,
+): InteractionHandlerResult | null {
+  if (!always && t > (maxTime(state.worldLine) ?? -1)) {
+    return null
+  }
+
+  const anchoredId = objectIds.find((id) => {
+    const object = state.cube.objectsById[id]
+    return object ? hasComponent(object.archetype.components, 'Anchored') : false
+  })
+
+  return anchoredId
+    ? {
+        ok: false,
+        error: { kind: 'AnchoredInTime', objectId: anchoredId },
+        status: statusTranslator(state)('interaction.anchored', { objectId: anchoredId }),
+      }
+    : null
+}
+
 export function blockingObjectsAt(cube: TimeCube, position: Position3D) {
-  return objectsAt(cube, position).filter((object) =>
-    hasComponent(object.archetype.components, 'BlocksMovement'),
+  return objectsAt(cube, position).filter((object) => blocksMovementAt(cube, object, position.t))
+}
+
+export function isSlipperyAt(cube: TimeCube, position: Position3D): boolean {
+  return objectsAt(cube, position).some((object) =>
+    hasComponent(object.archetype.components, 'Slippery'),
   )
 }
 
+/**
+ * Forced follow-up cells after entering `from` while moving in `direction`: one cell per slice for
+ * as long as the current cell is slippery (and not an exit) and the next cell is in bounds, before
+ * the last slice (unless time wraps), unblocked and off the player's world line.
+ */
+export function slidePath(
+  cube: TimeCube,
+  worldLine: WorldLineState,
+  from: Position3D,
+  direction: Direction2D,
+): Position3D[] {
+  const path: Position3D[] = []
+  const wrapping = wrappingOf(cube)
+  let cursor = from
+
+  while (isSlipperyAt(cube, cursor) && !hasExit(cube, cursor)) {
+    const spatial = wrapPosition(movePosition(cursor, direction), wrapping)
+    const next = { x: spatial.x, y: spatial.y, t: followingTime(cursor.t, wrapping.timeDepth) }
+
+    if (
+      !isInBounds(spatial, cube.width, cube.height) ||
+      next.t >= cube.timeDepth ||
+      blockingObjectsAt(cube, next).length > 0 ||
+      wouldIntersect(worldLine, next)
+    ) {
+      break
+    }
+
+    path.push(next)
+    cursor = next
+  }
+
+  return path
+}
+
 export function firstObjectIdAt(cube: TimeCube, position: Position3D): string | undefined {
   return objectsAt(cube, position)[0]?.id
 }
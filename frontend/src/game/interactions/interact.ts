@@ -0,0 +1,179 @@
+import { interactableOf, type InteractionKind } from '../../core/components'
+import { evaluateDetectionV1 } from '../../core/detection'
+import { currentLayer } from '../../core/elevation'
+import { isKnockable, knockOut } from '../../core/knockout'
+import { movePosition, wrapPosition, type Position3D } from '../../core/position'
+import { objectsAt, wrappingOf } from '../../core/timeCube'
+import { blockingObjectsAt, extendWorldLineOrError, statusTranslator, waitStep } from './common'
+import type { InteractionHandler, InteractionHandlerResult, InteractionState } from './types'
+
+/** Knocks out the guard at `at`; one that sees the player this turn is not taken unaware. */
+function knockOutGuard(
+  state: InteractionState,
+  enemyId: string,
+  to: Position3D,
+  at: Position3D,
+): InteractionHandlerResult {
+  const message = statusTranslator(state)
+
+  const worldLineResult = extendWorldLineOrError(
+    state.worldLine,
+    to,
+    'Normal',
+    wrappingOf(state.cube),
+  )
+
+  if (!worldLineResult.ok) {
+    return {
+      ok: false,
+      error: worldLineResult.error,
+      status:
+        worldLineResult.error.kind === 'SelfIntersection'
+          ? message('interaction.selfIntersection')
+          : message('interaction.invalidStep', { action: 'Interact' }),
+    }
+  }
+
+  const seen = evaluateDetectionV1({
+    cube: state.cube,
+    worldLine: worldLineResult.value,
+    currentTime: to.t,
+    config: state.detectionConfig,
+    configByEnemyId: state.enemyDetectionConfigById,
+  })
+
+  if (seen.events.some((event) => event.enemyId === enemyId)) {
+    return {
+      ok: false,
+      error: { kind: 'GuardAware', enemyId },
+      status: message('interaction.guardAware', { enemyId }),
+    }
+  }
+
+  const knocked = knockOut(state.cube, enemyId, at.t)
+
+  if (!knocked.ok) {
+    return knocked.error.kind === 'TargetOccupied'
+      ? {
+          ok: false,
+          error: { kind: 'BlockedByObject', objectId: knocked.error.id },
+          status: message('interaction.noRoomForBody'),
+        }
+      : {
+          ok: false,
+          error: { kind: 'Internal', message: knocked.error.kind },
+          status: message('interaction.internalError', { action: 'Interact' }),
+        }
+  }
+
+  state.cube = knocked.value.cube
+  state.worldLine = worldLineResult.value
+
+  return {
+    ok: true,
+    outcome: { kind: 'KnockedOut', to, enemyId, bodyId: knocked.value.bodyId, at },
+    status: message('interaction.knockedOut', { enemyId }),
+  }
+}
+
+/**
+ * Use the interactable next to the player; like a wait, the player holds their cell for one
+ * slice. The handler only finds the target; what it does is up to the trigger for its kind,
+ * which the pipeline fires once the turn is staged. On levels with knockouts, a guard next to
+ * the player is knocked out instead.
+ */
+export const interactInteractionHandler: InteractionHandler<'Interact'> = {
+  kind: 'Interact',
+  execute(state, action) {
+    const message = statusTranslator(state)
+
+    if (currentLayer(state.worldLine) !== 'ground') {
+      return {
+        ok: false,
+        error: { kind: 'NotOnGround' },
+        status: message('interaction.offGroundAnything'),
+      }
+    }
+
+    const wrapping = wrappingOf(state.cube)
+    const step = waitStep(state.worldLine, state.timeDepth, wrapping)
+
+    if (!step.ok) {
+      switch (step.error.kind) {
+        case 'TimeBoundary':
+          return { ok: false, error: step.error, status: message('interaction.timeBoundary') }
+        default:
+          return {
+            ok: false,
+            error: step.error,
+            status: message('interaction.internalError', { action: 'Interact' }),
+          }
+      }
+    }
+
+    if (blockingObjectsAt(state.cube, step.value.next).length > 0) {
+      return {
+        ok: false,
+        error: { kind: 'BlockedByObject' },
+        status: message('interaction.blockedByObject'),
+      }
+    }
+
+    const cell = wrapPosition(movePosition(step.value.next, action.direction), wrapping)
+    const at = { x: cell.x, y: cell.y, t: step.value.next.t }
+    let target: { id: string; interaction: InteractionKind } | null = null
+
+    for (const object of objectsAt(state.cube, at)) {
+      const interactable = interactableOf(object.archetype.components)
+
+      if (interactable) {
+        target = { id: object.id, interaction: interactable.interaction }
+        break
+      }
+    }
+
+    if (!target) {
+      const guard = state.knockoutConfig ? objectsAt(state.cube, at).find(isKnockable) : undefined
+
+      return guard
+        ? knockOutGuard(state, guard.id, step.value.next, at)
+        : {
+            ok: false,
+            error: { kind: 'NothingToInteract' },
+            status: message('interaction.nothingToUse'),
+          }
+    }
+
+    const worldLineResult = extendWorldLineOrError(
+      state.worldLine,
+      step.value.next,
+      'Normal',
+      wrapping,
+    )
+
+    if (!worldLineResult.ok) {
+      return {
+        ok: false,
+        error: worldLineResult.error,
+        status:
+          worldLineResult.error.kind === 'SelfIntersection'
+            ? message('interaction.selfIntersection')
+            : message('interaction.invalidStep', { action: 'Interact' }),
+      }
+    }
+
+    state.worldLine = worldLineResult.value
+
+    return {
+      ok: true,
+      outcome: {
+        kind: 'Interacted',
+        to: step.value.next,
+        objectId: target.id,
+        interaction: target.interaction,
+        at,
+      },
+      status: message('interaction.used', { objectId: target.id }),
+    }
+  },
+}
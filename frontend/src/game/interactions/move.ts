@@ -1,37 +1,64 @@
-import { firstObjectIdAt, nextNormalStep, blockingObjectsAt, extendWorldLineOrError } from './common'
+import { catwalkAt, currentLayer } from '../../core/elevation'
+import { wrappingOf } from '../../core/timeCube'
+import {
+  firstObjectIdAt,
+  nextNormalStep,
+  blockingObjectsAt,
+  extendWorldLineOrError,
+  slidePath,
+  statusTranslator,
+} from './common'
 import type { InteractionHandler } from './types'
 
 export const moveInteractionHandler: InteractionHandler<'Move'> = {
   kind: 'Move',
   execute(state, action) {
+    const message = statusTranslator(state)
     const step = nextNormalStep(
       state.worldLine,
       state.boardWidth,
       state.boardHeight,
       state.timeDepth,
       action.direction,
+      wrappingOf(state.cube),
     )
 
     if (!step.ok) {
       switch (step.error.kind) {
         case 'OutOfBounds':
-          return { ok: false, error: step.error, status: 'Blocked by boundary' }
+          return { ok: false, error: step.error, status: message('interaction.outOfBounds') }
         case 'TimeBoundary':
-          return { ok: false, error: step.error, status: 'Blocked by time boundary' }
+          return { ok: false, error: step.error, status: message('interaction.timeBoundary') }
         default:
-          return { ok: false, error: step.error, status: 'Internal movement error' }
+          return {
+            ok: false,
+            error: step.error,
+            status: message('interaction.internalError', { action: 'Move' }),
+          }
       }
     }
 
-    if (blockingObjectsAt(state.cube, step.value.next).length > 0) {
+    // Up on the catwalk only the walkway matters; whatever stands on the ground passes beneath.
+    const elevated = currentLayer(state.worldLine) === 'catwalk'
+
+    if (elevated && !catwalkAt(state.cube, step.value.next)) {
+      return { ok: false, error: { kind: 'NoCatwalk' }, status: message('interaction.noCatwalk') }
+    }
+
+    if (!elevated && blockingObjectsAt(state.cube, step.value.next).length > 0) {
       return {
         ok: false,
         error: { kind: 'BlockedByObject', objectId: firstObjectIdAt(state.cube, step.value.next) },
-        status: 'Blocked by object',
+        status: message('interaction.blockedByObject'),
       }
     }
 
-    const worldLineResult = extendWorldLineOrError(state.worldLine, step.value.next)
+    const worldLineResult = extendWorldLineOrError(
+      state.worldLine,
+      step.value.next,
+      'Normal',
+      wrappingOf(state.cube),
+    )
 
     if (!worldLineResult.ok) {
       return {
@@ -39,17 +66,50 @@ export const moveInteractionHandler: InteractionHandler<'Move'> = {
         error: worldLineResult.error,
         status:
           worldLineResult.error.kind === 'SelfIntersection'
-            ? 'Blocked by self-intersection'
-            : 'Invalid move',
+            ? message('interaction.selfIntersection')
+            : message('interaction.invalidStep', { action: 'Move' }),
+      }
+    }
+
+    let worldLine = worldLineResult.value
+    const slide = elevated
+      ? []
+      : slidePath(state.cube, worldLine, step.value.next, action.direction)
+
+    for (const position of slide) {
+      const slid = extendWorldLineOrError(worldLine, position, 'Slide', wrappingOf(state.cube))
+
+      if (!slid.ok) {
+        return {
+          ok: false,
+          error: slid.error,
+          status: message('interaction.invalidStep', { action: 'Move' }),
+        }
       }
+
+      worldLine = slid.value
     }
 
-    state.worldLine = worldLineResult.value
+    state.worldLine = worldLine
+
+    if (slide.length > 0) {
+      const to = slide[slide.length - 1]
+
+      return {
+        ok: true,
+        outcome: { kind: 'Moved', to, slidThrough: [step.value.next, ...slide.slice(0, -1)] },
+        status: message('interaction.slid', { x: to.x, y: to.y, time: to.t }),
+      }
+    }
 
     return {
       ok: true,
       outcome: { kind: 'Moved', to: step.value.next },
-      status: `move to (${step.value.next.x}, ${step.value.next.y}, t=${step.value.next.t})`,
+      status: message('interaction.moved', {
+        x: step.value.next.x,
+        y: step.value.next.y,
+        time: step.value.next.t,
+      }),
     }
   },
 }
@@ -0,0 +1,179 @@
+import {
+  isInBounds,
+  movePosition,
+  wrapPosition,
+  type Direction2D,
+  type Position3D,
+} from '../../core/position'
+import type { Result } from '../../core/result'
+import { wrappingOf } from '../../core/timeCube'
+import type { Translator } from '../../render/i18n'
+import { currentPosition, positionKey, wouldIntersect } from '../../core/worldLine'
+import { blockingObjectsAt, statusTranslator } from './common'
+import { moveInteractionHandler } from './move'
+import type { GamePhase, InteractionAction, InteractionHandler, InteractionState } from './types'
+import { waitInteractionHandler } from './wait'
+
+export type MoveToError =
+  | { kind: 'OutsideHorizon'; target: Position3D }
+  | { kind: 'NoPath'; target: Position3D }
+  | { kind: 'HotseatActive' }
+  | { kind: 'StepRejected'; step: number; action: InteractionAction; status: string }
+  | { kind: 'WouldEnd'; step: number; phase: GamePhase; status: string }
+  | { kind: 'Diverged'; step: number; expected: Position3D; actual: Position3D | null }
+
+const STEP_DIRECTIONS: Direction2D[] = ['north', 'east', 'south', 'west']
+
+type StepAction = Extract<InteractionAction, { kind: 'Move' | 'Wait' }>
+
+export interface PlannedStep {
+  action: StepAction
+  to: Position3D
+}
+
+/**
+ * Shortest run of Move/Wait steps from the player to `target`, one slice per step, avoiding
+ * blocking objects and the player's own world line. A target in the player's own slice stands
+ * for that cell in the earliest later slice that reaches it. Only the cube as it is now is
+ * consulted; `rehearseMoveTo` catches what the plan cannot see (slides, chasers, detection).
+ */
+export function planMoveTo(
+  state: InteractionState,
+  target: Position3D,
+): Result<PlannedStep[], MoveToError> {
+  const start = currentPosition(state.worldLine)
+
+  if (
+    !start ||
+    target.t < start.t ||
+    target.t >= state.timeDepth ||
+    !isInBounds(target, state.boardWidth, state.boardHeight)
+  ) {
+    return { ok: false, error: { kind: 'OutsideHorizon', target } }
+  }
+
+  const sameSlice = target.t === start.t
+
+  if (sameSlice && target.x === start.x && target.y === start.y) {
+    return { ok: true, value: [] }
+  }
+
+  const lastTime = sameSlice ? state.timeDepth - 1 : target.t
+  const isGoal = (position: Position3D) =>
+    position.x === target.x && position.y === target.y && (sameSlice || position.t === target.t)
+  const wrapping = wrappingOf(state.cube)
+  const parents = new Map<string, { from: string; step: PlannedStep }>()
+  let frontier: Position3D[] = [start]
+  let arrival: Position3D | null = null
+
+  for (let t = start.t; t < lastTime && frontier.length > 0 && !arrival; t += 1) {
+    const next: Position3D[] = []
+
+    for (const from of frontier) {
+      const options: PlannedStep[] = [
+        ...STEP_DIRECTIONS.map((direction): PlannedStep => ({
+          action: { kind: 'Move', direction },
+          to: { ...wrapPosition(movePosition(from, direction), wrapping), t: t + 1 },
+        })),
+        { action: { kind: 'Wait' }, to: { x: from.x, y: from.y, t: t + 1 } },
+      ]
+
+      for (const option of options) {
+        const key = positionKey(option.to)
+
+        if (
+          parents.has(key) ||
+          !isInBounds(option.to, state.boardWidth, state.boardHeight) ||
+          wouldIntersect(state.worldLine, option.to) ||
+          blockingObjectsAt(state.cube, option.to).length > 0
+        ) {
+          continue
+        }
+
+        parents.set(key, { from: positionKey(from), step: option })
+        next.push(option.to)
+        arrival = arrival ?? (isGoal(option.to) ? option.to : null)
+      }
+    }
+
+    frontier = next
+  }
+
+  if (!arrival) {
+    return { ok: false, error: { kind: 'NoPath', target } }
+  }
+
+  const steps: PlannedStep[] = []
+  let key = positionKey(arrival)
+
+  while (key !== positionKey(start)) {
+    const parent = parents.get(key)
+
+    if (!parent) {
+      return { ok: false, error: { kind: 'NoPath', target } }
+    }
+
+    steps.unshift(parent.step)
+    key = parent.from
+  }
+
+  return { ok: true, value: steps }
+}
+
+/** One turn of a MoveTo: the first Move or Wait of the current plan toward `target`. */
+export const moveToInteractionHandler: InteractionHandler<'MoveTo'> = {
+  kind: 'MoveTo',
+  execute(state, action) {
+    const message = statusTranslator(state)
+    const plan = planMoveTo(state, action.target)
+
+    if (!plan.ok) {
+      return {
+        ok: false,
+        error: { kind: 'NoPath', target: action.target },
+        status: message('interaction.moveTo', { reason: formatMoveToError(message, plan.error) }),
+      }
+    }
+
+    const [step] = plan.value
+
+    if (!step) {
+      return {
+        ok: false,
+        error: { kind: 'NoPath', target: action.target },
+        status: message('interaction.moveToArrived'),
+      }
+    }
+
+    return step.action.kind === 'Move'
+      ? moveInteractionHandler.execute(state, step.action)
+      : waitInteractionHandler.execute(state, step.action)
+  },
+}
+
+export function formatMoveToError(message: Translator, error: MoveToError): string {
+  switch (error.kind) {
+    case 'OutsideHorizon':
+    case 'NoPath':
+      return message(error.kind === 'NoPath' ? 'moveTo.noPath' : 'moveTo.outsideHorizon', {
+        x: error.target.x,
+        y: error.target.y,
+        time: error.target.t,
+      })
+    case 'HotseatActive':
+      return message('moveTo.hotseat')
+    case 'StepRejected':
+      return message('moveTo.stepRejected', { step: error.step + 1, status: error.status })
+    case 'WouldEnd':
+      return message('moveTo.wouldEnd', { step: error.step + 1, status: error.status })
+    case 'Diverged':
+      return message('moveTo.diverged', {
+        step: error.step + 1,
+        actual: error.actual
+          ? message('moveTo.cell', { x: error.actual.x, y: error.actual.y })
+          : message('moveTo.nowhere'),
+        x: error.expected.x,
+        y: error.expected.y,
+      })
+  }
+}
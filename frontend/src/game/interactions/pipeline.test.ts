@@ -0,0 +1,109 @@
+import { afterEach, describe, expect, it, vi } from 'vitest'
+
+import { gameReducer, performInteraction, type GameState } from '../gameSlice'
+import { interactionRegistry } from './registry'
+
+const chaseFailure = vi.hoisted(() => ({ enabled: false }))
+
+vi.mock('../../core/chase', async (importOriginal) => {
+  const actual = await importOriginal<typeof import('../../core/chase')>()
+
+  return {
+    ...actual,
+    propagateChasers: (input: Parameters<typeof actual.propagateChasers>[0]) =>
+      chaseFailure.enabled
+        ? { ok: false as const, error: { kind: 'EntityNotFound' as const, id: 'enemy.alpha' } }
+        : actual.propagateChasers(input),
+  }
+})
+
+function turnState(state: GameState) {
+  return {
+    cube: state.cube,
+    worldLine: state.worldLine,
+    lures: state.lures,
+    turn: state.turn,
+    currentTime: state.currentTime,
+    history: state.history,
+    causalAnchors: state.causalAnchors,
+  }
+}
+
+describe('runInteractionPipeline', () => {
+  afterEach(() => {
+    chaseFailure.enabled = false
+    vi.restoreAllMocks()
+  })
+
+  it('drops the edits of a handler that refuses halfway', () => {
+    const before = gameReducer(undefined, { type: 'init' })
+
+    vi.spyOn(interactionRegistry.Wait, 'execute').mockImplementation((state) => {
+      state.cube = { ...state.cube, objectsById: {} }
+      state.worldLine = { ...state.worldLine, path: [] }
+      state.lures = { ...state.lures, remaining: 0 }
+
+      return {
+        ok: false,
+        error: { kind: 'Internal', message: 'injected' },
+        status: 'Injected refusal',
+      }
+    })
+
+    const after = gameReducer(before, performInteraction({ kind: 'Wait' }))
+
+    expect(after.status).toBe('Injected refusal')
+    expect(turnState(after)).toEqual(turnState(before))
+  })
+
+  it('leaves the state as it was when propagation fails after the action', () => {
+    const before = gameReducer(undefined, { type: 'init' })
+
+    chaseFailure.enabled = true
+    const after = gameReducer(before, performInteraction({ kind: 'Move', direction: 'east' }))
+
+    expect(after.status).toBe('Internal error: chaser propagation failed (EntityNotFound)')
+    expect(turnState(after)).toEqual(turnState(before))
+    expect(after.worldLine.path).toHaveLength(1)
+  })
+
+  it('commits the handler edits and the propagated cube together', () => {
+    const before = gameReducer(undefined, { type: 'init' })
+    const after = gameReducer(before, performInteraction({ kind: 'Move', direction: 'east' }))
+
+    expect(after.turn).toBe(1)
+    expect(after.worldLine.path.at(-1)).toEqual({ x: 6, y: 5, t: 1 })
+    expect(after.history).toHaveLength(1)
+  })
+
+  it('checks anchors in slices a push on a time-loop level wraps round into', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    let state: GameState = {
+      ...initial,
+      cube: { ...initial.cube, timeWraps: true },
+      causalAnchors: [
+        {
+          id: 'seed-object-anchor',
+          requirement: {
+            kind: 'ObjectAt',
+            objectId: 'box.main',
+            position: { x: 8, y: 6, t: 1 },
+            sourceTurn: 0,
+          },
+        },
+      ],
+    }
+
+    for (const direction of ['east', 'east', 'south'] as const) {
+      state = gameReducer(state, performInteraction({ kind: 'Move', direction }))
+    }
+
+    expect(state.phase).toBe('Playing')
+
+    const pushed = gameReducer(state, performInteraction({ kind: 'Push', direction: 'east' }))
+
+    expect(pushed.history.at(-1)?.affectedFromTime).toBe(0)
+    expect(pushed.phase).toBe('Paradox')
+    expect(pushed.lastParadox?.violations[0].anchorId).toBe('seed-object-anchor')
+  })
+})
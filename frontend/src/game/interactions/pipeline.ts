@@ -1,13 +1,39 @@
-import { evaluateDetectionV1 } from '../../core/detection'
+import { lockdownAt, raiseLockdown } from '../../core/alarm'
+import { allCharactersAtExit, extractionTurnsAt, isAtExit } from '../../core/characters'
+import { propagateChasers } from '../../core/chase'
+import { propagateDependencies, type UnmadeObject } from '../../core/dependency'
+import { evaluateDetectionV1, movedObjectNoticesAt } from '../../core/detection'
+import { propagatePatrolDetours } from '../../core/detour'
+import { bodySightingsAt } from '../../core/knockout'
+import { propagateLures } from '../../core/lure'
 import {
   evaluateParadoxV1,
   mergeCausalAnchors,
   type CausalAnchor,
 } from '../../core/paradox'
-import { getObjectById, hasExit } from '../../core/timeCube'
+import type { Position3D } from '../../core/position'
+import type { PropagationWarning } from '../../core/propagation'
+import type { Result } from '../../core/result'
+import { getObjectById, type TimeCube } from '../../core/timeCube'
+import { traceSpan } from '../../core/trace'
+import {
+  activeTimeline,
+  forkTimeline,
+  markActiveTimelineInconsistent,
+  updateActiveTimeline,
+} from '../../core/timelineSet'
 import { currentPosition } from '../../core/worldLine'
+import { diffCubeObjects } from '../stateDiff'
+import { statusTranslator } from './common'
 import { executeRegisteredInteraction } from './registry'
-import type { InteractionAction, InteractionState, SuccessfulOutcome } from './types'
+import { passControl } from './switchCharacter'
+import { fireInteractionTrigger } from './triggers'
+import type {
+  InteractionAction,
+  InteractionState,
+  OutcomeEvent,
+  SuccessfulOutcome,
+} from './types'
 
 function guardActivePhase(state: InteractionState): boolean {
   if (state.phase === 'BootError') {
@@ -15,15 +41,29 @@ function guardActivePhase(state: InteractionState): boolean {
   }
 
   if (state.phase !== 'Playing') {
-    state.status = 'Game already ended. Press R to restart.'
+    state.status = statusTranslator(state)('status.gameEnded')
     return false
   }
 
   return true
 }
 
+/**
+ * Earliest slice the turn rewrote before `from`. On time-loop levels changes carry on round the
+ * seam into slices before the action's own, and anchors there need checking too.
+ */
+function wrappedFromTime(before: TimeCube, after: TimeCube, from: number): number {
+  if (!after.timeWraps) {
+    return from
+  }
+
+  const rewritten = after.slices.findIndex((slice, t) => t < from && slice !== before.slices[t])
+  return rewritten === -1 ? from : rewritten
+}
+
 function buildCommitAnchors(
   state: InteractionState,
+  cubeBefore: TimeCube,
   outcome: SuccessfulOutcome,
   turn: number,
 ): { anchors: CausalAnchor[]; affectedFromTime: number } {
@@ -36,7 +76,20 @@ function buildCommitAnchors(
     },
   }
   const anchors: CausalAnchor[] = [playerAnchor]
-  let affectedFromTime = outcome.to.t
+
+  if (outcome.kind === 'Moved' && outcome.slidThrough) {
+    outcome.slidThrough.forEach((position, index) => {
+      anchors.push({
+        id: `turn-${turn}-slide-${index}`,
+        requirement: { kind: 'PlayerAt', position, sourceTurn: turn },
+      })
+    })
+  }
+
+  let affectedFromTime =
+    outcome.kind === 'Moved' && outcome.slidThrough && outcome.slidThrough.length > 0
+      ? outcome.slidThrough[0].t
+      : outcome.to.t
 
   if (outcome.kind === 'Pushed' || outcome.kind === 'Pulled') {
     let index = 0
@@ -62,92 +115,613 @@ function buildCommitAnchors(
     }
   }
 
-  return { anchors, affectedFromTime }
+  return { anchors, affectedFromTime: wrappedFromTime(cubeBefore, state.cube, affectedFromTime) }
 }
 
-export function runInteractionPipeline(
+/** Events implied by the action's own outcome; the pipeline appends what follows from it. */
+function outcomeEvents(outcome: SuccessfulOutcome): OutcomeEvent[] {
+  switch (outcome.kind) {
+    case 'Moved':
+      return outcome.slidThrough && outcome.slidThrough.length > 0
+        ? [{ kind: 'Slid', through: outcome.slidThrough, to: outcome.to }]
+        : []
+    case 'Pushed':
+      return [
+        ...(outcome.movedObjectIds.length > 0
+          ? [{ kind: 'ObjectsMoved' as const, objectIds: outcome.movedObjectIds }]
+          : []),
+        ...(outcome.bridged ? [{ kind: 'WaterBridged' as const, at: outcome.bridged }] : []),
+      ]
+    case 'Pulled':
+      return outcome.movedObjectIds.length > 0
+        ? [{ kind: 'ObjectsMoved', objectIds: outcome.movedObjectIds }]
+        : []
+    case 'Thrown':
+      return [{ kind: 'LureLanded', at: outcome.lure }]
+    case 'Broke':
+      return outcome.destroyed
+        ? [
+            { kind: 'NoiseMade', at: outcome.at },
+            ...[outcome.objectId, ...(outcome.linkedIds ?? [])].map((objectId) => ({
+              kind: 'ObjectBroken' as const,
+              objectId,
+            })),
+          ]
+        : [{ kind: 'NoiseMade', at: outcome.at }]
+    case 'Interacted':
+      return [{ kind: 'Interacted', objectId: outcome.objectId, interaction: outcome.interaction }]
+    case 'KnockedOut':
+      return [{ kind: 'KnockedOut', enemyId: outcome.enemyId, bodyId: outcome.bodyId }]
+    case 'Rifted':
+    case 'Switched':
+    case 'Climbed':
+      return []
+  }
+}
+
+/**
+ * Branching mode: a turn that changed a slice the player had already lived through forks a new
+ * timeline and leaves the old one as it was; any other turn just updates the active branch.
+ */
+function recordTimeline(
   state: InteractionState,
-  action: InteractionAction,
+  before: TimeCube,
+  livedThrough: number,
+  events: OutcomeEvent[],
 ): void {
-  if (!guardActivePhase(state)) {
+  if (!state.timelines) {
     return
   }
 
-  const result = executeRegisteredInteraction(state, action)
+  const forkTime = Math.min(...diffCubeObjects(before, state.cube).map((change) => change.t))
 
-  if (!result.ok) {
-    state.status = result.status
+  if (forkTime > livedThrough) {
+    state.timelines = updateActiveTimeline(state.timelines, state.cube)
     return
   }
 
-  const player = currentPosition(state.worldLine)
+  state.timelines = forkTimeline(state.timelines, before, state.cube, forkTime)
+  events.push({ kind: 'TimelineForked', timelineId: state.timelines.activeId, forkTime })
+}
+
+/**
+ * Marks the state detected if any enemy sees the player at `time`. In hotseat play only the seen
+ * character is caught and the next one takes over; the run is lost once nobody is left.
+ */
+function commitDetectionAt(
+  state: InteractionState,
+  time: number,
+  events: OutcomeEvent[],
+): boolean {
+  const detection = traceSpan(
+    'detection',
+    { time },
+    () =>
+      evaluateDetectionV1({
+        cube: state.cube,
+        worldLine: state.worldLine,
+        currentTime: time,
+        config: state.detectionConfig,
+        configByEnemyId: state.enemyDetectionConfigById,
+      }),
+    (report) => ({
+      detected: report.detected,
+      enemies: report.events.map((event) => event.enemyId),
+    }),
+  )
+
+  if (!detection.detected) {
+    return false
+  }
+
+  const primary = detection.events[0]
+  state.lastDetection = detection
+
+  if (state.hotseat) {
+    const caughtId = state.activeCharacterId
+    state.caughtCharacterIds = [...state.caughtCharacterIds, caughtId]
+    events.push({ kind: 'CharacterCaught', characterId: caughtId, enemyId: primary.enemyId })
+    const next = passControl(state)
+
+    if (next) {
+      events.push({ kind: 'ControlPassed', characterId: next.id })
+      const nextPosition = currentPosition(next.worldLine)
+      state.currentTime = nextPosition?.t ?? state.currentTime
+      state.status = statusTranslator(state)('status.caughtPlaysOn', {
+        turn: state.turn,
+        characterId: caughtId,
+        enemyId: primary.enemyId,
+        nextId: next.id,
+      })
+      return true
+    }
+  }
+
+  events.push({ kind: 'Detected', enemyId: primary.enemyId, observed: primary.observedPlayer })
+  state.phase = 'Detected'
+  state.status = statusTranslator(state)('status.detected', {
+    turn: state.turn,
+    enemyId: primary.enemyId,
+    time: primary.observedPlayer.t,
+  })
+  return true
+}
+
+/**
+ * A guard that glimpses the player just past its range, or on hard mode notices a box moved since
+ * its delayed view, adds one to the suspicion; once it reaches the threshold outside a lockdown,
+ * the alarm locks the level down from `time` on. Returns what to add to the status, or null when
+ * nothing roused a guard.
+ */
+function commitAlarmAt(
+  state: InteractionState,
+  time: number,
+  events: OutcomeEvent[],
+): string | null {
+  const config = state.alarmConfig
+
+  if (!config) {
+    return null
+  }
+
+  const glimpse = evaluateDetectionV1({
+    cube: state.cube,
+    worldLine: state.worldLine,
+    currentTime: time,
+    config: state.detectionConfig,
+    configByEnemyId: state.enemyDetectionConfigById,
+    rangeBonus: config.glimpseMargin,
+  })
+  const notice = glimpse.detected
+    ? null
+    : (movedObjectNoticesAt({
+        cube: state.cube,
+        currentTime: time,
+        config: state.detectionConfig,
+        configByEnemyId: state.enemyDetectionConfigById,
+      })[0] ?? null)
+
+  if (!glimpse.detected && !notice) {
+    return null
+  }
+
+  const enemyId = notice ? notice.enemyId : glimpse.events[0].enemyId
+  const suspicion = Math.min(config.threshold, state.alarm.suspicion + 1)
+  events.push(
+    notice
+      ? { kind: 'MovedObjectNoticed', enemyId, cell: notice.cell, suspicion }
+      : { kind: 'Glimpsed', enemyId, suspicion },
+  )
+
+  if (suspicion < config.threshold || lockdownAt(state.cube, time)) {
+    state.alarm = { ...state.alarm, suspicion }
+    return statusTranslator(state)(notice ? 'status.noticedMoved' : 'status.glimpsed', {
+      enemyId,
+      suspicion,
+      threshold: config.threshold,
+    })
+  }
+
+  state.cube = raiseLockdown(state.cube, time, config)
+  state.alarm = { ...state.alarm, suspicion: 0 }
+
+  if (state.timelines) {
+    state.timelines = updateActiveTimeline(state.timelines, state.cube)
+  }
+
+  const lockdown = state.cube.lockdowns[state.cube.lockdowns.length - 1]
+  events.push({ kind: 'AlarmRaised', lockdown })
+
+  return statusTranslator(state)('status.alarmRaised', { enemyId, time: lockdown.to })
+}
+
+/**
+ * A guard that sees a body no one has found yet in slice `time` alerts the level: it locks down
+ * from `time` on unless a lockdown is already running. Each body is only found once. Returns
+ * what to add to the status, or null when no new body was found.
+ */
+function commitBodySightingsAt(
+  state: InteractionState,
+  time: number,
+  events: OutcomeEvent[],
+): string | null {
+  const config = state.knockoutConfig
+
+  if (!config) {
+    return null
+  }
+
+  const sightings = bodySightingsAt({
+    cube: state.cube,
+    t: time,
+    config: state.detectionConfig,
+    configByEnemyId: state.enemyDetectionConfigById,
+  }).filter((sighting) => !state.foundBodyIds.includes(sighting.bodyId))
+
+  if (sightings.length === 0) {
+    return null
+  }
+
+  state.foundBodyIds = [
+    ...state.foundBodyIds,
+    ...sightings.map((sighting) => sighting.bodyId),
+  ]
+
+  for (const sighting of sightings) {
+    events.push({ kind: 'BodyFound', bodyId: sighting.bodyId, enemyId: sighting.enemyId })
+  }
+
+  const { bodyId, enemyId } = sightings[0]
+
+  if (lockdownAt(state.cube, time)) {
+    return statusTranslator(state)('status.bodyFound', { enemyId, bodyId })
+  }
+
+  state.cube = raiseLockdown(state.cube, time, {
+    lockdownTurns: config.alertTurns,
+    rangeBonus: config.rangeBonus,
+  })
+
+  if (state.timelines) {
+    state.timelines = updateActiveTimeline(state.timelines, state.cube)
+  }
+
+  const lockdown = state.cube.lockdowns[state.cube.lockdowns.length - 1]
+  events.push({ kind: 'AlarmRaised', lockdown })
+
+  return statusTranslator(state)('status.bodyFoundLockdown', { enemyId, bodyId, time: lockdown.to })
+}
+
+/** Everything a turn changes, worked out before any of it reaches the state. */
+interface StagedTurn {
+  /** Copy of the state with the handler's edits; the propagated cube replaces its `cube`. */
+  working: InteractionState
+  cube: TimeCube
+  outcome: SuccessfulOutcome
+  status: string
+  player: Position3D
+  /** Events set off by an interactable's trigger. */
+  triggered: OutcomeEvent[]
+  unmade: UnmadeObject[]
+  restored: UnmadeObject[]
+  warnings: PropagationWarning[]
+}
+
+/**
+ * Runs the handler on a working copy and propagates its cube. Handlers only replace state fields
+ * and never edit them in place, so a shallow copy keeps the real state untouched; the error is
+ * the status to show when the action is refused or propagation fails.
+ */
+function stageTurn(state: InteractionState, action: InteractionAction): Result<StagedTurn, string> {
+  const message = statusTranslator(state)
+  const working: InteractionState = { ...state }
+  const result = traceSpan(
+    'interaction',
+    { action: action.kind },
+    () => executeRegisteredInteraction(working, action),
+    (handled) =>
+      handled.ok
+        ? { outcome: handled.outcome.kind, to: handled.outcome.to }
+        : { refused: handled.error.kind },
+  )
+
+  if (!result.ok) {
+    return { ok: false, error: result.status }
+  }
+
+  // Interactables act before anything propagates, so guards already follow what they changed.
+  const triggered =
+    result.outcome.kind === 'Interacted' ? fireInteractionTrigger(working, result.outcome) : null
+  const player = currentPosition(working.worldLine)
 
   if (!player) {
-    state.status = 'Internal error: empty world line'
+    return { ok: false, error: message('interaction.emptyWorldLine') }
+  }
+
+  const dependencies = traceSpan(
+    'propagate.dependencies',
+    {},
+    () => propagateDependencies(working.cube),
+    (propagated) =>
+      propagated.ok
+        ? {
+            unmade: propagated.value.unmade.map((unmade) => unmade.id),
+            restored: propagated.value.restored.map((restored) => restored.id),
+          }
+        : { error: propagated.error.kind },
+  )
+
+  if (!dependencies.ok) {
+    return {
+      ok: false,
+      error: message('status.dependencyFailed', { kind: dependencies.error.kind }),
+    }
+  }
+
+  const detoured = traceSpan(
+    'propagate.detours',
+    {},
+    () => propagatePatrolDetours(dependencies.value.cube),
+    (propagated) => (propagated.ok ? {} : { error: propagated.error.kind }),
+  )
+
+  if (!detoured.ok) {
+    return { ok: false, error: message('status.detourFailed', { kind: detoured.error.kind }) }
+  }
+
+  const lures = [...working.lures.thrown, ...working.noises]
+  const lured = traceSpan(
+    'propagate.lures',
+    { lures: lures.length },
+    () =>
+      propagateLures({
+        cube: detoured.value,
+        lures,
+        settings: working.interactionConfig.lure,
+      }),
+    (propagated) =>
+      propagated.ok
+        ? { warnings: propagated.value.warnings.length }
+        : { error: propagated.error.kind },
+  )
+
+  if (!lured.ok) {
+    return { ok: false, error: message('status.lureFailed', { kind: lured.error.kind }) }
+  }
+
+  const chased = traceSpan(
+    'propagate.chasers',
+    {},
+    () =>
+      propagateChasers({
+        cube: lured.value.cube,
+        worldLine: working.worldLine,
+        config: working.detectionConfig,
+        configByEnemyId: working.enemyDetectionConfigById,
+      }),
+    (propagated) =>
+      propagated.ok
+        ? { warnings: propagated.value.warnings.length }
+        : { error: propagated.error.kind },
+  )
+
+  if (!chased.ok) {
+    return {
+      ok: false,
+      error: message('status.chaserFailed', { kind: chased.error.kind }),
+    }
+  }
+
+  return {
+    ok: true,
+    value: {
+      working,
+      cube: chased.value.cube,
+      outcome: result.outcome,
+      status: triggered ? `${result.status}; ${triggered.status}` : result.status,
+      player,
+      triggered: triggered?.events ?? [],
+      unmade: dependencies.value.unmade,
+      restored: dependencies.value.restored,
+      warnings: [...lured.value.warnings, ...chased.value.warnings],
+    },
+  }
+}
+
+function runPipelineSteps(state: InteractionState, action: InteractionAction): void {
+  if (!guardActivePhase(state)) {
     return
   }
 
+  const message = statusTranslator(state)
+
+  const cubeBefore = state.cube
+  const livedThrough = Math.max(...state.worldLine.path.map((position) => position.t))
+  const staged = stageTurn(state, action)
+
+  if (!staged.ok) {
+    state.status = staged.error
+    return
+  }
+
+  const { outcome, player } = staged.value
+
+  // The whole turn is known to go through; commit the handler's edits and the propagated cube.
+  Object.assign(state, staged.value.working, { cube: staged.value.cube })
+
   state.turn += 1
   state.currentTime = player.t
-  const commitMeta = buildCommitAnchors(state, result.outcome, state.turn)
+  const commitMeta = buildCommitAnchors(state, cubeBefore, outcome, state.turn)
   const mergedAnchors = mergeCausalAnchors({
     existing: state.causalAnchors,
     incoming: commitMeta.anchors,
   })
   state.causalAnchors = mergedAnchors.anchors
   state.causalAnchorsByTime = mergedAnchors.anchorsByTime
+  const events: OutcomeEvent[] = [
+    ...outcomeEvents(outcome),
+    ...staged.value.triggered,
+    ...staged.value.unmade.map(
+      (unmade): OutcomeEvent => ({ kind: 'ObjectUnmade', objectId: unmade.id, from: unmade.from }),
+    ),
+    ...staged.value.restored.map(
+      (restored): OutcomeEvent => ({
+        kind: 'ObjectRestored',
+        objectId: restored.id,
+        from: restored.from,
+      }),
+    ),
+    // Guards that could not take their re-resolved cells; a collision here hints at a broken level.
+    ...staged.value.warnings.map(
+      (warning): OutcomeEvent => ({ kind: 'PropagationWarning', warning }),
+    ),
+  ]
+  recordTimeline(state, cubeBefore, livedThrough, events)
   state.history.push({
     turn: state.turn,
     action,
-    outcome: result.outcome,
+    outcome,
     anchors: commitMeta.anchors,
     affectedFromTime: commitMeta.affectedFromTime,
+    events,
   })
 
-  const paradox = evaluateParadoxV1({
-    cube: state.cube,
-    worldLine: state.worldLine,
-    anchors: state.causalAnchors,
-    anchorsByTime: state.causalAnchorsByTime,
-    checkedFromTime: commitMeta.affectedFromTime,
-    config: state.paradoxConfig,
-  })
+  const paradox = traceSpan(
+    'paradox',
+    { fromTime: commitMeta.affectedFromTime, anchors: state.causalAnchors.length },
+    () =>
+      evaluateParadoxV1({
+        cube: state.cube,
+        worldLine: state.worldLine,
+        otherWorldLines: state.parkedCharacters.map((character) => character.worldLine),
+        anchors: state.causalAnchors,
+        anchorsByTime: state.causalAnchorsByTime,
+        checkedFromTime: commitMeta.affectedFromTime,
+        config: state.paradoxConfig,
+      }),
+    (report) => ({ paradox: report.paradox }),
+  )
 
   if (paradox.paradox) {
     const primary = paradox.violations[0]
     state.lastParadox = paradox
-    state.lastDetection = null
-    state.phase = 'Paradox'
-    state.status = `Turn ${state.turn}: paradox (${primary.reason})`
-    return
+
+    if (!state.timelines) {
+      events.push({ kind: 'Paradox', anchorId: primary.anchorId, reason: primary.reason })
+      state.lastDetection = null
+      state.phase = 'Paradox'
+      state.status = message('status.paradox', { turn: state.turn, reason: primary.reason })
+      return
+    }
+
+    // Branching mode: the paradox taints this timeline instead of ending the run.
+    if (activeTimeline(state.timelines).consistent) {
+      events.push({ kind: 'Paradox', anchorId: primary.anchorId, reason: primary.reason })
+      state.timelines = markActiveTimelineInconsistent(state.timelines)
+    }
+  } else {
+    state.lastParadox = null
+  }
+
+  // Slides cross several slices in one turn; every slice passed through can be observed.
+  if (outcome.kind === 'Moved' && outcome.slidThrough) {
+    for (const position of outcome.slidThrough) {
+      if (commitDetectionAt(state, position.t, events)) {
+        return
+      }
+    }
+  }
+
+  const atExit = isAtExit(state.cube, state.worldLine)
+  const consistent = !state.timelines || activeTimeline(state.timelines).consistent
+  const extraction = extractionTurnsAt(state.cube, state.worldLine)
+  const previous = state.worldLine.path.at(-2)
+  const heldStill =
+    outcome.kind !== 'Switched' && previous?.x === player.x && previous?.y === player.y
+
+  state.extractionTurns = extraction > 0 && heldStill ? state.extractionTurns + 1 : 0
+
+  if (atExit) {
+    events.push({ kind: 'ReachedExit', characterId: state.activeCharacterId })
   }
 
-  state.lastParadox = null
+  // An extraction point is only held while unseen, so this turn's detection comes first.
+  if (extraction > 0 && commitDetectionAt(state, player.t, events)) {
+    return
+  }
 
-  if (hasExit(state.cube, player)) {
+  if (
+    atExit &&
+    consistent &&
+    state.extractionTurns >= extraction &&
+    allCharactersAtExit(
+      state.cube,
+      state.worldLine,
+      state.parkedCharacters,
+      state.caughtCharacterIds,
+    )
+  ) {
+    events.push({ kind: 'Won' })
     state.lastDetection = null
     state.phase = 'Won'
-    state.status = `Turn ${state.turn}: reached exit at (${player.x}, ${player.y}, t=${player.t})`
+    state.status = message('status.won', {
+      turn: state.turn,
+      x: player.x,
+      y: player.y,
+      time: player.t,
+    })
     return
   }
 
-  const detection = evaluateDetectionV1({
-    cube: state.cube,
-    worldLine: state.worldLine,
-    currentTime: player.t,
-    config: state.detectionConfig,
-    configByEnemyId: state.enemyDetectionConfigById,
-  })
-
-  if (detection.detected) {
-    const primary = detection.events[0]
-    state.lastDetection = detection
-    state.phase = 'Detected'
-    state.status = `Turn ${state.turn}: detected by ${primary.enemyId} (observed t=${primary.observedPlayer.t})`
+  if (extraction === 0 && commitDetectionAt(state, player.t, events)) {
     return
   }
 
   state.lastDetection = null
 
-  state.status = `Turn ${state.turn}: ${result.status}`
+  const alarm = commitAlarmAt(state, player.t, events)
+  const found = commitBodySightingsAt(state, player.t, events)
+
+  state.status = !atExit
+    ? message('status.turn', { turn: state.turn, status: staged.value.status })
+    : !consistent
+      ? message('status.inconsistentExit', { turn: state.turn })
+      : state.extractionTurns < extraction
+        ? message('status.holdingExit', {
+            turn: state.turn,
+            held: state.extractionTurns,
+            needed: extraction,
+          })
+        : message('status.othersNeedExit', {
+            turn: state.turn,
+            characterId: state.activeCharacterId,
+          })
+
+  if (alarm) {
+    state.status = `${state.status}; ${alarm}`
+  }
+
+  if (found) {
+    state.status = `${state.status}; ${found}`
+  }
+
+  if (state.hotseat && action.kind !== 'SwitchCharacter') {
+    const next = passControl(state)
+    const nextPosition = next ? currentPosition(next.worldLine) : null
+
+    if (next && nextPosition) {
+      events.push({ kind: 'ControlPassed', characterId: next.id })
+      state.currentTime = nextPosition.t
+      state.status = `${state.status}; ${message('status.nextCharacter', { characterId: next.id })}`
+    }
+  }
+}
+
+/** Applies one action and everything that follows from it; traced as a `pipeline` span. */
+export function runInteractionPipeline(
+  state: InteractionState,
+  action: InteractionAction,
+): void {
+  const historyLength = state.history.length
+
+  traceSpan(
+    'pipeline',
+    { action: action.kind, turn: state.turn, time: state.currentTime },
+    () => runPipelineSteps(state, action),
+    () => ({
+      committed: state.history.length > historyLength,
+      phase: state.phase,
+      status: state.status,
+    }),
+  )
+}
+
+/** Runs the pipeline on a detached copy so callers can inspect the consequences before committing. */
+export function simulateInteractionPipeline<S extends InteractionState>(
+  state: S,
+  action: InteractionAction,
+): S {
+  const simulated = structuredClone(state)
+  runInteractionPipeline(simulated, action)
+  return simulated
 }
@@ -1,36 +1,60 @@
 import { hasComponent } from '../../core/components'
-import { isInBounds, movePosition } from '../../core/position'
-import { applyRelocationsFromTime, objectsAt } from '../../core/timeCube'
-import { blockingObjectsAt, extendWorldLineOrError, nextNormalStep, oppositeDirection } from './common'
+import { detouringGuardIds, propagatePatrolDetours } from '../../core/detour'
+import { echoIds } from '../../core/echo'
+import { isInBounds, movePosition, wrapPosition } from '../../core/position'
+import { stackAt } from '../../core/stacking'
+import { applyRelocationsFromTime, objectsAt, wrappingOf } from '../../core/timeCube'
+import {
+  blockingObjectsAt,
+  extendWorldLineOrError,
+  nextNormalStep,
+  oppositeDirection,
+  refuseAnchored,
+  refuseOffGround,
+  statusTranslator,
+} from './common'
 import type { InteractionHandler } from './types'
 
 export const pullInteractionHandler: InteractionHandler<'Pull'> = {
   kind: 'Pull',
   execute(state, action) {
+    const message = statusTranslator(state)
+
     if (!state.interactionConfig.allowPull) {
       return {
         ok: false,
         error: { kind: 'NotPullable' },
-        status: 'Pull is disabled',
+        status: message('interaction.pullDisabled'),
       }
     }
 
+    const offGround = refuseOffGround(state)
+
+    if (offGround) {
+      return offGround
+    }
+
     const step = nextNormalStep(
       state.worldLine,
       state.boardWidth,
       state.boardHeight,
       state.timeDepth,
       action.direction,
+      wrappingOf(state.cube),
     )
 
     if (!step.ok) {
       switch (step.error.kind) {
         case 'OutOfBounds':
-          return { ok: false, error: step.error, status: 'Blocked by boundary' }
+          return { ok: false, error: step.error, status: message('interaction.outOfBounds') }
         case 'TimeBoundary':
-          return { ok: false, error: step.error, status: 'Blocked by time boundary' }
+          return { ok: false, error: step.error, status: message('interaction.timeBoundary') }
         default:
-          return { ok: false, error: step.error, status: 'Internal pull error' }
+          return {
+            ok: false,
+            error: step.error,
+            status: message('interaction.internalError', { action: 'Pull' }),
+          }
       }
     }
 
@@ -38,17 +62,20 @@ export const pullInteractionHandler: InteractionHandler<'Pull'> = {
       return {
         ok: false,
         error: { kind: 'BlockedByObject' },
-        status: 'Blocked by object',
+        status: message('interaction.blockedByObject'),
       }
     }
 
-    const behind = movePosition(step.value.current, oppositeDirection(action.direction))
+    const behind = wrapPosition(
+      movePosition(step.value.current, oppositeDirection(action.direction)),
+      wrappingOf(state.cube),
+    )
 
     if (!isInBounds(behind, state.boardWidth, state.boardHeight)) {
       return {
         ok: false,
         error: { kind: 'NothingToPull' },
-        status: 'Nothing to pull',
+        status: message('interaction.nothingToPull'),
       }
     }
 
@@ -62,35 +89,51 @@ export const pullInteractionHandler: InteractionHandler<'Pull'> = {
       return {
         ok: false,
         error: { kind: 'NothingToPull' },
-        status: 'Nothing to pull',
+        status: message('interaction.nothingToPull'),
       }
     }
 
-    const pullable = objectsBehind.find((object) => hasComponent(object.archetype.components, 'Pullable'))
+    // Pulling a stack only takes its top box off.
+    const stack = stackAt(state.cube, { x: behind.x, y: behind.y, t: step.value.current.t })
+    const candidates = stack.length > 1 ? stack.slice(-1) : objectsBehind
+    const pullable = candidates.find((object) =>
+      hasComponent(object.archetype.components, 'Pullable'),
+    )
 
     if (!pullable) {
       return {
         ok: false,
         error: { kind: 'NotPullable' },
-        status: 'Target is not pullable',
+        status: message('interaction.notPullable'),
       }
     }
 
-    const relocationResult = applyRelocationsFromTime(state.cube, step.value.next.t, [
-      {
-        id: pullable.id,
-        from: {
-          x: pullable.position.x,
-          y: pullable.position.y,
-          t: step.value.next.t,
-        },
-        to: {
-          x: step.value.current.x,
-          y: step.value.current.y,
-          t: step.value.next.t,
+    const anchored = refuseAnchored(state, [pullable.id], step.value.next.t)
+
+    if (anchored) {
+      return anchored
+    }
+
+    const relocationResult = applyRelocationsFromTime(
+      state.cube,
+      step.value.next.t,
+      [
+        {
+          id: pullable.id,
+          from: {
+            x: pullable.position.x,
+            y: pullable.position.y,
+            t: step.value.next.t,
+          },
+          to: {
+            x: step.value.current.x,
+            y: step.value.current.y,
+            t: step.value.next.t,
+          },
         },
-      },
-    ])
+      ],
+      [...detouringGuardIds(state.cube), ...echoIds(state.cube)],
+    )
 
     if (!relocationResult.ok) {
       switch (relocationResult.error.kind) {
@@ -98,13 +141,13 @@ export const pullInteractionHandler: InteractionHandler<'Pull'> = {
           return {
             ok: false,
             error: { kind: 'BlockedByObject', objectId: relocationResult.error.id },
-            status: 'Blocked by object',
+            status: message('interaction.blockedByObject'),
           }
         case 'EntityNotInSlice':
           return {
             ok: false,
             error: { kind: 'NotPullable' },
-            status: 'Target cannot be pulled in this timeline',
+            status: message('interaction.notPullableHere'),
           }
         case 'OutOfBounds':
         case 'InvalidRelocationTime':
@@ -113,12 +156,17 @@ export const pullInteractionHandler: InteractionHandler<'Pull'> = {
           return {
             ok: false,
             error: { kind: 'Internal', message: 'Invalid pull relocation target' },
-            status: 'Invalid pull target',
+            status: message('interaction.invalidPullTarget'),
           }
       }
     }
 
-    const worldLineResult = extendWorldLineOrError(state.worldLine, step.value.next)
+    const worldLineResult = extendWorldLineOrError(
+      state.worldLine,
+      step.value.next,
+      'Normal',
+      wrappingOf(state.cube),
+    )
 
     if (!worldLineResult.ok) {
       return {
@@ -126,12 +174,22 @@ export const pullInteractionHandler: InteractionHandler<'Pull'> = {
         error: worldLineResult.error,
         status:
           worldLineResult.error.kind === 'SelfIntersection'
-            ? 'Blocked by self-intersection'
-            : 'Invalid pull movement',
+            ? message('interaction.selfIntersection')
+            : message('interaction.invalidStep', { action: 'Pull' }),
+      }
+    }
+
+    const rerouted = propagatePatrolDetours(relocationResult.value)
+
+    if (!rerouted.ok) {
+      return {
+        ok: false,
+        error: { kind: 'BlockedByObject' },
+        status: message('interaction.guardNoWayAround'),
       }
     }
 
-    state.cube = relocationResult.value
+    state.cube = rerouted.value
     state.worldLine = worldLineResult.value
 
     return {
@@ -141,7 +199,11 @@ export const pullInteractionHandler: InteractionHandler<'Pull'> = {
         to: step.value.next,
         movedObjectIds: [pullable.id],
       },
-      status: `pulled 1 object to (${step.value.next.x}, ${step.value.next.y}, t=${step.value.next.t})`,
+      status: message('interaction.pulled', {
+        x: step.value.next.x,
+        y: step.value.next.y,
+        time: step.value.next.t,
+      }),
     }
   },
 }
@@ -1,13 +1,51 @@
 import { hasComponent } from '../../core/components'
-import { applyRelocationsFromTime, type ObjectRelocation } from '../../core/timeCube'
-import { isInBounds, movePosition, type Direction2D, type Position2D, type Position3D } from '../../core/position'
-import { blockingObjectsAt, extendWorldLineOrError, nextNormalStep } from './common'
-import type { InteractionHandler, InteractionResult, InteractionState } from './types'
+import { detouringGuardIds, propagatePatrolDetours } from '../../core/detour'
+import { echoIds } from '../../core/echo'
+import { footprintCells, isMultiTile, leadingEdgeCells } from '../../core/footprint'
+import { groupMembersAt, groupOf } from '../../core/groups'
+import type { ResolvedObjectInstance } from '../../core/objects'
+import { riftLinksAt } from '../../core/rift'
+import { stackAt, stackingPushTarget, type StackingPush } from '../../core/stacking'
+import {
+  applyRelocationsFromTime,
+  relocateThroughTime,
+  wrappingOf,
+  type ObjectRelocation,
+  type TimeCube,
+} from '../../core/timeCube'
+import { sinkIntoWater, waterAt } from '../../core/water'
+import {
+  isInBounds,
+  movePosition,
+  wrapPosition,
+  type Direction2D,
+  type Position2D,
+  type Position3D,
+} from '../../core/position'
+import { wouldIntersect } from '../../core/worldLine'
+import {
+  blockingObjectsAt,
+  extendWorldLineOrError,
+  lastStepDirection,
+  nextNormalStep,
+  refuseAnchored,
+  refuseOffGround,
+  slidePath,
+  statusTranslator,
+} from './common'
+import type {
+  InteractionHandler,
+  InteractionHandlerResult,
+  InteractionResult,
+  InteractionState,
+} from './types'
 
 interface PushChainResult {
   chain: Position3D[]
   freeTarget: Position2D
   movedObjectIds: string[]
+  /** Water at `freeTarget` the lead box sinks into instead of landing on floor. */
+  waterId?: string
 }
 
 function collectPushChain(
@@ -34,6 +72,31 @@ function collectPushChain(
       }
     }
 
+    const water = chain.length > 0 ? waterAt(state.cube, cell) : null
+
+    if (water) {
+      return {
+        ok: true,
+        value: { chain, freeTarget: cursor, movedObjectIds, waterId: water.id },
+      }
+    }
+
+    if (stackAt(state.cube, cell).length > 1) {
+      return { ok: false, error: { kind: 'StackedBox' } }
+    }
+
+    const multiTile = blockers.find((object) => isMultiTile(object.archetype.components))
+
+    if (multiTile) {
+      return { ok: false, error: { kind: 'MultiTileInChain', objectId: multiTile.id } }
+    }
+
+    const grouped = blockers.find((object) => groupOf(object.archetype.components) !== null)
+
+    if (grouped) {
+      return { ok: false, error: { kind: 'GroupInChain', objectId: grouped.id } }
+    }
+
     const pushable = blockers.find((object) => hasComponent(object.archetype.components, 'Pushable'))
     const nonPushableExists = blockers.some(
       (object) => !hasComponent(object.archetype.components, 'Pushable'),
@@ -57,7 +120,7 @@ function collectPushChain(
       }
     }
 
-    const nextCursor = movePosition(cursor, direction)
+    const nextCursor = wrapPosition(movePosition(cursor, direction), wrappingOf(state.cube))
 
     if (!isInBounds(nextCursor, state.boardWidth, state.boardHeight)) {
       return { ok: false, error: { kind: 'NoSpaceToPush' } }
@@ -67,32 +130,308 @@ function collectPushChain(
   }
 }
 
+/** Rift tile the lead box lands on, if any; only forward links carry boxes. */
+function riftExitForBox(state: InteractionState, landing: Position3D): Position3D | null {
+  return riftLinksAt(state.cube, landing).find((link) => !link.reverse)?.target ?? null
+}
+
+/** The box may not land on any cell the player has walked or is about to step into. */
+function playerOccupiesArrival(
+  state: InteractionState,
+  playerNext: Position3D,
+  arrival: Position3D,
+): boolean {
+  for (let t = arrival.t; t < state.timeDepth; t += 1) {
+    const cell = { x: arrival.x, y: arrival.y, t }
+    const isPlayerNext = cell.x === playerNext.x && cell.y === playerNext.y && t === playerNext.t
+
+    if (isPlayerNext || wouldIntersect(state.worldLine, cell)) {
+      return true
+    }
+  }
+
+  return false
+}
+
+/** Heavy boxes move alone and only when the player already stepped the same way last turn. */
+function checkHeavyPush(
+  state: InteractionState,
+  movedObjectIds: string[],
+  direction: Direction2D,
+): InteractionResult<null> {
+  const heavyId = movedObjectIds.find((id) => {
+    const object = state.cube.objectsById[id]
+    return object ? hasComponent(object.archetype.components, 'Heavy') : false
+  })
+
+  if (!heavyId) {
+    return { ok: true, value: null }
+  }
+
+  if (movedObjectIds.length > 1) {
+    return { ok: false, error: { kind: 'HeavyInChain', objectId: heavyId } }
+  }
+
+  if (lastStepDirection(state.worldLine, wrappingOf(state.cube)) !== direction) {
+    return { ok: false, error: { kind: 'HeavyNeedsMomentum', objectId: heavyId } }
+  }
+
+  return { ok: true, value: null }
+}
+
+/** Sets the pushed box on top of the one behind it; the player steps into the box's old cell. */
+function pushOntoStack(
+  state: InteractionState,
+  playerNext: Position3D,
+  stacking: StackingPush,
+  direction: Direction2D,
+): InteractionHandlerResult {
+  const message = statusTranslator(state)
+
+  const heavyCheck = checkHeavyPush(state, [stacking.boxId], direction)
+
+  if (!heavyCheck.ok) {
+    return { ok: false, error: heavyCheck.error, status: message('interaction.heavyNeedsMomentum') }
+  }
+
+  const anchored = refuseAnchored(state, [stacking.boxId], playerNext.t)
+
+  if (anchored) {
+    return anchored
+  }
+
+  const relocationResult = applyRelocationsFromTime(
+    state.cube,
+    playerNext.t,
+    [{ id: stacking.boxId, from: playerNext, to: stacking.to }],
+    [stacking.baseId],
+  )
+
+  if (!relocationResult.ok) {
+    return {
+      ok: false,
+      error: { kind: 'NoSpaceToPush' },
+      status: message('interaction.noSpaceToPush'),
+    }
+  }
+
+  const worldLineResult = extendWorldLineOrError(
+    state.worldLine,
+    playerNext,
+    'Normal',
+    wrappingOf(state.cube),
+  )
+
+  if (!worldLineResult.ok) {
+    return {
+      ok: false,
+      error: worldLineResult.error,
+      status:
+        worldLineResult.error.kind === 'SelfIntersection'
+          ? message('interaction.selfIntersection')
+          : message('interaction.invalidStep', { action: 'Push' }),
+    }
+  }
+
+  state.cube = relocationResult.value
+  state.worldLine = worldLineResult.value
+
+  const { to } = stacking
+
+  return {
+    ok: true,
+    outcome: { kind: 'Pushed', to: playerNext, movedObjectIds: [stacking.boxId] },
+    status: message('interaction.stacked', {
+      objectId: stacking.boxId,
+      baseId: stacking.baseId,
+      x: to.x,
+      y: to.y,
+      time: to.t,
+    }),
+  }
+}
+
+/**
+ * Blocks move alone and as one: a multi-tile object, or every member of a group still in the
+ * slice. Each cell past the block's leading edge must be open floor on the map. Blocks never sink,
+ * rift or slide; the player steps into the cell pushed against.
+ */
+function pushBlock(
+  state: InteractionState,
+  playerNext: Position3D,
+  members: ResolvedObjectInstance[],
+  direction: Direction2D,
+): InteractionHandlerResult {
+  const message = statusTranslator(state)
+
+  const ids = members.map((member) => member.id)
+  const foreign = blockingObjectsAt(state.cube, playerNext).some(
+    (object) => !ids.includes(object.id),
+  )
+
+  if (
+    foreign ||
+    members.some((member) => !hasComponent(member.archetype.components, 'Pushable'))
+  ) {
+    return { ok: false, error: { kind: 'NotPushable' }, status: message('interaction.notPushable') }
+  }
+
+  const heavyCheck = checkHeavyPush(state, ids, direction)
+
+  if (!heavyCheck.ok) {
+    return {
+      ok: false,
+      error: heavyCheck.error,
+      status:
+        heavyCheck.error.kind === 'HeavyInChain'
+          ? message('interaction.heavyInChain')
+          : message('interaction.heavyNeedsMomentum'),
+    }
+  }
+
+  const wrapping = wrappingOf(state.cube)
+  const cells = members.flatMap((member) =>
+    footprintCells(member.archetype.components, member.position),
+  )
+  const edgeBlocked = leadingEdgeCells(cells, direction)
+    .map((cell) => wrapPosition(cell, wrapping))
+    .some(
+      (cell) =>
+        !isInBounds(cell, state.boardWidth, state.boardHeight) ||
+        blockingObjectsAt(state.cube, { ...cell, t: playerNext.t }).length > 0,
+    )
+
+  if (edgeBlocked) {
+    return {
+      ok: false,
+      error: { kind: 'NoSpaceToPush' },
+      status: message('interaction.noSpaceToPush'),
+    }
+  }
+
+  const anchored = refuseAnchored(state, ids, playerNext.t)
+
+  if (anchored) {
+    return anchored
+  }
+
+  const relocations = members.map((member) => ({
+    id: member.id,
+    from: { ...member.position, t: playerNext.t },
+    to: { ...wrapPosition(movePosition(member.position, direction), wrapping), t: playerNext.t },
+  }))
+  const guardIds = [...detouringGuardIds(state.cube), ...echoIds(state.cube)]
+  const relocationResult = applyRelocationsFromTime(
+    state.cube,
+    playerNext.t,
+    relocations,
+    guardIds,
+  )
+
+  if (!relocationResult.ok) {
+    return {
+      ok: false,
+      error: { kind: 'NoSpaceToPush' },
+      status: message('interaction.noSpaceToPush'),
+    }
+  }
+
+  const worldLineResult = extendWorldLineOrError(
+    state.worldLine,
+    playerNext,
+    'Normal',
+    wrappingOf(state.cube),
+  )
+
+  if (!worldLineResult.ok) {
+    return {
+      ok: false,
+      error: worldLineResult.error,
+      status:
+        worldLineResult.error.kind === 'SelfIntersection'
+          ? message('interaction.selfIntersection')
+          : message('interaction.invalidStep', { action: 'Push' }),
+    }
+  }
+
+  const rerouted = propagatePatrolDetours(relocationResult.value)
+
+  if (!rerouted.ok) {
+    return {
+      ok: false,
+      error: { kind: 'NoSpaceToPush' },
+      status: message('interaction.guardNoWayAround'),
+    }
+  }
+
+  state.cube = rerouted.value
+  state.worldLine = worldLineResult.value
+
+  const [lead] = relocations
+  const groupId = groupOf(members[0].archetype.components)
+
+  return {
+    ok: true,
+    outcome: { kind: 'Pushed', to: playerNext, movedObjectIds: ids },
+    status: groupId
+      ? message('interaction.pushedGroup', {
+          groupId,
+          count: ids.length,
+          direction: message(`direction.${direction}`),
+        })
+      : message('interaction.pushedObject', {
+          objectId: lead.id,
+          x: lead.to.x,
+          y: lead.to.y,
+          time: lead.to.t,
+        }),
+  }
+}
+
 export const pushInteractionHandler: InteractionHandler<'Push'> = {
   kind: 'Push',
   execute(state, action) {
+    const message = statusTranslator(state)
+
+    const offGround = refuseOffGround(state)
+
+    if (offGround) {
+      return offGround
+    }
+
     const step = nextNormalStep(
       state.worldLine,
       state.boardWidth,
       state.boardHeight,
       state.timeDepth,
       action.direction,
+      wrappingOf(state.cube),
     )
 
     if (!step.ok) {
       switch (step.error.kind) {
         case 'OutOfBounds':
-          return { ok: false, error: step.error, status: 'Blocked by boundary' }
+          return { ok: false, error: step.error, status: message('interaction.outOfBounds') }
         case 'TimeBoundary':
-          return { ok: false, error: step.error, status: 'Blocked by time boundary' }
+          return { ok: false, error: step.error, status: message('interaction.timeBoundary') }
         default:
-          return { ok: false, error: step.error, status: 'Internal push error' }
+          return {
+            ok: false,
+            error: step.error,
+            status: message('interaction.internalError', { action: 'Push' }),
+          }
       }
     }
 
     const firstBlockers = blockingObjectsAt(state.cube, step.value.next)
 
     if (firstBlockers.length === 0) {
-      const worldLineResult = extendWorldLineOrError(state.worldLine, step.value.next)
+      const worldLineResult = extendWorldLineOrError(
+        state.worldLine,
+        step.value.next,
+        'Normal',
+        wrappingOf(state.cube),
+      )
 
       if (!worldLineResult.ok) {
         return {
@@ -100,8 +439,8 @@ export const pushInteractionHandler: InteractionHandler<'Push'> = {
           error: worldLineResult.error,
           status:
             worldLineResult.error.kind === 'SelfIntersection'
-              ? 'Blocked by self-intersection'
-              : 'Invalid move',
+              ? message('interaction.selfIntersection')
+              : message('interaction.invalidStep', { action: 'Push' }),
         }
       }
 
@@ -110,28 +449,148 @@ export const pushInteractionHandler: InteractionHandler<'Push'> = {
       return {
         ok: true,
         outcome: { kind: 'Moved', to: step.value.next },
-        status: `move to (${step.value.next.x}, ${step.value.next.y}, t=${step.value.next.t})`,
+        status: message('interaction.moved', {
+          x: step.value.next.x,
+          y: step.value.next.y,
+          time: step.value.next.t,
+        }),
       }
     }
 
+    const stacking = stackingPushTarget(state.cube, step.value.next, action.direction)
+
+    if (stacking) {
+      return pushOntoStack(state, step.value.next, stacking, action.direction)
+    }
+
+    const block = firstBlockers.find(
+      (object) =>
+        isMultiTile(object.archetype.components) || groupOf(object.archetype.components) !== null,
+    )
+
+    if (block) {
+      const groupId = groupOf(block.archetype.components)
+      const members = groupId ? groupMembersAt(state.cube, groupId, step.value.next.t) : [block]
+
+      return pushBlock(state, step.value.next, members, action.direction)
+    }
+
     const chainResult = collectPushChain(state, step.value.next, action.direction)
 
     if (!chainResult.ok) {
       switch (chainResult.error.kind) {
         case 'NotPushable':
-          return { ok: false, error: chainResult.error, status: 'Target is not pushable' }
+          return { ok: false, error: chainResult.error, status: message('interaction.notPushable') }
         case 'PushChainTooLong':
-          return { ok: false, error: chainResult.error, status: 'Push chain too long' }
+          return {
+            ok: false,
+            error: chainResult.error,
+            status: message('interaction.pushChainTooLong'),
+          }
         case 'NoSpaceToPush':
-          return { ok: false, error: chainResult.error, status: 'No space to push' }
+          return {
+            ok: false,
+            error: chainResult.error,
+            status: message('interaction.noSpaceToPush'),
+          }
+        case 'StackedBox':
+          return { ok: false, error: chainResult.error, status: message('interaction.stackedBox') }
+        case 'MultiTileInChain':
+          return {
+            ok: false,
+            error: chainResult.error,
+            status: message('interaction.multiTileInChain'),
+          }
+        case 'GroupInChain':
+          return {
+            ok: false,
+            error: chainResult.error,
+            status: message('interaction.groupInChain'),
+          }
         default:
-          return { ok: false, error: chainResult.error, status: 'Push blocked' }
+          return { ok: false, error: chainResult.error, status: message('interaction.pushBlocked') }
+      }
+    }
+
+    const heavyCheck = checkHeavyPush(state, chainResult.value.movedObjectIds, action.direction)
+
+    if (!heavyCheck.ok) {
+      return {
+        ok: false,
+        error: heavyCheck.error,
+        status:
+          heavyCheck.error.kind === 'HeavyInChain'
+            ? message('interaction.heavyInChain')
+            : message('interaction.heavyNeedsMomentum'),
       }
     }
 
     const relocations: ObjectRelocation[] = []
+    const pushTime = step.value.next.t
+    const leadIndex = chainResult.value.chain.length - 1
+    const { waterId } = chainResult.value
+    const landing = { ...chainResult.value.freeTarget, t: pushTime }
+    const riftExit = waterId ? null : riftExitForBox(state, landing)
+    let cube: TimeCube = state.cube
+
+    // A rift sends the lead box into another slice, a time effect anchored boxes never take.
+    const anchored = refuseAnchored(
+      state,
+      chainResult.value.movedObjectIds,
+      pushTime,
+      riftExit !== null,
+    )
+
+    if (anchored) {
+      return anchored
+    }
+
+    if (waterId) {
+      const sunk = sinkIntoWater(cube, chainResult.value.movedObjectIds[leadIndex], waterId, pushTime)
+
+      if (!sunk.ok) {
+        return {
+          ok: false,
+          error: { kind: 'Internal', message: 'Failed to sink box into water' },
+          status: message('interaction.pushFailed'),
+        }
+      }
+
+      cube = sunk.value
+    }
+
+    if (riftExit) {
+      if (playerOccupiesArrival(state, step.value.next, riftExit)) {
+        return {
+          ok: false,
+          error: { kind: 'NoSpaceToPush' },
+          status: message('interaction.riftExitBlocked'),
+        }
+      }
+
+      const transit = relocateThroughTime(
+        cube,
+        chainResult.value.movedObjectIds[leadIndex],
+        pushTime,
+        riftExit,
+      )
+
+      if (!transit.ok) {
+        return {
+          ok: false,
+          error: { kind: 'NoSpaceToPush' },
+          status: message('interaction.riftExitBlocked'),
+        }
+      }
+
+      cube = transit.value
+    }
+
+    for (let i = leadIndex; i >= 0; i -= 1) {
+      if ((riftExit || waterId) && i === leadIndex) {
+        continue
+      }
 
-    for (let i = chainResult.value.chain.length - 1; i >= 0; i -= 1) {
       const from = chainResult.value.chain[i]
       const toSource = i === chainResult.value.chain.length - 1
         ? chainResult.value.freeTarget
@@ -144,7 +603,9 @@ export const pushInteractionHandler: InteractionHandler<'Push'> = {
       })
     }
 
-    const relocationResult = applyRelocationsFromTime(state.cube, step.value.next.t, relocations)
+    // Patrols may walk where the boxes land; they are routed around them below. Echoes are ghosts.
+    const guardIds = [...detouringGuardIds(cube), ...echoIds(cube)]
+    const relocationResult = applyRelocationsFromTime(cube, pushTime, relocations, guardIds)
 
     if (!relocationResult.ok) {
       switch (relocationResult.error.kind) {
@@ -153,22 +614,31 @@ export const pushInteractionHandler: InteractionHandler<'Push'> = {
           return {
             ok: false,
             error: { kind: 'NoSpaceToPush' },
-            status: 'No space to push',
+            status: message('interaction.noSpaceToPush'),
           }
         case 'OutOfBounds':
         case 'InvalidRelocationTime':
         case 'EntityNotFound':
-          return { ok: false, error: { kind: 'NoSpaceToPush' }, status: 'No space to push' }
+          return {
+            ok: false,
+            error: { kind: 'NoSpaceToPush' },
+            status: message('interaction.noSpaceToPush'),
+          }
         default:
           return {
             ok: false,
             error: { kind: 'Internal', message: 'Failed to apply push relocation' },
-            status: 'Push failed',
+            status: message('interaction.pushFailed'),
           }
       }
     }
 
-    const worldLineResult = extendWorldLineOrError(state.worldLine, step.value.next)
+    const worldLineResult = extendWorldLineOrError(
+      state.worldLine,
+      step.value.next,
+      'Normal',
+      wrappingOf(state.cube),
+    )
 
     if (!worldLineResult.ok) {
       return {
@@ -176,22 +646,76 @@ export const pushInteractionHandler: InteractionHandler<'Push'> = {
         error: worldLineResult.error,
         status:
           worldLineResult.error.kind === 'SelfIntersection'
-            ? 'Blocked by self-intersection'
-            : 'Invalid move',
+            ? message('interaction.selfIntersection')
+            : message('interaction.invalidStep', { action: 'Push' }),
+      }
+    }
+
+    // A lead box resting on slippery floor keeps sliding one cell per slice.
+    let pushedCube = relocationResult.value
+    let slideFrom: Position3D = { ...chainResult.value.freeTarget, t: pushTime }
+    const slide = riftExit || waterId
+      ? []
+      : slidePath(pushedCube, worldLineResult.value, slideFrom, action.direction)
+
+    for (const position of slide) {
+      const slid = applyRelocationsFromTime(
+        pushedCube,
+        position.t,
+        [
+          {
+            id: chainResult.value.movedObjectIds[leadIndex],
+            from: { x: slideFrom.x, y: slideFrom.y, t: position.t },
+            to: position,
+          },
+        ],
+        guardIds,
+      )
+
+      if (!slid.ok) {
+        break
       }
+
+      pushedCube = slid.value
+      slideFrom = position
     }
 
-    state.cube = relocationResult.value
+    const rerouted = propagatePatrolDetours(pushedCube)
+
+    if (!rerouted.ok) {
+      return {
+        ok: false,
+        error: { kind: 'NoSpaceToPush' },
+        status: message('interaction.guardNoWayAround'),
+      }
+    }
+
+    state.cube = rerouted.value
     state.worldLine = worldLineResult.value
 
+    const leadAt = riftExit ?? (waterId ? landing : step.value.next)
+
     return {
       ok: true,
       outcome: {
         kind: 'Pushed',
         to: step.value.next,
         movedObjectIds: chainResult.value.movedObjectIds,
+        ...(waterId ? { bridged: landing } : {}),
       },
-      status: `pushed ${chainResult.value.movedObjectIds.length} object(s) to (${step.value.next.x}, ${step.value.next.y}, t=${step.value.next.t})`,
+      status: message(
+        riftExit
+          ? 'interaction.pushedRifted'
+          : waterId
+            ? 'interaction.pushedSank'
+            : 'interaction.pushed',
+        {
+          count: chainResult.value.movedObjectIds.length,
+          x: leadAt.x,
+          y: leadAt.y,
+          time: leadAt.t,
+        },
+      ),
     }
   },
 }
@@ -1,11 +1,14 @@
 import { describe, expect, it } from 'vitest'
 
+import { ACTION_KINDS } from '../../data/contracts'
 import { interactionRegistry } from './registry'
 import type { InteractionAction } from './types'
 
 describe('interaction registry', () => {
   it('registers handlers for every action kind', () => {
-    const actionKinds: InteractionAction['kind'][] = ['Move', 'Wait', 'ApplyRift', 'Push', 'Pull']
+    const actionKinds: InteractionAction['kind'][] = [...ACTION_KINDS]
+
+    expect(Object.keys(interactionRegistry).sort()).toEqual([...ACTION_KINDS].sort())
 
     for (const kind of actionKinds) {
       expect(interactionRegistry[kind]).toBeDefined()
@@ -1,7 +1,14 @@
+import { breakInteractionHandler } from './break'
+import { climbInteractionHandler } from './climb'
+import { interactInteractionHandler } from './interact'
 import { moveInteractionHandler } from './move'
+import { moveToInteractionHandler } from './moveTo'
 import { pullInteractionHandler } from './pull'
 import { pushInteractionHandler } from './push'
 import { riftInteractionHandler } from './rift'
+import { switchCharacterInteractionHandler } from './switchCharacter'
+import { throwInteractionHandler } from './throw'
+import { timePushInteractionHandler } from './timePush'
 import type {
   InteractionAction,
   InteractionHandlerResult,
@@ -16,6 +23,13 @@ export const interactionRegistry: InteractionRegistry = {
   ApplyRift: riftInteractionHandler,
   Push: pushInteractionHandler,
   Pull: pullInteractionHandler,
+  TimePush: timePushInteractionHandler,
+  Throw: throwInteractionHandler,
+  SwitchCharacter: switchCharacterInteractionHandler,
+  Climb: climbInteractionHandler,
+  Break: breakInteractionHandler,
+  Interact: interactInteractionHandler,
+  MoveTo: moveToInteractionHandler,
 }
 
 export function executeRegisteredInteraction(
@@ -33,5 +47,19 @@ export function executeRegisteredInteraction(
       return interactionRegistry.Push.execute(state, action)
     case 'Pull':
       return interactionRegistry.Pull.execute(state, action)
+    case 'TimePush':
+      return interactionRegistry.TimePush.execute(state, action)
+    case 'Throw':
+      return interactionRegistry.Throw.execute(state, action)
+    case 'SwitchCharacter':
+      return interactionRegistry.SwitchCharacter.execute(state, action)
+    case 'Climb':
+      return interactionRegistry.Climb.execute(state, action)
+    case 'Break':
+      return interactionRegistry.Break.execute(state, action)
+    case 'Interact':
+      return interactionRegistry.Interact.execute(state, action)
+    case 'MoveTo':
+      return interactionRegistry.MoveTo.execute(state, action)
   }
 }
@@ -1,24 +1,42 @@
 import { extendViaRift } from '../../core/worldLine'
-import { resolveRift } from '../../core/rift'
-import { blockingObjectsAt } from './common'
+import { resolveRift, riftLinksAt, type RiftInstruction, type RiftTileLink } from '../../core/rift'
+import type { TimeCube } from '../../core/timeCube'
+import { blockingObjectsAt, statusTranslator } from './common'
 import type { InteractionHandler } from './types'
 
+/** Only two-way rifts take a bare rift; one-way tiles leave it the default jump. */
+function isTwoWay(cube: TimeCube, link: RiftTileLink): boolean {
+  return (cube.objectsById[link.objectId]?.archetype.components ?? []).some(
+    (component) => component.kind === 'Rift' && component.bidirectional,
+  )
+}
+
 export const riftInteractionHandler: InteractionHandler<'ApplyRift'> = {
   kind: 'ApplyRift',
   execute(state, action) {
+    const message = statusTranslator(state)
+
     const current = state.worldLine.path.at(-1)
 
     if (!current) {
       return {
         ok: false,
         error: { kind: 'Internal', message: 'Empty world line' },
-        status: 'Internal error: empty world line',
+        status: message('interaction.emptyWorldLine'),
       }
     }
 
+    // A bare rift on a bidirectional rift tile, or on its landing cell, takes the tile.
+    const tileLink = action.instruction
+      ? null
+      : (riftLinksAt(state.cube, current).find((link) => isTwoWay(state.cube, link)) ?? null)
+    const instruction: RiftInstruction | undefined = tileLink
+      ? { kind: 'tunnel', target: tileLink.target, tunnelId: tileLink.objectId }
+      : action.instruction
+
     const riftResult = resolveRift({
       current,
-      instruction: action.instruction,
+      instruction,
       settings: state.riftSettings,
       resources: state.riftResources,
       boardWidth: state.boardWidth,
@@ -35,14 +53,14 @@ export const riftInteractionHandler: InteractionHandler<'ApplyRift'> = {
             error: { kind: 'InvalidRiftTarget' },
             status:
               riftResult.error.kind === 'InvalidTargetTime'
-                ? 'Invalid rift target time'
-                : 'Invalid rift target position',
+                ? message('interaction.riftTargetTime')
+                : message('interaction.riftTargetPosition'),
           }
         case 'InsufficientEnergy':
           return {
             ok: false,
             error: { kind: 'InsufficientEnergy' },
-            status: 'Insufficient energy for rift',
+            status: message('interaction.insufficientEnergy'),
           }
       }
     }
@@ -53,7 +71,7 @@ export const riftInteractionHandler: InteractionHandler<'ApplyRift'> = {
       return {
         ok: false,
         error: { kind: 'BlockedByObject' },
-        status: 'Blocked by object',
+        status: message('interaction.blockedByObject'),
       }
     }
 
@@ -68,21 +86,29 @@ export const riftInteractionHandler: InteractionHandler<'ApplyRift'> = {
             : { kind: 'Internal', message: 'Invalid rift extension' },
         status:
           worldLineResult.error.kind === 'SelfIntersection'
-            ? 'Blocked by self-intersection'
-            : 'Invalid rift',
+            ? message('interaction.selfIntersection')
+            : message('interaction.invalidStep', { action: 'ApplyRift' }),
       }
     }
 
     state.worldLine = worldLineResult.value
 
     if (state.riftResources.energy !== null) {
-      state.riftResources.energy -= riftResult.value.energyCost
+      state.riftResources = {
+        ...state.riftResources,
+        energy: state.riftResources.energy - riftResult.value.energyCost,
+      }
     }
 
     return {
       ok: true,
       outcome: { kind: 'Rifted', to: next, mode: riftResult.value.mode },
-      status: `rift(${riftResult.value.mode}) to (${next.x}, ${next.y}, t=${next.t})`,
+      status: message('interaction.rifted', {
+        mode: riftResult.value.mode,
+        x: next.x,
+        y: next.y,
+        time: next.t,
+      }),
     }
   },
 }
@@ -0,0 +1,68 @@
+import { currentPosition } from '../../core/worldLine'
+import type { ParkedCharacter } from '../../core/characters'
+import { statusTranslator } from './common'
+import type { InteractionHandler, InteractionState } from './types'
+
+/**
+ * Hands control to the next parked character that has not been caught, keeping the rotation
+ * order; a held extraction point starts over. Returns that character, or null when nobody else can
+ * play.
+ */
+export function passControl(state: InteractionState): ParkedCharacter | null {
+  const index = state.parkedCharacters.findIndex(
+    (character) => !state.caughtCharacterIds.includes(character.id),
+  )
+  const next = state.parkedCharacters[index]
+
+  if (!next) {
+    return null
+  }
+
+  state.parkedCharacters = [
+    ...state.parkedCharacters.slice(index + 1),
+    { id: state.activeCharacterId, worldLine: state.worldLine },
+    ...state.parkedCharacters.slice(0, index),
+  ]
+  state.activeCharacterId = next.id
+  state.worldLine = next.worldLine
+  state.extractionTurns = 0
+
+  return next
+}
+
+/**
+ * Hand control to the next character. The one left behind keeps its world line as is; no time
+ * passes for either of them. In hotseat play this passes the turn without acting.
+ */
+export const switchCharacterInteractionHandler: InteractionHandler<'SwitchCharacter'> = {
+  kind: 'SwitchCharacter',
+  execute(state) {
+    const message = statusTranslator(state)
+
+    const next = passControl(state)
+
+    if (!next) {
+      return {
+        ok: false,
+        error: { kind: 'NoOtherCharacter' },
+        status: message('interaction.noOtherCharacter'),
+      }
+    }
+
+    const to = currentPosition(next.worldLine)
+
+    if (!to) {
+      return {
+        ok: false,
+        error: { kind: 'Internal', message: 'Empty world line' },
+        status: message('interaction.internalError', { action: 'SwitchCharacter' }),
+      }
+    }
+
+    return {
+      ok: true,
+      outcome: { kind: 'Switched', to, characterId: next.id },
+      status: message('interaction.switched', { characterId: next.id }),
+    }
+  },
+}
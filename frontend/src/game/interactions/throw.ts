@@ -0,0 +1,98 @@
+import { lureLandingCell } from '../../core/lure'
+import { wrappingOf } from '../../core/timeCube'
+import { blockingObjectsAt, extendWorldLineOrError, statusTranslator, waitStep } from './common'
+import type { InteractionHandler } from './types'
+
+/**
+ * Throw a lure: the player holds their cell for one slice while the lure flies `range` cells and
+ * lands in the slice they arrive in. Guards nearby are re-resolved by the pipeline.
+ */
+export const throwInteractionHandler: InteractionHandler<'Throw'> = {
+  kind: 'Throw',
+  execute(state, action) {
+    const message = statusTranslator(state)
+
+    if (state.lures.remaining <= 0) {
+      return {
+        ok: false,
+        error: { kind: 'NoLuresLeft' },
+        status: message('interaction.noLuresLeft'),
+      }
+    }
+
+    const max = state.interactionConfig.lure.maxRange
+
+    if (action.range < 1 || action.range > max) {
+      return {
+        ok: false,
+        error: { kind: 'ThrowOutOfRange', range: action.range, max },
+        status: message('interaction.throwRange', { max }),
+      }
+    }
+
+    const step = waitStep(state.worldLine, state.timeDepth, wrappingOf(state.cube))
+
+    if (!step.ok) {
+      switch (step.error.kind) {
+        case 'TimeBoundary':
+          return { ok: false, error: step.error, status: message('interaction.timeBoundary') }
+        default:
+          return {
+            ok: false,
+            error: step.error,
+            status: message('interaction.internalError', { action: 'Throw' }),
+          }
+      }
+    }
+
+    if (blockingObjectsAt(state.cube, step.value.next).length > 0) {
+      return {
+        ok: false,
+        error: { kind: 'BlockedByObject' },
+        status: message('interaction.blockedByObject'),
+      }
+    }
+
+    const landing = lureLandingCell(state.cube, step.value.next, action.direction, action.range)
+
+    if (!landing) {
+      return {
+        ok: false,
+        error: { kind: 'BlockedByObject' },
+        status: message('interaction.noRoomToThrow'),
+      }
+    }
+
+    const worldLineResult = extendWorldLineOrError(
+      state.worldLine,
+      step.value.next,
+      'Normal',
+      wrappingOf(state.cube),
+    )
+
+    if (!worldLineResult.ok) {
+      return {
+        ok: false,
+        error: worldLineResult.error,
+        status:
+          worldLineResult.error.kind === 'SelfIntersection'
+            ? message('interaction.selfIntersection')
+            : message('interaction.invalidStep', { action: 'Throw' }),
+      }
+    }
+
+    const lure = { id: `lure.${state.lures.thrown.length}`, position: landing }
+
+    state.worldLine = worldLineResult.value
+    state.lures = {
+      remaining: state.lures.remaining - 1,
+      thrown: [...state.lures.thrown, lure],
+    }
+
+    return {
+      ok: true,
+      outcome: { kind: 'Thrown', to: step.value.next, lure: landing },
+      status: message('interaction.threw', { x: landing.x, y: landing.y, time: landing.t }),
+    }
+  },
+}
@@ -0,0 +1,150 @@
+import { hasComponent } from '../../core/components'
+import { relocateThroughTime, wrappingOf } from '../../core/timeCube'
+import {
+  blockingObjectsAt,
+  extendWorldLineOrError,
+  lastStepDirection,
+  nextNormalStep,
+  refuseAnchored,
+  refuseOffGround,
+  statusTranslator,
+} from './common'
+import type { InteractionHandler } from './types'
+
+/**
+ * Temporal shove: step into the adjacent box's cell while the box skips one slice forward at the
+ * same (x,y). It vanishes from the slice the player enters and reappears from the slice after.
+ */
+export const timePushInteractionHandler: InteractionHandler<'TimePush'> = {
+  kind: 'TimePush',
+  execute(state, action) {
+    const message = statusTranslator(state)
+
+    if (!state.interactionConfig.allowTimePush) {
+      return {
+        ok: false,
+        error: { kind: 'NotPushable' },
+        status: message('interaction.timePushDisabled'),
+      }
+    }
+
+    const offGround = refuseOffGround(state)
+
+    if (offGround) {
+      return offGround
+    }
+
+    const step = nextNormalStep(
+      state.worldLine,
+      state.boardWidth,
+      state.boardHeight,
+      state.timeDepth,
+      action.direction,
+      wrappingOf(state.cube),
+    )
+
+    if (!step.ok) {
+      switch (step.error.kind) {
+        case 'OutOfBounds':
+          return { ok: false, error: step.error, status: message('interaction.outOfBounds') }
+        case 'TimeBoundary':
+          return { ok: false, error: step.error, status: message('interaction.timeBoundary') }
+        default:
+          return {
+            ok: false,
+            error: step.error,
+            status: message('interaction.internalError', { action: 'TimePush' }),
+          }
+      }
+    }
+
+    const blockers = blockingObjectsAt(state.cube, step.value.next)
+    const pushable = blockers.find((object) => hasComponent(object.archetype.components, 'Pushable'))
+
+    if (!pushable || blockers.length > 1) {
+      return {
+        ok: false,
+        error: { kind: 'NotPushable' },
+        status: message('interaction.notPushable'),
+      }
+    }
+
+    const anchored = refuseAnchored(
+      state,
+      [pushable.id],
+      step.value.next.t,
+      true,
+    )
+
+    if (anchored) {
+      return anchored
+    }
+
+    if (
+      hasComponent(pushable.archetype.components, 'Heavy') &&
+      lastStepDirection(state.worldLine, wrappingOf(state.cube)) !== action.direction
+    ) {
+      return {
+        ok: false,
+        error: { kind: 'HeavyNeedsMomentum', objectId: pushable.id },
+        status: message('interaction.heavyNeedsMomentum'),
+      }
+    }
+
+    const arrival = { ...step.value.next, t: step.value.next.t + 1 }
+
+    if (arrival.t >= state.timeDepth) {
+      return {
+        ok: false,
+        error: { kind: 'TimeBoundary' },
+        status: message('interaction.noLaterSlice'),
+      }
+    }
+
+    const relocationResult = relocateThroughTime(
+      state.cube,
+      pushable.id,
+      step.value.next.t,
+      arrival,
+    )
+
+    if (!relocationResult.ok) {
+      return {
+        ok: false,
+        error: { kind: 'NoSpaceToPush' },
+        status: message('interaction.noSpaceToPush'),
+      }
+    }
+
+    const worldLineResult = extendWorldLineOrError(
+      state.worldLine,
+      step.value.next,
+      'Normal',
+      wrappingOf(state.cube),
+    )
+
+    if (!worldLineResult.ok) {
+      return {
+        ok: false,
+        error: worldLineResult.error,
+        status:
+          worldLineResult.error.kind === 'SelfIntersection'
+            ? message('interaction.selfIntersection')
+            : message('interaction.invalidStep', { action: 'TimePush' }),
+      }
+    }
+
+    state.cube = relocationResult.value
+    state.worldLine = worldLineResult.value
+
+    return {
+      ok: true,
+      outcome: {
+        kind: 'Pushed',
+        to: step.value.next,
+        movedObjectIds: [pushable.id],
+      },
+      status: message('interaction.timePushed', { objectId: pushable.id, time: arrival.t }),
+    }
+  },
+}
@@ -0,0 +1,106 @@
+import { liftLockdown, lockdownAt } from '../../core/alarm'
+import { interactableOf, type InteractionKind, type PatrolSwitch } from '../../core/components'
+import { switchPatrolFrom } from '../../core/levers'
+import { propagationWarningFor } from '../../core/propagation'
+import type { MessageKey } from '../../render/i18n'
+import { statusTranslator } from './common'
+import type { InteractionState, OutcomeEvent, SuccessfulOutcome } from './types'
+
+type InteractedOutcome = Extract<SuccessfulOutcome, { kind: 'Interacted' }>
+
+export interface TriggerResult {
+  events: OutcomeEvent[]
+  /** Appended to the handler's status. */
+  status: string
+}
+
+/**
+ * What using an interactable of one kind sets off. Triggers run on the pipeline's working copy
+ * before propagation and, like handlers, only replace state fields. New interactables only need a
+ * kind and a trigger here, never a new action.
+ */
+export type InteractionTrigger = (
+  state: InteractionState,
+  outcome: InteractedOutcome,
+) => TriggerResult
+
+/** Ends the lockdown from the slice it is used in and clears the suspicion built up so far. */
+const terminalTrigger: InteractionTrigger = (state, outcome) => {
+  const message = statusTranslator(state)
+  const { t } = outcome.at
+  const hadSuspicion = state.alarm.suspicion > 0
+
+  state.alarm = { ...state.alarm, suspicion: 0 }
+
+  if (!lockdownAt(state.cube, t)) {
+    return {
+      events: [],
+      status: message(hadSuspicion ? 'interaction.suspicionCleared' : 'interaction.noAlarm'),
+    }
+  }
+
+  state.cube = liftLockdown(state.cube, t)
+
+  return {
+    events: [{ kind: 'LockdownLifted', at: t }],
+    status: message('interaction.lockdownLifted', { time: t }),
+  }
+}
+
+const SWITCH_MESSAGES: Record<PatrolSwitch['kind'], MessageKey> = {
+  reverse: 'interaction.leverReverse',
+  pause: 'interaction.leverPause',
+  resume: 'interaction.leverResume',
+}
+
+/**
+ * Turns or stops every guard the lever is linked to from the slice it is pulled in. A guard that
+ * cannot take its new cells keeps its old ones, with a warning as for any propagation.
+ */
+const leverTrigger: InteractionTrigger = (state, outcome) => {
+  const message = statusTranslator(state)
+  const lever = interactableOf(state.cube.objectsById[outcome.objectId]?.archetype.components ?? [])
+
+  if (lever?.interaction !== 'Lever') {
+    return { events: [], status: message('interaction.nothingHappens') }
+  }
+
+  const events: OutcomeEvent[] = []
+  const changes: string[] = []
+
+  for (const enemyId of lever.targets) {
+    const switched = switchPatrolFrom(state.cube, enemyId, lever.effect, outcome.at.t)
+
+    if (!switched.ok) {
+      const warning =
+        switched.error.kind === 'NoPatrol' ? null : propagationWarningFor(enemyId, switched.error)
+
+      if (warning) {
+        events.push({ kind: 'PropagationWarning', warning })
+      }
+
+      continue
+    }
+
+    state.cube = switched.value.cube
+    events.push({ kind: 'PatrolSwitched', enemyId, change: switched.value.change })
+    changes.push(message(SWITCH_MESSAGES[switched.value.change.kind], { enemyId }))
+  }
+
+  return {
+    events,
+    status: changes.length > 0 ? changes.join(', ') : message('interaction.nothingHappens'),
+  }
+}
+
+export const interactionTriggers: Record<InteractionKind, InteractionTrigger> = {
+  Terminal: terminalTrigger,
+  Lever: leverTrigger,
+}
+
+export function fireInteractionTrigger(
+  state: InteractionState,
+  outcome: InteractedOutcome,
+): TriggerResult {
+  return interactionTriggers[outcome.interaction](state, outcome)
+}
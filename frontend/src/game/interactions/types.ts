@@ -1,14 +1,25 @@
+import type { AlarmConfig, AlarmState, Lockdown } from '../../core/alarm'
+import type { ParkedCharacter } from '../../core/characters'
+import type { LureResources, LureSettings, ThrownLure } from '../../core/lure'
 import type { RiftInstruction, RiftResources, RiftSettings } from '../../core/rift'
+import type { InteractionKind, PatrolSwitch } from '../../core/components'
+import type { KnockoutConfig } from '../../core/knockout'
+import type { RngState } from '../../core/rng'
 import type { TimeCube } from '../../core/timeCube'
+import type { TimelineSet } from '../../core/timelineSet'
 import type { Direction2D, Position3D } from '../../core/position'
 import type { WorldLineState } from '../../core/worldLine'
 import type { Result } from '../../core/result'
 import type { DetectionConfig, DetectionReport } from '../../core/detection'
+import type { Layer } from '../../core/elevation'
+import type { PropagationWarning } from '../../core/propagation'
+import type { Locale } from '../../render/i18n'
 import type {
   CausalAnchor,
   CausalAnchorIndexByTime,
   ParadoxConfig,
   ParadoxReport,
+  ParadoxViolation,
 } from '../../core/paradox'
 
 export type GamePhase = 'Playing' | 'Won' | 'Detected' | 'Paradox' | 'BootError'
@@ -19,6 +30,14 @@ export type InteractionAction =
   | { kind: 'ApplyRift'; instruction?: RiftInstruction }
   | { kind: 'Push'; direction: Direction2D }
   | { kind: 'Pull'; direction: Direction2D }
+  | { kind: 'TimePush'; direction: Direction2D }
+  | { kind: 'Throw'; direction: Direction2D; range: number }
+  | { kind: 'SwitchCharacter' }
+  | { kind: 'Climb' }
+  | { kind: 'Break'; direction: Direction2D }
+  | { kind: 'Interact'; direction: Direction2D }
+  /** One step toward `target`; a target in the current slice means the earliest slice there. */
+  | { kind: 'MoveTo'; target: Position3D }
 
 export type InteractionError =
   | { kind: 'OutOfBounds' }
@@ -28,17 +47,100 @@ export type InteractionError =
   | { kind: 'NotPullable' }
   | { kind: 'PushChainTooLong'; length: number; max: number }
   | { kind: 'NoSpaceToPush' }
+  | { kind: 'HeavyNeedsMomentum'; objectId: string }
+  | { kind: 'HeavyInChain'; objectId: string }
+  | { kind: 'MultiTileInChain'; objectId: string }
+  | { kind: 'GroupInChain'; objectId: string }
+  | { kind: 'StackedBox' }
   | { kind: 'NothingToPull' }
   | { kind: 'SelfIntersection' }
   | { kind: 'InvalidRiftTarget' }
   | { kind: 'InsufficientEnergy' }
+  | { kind: 'NoLuresLeft' }
+  | { kind: 'ThrowOutOfRange'; range: number; max: number }
+  | { kind: 'NoOtherCharacter' }
+  | { kind: 'NoLadder' }
+  | { kind: 'NoCatwalk' }
+  | { kind: 'NotOnGround' }
+  | { kind: 'NothingToBreak' }
+  | { kind: 'NothingToInteract' }
+  | { kind: 'GuardAware'; enemyId: string }
+  | { kind: 'AnchoredInTime'; objectId: string }
+  | { kind: 'WaitDisabled' }
+  | { kind: 'NoPath'; target: Position3D }
   | { kind: 'Internal'; message: string }
 
 export type SuccessfulOutcome =
-  | { kind: 'Moved'; to: Position3D }
+  /** `slidThrough` lists cells passed on slippery floor before coming to rest at `to`. */
+  | { kind: 'Moved'; to: Position3D; slidThrough?: Position3D[] }
   | { kind: 'Rifted'; to: Position3D; mode: RiftInstruction['kind'] }
-  | { kind: 'Pushed'; to: Position3D; movedObjectIds: string[] }
+  /** `bridged` is the water cell the lead box sank into, now floor. */
+  | { kind: 'Pushed'; to: Position3D; movedObjectIds: string[]; bridged?: Position3D }
   | { kind: 'Pulled'; to: Position3D; movedObjectIds: string[] }
+  | { kind: 'Thrown'; to: Position3D; lure: Position3D }
+  | { kind: 'Switched'; to: Position3D; characterId: string }
+  | { kind: 'Climbed'; to: Position3D; layer: Layer }
+  /**
+   * One blow on `objectId` at `at`; `destroyed` once it has taken all its hits. `linkedIds` are
+   * the other members of its group that came down with it.
+   */
+  | {
+      kind: 'Broke'
+      to: Position3D
+      objectId: string
+      at: Position3D
+      destroyed: boolean
+      linkedIds?: string[]
+    }
+  /** Used the interactable `objectId` at `at`; its trigger adds whatever that set off. */
+  | {
+      kind: 'Interacted'
+      to: Position3D
+      objectId: string
+      interaction: InteractionKind
+      at: Position3D
+    }
+  /** Knocked guard `enemyId` out at `at`; `bodyId` lies there from that slice on. */
+  | { kind: 'KnockedOut'; to: Position3D; enemyId: string; bodyId: string; at: Position3D }
+
+/**
+ * Everything a committed turn set off, in the order it happened. The outcome says what the
+ * action did; events add the consequences (a slide, a box shoved, a guard spotting you).
+ */
+export type OutcomeEvent =
+  | { kind: 'Slid'; through: Position3D[]; to: Position3D }
+  | { kind: 'ObjectsMoved'; objectIds: string[] }
+  | { kind: 'LureLanded'; at: Position3D }
+  | { kind: 'NoiseMade'; at: Position3D }
+  | { kind: 'ObjectBroken'; objectId: string }
+  /** A dependent whose cause is gone; it no longer exists from slice `from` on. */
+  | { kind: 'ObjectUnmade'; objectId: string; from: number }
+  /** An unmade dependent whose cause is back exists again from slice `from` on. */
+  | { kind: 'ObjectRestored'; objectId: string; from: number }
+  /** A guard kept its old cells because its new trajectory collided or left the map. */
+  | { kind: 'PropagationWarning'; warning: PropagationWarning }
+  | { kind: 'WaterBridged'; at: Position3D }
+  | { kind: 'EnteredRegion'; regionId: string; name: string }
+  | { kind: 'TimelineForked'; timelineId: string; forkTime: number }
+  | { kind: 'Paradox'; anchorId: string; reason: ParadoxViolation['reason'] }
+  | { kind: 'Detected'; enemyId: string; observed: Position3D }
+  /** Seen just past `enemyId`'s range; `suspicion` is the total so far toward the alarm. */
+  | { kind: 'Glimpsed'; enemyId: string; suspicion: number }
+  /** `enemyId` saw boxes at `cell` differ from its delayed view; counts like a glimpse. */
+  | { kind: 'MovedObjectNoticed'; enemyId: string; cell: Position3D; suspicion: number }
+  | { kind: 'AlarmRaised'; lockdown: Lockdown }
+  | { kind: 'Interacted'; objectId: string; interaction: InteractionKind }
+  /** A terminal ended the lockdown from slice `at` on. */
+  | { kind: 'LockdownLifted'; at: number }
+  /** A lever turned, stopped or restarted `enemyId`'s patrol from slice `change.at` on. */
+  | { kind: 'PatrolSwitched'; enemyId: string; change: PatrolSwitch }
+  | { kind: 'KnockedOut'; enemyId: string; bodyId: string }
+  /** `enemyId` saw `bodyId` where it lies; an `AlarmRaised` follows unless already locked down. */
+  | { kind: 'BodyFound'; bodyId: string; enemyId: string }
+  | { kind: 'CharacterCaught'; characterId: string; enemyId: string }
+  | { kind: 'ReachedExit'; characterId: string }
+  | { kind: 'Won' }
+  | { kind: 'ControlPassed'; characterId: string }
 
 export type InteractionOutcome = SuccessfulOutcome | { kind: 'Blocked'; reason: InteractionError }
 
@@ -48,11 +150,18 @@ export interface InteractionHistoryEntry {
   outcome: SuccessfulOutcome
   anchors?: CausalAnchor[]
   affectedFromTime?: number
+  events?: OutcomeEvent[]
 }
 
 export interface InteractionConfig {
   maxPushChain: number
   allowPull: boolean
+  allowTimePush: boolean
+  lure: LureSettings
+  /** Guards within this Manhattan distance hear every blow of the `Break` action. */
+  breakNoiseRadius: number
+  /** False under the "no waiting" mutator; missing means waiting is allowed. */
+  allowWait?: boolean
 }
 
 export interface InteractionState {
@@ -60,12 +169,33 @@ export interface InteractionState {
   boardHeight: number
   timeDepth: number
   cube: TimeCube
+  /** World line of the controlled character; the others wait in `parkedCharacters`. */
   worldLine: WorldLineState
+  activeCharacterId: string
+  parkedCharacters: ParkedCharacter[]
+  /** Hotseat: control passes to the next character after every action. */
+  hotseat: boolean
+  /** Characters caught in hotseat play; they sit out the rest of the run. */
+  caughtCharacterIds: string[]
   currentTime: number
   turn: number
   phase: GamePhase
   riftSettings: RiftSettings
   riftResources: RiftResources
+  lures: LureResources
+  /** Heard like lures that land, one per `Break` blow. */
+  noises: ThrownLure[]
+  /** Blows landed so far on each breakable object. */
+  breakHits: Record<string, number>
+  /** Turns the controlled character has held its extraction point so far; 0 anywhere else. */
+  extractionTurns: number
+  /** Null on levels without an alarm. */
+  alarmConfig: AlarmConfig | null
+  alarm: AlarmState
+  /** Null on levels where guards cannot be knocked out. */
+  knockoutConfig: KnockoutConfig | null
+  /** Bodies a guard has already found; each alerts the level once. */
+  foundBodyIds: string[]
   interactionConfig: InteractionConfig
   history: InteractionHistoryEntry[]
   detectionConfig: DetectionConfig
@@ -75,6 +205,12 @@ export interface InteractionState {
   lastParadox: ParadoxReport | null
   causalAnchors: CausalAnchor[]
   causalAnchorsByTime: CausalAnchorIndexByTime
+  /** Branching mode: every timeline so far, the active one holding `cube`; null otherwise. */
+  timelines: TimelineSet | null
+  /** Seeded generator for stochastic mechanics; reset from its seed on restart. */
+  rng: RngState
+  /** Language of `status`; the shell keeps it in step with the UI setting. */
+  locale?: Locale
   status: string
 }
 
@@ -82,6 +218,10 @@ export type InteractionHandlerResult =
   | { ok: true; outcome: SuccessfulOutcome; status: string }
   | { ok: false; error: InteractionError; status: string }
 
+/**
+ * Handlers run on a working copy of the state and may only replace its fields, never edit them in
+ * place: the pipeline commits the copy once the action and its propagation have all succeeded.
+ */
 export type InteractionHandler<K extends InteractionAction['kind']> = {
   kind: K
   execute(
@@ -1,29 +1,58 @@
-import { blockingObjectsAt, extendWorldLineOrError, waitStep } from './common'
+import { currentLayer } from '../../core/elevation'
+import { wrappingOf } from '../../core/timeCube'
+import {
+  blockingObjectsAt,
+  extendWorldLineOrError,
+  statusTranslator,
+  waitStep,
+} from './common'
 import type { InteractionHandler } from './types'
 
 export const waitInteractionHandler: InteractionHandler<'Wait'> = {
   kind: 'Wait',
   execute(state) {
-    const step = waitStep(state.worldLine, state.timeDepth)
+    const message = statusTranslator(state)
+
+    if (state.interactionConfig.allowWait === false) {
+      return {
+        ok: false,
+        error: { kind: 'WaitDisabled' },
+        status: message('interaction.waitDisabled'),
+      }
+    }
+
+    const step = waitStep(state.worldLine, state.timeDepth, wrappingOf(state.cube))
 
     if (!step.ok) {
       switch (step.error.kind) {
         case 'TimeBoundary':
-          return { ok: false, error: step.error, status: 'Blocked by time boundary' }
+          return { ok: false, error: step.error, status: message('interaction.timeBoundary') }
         default:
-          return { ok: false, error: step.error, status: 'Internal wait error' }
+          return {
+            ok: false,
+            error: step.error,
+            status: message('interaction.internalError', { action: 'Wait' }),
+          }
       }
     }
 
-    if (blockingObjectsAt(state.cube, step.value.next).length > 0) {
+    if (
+      currentLayer(state.worldLine) === 'ground' &&
+      blockingObjectsAt(state.cube, step.value.next).length > 0
+    ) {
       return {
         ok: false,
         error: { kind: 'BlockedByObject' },
-        status: 'Blocked by object',
+        status: message('interaction.blockedByObject'),
       }
     }
 
-    const worldLineResult = extendWorldLineOrError(state.worldLine, step.value.next)
+    const worldLineResult = extendWorldLineOrError(
+      state.worldLine,
+      step.value.next,
+      'Normal',
+      wrappingOf(state.cube),
+    )
 
     if (!worldLineResult.ok) {
       return {
@@ -31,8 +60,8 @@ export const waitInteractionHandler: InteractionHandler<'Wait'> = {
         error: worldLineResult.error,
         status:
           worldLineResult.error.kind === 'SelfIntersection'
-            ? 'Blocked by self-intersection'
-            : 'Invalid wait',
+            ? message('interaction.selfIntersection')
+            : message('interaction.invalidStep', { action: 'Wait' }),
       }
     }
 
@@ -41,7 +70,7 @@ export const waitInteractionHandler: InteractionHandler<'Wait'> = {
     return {
       ok: true,
       outcome: { kind: 'Moved', to: step.value.next },
-      status: `wait at t=${step.value.next.t}`,
+      status: message('interaction.waited', { time: step.value.next.t }),
     }
   },
 }
@@ -2,6 +2,7 @@ import { hasComponent, type Component } from '../core/components'
 import { type ObjectRegistryError, createObjectRegistry, resolveObjectInstance, type LevelObjectsConfig, type ObjectRegistry, type ResolvedObjectInstance } from '../core/objects'
 import type { Result } from '../core/result'
 import { applyRelocationsFromTime, createTimeCube, placeObjects, type CubeError, type RelocationError, type TimeCube } from '../core/timeCube'
+import { patrolPositionAt, type PatrolRoute } from '../core/patrol'
 import type { Position2D } from '../core/position'
 
 export const defaultLevelObjectsConfig: LevelObjectsConfig = {
@@ -66,15 +67,10 @@ export interface BootstrapObjectsResult {
 
 interface PatrolProjectionState {
   id: string
-  loops: boolean
-  path: Position2D[]
+  route: PatrolRoute
   previous: Position2D
 }
 
-function modulo(value: number, divisor: number): number {
-  return ((value % divisor) + divisor) % divisor
-}
-
 function getPatrolComponent(
   components: Component[],
 ): Extract<Component, { kind: 'Patrol' }> | null {
@@ -87,29 +83,6 @@ function getPatrolComponent(
   return null
 }
 
-function resolvePatrolPosition(path: Position2D[], loops: boolean, t: number): Position2D {
-  if (path.length === 0) {
-    return { x: 0, y: 0 }
-  }
-
-  if (loops) {
-    return path[modulo(t, path.length)]
-  }
-
-  if (path.length === 1) {
-    return path[0]
-  }
-
-  const period = path.length * 2 - 2
-  const offset = modulo(t, period)
-
-  if (offset < path.length) {
-    return path[offset]
-  }
-
-  return path[period - offset]
-}
-
 function buildPatrolProjectionStates(objects: ResolvedObjectInstance[]): PatrolProjectionState[] {
   const states: PatrolProjectionState[] = []
 
@@ -122,8 +95,7 @@ function buildPatrolProjectionStates(objects: ResolvedObjectInstance[]): PatrolP
 
     states.push({
       id: object.id,
-      loops: patrol.loops,
-      path: patrol.path,
+      route: patrol,
       previous: { x: object.position.x, y: object.position.y },
     })
   }
@@ -146,7 +118,7 @@ function applyProjectedPatrolOccupancy(
   for (let t = 0; t < cube.timeDepth; t += 1) {
     const relocations = projectionStates
       .map((state) => {
-        const target = resolvePatrolPosition(state.path, state.loops, t)
+        const target = patrolPositionAt(state.route, t) ?? { x: 0, y: 0 }
         const from = state.previous
 
         state.previous = target
@@ -233,7 +205,13 @@ export function bootstrapLevelObjects(
     ok: true,
     value: {
       objectRegistry,
-      cube: projected.value,
+      cube: {
+        ...projected.value,
+        regions: config.regions ?? [],
+        timeWraps: config.timeWraps ?? false,
+        xWraps: config.xWraps ?? false,
+        yWraps: config.yWraps ?? false,
+      },
       objects: resolvedObjects,
     },
   }
@@ -0,0 +1,85 @@
+import { describe, expect, it } from 'vitest'
+
+import type { LevelObjectsConfig } from '../core/objects'
+import { objectsAtTime } from '../core/timeCube'
+import { cropLevelObjects, resizeLevelObjects } from './levelResize'
+
+function sampleConfig(): LevelObjectsConfig {
+  return {
+    archetypes: {
+      wall: {
+        kind: 'wall',
+        components: [{ kind: 'BlocksMovement' }, { kind: 'TimePersistent' }],
+        render: {},
+      },
+      guard: {
+        kind: 'enemy',
+        components: [
+          { kind: 'BlocksMovement' },
+          { kind: 'TimePersistent' },
+          { kind: 'Patrol', path: [{ x: 3, y: 3 }, { x: 4, y: 3 }], loops: true },
+        ],
+        render: {},
+      },
+    },
+    instances: [
+      { id: 'wall.near', archetype: 'wall', position: { x: 1, y: 1, t: 0 } },
+      { id: 'wall.far', archetype: 'wall', position: { x: 6, y: 6, t: 0 } },
+      { id: 'guard.a', archetype: 'guard', position: { x: 3, y: 3, t: 0 } },
+    ],
+    regions: [{ id: 'yard', name: 'Yard', x: 2, y: 2, width: 6, height: 6 }],
+  }
+}
+
+describe('resizeLevelObjects', () => {
+  it('reports objects that would fall outside instead of dropping them', () => {
+    const result = resizeLevelObjects(
+      sampleConfig(),
+      { width: 5, height: 5, timeDepth: 4 },
+      'report',
+    )
+
+    expect(result).toEqual({ ok: false, error: { kind: 'ObjectsOutside', ids: ['wall.far'] } })
+  })
+
+  it('clips outside objects and regions, then projects patrols again', () => {
+    const result = resizeLevelObjects(
+      sampleConfig(),
+      { width: 5, height: 5, timeDepth: 4 },
+      'clip',
+    )
+
+    expect(result.ok).toBe(true)
+    if (!result.ok) {
+      return
+    }
+
+    const { bootstrap, clippedIds, config } = result.value
+
+    expect(clippedIds).toEqual(['wall.far'])
+    expect(config.regions).toEqual([{ id: 'yard', name: 'Yard', x: 2, y: 2, width: 3, height: 3 }])
+    expect(bootstrap.cube.timeDepth).toBe(4)
+    const guard = objectsAtTime(bootstrap.cube, 3).find((object) => object.id === 'guard.a')
+
+    expect(guard?.position).toEqual({ x: 4, y: 3, t: 3 })
+  })
+})
+
+describe('cropLevelObjects', () => {
+  it('moves objects, patrol paths and regions into the cropped frame', () => {
+    const result = cropLevelObjects(sampleConfig(), 4, { x: 2, y: 2, width: 4, height: 3 }, 'clip')
+
+    expect(result.ok).toBe(true)
+    if (!result.ok) {
+      return
+    }
+
+    expect(result.value.clippedIds).toEqual(['wall.near', 'wall.far'])
+    expect(result.value.config.regions).toEqual([
+      { id: 'yard', name: 'Yard', x: 0, y: 0, width: 4, height: 3 },
+    ])
+    expect(
+      objectsAtTime(result.value.bootstrap.cube, 1).map((object) => [object.id, object.position]),
+    ).toEqual([['guard.a', { x: 2, y: 1, t: 1 }]])
+  })
+})
@@ -0,0 +1,191 @@
+import type { Component } from '../core/components'
+import type { LevelObjectsConfig, ObjectArchetype, ObjectInstance } from '../core/objects'
+import type { Position2D, Position3D } from '../core/position'
+import type { Region } from '../core/regions'
+import type { Result } from '../core/result'
+import {
+  bootstrapLevelObjects,
+  type BootstrapObjectsResult,
+  type ObjectBootstrapError,
+} from './levelObjects'
+
+export interface CubeBounds {
+  width: number
+  height: number
+  timeDepth: number
+}
+
+/** What to do with objects that no longer fit: drop them, or fail listing them. */
+export type OutsidePolicy = 'clip' | 'report'
+
+export type ResizeError =
+  | { kind: 'InvalidBounds'; bounds: CubeBounds }
+  | { kind: 'ObjectsOutside'; ids: string[] }
+  | ObjectBootstrapError
+
+export interface ResizedLevel {
+  config: LevelObjectsConfig
+  bounds: CubeBounds
+  bootstrap: BootstrapObjectsResult
+  /** Objects dropped under the `clip` policy; always empty under `report`. */
+  clippedIds: string[]
+}
+
+function shiftPoint<T extends Position2D>(point: T, dx: number, dy: number): T {
+  return { ...point, x: point.x + dx, y: point.y + dy }
+}
+
+function shiftComponent(component: Component, dx: number, dy: number): Component {
+  switch (component.kind) {
+    case 'Patrol':
+    case 'Searchlight':
+      return { ...component, path: component.path.map((point) => shiftPoint(point, dx, dy)) }
+    case 'Rift':
+      return { ...component, target: shiftPoint(component.target, dx, dy) }
+    default:
+      return component
+  }
+}
+
+/** Cells a component refers to besides its owner's own position. */
+function componentPoints(component: Component): (Position2D | Position3D)[] {
+  switch (component.kind) {
+    case 'Patrol':
+    case 'Searchlight':
+      return component.path
+    case 'Rift':
+      return [component.target]
+    default:
+      return []
+  }
+}
+
+function fits(point: Position2D | Position3D, bounds: CubeBounds): boolean {
+  const inPlane =
+    point.x >= 0 && point.y >= 0 && point.x < bounds.width && point.y < bounds.height
+
+  return inPlane && (!('t' in point) || (point.t >= 0 && point.t < bounds.timeDepth))
+}
+
+function instanceFits(
+  instance: ObjectInstance,
+  archetypes: Record<string, ObjectArchetype>,
+  bounds: CubeBounds,
+): boolean {
+  const components =
+    instance.overrides?.components ?? archetypes[instance.archetype]?.components ?? []
+
+  return (
+    fits(instance.position, bounds) &&
+    components.every((component) =>
+      componentPoints(component).every((point) => fits(point, bounds)),
+    )
+  )
+}
+
+function clipRegion(region: Region, bounds: CubeBounds): Region | null {
+  const x = Math.max(0, region.x)
+  const y = Math.max(0, region.y)
+  const width = Math.min(bounds.width, region.x + region.width) - x
+  const height = Math.min(bounds.height, region.y + region.height) - y
+
+  return width > 0 && height > 0 ? { ...region, x, y, width, height } : null
+}
+
+function shiftConfig(config: LevelObjectsConfig, dx: number, dy: number): LevelObjectsConfig {
+  if (dx === 0 && dy === 0) {
+    return config
+  }
+
+  const archetypes: Record<string, ObjectArchetype> = {}
+
+  for (const [key, archetype] of Object.entries(config.archetypes)) {
+    archetypes[key] = {
+      ...archetype,
+      components: archetype.components.map((component) => shiftComponent(component, dx, dy)),
+    }
+  }
+
+  const instances = config.instances.map((instance): ObjectInstance => {
+    const components = instance.overrides?.components
+    const shifted = { ...instance, position: shiftPoint(instance.position, dx, dy) }
+
+    return components
+      ? {
+          ...shifted,
+          overrides: {
+            ...instance.overrides,
+            components: components.map((component) => shiftComponent(component, dx, dy)),
+          },
+        }
+      : shifted
+  })
+  const regions = config.regions?.map((region) => shiftPoint(region, dx, dy))
+
+  return regions ? { archetypes, instances, regions } : { archetypes, instances }
+}
+
+/**
+ * Rebuild a level's cube at new dimensions. The config, not the current cube, is the source:
+ * objects are placed again and patrols projected again, so no slice keeps stale occupancy.
+ * Regions are clipped to the new map silently; objects are clipped or reported per `outside`.
+ */
+export function resizeLevelObjects(
+  config: LevelObjectsConfig,
+  bounds: CubeBounds,
+  outside: OutsidePolicy,
+): Result<ResizedLevel, ResizeError> {
+  if (
+    !Number.isInteger(bounds.width) ||
+    !Number.isInteger(bounds.height) ||
+    !Number.isInteger(bounds.timeDepth) ||
+    bounds.width < 1 ||
+    bounds.height < 1 ||
+    bounds.timeDepth < 1
+  ) {
+    return { ok: false, error: { kind: 'InvalidBounds', bounds } }
+  }
+
+  const outsideIds = config.instances
+    .filter((instance) => !instanceFits(instance, config.archetypes, bounds))
+    .map((instance) => instance.id)
+
+  if (outsideIds.length > 0 && outside === 'report') {
+    return { ok: false, error: { kind: 'ObjectsOutside', ids: outsideIds } }
+  }
+
+  const clipped = new Set(outsideIds)
+  const regions = config.regions?.flatMap((region) => clipRegion(region, bounds) ?? [])
+  const resized: LevelObjectsConfig = {
+    archetypes: config.archetypes,
+    instances: config.instances.filter((instance) => !clipped.has(instance.id)),
+    ...(regions ? { regions } : {}),
+  }
+  const bootstrap = bootstrapLevelObjects(bounds.width, bounds.height, bounds.timeDepth, resized)
+
+  if (!bootstrap.ok) {
+    return bootstrap
+  }
+
+  return {
+    ok: true,
+    value: { config: resized, bounds, bootstrap: bootstrap.value, clippedIds: outsideIds },
+  }
+}
+
+/**
+ * Cut `region` out of the map: everything moves by (-region.x, -region.y), including patrol and
+ * searchlight paths and rift targets, and the map shrinks to the region. Time depth is kept.
+ */
+export function cropLevelObjects(
+  config: LevelObjectsConfig,
+  timeDepth: number,
+  region: Pick<Region, 'x' | 'y' | 'width' | 'height'>,
+  outside: OutsidePolicy,
+): Result<ResizedLevel, ResizeError> {
+  return resizeLevelObjects(
+    shiftConfig(config, -region.x, -region.y),
+    { width: region.width, height: region.height, timeDepth },
+    outside,
+  )
+}
@@ -0,0 +1,52 @@
+import { describe, expect, it } from 'vitest'
+
+import type { ResolvedObjectInstance } from '../core/objects'
+import { applyRelocationsFromTime, createTimeCube, placeObjects } from '../core/timeCube'
+import { changedCells, diffCubeObjects } from './stateDiff'
+
+function box(id: string, x: number): ResolvedObjectInstance {
+  return {
+    id,
+    archetypeKey: 'box',
+    position: { x, y: 1, t: 0 },
+    archetype: {
+      kind: 'box',
+      components: [{ kind: 'BlocksMovement' }, { kind: 'TimePersistent' }],
+      render: {},
+    },
+  }
+}
+
+describe('state diff', () => {
+  it('lists the cells an object left and entered from the slice it moved on', () => {
+    const placed = placeObjects(createTimeCube(5, 3, 4), [box('box.a', 1), box('box.b', 3)])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const moved = applyRelocationsFromTime(placed.value, 2, [
+      { id: 'box.a', from: { x: 1, y: 1, t: 2 }, to: { x: 2, y: 1, t: 2 } },
+    ])
+
+    expect(moved.ok).toBe(true)
+    if (!moved.ok) {
+      return
+    }
+
+    const changes = diffCubeObjects(placed.value, moved.value)
+
+    expect(changes).toEqual([
+      { id: 'box.a', t: 2, from: { x: 1, y: 1 }, to: { x: 2, y: 1 } },
+      { id: 'box.a', t: 3, from: { x: 1, y: 1 }, to: { x: 2, y: 1 } },
+    ])
+    expect(changedCells(changes)).toEqual([
+      { x: 1, y: 1, t: 2 },
+      { x: 2, y: 1, t: 2 },
+      { x: 1, y: 1, t: 3 },
+      { x: 2, y: 1, t: 3 },
+    ])
+    expect(diffCubeObjects(placed.value, placed.value)).toEqual([])
+  })
+})
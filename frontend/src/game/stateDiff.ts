@@ -0,0 +1,102 @@
+import type { Position2D, Position3D } from '../core/position'
+import { objectPositionAt, type TimeCube } from '../core/timeCube'
+import type { GamePhase, InteractionState } from './interactions/types'
+
+/** One object's cell in one slice before and after; null means absent from that slice. */
+export interface ObjectCellChange {
+  id: string
+  t: number
+  from: Position2D | null
+  to: Position2D | null
+}
+
+/**
+ * What changed between two snapshots of a run, small enough to send after every action.
+ * `worldLine.replaced` is set when the path was swapped (character switch, restart) rather
+ * than extended; `path` then holds the whole new path instead of just the new cells.
+ */
+export interface StateDiff {
+  turn: number
+  currentTime: number
+  phase: GamePhase
+  status: string
+  activeCharacterId: string
+  worldLine: { replaced: boolean; path: Position3D[] }
+  objects: ObjectCellChange[]
+}
+
+function samePosition(a: Position3D, b: Position3D): boolean {
+  return a.x === b.x && a.y === b.y && a.t === b.t
+}
+
+function worldLineChange(prev: Position3D[], next: Position3D[]): StateDiff['worldLine'] {
+  const isExtension =
+    next.length >= prev.length &&
+    prev.every((position, index) => samePosition(position, next[index]))
+
+  return isExtension
+    ? { replaced: false, path: next.slice(prev.length) }
+    : { replaced: true, path: next }
+}
+
+function sameCell(a: Position3D | null, b: Position3D | null): boolean {
+  return a === null || b === null ? a === b : a.x === b.x && a.y === b.y
+}
+
+function cell(position: Position3D | null): Position2D | null {
+  return position ? { x: position.x, y: position.y } : null
+}
+
+/** Every object cell that differs between two cubes, over the slices of `next`. */
+export function diffCubeObjects(prev: TimeCube, next: TimeCube): ObjectCellChange[] {
+  const ids = new Set([...Object.keys(prev.objectsById), ...Object.keys(next.objectsById)])
+  const objects: ObjectCellChange[] = []
+
+  for (const id of [...ids].sort()) {
+    for (let t = 0; t < next.timeDepth; t += 1) {
+      const from = objectPositionAt(prev, id, t)
+      const to = objectPositionAt(next, id, t)
+
+      if (!sameCell(from, to)) {
+        objects.push({ id, t, from: cell(from), to: cell(to) })
+      }
+    }
+  }
+
+  return objects
+}
+
+/** Cells an object left or entered, once per slice, in the order the changes list them. */
+export function changedCells(changes: ObjectCellChange[]): Position3D[] {
+  const seen = new Set<string>()
+  const cells: Position3D[] = []
+
+  for (const change of changes) {
+    for (const position of [change.from, change.to]) {
+      if (!position) {
+        continue
+      }
+
+      const key = `${position.x},${position.y},${change.t}`
+
+      if (!seen.has(key)) {
+        seen.add(key)
+        cells.push({ x: position.x, y: position.y, t: change.t })
+      }
+    }
+  }
+
+  return cells
+}
+
+export function diffInteractionState(prev: InteractionState, next: InteractionState): StateDiff {
+  return {
+    turn: next.turn,
+    currentTime: next.currentTime,
+    phase: next.phase,
+    status: next.status,
+    activeCharacterId: next.activeCharacterId,
+    worldLine: worldLineChange(prev.worldLine.path, next.worldLine.path),
+    objects: diffCubeObjects(prev.cube, next.cube),
+  }
+}
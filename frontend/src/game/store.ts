@@ -1,12 +1,29 @@
 import { configureStore } from '@reduxjs/toolkit'
 
+import {
+  connectWebSocketSink,
+  createBroadcastMiddleware,
+  type BroadcastTarget,
+  type DiffSink,
+} from '../net/broadcast'
 import { gameReducer } from './gameSlice'
 
+let spectatorSink: DiffSink | null = null
+
+/** Opens the spectator socket; until then (and by default) no diffs are sent anywhere. */
+export function startSpectatorBroadcast(target: BroadcastTarget): void {
+  spectatorSink ??= connectWebSocketSink(target)
+}
+
+const broadcastMiddleware = createBroadcastMiddleware(() => spectatorSink)
+
 export const store = configureStore({
   reducer: {
     game: gameReducer,
   },
+  middleware: (getDefaultMiddleware) => getDefaultMiddleware().concat(broadcastMiddleware),
 })
 
+export type AppStore = typeof store
 export type RootState = ReturnType<typeof store.getState>
 export type AppDispatch = typeof store.dispatch
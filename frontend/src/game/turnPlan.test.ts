@@ -0,0 +1,85 @@
+import { describe, expect, it } from 'vitest'
+
+import { commitTurnPlan, configureDetectionConfig, gameReducer } from './gameSlice'
+import { diffTurnPlan, previewTurnPlan, simulateTurnPlan } from './turnPlan'
+
+describe('previewTurnPlan', () => {
+  it('predicts each queued step without touching the state', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+
+    const preview = previewTurnPlan(initial, [
+      { kind: 'Move', direction: 'east' },
+      { kind: 'Move', direction: 'north' },
+      { kind: 'Wait' },
+    ])
+
+    expect(initial.turn).toBe(0)
+    expect(preview.map((step) => [step.committed, step.position])).toEqual([
+      [true, { x: 6, y: 5, t: 1 }],
+      [true, { x: 6, y: 4, t: 2 }],
+      [true, { x: 6, y: 4, t: 3 }],
+    ])
+    expect(preview.every((step) => step.seenBy.length === 0)).toBe(true)
+  })
+
+  it('flags a rejected step and the guard that would spot the player', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const watched = gameReducer(
+      initial,
+      configureDetectionConfig({ enabled: true, delayTurns: 1, maxDistance: 8 }),
+    )
+
+    const blocked = previewTurnPlan(initial, [{ kind: 'Move', direction: 'north' }])
+    const seen = previewTurnPlan(watched, [{ kind: 'Wait' }, { kind: 'Wait' }])
+
+    expect(blocked[0]).toMatchObject({ committed: false, status: 'Blocked by object' })
+    expect(seen[0]).toMatchObject({ committed: true, phase: 'Detected', seenBy: ['enemy.alpha'] })
+    expect(seen[1].committed).toBe(false)
+  })
+})
+
+describe('diffTurnPlan', () => {
+  it('reports where a push leaves the box and the player in the final slice', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const { final } = simulateTurnPlan(initial, [
+      { kind: 'Move', direction: 'east' },
+      { kind: 'Move', direction: 'east' },
+      { kind: 'Move', direction: 'south' },
+      { kind: 'Push', direction: 'east' },
+    ])
+
+    expect(diffTurnPlan(initial, final)).toEqual({
+      time: 4,
+      player: { x: 8, y: 6, t: 4 },
+      moves: [{ id: 'box.main', kind: 'box', from: { x: 8, y: 6 }, to: { x: 9, y: 6 } }],
+    })
+  })
+
+  it('is null when no step would be committed', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+    const { final } = simulateTurnPlan(initial, [{ kind: 'Move', direction: 'north' }])
+
+    expect(diffTurnPlan(initial, final)).toBeNull()
+  })
+})
+
+describe('commitTurnPlan', () => {
+  it('commits queued steps until one fails', () => {
+    const initial = gameReducer(undefined, { type: 'init' })
+
+    const committed = gameReducer(
+      initial,
+      commitTurnPlan([
+        { kind: 'Move', direction: 'east' },
+        { kind: 'Move', direction: 'east' },
+        { kind: 'Move', direction: 'south' },
+        { kind: 'Move', direction: 'east' },
+        { kind: 'Wait' },
+      ]),
+    )
+
+    expect(committed.turn).toBe(3)
+    expect(committed.worldLine.path.at(-1)).toEqual({ x: 7, y: 6, t: 3 })
+    expect(committed.status).toBe('Blocked by object')
+  })
+})
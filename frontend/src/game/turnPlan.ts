@@ -0,0 +1,182 @@
+import type { Position2D, Position3D } from '../core/position'
+import type { Result } from '../core/result'
+import { allObjects, objectPositionAt } from '../core/timeCube'
+import { currentPosition, positionKey } from '../core/worldLine'
+import { planMoveTo, type MoveToError } from './interactions/moveTo'
+import { runInteractionPipeline } from './interactions/pipeline'
+import type { GamePhase, InteractionAction, InteractionState } from './interactions/types'
+
+/** Predicted result of one queued action, given every step before it went as predicted. */
+export interface PlannedStepPreview {
+  action: InteractionAction
+  committed: boolean
+  status: string
+  position: Position3D | null
+  phase: GamePhase
+  /** Enemies that would see the player as a result of this step. */
+  seenBy: string[]
+}
+
+/** An object the plan would move: its cell in the plan's final slice now, and after the plan. */
+export interface PlannedObjectMove {
+  id: string
+  kind: string
+  from: Position2D
+  /** Null when the plan removes the object from that slice (broken, sunk). */
+  to: Position2D | null
+}
+
+/** What the board would look like once the plan is played, as a difference from now. */
+export interface TurnPlanDiff {
+  /** Slice the player ends the plan in; moves are compared there. */
+  time: number
+  player: Position3D | null
+  moves: PlannedObjectMove[]
+}
+
+export interface TurnPlanSimulation<S> {
+  steps: PlannedStepPreview[]
+  final: S
+}
+
+/**
+ * Play a queued plan on one detached copy of the state and report each step. Steps after one
+ * that fails or ends the run are still listed (as not committed) so the plan can be edited.
+ */
+export function previewTurnPlan<S extends InteractionState>(
+  state: S,
+  actions: InteractionAction[],
+): PlannedStepPreview[] {
+  return simulateTurnPlan(state, actions).steps
+}
+
+/** `previewTurnPlan`, also handing back the detached state the plan ends in. */
+export function simulateTurnPlan<S extends InteractionState>(
+  state: S,
+  actions: InteractionAction[],
+): TurnPlanSimulation<S> {
+  const simulated = structuredClone(state)
+
+  const steps = actions.map((action) => {
+    const historyLength = simulated.history.length
+    runInteractionPipeline(simulated, action)
+    const committed = simulated.history.length > historyLength
+    const position = currentPosition(simulated.worldLine)
+
+    return {
+      action,
+      committed,
+      status: simulated.status,
+      position: position ? { ...position } : null,
+      phase: simulated.phase,
+      seenBy:
+        committed && simulated.lastDetection
+          ? simulated.lastDetection.events.map((event) => event.enemyId)
+          : [],
+    }
+  })
+
+  return { steps, final: simulated }
+}
+
+/**
+ * Objects whose cell in the plan's final slice differs between `before` and `after`, and where
+ * the player ends up. Null when nothing was committed.
+ */
+export function diffTurnPlan(
+  before: InteractionState,
+  after: InteractionState,
+): TurnPlanDiff | null {
+  if (after.history.length === before.history.length) {
+    return null
+  }
+
+  const player = currentPosition(after.worldLine)
+  const time = player?.t ?? after.currentTime
+  const moves: PlannedObjectMove[] = []
+
+  for (const object of allObjects(before.cube)) {
+    const from = objectPositionAt(before.cube, object.id, time)
+
+    if (!from) {
+      continue
+    }
+
+    const to = after.cube.objectsById[object.id]
+      ? objectPositionAt(after.cube, object.id, time)
+      : null
+
+    if (to && to.x === from.x && to.y === from.y) {
+      continue
+    }
+
+    moves.push({
+      id: object.id,
+      kind: object.archetype.kind,
+      from: { x: from.x, y: from.y },
+      to: to ? { x: to.x, y: to.y } : null,
+    })
+  }
+
+  return { time, player: player ? { ...player } : null, moves }
+}
+
+/**
+ * Expand a MoveTo into one `MoveTo` turn per planned step, then play them on a detached copy.
+ * Succeeds only if every step commits, lands where planned and leaves the run going (winning on
+ * the last step is fine), so the caller can replay the actions knowing none of them fails
+ * halfway. The actions carry the resolved arrival cell, so a same-slice target stays put between
+ * steps. Hotseat is refused: control would pass to another character after one step.
+ */
+export function rehearseMoveTo<S extends InteractionState>(
+  state: S,
+  target: Position3D,
+): Result<InteractionAction[], MoveToError> {
+  if (state.hotseat) {
+    return { ok: false, error: { kind: 'HotseatActive' } }
+  }
+
+  const plan = planMoveTo(state, target)
+
+  if (!plan.ok) {
+    return plan
+  }
+
+  const arrival = plan.value.at(-1)?.to ?? target
+  const action: InteractionAction = { kind: 'MoveTo', target: arrival }
+  const rehearsal = structuredClone(state)
+
+  for (const [index, step] of plan.value.entries()) {
+    const historyLength = rehearsal.history.length
+    runInteractionPipeline(rehearsal, action)
+
+    if (rehearsal.history.length === historyLength) {
+      return {
+        ok: false,
+        error: { kind: 'StepRejected', step: index, action: step.action, status: rehearsal.status },
+      }
+    }
+
+    const isLast = index === plan.value.length - 1
+
+    if (rehearsal.phase !== 'Playing' && !(isLast && rehearsal.phase === 'Won')) {
+      return {
+        ok: false,
+        error: {
+          kind: 'WouldEnd',
+          step: index,
+          phase: rehearsal.phase,
+          status: rehearsal.status,
+        },
+      }
+    }
+
+    const actual = currentPosition(rehearsal.worldLine)
+
+    if (!actual || positionKey(actual) !== positionKey(step.to)) {
+      return { ok: false, error: { kind: 'Diverged', step: index, expected: step.to, actual } }
+    }
+  }
+
+  return { ok: true, value: plan.value.map(() => action) }
+}
@@ -0,0 +1,60 @@
+import { describe, expect, it } from 'vitest'
+
+import type { TutorialStepConfig } from '../data/contracts'
+import type { InteractionHistoryEntry } from './interactions/types'
+import { advanceTutorialStep } from './tutorial'
+
+const steps: TutorialStepConfig[] = [
+  { prompt: 'Step east', gate: { kind: 'Action', action: 'Move', direction: 'east' } },
+  { prompt: 'Wait', gate: { kind: 'Action', action: 'Wait' } },
+  { prompt: 'Reach exit', gate: { kind: 'ReachPosition', position: { x: 3, y: 1 } } },
+]
+
+function entry(
+  action: InteractionHistoryEntry['action'],
+  to: { x: number; y: number; t: number },
+): InteractionHistoryEntry {
+  return { turn: to.t, action, outcome: { kind: 'Moved', to } }
+}
+
+describe('advanceTutorialStep', () => {
+  it('only advances when the committed turn matches the gate', () => {
+    const wrongDirection = advanceTutorialStep(
+      steps,
+      0,
+      entry({ kind: 'Move', direction: 'west' }, { x: 0, y: 1, t: 1 }),
+    )
+    const matched = advanceTutorialStep(
+      steps,
+      0,
+      entry({ kind: 'Move', direction: 'east' }, { x: 2, y: 1, t: 1 }),
+    )
+
+    expect(wrongDirection).toBe(0)
+    expect(matched).toBe(1)
+  })
+
+  it('advances position gates and stops after the final step', () => {
+    const reached = advanceTutorialStep(
+      steps,
+      2,
+      entry({ kind: 'Move', direction: 'east' }, { x: 3, y: 1, t: 4 }),
+    )
+    const finished = advanceTutorialStep(steps, 3, entry({ kind: 'Wait' }, { x: 3, y: 1, t: 5 }))
+
+    expect(reached).toBe(3)
+    expect(finished).toBe(3)
+  })
+
+  it('advances region gates once the player stands inside the region', () => {
+    const regions = [{ id: 'vault', name: 'Vault', x: 4, y: 0, width: 2, height: 2 }]
+    const vaultSteps: TutorialStepConfig[] = [
+      { prompt: 'Find the vault', gate: { kind: 'EnterRegion', region: 'vault' } },
+    ]
+    const outside = entry({ kind: 'Move', direction: 'east' }, { x: 3, y: 1, t: 1 })
+    const inside = entry({ kind: 'Move', direction: 'east' }, { x: 4, y: 1, t: 2 })
+
+    expect(advanceTutorialStep(vaultSteps, 0, outside, regions)).toBe(0)
+    expect(advanceTutorialStep(vaultSteps, 0, inside, regions)).toBe(1)
+  })
+})
@@ -0,0 +1,42 @@
+import { regionsAt, type Region } from '../core/regions'
+import type { TutorialGate, TutorialStepConfig } from '../data/contracts'
+import type { InteractionHistoryEntry } from './interactions/types'
+
+export function isTutorialGateSatisfied(
+  gate: TutorialGate,
+  entry: InteractionHistoryEntry,
+  regions: Region[] = [],
+): boolean {
+  switch (gate.kind) {
+    case 'Action':
+      if (entry.action.kind !== gate.action) {
+        return false
+      }
+
+      if (!gate.direction) {
+        return true
+      }
+
+      return 'direction' in entry.action && entry.action.direction === gate.direction
+    case 'ReachPosition':
+      return entry.outcome.to.x === gate.position.x && entry.outcome.to.y === gate.position.y
+    case 'EnterRegion':
+      return regionsAt(regions, entry.outcome.to).some((region) => region.id === gate.region)
+  }
+}
+
+/** Returns the next step index; prompts only advance on a committed turn that meets the gate. */
+export function advanceTutorialStep(
+  steps: TutorialStepConfig[],
+  stepIndex: number,
+  entry: InteractionHistoryEntry,
+  regions: Region[] = [],
+): number {
+  const step = steps[stepIndex]
+
+  if (!step || !isTutorialGateSatisfied(step.gate, entry, regions)) {
+    return stepIndex
+  }
+
+  return stepIndex + 1
+}
@@ -3,13 +3,32 @@ import { createRoot } from 'react-dom/client'
 import { Provider } from 'react-redux'
 
 import App from './App'
-import { store } from './game/store'
+import { CrashBoundary } from './app/CrashBoundary'
+import { loadRuntimeConfig } from './app/runtimeConfig'
+import { setContentPackId } from './game/gameSlice'
+import { startSpectatorBroadcast, store } from './game/store'
+import { resolveBroadcastTarget } from './net/broadcast'
 import './index.css'
 
-createRoot(document.getElementById('root')!).render(
-  <StrictMode>
-    <Provider store={store}>
-      <App />
-    </Provider>
-  </StrictMode>,
-)
+const broadcastTarget = resolveBroadcastTarget(window.location.search, import.meta.env)
+
+if (broadcastTarget) {
+  startSpectatorBroadcast(broadcastTarget)
+}
+
+// Settings, keymap and theme are read before the first render, like a config file at startup.
+void loadRuntimeConfig(window.location.search).then((runtimeConfig) => {
+  if (runtimeConfig.startPackId) {
+    store.dispatch(setContentPackId(runtimeConfig.startPackId))
+  }
+
+  createRoot(document.getElementById('root')!).render(
+    <StrictMode>
+      <Provider store={store}>
+        <CrashBoundary store={store}>
+          <App runtimeConfig={runtimeConfig} />
+        </CrashBoundary>
+      </Provider>
+    </StrictMode>,
+  )
+})
@@ -0,0 +1,58 @@
+import { configureStore } from '@reduxjs/toolkit'
+import { describe, expect, it, vi } from 'vitest'
+
+import { configureParadoxConfig, gameReducer, movePlayer2D } from '../game/gameSlice'
+import {
+  createBroadcastMiddleware,
+  parseBroadcastTarget,
+  resolveBroadcastTarget,
+} from './broadcast'
+
+describe('broadcast', () => {
+  it('parses addr:port targets', () => {
+    expect(parseBroadcastTarget('127.0.0.1:9000')).toEqual({
+      ok: true,
+      value: { host: '127.0.0.1', port: 9000 },
+    })
+    expect(parseBroadcastTarget('localhost').ok).toBe(false)
+    expect(parseBroadcastTarget(':9000').ok).toBe(false)
+    expect(parseBroadcastTarget('localhost:99999').ok).toBe(false)
+  })
+
+  it('only resolves targets on the configured broadcast host', () => {
+    const env = { VITE_HWU_BROADCAST_HOST: 'overlay.local' }
+    const warn = vi.spyOn(console, 'warn').mockImplementation(() => {})
+
+    expect(resolveBroadcastTarget('?pack=default&broadcast=overlay.local:7000', env)).toEqual({
+      host: 'overlay.local',
+      port: 7000,
+    })
+    expect(resolveBroadcastTarget('?pack=default', env)).toBeNull()
+    expect(resolveBroadcastTarget('?broadcast=evil.example:7000', env)).toBeNull()
+    expect(resolveBroadcastTarget('?broadcast=overlay.local:7000', {})).toBeNull()
+
+    warn.mockRestore()
+  })
+
+  it('sends one diff line per applied action and none for settings changes', () => {
+    const lines: string[] = []
+    const broadcast = createBroadcastMiddleware(() => ({
+      send: (line) => lines.push(line),
+    }))
+    const store = configureStore({
+      reducer: { game: gameReducer },
+      middleware: (getDefaultMiddleware) => getDefaultMiddleware().concat(broadcast),
+    })
+
+    store.dispatch(configureParadoxConfig({ enabled: false }))
+    expect(lines).toHaveLength(0)
+
+    store.dispatch(movePlayer2D('east'))
+    expect(lines).toHaveLength(1)
+
+    const message = JSON.parse(lines[0]) as { type: string; diff: { turn: number } }
+
+    expect(message.type).toBe('diff')
+    expect(message.diff.turn).toBe(1)
+  })
+})
@@ -0,0 +1,107 @@
+import type { Middleware } from '@reduxjs/toolkit'
+
+import type { Result } from '../core/result'
+import type { InteractionState } from '../game/interactions/types'
+import { diffInteractionState } from '../game/stateDiff'
+import { encodeMessage } from './protocol'
+
+/** Where spectator diffs go; `?broadcast=host:port` in the page URL, `--broadcast` in scripts. */
+export interface BroadcastTarget {
+  host: string
+  port: number
+}
+
+/** Anything that can carry one encoded protocol line to viewers. */
+export interface DiffSink {
+  send(line: string): void
+}
+
+export function parseBroadcastTarget(value: string): Result<BroadcastTarget, string> {
+  const separator = value.lastIndexOf(':')
+  const host = value.slice(0, separator)
+  const port = Number(value.slice(separator + 1))
+
+  if (separator <= 0 || !Number.isInteger(port) || port < 1 || port > 65535) {
+    return { ok: false, error: `broadcast target must be addr:port (got ${value})` }
+  }
+
+  return { ok: true, value: { host, port } }
+}
+
+interface BroadcastEnv {
+  VITE_HWU_BROADCAST_HOST?: string
+}
+
+/**
+ * Broadcasting is opt-in per build: `VITE_HWU_BROADCAST_HOST` names the only host a page may
+ * stream to, and a `?broadcast=` target anywhere else (or with no host configured) is ignored.
+ */
+export function resolveBroadcastTarget(search: string, env: BroadcastEnv): BroadcastTarget | null {
+  const value = new URLSearchParams(search).get('broadcast')
+
+  if (!value) {
+    return null
+  }
+
+  const parsed = parseBroadcastTarget(value)
+
+  if (!parsed.ok) {
+    console.warn(`[broadcast] ${parsed.error}`)
+    return null
+  }
+
+  if (!env.VITE_HWU_BROADCAST_HOST) {
+    console.warn('[broadcast] set VITE_HWU_BROADCAST_HOST to enable spectator broadcast')
+    return null
+  }
+
+  if (parsed.value.host !== env.VITE_HWU_BROADCAST_HOST) {
+    console.warn(`[broadcast] ${parsed.value.host} is not the configured broadcast host`)
+    return null
+  }
+
+  return parsed.value
+}
+
+/**
+ * Browsers cannot open raw sockets, so the page pushes lines to a WebSocket listener (an overlay
+ * or relay) instead. Lines produced before the socket opens are dropped, not queued.
+ */
+export function connectWebSocketSink(target: BroadcastTarget): DiffSink {
+  const socket = new WebSocket(`ws://${target.host}:${target.port}`)
+
+  return {
+    send(line) {
+      if (socket.readyState === WebSocket.OPEN) {
+        socket.send(line)
+      }
+    },
+  }
+}
+
+/**
+ * Sends a diff line whenever an action changed the run. Immer keeps untouched branches by
+ * reference, so settings and status-only updates are skipped without comparing contents.
+ * `sink` is asked on every action; while it returns null nothing is diffed or sent.
+ */
+export function createBroadcastMiddleware(
+  sink: () => DiffSink | null,
+): Middleware<object, { game: InteractionState }> {
+  return (api) => (next) => (action) => {
+    const target = sink()
+
+    if (!target) {
+      return next(action)
+    }
+
+    const previous = api.getState().game
+    const result = next(action)
+    const current = api.getState().game
+
+    if (previous.worldLine !== current.worldLine || previous.cube !== current.cube) {
+      target.send(encodeMessage({ type: 'diff', diff: diffInteractionState(previous, current) }))
+    }
+
+    return result
+  }
+}
@@ -0,0 +1,159 @@
+import type { Direction2D, Position2D, Position3D } from '../core/position'
+import type { Result } from '../core/result'
+import type { RiftInstruction } from '../core/rift'
+import type { InteractionAction } from '../game/interactions/types'
+import type { StateDiff } from '../game/stateDiff'
+import type { GuardPlan } from './versus'
+
+/**
+ * Versus wire format: one JSON object per line over TCP. The server owns the run; the guard
+ * planner submits a plan first, then the sneaker sends actions and both receive the diffs.
+ */
+export type VersusRole = 'planner' | 'sneaker'
+
+export type ClientMessage =
+  | { type: 'join'; role: VersusRole }
+  | { type: 'plan'; plan: GuardPlan }
+  | { type: 'action'; action: InteractionAction }
+
+export type ServerMessage =
+  | { type: 'welcome'; role: VersusRole; packId: string; budget: number }
+  | { type: 'planAccepted'; cost: number }
+  | { type: 'start' }
+  | { type: 'diff'; diff: StateDiff }
+  | { type: 'end'; phase: StateDiff['phase']; turn: number }
+  | { type: 'error'; message: string }
+
+export function encodeMessage(message: ClientMessage | ServerMessage): string {
+  return `${JSON.stringify(message)}\n`
+}
+
+function isObject(value: unknown): value is Record<string, unknown> {
+  return typeof value === 'object' && value !== null && !Array.isArray(value)
+}
+
+const DIRECTIONS: readonly string[] = ['north', 'east', 'south', 'west']
+
+function isDirection(value: unknown): value is Direction2D {
+  return typeof value === 'string' && DIRECTIONS.includes(value)
+}
+
+function isInteger(value: unknown): value is number {
+  return typeof value === 'number' && Number.isInteger(value)
+}
+
+function isPosition2D(value: unknown): value is Position2D {
+  return isObject(value) && isInteger(value.x) && isInteger(value.y)
+}
+
+function isPosition3D(value: unknown): value is Position3D {
+  return isObject(value) && isInteger(value.x) && isInteger(value.y) && isInteger(value.t)
+}
+
+function isRiftInstruction(value: unknown): value is RiftInstruction {
+  if (!isObject(value)) {
+    return false
+  }
+
+  switch (value.kind) {
+    case 'default':
+      return true
+    case 'delta':
+      return (
+        isInteger(value.delta) &&
+        (value.targetSpatial === undefined || isPosition2D(value.targetSpatial))
+      )
+    case 'tunnel':
+      return (
+        isPosition3D(value.target) &&
+        (value.tunnelId === undefined || typeof value.tunnelId === 'string')
+      )
+    default:
+      return false
+  }
+}
+
+/**
+ * An action with every field its kind needs; handlers trust their action's shape, so anything
+ * else from the wire is refused here.
+ */
+function parseAction(value: unknown): InteractionAction | null {
+  if (!isObject(value)) {
+    return null
+  }
+
+  switch (value.kind) {
+    case 'Move':
+    case 'Push':
+    case 'Pull':
+    case 'TimePush':
+    case 'Break':
+    case 'Interact':
+      return isDirection(value.direction) ? { kind: value.kind, direction: value.direction } : null
+    case 'Throw':
+      return isDirection(value.direction) && isInteger(value.range)
+        ? { kind: 'Throw', direction: value.direction, range: value.range }
+        : null
+    case 'Wait':
+    case 'SwitchCharacter':
+    case 'Climb':
+      return { kind: value.kind }
+    case 'ApplyRift':
+      if (value.instruction === undefined) {
+        return { kind: 'ApplyRift' }
+      }
+
+      return isRiftInstruction(value.instruction)
+        ? { kind: 'ApplyRift', instruction: value.instruction }
+        : null
+    case 'MoveTo':
+      return isPosition3D(value.target) ? { kind: 'MoveTo', target: value.target } : null
+    default:
+      return null
+  }
+}
+
+/** Shape-checks one line from a client; the plan contents are checked by the game. */
+export function parseClientMessage(line: string): Result<ClientMessage, string> {
+  let parsed: unknown
+
+  try {
+    parsed = JSON.parse(line)
+  } catch {
+    return { ok: false, error: 'message is not valid JSON' }
+  }
+
+  if (!isObject(parsed)) {
+    return { ok: false, error: 'message must be an object' }
+  }
+
+  switch (parsed.type) {
+    case 'join':
+      return parsed.role === 'planner' || parsed.role === 'sneaker'
+        ? { ok: true, value: { type: 'join', role: parsed.role } }
+        : { ok: false, error: 'join.role must be planner or sneaker' }
+    case 'plan':
+      return isObject(parsed.plan) &&
+        Array.isArray(parsed.plan.placements) &&
+        Array.isArray(parsed.plan.reroutes)
+        ? { ok: true, value: { type: 'plan', plan: parsed.plan as unknown as GuardPlan } }
+        : { ok: false, error: 'plan needs placements and reroutes arrays' }
+    case 'action': {
+      const action = parseAction(parsed.action)
+
+      return action
+        ? { ok: true, value: { type: 'action', action } }
+        : { ok: false, error: 'action needs a known kind and its fields' }
+    }
+    default:
+      return { ok: false, error: `unknown message type ${String(parsed.type)}` }
+  }
+}
+
+/** Splits a TCP stream into lines, keeping the trailing partial line for the next chunk. */
+export function splitLines(buffer: string, chunk: string): { lines: string[]; rest: string } {
+  const parts = `${buffer}${chunk}`.split('\n')
+  const rest = parts.pop() ?? ''
+
+  return { lines: parts.filter((line) => line.trim().length > 0), rest }
+}
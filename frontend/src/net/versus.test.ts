@@ -0,0 +1,149 @@
+import { describe, expect, it } from 'vitest'
+
+import defaultBehavior from '../data/content/default.behavior.json'
+import defaultLevel from '../data/content/default.level.json'
+import defaultRules from '../data/content/default.rules.json'
+import defaultTheme from '../data/content/default.theme.json'
+import type { ContentPack } from '../data/contracts'
+import { createInitialSolverState } from '../data/generation/solver'
+import { validateContentPack } from '../data/validate'
+import { runInteractionPipeline } from '../game/interactions/pipeline'
+import { diffInteractionState } from '../game/stateDiff'
+import { encodeMessage, parseClientMessage, splitLines } from './protocol'
+import { applyGuardPlan, guardPlanCost } from './versus'
+
+function defaultPack(): ContentPack {
+  const validated = validateContentPack({
+    level: defaultLevel,
+    behavior: defaultBehavior,
+    rules: defaultRules,
+    theme: defaultTheme,
+  })
+
+  if (!validated.ok) {
+    throw new Error('default pack should validate')
+  }
+
+  return validated.value
+}
+
+describe('versus guard plans', () => {
+  it('charges moved cells plus route waypoints', () => {
+    const pack = defaultPack()
+
+    expect(
+      guardPlanCost(pack, {
+        placements: [{ instanceId: 'enemy.alpha', position: { x: 3, y: 9 } }],
+        reroutes: [
+          {
+            instanceId: 'enemy.alpha',
+            path: [
+              { x: 3, y: 9 },
+              { x: 4, y: 9 },
+            ],
+          },
+        ],
+      }),
+    ).toBe(4)
+  })
+
+  it('moves and re-routes a guard within budget', () => {
+    const result = applyGuardPlan(
+      defaultPack(),
+      {
+        placements: [{ instanceId: 'enemy.alpha', position: { x: 3, y: 8 } }],
+        reroutes: [
+          {
+            instanceId: 'enemy.alpha',
+            path: [
+              { x: 3, y: 8 },
+              { x: 3, y: 7 },
+            ],
+            pingPong: true,
+          },
+        ],
+      },
+      3,
+    )
+
+    expect(result.ok).toBe(true)
+    if (!result.ok) {
+      return
+    }
+
+    expect(result.value.level.instances.find((i) => i.id === 'enemy.alpha')?.position).toEqual({
+      x: 3,
+      y: 8,
+      t: 0,
+    })
+    expect(result.value.behavior.assignments['enemy.alpha']).toBe('versus_enemy.alpha')
+    expect(result.value.behavior.policies['versus_enemy.alpha']?.kind).toBe('PatrolPingPong')
+  })
+
+  it('rejects plans over budget or touching non-guards', () => {
+    const pack = defaultPack()
+    const move = { instanceId: 'enemy.alpha', position: { x: 0, y: 0 } }
+
+    expect(applyGuardPlan(pack, { placements: [move], reroutes: [] }, 3)).toEqual({
+      ok: false,
+      error: { kind: 'OverBudget', cost: 10, budget: 3 },
+    })
+    expect(
+      applyGuardPlan(
+        pack,
+        { placements: [{ instanceId: 'box.main', position: { x: 8, y: 7 } }], reroutes: [] },
+        3,
+      ),
+    ).toEqual({ ok: false, error: { kind: 'UnknownGuard', instanceId: 'box.main' } })
+  })
+
+  it('rejects plans that route a guard off the map', () => {
+    const result = applyGuardPlan(
+      defaultPack(),
+      { placements: [], reroutes: [{ instanceId: 'enemy.alpha', path: [{ x: 12, y: 8 }] }] },
+      10,
+    )
+
+    expect(result.ok).toBe(false)
+    expect(!result.ok && result.error.kind).toBe('InvalidPack')
+  })
+})
+
+describe('versus protocol', () => {
+  it('round-trips client messages through JSON lines', () => {
+    const line = encodeMessage({ type: 'action', action: { kind: 'Wait' } })
+    const split = splitLines('', `${line}{"type":"jo`)
+
+    expect(split.rest).toBe('{"type":"jo')
+    expect(split.lines.map(parseClientMessage)).toEqual([
+      { ok: true, value: { type: 'action', action: { kind: 'Wait' } } },
+    ])
+    expect(parseClientMessage('{"type":"join","role":"referee"}').ok).toBe(false)
+    expect(parseClientMessage('not json').ok).toBe(false)
+
+    for (const action of ['{"kind":"Fly"}', '{"kind":"Move"}', '{"kind":"Throw","range":2}']) {
+      expect(parseClientMessage(`{"type":"action","action":${action}}`).ok).toBe(false)
+    }
+
+    expect(
+      parseClientMessage('{"type":"action","action":{"kind":"Move","direction":"east","x":1}}'),
+    ).toEqual({ ok: true, value: { type: 'action', action: { kind: 'Move', direction: 'east' } } })
+  })
+
+  it('reports one step of the sneaker as a state diff', () => {
+    const state = createInitialSolverState(defaultPack())
+
+    expect(state).not.toBeNull()
+    if (!state) {
+      return
+    }
+
+    const previous = structuredClone(state)
+    runInteractionPipeline(state, { kind: 'Move', direction: 'east' })
+    const diff = diffInteractionState(previous, state)
+
+    expect(diff.turn).toBe(1)
+    expect(diff.worldLine.replaced).toBe(false)
+    expect(diff.worldLine.path).toHaveLength(1)
+  })
+})
@@ -0,0 +1,95 @@
+import { manhattanDistance, type Position2D } from '../core/position'
+import type { Result } from '../core/result'
+import type { ContentLoadError, ContentPack } from '../data/contracts'
+import { validateContentPack } from '../data/validate'
+
+/** Moves a guard's starting cell; costs one point per cell moved. */
+export interface GuardPlacement {
+  instanceId: string
+  position: Position2D
+}
+
+/** Gives a guard a new patrol route; costs one point per waypoint. */
+export interface GuardReroute {
+  instanceId: string
+  path: Position2D[]
+  pingPong?: boolean
+}
+
+/** What the guard planner submits before the run. */
+export interface GuardPlan {
+  placements: GuardPlacement[]
+  reroutes: GuardReroute[]
+}
+
+export type GuardPlanError =
+  | { kind: 'UnknownGuard'; instanceId: string }
+  | { kind: 'OverBudget'; cost: number; budget: number }
+  | { kind: 'InvalidPack'; error: ContentLoadError }
+
+function isGuardInstance(pack: ContentPack, instanceId: string): boolean {
+  const instance = pack.level.instances.find((candidate) => candidate.id === instanceId)
+
+  return instance !== undefined && pack.level.archetypes[instance.archetype]?.kind === 'enemy'
+}
+
+export function guardPlanCost(pack: ContentPack, plan: GuardPlan): number {
+  const placementCost = plan.placements.reduce((total, placement) => {
+    const instance = pack.level.instances.find((candidate) => candidate.id === placement.instanceId)
+
+    return total + (instance ? manhattanDistance(instance.position, placement.position) : 0)
+  }, 0)
+  const rerouteCost = plan.reroutes.reduce((total, reroute) => total + reroute.path.length, 0)
+
+  return placementCost + rerouteCost
+}
+
+/**
+ * Applies the planner's edits to a copy of the pack and re-validates it, so a plan can never
+ * produce a level the loader would reject (off-map guards or patrol routes, ...).
+ */
+export function applyGuardPlan(
+  pack: ContentPack,
+  plan: GuardPlan,
+  budget: number,
+): Result<ContentPack, GuardPlanError> {
+  for (const edit of [...plan.placements, ...plan.reroutes]) {
+    if (!isGuardInstance(pack, edit.instanceId)) {
+      return { ok: false, error: { kind: 'UnknownGuard', instanceId: edit.instanceId } }
+    }
+  }
+
+  const cost = guardPlanCost(pack, plan)
+
+  if (cost > budget) {
+    return { ok: false, error: { kind: 'OverBudget', cost, budget } }
+  }
+
+  const next = structuredClone(pack)
+
+  for (const placement of plan.placements) {
+    const instance = next.level.instances.find((candidate) => candidate.id === placement.instanceId)
+
+    if (instance) {
+      instance.position = { ...instance.position, x: placement.position.x, y: placement.position.y }
+    }
+  }
+
+  for (const reroute of plan.reroutes) {
+    const key = `versus_${reroute.instanceId}`
+
+    next.behavior.policies[key] = {
+      kind: reroute.pingPong ? 'PatrolPingPong' : 'PatrolLoop',
+      path: reroute.path,
+    }
+    next.behavior.assignments[reroute.instanceId] = key
+  }
+
+  const validated = validateContentPack(next)
+
+  if (!validated.ok) {
+    return { ok: false, error: { kind: 'InvalidPack', error: validated.error } }
+  }
+
+  return validated
+}
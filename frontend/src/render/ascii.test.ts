@@ -0,0 +1,42 @@
+import { describe, expect, it } from 'vitest'
+
+import { asTurn } from '../core/coords'
+import { frameAtTurn } from '../game/frames'
+import { gameReducer, waitTurn } from '../game/gameSlice'
+import { renderFrameAscii, renderSliceAscii } from './ascii'
+
+describe('renderSliceAscii', () => {
+  it('draws the player, walls, box and guard of the viewed slice', () => {
+    const state = gameReducer(undefined, { type: 'init' })
+    const rows = renderSliceAscii(state, 0)
+
+    expect(rows).toHaveLength(state.boardHeight)
+    expect(rows[4][5]).toBe('#')
+    expect(rows[5][5]).toBe('@')
+    expect(rows[6].slice(6, 9)).toBe('#.B')
+    expect(rows[8][2]).toBe('E')
+  })
+
+  it('marks past selves in earlier slices', () => {
+    const state = gameReducer(gameReducer(undefined, { type: 'init' }), waitTurn())
+
+    expect(renderSliceAscii(state, 0)[5][5]).toBe('o')
+    expect(renderSliceAscii(state, 1)[5][5]).toBe('@')
+  })
+
+  it('draws a turn of the world line from its frame', () => {
+    const state = gameReducer(gameReducer(undefined, { type: 'init' }), waitTurn())
+    const frame = frameAtTurn(state, asTurn(0))
+
+    expect(frame).not.toBeNull()
+    if (!frame) {
+      return
+    }
+
+    const rows = renderFrameAscii(frame, state.boardWidth, state.boardHeight)
+
+    expect(rows[5][5]).toBe('@')
+    expect(rows[8][2]).toBe('E')
+    expect(renderSliceAscii(state, 0)[5][5]).toBe('o')
+  })
+})
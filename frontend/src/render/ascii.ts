@@ -0,0 +1,101 @@
+import { hasComponent } from '../core/components'
+import { footprintCells } from '../core/footprint'
+import type { ResolvedObjectInstance } from '../core/objects'
+import type { Position3D } from '../core/position'
+import { objectsAtTime, type TimeCube } from '../core/timeCube'
+import { currentPosition, positionsAtTime, type WorldLineState } from '../core/worldLine'
+import type { GameFrame } from '../game/frames'
+
+export interface AsciiBoardInput {
+  boardWidth: number
+  boardHeight: number
+  cube: TimeCube
+  worldLine: WorldLineState
+}
+
+const EMPTY_CELL = '.'
+const PLAYER_GLYPH = '@'
+const PAST_SELF_GLYPH = 'o'
+
+/** Fallbacks for objects without a `glyph` of their own; anything else shows its kind's initial. */
+const GLYPH_BY_KIND: Record<string, string> = {
+  wall: '#',
+  exit: '>',
+  box: 'B',
+  enemy: 'E',
+}
+
+function objectGlyph(kind: string, glyph: string | undefined): string {
+  return glyph?.[0] ?? GLYPH_BY_KIND[kind] ?? kind[0]?.toUpperCase() ?? '?'
+}
+
+function drawRows(
+  boardWidth: number,
+  boardHeight: number,
+  objects: ResolvedObjectInstance[],
+  selves: Position3D[],
+  player: Position3D | null,
+): string[] {
+  const rows = Array.from({ length: boardHeight }, () =>
+    Array.from({ length: boardWidth }, () => EMPTY_CELL),
+  )
+  const draw = (x: number, y: number, glyph: string) => {
+    if (y >= 0 && y < rows.length && x >= 0 && x < boardWidth) {
+      rows[y][x] = glyph
+    }
+  }
+  const layered = [...objects].sort(
+    (a, b) =>
+      Number(hasComponent(a.archetype.components, 'BlocksMovement')) -
+      Number(hasComponent(b.archetype.components, 'BlocksMovement')),
+  )
+
+  for (const object of layered) {
+    const glyph = objectGlyph(object.archetype.kind, object.archetype.render.glyph)
+
+    for (const cell of footprintCells(object.archetype.components, object.position)) {
+      draw(cell.x, cell.y, glyph)
+    }
+  }
+
+  for (const position of selves) {
+    draw(position.x, position.y, PAST_SELF_GLYPH)
+  }
+
+  if (player) {
+    draw(player.x, player.y, PLAYER_GLYPH)
+  }
+
+  return rows.map((row) => row.join(''))
+}
+
+/**
+ * Text view of slice `t` for terminal tools, one string per row. Blocking objects draw over the
+ * rest, past selves over objects and the player over everything.
+ */
+export function renderSliceAscii(input: AsciiBoardInput, t: number): string[] {
+  const player = currentPosition(input.worldLine)
+
+  return drawRows(
+    input.boardWidth,
+    input.boardHeight,
+    objectsAtTime(input.cube, t),
+    positionsAtTime(input.worldLine, t).map((self) => self.position),
+    player && player.t === t ? player : null,
+  )
+}
+
+/** Like `renderSliceAscii`, for one turn: its self is the `@`, the line's other selves `o`. */
+export function renderFrameAscii(
+  frame: GameFrame,
+  boardWidth: number,
+  boardHeight: number,
+): string[] {
+  return drawRows(
+    boardWidth,
+    boardHeight,
+    frame.objects,
+    frame.otherSelves.map((self) => self.position),
+    frame.player,
+  )
+}
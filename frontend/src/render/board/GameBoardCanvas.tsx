@@ -1,11 +1,36 @@
-import { useEffect, useRef, useState } from 'react'
+import { useCallback, useEffect, useRef, useState } from 'react'
 
+import { hasComponent } from '../../core/components'
+import type { Turn } from '../../core/coords'
 import type { DetectionEvent } from '../../core/detection'
-import type { ResolvedObjectInstance } from '../../core/objects'
-import type { Position3D } from '../../core/position'
+import { catwalkOf, type Layer } from '../../core/elevation'
+import { footprintOf } from '../../core/footprint'
+import { litCellsAt, searchlightOf } from '../../core/searchlight'
+import { resolveObjectRender, type ResolvedObjectInstance } from '../../core/objects'
+import type { Position2D, Position3D } from '../../core/position'
+import { stackedCells } from '../../core/stacking'
 import type { PositionAtTime } from '../../core/worldLine'
+import type { GamePhase } from '../../game/interactions/types'
 import { minimalMonoTheme } from '../theme'
+import {
+  BOARD_CUES,
+  cueFont,
+  cueFontSize,
+  pastSelfCueLabel,
+  type BoardCueStyle,
+} from './cues'
+import {
+  EFFECT_DURATION_MS,
+  effectForPhaseChange,
+  effectFrame,
+  riftShimmerFrame,
+  SHIMMER_FRAME_MS,
+  type BoardEffect,
+} from './effects'
+import { facingTickPoints, type EnemyFacing } from './facing'
 import type { ActionPreview } from './preview'
+import type { TurnPlanDiff } from '../../game/turnPlan'
+import { rolledIndex, type CameraShift } from './camera'
 import {
   DANGER_ICON_SLOT,
   PAST_SELF_ICON_SLOT,
@@ -19,11 +44,46 @@ interface GameBoardCanvasProps {
   boardHeight: number
   iconPackId: string
   objectsAtCurrentTime: ResolvedObjectInstance[]
+  /** Facing ticks for the guards and cameras among `objectsAtCurrentTime`. */
+  enemyFacings: EnemyFacing[]
+  /** Décor tiles; drawn first, under searchlights, objects and fog. */
+  decorAtCurrentTime: ResolvedObjectInstance[]
+  /** Thrown lures lying on the board in the viewed slice. */
+  luresAtCurrentTime: Position3D[]
+  /** Cells no lamp reaches in the viewed slice; empty unless the level is dark. */
+  darkCells: Position2D[]
+  /** Fog of war: cells the player has never seen in this slice. */
+  fogCells: Position2D[]
+  /** Fog of war: cells seen earlier but out of sight now. */
+  memoryCells: Position2D[]
   selvesAtCurrentTime: PositionAtTime[]
-  currentTurn: number
+  /** Other characters (not the controlled one) in the viewed slice. */
+  companionsAtCurrentTime: Position3D[]
+  /** Path index of the player's current self; the other selves in the slice are past ones. */
+  currentTurn: Turn
+  ghostPosition: Position3D | null
+  /** Cell the last hint points at; null while viewing a slice other than the present. */
+  hintTarget: Position3D | null
+  /** Cell under the examine cursor; null outside examine mode. */
+  examineCursor: Position2D | null
   showDangerPreview: boolean
   detectionEvents: DetectionEvent[]
   actionPreview: ActionPreview | null
+  /** While planning: where the queued turns would leave moved objects and the player. */
+  planDiff: TurnPlanDiff | null
+  accessibleCues: boolean
+  /** Phase changes trigger board effects (detection flash, win sweep). */
+  phase: GamePhase
+  /** Drop screenshake and sweeps; effects become a flat tint. */
+  reduceMotion: boolean
+  /** Source and destination of the rift the player just used; a new value plays a warp streak. */
+  riftWarp: { from: Position2D; to: Position2D } | null
+  /** Torus maps roll the board so the player stays centered; see `torusCameraShift`. */
+  cameraShift: CameraShift
+  /** Levels with catwalks: the layer drawn in front; the other one is outlined or veiled. */
+  layerView: Layer
+  /** Cells of the viewed slice that differ from the timeline before the last rift back. */
+  timelineChangedCells: Position2D[]
 }
 
 const DEFAULT_CANVAS_WIDTH = 560
@@ -81,6 +141,14 @@ function drawFallbackIcon(
       context.fill()
       context.stroke()
       break
+    case 'camera':
+      context.fillStyle = '#ffe08a'
+      context.fillRect(x + size * 0.15, y + size * 0.3, size * 0.7, size * 0.4)
+      context.strokeRect(x + size * 0.15, y + size * 0.3, size * 0.7, size * 0.4)
+      context.beginPath()
+      context.arc(centerX, centerY, size * 0.12, 0, Math.PI * 2)
+      context.stroke()
+      break
     case 'exit':
       context.strokeRect(x, y, size, size)
       context.beginPath()
@@ -110,11 +178,29 @@ export function GameBoardCanvas({
   boardHeight,
   iconPackId,
   objectsAtCurrentTime,
+  enemyFacings,
+  decorAtCurrentTime,
+  luresAtCurrentTime,
+  darkCells,
+  fogCells,
+  memoryCells,
   selvesAtCurrentTime,
+  companionsAtCurrentTime,
   currentTurn,
+  ghostPosition,
+  hintTarget,
+  examineCursor,
   showDangerPreview,
   detectionEvents,
   actionPreview,
+  planDiff,
+  accessibleCues,
+  phase,
+  reduceMotion,
+  riftWarp,
+  cameraShift,
+  layerView,
+  timelineChangedCells,
 }: GameBoardCanvasProps) {
   const canvasRef = useRef<HTMLCanvasElement | null>(null)
   const [viewport, setViewport] = useState<CanvasViewport>({
@@ -126,6 +212,84 @@ export function GameBoardCanvas({
     packId: string
     slots: Record<string, HTMLImageElement>
   } | null>(null)
+  const [effectClock, setEffectClock] = useState(0)
+  const effectsRef = useRef<BoardEffect[]>([])
+  const clockHandleRef = useRef<number | null>(null)
+  const shimmerActiveRef = useRef(false)
+  const previousPhaseRef = useRef(phase)
+  const hasRifts = objectsAtCurrentTime.some((object) =>
+    hasComponent(object.archetype.components, 'Rift'),
+  )
+
+  // One clock drives every animation. It ticks while an effect is running or rifts are on screen;
+  // shimmer-only ticks redraw once per shimmer frame rather than every animation frame.
+  const startClock = useCallback(() => {
+    if (clockHandleRef.current !== null) {
+      return
+    }
+
+    let lastShimmerFrame = -1
+    const tick = (now: number) => {
+      const shimmerFrame = Math.floor(now / SHIMMER_FRAME_MS)
+      const hadEffects = effectsRef.current.length > 0
+
+      effectsRef.current = effectsRef.current.filter(
+        (effect) => now - effect.startedAt < EFFECT_DURATION_MS[effect.kind],
+      )
+      clockHandleRef.current =
+        effectsRef.current.length > 0 || shimmerActiveRef.current
+          ? requestAnimationFrame(tick)
+          : null
+
+      if (hadEffects || shimmerFrame !== lastShimmerFrame) {
+        lastShimmerFrame = shimmerFrame
+        setEffectClock(now)
+      }
+    }
+
+    clockHandleRef.current = requestAnimationFrame(tick)
+  }, [])
+
+  const stopClock = useCallback(() => {
+    if (clockHandleRef.current !== null) {
+      cancelAnimationFrame(clockHandleRef.current)
+      clockHandleRef.current = null
+    }
+  }, [])
+
+  const playEffect = useCallback(
+    (effect: Omit<BoardEffect, 'startedAt'>) => {
+      effectsRef.current = [...effectsRef.current, { ...effect, startedAt: performance.now() }]
+      startClock()
+    },
+    [startClock],
+  )
+
+  useEffect(() => stopClock, [stopClock])
+
+  useEffect(() => {
+    const kind = effectForPhaseChange(previousPhaseRef.current, phase)
+
+    previousPhaseRef.current = phase
+
+    if (kind) {
+      playEffect({ kind })
+    }
+  }, [phase, playEffect])
+
+  useEffect(() => {
+    if (riftWarp) {
+      playEffect({ kind: 'WarpStreak', streak: riftWarp })
+    }
+  }, [riftWarp, playEffect])
+
+  useEffect(() => {
+    shimmerActiveRef.current = hasRifts && !reduceMotion
+
+    if (shimmerActiveRef.current) {
+      startClock()
+    }
+  }, [hasRifts, reduceMotion, startClock])
 
   useEffect(() => {
     const canvas = canvasRef.current
@@ -233,30 +397,49 @@ export function GameBoardCanvas({
     const boardPixelHeight = cellSize * boardHeight
     const originX = (canvasWidth - boardPixelWidth) / 2
     const originY = (canvasHeight - boardPixelHeight) / 2
+    // Left/top pixel edge of a board cell, after the torus camera roll.
+    const cellX = (x: number) => originX + rolledIndex(x, cameraShift.x, boardWidth) * cellSize
+    const cellY = (y: number) => originY + rolledIndex(y, cameraShift.y, boardHeight) * cellSize
     const theme = minimalMonoTheme.canvas
     const loadedSlotIcons = loadedIconsState?.packId === iconPackId ? loadedIconsState.slots : {}
+    const activeEffects = effectsRef.current.flatMap((effect) => {
+      const frame = effectFrame(effect.kind, effectClock - effect.startedAt, reduceMotion)
+
+      return frame ? [{ effect, frame }] : []
+    })
 
     context.clearRect(0, 0, canvasWidth, canvasHeight)
 
     context.fillStyle = theme.boardBackground
     context.fillRect(0, 0, canvasWidth, canvasHeight)
 
-    const drawRect = (position: Position3D, fill: string, stroke: string, inset: number) => {
-      const x = originX + position.x * cellSize + cellSize * inset
-      const y = originY + position.y * cellSize + cellSize * inset
-      const size = cellSize * (1 - inset * 2)
+    for (const { frame } of activeEffects) {
+      context.translate(frame.offset.x, frame.offset.y)
+    }
+
+    const drawRect = (
+      position: Position3D,
+      fill: string,
+      stroke: string,
+      inset: number,
+      span = { width: 1, height: 1 },
+    ) => {
+      const x = cellX(position.x) + cellSize * inset
+      const y = cellY(position.y) + cellSize * inset
+      const width = cellSize * (span.width - inset * 2)
+      const height = cellSize * (span.height - inset * 2)
 
       context.fillStyle = fill
-      context.fillRect(x, y, size, size)
+      context.fillRect(x, y, width, height)
 
       context.strokeStyle = stroke
       context.lineWidth = 2
-      context.strokeRect(x, y, size, size)
+      context.strokeRect(x, y, width, height)
     }
 
     const drawIconAt = (position: Position3D, slot: string, inset = 0.2) => {
-      const x = originX + position.x * cellSize + cellSize * inset
-      const y = originY + position.y * cellSize + cellSize * inset
+      const x = cellX(position.x) + cellSize * inset
+      const y = cellY(position.y) + cellSize * inset
       const size = cellSize * (1 - inset * 2)
       const loaded = loadedSlotIcons[slot]
 
@@ -268,16 +451,263 @@ export function GameBoardCanvas({
       drawFallbackIcon(context, slot, x, y, size)
     }
 
+    const drawCue = (position: Position2D, style: BoardCueStyle, label = style.glyph) => {
+      if (!accessibleCues) {
+        return
+      }
+
+      const x = cellX(position.x) + 2
+      const y = cellY(position.y) + 2
+
+      context.font = cueFont(style, cellSize)
+      context.textBaseline = 'top'
+      const width = context.measureText(label).width
+      const height = cueFontSize(cellSize)
+
+      context.fillStyle = theme.cuePlate
+      context.fillRect(x, y, width + 4, height + 2)
+      context.fillStyle = theme.cueInk
+      context.fillText(label, x + 2, y + 1)
+
+      if (style.underline) {
+        context.fillRect(x + 2, y + height, width, 1)
+      }
+    }
+
+    for (const decor of decorAtCurrentTime) {
+      const render = resolveObjectRender(decor.archetype)
+      const x = cellX(decor.position.x)
+      const y = cellY(decor.position.y)
+
+      if (render.fill) {
+        context.fillStyle = render.fill
+        context.fillRect(x, y, cellSize, cellSize)
+      }
+
+      if (render.glyph) {
+        context.font = `400 ${Math.round(cellSize * 0.5)}px 'IBM Plex Mono', monospace`
+        context.textAlign = 'center'
+        context.textBaseline = 'middle'
+        context.fillStyle = render.stroke ?? theme.decorInk
+        context.fillText(render.glyph, x + cellSize / 2, y + cellSize / 2)
+        context.textAlign = 'start'
+      }
+    }
+
+    // Shadow tiles are floor: a darker cell under whatever stands on it, never an object block.
+    for (const object of objectsAtCurrentTime) {
+      if (!hasComponent(object.archetype.components, 'Shadow')) {
+        continue
+      }
+
+      context.fillStyle = theme.shadowFill
+      context.fillRect(cellX(object.position.x), cellY(object.position.y), cellSize, cellSize)
+      drawCue(object.position, BOARD_CUES.ShadowCell)
+    }
+
+    // Searchlights have no body: only their lit cells are drawn, underneath everything else.
+    for (const object of objectsAtCurrentTime) {
+      const light = searchlightOf(object.archetype.components)
+
+      if (!light) {
+        continue
+      }
+
+      const t = object.position.t
+
+      for (const cell of litCellsAt(light, object.position, t, boardWidth, boardHeight)) {
+        context.fillStyle = theme.searchlightFill
+        context.fillRect(
+          cellX(cell.x),
+          cellY(cell.y),
+          cellSize,
+          cellSize,
+        )
+        drawCue({ ...cell, t }, BOARD_CUES.LitCell)
+      }
+    }
+
     for (const object of objectsAtCurrentTime) {
-      const fill = object.archetype.render.fill ?? theme.objectFill
-      const stroke = object.archetype.render.stroke ?? theme.objectStroke
-      const slot = resolveObjectIconSlot(object.archetype.kind, object.archetype.render)
+      if (
+        searchlightOf(object.archetype.components) ||
+        catwalkOf(object.archetype.components) ||
+        hasComponent(object.archetype.components, 'Shadow')
+      ) {
+        continue
+      }
+
+      const render = resolveObjectRender(object.archetype)
+      const fill = render.fill ?? theme.objectFill
+      const stroke = render.stroke ?? theme.objectStroke
+      const slot = resolveObjectIconSlot(object.archetype.kind, render)
+      // Multi-tile objects are one block over their footprint, marked once at its middle.
+      const span = footprintOf(object.archetype.components) ?? { width: 1, height: 1 }
+      const middle = {
+        ...object.position,
+        x: object.position.x + (span.width - 1) / 2,
+        y: object.position.y + (span.height - 1) / 2,
+      }
+
+      drawRect(object.position, fill, stroke, 0.08, span)
+
+      // Water without an authored glyph or icon still has to read as water, not as a wall.
+      const glyph =
+        render.glyph ??
+        (!slot && hasComponent(object.archetype.components, 'Water') ? '~' : undefined)
+
+      if (glyph) {
+        context.font = `700 ${Math.round(cellSize * 0.55)}px 'IBM Plex Mono', monospace`
+        context.textAlign = 'center'
+        context.textBaseline = 'middle'
+        context.fillStyle = stroke
+        context.fillText(glyph, cellX(middle.x) + cellSize / 2, cellY(middle.y) + cellSize / 2)
+        context.textAlign = 'start'
+      } else if (slot) {
+        drawIconAt(middle, slot)
+      }
+
+      if (hasComponent(object.archetype.components, 'Rift')) {
+        const shimmer = riftShimmerFrame(effectClock, object.position, reduceMotion)
+
+        context.font = `700 ${Math.round(cellSize * 0.5)}px 'IBM Plex Mono', monospace`
+        context.textAlign = 'center'
+        context.textBaseline = 'middle'
+        context.fillStyle = theme.riftShimmer[shimmer.shade % theme.riftShimmer.length]
+        context.fillText(
+          shimmer.glyph,
+          cellX(object.position.x) + cellSize / 2,
+          cellY(object.position.y) + cellSize / 2,
+        )
+        context.textAlign = 'start'
+      }
+    }
+
+    // A tick on the edge a guard faces: filled for cone watchers, outlined for guards that see
+    // all around and only show where they are heading.
+    for (const { position, facing, cone } of enemyFacings) {
+      const [tip, left, right] = facingTickPoints(
+        cellX(position.x),
+        cellY(position.y),
+        cellSize,
+        facing,
+      )
+
+      context.beginPath()
+      context.moveTo(tip.x, tip.y)
+      context.lineTo(left.x, left.y)
+      context.lineTo(right.x, right.y)
+      context.closePath()
+      context.fillStyle = cone ? theme.facingTick : theme.boardBackground
+      context.strokeStyle = theme.facingTick
+      context.lineWidth = 1.5
+      context.fill()
+      context.stroke()
+    }
+
+    // A stack of boxes reads as one tall block: mark it so it is not mistaken for a single box.
+    for (const cell of stackedCells(objectsAtCurrentTime)) {
+      context.font = `700 ${Math.round(cellSize * 0.35)}px 'IBM Plex Mono', monospace`
+      context.textAlign = 'right'
+      context.textBaseline = 'top'
+      context.fillStyle = theme.objectStroke
+      context.fillText('≡', cellX(cell.x) + cellSize - 2, cellY(cell.y) + 2)
+      context.textAlign = 'start'
+    }
+
+    // Catwalks go over the ground objects: outlined in the ground view, solid in the catwalk view
+    // where everything else sits under a veil. Closed railings get a heavier edge.
+    const catwalks = objectsAtCurrentTime.flatMap((object) => {
+      const catwalk = catwalkOf(object.archetype.components)
 
-      drawRect(object.position, fill, stroke, 0.08)
+      return catwalk ? [{ object, catwalk }] : []
+    })
 
-      if (slot) {
-        drawIconAt(object.position, slot)
+    if (catwalks.length > 0 && layerView === 'catwalk') {
+      context.fillStyle = theme.layerVeilFill
+      context.fillRect(originX, originY, boardPixelWidth, boardPixelHeight)
+    }
+
+    for (const { object, catwalk } of catwalks) {
+      const x = cellX(object.position.x)
+      const y = cellY(object.position.y)
+
+      if (layerView === 'catwalk') {
+        context.fillStyle = theme.objectFill
+        context.fillRect(x, y, cellSize, cellSize)
       }
+
+      context.strokeStyle = theme.catwalkStroke
+      context.lineWidth = catwalk.railing === 'closed' ? 3 : 1
+      context.setLineDash(layerView === 'catwalk' ? [] : [4, 3])
+      context.strokeRect(x + 1, y + 1, cellSize - 2, cellSize - 2)
+      context.setLineDash([])
+
+      if (hasComponent(object.archetype.components, 'Ladder')) {
+        context.font = `700 ${Math.round(cellSize * 0.5)}px 'IBM Plex Mono', monospace`
+        context.textAlign = 'center'
+        context.textBaseline = 'middle'
+        context.fillStyle = theme.catwalkStroke
+        context.fillText('H', x + cellSize / 2, y + cellSize / 2)
+        context.textAlign = 'start'
+      }
+    }
+
+    for (const cell of darkCells) {
+      context.fillStyle = theme.darknessFill
+      context.fillRect(cellX(cell.x), cellY(cell.y), cellSize, cellSize)
+      drawCue(cell, BOARD_CUES.DarkCell)
+    }
+
+    for (const lure of luresAtCurrentTime) {
+      const x = cellX(lure.x) + cellSize / 2
+      const y = cellY(lure.y) + cellSize / 2
+
+      context.fillStyle = theme.objectStroke
+      context.beginPath()
+      context.arc(x, y, cellSize * 0.14, 0, Math.PI * 2)
+      context.fill()
+      drawCue(lure, BOARD_CUES.Lure)
+    }
+
+    for (const cell of timelineChangedCells) {
+      const x = cellX(cell.x)
+      const y = cellY(cell.y)
+
+      context.fillStyle = theme.timelineDiffFill
+      context.fillRect(x, y, cellSize, cellSize)
+      context.strokeStyle = theme.timelineDiffStroke
+      context.lineWidth = 2
+      context.setLineDash([2, 2])
+      context.strokeRect(x + 2, y + 2, cellSize - 4, cellSize - 4)
+      context.setLineDash([])
+      drawCue(cell, BOARD_CUES.TimelineChange)
+    }
+
+    for (const cell of memoryCells) {
+      context.fillStyle = theme.memoryFill
+      context.fillRect(cellX(cell.x), cellY(cell.y), cellSize, cellSize)
+      drawCue(cell, BOARD_CUES.Remembered)
+    }
+
+    for (const cell of fogCells) {
+      context.fillStyle = theme.fogFill
+      context.fillRect(cellX(cell.x), cellY(cell.y), cellSize, cellSize)
+      drawCue(cell, BOARD_CUES.Unexplored)
+    }
+
+    if (ghostPosition) {
+      const x = cellX(ghostPosition.x) + cellSize * 0.22
+      const y = cellY(ghostPosition.y) + cellSize * 0.22
+      const size = cellSize * 0.56
+
+      context.strokeStyle = theme.ghostStroke
+      context.lineWidth = 2
+      context.setLineDash([3, 3])
+      context.beginPath()
+      context.arc(x + size / 2, y + size / 2, size / 2, 0, Math.PI * 2)
+      context.stroke()
+      context.setLineDash([])
+      drawCue(ghostPosition, BOARD_CUES.Ghost)
     }
 
     for (const self of selvesAtCurrentTime) {
@@ -287,6 +717,13 @@ export function GameBoardCanvas({
 
       drawRect(self.position, theme.pastSelfFill, theme.pastSelfStroke, 0.2)
       drawIconAt(self.position, PAST_SELF_ICON_SLOT, 0.26)
+      drawCue(self.position, BOARD_CUES.PastSelf, pastSelfCueLabel(self.turn))
+    }
+
+    for (const companion of companionsAtCurrentTime) {
+      drawRect(companion, theme.pastSelfFill, theme.playerStroke, 0.18)
+      drawIconAt(companion, PLAYER_ICON_SLOT, 0.32)
+      drawCue(companion, BOARD_CUES.Companion)
     }
 
     const currentSelf = selvesAtCurrentTime.find((self) => self.turn === currentTurn)
@@ -294,6 +731,7 @@ export function GameBoardCanvas({
     if (currentSelf) {
       drawRect(currentSelf.position, theme.playerFill, theme.playerStroke, 0.18)
       drawIconAt(currentSelf.position, PLAYER_ICON_SLOT, 0.24)
+      drawCue(currentSelf.position, BOARD_CUES.Player)
     }
 
     if (showDangerPreview) {
@@ -305,12 +743,37 @@ export function GameBoardCanvas({
 
       for (const position of uniqueMarkers.values()) {
         drawIconAt(position, DANGER_ICON_SLOT, 0.12)
+        drawCue(position, BOARD_CUES.AlertedEnemy)
       }
     }
 
+    if (hintTarget) {
+      const x = cellX(hintTarget.x) + cellSize * 0.04
+      const y = cellY(hintTarget.y) + cellSize * 0.04
+      const size = cellSize * 0.92
+
+      context.strokeStyle = theme.hintStroke
+      context.lineWidth = 3
+      context.strokeRect(x, y, size, size)
+      drawCue(hintTarget, BOARD_CUES.HintTarget)
+    }
+
+    if (examineCursor) {
+      const x = cellX(examineCursor.x) + 1
+      const y = cellY(examineCursor.y) + 1
+      const size = cellSize - 2
+
+      context.strokeStyle = theme.examineStroke
+      context.lineWidth = 2
+      context.setLineDash([4, 2])
+      context.strokeRect(x, y, size, size)
+      context.setLineDash([])
+      drawCue(examineCursor, BOARD_CUES.ExamineCursor)
+    }
+
     if (actionPreview) {
-      const x = originX + actionPreview.to.x * cellSize
-      const y = originY + actionPreview.to.y * cellSize
+      const x = cellX(actionPreview.to.x)
+      const y = cellY(actionPreview.to.y)
       const inset = cellSize * 0.12
       const size = cellSize - inset * 2
 
@@ -329,6 +792,108 @@ export function GameBoardCanvas({
         context.moveTo(x + cellSize - inset, y + inset)
         context.lineTo(x + inset, y + cellSize - inset)
         context.stroke()
+        drawCue(actionPreview.to, BOARD_CUES.BlockedPreview)
+      }
+    }
+
+    // The plan's outcome is drawn see-through over the board as it is now, each moved object
+    // tied back to where it stands by a dashed line.
+    if (planDiff) {
+      const center = (cell: Position2D) => ({
+        x: cellX(cell.x) + cellSize / 2,
+        y: cellY(cell.y) + cellSize / 2,
+      })
+
+      context.globalAlpha = 0.55
+      context.setLineDash([4, 3])
+
+      for (const move of planDiff.moves) {
+        const from = center(move.from)
+
+        if (!move.to) {
+          const reach = cellSize * 0.3
+
+          context.strokeStyle = theme.planStroke
+          context.lineWidth = 2
+          context.beginPath()
+          context.moveTo(from.x - reach, from.y - reach)
+          context.lineTo(from.x + reach, from.y + reach)
+          context.moveTo(from.x + reach, from.y - reach)
+          context.lineTo(from.x - reach, from.y + reach)
+          context.stroke()
+          continue
+        }
+
+        const to = center(move.to)
+
+        context.strokeStyle = theme.planStroke
+        context.lineWidth = 2
+        context.beginPath()
+        context.moveTo(from.x, from.y)
+        context.lineTo(to.x, to.y)
+        context.stroke()
+        drawRect({ ...move.to, t: planDiff.time }, theme.planFill, theme.planStroke, 0.12)
+      }
+
+      if (planDiff.player) {
+        drawRect(planDiff.player, theme.playerFill, theme.planStroke, 0.24)
+      }
+
+      context.setLineDash([])
+      context.globalAlpha = 1
+
+      for (const move of planDiff.moves) {
+        drawCue(move.to ?? move.from, BOARD_CUES.Planned)
+      }
+
+      if (planDiff.player) {
+        drawCue(planDiff.player, BOARD_CUES.Planned)
+      }
+    }
+
+    for (const { effect, frame } of activeEffects) {
+      if (frame.tint) {
+        context.globalAlpha = frame.tint.alpha
+        context.fillStyle =
+          frame.tint.kind === 'DetectedFlash' ? theme.detectedFlashFill : theme.wonSweepFill
+        context.fillRect(originX, originY, boardPixelWidth, boardPixelHeight)
+        context.globalAlpha = 1
+      }
+
+      if (frame.sweep !== null) {
+        const edge = originX + boardPixelWidth * frame.sweep
+        const bandX = Math.max(originX, edge - cellSize / 2)
+
+        context.globalAlpha = 0.3
+        context.fillStyle = theme.wonSweepFill
+        context.fillRect(originX, originY, edge - originX, boardPixelHeight)
+        context.globalAlpha = 0.8
+        context.fillRect(bandX, originY, edge - bandX, boardPixelHeight)
+        context.globalAlpha = 1
+      }
+
+      if (frame.streak !== null && effect.streak) {
+        const { from, to } = effect.streak
+        const pointAt = (progress: number) => ({
+          x: cellX(from.x) + (0.5 + (to.x - from.x) * progress) * cellSize,
+          y: cellY(from.y) + (0.5 + (to.y - from.y) * progress) * cellSize,
+        })
+        const head = pointAt(frame.streak)
+        const tail = pointAt(reduceMotion ? 0 : Math.max(0, frame.streak - 0.4))
+
+        context.strokeStyle = theme.warpStreakStroke
+        context.lineWidth = Math.max(2, cellSize * 0.12)
+        context.beginPath()
+
+        if (from.x === to.x && from.y === to.y) {
+          // Pure time jumps stay on the cell: the streak becomes a ring closing in on it.
+          context.arc(head.x, head.y, cellSize * (1.2 - frame.streak * 0.7), 0, Math.PI * 2)
+        } else {
+          context.moveTo(tail.x, tail.y)
+          context.lineTo(head.x, head.y)
+        }
+
+        context.stroke()
       }
     }
   }, [
@@ -337,12 +902,29 @@ export function GameBoardCanvas({
     iconPackId,
     loadedIconsState,
     objectsAtCurrentTime,
+    enemyFacings,
+    decorAtCurrentTime,
+    luresAtCurrentTime,
+    darkCells,
+    fogCells,
+    memoryCells,
     selvesAtCurrentTime,
+    companionsAtCurrentTime,
     currentTurn,
+    ghostPosition,
+    hintTarget,
+    examineCursor,
     showDangerPreview,
     detectionEvents,
     actionPreview,
+    planDiff,
+    accessibleCues,
+    effectClock,
+    reduceMotion,
     viewport,
+    cameraShift,
+    layerView,
+    timelineChangedCells,
   ])
 
   return (
@@ -0,0 +1,16 @@
+import { describe, expect, it } from 'vitest'
+
+import { rolledIndex, torusCameraShift } from './camera'
+
+describe('torus camera', () => {
+  it('centers the followed cell only along wrapping axes', () => {
+    expect(torusCameraShift({ x: 0, y: 1 }, 7, 5, { x: true, y: false })).toEqual({ x: 3, y: 0 })
+    expect(torusCameraShift({ x: 6, y: 4 }, 7, 5, { x: true, y: true })).toEqual({ x: -3, y: -2 })
+  })
+
+  it('rolls indices around the board edge', () => {
+    expect(rolledIndex(0, 3, 7)).toBe(3)
+    expect(rolledIndex(5, 3, 7)).toBe(1)
+    expect(rolledIndex(1, -3, 7)).toBe(5)
+  })
+})
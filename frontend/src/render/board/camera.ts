@@ -0,0 +1,27 @@
+import type { Position2D } from '../../core/position'
+
+/** Columns and rows the board is rolled by before drawing; zero on maps with hard edges. */
+export type CameraShift = Position2D
+
+export const NO_CAMERA_SHIFT: CameraShift = { x: 0, y: 0 }
+
+/**
+ * On torus maps the camera follows `center`: the board is rolled along each wrapping axis so that
+ * cell sits in the middle column/row and the map appears to continue past every edge.
+ */
+export function torusCameraShift(
+  center: Position2D,
+  width: number,
+  height: number,
+  wraps: { x: boolean; y: boolean },
+): CameraShift {
+  return {
+    x: wraps.x ? Math.floor(width / 2) - center.x : 0,
+    y: wraps.y ? Math.floor(height / 2) - center.y : 0,
+  }
+}
+
+/** On-screen column (or row) of board index `index` once rolled by `shift`. */
+export function rolledIndex(index: number, shift: number, size: number): number {
+  return (((index + shift) % size) + size) % size
+}
@@ -0,0 +1,20 @@
+import { describe, expect, it } from 'vitest'
+
+import { BOARD_CUES, cueFont, pastSelfCueLabel } from './cues'
+
+describe('board cues', () => {
+  it('gives every cue a distinct glyph so no state relies on color alone', () => {
+    const glyphs = Object.values(BOARD_CUES).map((cue) => cue.glyph)
+
+    expect(new Set(glyphs).size).toBe(glyphs.length)
+  })
+
+  it('labels past selves with their turn', () => {
+    expect(pastSelfCueLabel(3)).toBe('p3')
+  })
+
+  it('scales the cue font with the cell and keeps a readable minimum', () => {
+    expect(cueFont(BOARD_CUES.Player, 100)).toBe("700 30px 'IBM Plex Mono', monospace")
+    expect(cueFont(BOARD_CUES.PastSelf, 10)).toBe("400 9px 'IBM Plex Mono', monospace")
+  })
+})
@@ -0,0 +1,59 @@
+/**
+ * Non-color channels for board states that are otherwise told apart by fill alone.
+ * Each cue pairs a glyph with a text style so the board stays readable in grayscale.
+ */
+export type BoardCueKind =
+  | 'Player'
+  | 'PastSelf'
+  | 'Ghost'
+  | 'AlertedEnemy'
+  | 'HintTarget'
+  | 'BlockedPreview'
+  | 'LitCell'
+  | 'Lure'
+  | 'DarkCell'
+  | 'ShadowCell'
+  | 'Unexplored'
+  | 'Remembered'
+  | 'Companion'
+  | 'TimelineChange'
+  | 'ExamineCursor'
+  | 'Planned'
+
+export interface BoardCueStyle {
+  glyph: string
+  bold: boolean
+  underline: boolean
+}
+
+export const BOARD_CUES: Record<BoardCueKind, BoardCueStyle> = {
+  Player: { glyph: '@', bold: true, underline: true },
+  PastSelf: { glyph: 'p', bold: false, underline: false },
+  Ghost: { glyph: 'g', bold: false, underline: true },
+  AlertedEnemy: { glyph: '!', bold: true, underline: false },
+  HintTarget: { glyph: '?', bold: true, underline: false },
+  BlockedPreview: { glyph: 'x', bold: false, underline: false },
+  LitCell: { glyph: '*', bold: false, underline: false },
+  Lure: { glyph: 'o', bold: true, underline: false },
+  DarkCell: { glyph: '.', bold: false, underline: false },
+  ShadowCell: { glyph: '%', bold: false, underline: false },
+  Unexplored: { glyph: '#', bold: false, underline: false },
+  Remembered: { glyph: '~', bold: false, underline: false },
+  Companion: { glyph: 'c', bold: true, underline: false },
+  TimelineChange: { glyph: '+', bold: false, underline: true },
+  ExamineCursor: { glyph: 'i', bold: true, underline: true },
+  Planned: { glyph: '=', bold: false, underline: true },
+}
+
+/** Past selves carry their turn number so overlapping echoes stay distinguishable. */
+export function pastSelfCueLabel(turn: number): string {
+  return `${BOARD_CUES.PastSelf.glyph}${turn}`
+}
+
+export function cueFontSize(cellSize: number): number {
+  return Math.max(9, Math.round(cellSize * 0.3))
+}
+
+export function cueFont(style: BoardCueStyle, cellSize: number): string {
+  return `${style.bold ? '700' : '400'} ${cueFontSize(cellSize)}px 'IBM Plex Mono', monospace`
+}
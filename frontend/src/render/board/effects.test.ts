@@ -0,0 +1,62 @@
+import { describe, expect, it } from 'vitest'
+
+import {
+  EFFECT_DURATION_MS,
+  effectForPhaseChange,
+  effectFrame,
+  riftShimmerFrame,
+  SHIMMER_FRAME_MS,
+} from './effects'
+
+describe('board effects', () => {
+  it('starts effects only when the phase flips to Detected or Won', () => {
+    expect(effectForPhaseChange('Playing', 'Detected')).toBe('DetectedFlash')
+    expect(effectForPhaseChange('Playing', 'Won')).toBe('WonSweep')
+    expect(effectForPhaseChange('Won', 'Won')).toBeNull()
+    expect(effectForPhaseChange('Detected', 'Playing')).toBeNull()
+  })
+
+  it('shakes and fades the detection flash, then ends', () => {
+    const first = effectFrame('DetectedFlash', 0, false)
+    const later = effectFrame('DetectedFlash', 200, false)
+
+    expect(first?.offset).not.toEqual({ x: 0, y: 0 })
+    expect(first?.tint?.alpha).toBeGreaterThan(later?.tint?.alpha ?? 1)
+    expect(effectFrame('DetectedFlash', EFFECT_DURATION_MS.DetectedFlash, false)).toBeNull()
+  })
+
+  it('sweeps left to right on a win', () => {
+    expect(effectFrame('WonSweep', 0, false)?.sweep).toBe(0)
+    expect(effectFrame('WonSweep', EFFECT_DURATION_MS.WonSweep / 2, false)?.sweep).toBeCloseTo(0.5)
+  })
+
+  it('holds a still tint with reduce motion', () => {
+    for (const kind of ['DetectedFlash', 'WonSweep'] as const) {
+      const frame = effectFrame(kind, 100, true)
+
+      expect(frame?.offset).toEqual({ x: 0, y: 0 })
+      expect(frame?.sweep).toBeNull()
+      expect(frame?.tint).toEqual({ kind, alpha: 0.25 })
+    }
+  })
+
+  it('runs the warp streak from source to destination', () => {
+    expect(effectFrame('WarpStreak', 0, false)?.streak).toBe(0)
+    expect(effectFrame('WarpStreak', EFFECT_DURATION_MS.WarpStreak / 2, false)?.streak).toBeCloseTo(
+      0.5,
+    )
+    expect(effectFrame('WarpStreak', 10, true)?.streak).toBe(1)
+    expect(effectFrame('WarpStreak', EFFECT_DURATION_MS.WarpStreak, false)).toBeNull()
+  })
+
+  it('ripples rift shimmer across neighbouring tiles unless motion is reduced', () => {
+    const cell = { x: 2, y: 3 }
+    const now = riftShimmerFrame(0, cell, false)
+
+    expect(riftShimmerFrame(SHIMMER_FRAME_MS, cell, false).glyph).not.toBe(now.glyph)
+    expect(riftShimmerFrame(0, { x: 3, y: 3 }, false).glyph).not.toBe(now.glyph)
+    expect(riftShimmerFrame(SHIMMER_FRAME_MS * 7, cell, true)).toEqual(
+      riftShimmerFrame(0, { x: 0, y: 0 }, true),
+    )
+  })
+})
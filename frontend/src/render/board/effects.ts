@@ -0,0 +1,109 @@
+import type { Position2D } from '../../core/position'
+import type { GamePhase } from '../../game/interactions/types'
+
+/** Short board animations: phase changes, plus a warp streak when the player uses a rift. */
+export type BoardEffectKind = 'DetectedFlash' | 'WonSweep' | 'WarpStreak'
+
+export interface BoardEffect {
+  kind: BoardEffectKind
+  /** Animation-clock timestamp (ms) of the first frame. */
+  startedAt: number
+  /** WarpStreak only: the rift's source and destination cells. */
+  streak?: { from: Position2D; to: Position2D }
+}
+
+/** What to draw for one frame of an effect. */
+export interface EffectFrame {
+  /** Whole-board drawing offset in CSS pixels (screenshake). */
+  offset: { x: number; y: number }
+  /** Full-board tint drawn over everything, or null. */
+  tint: { kind: BoardEffectKind; alpha: number } | null
+  /** Fraction of columns the win sweep has covered (0..1), or null. */
+  sweep: number | null
+  /** How far the warp streak has travelled from source to destination (0..1), or null. */
+  streak: number | null
+}
+
+export const EFFECT_DURATION_MS: Record<BoardEffectKind, number> = {
+  DetectedFlash: 420,
+  WonSweep: 700,
+  WarpStreak: 360,
+}
+
+const JITTER_FRAME_MS = 40
+const JITTER_OFFSETS = [
+  { x: 3, y: -2 },
+  { x: -3, y: 2 },
+  { x: 2, y: 1 },
+  { x: -2, y: -1 },
+  { x: 1, y: 0 },
+]
+const NO_OFFSET = { x: 0, y: 0 }
+export const SHIMMER_FRAME_MS = 140
+const SHIMMER_GLYPHS = ['·', '∘', '○', '◌']
+
+export function effectForPhaseChange(previous: GamePhase, next: GamePhase): BoardEffectKind | null {
+  if (previous === next) {
+    return null
+  }
+
+  if (next === 'Detected') {
+    return 'DetectedFlash'
+  }
+
+  return next === 'Won' ? 'WonSweep' : null
+}
+
+/**
+ * Frame `elapsedMs` into an effect, or null once it has finished. With reduce motion on, nothing
+ * moves: detection holds a flat red tint, a win a flat green one and a rift a still streak.
+ */
+export function effectFrame(
+  kind: BoardEffectKind,
+  elapsedMs: number,
+  reduceMotion: boolean,
+): EffectFrame | null {
+  const duration = EFFECT_DURATION_MS[kind]
+
+  if (elapsedMs < 0 || elapsedMs >= duration) {
+    return null
+  }
+
+  const remaining = 1 - elapsedMs / duration
+
+  if (kind === 'WarpStreak') {
+    return { offset: NO_OFFSET, tint: null, sweep: null, streak: reduceMotion ? 1 : 1 - remaining }
+  }
+
+  if (reduceMotion) {
+    return { offset: NO_OFFSET, tint: { kind, alpha: 0.25 }, sweep: null, streak: null }
+  }
+
+  if (kind === 'WonSweep') {
+    return { offset: NO_OFFSET, tint: null, sweep: 1 - remaining, streak: null }
+  }
+
+  const jitter = JITTER_OFFSETS[Math.floor(elapsedMs / JITTER_FRAME_MS) % JITTER_OFFSETS.length]
+
+  return {
+    offset: { x: jitter.x * remaining, y: jitter.y * remaining },
+    tint: { kind, alpha: 0.45 * remaining },
+    sweep: null,
+    streak: null,
+  }
+}
+
+/**
+ * Rift tiles cycle through glyphs and shades; neighbouring tiles are offset by their position so
+ * a cluster ripples instead of blinking in sync. With reduce motion every tile holds frame 0.
+ */
+export function riftShimmerFrame(
+  clockMs: number,
+  cell: Position2D,
+  reduceMotion: boolean,
+): { glyph: string; shade: number } {
+  const frame = reduceMotion ? 0 : Math.floor(clockMs / SHIMMER_FRAME_MS) + cell.x + cell.y
+  const shade = frame % SHIMMER_GLYPHS.length
+
+  return { glyph: SHIMMER_GLYPHS[shade], shade }
+}
@@ -0,0 +1,86 @@
+import { describe, expect, it } from 'vitest'
+
+import type { ResolvedObjectInstance } from '../../core/objects'
+import { createTimeCube, placeObjects } from '../../core/timeCube'
+import { enemyFacingsAt, facingTickPoints } from './facing'
+
+function patroller(): ResolvedObjectInstance {
+  return {
+    id: 'enemy.alpha',
+    archetypeKey: 'enemy',
+    position: { x: 1, y: 1, t: 0 },
+    archetype: {
+      kind: 'enemy',
+      components: [
+        { kind: 'BlocksMovement' },
+        { kind: 'TimePersistent' },
+        {
+          kind: 'Patrol',
+          path: [
+            { x: 1, y: 1 },
+            { x: 2, y: 1 },
+            { x: 2, y: 2 },
+          ],
+          loops: false,
+        },
+      ],
+      render: {},
+    },
+  }
+}
+
+function sentry(): ResolvedObjectInstance {
+  return {
+    id: 'sentry.1',
+    archetypeKey: 'sentry',
+    position: { x: 4, y: 4, t: 0 },
+    archetype: {
+      kind: 'enemy',
+      components: [
+        { kind: 'TimePersistent' },
+        { kind: 'Guard', guard: 'Sentry', facings: ['north', 'west'] },
+      ],
+      render: {},
+    },
+  }
+}
+
+describe('enemyFacingsAt', () => {
+  it('follows patrol steps and sentry turns', () => {
+    const placed = placeObjects(createTimeCube(6, 6, 4), [patroller(), sentry()])
+
+    expect(placed.ok).toBe(true)
+    if (!placed.ok) {
+      return
+    }
+
+    const facing = (t: number) =>
+      enemyFacingsAt(placed.value, t)
+        .map((entry) => [entry.id, entry.facing, entry.cone])
+        .sort((a, b) => String(a[0]).localeCompare(String(b[0])))
+
+    // At t=0 the patroller has not moved yet, so it faces the step it is about to take.
+    expect(facing(0)).toEqual([
+      ['enemy.alpha', 'east', false],
+      ['sentry.1', 'north', true],
+    ])
+    expect(facing(2)).toEqual([
+      ['enemy.alpha', 'south', false],
+      ['sentry.1', 'north', true],
+    ])
+    expect(facing(3)).toEqual([
+      ['enemy.alpha', 'north', false],
+      ['sentry.1', 'west', true],
+    ])
+  })
+})
+
+describe('facingTickPoints', () => {
+  it('points the tick at the faced edge', () => {
+    expect(facingTickPoints(0, 0, 100, 'east')).toEqual([
+      { x: 98, y: 50 },
+      { x: 80, y: 65 },
+      { x: 80, y: 35 },
+    ])
+  })
+})
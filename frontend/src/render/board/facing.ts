@@ -0,0 +1,67 @@
+import { enemiesAtTime, enemyPositionAt, watchFacingAt } from '../../core/guards'
+import { stepDirection, type Direction2D, type Position3D } from '../../core/position'
+import { wrappingOf, type TimeCube } from '../../core/timeCube'
+
+const FACING_VECTORS: Record<Direction2D, { x: number; y: number }> = {
+  north: { x: 0, y: -1 },
+  south: { x: 0, y: 1 },
+  east: { x: 1, y: 0 },
+  west: { x: -1, y: 0 },
+}
+
+export interface EnemyFacing {
+  id: string
+  position: Position3D
+  facing: Direction2D
+  /** Sentries, echoes and cameras only see the cone ahead; other guards watch all around. */
+  cone: boolean
+}
+
+/**
+ * Which way each guard and camera in slice `t` faces. Directional watchers report their watch
+ * facing; walking guards face the step they took into `t`, or the one they take next when they
+ * have just stood still. Guards that neither turn nor move are left out.
+ */
+export function enemyFacingsAt(cube: TimeCube, t: number): EnemyFacing[] {
+  const wrapping = wrappingOf(cube)
+
+  return enemiesAtTime(cube, t).flatMap((object): EnemyFacing[] => {
+    const watch = watchFacingAt(object.archetype.components, t)
+    const facing = (direction: Direction2D, cone: boolean): EnemyFacing[] => [
+      { id: object.id, position: object.position, facing: direction, cone },
+    ]
+
+    if (watch !== undefined) {
+      return watch ? facing(watch, true) : []
+    }
+
+    const previous = enemyPositionAt(cube, object.id, t - 1)
+    const next = enemyPositionAt(cube, object.id, t + 1)
+    const heading =
+      (previous ? stepDirection(previous, object.position, wrapping) : null) ??
+      (next ? stepDirection(object.position, next, wrapping) : null)
+
+    return heading ? facing(heading, false) : []
+  })
+}
+
+/** Tip and base corners of the facing tick drawn against the faced edge of a cell. */
+export function facingTickPoints(
+  cellLeft: number,
+  cellTop: number,
+  cellSize: number,
+  facing: Direction2D,
+): [{ x: number; y: number }, { x: number; y: number }, { x: number; y: number }] {
+  const forward = FACING_VECTORS[facing]
+  const centerX = cellLeft + cellSize / 2
+  const centerY = cellTop + cellSize / 2
+  const baseX = centerX + forward.x * cellSize * 0.3
+  const baseY = centerY + forward.y * cellSize * 0.3
+  const half = cellSize * 0.15
+
+  return [
+    { x: centerX + forward.x * cellSize * 0.48, y: centerY + forward.y * cellSize * 0.48 },
+    { x: baseX - forward.y * half, y: baseY + forward.x * half },
+    { x: baseX + forward.y * half, y: baseY - forward.x * half },
+  ]
+}
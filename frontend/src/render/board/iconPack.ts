@@ -5,6 +5,7 @@ export const BOARD_ICON_SLOT_BY_KIND: Record<string, string> = {
   box: 'box',
   exit: 'exit',
   enemy: 'enemy',
+  camera: 'camera',
   marker: 'marker',
   patrol: 'patrol',
   rift: 'rift',
@@ -0,0 +1,30 @@
+import { describe, expect, it } from 'vitest'
+
+import { gameReducer } from '../../game/gameSlice'
+import { minimalMonoTheme } from '../theme'
+import { buildLegend } from './legend'
+
+describe('buildLegend', () => {
+  it('lists player marks, then each archetype the level uses once', () => {
+    const { cube } = gameReducer(undefined, { type: 'init' })
+    const legend = buildLegend({
+      cube,
+      theme: minimalMonoTheme.canvas,
+      hasGhost: false,
+      showDangerPreview: true,
+      dark: false,
+    })
+
+    expect(legend.map((entry) => (entry.kind === 'mark' ? entry.id : entry.name))).toEqual([
+      'player',
+      'pastSelf',
+      'danger',
+      'facing',
+      'wall',
+      'exit',
+      'box',
+      'enemy',
+    ])
+    expect(legend[0]).toMatchObject({ fill: minimalMonoTheme.canvas.playerFill, glyph: '@' })
+  })
+})
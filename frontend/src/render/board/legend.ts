@@ -0,0 +1,100 @@
+import { hasComponent } from '../../core/components'
+import { isEnemy } from '../../core/guards'
+import { resolveObjectRender } from '../../core/objects'
+import { searchlightOf } from '../../core/searchlight'
+import { allObjects, type TimeCube } from '../../core/timeCube'
+import type { CanvasTheme } from '../theme'
+import { BOARD_CUES } from './cues'
+
+/** Board marks that are not level objects; each has a `legend.<id>` message. */
+export type LegendMarkId =
+  | 'player'
+  | 'pastSelf'
+  | 'ghost'
+  | 'danger'
+  | 'facing'
+  | 'searchlight'
+  | 'darkness'
+  | 'shadow'
+
+export type LegendEntry =
+  | { kind: 'mark'; id: LegendMarkId; fill: string; stroke: string; glyph: string }
+  /** One per archetype and look; `name` is the archetype key the level author chose. */
+  | { kind: 'object'; id: string; name: string; fill: string; stroke: string; glyph: string }
+
+export interface LegendInput {
+  cube: TimeCube
+  theme: CanvasTheme
+  /** A best run's ghost is shown this attempt. */
+  hasGhost: boolean
+  showDangerPreview: boolean
+  /** The level sets `darkVisionFactor`. */
+  dark: boolean
+}
+
+/**
+ * What the board can show on this level, from the theme colors and glyphs it is drawn with:
+ * player marks first, then every archetype look in the order the level places them. Searchlights
+ * and shadow tiles are listed as marks, since the board draws them as cell tints.
+ */
+export function buildLegend(input: LegendInput): LegendEntry[] {
+  const { cube, theme } = input
+  const objects = [...allObjects(cube), ...cube.decor]
+  const mark = (id: LegendMarkId, fill: string, stroke: string, glyph: string): LegendEntry => ({
+    kind: 'mark',
+    id,
+    fill,
+    stroke,
+    glyph,
+  })
+
+  const marks: LegendEntry[] = [
+    mark('player', theme.playerFill, theme.playerStroke, BOARD_CUES.Player.glyph),
+    mark('pastSelf', theme.pastSelfFill, theme.pastSelfStroke, BOARD_CUES.PastSelf.glyph),
+    ...(input.hasGhost
+      ? [mark('ghost', theme.boardBackground, theme.ghostStroke, BOARD_CUES.Ghost.glyph)]
+      : []),
+    ...(input.showDangerPreview
+      ? [
+          mark(
+            'danger',
+            theme.dangerMarkerFill,
+            theme.dangerMarkerStroke,
+            BOARD_CUES.AlertedEnemy.glyph,
+          ),
+        ]
+      : []),
+    ...(objects.some(isEnemy) ? [mark('facing', theme.facingTick, theme.facingTick, '>')] : []),
+    ...(objects.some((object) => searchlightOf(object.archetype.components))
+      ? [mark('searchlight', theme.searchlightFill, theme.objectStroke, BOARD_CUES.LitCell.glyph)]
+      : []),
+    ...(input.dark
+      ? [mark('darkness', theme.darknessFill, theme.objectStroke, BOARD_CUES.DarkCell.glyph)]
+      : []),
+    ...(objects.some((object) => hasComponent(object.archetype.components, 'Shadow'))
+      ? [mark('shadow', theme.shadowFill, theme.objectStroke, BOARD_CUES.ShadowCell.glyph)]
+      : []),
+  ]
+
+  const looks = new Map<string, LegendEntry>()
+
+  for (const object of objects) {
+    const components = object.archetype.components
+
+    if (searchlightOf(components) || hasComponent(components, 'Shadow')) {
+      continue
+    }
+
+    const render = resolveObjectRender(object.archetype)
+    const fill = render.fill ?? theme.objectFill
+    const stroke = render.stroke ?? theme.objectStroke
+    const glyph = render.glyph ?? (hasComponent(components, 'Water') ? '~' : '')
+    const id = `${object.archetypeKey}|${fill}|${glyph}`
+
+    if (!looks.has(id)) {
+      looks.set(id, { kind: 'object', id, name: object.archetypeKey, fill, stroke, glyph })
+    }
+  }
+
+  return [...marks, ...looks.values()]
+}
@@ -1,9 +1,34 @@
+import { blocksMovementAt } from '../../core/alarm'
 import { hasComponent } from '../../core/components'
-import { isInBounds, movePosition, type Direction2D, type Position3D } from '../../core/position'
-import { objectsAt, type TimeCube } from '../../core/timeCube'
-import { currentPosition, wouldIntersect, type WorldLineState } from '../../core/worldLine'
-
-export type PreviewMode = 'Move' | 'Push' | 'Pull'
+import { catwalkAt, currentLayer } from '../../core/elevation'
+import { footprintCells, isMultiTile, leadingEdgeCells } from '../../core/footprint'
+import { groupMembersAt, groupOf } from '../../core/groups'
+import { lureLandingCell } from '../../core/lure'
+import {
+  isInBounds,
+  movePosition,
+  wrapPosition,
+  type Direction2D,
+  type Position3D,
+} from '../../core/position'
+import { stackAt, stackingPushTarget } from '../../core/stacking'
+import { objectsAt, wrappingOf, type TimeCube } from '../../core/timeCube'
+import { waterAt } from '../../core/water'
+import {
+  currentPosition,
+  followingTime,
+  wouldIntersect,
+  type WorldLineState,
+} from '../../core/worldLine'
+
+export type PreviewMode =
+  | 'Move'
+  | 'Push'
+  | 'Pull'
+  | 'TimePush'
+  | 'Throw'
+  | 'Break'
+  | 'Interact'
 
 export interface PreviewIntent {
   mode: PreviewMode
@@ -32,9 +57,7 @@ function oppositeDirection(direction: Direction2D): Direction2D {
 }
 
 function blockingObjects(cube: TimeCube, position: Position3D) {
-  return objectsAt(cube, position).filter((object) =>
-    hasComponent(object.archetype.components, 'BlocksMovement'),
-  )
+  return objectsAt(cube, position).filter((object) => blocksMovementAt(cube, object, position.t))
 }
 
 function isPushPreviewBlocked(input: {
@@ -49,10 +72,39 @@ function isPushPreviewBlocked(input: {
 
   const firstBlockers = blockingObjects(cube, to)
 
-  if (firstBlockers.length === 0) {
+  if (firstBlockers.length === 0 || stackingPushTarget(cube, to, direction)) {
     return { blocked: false }
   }
 
+  const block = firstBlockers.find(
+    (object) =>
+      isMultiTile(object.archetype.components) || groupOf(object.archetype.components) !== null,
+  )
+
+  if (block) {
+    const groupId = groupOf(block.archetype.components)
+    const members = groupId ? groupMembersAt(cube, groupId, to.t) : [block]
+    const ids = members.map((member) => member.id)
+
+    if (
+      firstBlockers.some((object) => !ids.includes(object.id)) ||
+      members.some((member) => !hasComponent(member.archetype.components, 'Pushable'))
+    ) {
+      return { blocked: true, reason: 'Target not pushable' }
+    }
+
+    const cells = members.flatMap((member) =>
+      footprintCells(member.archetype.components, member.position),
+    )
+    const edgeBlocked = leadingEdgeCells(cells, direction).some(
+      (cell) =>
+        !isInBounds(cell, boardWidth, boardHeight) ||
+        blockingObjects(cube, { ...cell, t: to.t }).length > 0,
+    )
+
+    return edgeBlocked ? { blocked: true, reason: 'No space to push' } : { blocked: false }
+  }
+
   let cursor = { x: to.x, y: to.y }
   let chainLength = 0
 
@@ -60,10 +112,22 @@ function isPushPreviewBlocked(input: {
     const cell = { x: cursor.x, y: cursor.y, t: to.t }
     const blockers = blockingObjects(cube, cell)
 
-    if (blockers.length === 0) {
+    if (blockers.length === 0 || (chainLength > 0 && waterAt(cube, cell))) {
       return { blocked: false }
     }
 
+    if (stackAt(cube, cell).length > 1) {
+      return { blocked: true, reason: 'Stacked boxes cannot be pushed' }
+    }
+
+    if (blockers.some((object) => isMultiTile(object.archetype.components))) {
+      return { blocked: true, reason: 'Large objects cannot be pushed in a chain' }
+    }
+
+    if (blockers.some((object) => groupOf(object.archetype.components) !== null)) {
+      return { blocked: true, reason: 'Linked boxes cannot be pushed in a chain' }
+    }
+
     const allPushable = blockers.every((object) =>
       hasComponent(object.archetype.components, 'Pushable'),
     )
@@ -78,7 +142,7 @@ function isPushPreviewBlocked(input: {
       return { blocked: true, reason: 'Push chain too long' }
     }
 
-    const next = movePosition(cursor, direction)
+    const next = wrapPosition(movePosition(cursor, direction), wrappingOf(cube))
 
     if (!isInBounds(next, boardWidth, boardHeight)) {
       return { blocked: true, reason: 'No space to push' }
@@ -102,7 +166,7 @@ function isPullPreviewBlocked(input: {
     return { blocked: true, reason: 'Blocked by object' }
   }
 
-  const behind = movePosition(from, oppositeDirection(direction))
+  const behind = wrapPosition(movePosition(from, oppositeDirection(direction)), wrappingOf(cube))
 
   if (!isInBounds(behind, boardWidth, boardHeight)) {
     return { blocked: true, reason: 'Nothing to pull' }
@@ -113,7 +177,8 @@ function isPullPreviewBlocked(input: {
     y: behind.y,
     t: from.t,
   })
-  const hasPullable = behindObjects.some((object) =>
+  const stack = stackAt(cube, { x: behind.x, y: behind.y, t: from.t })
+  const hasPullable = (stack.length > 1 ? stack.slice(-1) : behindObjects).some((object) =>
     hasComponent(object.archetype.components, 'Pullable'),
   )
 
@@ -124,6 +189,25 @@ function isPullPreviewBlocked(input: {
   return { blocked: false }
 }
 
+function isTimePushPreviewBlocked(input: {
+  cube: TimeCube
+  timeDepth: number
+  to: Position3D
+}): { blocked: boolean; reason?: string } {
+  const blockers = blockingObjects(input.cube, input.to)
+  const pushable = blockers.some((object) => hasComponent(object.archetype.components, 'Pushable'))
+
+  if (!pushable || blockers.length > 1) {
+    return { blocked: true, reason: 'Target is not pushable' }
+  }
+
+  if (input.to.t + 1 >= input.timeDepth) {
+    return { blocked: true, reason: 'No later slice to push into' }
+  }
+
+  return { blocked: false }
+}
+
 export function buildActionPreview(input: {
   cube: TimeCube
   worldLine: WorldLineState
@@ -133,9 +217,21 @@ export function buildActionPreview(input: {
   intent: PreviewIntent | null
   maxPushChain: number
   allowPull: boolean
+  allowTimePush?: boolean
+  lureRange?: number
 }): ActionPreview | null {
-  const { cube, worldLine, boardWidth, boardHeight, timeDepth, intent, maxPushChain, allowPull } =
-    input
+  const {
+    cube,
+    worldLine,
+    boardWidth,
+    boardHeight,
+    timeDepth,
+    intent,
+    maxPushChain,
+    allowPull,
+    allowTimePush = false,
+    lureRange = 0,
+  } = input
 
   if (!intent) {
     return null
@@ -147,8 +243,9 @@ export function buildActionPreview(input: {
     return null
   }
 
-  const nextSpatial = movePosition(from, intent.direction)
-  const nextTime = from.t + 1
+  const wrapping = wrappingOf(cube)
+  const nextSpatial = wrapPosition(movePosition(from, intent.direction), wrapping)
+  const nextTime = followingTime(from.t, wrapping.timeDepth)
 
   if (!isInBounds(nextSpatial, boardWidth, boardHeight)) {
     return {
@@ -184,6 +281,17 @@ export function buildActionPreview(input: {
 
   switch (intent.mode) {
     case 'Move': {
+      if (currentLayer(worldLine) === 'catwalk') {
+        const offCatwalk = catwalkAt(cube, to) === null
+        return {
+          mode: intent.mode,
+          from,
+          to,
+          blocked: offCatwalk,
+          reason: offCatwalk ? 'No catwalk there' : undefined,
+        }
+      }
+
       const blocked = blockingObjects(cube, to).length > 0
       return {
         mode: intent.mode,
@@ -237,5 +345,64 @@ export function buildActionPreview(input: {
         reason: pullResult.reason,
       }
     }
+    case 'TimePush': {
+      if (!allowTimePush) {
+        return {
+          mode: intent.mode,
+          from,
+          to,
+          blocked: true,
+          reason: 'Time push is disabled',
+        }
+      }
+
+      const timePushResult = isTimePushPreviewBlocked({ cube, timeDepth, to })
+      return {
+        mode: intent.mode,
+        from,
+        to,
+        blocked: timePushResult.blocked,
+        reason: timePushResult.reason,
+      }
+    }
+    case 'Throw': {
+      const landing = lureLandingCell(
+        cube,
+        { x: from.x, y: from.y, t: nextTime },
+        intent.direction,
+        lureRange,
+      )
+      return {
+        mode: intent.mode,
+        from,
+        to: landing ?? to,
+        blocked: landing === null,
+        reason: landing ? undefined : 'No room to throw',
+      }
+    }
+    case 'Break': {
+      const breakable = objectsAt(cube, to).some((object) =>
+        hasComponent(object.archetype.components, 'Breakable'),
+      )
+      return {
+        mode: intent.mode,
+        from,
+        to,
+        blocked: !breakable,
+        reason: breakable ? undefined : 'Nothing to break there',
+      }
+    }
+    case 'Interact': {
+      const usable = objectsAt(cube, to).some((object) =>
+        hasComponent(object.archetype.components, 'Interactable'),
+      )
+      return {
+        mode: intent.mode,
+        from,
+        to,
+        blocked: !usable,
+        reason: usable ? undefined : 'Nothing to use there',
+      }
+    }
   }
 }
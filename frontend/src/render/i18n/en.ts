@@ -0,0 +1,633 @@
+/** Reference catalog: every message key must exist here, and every other locale translates it. */
+export const en = {
+  'common.on': 'on',
+  'common.off': 'off',
+  'common.na': 'n/a',
+  'common.closeHint': '{keys} / Esc: close',
+
+  'shell.pack': 'Pack: {packId} | Channel: {channel}',
+  'shell.isoLoading': 'Loading isometric view...',
+  'shell.isoCaption': 'Iso window t={start}..{end}, focus={focus}',
+
+  'command.North.label': 'North',
+  'command.North.description': 'Apply the current mode northward',
+  'command.West.label': 'West',
+  'command.West.description': 'Apply the current mode westward',
+  'command.South.label': 'South',
+  'command.South.description': 'Apply the current mode southward',
+  'command.East.label': 'East',
+  'command.East.description': 'Apply the current mode eastward',
+  'command.SelectMove.label': 'Move mode',
+  'command.SelectMove.description': 'Action menu: normal movement',
+  'command.SelectPush.label': 'Push mode',
+  'command.SelectPush.description': 'Action menu: push a chain of boxes forward',
+  'command.SelectPull.label': 'Pull mode',
+  'command.SelectPull.description': 'Action menu: drag the object behind you',
+  'command.SelectTimePush.label': 'Time push mode',
+  'command.SelectTimePush.description': 'Action menu: shove a box one slice into the future',
+  'command.SelectThrow.label': 'Throw mode',
+  'command.SelectThrow.description': 'Action menu: throw a lure to draw guards away',
+  'command.SelectBreak.label': 'Break mode',
+  'command.SelectBreak.description': 'Action menu: strike a breakable wall next to you',
+  'command.SelectPeek.label': 'Peek mode',
+  'command.SelectPeek.description': 'Action menu: look around a corner without moving',
+  'command.SelectInteract.label': 'Use mode',
+  'command.SelectInteract.description': 'Action menu: use a terminal or other fixture next to you',
+  'command.Wait.label': 'Wait',
+  'command.Wait.description': 'Stay in place for one time slice',
+  'command.Rift.label': 'Rift',
+  'command.Rift.description': 'Jump back in time by the rift delta',
+  'command.SwitchCharacter.label': 'Switch',
+  'command.SwitchCharacter.description': 'Take control of the next character',
+  'command.Climb.label': 'Climb',
+  'command.Climb.description': 'Go up or down the ladder you stand on',
+  'command.Hint.label': 'Hint',
+  'command.Hint.description': 'Ask the solver for the next move (limited per level)',
+  'command.Restart.label': 'Restart',
+  'command.Restart.description': 'Restart the current level',
+  'command.ResumeSession.label': 'Resume',
+  'command.ResumeSession.description': 'Resume the last autosaved session',
+  'command.ToggleActionMenu.label': 'Menu',
+  'command.ToggleActionMenu.description': 'Open the action mode menu',
+  'command.ToggleLevels.label': 'Levels',
+  'command.ToggleLevels.description': 'Open level selection',
+  'command.ToggleState.label': 'State',
+  'command.ToggleState.description': 'Show detailed state',
+  'command.ToggleLog.label': 'Log',
+  'command.ToggleLog.description': 'Show the action log',
+  'command.ToggleSettings.label': 'Settings',
+  'command.ToggleSettings.description': 'Open settings',
+  'command.ToggleHelp.label': 'Help',
+  'command.ToggleHelp.description': 'Show this help screen',
+  'command.TogglePlan.label': 'Plan turns',
+  'command.TogglePlan.description': 'Queue several actions and preview them before committing',
+  'command.CommitPlan.label': 'Commit plan',
+  'command.CommitPlan.description': 'Play the queued plan (while planning)',
+  'command.UndoPlanStep.label': 'Undo plan step',
+  'command.UndoPlanStep.description': 'Drop the last queued action (while planning)',
+  'command.ToggleExamine.label': 'Examine',
+  'command.ToggleExamine.description':
+    'Move a cursor over the board to inspect what stands in the viewed slice',
+  'command.ToggleWorldLine.label': 'World line',
+  'command.ToggleWorldLine.description':
+    'List every position you have held and jump the board to one slice',
+  'command.SnapshotSlice.label': 'Snapshot',
+  'command.SnapshotSlice.description': 'Save the viewed slice as a text file',
+  'command.SnapshotAllSlices.label': 'Snapshot all',
+  'command.SnapshotAllSlices.description': 'Save every slice of the level as a text file',
+  'command.CloseLayer.label': 'Close',
+  'command.CloseLayer.description': 'Close the top overlay or cancel a pending action mode',
+  'command.ToggleDanger.label': 'Danger',
+  'command.ToggleDanger.description': 'Toggle the danger preview',
+  'command.ToggleLayerView.label': 'Layer view',
+  'command.ToggleLayerView.description': 'Switch the board between the ground and catwalk views',
+  'command.ToggleTimelineDiff.label': 'Timeline diff',
+  'command.ToggleTimelineDiff.description':
+    'Highlight cells that changed since the last rift into the past',
+  'command.CycleTimeline.label': 'Timelines',
+  'command.CycleTimeline.description': 'Show the next timeline branch on the board',
+  'command.NextPack.label': 'Pack',
+  'command.NextPack.description': 'Cycle to the next content pack',
+  'command.RiftDeltaDown.label': 'Rift -',
+  'command.RiftDeltaDown.description': 'Decrease the rift delta',
+  'command.RiftDeltaUp.label': 'Rift +',
+  'command.RiftDeltaUp.description': 'Increase the rift delta',
+  'command.PushChainDown.label': 'Push Max -',
+  'command.PushChainDown.description': 'Decrease the maximum push chain',
+  'command.PushChainUp.label': 'Push Max +',
+  'command.PushChainUp.description': 'Increase the maximum push chain',
+  'command.ToggleHotseat.label': 'Hotseat',
+  'command.ToggleHotseat.description':
+    'Two players take turns with the characters (set before moving)',
+  'command.Quit.label': 'Quit',
+  'command.Quit.description': 'Quit (not available in the web build)',
+
+  'group.Movement': 'Movement',
+  'group.Actions': 'Actions',
+  'group.Interface': 'Interface',
+  'group.Tuning': 'Tuning',
+
+  'bottomBar.direction': 'Direction',
+
+  'help.title': 'Help',
+  'help.levelTitle': 'This Level',
+  'help.goal': 'Reach the exit without being seen. Enemies watch earlier and later slices too.',
+  'help.riftTiles': 'Rift tiles: stand on one and press {keys} to travel to its linked slice.',
+  'help.push': 'Boxes can be pushed in Push mode ({menuKeys}, then {keys}).',
+  'help.pull': 'Some objects can be dragged in Pull mode ({menuKeys}, then {keys}).',
+  'help.timePush':
+    'Boxes can be shoved one slice ahead in time in Time Push mode ({menuKeys}, then {keys}).',
+  'help.slippery':
+    'Ice tiles keep you and pushed boxes sliding one cell per slice until something blocks the way.',
+  'help.water':
+    'You cannot wade into water, but a box pushed in sinks and leaves floor to walk on.',
+  'help.shadows':
+    'No guard, camera or searchlight sees you while you stand on a dark shadow tile.',
+  'help.lures':
+    'Lures draw nearby guards to where they land for a few slices. Throw mode: {menuKeys}, then {keys}.',
+  'help.hotseat':
+    'Hotseat ({keys}): seats take turns after each action; a caught character sits out.',
+  'help.characters':
+    'You control several characters; each must stand on an exit to win. Switch with {keys}.',
+  'help.breakables':
+    'Cracked walls give way after a few blows (Break mode: {menuKeys}, then {keys}). Guards hear each.',
+  'help.peek':
+    'Peek mode ({menuKeys}, then {keys}) shows what the next cell over sees. No time passes.',
+  'help.interactables':
+    'Terminals and other fixtures are used from the next cell (Use mode: {menuKeys}, then {keys}).',
+  'help.knockouts':
+    'Use ({menuKeys}, then {keys}) on a guard that cannot see you to knock it out. Hide the body.',
+  'help.elevation':
+    'Ladders ({keys}) lead onto catwalks. Guards below only see you up there past an open railing.',
+
+  'mode.Move': 'Move',
+  'mode.Move.description': 'Normal movement',
+  'mode.Push': 'Push',
+  'mode.Push.description': 'Push chain forward',
+  'mode.Pull': 'Pull',
+  'mode.Pull.description': 'Pull from behind',
+  'mode.TimePush': 'Time Push',
+  'mode.TimePush.description': 'Shove a box into the next slice',
+  'mode.Throw': 'Throw',
+  'mode.Throw.description': 'Toss a lure to distract guards',
+  'mode.Break': 'Break',
+  'mode.Break.description': 'Strike a breakable wall (noisy)',
+  'mode.Peek': 'Peek',
+  'mode.Peek.description': 'Look around a corner (no turn)',
+  'mode.Interact': 'Use',
+  'mode.Interact.description': 'Use a terminal or fixture',
+
+  'breadcrumb.mode': '{mode} > direction? ({cancel}: back to Move)',
+  'breadcrumb.examine': 'Examine ({x},{y}) > move the cursor ({cancel}: close)',
+  'breadcrumb.plan': 'Plan > {count} queued ({commit}: commit, {cancel}: discard)',
+
+  'phase.Playing': 'Playing',
+  'phase.Won': 'Won',
+  'phase.Detected': 'Detected',
+  'phase.Paradox': 'Paradox',
+  'phase.BootError': 'BootError',
+  'playDifficulty.Easy': 'Easy',
+  'playDifficulty.Normal': 'Normal',
+  'playDifficulty.Hard': 'Hard',
+  'mutator.thickFog.label': 'Thick fog',
+  'mutator.thickFog.description': 'Guard vision range -2',
+  'mutator.hastyGuards.label': 'Hasty guards',
+  'mutator.hastyGuards.description': 'Detection delay -1',
+  'mutator.eagleEyes.label': 'Eagle eyes',
+  'mutator.eagleEyes.description': 'Guard vision range +1',
+  'mutator.noWaiting.label': 'No waiting',
+  'mutator.noWaiting.description': 'The wait action is refused',
+  'mutator.noHints.label': 'No hints',
+  'mutator.noHints.description': 'Hint budget drops to 0',
+
+  'hud.command': 'Command',
+  'hud.mode': 'Mode: {mode}',
+  'hud.state': 'State',
+  'hud.log': 'Log',
+  'hud.legend': 'Legend',
+  'legend.player': 'You',
+  'legend.pastSelf': 'Past self',
+  'legend.ghost': 'Best run ghost',
+  'legend.danger': 'Guard that would see you',
+  'legend.facing': 'Facing (filled: cone only)',
+  'legend.searchlight': 'Searchlight beam',
+  'legend.darkness': 'Unlit cell',
+  'legend.shadow': 'Shadow tile (hidden)',
+  'hud.turn': 'Turn',
+  'hud.time': 'Time',
+  'hud.phase': 'Phase',
+  'hud.riftDelta': 'Rift Delta',
+  'hud.danger': 'Danger',
+  'hud.hints': 'Hints',
+  'hud.difficulty': 'Difficulty',
+  'hud.rules': 'Rules',
+  'hud.mutators': 'Mutators',
+  'hud.restarts': 'Restarts left',
+  'hud.lures': 'Lures',
+  'hud.peeks': 'Peeks',
+  'hud.character': 'Character',
+  'hud.seat': 'Seat',
+  'hud.seatValue': 'Player {seat}',
+  'hud.region': 'Area',
+  'hud.layer': 'Layer',
+  'hud.exit': 'Exit',
+  'hud.exitHeading': '{point}, {distance} away',
+  'hud.exitHere': 'Here',
+  'hud.extraction': 'Extraction',
+  'hud.extractionProgress': '{held} of {needed} turns held',
+  'hud.alarm': 'Alarm',
+  'hud.suspicion': 'Suspicion {suspicion}/{threshold}',
+  'hud.lockdown': 'Lockdown until t={time}',
+  'compass.N': 'N',
+  'compass.NE': 'NE',
+  'compass.E': 'E',
+  'compass.SE': 'SE',
+  'compass.S': 'S',
+  'compass.SW': 'SW',
+  'compass.W': 'W',
+  'compass.NW': 'NW',
+  'layer.ground': 'Ground',
+  'layer.catwalk': 'Catwalk',
+  'hud.timelines': 'Timelines',
+  'hud.timelineRow': 'from t={forkTime}, {state}',
+  'hud.timelinesHint': '{keys}: show the next branch',
+  'timeline.consistent': 'consistent',
+  'timeline.inconsistent': 'inconsistent',
+  'hud.examine': 'Examine ({x},{y}) at t={time}',
+  'hud.examineEmpty': 'Nothing here in this slice.',
+  'hud.examineRoute': 'Route: {route}',
+  'hud.examineSchedule': 'Next: {schedule}',
+  'hud.examineRift': 'Rift to ({x},{y}) at t={time}',
+  'hud.examineRiftBoth': 'Rift to ({x},{y}) at t={time}, both ways',
+  'hud.examineHint': 'Directions move the cursor; {keys} or Esc to leave',
+  'hud.timer': 'Timer',
+  'hud.victory': 'Victory',
+  'hud.victoryLevel': 'Cleared {name}',
+  'hud.victoryByAuthor': 'Cleared {name} by {author}',
+  'hud.stateDetailsHint': '{keys}: details',
+
+  'replay.faster': '{count} turn(s) faster than your best',
+  'replay.slower': '{count} turn(s) slower than your best',
+  'replay.tied': 'Same turn count as your best',
+  'replay.fewerRifts': '{count} fewer rift(s)',
+  'replay.moreRifts': '{count} more rift(s)',
+  'replay.avoided': 'You avoided {names} this time',
+  'replay.visited': 'You went through {names} this time',
+
+  'log.title': 'Action Log',
+  'log.empty': 'No actions yet.',
+  'worldLine.title': 'World Line',
+  'worldLine.controls': 'Up/Down: select, Enter: view that slice, {keys} / Esc: close',
+  'worldLine.turn': 'Turn',
+  'worldLine.via': 'Via',
+  'step.Start': 'Start',
+  'step.Normal': 'Step',
+  'step.Rift': 'Rift',
+  'step.Slide': 'Slide',
+  'step.Rewound': 'Rewind',
+  'step.Climb': 'Climb',
+  'log.entry': '{action} -> {outcome}',
+  'log.withEvents': '{entry}; {events}',
+
+  'action.Move': 'move {direction}',
+  'action.Push': 'push {direction}',
+  'action.Pull': 'pull {direction}',
+  'action.TimePush': 'time push {direction}',
+  'action.Throw': 'throw lure {direction} ({range})',
+  'action.Wait': 'wait',
+  'action.ApplyRift': 'rift',
+  'action.SwitchCharacter': 'switch character',
+  'action.Climb': 'climb',
+  'action.Break': 'break {direction}',
+  'action.Interact': 'use {direction}',
+  'action.MoveTo': 'move to ({x}, {y}, t={time})',
+
+  'direction.north': 'north',
+  'direction.south': 'south',
+  'direction.east': 'east',
+  'direction.west': 'west',
+
+  'outcome.Moved': 'moved',
+  'outcome.Rifted': 'rifted',
+  'outcome.Pushed': 'pushed',
+  'outcome.Pulled': 'pulled',
+  'outcome.Thrown': 'lure landed',
+  'outcome.Switched': 'switched',
+  'outcome.Climbed': 'climbed',
+  'outcome.Broke': 'struck',
+  'outcome.Interacted': 'used',
+  'outcome.KnockedOut': 'knocked out',
+
+  'event.Slid': 'slid to ({x}, {y})',
+  'event.ObjectsMoved': 'moved {count} object(s)',
+  'event.LureLanded': 'lure at ({x}, {y})',
+  'event.NoiseMade': 'noise at ({x}, {y})',
+  'event.ObjectBroken': '{objectId} gave way',
+  'event.ObjectUnmade': '{objectId} never was, from t={from}',
+  'event.ObjectRestored': '{objectId} is back, from t={from}',
+  'event.PropagationWarning.EntityCollision':
+    '{id} held its route: {blockerId} is at ({x},{y},t={t})',
+  'event.PropagationWarning.OutOfBounds': '{id} held its route: ({x},{y},t={t}) is off the map',
+  'event.WaterBridged': 'a box filled the water at ({x}, {y})',
+  'event.EnteredRegion': 'entered {name}',
+  'event.TimelineForked': 'the past changed: {timelineId} forks off at t={forkTime}',
+  'event.Paradox': 'paradox ({reason})',
+  'event.Detected': 'seen by {enemyId}',
+  'event.Glimpsed': 'glimpsed by {enemyId} (suspicion {suspicion})',
+  'event.MovedObjectNoticed': '{enemyId} noticed something moved (suspicion {suspicion})',
+  'event.AlarmRaised': 'alarm, lockdown until t={to}',
+  'event.Interacted': 'used {objectId}',
+  'event.LockdownLifted': 'lockdown lifted from t={at}',
+  'event.PatrolSwitched.reverse': '{enemyId} turns back from t={at}',
+  'event.PatrolSwitched.pause': '{enemyId} stops from t={at}',
+  'event.PatrolSwitched.resume': '{enemyId} moves on from t={at}',
+  'event.KnockedOut': '{enemyId} knocked out',
+  'event.BodyFound': '{enemyId} found {bodyId}',
+  'event.CharacterCaught': '{characterId} caught by {enemyId}',
+  'event.ReachedExit': '{characterId} at an exit',
+  'event.Won': 'level complete',
+  'event.ControlPassed': "{characterId}'s turn",
+
+  'plan.title': 'Turn Plan',
+  'plan.controls':
+    'Directions / Enter / Space: queue | Backspace: undo | Y: commit | B / Esc: discard',
+  'plan.empty': 'No steps queued yet.',
+  'plan.step': '{action} -> ({x}, {y}, t={t})',
+  'plan.rejected': '{action} -> {status}',
+  'plan.seenBy': 'seen by {enemies}',
+  'plan.ends': 'run ends: {phase}',
+
+  'confirm.title': 'Confirm',
+  'confirm.controls': 'Y / Enter: proceed | N / Esc: cancel',
+  'confirm.detection': 'This move gets you seen at t={time} — proceed?',
+  'confirm.detectionBy': 'This move gets you seen at t={time} by {enemyId} — proceed?',
+
+  'settings.title': 'Settings',
+  'settings.language': 'Language',
+  'settings.difficulty':
+    'Difficulty: Easy gives guards 1 turn more delay and 1 less sight and adds 2 hints; Hard the reverse, no hints, and guards notice moved boxes (reloads the level)',
+  'settings.ruleProfile': 'Rules (reloads the level)',
+  'settings.ruleProfilesError': 'Rule profiles config is invalid, only Standard is available: {error}',
+  'settings.isoPanel': 'Show isometric panel',
+  'settings.compactHints': 'Compact bottom hints',
+  'settings.defaultDanger': 'Default danger preview',
+  'settings.ghostRun': 'Show best-run ghost',
+  'settings.exitCompass': 'Exit compass: point to a far or hidden exit',
+  'settings.assistDetection': 'Assist: confirm moves that get you seen',
+  'settings.accessibleCues': 'Shape and glyph cues (not color alone)',
+  'settings.fogOfWar': 'Fog of war: only show what you can see',
+  'settings.soundCues': 'Sound cues for warnings, rifts, pushes and victory',
+  'settings.reduceMotion': 'Reduce motion: no screenshake or sweeps',
+  'settings.speedrunTimer': 'Speedrun timer: show and record real time per run',
+  'settings.ironmanCampaign': 'Iron-man campaign: separate progress, wiped by any detection',
+  'settings.keyRepeat': 'Held-key repeat delay',
+  'settings.keyRepeatOff': 'off',
+  'settings.keyRepeatMs': '{ms} ms',
+  'settings.savedToConfig': 'Changes are saved to config.toml.',
+  'settings.savedInBrowser': 'Changes are saved in this browser.',
+
+  'state.title': 'State Details',
+  'state.core': 'Core',
+  'state.tools': 'Tools',
+  'state.snapshot': 'Snapshot',
+  'state.board': 'Board',
+  'state.depth': 'Depth',
+  'state.mode': 'Mode',
+  'state.pushMax': 'Push Max',
+  'state.pull': 'Pull',
+  'state.sliceObjects': 'Slice Objects',
+  'state.player': 'Player',
+  'state.wins': 'Wins',
+  'state.winsValue': '{wins} ({hinted} hinted)',
+  'state.bestTurns': 'Best Turns',
+  'state.contentPack': 'Content Pack',
+  'state.packClass': 'Pack Class',
+  'state.difficulty': 'Difficulty',
+  'state.diffSource': 'Diff Source',
+  'state.diffScore': 'Diff Score',
+  'state.diffModel': 'Diff Model',
+  'state.diffTarget': 'Diff Target',
+  'state.flavor': 'Flavor',
+  'state.spatial': 'Spatial',
+  'state.temporal': 'Temporal',
+  'state.detect': 'Detect',
+  'state.interact': 'Interact',
+  'state.paradox': 'Paradox',
+
+  'tutorial.progress': 'Tutorial {step}/{total}',
+  'tutorial.complete': 'Tutorial complete.',
+
+  'progression.title': 'Progression',
+  'progression.controls':
+    'G / Esc close | Arrows navigate | Enter load | 1 difficulty | 2 tag | 3 sort | 4 community',
+  'progression.communityControls': 'G / Esc close | Up/Down navigate | Enter load | 4 records',
+  'progression.recordsControls': 'G / Esc close | 4 challenge',
+  'progression.challengeControls':
+    'G / Esc close | Up/Down navigate | Enter toggle | 1 daily | 2 clear | 4 tracks',
+  'progression.tabTracks': 'Tracks',
+  'progression.tabCommunity': 'Community ({count})',
+  'progression.tabRecords': 'Records',
+  'progression.tabChallenge': 'Challenge ({count})',
+  'progression.challengeDaily': 'Daily: {names}',
+  'progression.challengeClear': 'Clear all',
+  'progression.challengeNote':
+    'Mutators stack and apply to every level you load; changing them restarts the current one.',
+  'progression.resumeSession': 'Resume last session: {name}, turn {turn} (U)',
+  'progression.recordTurns': '{count} turns',
+  'progression.sumOfBest': 'Sum of best: {time}',
+  'progression.community': 'community',
+  'progression.invalid': 'invalid',
+  'progression.communityEmpty':
+    'No community levels. Put <id>.level.json files in ~/.local/share/he-walks-unseen/levels/ and reload the page.',
+  'progression.communityError': '{file}: {error}',
+  'progression.filterDifficulty': 'Difficulty: {value}',
+  'progression.filterTag': 'Tag: {value}',
+  'progression.filterAll': 'all',
+  'progression.sortTrack': 'Sort: track order',
+  'progression.sortDifficulty': 'Sort: difficulty',
+  'progression.noMatches': 'No levels match the filter.',
+  'progression.unavailable': 'Progression unavailable ({error}).',
+  'progression.loading': 'Loading progression...',
+  'progression.prev': 'Prev',
+  'progression.next': 'Next',
+  'progression.track': 'Track {index}/{count}: {trackId}',
+  'progression.complete': 'complete',
+  'progression.unlocked': 'unlocked',
+  'progression.locked': 'locked',
+  'progression.current': 'current',
+  'progression.empty': 'No progression tracks defined.',
+  'ironman.warning': 'Iron-man: one detection resets all campaign progress',
+  'ironman.title': 'Iron-man',
+  'ironman.stats': 'Streak {streak} | Best {best} | Resets {resets} | Flawless tracks {tracks}',
+  'ironman.achievement.firstClear': 'Clean start: clear a level',
+  'ironman.achievement.streak3': 'Ghost: clear 3 levels without being seen',
+  'ironman.achievement.streak5': 'Phantom: clear 5 levels without being seen',
+  'ironman.achievement.flawlessTrack': 'He walks unseen: finish a track without a detection',
+
+  'crash.title': 'Something broke',
+  'crash.summary':
+    'The game crashed on turn {turn} of {packId}. A crash report with the run so far was saved.',
+  'crash.download': 'Download crash report',
+  'crash.restart': 'Restart level',
+  'crash.reload': 'Reload page',
+
+  'debug.title': 'Debug console',
+  'debug.controls': 'Enter runs a command (try help). ` or Esc closes.',
+  'debug.input': 'Debug command',
+  'hud.perf': 'Performance',
+  'perf.apply': 'Last action',
+  'perf.slicesUpdated': 'Slices updated',
+  'perf.propagationWarnings': 'Guard collisions',
+  'perf.stateSize': 'State size',
+  'perf.cubeSize': 'Cube size',
+  'perf.waiting': 'Take an action to measure it.',
+  'perf.entities': 'Entities per slice: {counts}',
+
+  'status.communityLevelInvalid': 'Community level {file} failed validation.',
+  'status.levelLocked': 'Level locked: {packId}',
+  'status.planningDisabled': 'Planning is off under {rules} rules.',
+  'status.previewDisabled': 'Danger preview is off under {rules} rules.',
+  'status.layerView': 'Showing the {layer} layer.',
+  'status.timelineDiffOn': 'Timeline diff: {count} cells changed since the last rift back.',
+  'status.timelineDiffOff': 'Timeline diff hidden.',
+  'status.timelineDiffUnavailable': 'Nothing to compare yet: rift into the past first.',
+  'status.branchingOff': 'The past is overwritten, not branched, under {rules} rules.',
+  'status.timelineActive': 'Following the active timeline, {timelineId}.',
+  'status.timelineView': 'Viewing {timelineId} (forked at t={forkTime}, {state}).',
+  'status.examineOn': 'Examining: move the cursor to inspect a cell.',
+  'status.examineOff': 'Examine mode closed.',
+  'status.viewingSlice':
+    'Viewing slice t={t} as of turn {turn}; your next action returns to t={now}.',
+  'status.viewingPresent': 'Viewing the present slice.',
+  'status.snapshotSaved': 'Saved {fileName}.',
+  'status.ironmanReset': 'Detected in iron-man: campaign progress has been reset.',
+  'status.quitUnavailable': 'Quit is not wired in web build.',
+  'status.noSavedSession': 'No saved session to resume.',
+  'status.configError': 'Ignoring {error}',
+  'status.resumingSession': 'Loading {packId} to resume at turn {turn}...',
+  'status.contentLoadFailed': 'Content load failed ({packId}): {kind}',
+  'status.gameEnded': 'Game already ended. Press R to restart.',
+  'status.turn': 'Turn {turn}: {status}',
+  'status.caughtPlaysOn': 'Turn {turn}: {characterId} caught by {enemyId}; {nextId} plays on',
+  'status.detected': 'Turn {turn}: detected by {enemyId} (observed t={time})',
+  'status.paradox': 'Turn {turn}: paradox ({reason})',
+  'status.won': 'Turn {turn}: reached exit at ({x}, {y}, t={time})',
+  'status.inconsistentExit': 'Turn {turn}: exits only count in a consistent timeline',
+  'status.holdingExit': 'Turn {turn}: holding the exit ({held}/{needed})',
+  'status.othersNeedExit': 'Turn {turn}: {characterId} is at an exit; the others still need one',
+  'status.nextCharacter': "{characterId}'s turn",
+  'status.glimpsed': 'glimpsed by {enemyId} ({suspicion}/{threshold})',
+  'status.noticedMoved': '{enemyId} noticed something moved ({suspicion}/{threshold})',
+  'status.alarmRaised': 'alarm raised by {enemyId}; lockdown until t={time}',
+  'status.bodyFound': '{enemyId} found {bodyId}',
+  'status.bodyFoundLockdown': '{enemyId} found {bodyId}; lockdown until t={time}',
+  'status.dependencyFailed': 'Internal error: dependency propagation failed ({kind})',
+  'status.detourFailed': 'Internal error: patrol detour failed ({kind})',
+  'status.lureFailed': 'Internal error: lure propagation failed ({kind})',
+  'status.chaserFailed': 'Internal error: chaser propagation failed ({kind})',
+  'status.enteredRegion': 'entered {name}',
+  'status.bootFailed':
+    'Boot content failed ({kind}); gameplay disabled until valid content is loaded',
+  'status.bootFallback': 'Boot content failed ({kind}); using dev fallback level',
+  'status.bootContentFailed': 'Boot content failed',
+  'status.objectBootstrapFailed':
+    'Object bootstrap failed; gameplay disabled until valid content is loaded',
+  'status.contentBootstrapFailed': 'Loaded content bootstrap failed',
+  'status.restartNoLevel': 'Restart blocked: no level loaded. Load a valid content pack.',
+  'status.restartFailed': 'Restart failed: object bootstrap error',
+  'status.hotseatLocked': 'Hotseat can only be changed before the first action; restart to switch',
+  'status.hotseatOn': 'Hotseat on: characters take turns',
+  'status.hotseatOff': 'Hotseat off',
+  'status.riftSettings': 'Rift settings updated (delta={delta}, cost={cost})',
+  'status.interactionConfig':
+    'Interaction config updated (maxPushChain={maxPushChain}, allowPull={allowPull}, allowTimePush={allowTimePush})',
+  'status.detectionConfig':
+    'Detection config updated (enabled={enabled}, delay={delay}, range={range})',
+  'status.paradoxConfig': 'Paradox config updated (enabled={enabled})',
+  'status.loadingPack': 'Loading content pack: {packId}',
+  'status.loadedPack': 'Loaded content pack: {packId}',
+  'status.noRestarts': 'No restarts left under {rules} rules',
+  'status.restarted': 'Restarted',
+  'status.restartedEcho': 'Restarted; 1 echo retraces past attempts',
+  'status.restartedEchoes': 'Restarted; {count} echoes retrace past attempts',
+  'status.resumed': 'Resumed at turn {turn}',
+  'status.hintsNotPlaying': 'Hints are only available while playing',
+  'status.noHints': 'No hints left ({budget} per level)',
+  'status.hintSolved': 'Hint: you are already on the exit',
+  'status.hintNoRoute': 'Hint: solver found no safe route from here',
+  'status.hint': 'Hint {used}/{budget}: {action} to ({x}, {y}, t={time}); {steps} steps to exit',
+  'status.peekNotPlaying': 'Peeking is only possible while playing',
+  'status.noPeeks': 'No peeks left ({budget} per level)',
+  'status.nothingToPeek': 'Nothing to peek past {direction}',
+  'status.peeked': 'Peek {used}/{budget}: looked {direction} from ({x}, {y})',
+  'status.debugOutside': 'Debug: ({x},{y},t{time}) is outside the cube',
+  'status.debugTeleportFailed': 'Debug: teleport failed ({kind})',
+  'status.debugTeleported': 'Debug: teleported to ({x},{y},t{time})',
+  'status.debugUnknownArchetype': 'Debug: unknown archetype {archetype}',
+  'status.debugSpawnFailed': 'Debug: spawn failed ({kind})',
+  'status.debugSpawned': 'Debug: spawned {objectId} at ({x},{y},t{time})',
+
+  'interaction.outOfBounds': 'Blocked by boundary',
+  'interaction.timeBoundary': 'Blocked by time boundary',
+  'interaction.blockedByObject': 'Blocked by object',
+  'interaction.selfIntersection': 'Blocked by self-intersection',
+  'interaction.invalidStep': 'Invalid step ({action})',
+  'interaction.internalError': 'Internal error ({action})',
+  'interaction.emptyWorldLine': 'Internal error: empty world line',
+  'interaction.moved': 'move to ({x}, {y}, t={time})',
+  'interaction.slid': 'slid to ({x}, {y}, t={time})',
+  'interaction.noCatwalk': 'No catwalk there',
+  'interaction.waitDisabled': 'Waiting is disabled',
+  'interaction.waited': 'wait at t={time}',
+  'interaction.noLadder': 'No ladder here',
+  'interaction.climbedUp': 'climbed up at t={time}',
+  'interaction.climbedDown': 'climbed down at t={time}',
+  'interaction.riftTargetTime': 'Invalid rift target time',
+  'interaction.riftTargetPosition': 'Invalid rift target position',
+  'interaction.insufficientEnergy': 'Insufficient energy for rift',
+  'interaction.rifted': 'rift({mode}) to ({x}, {y}, t={time})',
+  'interaction.offGroundBoxes': 'Cannot reach boxes from the catwalk',
+  'interaction.offGroundWalls': 'Cannot reach walls from the catwalk',
+  'interaction.offGroundAnything': 'Cannot reach anything from the catwalk',
+  'interaction.anchored': '{objectId} is anchored in time',
+  'interaction.notPushable': 'Target is not pushable',
+  'interaction.pushChainTooLong': 'Push chain too long',
+  'interaction.noSpaceToPush': 'No space to push',
+  'interaction.stackedBox': 'Stacked boxes cannot be pushed',
+  'interaction.multiTileInChain': 'Large objects cannot be pushed in a chain',
+  'interaction.groupInChain': 'Linked boxes cannot be pushed in a chain',
+  'interaction.pushBlocked': 'Push blocked',
+  'interaction.pushFailed': 'Push failed',
+  'interaction.heavyNeedsMomentum': 'Heavy box needs a running start',
+  'interaction.heavyInChain': 'Heavy box cannot be pushed in a chain',
+  'interaction.riftExitBlocked': 'Rift exit blocked',
+  'interaction.guardNoWayAround': 'A guard has no way around',
+  'interaction.stacked': 'stacked {objectId} on {baseId} at ({x}, {y}, t={time})',
+  'interaction.pushedGroup': 'pushed group {groupId} ({count} object(s)) {direction}',
+  'interaction.pushedObject': 'pushed {objectId} to ({x}, {y}, t={time})',
+  'interaction.pushed': 'pushed {count} object(s) to ({x}, {y}, t={time})',
+  'interaction.pushedRifted': 'pushed {count} object(s); lead box rifted to ({x}, {y}, t={time})',
+  'interaction.pushedSank':
+    'pushed {count} object(s); lead box sank into the water at ({x}, {y}, t={time})',
+  'interaction.pullDisabled': 'Pull is disabled',
+  'interaction.nothingToPull': 'Nothing to pull',
+  'interaction.notPullable': 'Target is not pullable',
+  'interaction.notPullableHere': 'Target cannot be pulled in this timeline',
+  'interaction.invalidPullTarget': 'Invalid pull target',
+  'interaction.pulled': 'pulled 1 object to ({x}, {y}, t={time})',
+  'interaction.timePushDisabled': 'Time push is disabled',
+  'interaction.noLaterSlice': 'No later slice to push into',
+  'interaction.timePushed': 'time-pushed {objectId} to t={time}',
+  'interaction.noLuresLeft': 'No lures left',
+  'interaction.throwRange': 'Throw range must be between 1 and {max}',
+  'interaction.noRoomToThrow': 'No room to throw',
+  'interaction.threw': 'threw lure to ({x}, {y}, t={time})',
+  'interaction.nothingToBreak': 'Nothing to break there',
+  'interaction.broke': 'broke {objectId} at t={time}',
+  'interaction.brokeLinked': 'broke {objectId} and {count} linked object(s) at t={time}',
+  'interaction.hit': 'hit {objectId} ({hits}/{total}) at t={time}',
+  'interaction.nothingToUse': 'Nothing to use there',
+  'interaction.used': 'used {objectId}',
+  'interaction.guardAware': '{enemyId} sees you coming',
+  'interaction.noRoomForBody': 'No room for the body',
+  'interaction.knockedOut': 'knocked out {enemyId}',
+  'interaction.noOtherCharacter': 'No other character to switch to',
+  'interaction.switched': 'switched to {characterId}',
+  'interaction.suspicionCleared': 'suspicion cleared',
+  'interaction.noAlarm': 'no alarm to reset',
+  'interaction.lockdownLifted': 'lockdown lifted from t={time}',
+  'interaction.nothingHappens': 'nothing happens',
+  'interaction.leverReverse': '{enemyId} turns back',
+  'interaction.leverPause': '{enemyId} stops',
+  'interaction.leverResume': '{enemyId} moves on',
+  'interaction.moveTo': 'Move to: {reason}',
+  'interaction.moveToArrived': 'Move to: already there',
+  'moveTo.outsideHorizon': 'target ({x}, {y}, t={time}) is out of reach',
+  'moveTo.noPath': 'no path to ({x}, {y}, t={time})',
+  'moveTo.hotseat': 'not available in hotseat',
+  'moveTo.stepRejected': 'step {step} rejected ({status})',
+  'moveTo.wouldEnd': 'step {step} would end the run ({status})',
+  'moveTo.diverged': 'step {step} ends at {actual} instead of ({x}, {y})',
+  'moveTo.cell': '({x}, {y})',
+  'moveTo.nowhere': 'nowhere',
+} satisfies Record<string, string>
@@ -0,0 +1,650 @@
+import type { MessageCatalog } from './index'
+
+export const es: MessageCatalog = {
+  'common.on': 'sí',
+  'common.off': 'no',
+  'common.na': 'n/d',
+  'common.closeHint': '{keys} / Esc: cerrar',
+
+  'shell.pack': 'Paquete: {packId} | Canal: {channel}',
+  'shell.isoLoading': 'Cargando vista isométrica...',
+  'shell.isoCaption': 'Ventana iso t={start}..{end}, foco={focus}',
+
+  'command.North.label': 'Norte',
+  'command.North.description': 'Aplica el modo actual hacia el norte',
+  'command.West.label': 'Oeste',
+  'command.West.description': 'Aplica el modo actual hacia el oeste',
+  'command.South.label': 'Sur',
+  'command.South.description': 'Aplica el modo actual hacia el sur',
+  'command.East.label': 'Este',
+  'command.East.description': 'Aplica el modo actual hacia el este',
+  'command.SelectMove.label': 'Modo mover',
+  'command.SelectMove.description': 'Menú de acción: movimiento normal',
+  'command.SelectPush.label': 'Modo empujar',
+  'command.SelectPush.description': 'Menú de acción: empuja una fila de cajas',
+  'command.SelectPull.label': 'Modo arrastrar',
+  'command.SelectPull.description': 'Menú de acción: arrastra el objeto a tu espalda',
+  'command.SelectTimePush.label': 'Modo empuje temporal',
+  'command.SelectTimePush.description': 'Menú de acción: lanza una caja un corte hacia el futuro',
+  'command.SelectThrow.label': 'Modo lanzar',
+  'command.SelectThrow.description': 'Menú de acción: lanza un señuelo para alejar a los guardias',
+  'command.SelectBreak.label': 'Modo romper',
+  'command.SelectBreak.description': 'Menú de acción: golpea un muro frágil junto a ti',
+  'command.SelectPeek.label': 'Modo asomarse',
+  'command.SelectPeek.description': 'Menú de acción: mira tras una esquina sin moverte',
+  'command.SelectInteract.label': 'Modo usar',
+  'command.SelectInteract.description': 'Menú de acción: usa un aparato junto a ti',
+  'command.Wait.label': 'Esperar',
+  'command.Wait.description': 'Quédate quieto durante un corte de tiempo',
+  'command.Rift.label': 'Grieta',
+  'command.Rift.description': 'Retrocede en el tiempo según el delta de grieta',
+  'command.SwitchCharacter.label': 'Cambiar',
+  'command.SwitchCharacter.description': 'Toma el control del siguiente personaje',
+  'command.Climb.label': 'Trepar',
+  'command.Climb.description': 'Sube o baja por la escalera en la que estás',
+  'command.Hint.label': 'Pista',
+  'command.Hint.description': 'Pide al solucionador el siguiente movimiento (limitado por nivel)',
+  'command.Restart.label': 'Reiniciar',
+  'command.Restart.description': 'Reinicia el nivel actual',
+  'command.ResumeSession.label': 'Reanudar',
+  'command.ResumeSession.description': 'Reanuda la última sesión guardada automáticamente',
+  'command.ToggleActionMenu.label': 'Menú',
+  'command.ToggleActionMenu.description': 'Abre el menú de modos de acción',
+  'command.ToggleLevels.label': 'Niveles',
+  'command.ToggleLevels.description': 'Abre la selección de niveles',
+  'command.ToggleState.label': 'Estado',
+  'command.ToggleState.description': 'Muestra el estado detallado',
+  'command.ToggleLog.label': 'Registro',
+  'command.ToggleLog.description': 'Muestra el registro de acciones',
+  'command.ToggleSettings.label': 'Ajustes',
+  'command.ToggleSettings.description': 'Abre los ajustes',
+  'command.ToggleHelp.label': 'Ayuda',
+  'command.ToggleHelp.description': 'Muestra esta pantalla de ayuda',
+  'command.TogglePlan.label': 'Planificar',
+  'command.TogglePlan.description': 'Encola varias acciones y revísalas antes de confirmarlas',
+  'command.CommitPlan.label': 'Confirmar plan',
+  'command.CommitPlan.description': 'Ejecuta el plan encolado (al planificar)',
+  'command.UndoPlanStep.label': 'Deshacer paso',
+  'command.UndoPlanStep.description': 'Quita la última acción encolada (al planificar)',
+  'command.ToggleExamine.label': 'Examinar',
+  'command.ToggleExamine.description':
+    'Mueve un cursor por el tablero para inspeccionar lo que hay en el corte visible',
+  'command.ToggleWorldLine.label': 'Línea de mundo',
+  'command.ToggleWorldLine.description':
+    'Lista cada posición que has ocupado y lleva el tablero a uno de sus cortes',
+  'command.SnapshotSlice.label': 'Captura',
+  'command.SnapshotSlice.description': 'Guarda el corte visible como archivo de texto',
+  'command.SnapshotAllSlices.label': 'Captura completa',
+  'command.SnapshotAllSlices.description': 'Guarda todos los cortes del nivel como archivo de texto',
+  'command.CloseLayer.label': 'Cerrar',
+  'command.CloseLayer.description':
+    'Cierra la capa superior o cancela el modo de acción pendiente',
+  'command.ToggleDanger.label': 'Peligro',
+  'command.ToggleDanger.description': 'Activa o desactiva la vista de peligro',
+  'command.ToggleLayerView.label': 'Vista de nivel',
+  'command.ToggleLayerView.description': 'Alterna el tablero entre el suelo y las pasarelas',
+  'command.ToggleTimelineDiff.label': 'Cambios en la línea temporal',
+  'command.ToggleTimelineDiff.description':
+    'Resalta las casillas que cambiaron desde el último salto al pasado',
+  'command.CycleTimeline.label': 'Líneas temporales',
+  'command.CycleTimeline.description': 'Muestra la siguiente rama temporal en el tablero',
+  'command.NextPack.label': 'Paquete',
+  'command.NextPack.description': 'Pasa al siguiente paquete de contenido',
+  'command.RiftDeltaDown.label': 'Grieta -',
+  'command.RiftDeltaDown.description': 'Reduce el delta de grieta',
+  'command.RiftDeltaUp.label': 'Grieta +',
+  'command.RiftDeltaUp.description': 'Aumenta el delta de grieta',
+  'command.PushChainDown.label': 'Empuje máx -',
+  'command.PushChainDown.description': 'Reduce la fila máxima de empuje',
+  'command.PushChainUp.label': 'Empuje máx +',
+  'command.PushChainUp.description': 'Aumenta la fila máxima de empuje',
+  'command.ToggleHotseat.label': 'Por turnos',
+  'command.ToggleHotseat.description':
+    'Dos jugadores se turnan con los personajes (antes de moverse)',
+  'command.Quit.label': 'Salir',
+  'command.Quit.description': 'Salir (no disponible en la versión web)',
+
+  'group.Movement': 'Movimiento',
+  'group.Actions': 'Acciones',
+  'group.Interface': 'Interfaz',
+  'group.Tuning': 'Ajuste fino',
+
+  'bottomBar.direction': 'Dirección',
+
+  'help.title': 'Ayuda',
+  'help.levelTitle': 'Este nivel',
+  'help.goal':
+    'Llega a la salida sin que te vean. Los enemigos también vigilan cortes anteriores y posteriores.',
+  'help.riftTiles': 'Casillas de grieta: colócate encima y pulsa {keys} para viajar a su corte enlazado.',
+  'help.push': 'Las cajas se empujan en modo empujar ({menuKeys}, luego {keys}).',
+  'help.pull': 'Algunos objetos se arrastran en modo arrastrar ({menuKeys}, luego {keys}).',
+  'help.timePush':
+    'Las cajas se lanzan un corte hacia el futuro en modo empuje temporal ({menuKeys}, luego {keys}).',
+  'help.slippery':
+    'Sobre el hielo tú y las cajas empujadas seguís deslizándoos una casilla por corte hasta chocar.',
+  'help.water':
+    'No puedes entrar en el agua, pero una caja empujada se hunde y deja suelo firme.',
+  'help.shadows':
+    'Ningún guardia, cámara ni foco te ve mientras pisas una casilla de sombra oscura.',
+  'help.lures':
+    'Los señuelos atraen a los guardias cercanos durante unos cortes. Modo lanzar: {menuKeys}, luego {keys}.',
+  'help.hotseat':
+    'Por turnos ({keys}): los asientos se alternan tras cada acción; un personaje atrapado queda fuera.',
+  'help.characters':
+    'Controlas varios personajes; todos deben llegar a una salida para ganar. Cambia con {keys}.',
+  'help.breakables':
+    'Los muros agrietados ceden tras unos golpes (modo romper: {menuKeys}, luego {keys}). Se oyen.',
+  'help.peek':
+    'El modo asomarse ({menuKeys}, luego {keys}) muestra lo que se ve desde la casilla de al lado.',
+  'help.interactables':
+    'Las terminales y otros aparatos se usan desde al lado (modo usar: {menuKeys}, luego {keys}).',
+  'help.knockouts':
+    'Usa ({menuKeys}, luego {keys}) a un guardia que no te ve para noquearlo. Esconde el cuerpo.',
+  'help.elevation':
+    'Las escaleras ({keys}) llevan a las pasarelas. Desde abajo solo te ven tras una barandilla abierta.',
+
+  'mode.Move': 'Mover',
+  'mode.Move.description': 'Movimiento normal',
+  'mode.Push': 'Empujar',
+  'mode.Push.description': 'Empuja la fila hacia delante',
+  'mode.Pull': 'Arrastrar',
+  'mode.Pull.description': 'Arrastra desde atrás',
+  'mode.TimePush': 'Empuje temporal',
+  'mode.TimePush.description': 'Lanza una caja al siguiente corte',
+  'mode.Throw': 'Lanzar',
+  'mode.Throw.description': 'Lanza un señuelo para distraer a los guardias',
+  'mode.Break': 'Romper',
+  'mode.Break.description': 'Golpea un muro frágil (hace ruido)',
+  'mode.Peek': 'Asomarse',
+  'mode.Peek.description': 'Mira tras una esquina (sin turno)',
+  'mode.Interact': 'Usar',
+  'mode.Interact.description': 'Usa una terminal o aparato',
+
+  'breadcrumb.mode': '{mode} > ¿dirección? ({cancel}: volver a Mover)',
+  'breadcrumb.examine': 'Examinar ({x},{y}) > mueve el cursor ({cancel}: cerrar)',
+  'breadcrumb.plan': 'Plan > {count} en cola ({commit}: ejecutar, {cancel}: descartar)',
+
+  'phase.Playing': 'Jugando',
+  'phase.Won': 'Victoria',
+  'phase.Detected': 'Detectado',
+  'phase.Paradox': 'Paradoja',
+  'phase.BootError': 'Error de carga',
+  'playDifficulty.Easy': 'Fácil',
+  'playDifficulty.Normal': 'Normal',
+  'playDifficulty.Hard': 'Difícil',
+  'mutator.thickFog.label': 'Niebla espesa',
+  'mutator.thickFog.description': 'Alcance de visión de los guardias -2',
+  'mutator.hastyGuards.label': 'Guardias apresurados',
+  'mutator.hastyGuards.description': 'Retraso de detección -1',
+  'mutator.eagleEyes.label': 'Ojos de águila',
+  'mutator.eagleEyes.description': 'Alcance de visión de los guardias +1',
+  'mutator.noWaiting.label': 'Sin esperas',
+  'mutator.noWaiting.description': 'La acción de esperar se rechaza',
+  'mutator.noHints.label': 'Sin pistas',
+  'mutator.noHints.description': 'El presupuesto de pistas baja a 0',
+
+  'hud.command': 'Órdenes',
+  'hud.mode': 'Modo: {mode}',
+  'hud.state': 'Estado',
+  'hud.log': 'Registro',
+  'hud.legend': 'Leyenda',
+  'legend.player': 'Tú',
+  'legend.pastSelf': 'Yo pasado',
+  'legend.ghost': 'Fantasma de la mejor partida',
+  'legend.danger': 'Guardia que te vería',
+  'legend.facing': 'Orientación (relleno: solo cono)',
+  'legend.searchlight': 'Haz del foco',
+  'legend.darkness': 'Casilla sin luz',
+  'legend.shadow': 'Casilla de sombra (oculto)',
+  'hud.turn': 'Turno',
+  'hud.time': 'Tiempo',
+  'hud.phase': 'Fase',
+  'hud.riftDelta': 'Delta grieta',
+  'hud.danger': 'Peligro',
+  'hud.hints': 'Pistas',
+  'hud.difficulty': 'Dificultad',
+  'hud.rules': 'Reglas',
+  'hud.mutators': 'Mutadores',
+  'hud.restarts': 'Reinicios restantes',
+  'hud.lures': 'Señuelos',
+  'hud.peeks': 'Vistazos',
+  'hud.character': 'Personaje',
+  'hud.seat': 'Asiento',
+  'hud.seatValue': 'Jugador {seat}',
+  'hud.region': 'Zona',
+  'hud.layer': 'Nivel',
+  'hud.exit': 'Salida',
+  'hud.exitHeading': '{point}, a {distance}',
+  'hud.exitHere': 'Aquí',
+  'hud.extraction': 'Extracción',
+  'hud.extractionProgress': '{held} de {needed} turnos aguantados',
+  'hud.alarm': 'Alarma',
+  'hud.suspicion': 'Sospecha {suspicion}/{threshold}',
+  'hud.lockdown': 'Cierre hasta t={time}',
+  'compass.N': 'N',
+  'compass.NE': 'NE',
+  'compass.E': 'E',
+  'compass.SE': 'SE',
+  'compass.S': 'S',
+  'compass.SW': 'SO',
+  'compass.W': 'O',
+  'compass.NW': 'NO',
+  'layer.ground': 'Suelo',
+  'layer.catwalk': 'Pasarela',
+  'hud.timelines': 'Líneas temporales',
+  'hud.timelineRow': 'desde t={forkTime}, {state}',
+  'hud.timelinesHint': '{keys}: mostrar la siguiente rama',
+  'timeline.consistent': 'coherente',
+  'timeline.inconsistent': 'incoherente',
+  'hud.examine': 'Examinar ({x},{y}) en t={time}',
+  'hud.examineEmpty': 'No hay nada aquí en este corte.',
+  'hud.examineRoute': 'Ruta: {route}',
+  'hud.examineSchedule': 'Próximo: {schedule}',
+  'hud.examineRift': 'Grieta hacia ({x},{y}) en t={time}',
+  'hud.examineRiftBoth': 'Grieta hacia ({x},{y}) en t={time}, en ambos sentidos',
+  'hud.examineHint': 'Las direcciones mueven el cursor; {keys} o Esc para salir',
+  'hud.timer': 'Tiempo',
+  'hud.victory': 'Victoria',
+  'hud.victoryLevel': 'Superaste {name}',
+  'hud.victoryByAuthor': 'Superaste {name} de {author}',
+  'hud.stateDetailsHint': '{keys}: detalles',
+
+  'replay.faster': '{count} turno(s) más rápido que tu mejor marca',
+  'replay.slower': '{count} turno(s) más lento que tu mejor marca',
+  'replay.tied': 'Mismos turnos que tu mejor marca',
+  'replay.fewerRifts': '{count} grieta(s) menos',
+  'replay.moreRifts': '{count} grieta(s) más',
+  'replay.avoided': 'Esta vez evitaste {names}',
+  'replay.visited': 'Esta vez pasaste por {names}',
+
+  'log.title': 'Registro de acciones',
+  'log.empty': 'Aún no hay acciones.',
+  'worldLine.title': 'Línea de mundo',
+  'worldLine.controls': 'Arriba/Abajo: elegir, Enter: ver ese corte, {keys} / Esc: cerrar',
+  'worldLine.turn': 'Turno',
+  'worldLine.via': 'Vía',
+  'step.Start': 'Inicio',
+  'step.Normal': 'Paso',
+  'step.Rift': 'Grieta',
+  'step.Slide': 'Deslizar',
+  'step.Rewound': 'Rebobinado',
+  'step.Climb': 'Trepar',
+  'log.entry': '{action} -> {outcome}',
+  'log.withEvents': '{entry}; {events}',
+
+  'action.Move': 'mover {direction}',
+  'action.Push': 'empujar {direction}',
+  'action.Pull': 'arrastrar {direction}',
+  'action.TimePush': 'empuje temporal {direction}',
+  'action.Throw': 'lanzar señuelo {direction} ({range})',
+  'action.Wait': 'esperar',
+  'action.ApplyRift': 'grieta',
+  'action.SwitchCharacter': 'cambiar de personaje',
+  'action.Climb': 'trepar',
+  'action.Break': 'romper {direction}',
+  'action.Interact': 'usar {direction}',
+  'action.MoveTo': 'ir a ({x}, {y}, t={time})',
+
+  'direction.north': 'norte',
+  'direction.south': 'sur',
+  'direction.east': 'este',
+  'direction.west': 'oeste',
+
+  'outcome.Moved': 'movido',
+  'outcome.Rifted': 'a través de la grieta',
+  'outcome.Pushed': 'empujado',
+  'outcome.Pulled': 'arrastrado',
+  'outcome.Thrown': 'señuelo lanzado',
+  'outcome.Switched': 'cambio de personaje',
+  'outcome.Climbed': 'trepado',
+  'outcome.Broke': 'golpeado',
+  'outcome.Interacted': 'usado',
+  'outcome.KnockedOut': 'noqueado',
+
+  'event.Slid': 'deslizado hasta ({x}, {y})',
+  'event.ObjectsMoved': '{count} objeto(s) movido(s)',
+  'event.LureLanded': 'señuelo en ({x}, {y})',
+  'event.NoiseMade': 'ruido en ({x}, {y})',
+  'event.ObjectBroken': '{objectId} cedió',
+  'event.ObjectUnmade': '{objectId} dejó de existir desde t={from}',
+  'event.ObjectRestored': '{objectId} vuelve a existir desde t={from}',
+  'event.PropagationWarning.EntityCollision':
+    '{id} mantuvo su ruta: {blockerId} está en ({x},{y},t={t})',
+  'event.PropagationWarning.OutOfBounds':
+    '{id} mantuvo su ruta: ({x},{y},t={t}) está fuera del mapa',
+  'event.WaterBridged': 'una caja rellenó el agua en ({x}, {y})',
+  'event.EnteredRegion': 'entrada en {name}',
+  'event.TimelineForked': 'el pasado cambió: {timelineId} se bifurca en t={forkTime}',
+  'event.Paradox': 'paradoja ({reason})',
+  'event.Detected': 'visto por {enemyId}',
+  'event.Glimpsed': 'entrevisto por {enemyId} (sospecha {suspicion})',
+  'event.MovedObjectNoticed': '{enemyId} noto algo movido (sospecha {suspicion})',
+  'event.AlarmRaised': 'alarma, cierre hasta t={to}',
+  'event.Interacted': '{objectId} usado',
+  'event.LockdownLifted': 'cierre levantado desde t={at}',
+  'event.PatrolSwitched.reverse': '{enemyId} da media vuelta desde t={at}',
+  'event.PatrolSwitched.pause': '{enemyId} se detiene desde t={at}',
+  'event.PatrolSwitched.resume': '{enemyId} sigue su ruta desde t={at}',
+  'event.KnockedOut': '{enemyId} noqueado',
+  'event.BodyFound': '{enemyId} encontró {bodyId}',
+  'event.CharacterCaught': '{characterId} atrapado por {enemyId}',
+  'event.ReachedExit': '{characterId} en una salida',
+  'event.Won': 'nivel completado',
+  'event.ControlPassed': 'turno de {characterId}',
+
+  'plan.title': 'Plan de turnos',
+  'plan.controls':
+    'Direcciones / Enter / Espacio: encolar | Retroceso: deshacer | Y: confirmar | B / Esc: descartar',
+  'plan.empty': 'Aún no hay pasos encolados.',
+  'plan.step': '{action} -> ({x}, {y}, t={t})',
+  'plan.rejected': '{action} -> {status}',
+  'plan.seenBy': 'visto por {enemies}',
+  'plan.ends': 'fin de la partida: {phase}',
+
+  'confirm.title': 'Confirmar',
+  'confirm.controls': 'Y / Enter: continuar | N / Esc: cancelar',
+  'confirm.detection': 'Con este movimiento te verán en t={time}. ¿Continuar?',
+  'confirm.detectionBy': 'Con este movimiento {enemyId} te verá en t={time}. ¿Continuar?',
+
+  'settings.title': 'Ajustes',
+  'settings.language': 'Idioma',
+  'settings.difficulty':
+    'Dificultad: Fácil da a los guardias 1 turno más de retraso y 1 menos de visión y añade 2 pistas; Difícil lo contrario, sin pistas, y los guardias notan cajas movidas (recarga el nivel)',
+  'settings.ruleProfile': 'Reglas (recarga el nivel)',
+  'settings.ruleProfilesError':
+    'La configuración de reglas no es válida, solo Estándar está disponible: {error}',
+  'settings.isoPanel': 'Mostrar panel isométrico',
+  'settings.compactHints': 'Atajos compactos en la barra inferior',
+  'settings.defaultDanger': 'Vista de peligro por defecto',
+  'settings.ghostRun': 'Mostrar fantasma de la mejor partida',
+  'settings.exitCompass': 'Brújula de salida: señala una salida lejana u oculta',
+  'settings.assistDetection': 'Asistencia: confirmar movimientos que te delatan',
+  'settings.accessibleCues': 'Señales de forma y glifo (no solo color)',
+  'settings.fogOfWar': 'Niebla de guerra: solo mostrar lo que ves',
+  'settings.soundCues': 'Sonidos para avisos, grietas, empujes y victoria',
+  'settings.reduceMotion': 'Reducir movimiento: sin sacudidas ni barridos',
+  'settings.speedrunTimer': 'Cronómetro speedrun: mostrar y guardar el tiempo real de cada partida',
+  'settings.ironmanCampaign':
+    'Campaña iron-man: progreso aparte, se borra con cualquier detección',
+  'settings.keyRepeat': 'Retardo de repetición de tecla',
+  'settings.keyRepeatOff': 'desactivado',
+  'settings.keyRepeatMs': '{ms} ms',
+  'settings.savedToConfig': 'Los cambios se guardan en config.toml.',
+  'settings.savedInBrowser': 'Los cambios se guardan en este navegador.',
+
+  'state.title': 'Detalles del estado',
+  'state.core': 'Núcleo',
+  'state.tools': 'Herramientas',
+  'state.snapshot': 'Instantánea',
+  'state.board': 'Tablero',
+  'state.depth': 'Profundidad',
+  'state.mode': 'Modo',
+  'state.pushMax': 'Empuje máx',
+  'state.pull': 'Arrastre',
+  'state.sliceObjects': 'Objetos del corte',
+  'state.player': 'Jugador',
+  'state.wins': 'Victorias',
+  'state.winsValue': '{wins} ({hinted} con pistas)',
+  'state.bestTurns': 'Mejor en turnos',
+  'state.contentPack': 'Paquete',
+  'state.packClass': 'Clase',
+  'state.difficulty': 'Dificultad',
+  'state.diffSource': 'Origen dif.',
+  'state.diffScore': 'Puntuación dif.',
+  'state.diffModel': 'Modelo dif.',
+  'state.diffTarget': 'Objetivo dif.',
+  'state.flavor': 'Estilo',
+  'state.spatial': 'Espacial',
+  'state.temporal': 'Temporal',
+  'state.detect': 'Detección',
+  'state.interact': 'Interacción',
+  'state.paradox': 'Paradoja',
+
+  'tutorial.progress': 'Tutorial {step}/{total}',
+  'tutorial.complete': 'Tutorial completado.',
+
+  'progression.title': 'Progreso',
+  'progression.controls':
+    'G / Esc cerrar | Flechas navegar | Enter cargar | 1 dificultad | 2 etiqueta | 3 orden | 4 comunidad',
+  'progression.communityControls':
+    'G / Esc cerrar | Arriba/Abajo navegar | Enter cargar | 4 récords',
+  'progression.recordsControls': 'G / Esc cerrar | 4 desafío',
+  'progression.challengeControls':
+    'G / Esc cerrar | Arriba/Abajo navegar | Enter alternar | 1 diario | 2 quitar | 4 rutas',
+  'progression.tabTracks': 'Rutas',
+  'progression.tabCommunity': 'Comunidad ({count})',
+  'progression.tabRecords': 'Récords',
+  'progression.tabChallenge': 'Desafío ({count})',
+  'progression.challengeDaily': 'Diario: {names}',
+  'progression.challengeClear': 'Quitar todos',
+  'progression.challengeNote':
+    'Los mutadores se acumulan y se aplican a cada nivel que cargues; cambiarlos reinicia el actual.',
+  'progression.resumeSession': 'Reanudar última sesión: {name}, turno {turn} (U)',
+  'progression.recordTurns': '{count} turnos',
+  'progression.sumOfBest': 'Suma de mejores: {time}',
+  'progression.community': 'comunidad',
+  'progression.invalid': 'inválido',
+  'progression.communityEmpty':
+    'No hay niveles de la comunidad. Coloca archivos <id>.level.json en ~/.local/share/he-walks-unseen/levels/ y recarga la página.',
+  'progression.communityError': '{file}: {error}',
+  'progression.filterDifficulty': 'Dificultad: {value}',
+  'progression.filterTag': 'Etiqueta: {value}',
+  'progression.filterAll': 'todas',
+  'progression.sortTrack': 'Orden: ruta',
+  'progression.sortDifficulty': 'Orden: dificultad',
+  'progression.noMatches': 'Ningún nivel coincide con el filtro.',
+  'progression.unavailable': 'Progreso no disponible ({error}).',
+  'progression.loading': 'Cargando progreso...',
+  'progression.prev': 'Ant.',
+  'progression.next': 'Sig.',
+  'progression.track': 'Ruta {index}/{count}: {trackId}',
+  'progression.complete': 'completado',
+  'progression.unlocked': 'desbloqueado',
+  'progression.locked': 'bloqueado',
+  'progression.current': 'actual',
+  'progression.empty': 'No hay rutas de progreso definidas.',
+  'ironman.warning': 'Iron-man: una sola detección reinicia todo el progreso de la campaña',
+  'ironman.title': 'Iron-man',
+  'ironman.stats':
+    'Racha {streak} | Mejor {best} | Reinicios {resets} | Rutas perfectas {tracks}',
+  'ironman.achievement.firstClear': 'Comienzo limpio: supera un nivel',
+  'ironman.achievement.streak3': 'Fantasma: supera 3 niveles sin ser visto',
+  'ironman.achievement.streak5': 'Espectro: supera 5 niveles sin ser visto',
+  'ironman.achievement.flawlessTrack': 'Camina sin ser visto: termina una ruta sin detecciones',
+
+  'crash.title': 'Algo se rompió',
+  'crash.summary':
+    'El juego falló en el turno {turn} de {packId}. Se guardó un informe con la partida hasta ahora.',
+  'crash.download': 'Descargar informe de fallo',
+  'crash.restart': 'Reiniciar nivel',
+  'crash.reload': 'Recargar página',
+
+  'debug.title': 'Consola de depuración',
+  'debug.controls': 'Enter ejecuta una orden (prueba help). ` o Esc para cerrar.',
+  'debug.input': 'Orden de depuración',
+  'hud.perf': 'Rendimiento',
+  'perf.apply': 'Última acción',
+  'perf.slicesUpdated': 'Cortes cambiados',
+  'perf.propagationWarnings': 'Choques de guardias',
+  'perf.stateSize': 'Tamaño del estado',
+  'perf.cubeSize': 'Tamaño del cubo',
+  'perf.waiting': 'Realiza una acción para medirla.',
+  'perf.entities': 'Entidades por corte: {counts}',
+
+  'status.communityLevelInvalid': 'El nivel de la comunidad {file} no pasó la validación.',
+  'status.levelLocked': 'Nivel bloqueado: {packId}',
+  'status.planningDisabled': 'La planificación está desactivada con las reglas {rules}.',
+  'status.layerView': 'Mostrando el nivel {layer}.',
+  'status.timelineDiffOn':
+    'Cambios en la línea temporal: {count} casillas desde el último salto atrás.',
+  'status.timelineDiffOff': 'Cambios en la línea temporal ocultos.',
+  'status.timelineDiffUnavailable': 'Aún no hay nada que comparar: salta al pasado primero.',
+  'status.branchingOff': 'Con las reglas {rules} el pasado se sobrescribe, no se ramifica.',
+  'status.timelineActive': 'Siguiendo la línea temporal activa, {timelineId}.',
+  'status.timelineView': 'Viendo {timelineId} (bifurcada en t={forkTime}, {state}).',
+  'status.previewDisabled': 'La vista de peligro está desactivada con las reglas {rules}.',
+  'status.examineOn': 'Examinando: mueve el cursor para inspeccionar una casilla.',
+  'status.examineOff': 'Modo examinar cerrado.',
+  'status.viewingSlice':
+    'Viendo el corte t={t} del turno {turn}; tu próxima acción vuelve a t={now}.',
+  'status.viewingPresent': 'Viendo el corte presente.',
+  'status.snapshotSaved': 'Guardado {fileName}.',
+  'status.ironmanReset': 'Detectado en iron-man: el progreso de la campaña se ha reiniciado.',
+  'status.quitUnavailable': 'Salir no está disponible en la versión web.',
+  'status.noSavedSession': 'No hay ninguna sesión guardada que reanudar.',
+  'status.configError': 'Se ignora {error}',
+  'status.resumingSession': 'Cargando {packId} para reanudar en el turno {turn}...',
+  'status.contentLoadFailed': 'Fallo al cargar contenido ({packId}): {kind}',
+  'status.gameEnded': 'La partida ya terminó. Pulsa R para reiniciar.',
+  'status.turn': 'Turno {turn}: {status}',
+  'status.caughtPlaysOn': 'Turno {turn}: {enemyId} atrapó a {characterId}; sigue {nextId}',
+  'status.detected': 'Turno {turn}: detectado por {enemyId} (observado en t={time})',
+  'status.paradox': 'Turno {turn}: paradoja ({reason})',
+  'status.won': 'Turno {turn}: salida alcanzada en ({x}, {y}, t={time})',
+  'status.inconsistentExit':
+    'Turno {turn}: las salidas solo cuentan en una línea temporal coherente',
+  'status.holdingExit': 'Turno {turn}: manteniendo la salida ({held}/{needed})',
+  'status.othersNeedExit':
+    'Turno {turn}: {characterId} está en una salida; los demás aún necesitan una',
+  'status.nextCharacter': 'turno de {characterId}',
+  'status.glimpsed': '{enemyId} te entrevió ({suspicion}/{threshold})',
+  'status.noticedMoved': '{enemyId} notó que algo se movió ({suspicion}/{threshold})',
+  'status.alarmRaised': '{enemyId} dio la alarma; cierre hasta t={time}',
+  'status.bodyFound': '{enemyId} encontró a {bodyId}',
+  'status.bodyFoundLockdown': '{enemyId} encontró a {bodyId}; cierre hasta t={time}',
+  'status.dependencyFailed': 'Error interno: falló la propagación de dependencias ({kind})',
+  'status.detourFailed': 'Error interno: falló el desvío de patrulla ({kind})',
+  'status.lureFailed': 'Error interno: falló la propagación de señuelos ({kind})',
+  'status.chaserFailed': 'Error interno: falló la propagación de perseguidores ({kind})',
+  'status.enteredRegion': 'entraste en {name}',
+  'status.bootFailed':
+    'Falló el contenido inicial ({kind}); juego desactivado hasta cargar contenido válido',
+  'status.bootFallback':
+    'Falló el contenido inicial ({kind}); se usa el nivel de respaldo de desarrollo',
+  'status.bootContentFailed': 'Falló el contenido inicial',
+  'status.objectBootstrapFailed':
+    'Falló la preparación de objetos; juego desactivado hasta cargar contenido válido',
+  'status.contentBootstrapFailed': 'Falló la preparación del contenido cargado',
+  'status.restartNoLevel':
+    'Reinicio bloqueado: no hay nivel cargado. Carga un paquete de contenido válido.',
+  'status.restartFailed': 'El reinicio falló: error al preparar los objetos',
+  'status.hotseatLocked':
+    'El modo por turnos solo cambia antes de la primera acción; reinicia para cambiarlo',
+  'status.hotseatOn': 'Por turnos: los personajes se turnan',
+  'status.hotseatOff': 'Por turnos desactivado',
+  'status.riftSettings': 'Ajustes de grieta actualizados (delta={delta}, coste={cost})',
+  'status.interactionConfig':
+    'Configuración de interacción actualizada (maxPushChain={maxPushChain}, allowPull={allowPull}, allowTimePush={allowTimePush})',
+  'status.detectionConfig':
+    'Configuración de detección actualizada (enabled={enabled}, delay={delay}, range={range})',
+  'status.paradoxConfig': 'Configuración de paradojas actualizada (enabled={enabled})',
+  'status.loadingPack': 'Cargando paquete de contenido: {packId}',
+  'status.loadedPack': 'Paquete de contenido cargado: {packId}',
+  'status.noRestarts': 'No quedan reinicios con las reglas {rules}',
+  'status.restarted': 'Reiniciado',
+  'status.restartedEcho': 'Reiniciado; 1 eco repite intentos anteriores',
+  'status.restartedEchoes': 'Reiniciado; {count} ecos repiten intentos anteriores',
+  'status.resumed': 'Reanudado en el turno {turn}',
+  'status.hintsNotPlaying': 'Las pistas solo están disponibles durante la partida',
+  'status.noHints': 'No quedan pistas ({budget} por nivel)',
+  'status.hintSolved': 'Pista: ya estás en la salida',
+  'status.hintNoRoute': 'Pista: no hay una ruta segura desde aquí',
+  'status.hint':
+    'Pista {used}/{budget}: {action} hasta ({x}, {y}, t={time}); {steps} pasos hasta la salida',
+  'status.peekNotPlaying': 'Solo puedes asomarte durante la partida',
+  'status.noPeeks': 'No quedan vistazos ({budget} por nivel)',
+  'status.nothingToPeek': 'No hay nada tras lo que asomarse al {direction}',
+  'status.peeked': 'Vistazo {used}/{budget}: miraste al {direction} desde ({x}, {y})',
+  'status.debugOutside': 'Depuración: ({x},{y},t{time}) está fuera del cubo',
+  'status.debugTeleportFailed': 'Depuración: falló el teletransporte ({kind})',
+  'status.debugTeleported': 'Depuración: teletransportado a ({x},{y},t{time})',
+  'status.debugUnknownArchetype': 'Depuración: arquetipo desconocido {archetype}',
+  'status.debugSpawnFailed': 'Depuración: falló la creación ({kind})',
+  'status.debugSpawned': 'Depuración: creado {objectId} en ({x},{y},t{time})',
+
+  'interaction.outOfBounds': 'Bloqueado por el borde',
+  'interaction.timeBoundary': 'Bloqueado por el límite del tiempo',
+  'interaction.blockedByObject': 'Bloqueado por un objeto',
+  'interaction.selfIntersection': 'Bloqueado por tu propia línea temporal',
+  'interaction.invalidStep': 'Paso no válido ({action})',
+  'interaction.internalError': 'Error interno ({action})',
+  'interaction.emptyWorldLine': 'Error interno: línea temporal vacía',
+  'interaction.moved': 'mover a ({x}, {y}, t={time})',
+  'interaction.slid': 'resbalaste hasta ({x}, {y}, t={time})',
+  'interaction.noCatwalk': 'No hay pasarela ahí',
+  'interaction.waitDisabled': 'Esperar está desactivado',
+  'interaction.waited': 'esperar en t={time}',
+  'interaction.noLadder': 'No hay escalera aquí',
+  'interaction.climbedUp': 'subiste en t={time}',
+  'interaction.climbedDown': 'bajaste en t={time}',
+  'interaction.riftTargetTime': 'Tiempo de destino de la grieta no válido',
+  'interaction.riftTargetPosition': 'Posición de destino de la grieta no válida',
+  'interaction.insufficientEnergy': 'Energía insuficiente para la grieta',
+  'interaction.rifted': 'grieta({mode}) a ({x}, {y}, t={time})',
+  'interaction.offGroundBoxes': 'No alcanzas las cajas desde la pasarela',
+  'interaction.offGroundWalls': 'No alcanzas los muros desde la pasarela',
+  'interaction.offGroundAnything': 'No alcanzas nada desde la pasarela',
+  'interaction.anchored': '{objectId} está anclado en el tiempo',
+  'interaction.notPushable': 'No se puede empujar el objetivo',
+  'interaction.pushChainTooLong': 'Cadena de empuje demasiado larga',
+  'interaction.noSpaceToPush': 'No hay espacio para empujar',
+  'interaction.stackedBox': 'Las cajas apiladas no se pueden empujar',
+  'interaction.multiTileInChain': 'Los objetos grandes no se pueden empujar en cadena',
+  'interaction.groupInChain': 'Las cajas unidas no se pueden empujar en cadena',
+  'interaction.pushBlocked': 'Empuje bloqueado',
+  'interaction.pushFailed': 'El empuje falló',
+  'interaction.heavyNeedsMomentum': 'La caja pesada necesita impulso',
+  'interaction.heavyInChain': 'La caja pesada no se puede empujar en cadena',
+  'interaction.riftExitBlocked': 'La salida de la grieta está bloqueada',
+  'interaction.guardNoWayAround': 'Un guardia no tiene por dónde pasar',
+  'interaction.stacked': 'apilaste {objectId} sobre {baseId} en ({x}, {y}, t={time})',
+  'interaction.pushedGroup':
+    'empujaste el grupo {groupId} ({count} objeto(s)) hacia el {direction}',
+  'interaction.pushedObject': 'empujaste {objectId} hasta ({x}, {y}, t={time})',
+  'interaction.pushed': 'empujaste {count} objeto(s) hasta ({x}, {y}, t={time})',
+  'interaction.pushedRifted':
+    'empujaste {count} objeto(s); la primera caja cruzó la grieta hasta ({x}, {y}, t={time})',
+  'interaction.pushedSank':
+    'empujaste {count} objeto(s); la primera caja se hundió en el agua en ({x}, {y}, t={time})',
+  'interaction.pullDisabled': 'Tirar está desactivado',
+  'interaction.nothingToPull': 'No hay nada de lo que tirar',
+  'interaction.notPullable': 'No se puede tirar del objetivo',
+  'interaction.notPullableHere': 'No se puede tirar del objetivo en esta línea temporal',
+  'interaction.invalidPullTarget': 'Objetivo de tirón no válido',
+  'interaction.pulled': 'tiraste de 1 objeto hasta ({x}, {y}, t={time})',
+  'interaction.timePushDisabled': 'El empuje temporal está desactivado',
+  'interaction.noLaterSlice': 'No hay un corte posterior al que empujar',
+  'interaction.timePushed': 'empujaste {objectId} en el tiempo hasta t={time}',
+  'interaction.noLuresLeft': 'No quedan señuelos',
+  'interaction.throwRange': 'El alcance del lanzamiento debe estar entre 1 y {max}',
+  'interaction.noRoomToThrow': 'No hay sitio para lanzar',
+  'interaction.threw': 'lanzaste un señuelo a ({x}, {y}, t={time})',
+  'interaction.nothingToBreak': 'No hay nada que romper ahí',
+  'interaction.broke': 'rompiste {objectId} en t={time}',
+  'interaction.brokeLinked': 'rompiste {objectId} y {count} objeto(s) unido(s) en t={time}',
+  'interaction.hit': 'golpeaste {objectId} ({hits}/{total}) en t={time}',
+  'interaction.nothingToUse': 'No hay nada que usar ahí',
+  'interaction.used': 'usaste {objectId}',
+  'interaction.guardAware': '{enemyId} te ve venir',
+  'interaction.noRoomForBody': 'No hay sitio para el cuerpo',
+  'interaction.knockedOut': 'dejaste fuera de combate a {enemyId}',
+  'interaction.noOtherCharacter': 'No hay otro personaje al que cambiar',
+  'interaction.switched': 'cambiaste a {characterId}',
+  'interaction.suspicionCleared': 'sospecha disipada',
+  'interaction.noAlarm': 'no hay alarma que reiniciar',
+  'interaction.lockdownLifted': 'cierre levantado desde t={time}',
+  'interaction.nothingHappens': 'no pasa nada',
+  'interaction.leverReverse': '{enemyId} da media vuelta',
+  'interaction.leverPause': '{enemyId} se detiene',
+  'interaction.leverResume': '{enemyId} sigue adelante',
+  'interaction.moveTo': 'Ir a: {reason}',
+  'interaction.moveToArrived': 'Ir a: ya estás ahí',
+  'moveTo.outsideHorizon': 'el destino ({x}, {y}, t={time}) está fuera de alcance',
+  'moveTo.noPath': 'no hay camino hasta ({x}, {y}, t={time})',
+  'moveTo.hotseat': 'no disponible por turnos',
+  'moveTo.stepRejected': 'paso {step} rechazado ({status})',
+  'moveTo.wouldEnd': 'el paso {step} terminaría la partida ({status})',
+  'moveTo.diverged': 'el paso {step} acaba en {actual} en lugar de ({x}, {y})',
+  'moveTo.cell': '({x}, {y})',
+  'moveTo.nowhere': 'ninguna parte',
+}
@@ -0,0 +1,48 @@
+import { describe, expect, it } from 'vitest'
+
+import { en } from './en'
+import { createTranslator, isLocale, MESSAGE_CATALOGS } from './index'
+
+describe('i18n', () => {
+  it('interpolates named parameters and leaves unknown placeholders intact', () => {
+    const t = createTranslator('en')
+
+    expect(t('tutorial.progress', { step: 2, total: 5 })).toBe('Tutorial 2/5')
+    expect(t('status.levelLocked')).toBe('Level locked: {packId}')
+  })
+
+  it('falls back to English for keys missing from a partial catalog', () => {
+    const t = createTranslator('es', { en, es: { 'help.title': 'Ayuda' } })
+
+    expect(t('help.title')).toBe('Ayuda')
+    expect(t('log.empty')).toBe('No actions yet.')
+  })
+
+  it('uses the selected catalog when the key is translated', () => {
+    expect(createTranslator('es')('command.Wait.label')).toBe('Esperar')
+  })
+
+  it('keeps every catalog within the English key set', () => {
+    const englishKeys = new Set(Object.keys(en))
+
+    for (const catalog of Object.values(MESSAGE_CATALOGS)) {
+      for (const key of Object.keys(catalog)) {
+        expect(englishKeys.has(key)).toBe(true)
+      }
+    }
+  })
+
+  it('translates every English key in every catalog', () => {
+    for (const catalog of Object.values(MESSAGE_CATALOGS)) {
+      const untranslated = Object.keys(en).filter((key) => !(key in catalog))
+
+      expect(untranslated).toEqual([])
+    }
+  })
+
+  it('recognises only supported locales', () => {
+    expect(isLocale('es')).toBe(true)
+    expect(isLocale('xx')).toBe(false)
+    expect(isLocale(undefined)).toBe(false)
+  })
+})
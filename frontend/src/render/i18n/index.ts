@@ -0,0 +1,42 @@
+import { en } from './en'
+import { es } from './es'
+
+export type MessageKey = keyof typeof en
+export type MessageCatalog = Partial<Record<MessageKey, string>>
+export type MessageParams = Record<string, string | number>
+export type Locale = 'en' | 'es'
+export type Translator = (key: MessageKey, params?: MessageParams) => string
+
+export const DEFAULT_LOCALE: Locale = 'en'
+
+export const SUPPORTED_LOCALES: { id: Locale; label: string }[] = [
+  { id: 'en', label: 'English' },
+  { id: 'es', label: 'Español' },
+]
+
+export const MESSAGE_CATALOGS: Record<Locale, MessageCatalog> = { en, es }
+
+export function isLocale(value: unknown): value is Locale {
+  return SUPPORTED_LOCALES.some((locale) => locale.id === value)
+}
+
+function interpolate(template: string, params: MessageParams | undefined): string {
+  if (!params) {
+    return template
+  }
+
+  return template.replace(/\{(\w+)\}/g, (match, name: string) =>
+    name in params ? String(params[name]) : match,
+  )
+}
+
+/** Looks keys up in the requested locale, falling back to English and finally to the key itself. */
+export function createTranslator(
+  locale: Locale,
+  catalogs: Record<Locale, MessageCatalog> = MESSAGE_CATALOGS,
+): Translator {
+  const catalog = catalogs[locale]
+  const fallback = catalogs[DEFAULT_LOCALE]
+
+  return (key, params) => interpolate(catalog[key] ?? fallback[key] ?? key, params)
+}
@@ -1,5 +1,6 @@
 import { Edges } from '@react-three/drei'
 
+import type { Turn } from '../../core/coords'
 import type { IsoCubeViewModel } from './buildIsoViewModel'
 import { cellToWorld, sliceOpacity } from './constants'
 import type { IsoTheme } from '../theme'
@@ -11,6 +12,7 @@ function ObjectBlock({
   opacity,
   position,
   theme,
+  accessibleCues,
 }: {
   kind: string
   colorFill: string
@@ -18,8 +20,17 @@ function ObjectBlock({
   opacity: number
   position: [number, number, number]
   theme: IsoTheme
+  accessibleCues: boolean
 }) {
-  const objectHeight = kind === 'exit' ? theme.view.objectHeight * 0.7 : theme.view.objectHeight
+  // With accessible cues on, enemies also differ by silhouette (a narrow pillar), not only color.
+  const isEnemyPillar = accessibleCues && kind === 'enemy'
+  const objectHeight =
+    kind === 'exit'
+      ? theme.view.objectHeight * 0.7
+      : isEnemyPillar
+        ? theme.view.objectHeight * 1.5
+        : theme.view.objectHeight
+  const objectSize = isEnemyPillar ? theme.view.objectSize * 0.55 : theme.view.objectSize
 
   return (
     <group
@@ -30,7 +41,7 @@ function ObjectBlock({
       ]}
     >
       <mesh>
-        <boxGeometry args={[theme.view.objectSize, objectHeight, theme.view.objectSize]} />
+        <boxGeometry args={[objectSize, objectHeight, objectSize]} />
         <meshBasicMaterial color={colorFill} transparent opacity={opacity} />
         <Edges
           color={colorStroke}
@@ -50,23 +61,27 @@ function PlayerBlock({
   opacity,
   position,
   theme,
+  heightScale,
 }: {
   colorFill: string
   colorStroke: string
   opacity: number
   position: [number, number, number]
   theme: IsoTheme
+  heightScale: number
 }) {
+  const playerHeight = theme.view.playerHeight * heightScale
+
   return (
     <group
       position={[
         position[0],
-        position[1] + theme.view.playerHeight / 2 + theme.view.sliceThickness / 2,
+        position[1] + playerHeight / 2 + theme.view.sliceThickness / 2,
         position[2],
       ]}
     >
       <mesh>
-        <boxGeometry args={[theme.view.playerSize, theme.view.playerHeight, theme.view.playerSize]} />
+        <boxGeometry args={[theme.view.playerSize, playerHeight, theme.view.playerSize]} />
         <meshBasicMaterial color={colorFill} transparent opacity={opacity} />
         <Edges
           color={colorStroke}
@@ -83,9 +98,10 @@ function PlayerBlock({
 interface IsoActorsProps {
   boardWidth: number
   boardHeight: number
-  currentTurn: number
+  currentTurn: Turn
   viewModel: IsoCubeViewModel
   theme: IsoTheme
+  accessibleCues: boolean
 }
 
 export function IsoActors({
@@ -94,6 +110,7 @@ export function IsoActors({
   currentTurn,
   viewModel,
   theme,
+  accessibleCues,
 }: IsoActorsProps) {
   return (
     <>
@@ -132,13 +149,14 @@ export function IsoActors({
 
           return (
             <ObjectBlock
-              key={`object-${slice.t}-${object.id}`}
+              key={`object-${slice.t}-${object.id}-${object.x},${object.y}`}
               kind={object.kind}
               position={position}
               opacity={objectOpacity}
               colorFill={palette.fill}
               colorStroke={palette.stroke}
               theme={theme}
+              accessibleCues={accessibleCues}
             />
           )
         })
@@ -167,6 +185,7 @@ export function IsoActors({
               colorFill={isCurrentTurnSelf ? theme.selfFill : theme.pastSelfFill}
               colorStroke={isCurrentTurnSelf ? theme.selfStroke : theme.pastSelfStroke}
               theme={theme}
+              heightScale={accessibleCues && isCurrentTurnSelf ? 1.6 : 1}
             />
           )
         })
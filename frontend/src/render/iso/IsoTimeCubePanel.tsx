@@ -3,6 +3,7 @@ import { useCallback, useEffect, useMemo, useRef, useState } from 'react'
 import type { OrthographicCamera } from 'three'
 import type { OrbitControls as OrbitControlsImpl } from 'three-stdlib'
 
+import type { Turn } from '../../core/coords'
 import { IsoActors } from './IsoActors'
 import { IsoControlBar, IsoOrbitSceneControls } from './IsoCameraControls'
 import { IsoSlices } from './IsoSlices'
@@ -15,8 +16,9 @@ import { minimalMonoTheme } from '../theme'
 interface IsoTimeCubePanelProps {
   boardWidth: number
   boardHeight: number
-  currentTurn: number
+  currentTurn: Turn
   viewModel: IsoCubeViewModel
+  accessibleCues: boolean
 }
 
 export function IsoTimeCubePanel({
@@ -24,6 +26,7 @@ export function IsoTimeCubePanel({
   boardHeight,
   currentTurn,
   viewModel,
+  accessibleCues,
 }: IsoTimeCubePanelProps) {
   const cameraRef = useRef<OrthographicCamera | null>(null)
   const controlsRef = useRef<OrbitControlsImpl | null>(null)
@@ -135,6 +138,7 @@ export function IsoTimeCubePanel({
             currentTurn={currentTurn}
             viewModel={viewModel}
             theme={theme}
+            accessibleCues={accessibleCues}
           />
         </group>
         <IsoOrbitSceneControls
@@ -2,6 +2,7 @@ import { Edges, Line } from '@react-three/drei'
 import { useMemo } from 'react'
 import { CatmullRomCurve3, Vector3 } from 'three'
 
+import type { Turn } from '../../core/coords'
 import type { IsoCubeViewModel, IsoMovingObjectTrack } from './buildIsoViewModel'
 import { pathOpacity, trackPointToWorld } from './constants'
 import type { IsoTrackRenderModel } from './trajectory'
@@ -99,7 +100,7 @@ interface MovingObjectTrackWithRender extends IsoMovingObjectTrack {
 interface IsoTracksProps {
   boardWidth: number
   boardHeight: number
-  currentTurn: number
+  currentTurn: Turn
   viewModel: IsoCubeViewModel
   playerTrack: IsoTrackRenderModel
   movingObjectTracks: MovingObjectTrackWithRender[]
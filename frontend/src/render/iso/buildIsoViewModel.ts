@@ -1,3 +1,5 @@
+import { footprintCells } from '../../core/footprint'
+import { resolveObjectRender } from '../../core/objects'
 import { objectsAtTime, type TimeCube } from '../../core/timeCube'
 import type { WorldLineState } from '../../core/worldLine'
 import { selectIsoWindow } from './selectIsoWindow'
@@ -82,28 +84,33 @@ export function buildIsoViewModel(input: BuildIsoViewModelInput): IsoCubeViewMod
         turn: entry.turn,
       }))
 
-    const objects = objectsAtTime(input.cube, t).map((object) => ({
-      id: object.id,
-      x: object.position.x,
-      y: object.position.y,
-      kind: object.archetype.kind,
-      render: object.archetype.render,
-    }))
-
-    for (const object of objects) {
+    const resolved = objectsAtTime(input.cube, t)
+    // Multi-tile objects get a block per covered cell; their tracks follow the anchor.
+    const objects = resolved.flatMap((object) =>
+      footprintCells(object.archetype.components, object.position).map((cell) => ({
+        id: object.id,
+        x: cell.x,
+        y: cell.y,
+        kind: object.archetype.kind,
+        render: resolveObjectRender(object.archetype),
+      })),
+    )
+
+    for (const object of resolved) {
+      const { x, y } = object.position
       const existing = objectPointsById.get(object.id)
 
       if (!existing) {
         objectPointsById.set(object.id, {
           id: object.id,
-          kind: object.kind,
-          render: object.render,
-          anchors: [{ x: object.x, y: object.y, t }],
+          kind: object.archetype.kind,
+          render: resolveObjectRender(object.archetype),
+          anchors: [{ x, y, t }],
         })
         continue
       }
 
-      existing.anchors.push({ x: object.x, y: object.y, t })
+      existing.anchors.push({ x, y, t })
     }
 
     slices.push({
@@ -120,6 +127,7 @@ export function buildIsoViewModel(input: BuildIsoViewModelInput): IsoCubeViewMod
       y: position.y,
       t: position.t,
       turn,
+      step: input.worldLine.steps[turn],
     }))
     .filter((entry) => entry.t >= window.startT && entry.t <= window.endT)
     .sort((a, b) => (a.turn ?? 0) - (b.turn ?? 0))
@@ -38,4 +38,16 @@ describe('buildTrackRenderModel', () => {
     expect(model.localPaths[0].length).toBeGreaterThan(anchors.length)
     expect(model.riftBridges).toHaveLength(0)
   })
+
+  it('bridges a rift step even when it lands next to the previous cell', () => {
+    const anchors: IsoTrackPoint[] = [
+      { x: 1, y: 1, t: 0, turn: 0, step: 'Start' },
+      { x: 2, y: 1, t: 1, turn: 1, step: 'Rift' },
+    ]
+
+    const model = buildTrackRenderModel(anchors, 'exact')
+
+    expect(model.localPaths).toHaveLength(0)
+    expect(model.riftBridges).toEqual([{ from: anchors[0], to: anchors[1] }])
+  })
 })
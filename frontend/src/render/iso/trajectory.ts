@@ -1,5 +1,7 @@
 import { CatmullRomCurve3, Vector3 } from 'three'
 
+import type { WorldLineStepKind } from '../../core/worldLine'
+
 export type IsoPathMode = 'organic' | 'exact'
 
 export interface IsoTrackPoint {
@@ -7,6 +9,8 @@ export interface IsoTrackPoint {
   y: number
   t: number
   turn?: number
+  /** How the world line reached this point; without it, edges are classified by geometry. */
+  step?: WorldLineStepKind
 }
 
 export interface IsoTrackBridge {
@@ -25,6 +29,10 @@ function manhattan2D(a: IsoTrackPoint, b: IsoTrackPoint): number {
 }
 
 function isLocalEdge(a: IsoTrackPoint, b: IsoTrackPoint): boolean {
+  if (b.step === 'Rift') {
+    return false
+  }
+
   return Math.abs(a.t - b.t) === 1 && manhattan2D(a, b) <= 1
 }
 
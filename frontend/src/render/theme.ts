@@ -4,11 +4,42 @@ export interface CanvasTheme {
   playerStroke: string
   pastSelfFill: string
   pastSelfStroke: string
+  ghostStroke: string
+  hintStroke: string
+  /** Frame of the examine cursor. */
+  examineStroke: string
+  cueInk: string
+  cuePlate: string
   objectFill: string
   objectStroke: string
   objectGlyph: string
+  /** Ink for décor glyphs; kept faint so décor reads as floor, not as something to avoid. */
+  decorInk: string
   dangerMarkerStroke: string
   dangerMarkerFill: string
+  searchlightFill: string
+  darknessFill: string
+  /** Shadow tiles, where the player cannot be detected. */
+  shadowFill: string
+  fogFill: string
+  memoryFill: string
+  detectedFlashFill: string
+  wonSweepFill: string
+  /** Rift shimmer shades, indexed by the shimmer frame. */
+  riftShimmer: string[]
+  warpStreakStroke: string
+  /** Catwalk outline in the ground view; the catwalk view draws walkways as solid tiles. */
+  catwalkStroke: string
+  /** Wash over everything off the catwalks in the catwalk view. */
+  layerVeilFill: string
+  /** Cells the last rift into the past changed, in the timeline diff view. */
+  timelineDiffFill: string
+  timelineDiffStroke: string
+  /** Where the turn plan would leave moved objects; drawn see-through over the board. */
+  planFill: string
+  planStroke: string
+  /** Tick on the edge a guard or camera faces. */
+  facingTick: string
 }
 
 export interface IsoTheme {
@@ -80,11 +111,33 @@ export const minimalMonoTheme: AppTheme = {
     playerStroke: '#111111',
     pastSelfFill: '#9a9a9a',
     pastSelfStroke: '#4d4d4d',
+    ghostStroke: '#8a8a8a',
+    hintStroke: '#111111',
+    examineStroke: '#7b5fc8',
+    cueInk: '#111111',
+    cuePlate: '#ffffff',
     objectFill: '#efefef',
     objectStroke: '#111111',
     objectGlyph: '#111111',
+    decorInk: '#a8a8a8',
     dangerMarkerStroke: '#111111',
     dangerMarkerFill: '#cfcfcf',
+    searchlightFill: 'rgba(255, 214, 64, 0.35)',
+    darknessFill: 'rgba(17, 17, 17, 0.3)',
+    shadowFill: '#5a5a5a',
+    fogFill: '#2a2a2a',
+    memoryFill: 'rgba(240, 240, 240, 0.6)',
+    detectedFlashFill: '#d62828',
+    wonSweepFill: '#2a9d4b',
+    riftShimmer: ['#5b3fa8', '#7b5fc8', '#a48ee0', '#7b5fc8'],
+    warpStreakStroke: '#7b5fc8',
+    catwalkStroke: '#6a6a6a',
+    layerVeilFill: 'rgba(255, 255, 255, 0.7)',
+    timelineDiffFill: 'rgba(123, 95, 200, 0.18)',
+    timelineDiffStroke: '#7b5fc8',
+    planFill: '#bcd4f0',
+    planStroke: '#2f6fb5',
+    facingTick: '#111111',
   },
   iso: {
     view: {
@@ -1,7 +1,21 @@
 import { defineConfig } from 'vite'
 import react from '@vitejs/plugin-react'
 
+import { communityLevelsPlugin } from './scripts/community-levels-plugin'
+import {
+  configFilePlugin,
+  readConfiguredLevelsDir,
+  resolveConfigPath,
+} from './scripts/config-file-plugin'
+
+const configPath = resolveConfigPath()
+
 // https://vite.dev/config/
-export default defineConfig({
-  plugins: [react()],
-})
+export default defineConfig(({ command, isPreview }) => ({
+  plugins: [
+    react(),
+    // Settings are only written back from `vite dev`; preview and builds never take a PUT.
+    configFilePlugin(configPath, { writable: command === 'serve' && !isPreview }),
+    communityLevelsPlugin(readConfiguredLevelsDir(configPath)),
+  ],
+}))